@@ -50,5 +50,47 @@ fn fork(c: &mut Criterion) {
     });
 }
 
+// Compares the ChaCha generator to the software AES one, which it replaces on platforms without
+// dedicated AES instructions.
+#[cfg(all(feature = "generator_chacha", feature = "generator_fallback"))]
+mod chacha {
+    use super::N_GEN;
+    use concrete_csprng::generators::{
+        ChaChaRandomGenerator, RandomGenerator, SoftwareRandomGenerator,
+    };
+    use concrete_csprng::seeders::{RdseedSeeder, Seeder};
+    use criterion::Criterion;
+
+    fn generate<G: RandomGenerator>(c: &mut Criterion, name: &str) {
+        let mut seeder = RdseedSeeder;
+        let mut generator = G::new(seeder.seed());
+        c.bench_function(name, |b| {
+            b.iter(|| {
+                (0..N_GEN).for_each(|_| {
+                    generator.next();
+                })
+            })
+        });
+    }
+
+    pub fn chacha_parent_generate(c: &mut Criterion) {
+        generate::<ChaChaRandomGenerator>(c, "chacha_parent_generate");
+    }
+
+    pub fn software_parent_generate(c: &mut Criterion) {
+        generate::<SoftwareRandomGenerator>(c, "software_parent_generate");
+    }
+}
+
 criterion_group!(benches, parent_generate, child_generate, fork);
+#[cfg(all(feature = "generator_chacha", feature = "generator_fallback"))]
+criterion_group!(
+    chacha_benches,
+    chacha::chacha_parent_generate,
+    chacha::software_parent_generate
+);
+
+#[cfg(not(all(feature = "generator_chacha", feature = "generator_fallback")))]
 criterion_main!(benches);
+#[cfg(all(feature = "generator_chacha", feature = "generator_fallback"))]
+criterion_main!(benches, chacha_benches);
@@ -4,11 +4,18 @@
 use clap::{value_parser, Arg, Command};
 #[cfg(feature = "generator_x86_64_aesni")]
 use concrete_csprng::generators::AesniRandomGenerator as ActivatedRandomGenerator;
+#[cfg(all(
+    not(feature = "generator_x86_64_aesni"),
+    not(feature = "generator_aarch64_aes"),
+    feature = "generator_chacha"
+))]
+use concrete_csprng::generators::ChaChaRandomGenerator as ActivatedRandomGenerator;
 #[cfg(feature = "generator_aarch64_aes")]
 use concrete_csprng::generators::NeonAesRandomGenerator as ActivatedRandomGenerator;
 #[cfg(all(
     not(feature = "generator_x86_64_aesni"),
     not(feature = "generator_aarch64_aes"),
+    not(feature = "generator_chacha"),
     feature = "generator_fallback"
 ))]
 use concrete_csprng::generators::SoftwareRandomGenerator as ActivatedRandomGenerator;
@@ -2,7 +2,7 @@ use crate::generators::aes_ctr::block_cipher::{AesBlockCipher, AesKey};
 use crate::generators::aes_ctr::index::TableIndex;
 use crate::generators::aes_ctr::states::{BufferPointer, ShiftAction, State};
 use crate::generators::aes_ctr::BYTES_PER_BATCH;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError};
+use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, StateError};
 
 // Usually, to work with iterators and parallel iterators, we would use opaque types such as
 // `impl Iterator<..>`. Unfortunately, it is not yet possible to return existential types in
@@ -82,11 +82,63 @@ impl<BlockCipher: AesBlockCipher> AesCtrGenerator<BlockCipher> {
         }
     }
 
+    /// Restores a csprng from its key, the table index of the next byte to output, and its bound.
+    ///
+    /// Note :
+    /// ------
+    ///
+    /// Contrary to [`AesCtrGenerator::new`], the `next_index` can be equal to the `bound_index`,
+    /// which allows to restore a generator which reached its bound. For the reason explained in
+    /// [`AesCtrGenerator::new`], the `next_index` can not be the first table index.
+    pub fn try_restore(
+        key: AesKey,
+        next_index: TableIndex,
+        bound_index: TableIndex,
+    ) -> Result<AesCtrGenerator<BlockCipher>, StateError> {
+        if next_index == TableIndex::FIRST {
+            return Err(StateError::FirstTableIndex);
+        }
+        if next_index > bound_index {
+            return Err(StateError::IndexAfterBound);
+        }
+        Ok(AesCtrGenerator {
+            block_cipher: Box::new(BlockCipher::new(key)),
+            state: State::new(next_index),
+            last: bound_index.decremented(),
+            buffer: [0u8; BYTES_PER_BATCH],
+        })
+    }
+
+    /// Returns the key of the block cipher used in the background.
+    pub fn key(&self) -> AesKey {
+        self.block_cipher.key()
+    }
+
     /// Returns the table index related to the previous random byte.
     pub fn table_index(&self) -> TableIndex {
         self.state.table_index()
     }
 
+    /// Returns the table index of the next random byte.
+    pub fn next_table_index(&self) -> TableIndex {
+        self.state.table_index().incremented()
+    }
+
+    /// Tries to move the generator forward, so that the next outputted byte is the one at `index`.
+    ///
+    /// The bytes between the current position and `index` are never outputted by the generator.
+    /// Moving backward is not allowed, as it would make the generator output the same bytes twice.
+    pub fn try_skip_to(&mut self, index: TableIndex) -> Result<(), StateError> {
+        if index < self.next_table_index() {
+            return Err(StateError::SkipBackward);
+        }
+        if index > self.get_bound() {
+            return Err(StateError::IndexAfterBound);
+        }
+        self.state = State::new(index);
+        Ok(())
+    }
+
     /// Returns the bound of the generator if any.
     ///
     /// The bound is the table index of the first byte that can not be outputted by the generator.
@@ -255,6 +307,65 @@ pub mod aes_ctr_generic_test {
             })
     }
 
+    /// Check the property:
+    ///     For all keys k, table indices t and shifts i,
+    ///         a generator restored from (k, t+i, bound) outputs the same bytes as a generator
+    ///         started at t, after it outputted i bytes.
+    pub fn prop_restore<G: AesBlockCipher>() {
+        for _ in 0..1000 {
+            let (((key, t), i), bound) = any_key()
+                .zip(any_table_index())
+                .zip(any_usize())
+                .zip(any_table_index())
+                .map(|(((k, t), i), b)| (((k, t), i % 1024), b))
+                .find(|(((_, t), i), b)| *t > TableIndex::FIRST && t.increased(*i + 64) < *b)
+                .unwrap();
+            let mut generator = AesCtrGenerator::<G>::new(key, Some(t), Some(bound));
+            for _ in 0..i {
+                generator.next().unwrap();
+            }
+            let mut restored =
+                AesCtrGenerator::<G>::try_restore(key, generator.next_table_index(), bound)
+                    .unwrap();
+            assert_eq!(restored.key().0, generator.key().0);
+            assert_eq!(restored.next_table_index(), t.increased(i));
+            assert_eq!(restored.remaining_bytes(), generator.remaining_bytes());
+            for _ in 0..64 {
+                assert_eq!(restored.next(), generator.next());
+            }
+        }
+    }
+
+    /// Check the property:
+    ///     For all keys k, table indices t and shifts i,
+    ///         a generator started at t and skipped to t+i outputs the same bytes as a generator
+    ///         started at t, after it outputted i bytes.
+    pub fn prop_skip_to<G: AesBlockCipher>() {
+        for _ in 0..1000 {
+            let ((key, t), i) = any_key()
+                .zip(any_table_index())
+                .zip(any_usize())
+                .map(|((k, t), i)| ((k, t), i % 1024))
+                .find(|((_, t), i)| {
+                    *t > TableIndex::FIRST && t.increased(*i + 64) < TableIndex::LAST
+                })
+                .unwrap();
+            let mut generator = AesCtrGenerator::<G>::new(key, Some(t), None);
+            let mut skipped = generator.clone();
+            for _ in 0..i {
+                generator.next().unwrap();
+            }
+            skipped.try_skip_to(t.increased(i)).unwrap();
+            for _ in 0..64 {
+                assert_eq!(skipped.next(), generator.next());
+            }
+            assert!(matches!(
+                skipped.try_skip_to(t),
+                Err(StateError::SkipBackward)
+            ));
+        }
+    }
+
     /// Check the property:
     ///     On a valid fork, the table index of the first child is the same as the table index of
     ///     the parent before the fork.
@@ -46,6 +46,25 @@ impl TableIndex {
         }
     }
 
+    /// Creates a table index from an aes index and a byte index, returns `None` if the byte index
+    /// is out of the range of an aes call.
+    pub fn try_new(aes_index: AesIndex, byte_index: ByteIndex) -> Option<Self> {
+        (byte_index.0 < BYTES_PER_AES_CALL).then_some(TableIndex {
+            aes_index,
+            byte_index,
+        })
+    }
+
+    /// Returns the aes index of the table index.
+    pub fn aes_index(&self) -> AesIndex {
+        self.aes_index
+    }
+
+    /// Returns the byte index of the table index.
+    pub fn byte_index(&self) -> ByteIndex {
+        self.byte_index
+    }
+
     /// Shifts the table index forward of `shift` bytes.
     pub fn increase(&mut self, shift: usize) {
         // Compute full shifts to avoid overflows
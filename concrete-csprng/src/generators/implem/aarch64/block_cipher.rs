@@ -14,6 +14,8 @@ const NUM_ROUND_KEYS: usize = NUM_ROUNDS + 1;
 /// An aes block cipher implementation which uses `neon` and `aes` instructions.
 #[derive(Clone)]
 pub struct ArmAesBlockCipher {
+    // The secret key, kept to be able to save the generator state
+    key: AesKey,
     round_keys: [uint8x16_t; NUM_ROUND_KEYS],
 }
 
@@ -31,7 +33,11 @@ impl AesBlockCipher for ArmAesBlockCipher {
         }
 
         let round_keys = unsafe { generate_round_keys(key) };
-        ArmAesBlockCipher { round_keys }
+        ArmAesBlockCipher { key, round_keys }
+    }
+
+    fn key(&self) -> AesKey {
+        self.key
     }
 
     fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
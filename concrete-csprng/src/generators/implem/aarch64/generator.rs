@@ -1,6 +1,9 @@
 use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
 use crate::generators::implem::aarch64::block_cipher::ArmAesBlockCipher;
-use crate::generators::{ByteCount, BytesPerChild, ChildrenCount, ForkError, RandomGenerator};
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+    StateError, TableIndex,
+};
 use crate::seeders::Seed;
 
 /// A random number generator using the `aesni` instructions.
@@ -36,6 +39,20 @@ impl RandomGenerator for NeonAesRandomGenerator {
             .try_fork(n_children, n_bytes)
             .map(ArmAesChildrenIterator)
     }
+    fn state(&self) -> Result<GeneratorState, StateError> {
+        Ok(GeneratorState {
+            seed: Seed(self.0.key().0),
+            next_index: self.0.next_table_index(),
+            bound: self.0.get_bound(),
+        })
+    }
+    fn try_restore(state: GeneratorState) -> Result<Self, StateError> {
+        AesCtrGenerator::try_restore(AesKey(state.seed.0), state.next_index, state.bound)
+            .map(ArmAesRandomGenerator)
+    }
+    fn try_skip_to(&mut self, index: TableIndex) -> Result<(), StateError> {
+        self.0.try_skip_to(index)
+    }
 }
 
 impl Iterator for NeonAesRandomGenerator {
@@ -87,6 +104,16 @@ mod test {
         aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<ArmAesBlockCipher>();
     }
 
+    #[test]
+    fn prop_restore() {
+        aes_ctr_generic_test::prop_restore::<ArmAesBlockCipher>();
+    }
+
+    #[test]
+    fn prop_skip_to() {
+        aes_ctr_generic_test::prop_skip_to::<ArmAesBlockCipher>();
+    }
+
     #[test]
     fn test_roughly_uniform() {
         generator_generic_test::test_roughly_uniform::<NeonAesRandomGenerator>();
@@ -102,6 +129,16 @@ mod test {
         generator_generic_test::test_fork_children::<NeonAesRandomGenerator>();
     }
 
+    #[test]
+    fn test_state_restore() {
+        generator_generic_test::test_state_restore::<NeonAesRandomGenerator>();
+    }
+
+    #[test]
+    fn test_skip_to() {
+        generator_generic_test::test_skip_to::<NeonAesRandomGenerator>();
+    }
+
     #[test]
     #[should_panic(expected = "expected test panic")]
     fn test_bounded_panic() {
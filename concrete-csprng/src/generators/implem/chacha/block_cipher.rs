@@ -0,0 +1,180 @@
+use crate::generators::aes_ctr::{
+    AesBlockCipher, AesIndex, AesKey, BYTES_PER_AES_CALL, BYTES_PER_BATCH,
+};
+
+/// The number of rounds of the ChaCha block function.
+const CHACHA_ROUNDS: usize = 20;
+/// The number of 32 bits words in a ChaCha state.
+const WORDS_PER_CHACHA_BLOCK: usize = 16;
+/// The number of bytes outputted by a call to the ChaCha block function.
+const BYTES_PER_CHACHA_BLOCK: usize = WORDS_PER_CHACHA_BLOCK * 4;
+/// The number of 16 bytes chunks (i.e. aes indices) contained in a ChaCha block.
+const AES_CALLS_PER_CHACHA_BLOCK: u128 = (BYTES_PER_CHACHA_BLOCK / BYTES_PER_AES_CALL) as u128;
+/// The mask keeping the ChaCha block counter in the range covered by the aes indices.
+const CHACHA_COUNTER_MASK: u128 = u128::MAX >> 2;
+/// The "expand 16-byte k" constant used by ChaCha with 128 bits keys.
+const TAU: [u32; 4] = [0x61707865, 0x3120646e, 0x79622d36, 0x6b206574];
+
+/// A ChaCha20 implementation, exposed as a block cipher over 16 bytes chunks of the keystream.
+#[derive(Clone)]
+pub struct ChaChaBlockCipher {
+    // The secret key, kept to be able to save the generator state
+    key: AesKey,
+    // The constant and key words of the ChaCha state.
+    key_words: [u32; 12],
+}
+
+impl AesBlockCipher for ChaChaBlockCipher {
+    fn new(key: AesKey) -> ChaChaBlockCipher {
+        let key_bytes = key.0.to_le_bytes();
+        let mut key_words = [0u32; 12];
+        key_words[..4].copy_from_slice(&TAU);
+        for (i, chunk) in key_bytes.chunks_exact(4).enumerate() {
+            let word = u32::from_le_bytes(chunk.try_into().unwrap());
+            // The 128 bits key is used twice, as specified for the 16 bytes keys variant.
+            key_words[4 + i] = word;
+            key_words[8 + i] = word;
+        }
+        ChaChaBlockCipher { key, key_words }
+    }
+
+    fn key(&self) -> AesKey {
+        self.key
+    }
+
+    fn generate_batch(&mut self, AesIndex(aes_ctr): AesIndex) -> [u8; BYTES_PER_BATCH] {
+        // The aes index points to a 16 bytes chunk of the keystream, which may be located in
+        // the middle of a ChaCha block. Only the blocks overlapping the batch are computed, i.e.
+        // a third block is only needed when the batch does not start on a block boundary.
+        let mut counter = aes_ctr / AES_CALLS_PER_CHACHA_BLOCK;
+        let mut offset = (aes_ctr % AES_CALLS_PER_CHACHA_BLOCK) as usize * BYTES_PER_AES_CALL;
+
+        let mut output = [0u8; BYTES_PER_BATCH];
+        let mut written = 0;
+        while written < BYTES_PER_BATCH {
+            let block = chacha_block(&self.state(counter & CHACHA_COUNTER_MASK));
+            let len = (BYTES_PER_CHACHA_BLOCK - offset).min(BYTES_PER_BATCH - written);
+            output[written..written + len].copy_from_slice(&block[offset..offset + len]);
+            written += len;
+            offset = 0;
+            counter = counter.wrapping_add(1);
+        }
+        output
+    }
+}
+
+impl ChaChaBlockCipher {
+    // Builds the input state of the block function for a given block counter. The four last
+    // words, usually split between counter and nonce, are used as a single 128 bits counter.
+    fn state(&self, counter: u128) -> [u32; WORDS_PER_CHACHA_BLOCK] {
+        let mut state = [0u32; WORDS_PER_CHACHA_BLOCK];
+        state[..12].copy_from_slice(&self.key_words);
+        state[12] = counter as u32;
+        state[13] = (counter >> 32) as u32;
+        state[14] = (counter >> 64) as u32;
+        state[15] = (counter >> 96) as u32;
+        state
+    }
+}
+
+#[inline(always)]
+fn quarter_round(x: &mut [u32; WORDS_PER_CHACHA_BLOCK], a: usize, b: usize, c: usize, d: usize) {
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(16);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(12);
+    x[a] = x[a].wrapping_add(x[b]);
+    x[d] = (x[d] ^ x[a]).rotate_left(8);
+    x[c] = x[c].wrapping_add(x[d]);
+    x[b] = (x[b] ^ x[c]).rotate_left(7);
+}
+
+// The ChaCha block function, as described in section 2.3 of RFC 8439.
+fn chacha_block(input: &[u32; WORDS_PER_CHACHA_BLOCK]) -> [u8; BYTES_PER_CHACHA_BLOCK] {
+    let mut x = *input;
+    for _ in 0..CHACHA_ROUNDS / 2 {
+        // Column rounds
+        quarter_round(&mut x, 0, 4, 8, 12);
+        quarter_round(&mut x, 1, 5, 9, 13);
+        quarter_round(&mut x, 2, 6, 10, 14);
+        quarter_round(&mut x, 3, 7, 11, 15);
+        // Diagonal rounds
+        quarter_round(&mut x, 0, 5, 10, 15);
+        quarter_round(&mut x, 1, 6, 11, 12);
+        quarter_round(&mut x, 2, 7, 8, 13);
+        quarter_round(&mut x, 3, 4, 9, 14);
+    }
+
+    let mut output = [0u8; BYTES_PER_CHACHA_BLOCK];
+    output
+        .chunks_exact_mut(4)
+        .zip(x.iter().zip(input.iter()))
+        .for_each(|(out, (x, i))| out.copy_from_slice(&x.wrapping_add(*i).to_le_bytes()));
+    output
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::{thread_rng, Rng};
+
+    const REPEATS: usize = 10_000;
+
+    // Test vector for the ChaCha20 block function, from section 2.3.2 of RFC 8439
+    const INPUT_STATE: [u32; WORDS_PER_CHACHA_BLOCK] = [
+        0x61707865, 0x3320646e, 0x79622d32, 0x6b206574, 0x03020100, 0x07060504, 0x0b0a0908,
+        0x0f0e0d0c, 0x13121110, 0x17161514, 0x1b1a1918, 0x1f1e1d1c, 0x00000001, 0x09000000,
+        0x4a000000, 0x00000000,
+    ];
+    const OUTPUT_BLOCK: [u8; BYTES_PER_CHACHA_BLOCK] = [
+        0x10, 0xf1, 0xe7, 0xe4, 0xd1, 0x3b, 0x59, 0x15, 0x50, 0x0f, 0xdd, 0x1f, 0xa3, 0x20, 0x71,
+        0xc4, 0xc7, 0xd1, 0xf4, 0xc7, 0x33, 0xc0, 0x68, 0x03, 0x04, 0x22, 0xaa, 0x9a, 0xc3, 0xd4,
+        0x6c, 0x4e, 0xd2, 0x82, 0x64, 0x46, 0x07, 0x9f, 0xaa, 0x09, 0x14, 0xc2, 0xd7, 0x05, 0xd9,
+        0x8b, 0x02, 0xa2, 0xb5, 0x12, 0x9c, 0xd1, 0xde, 0x16, 0x4e, 0xb9, 0xcb, 0xd0, 0x83, 0xe8,
+        0xa2, 0x50, 0x3c, 0x4e,
+    ];
+
+    #[test]
+    fn test_chacha_block() {
+        assert_eq!(chacha_block(&INPUT_STATE), OUTPUT_BLOCK);
+    }
+
+    #[test]
+    /// Check the property:
+    ///     For all keys k, aes indices a,
+    ///         the batch at a is the keystream starting at the aes call a.
+    fn prop_generate_batch_keystream() {
+        for _ in 0..REPEATS {
+            let mut cipher = ChaChaBlockCipher::new(AesKey(thread_rng().gen()));
+            let aes_ctr = thread_rng().gen::<u128>() >> 1;
+            let batch = cipher.generate_batch(AesIndex(aes_ctr));
+
+            let first_block = aes_ctr / AES_CALLS_PER_CHACHA_BLOCK;
+            let offset = (aes_ctr % AES_CALLS_PER_CHACHA_BLOCK) as usize * BYTES_PER_AES_CALL;
+            let keystream: Vec<u8> = (0..3)
+                .flat_map(|i| {
+                    let counter = (first_block + i) & CHACHA_COUNTER_MASK;
+                    chacha_block(&cipher.state(counter))
+                })
+                .collect();
+            assert_eq!(batch[..], keystream[offset..offset + BYTES_PER_BATCH]);
+        }
+    }
+
+    #[test]
+    /// Check the property:
+    ///     For all keys k, aes indices a,
+    ///         the batch at a+1 is the batch at a shifted by one aes call.
+    fn prop_generate_batch_shift() {
+        for _ in 0..REPEATS {
+            let mut cipher = ChaChaBlockCipher::new(AesKey(thread_rng().gen()));
+            let aes_ctr = thread_rng().gen::<u128>() >> 1;
+            let batch = cipher.generate_batch(AesIndex(aes_ctr));
+            let shifted_batch = cipher.generate_batch(AesIndex(aes_ctr + 1));
+            assert_eq!(
+                batch[BYTES_PER_AES_CALL..],
+                shifted_batch[..BYTES_PER_BATCH - BYTES_PER_AES_CALL]
+            );
+        }
+    }
+}
@@ -0,0 +1,147 @@
+use crate::generators::aes_ctr::{AesCtrGenerator, AesKey, ChildrenIterator};
+use crate::generators::implem::chacha::block_cipher::ChaChaBlockCipher;
+use crate::generators::{
+    ByteCount, BytesPerChild, ChildrenCount, ForkError, GeneratorState, RandomGenerator,
+    StateError, TableIndex,
+};
+use crate::seeders::Seed;
+
+/// A random number generator using the ChaCha20 stream cipher.
+pub struct ChaChaRandomGenerator(pub(super) AesCtrGenerator<ChaChaBlockCipher>);
+
+/// The children iterator used by [`ChaChaRandomGenerator`].
+///
+/// Outputs children generators one by one.
+pub struct ChaChaChildrenIterator(ChildrenIterator<ChaChaBlockCipher>);
+
+impl Iterator for ChaChaChildrenIterator {
+    type Item = ChaChaRandomGenerator;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next().map(ChaChaRandomGenerator)
+    }
+}
+
+impl RandomGenerator for ChaChaRandomGenerator {
+    type ChildrenIter = ChaChaChildrenIterator;
+    fn new(seed: Seed) -> Self {
+        ChaChaRandomGenerator(AesCtrGenerator::new(AesKey(seed.0), None, None))
+    }
+    fn remaining_bytes(&self) -> ByteCount {
+        self.0.remaining_bytes()
+    }
+    fn try_fork(
+        &mut self,
+        n_children: ChildrenCount,
+        n_bytes: BytesPerChild,
+    ) -> Result<Self::ChildrenIter, ForkError> {
+        self.0
+            .try_fork(n_children, n_bytes)
+            .map(ChaChaChildrenIterator)
+    }
+    fn state(&self) -> Result<GeneratorState, StateError> {
+        Ok(GeneratorState {
+            seed: Seed(self.0.key().0),
+            next_index: self.0.next_table_index(),
+            bound: self.0.get_bound(),
+        })
+    }
+    fn try_restore(state: GeneratorState) -> Result<Self, StateError> {
+        AesCtrGenerator::try_restore(AesKey(state.seed.0), state.next_index, state.bound)
+            .map(ChaChaRandomGenerator)
+    }
+    fn try_skip_to(&mut self, index: TableIndex) -> Result<(), StateError> {
+        self.0.try_skip_to(index)
+    }
+}
+
+impl Iterator for ChaChaRandomGenerator {
+    type Item = u8;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.0.next()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::generators::aes_ctr::aes_ctr_generic_test;
+    use crate::generators::generator_generic_test;
+
+    #[test]
+    fn prop_fork_first_state_table_index() {
+        aes_ctr_generic_test::prop_fork_first_state_table_index::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_last_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_last_bound_table_index::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_bound_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_bound_table_index::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_state_table_index() {
+        aes_ctr_generic_test::prop_fork_parent_state_table_index::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork() {
+        aes_ctr_generic_test::prop_fork::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_children_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_children_remaining_bytes::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_fork_parent_remaining_bytes() {
+        aes_ctr_generic_test::prop_fork_parent_remaining_bytes::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_restore() {
+        aes_ctr_generic_test::prop_restore::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn prop_skip_to() {
+        aes_ctr_generic_test::prop_skip_to::<ChaChaBlockCipher>();
+    }
+
+    #[test]
+    fn test_roughly_uniform() {
+        generator_generic_test::test_roughly_uniform::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_fork() {
+        generator_generic_test::test_fork_children::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_generator_determinism() {
+        generator_generic_test::test_generator_determinism::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_state_restore() {
+        generator_generic_test::test_state_restore::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    fn test_skip_to() {
+        generator_generic_test::test_skip_to::<ChaChaRandomGenerator>();
+    }
+
+    #[test]
+    #[should_panic(expected = "expected test panic")]
+    fn test_bounded_panic() {
+        generator_generic_test::test_bounded_none_should_panic::<ChaChaRandomGenerator>();
+    }
+}
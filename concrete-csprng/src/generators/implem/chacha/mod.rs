@@ -0,0 +1,22 @@
+//! A module implementing a random number generator, using the ChaCha20 stream cipher.
+//!
+//! This module implements a cryptographically secure pseudorandom number generator
+//! (CS-PRNG), using a portable implementation of the ChaCha20 block function described in
+//! [RFC 8439](https://datatracker.ietf.org/doc/html/rfc8439). Contrary to the software AES
+//! fallback, ChaCha20 only relies on additions, rotations and xors on 32 bits words, which makes it
+//! both fast and constant-time on platforms without dedicated AES instructions.
+//!
+//! The ChaCha20 keystream is split in 16 bytes chunks indexed by an
+//! [`AesIndex`](crate::generators::aes_ctr::AesIndex), so that the generic counter mode
+//! generator of the `aes_ctr` module can be reused as is. The generators of this module hence
+//! share the exact same `try_fork`/`par_try_fork` table index semantics as the aes based ones.
+
+mod block_cipher;
+
+mod generator;
+pub use generator::*;
+
+#[cfg(feature = "parallel")]
+mod parallel;
+#[cfg(feature = "parallel")]
+pub use parallel::*;
@@ -12,3 +12,8 @@ pub use aarch64::*;
 mod soft;
 #[cfg(feature = "generator_fallback")]
 pub use soft::*;
+
+#[cfg(feature = "generator_chacha")]
+mod chacha;
+#[cfg(feature = "generator_chacha")]
+pub use chacha::*;
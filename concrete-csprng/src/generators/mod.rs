@@ -51,6 +51,67 @@ impl Display for ForkError {
 }
 impl Error for ForkError {}
 
+/// A snapshot of the state of a generator, from which the generator can be restored.
+///
+/// Note:
+/// -----
+///
+/// The state contains the seed of the generator. A generator restored from a state outputs the
+/// exact same bytes as the original one. The state of a generator used to sample secret values
+/// must then be stored with the same care as the secret values themselves.
+///
+/// A state must be restored at most once, and the original generator must not be used anymore
+/// once its state was restored, otherwise the same bytes are outputted twice. For this reason the
+/// state is neither `Copy` nor `Clone`, and is consumed when restored.
+#[derive(Debug, PartialEq, Eq)]
+pub struct GeneratorState {
+    /// The seed the generator was created from.
+    pub seed: Seed,
+    /// The table index of the next byte to be outputted by the generator.
+    pub next_index: TableIndex,
+    /// The table index of the first byte that can __not__ be outputted by the generator.
+    pub bound: TableIndex,
+}
+
+/// An error occurring when restoring or moving the state of a generator.
+#[derive(Debug)]
+pub enum StateError {
+    FirstTableIndex,
+    IndexAfterBound,
+    SkipBackward,
+    Unsupported,
+}
+
+impl Display for StateError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            StateError::FirstTableIndex => {
+                write!(
+                    f,
+                    "The next index of the generator can not be the first table index."
+                )
+            }
+            StateError::IndexAfterBound => {
+                write!(f, "The next index of the generator is after its bound.")
+            }
+            StateError::SkipBackward => {
+                write!(
+                    f,
+                    "The generator can not be moved backward, as it would output the same bytes \
+                    twice."
+                )
+            }
+            StateError::Unsupported => {
+                write!(
+                    f,
+                    "The generator does not support saving, restoring or moving its state."
+                )
+            }
+        }
+    }
+}
+impl Error for StateError {}
+
 /// A trait for cryptographically secure pseudo-random generators.
 ///
 /// See the [crate-level](#crate) documentation for details.
@@ -95,6 +156,46 @@ pub trait RandomGenerator: Iterator<Item = u8> {
         n_children: ChildrenCount,
         n_bytes: BytesPerChild,
     ) -> Result<Self::ChildrenIter, ForkError>;
+
+    /// Tries to return a snapshot of the state of the generator.
+    ///
+    /// See [`GeneratorState`] for the precautions to take when storing the state.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The default implementation returns [`StateError::Unsupported`].
+    fn state(&self) -> Result<GeneratorState, StateError> {
+        Err(StateError::Unsupported)
+    }
+
+    /// Tries to restore a generator from a snapshot of its state.
+    ///
+    /// The restored generator outputs the bytes the original generator would have outputted after
+    /// the snapshot was taken. The state is consumed, and must not be restored a second time.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// The default implementation returns [`StateError::Unsupported`].
+    fn try_restore(_state: GeneratorState) -> Result<Self, StateError>
+    where
+        Self: Sized,
+    {
+        Err(StateError::Unsupported)
+    }
+
+    /// Tries to move the generator forward, so that the next outputted byte is the one at `index`.
+    ///
+    /// Note:
+    /// -----
+    ///
+    /// To be successful, `index` must be between the next index of the generator and its bound.
+    /// Moving backward is not allowed, as it would reuse randomness. The default implementation
+    /// returns [`StateError::Unsupported`].
+    fn try_skip_to(&mut self, _index: TableIndex) -> Result<(), StateError> {
+        Err(StateError::Unsupported)
+    }
 }
 
 /// A trait extending [`RandomGenerator`] to the parallel iterators of `rayon`.
@@ -119,6 +220,7 @@ pub trait ParallelRandomGenerator: RandomGenerator + Send {
 }
 
 mod aes_ctr;
+pub use aes_ctr::{AesIndex, ByteIndex, TableIndex};
 
 mod implem;
 pub use implem::*;
@@ -212,6 +314,69 @@ pub mod generator_generic_test {
         }
     }
 
+    /// Checks that a generator restored from a snapshot of its state outputs the same bytes as the
+    /// original generator, including when the generator is bounded.
+    pub fn test_state_restore<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let ((seed, n_children), n_bytes) = any_seed()
+                .zip(some_children_count())
+                .zip(some_bytes_per_child())
+                .next()
+                .unwrap();
+            let mut gen = G::new(seed);
+            let mut bounded = gen.try_fork(n_children, n_bytes).unwrap().next().unwrap();
+            let n_consumed = rand::thread_rng().gen::<usize>() % (n_bytes.0 + 1);
+            for _ in 0..n_consumed {
+                bounded.next().unwrap();
+            }
+            let state = bounded.state().unwrap();
+            assert_eq!(state.seed, seed);
+            let mut restored = G::try_restore(state).unwrap();
+            assert_eq!(restored.state().unwrap(), bounded.state().unwrap());
+            assert_eq!(restored.remaining_bytes(), bounded.remaining_bytes());
+            for _ in n_consumed..n_bytes.0 {
+                assert_eq!(restored.next(), bounded.next());
+            }
+            assert!(restored.next().is_none());
+        }
+    }
+
+    /// Checks that skipping a generator to an index outputs the same bytes as consuming the
+    /// generator up to this index, and that skipping backward or after the bound fails.
+    pub fn test_skip_to<G: RandomGenerator>() {
+        for _ in 0..REPEATS {
+            let seed = any_seed().next().unwrap();
+            let mut gen = G::new(seed);
+            let mut skipped = G::new(seed);
+            let n_skipped = rand::thread_rng().gen::<usize>() % 4096;
+            let start = gen.state().unwrap().next_index;
+            for _ in 0..n_skipped {
+                gen.next().unwrap();
+            }
+            skipped.try_skip_to(start.increased(n_skipped)).unwrap();
+            assert_eq!(skipped.state().unwrap(), gen.state().unwrap());
+            for _ in 0..1024 {
+                assert_eq!(skipped.next(), gen.next());
+            }
+            assert!(matches!(
+                skipped.try_skip_to(start),
+                Err(StateError::SkipBackward)
+            ));
+            let mut bounded = gen
+                .try_fork(ChildrenCount(1), BytesPerChild(16))
+                .unwrap()
+                .next()
+                .unwrap();
+            let bound = bounded.state().unwrap().bound;
+            assert!(matches!(
+                bounded.try_skip_to(bound.incremented()),
+                Err(StateError::IndexAfterBound)
+            ));
+            bounded.try_skip_to(bound).unwrap();
+            assert!(bounded.next().is_none());
+        }
+    }
+
     /// Checks that a bounded prng returns none when exceeding the allowed number of bytes.
     ///
     /// To properly check for panic use `#[should_panic(expected = "expected test panic")]` as an
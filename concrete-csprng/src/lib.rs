@@ -110,5 +110,9 @@
 //!
 //! The implementation is based on the AES blockcipher used in counter (CTR) mode, as presented
 //! in the ISO/IEC 18033-4 document.
+//!
+//! On platforms without hardware AES support, a generator based on the ChaCha20 stream cipher can
+//! be enabled with the `generator_chacha` feature. It exposes the same pseudo-random table and the
+//! same forking semantics as the AES based generators.
 pub mod generators;
 pub mod seeders;
@@ -0,0 +1,156 @@
+use crate::seeders::{HealthTestError, HealthTestedSeeder, Seed, Seeder};
+use sha3::digest::{ExtendableOutput, Update, XofReader};
+use sha3::Shake256;
+
+/// The domain separator prepended to the input of the seed combination function.
+const SEED_COMBINATION_DOMAIN: &[u8] = b"concrete-csprng-seed-combination-v1";
+
+// An object-safe view of a health tested seeder, to store seeders of different types.
+trait HealthTestedSource {
+    fn try_seed(&mut self) -> Result<Seed, HealthTestError>;
+}
+
+impl<S: Seeder> HealthTestedSource for HealthTestedSeeder<S> {
+    fn try_seed(&mut self) -> Result<Seed, HealthTestError> {
+        HealthTestedSeeder::try_seed(self)
+    }
+}
+
+/// A seeder combining the seeds of several health tested seeders.
+///
+/// Each seed is obtained by hashing, with SHAKE256, one seed of each source. The output is then
+/// unpredictable as long as at least one of the sources is. If any source fails its health tests,
+/// the combined seeder fails closed: `try_seed` returns the error and `seed` panics, rather than
+/// falling back on the remaining sources.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_csprng::seeders::{
+///     CombinedSeeder, DerivedSeeder, HealthTestConfig, HealthTestedSeeder, Seed,
+/// };
+///
+/// let config = HealthTestConfig::default();
+/// let first_source = HealthTestedSeeder::new(DerivedSeeder::new(Seed(0), b"first"), config);
+/// let second_source = HealthTestedSeeder::new(DerivedSeeder::new(Seed(1), b"second"), config);
+///
+/// let mut seeder = CombinedSeeder::new(first_source.unwrap());
+/// seeder.add_source(second_source.unwrap());
+/// assert_eq!(seeder.source_count(), 2);
+/// assert_ne!(seeder.try_seed().unwrap(), seeder.try_seed().unwrap());
+/// ```
+pub struct CombinedSeeder {
+    sources: Vec<Box<dyn HealthTestedSource>>,
+}
+
+impl CombinedSeeder {
+    /// Creates a new combined seeder with a single source.
+    pub fn new<S: Seeder + 'static>(source: HealthTestedSeeder<S>) -> CombinedSeeder {
+        CombinedSeeder {
+            sources: vec![Box::new(source)],
+        }
+    }
+
+    /// Adds a source to the combined seeder.
+    pub fn add_source<S: Seeder + 'static>(&mut self, source: HealthTestedSeeder<S>) {
+        self.sources.push(Box::new(source));
+    }
+
+    /// Returns the number of sources of the combined seeder.
+    pub fn source_count(&self) -> usize {
+        self.sources.len()
+    }
+
+    /// Generates a new seed, if all the sources passed their health tests so far.
+    pub fn try_seed(&mut self) -> Result<Seed, HealthTestError> {
+        let mut hasher = Shake256::default();
+        hasher.update(SEED_COMBINATION_DOMAIN);
+        hasher.update(&(self.sources.len() as u64).to_le_bytes());
+        for source in self.sources.iter_mut() {
+            hasher.update(&source.try_seed()?.0.to_le_bytes());
+        }
+
+        let mut output = [0u8; 16];
+        hasher.finalize_xof().read(&mut output);
+        Ok(Seed(u128::from_le_bytes(output)))
+    }
+}
+
+impl Seeder for CombinedSeeder {
+    fn seed(&mut self) -> Seed {
+        self.try_seed()
+            .unwrap_or_else(|failure| panic!("{failure}"))
+    }
+
+    fn is_available() -> bool {
+        true
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::{DerivedSeeder, HealthTestConfig};
+
+    // A seeder outputting a fixed number of seeds from a derived seeder, then a constant.
+    struct FailingSeeder {
+        seeder: DerivedSeeder,
+        remaining_seeds: usize,
+    }
+
+    impl Seeder for FailingSeeder {
+        fn seed(&mut self) -> Seed {
+            if self.remaining_seeds == 0 {
+                return Seed(0);
+            }
+            self.remaining_seeds -= 1;
+            self.seeder.seed()
+        }
+
+        fn is_available() -> bool {
+            true
+        }
+    }
+
+    fn derived_source(master_seed: u128) -> HealthTestedSeeder<DerivedSeeder> {
+        let seeder = DerivedSeeder::new(Seed(master_seed), b"combined");
+        HealthTestedSeeder::new(seeder, HealthTestConfig::default()).unwrap()
+    }
+
+    #[test]
+    fn check_combination_depends_on_all_sources() {
+        let mut reference = CombinedSeeder::new(derived_source(0));
+        reference.add_source(derived_source(1));
+        let mut same = CombinedSeeder::new(derived_source(0));
+        same.add_source(derived_source(1));
+        let mut other = CombinedSeeder::new(derived_source(0));
+        other.add_source(derived_source(2));
+        let mut single = CombinedSeeder::new(derived_source(0));
+
+        for _ in 0..100 {
+            let seed = reference.try_seed().unwrap();
+            assert_eq!(seed, same.try_seed().unwrap());
+            assert_ne!(seed, other.try_seed().unwrap());
+            assert_ne!(seed, single.try_seed().unwrap());
+        }
+    }
+
+    #[test]
+    fn check_combined_seeder_fails_closed() {
+        let failing_source = FailingSeeder {
+            seeder: DerivedSeeder::new(Seed(1), b"failing"),
+            remaining_seeds: 164,
+        };
+        let failing_source =
+            HealthTestedSeeder::new(failing_source, HealthTestConfig::default()).unwrap();
+        let mut seeder = CombinedSeeder::new(derived_source(0));
+        seeder.add_source(failing_source);
+
+        for _ in 0..100 {
+            seeder.try_seed().unwrap();
+        }
+        for _ in 0..10 {
+            assert_eq!(seeder.try_seed(), Err(HealthTestError::RepetitionCount));
+        }
+    }
+}
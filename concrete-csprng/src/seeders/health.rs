@@ -0,0 +1,394 @@
+use crate::seeders::{Seed, Seeder};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+/// The size of the window of the adaptive proportion test, for non-binary samples.
+const ADAPTIVE_PROPORTION_WINDOW_SIZE: usize = 512;
+
+/// The number of samples that go through the health tests when a [`HealthTestedSeeder`] is
+/// created, before any seed is outputted.
+const STARTUP_SAMPLE_COUNT: usize = 1024;
+
+/// The number of bytes (samples) in a seed.
+const SEED_BYTES: usize = std::mem::size_of::<u128>();
+
+/// An error occurring when a seeder fails a continuous health test.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum HealthTestError {
+    RepetitionCount,
+    AdaptiveProportion,
+}
+
+impl Display for HealthTestError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            HealthTestError::RepetitionCount => {
+                write!(
+                    f,
+                    "The seeder failed the repetition count health test, the same byte was \
+                    outputted too many times in a row."
+                )
+            }
+            HealthTestError::AdaptiveProportion => {
+                write!(
+                    f,
+                    "The seeder failed the adaptive proportion health test, a byte value was \
+                    outputted too many times in a window."
+                )
+            }
+        }
+    }
+}
+impl Error for HealthTestError {}
+
+/// The parameters of the continuous health tests of a [`HealthTestedSeeder`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct HealthTestConfig {
+    /// The min-entropy claimed for each byte outputted by the seeder, in bits. Must be in
+    /// `]0, 8]`.
+    pub min_entropy_per_byte: f64,
+    /// The base 2 logarithm of the probability of a false positive for each test, i.e. the
+    /// probability for a healthy source to fail a test. NIST SP 800-90B recommends values
+    /// between `-40` and `-20`.
+    pub false_positive_log2: i32,
+}
+
+impl Default for HealthTestConfig {
+    /// A conservative configuration, claiming 4 bits of min-entropy per byte with a false
+    /// positive probability of $2^{-30}$.
+    fn default() -> Self {
+        HealthTestConfig {
+            min_entropy_per_byte: 4.,
+            false_positive_log2: -30,
+        }
+    }
+}
+
+impl HealthTestConfig {
+    /// Returns the number of identical consecutive bytes which triggers a failure of the
+    /// repetition count test, i.e. $1 + \lceil \frac{-\log_2(\alpha)}{H} \rceil$.
+    pub fn repetition_count_cutoff(&self) -> usize {
+        self.check();
+        1 + (-self.false_positive_log2 as f64 / self.min_entropy_per_byte).ceil() as usize
+    }
+
+    /// Returns the number of occurrences of a byte value in a window of 512 bytes which triggers a
+    /// failure of the adaptive proportion test.
+    ///
+    /// This is one more than the smallest number of occurrences $k$ such that a binomial random
+    /// variable of parameters $(512, 2^{-H})$ exceeds $k$ with probability at most $\alpha$.
+    pub fn adaptive_proportion_cutoff(&self) -> usize {
+        self.check();
+        let n = ADAPTIVE_PROPORTION_WINDOW_SIZE;
+        let p = 2f64.powf(-self.min_entropy_per_byte);
+        let alpha = 2f64.powi(self.false_positive_log2);
+
+        // The log of the probabilities P(X = k), the binomial coefficient being computed
+        // incrementally.
+        let mut log_binomial = 0f64;
+        let log_probabilities: Vec<f64> = (0..=n)
+            .map(|k| {
+                if k > 0 {
+                    log_binomial += ((n - k + 1) as f64).ln() - (k as f64).ln();
+                }
+                log_binomial + k as f64 * p.ln() + (n - k) as f64 * (-p).ln_1p()
+            })
+            .collect();
+
+        // The upper tail is accumulated from the top, to keep precision for small alpha.
+        let mut tail = 0f64;
+        for k in (0..n).rev() {
+            tail += log_probabilities[k + 1].exp();
+            if tail > alpha {
+                return k + 2;
+            }
+        }
+        1
+    }
+
+    fn check(&self) {
+        assert!(
+            self.min_entropy_per_byte > 0. && self.min_entropy_per_byte <= 8.,
+            "The min-entropy per byte must be in ]0, 8], got {}.",
+            self.min_entropy_per_byte
+        );
+        assert!(
+            self.false_positive_log2 < 0,
+            "The false positive probability must be smaller than 1, got 2^{}.",
+            self.false_positive_log2
+        );
+    }
+}
+
+/// A seeder running the continuous health tests of NIST SP 800-90B on the outputs of another
+/// seeder.
+///
+/// Each byte of the seeds outputted by the wrapped seeder is considered as a sample, and goes
+/// through:
+/// - the repetition count test, detecting a source stuck on a single value;
+/// - the adaptive proportion test, detecting a source outputting a value much more often than its
+///   claimed min-entropy allows.
+///
+/// The tests are run on 1024 samples when the seeder is created, before any seed is outputted.
+/// Once a test failed, the seeder fails closed: no seed is outputted anymore, `try_seed` returns
+/// the error and `seed` panics. A new seeder has to be created to recover from a failure.
+///
+/// # Example
+///
+/// ```rust
+/// use concrete_csprng::seeders::{
+///     DerivedSeeder, HealthTestConfig, HealthTestError, HealthTestedSeeder, Seed, Seeder,
+/// };
+///
+/// let source = DerivedSeeder::new(Seed(0), b"example");
+/// let mut seeder = HealthTestedSeeder::new(source, HealthTestConfig::default()).unwrap();
+/// assert_ne!(seeder.try_seed().unwrap(), seeder.try_seed().unwrap());
+///
+/// // A source stuck on a single value fails the startup tests.
+/// struct StuckSeeder;
+/// impl Seeder for StuckSeeder {
+///     fn seed(&mut self) -> Seed {
+///         Seed(0)
+///     }
+///     fn is_available() -> bool {
+///         true
+///     }
+/// }
+/// let result = HealthTestedSeeder::new(StuckSeeder, HealthTestConfig::default());
+/// assert_eq!(result.err(), Some(HealthTestError::RepetitionCount));
+/// ```
+pub struct HealthTestedSeeder<S: Seeder> {
+    seeder: S,
+    repetition_count: RepetitionCountTest,
+    adaptive_proportion: AdaptiveProportionTest,
+    failure: Option<HealthTestError>,
+}
+
+impl<S: Seeder> HealthTestedSeeder<S> {
+    /// Creates a new health tested seeder wrapping `seeder`, and runs the startup tests.
+    pub fn new(seeder: S, config: HealthTestConfig) -> Result<Self, HealthTestError> {
+        let mut health_tested_seeder = HealthTestedSeeder {
+            seeder,
+            repetition_count: RepetitionCountTest::new(config.repetition_count_cutoff()),
+            adaptive_proportion: AdaptiveProportionTest::new(config.adaptive_proportion_cutoff()),
+            failure: None,
+        };
+        for _ in 0..STARTUP_SAMPLE_COUNT / SEED_BYTES {
+            health_tested_seeder.try_seed()?;
+        }
+        Ok(health_tested_seeder)
+    }
+
+    /// Generates a new seed, if the seeder passed all the health tests so far.
+    pub fn try_seed(&mut self) -> Result<Seed, HealthTestError> {
+        if let Some(failure) = self.failure {
+            return Err(failure);
+        }
+        let seed = self.seeder.seed();
+        for byte in seed.0.to_le_bytes() {
+            let result = self
+                .repetition_count
+                .test(byte)
+                .and_then(|_| self.adaptive_proportion.test(byte));
+            if let Err(failure) = result {
+                self.failure = Some(failure);
+                return Err(failure);
+            }
+        }
+        Ok(seed)
+    }
+
+    /// Returns the health test failure of the seeder, if any.
+    pub fn failure(&self) -> Option<HealthTestError> {
+        self.failure
+    }
+}
+
+impl<S: Seeder> Seeder for HealthTestedSeeder<S> {
+    fn seed(&mut self) -> Seed {
+        self.try_seed()
+            .unwrap_or_else(|failure| panic!("{failure}"))
+    }
+
+    fn is_available() -> bool {
+        S::is_available()
+    }
+}
+
+// The repetition count test of NIST SP 800-90B, section 4.4.1.
+struct RepetitionCountTest {
+    cutoff: usize,
+    last_sample: Option<u8>,
+    count: usize,
+}
+
+impl RepetitionCountTest {
+    fn new(cutoff: usize) -> Self {
+        RepetitionCountTest {
+            cutoff,
+            last_sample: None,
+            count: 0,
+        }
+    }
+
+    fn test(&mut self, sample: u8) -> Result<(), HealthTestError> {
+        if self.last_sample == Some(sample) {
+            self.count += 1;
+            if self.count >= self.cutoff {
+                return Err(HealthTestError::RepetitionCount);
+            }
+        } else {
+            self.last_sample = Some(sample);
+            self.count = 1;
+        }
+        Ok(())
+    }
+}
+
+// The adaptive proportion test of NIST SP 800-90B, section 4.4.2.
+struct AdaptiveProportionTest {
+    cutoff: usize,
+    reference_sample: u8,
+    count: usize,
+    window_position: usize,
+}
+
+impl AdaptiveProportionTest {
+    fn new(cutoff: usize) -> Self {
+        AdaptiveProportionTest {
+            cutoff,
+            reference_sample: 0,
+            count: 0,
+            window_position: 0,
+        }
+    }
+
+    fn test(&mut self, sample: u8) -> Result<(), HealthTestError> {
+        if self.window_position == 0 {
+            self.reference_sample = sample;
+            self.count = 1;
+        } else if sample == self.reference_sample {
+            self.count += 1;
+            if self.count >= self.cutoff {
+                return Err(HealthTestError::AdaptiveProportion);
+            }
+        }
+        self.window_position = (self.window_position + 1) % ADAPTIVE_PROPORTION_WINDOW_SIZE;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::seeders::DerivedSeeder;
+
+    // A seeder outputting the seeds of a derived seeder, until it gets stuck on a single value.
+    struct StuckAfterSeeder {
+        seeder: DerivedSeeder,
+        remaining_seeds: usize,
+    }
+
+    impl Seeder for StuckAfterSeeder {
+        fn seed(&mut self) -> Seed {
+            if self.remaining_seeds == 0 {
+                return Seed(u128::MAX);
+            }
+            self.remaining_seeds -= 1;
+            self.seeder.seed()
+        }
+
+        fn is_available() -> bool {
+            true
+        }
+    }
+
+    // A seeder outputting a zero byte every other byte, which never repeats a byte but has about
+    // 4 bits of min-entropy per byte.
+    struct BiasedSeeder(DerivedSeeder);
+
+    impl Seeder for BiasedSeeder {
+        fn seed(&mut self) -> Seed {
+            let mask = u128::from_le_bytes([0, 0xff].repeat(8).try_into().unwrap());
+            let odd_bytes = u128::from_le_bytes([0, 1].repeat(8).try_into().unwrap());
+            Seed((self.0.seed().0 & mask) | odd_bytes)
+        }
+
+        fn is_available() -> bool {
+            true
+        }
+    }
+
+    #[test]
+    fn check_cutoffs() {
+        // The values given in NIST SP 800-90B for a false positive probability of 2^-20.
+        for (min_entropy_per_byte, repetition_count_cutoff, adaptive_proportion_cutoff) in [
+            (0.5, 41, 410),
+            (1., 21, 311),
+            (2., 11, 177),
+            (4., 6, 62),
+            (8., 4, 13),
+        ] {
+            let config = HealthTestConfig {
+                min_entropy_per_byte,
+                false_positive_log2: -20,
+            };
+            assert_eq!(config.repetition_count_cutoff(), repetition_count_cutoff);
+            assert_eq!(
+                config.adaptive_proportion_cutoff(),
+                adaptive_proportion_cutoff
+            );
+        }
+
+        let config = HealthTestConfig::default();
+        assert_eq!(config.repetition_count_cutoff(), 9);
+        assert_eq!(config.adaptive_proportion_cutoff(), 71);
+    }
+
+    #[test]
+    fn check_healthy_seeder_passes() {
+        let source = DerivedSeeder::new(Seed(0), b"healthy");
+        let mut seeder = HealthTestedSeeder::new(source, HealthTestConfig::default()).unwrap();
+        for _ in 0..10_000 {
+            seeder.try_seed().unwrap();
+        }
+        assert_eq!(seeder.failure(), None);
+    }
+
+    #[test]
+    fn check_stuck_seeder_fails_closed() {
+        let source = StuckAfterSeeder {
+            seeder: DerivedSeeder::new(Seed(0), b"stuck"),
+            remaining_seeds: 100,
+        };
+        let mut seeder = HealthTestedSeeder::new(source, HealthTestConfig::default()).unwrap();
+        let mut outputted_seeds = 0;
+        while seeder.try_seed().is_ok() {
+            outputted_seeds += 1;
+        }
+        // The stuck seed is never outputted
+        assert_eq!(outputted_seeds, 100 - STARTUP_SAMPLE_COUNT / SEED_BYTES);
+        assert_eq!(seeder.failure(), Some(HealthTestError::RepetitionCount));
+        // The seeder stays failed, even if the source recovered
+        seeder.seeder.remaining_seeds = 100;
+        assert_eq!(seeder.try_seed(), Err(HealthTestError::RepetitionCount));
+    }
+
+    #[test]
+    fn check_biased_seeder_fails() {
+        let source = BiasedSeeder(DerivedSeeder::new(Seed(0), b"biased"));
+        let result = HealthTestedSeeder::new(source, HealthTestConfig::default());
+        assert_eq!(result.err(), Some(HealthTestError::AdaptiveProportion));
+    }
+
+    #[test]
+    #[should_panic]
+    fn check_failed_seeder_panics() {
+        let source = StuckAfterSeeder {
+            seeder: DerivedSeeder::new(Seed(0), b"stuck"),
+            remaining_seeds: STARTUP_SAMPLE_COUNT / SEED_BYTES,
+        };
+        let mut seeder = HealthTestedSeeder::new(source, HealthTestConfig::default()).unwrap();
+        seeder.seed();
+    }
+}
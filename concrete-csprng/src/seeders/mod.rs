@@ -4,6 +4,15 @@
 //! AES blockcipher. As a consequence, the quality of the outputs of the generator is directly
 //! conditioned by the quality of this seed. This module proposes different mechanisms to deliver
 //! seeds that can accommodate varying scenarios.
+//!
+//! Seeds can also be derived from a master seed with [`Seed::derive`], or with a [`DerivedSeeder`]
+//! wherever a [`Seeder`] is expected, to make a whole set of objects reproducible from a single
+//! seed.
+//!
+//! The outputs of any [`Seeder`] can be checked with the continuous health tests of NIST SP
+//! 800-90B by wrapping it in a [`HealthTestedSeeder`]. Several health tested seeders can be
+//! combined in a [`CombinedSeeder`], which stops outputting seeds as soon as one of its sources
+//! fails a test.
 
 /// A seed value, used to initialize a generator.
 #[derive(Debug, Copy, Clone, PartialEq, Eq)]
@@ -21,6 +30,15 @@ pub trait Seeder {
         Self: Sized;
 }
 
+mod derivation;
+pub use derivation::*;
+
+mod health;
+pub use health::*;
+
+mod combined;
+pub use combined::*;
+
 mod implem;
 // This import statement can be empty if seeder features are disabled, rustc's behavior changed to
 // warn of empty modules, we know this can happen, so allow it.
@@ -37,8 +37,8 @@ impl<Scalar: UnsignedInteger> From<BooleanParameters> for CryptoParametersRecord
             lwe_dimension: Some(params.lwe_dimension),
             glwe_dimension: Some(params.glwe_dimension),
             polynomial_size: Some(params.polynomial_size),
-            lwe_modular_std_dev: Some(params.lwe_modular_std_dev),
-            glwe_modular_std_dev: Some(params.glwe_modular_std_dev),
+            lwe_modular_std_dev: params.lwe_noise_distribution.gaussian_std_dev(),
+            glwe_modular_std_dev: params.glwe_noise_distribution.gaussian_std_dev(),
             pbs_base_log: Some(params.pbs_base_log),
             pbs_level: Some(params.pbs_level),
             ks_base_log: Some(params.ks_base_log),
@@ -65,8 +65,8 @@ where
             lwe_dimension: Some(params.lwe_dimension()),
             glwe_dimension: Some(params.glwe_dimension()),
             polynomial_size: Some(params.polynomial_size()),
-            lwe_modular_std_dev: Some(params.lwe_modular_std_dev()),
-            glwe_modular_std_dev: Some(params.glwe_modular_std_dev()),
+            lwe_modular_std_dev: params.lwe_noise_distribution().gaussian_std_dev(),
+            glwe_modular_std_dev: params.glwe_noise_distribution().gaussian_std_dev(),
             pbs_base_log: Some(params.pbs_base_log()),
             pbs_level: Some(params.pbs_level()),
             ks_base_log: Some(params.ks_base_log()),
@@ -162,7 +162,7 @@ impl Bootstrapper {
                 &cks.glwe_secret_key,
                 cks.parameters.pbs_base_log,
                 cks.parameters.pbs_level,
-                cks.parameters.glwe_modular_std_dev,
+                cks.parameters.glwe_noise_distribution,
                 CiphertextModulus::new_native(),
                 &mut self.encryption_generator,
             );
@@ -199,7 +199,7 @@ impl Bootstrapper {
             &cks.lwe_secret_key,
             cks.parameters.ks_base_log,
             cks.parameters.ks_level,
-            cks.parameters.lwe_modular_std_dev,
+            cks.parameters.lwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.encryption_generator,
         );
@@ -218,7 +218,7 @@ impl Bootstrapper {
             &cks.glwe_secret_key,
             cks.parameters.pbs_base_log,
             cks.parameters.pbs_level,
-            cks.parameters.glwe_modular_std_dev,
+            cks.parameters.glwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.seeder,
         );
@@ -229,7 +229,7 @@ impl Bootstrapper {
             &cks.glwe_secret_key,
             cks.parameters.pbs_base_log,
             cks.parameters.pbs_level,
-            cks.parameters.glwe_modular_std_dev,
+            cks.parameters.glwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.seeder,
         );
@@ -242,7 +242,7 @@ impl Bootstrapper {
             &cks.lwe_secret_key,
             cks.parameters.ks_base_log,
             cks.parameters.ks_level,
-            cks.parameters.lwe_modular_std_dev,
+            cks.parameters.lwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.seeder,
         );
@@ -116,11 +116,11 @@ impl BooleanEngine {
         let (lwe_sk, encryption_noise) = match client_parameters.encryption_key_choice {
             EncryptionKeyChoice::Big => (
                 client_key.glwe_secret_key.as_lwe_secret_key(),
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution,
             ),
             EncryptionKeyChoice::Small => {
                 let view = LweSecretKey::from_container(client_key.lwe_secret_key.as_ref());
-                (view, client_key.parameters.lwe_modular_std_dev)
+                (view, client_key.parameters.lwe_noise_distribution)
             }
         };
 
@@ -159,11 +159,11 @@ impl BooleanEngine {
         let (lwe_sk, encryption_noise) = match client_parameters.encryption_key_choice {
             EncryptionKeyChoice::Big => (
                 client_key.glwe_secret_key.as_lwe_secret_key(),
-                client_key.parameters.glwe_modular_std_dev,
+                client_key.parameters.glwe_noise_distribution,
             ),
             EncryptionKeyChoice::Small => {
                 let view = LweSecretKey::from_container(client_key.lwe_secret_key.as_ref());
-                (view, client_key.parameters.lwe_modular_std_dev)
+                (view, client_key.parameters.lwe_noise_distribution)
             }
         };
 
@@ -228,7 +228,7 @@ cks1 has {choice1:?}, cks2 has: {choice2:?}
             &lwe_sk2,
             params.ks_base_log,
             params.ks_level,
-            cks2.parameters.lwe_modular_std_dev,
+            cks2.parameters.lwe_noise_distribution,
             CiphertextModulus::new_native(),
             &mut self.encryption_generator,
         )
@@ -249,11 +249,11 @@ cks1 has {choice1:?}, cks2 has: {choice2:?}
         let (lwe_sk, encryption_noise) = match cks.parameters.encryption_key_choice {
             EncryptionKeyChoice::Big => (
                 cks.glwe_secret_key.as_lwe_secret_key(),
-                cks.parameters.glwe_modular_std_dev,
+                cks.parameters.glwe_noise_distribution,
             ),
             EncryptionKeyChoice::Small => {
                 let view = LweSecretKey::from_container(cks.lwe_secret_key.as_ref());
-                (view, cks.parameters.lwe_modular_std_dev)
+                (view, cks.parameters.lwe_noise_distribution)
             }
         };
 
@@ -280,11 +280,11 @@ cks1 has {choice1:?}, cks2 has: {choice2:?}
         let (lwe_sk, encryption_noise) = match cks.parameters.encryption_key_choice {
             EncryptionKeyChoice::Big => (
                 cks.glwe_secret_key.as_lwe_secret_key(),
-                cks.parameters.glwe_modular_std_dev,
+                cks.parameters.glwe_noise_distribution,
             ),
             EncryptionKeyChoice::Small => {
                 let view = LweSecretKey::from_container(cks.lwe_secret_key.as_ref());
-                (view, cks.parameters.lwe_modular_std_dev)
+                (view, cks.parameters.lwe_noise_distribution)
             }
         };
 
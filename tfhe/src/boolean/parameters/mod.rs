@@ -18,12 +18,16 @@
 //! Failing to properly fix the parameters will potentially result with an incorrect and/or insecure
 //! computation.
 
-pub use crate::core_crypto::commons::dispersion::StandardDev;
+pub use crate::core_crypto::commons::dispersion::{NoiseDistribution, StandardDev};
+pub use crate::core_crypto::commons::math::random::TUniform;
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, EncryptionKeyChoice, GlweDimension,
     LweDimension, PolynomialSize,
 };
+pub use crate::core_crypto::commons::security_estimator::SecurityError;
 
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::security_estimator::validate_lwe_and_glwe_security;
 use serde::{Deserialize, Serialize};
 
 /// A set of cryptographic parameters for homomorphic Boolean circuit evaluation.
@@ -44,8 +48,8 @@ pub struct BooleanParameters {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
-    pub lwe_modular_std_dev: StandardDev,
-    pub glwe_modular_std_dev: StandardDev,
+    pub lwe_noise_distribution: NoiseDistribution,
+    pub glwe_noise_distribution: NoiseDistribution,
     pub pbs_base_log: DecompositionBaseLog,
     pub pbs_level: DecompositionLevelCount,
     pub ks_base_log: DecompositionBaseLog,
@@ -67,8 +71,8 @@ impl BooleanParameters {
         lwe_dimension: LweDimension,
         glwe_dimension: GlweDimension,
         polynomial_size: PolynomialSize,
-        lwe_modular_std_dev: StandardDev,
-        glwe_modular_std_dev: StandardDev,
+        lwe_noise_distribution: NoiseDistribution,
+        glwe_noise_distribution: NoiseDistribution,
         pbs_base_log: DecompositionBaseLog,
         pbs_level: DecompositionLevelCount,
         ks_base_log: DecompositionBaseLog,
@@ -79,8 +83,8 @@ impl BooleanParameters {
             lwe_dimension,
             glwe_dimension,
             polynomial_size,
-            lwe_modular_std_dev,
-            glwe_modular_std_dev,
+            lwe_noise_distribution,
+            glwe_noise_distribution,
             pbs_base_log,
             pbs_level,
             ks_base_log,
@@ -88,6 +92,33 @@ impl BooleanParameters {
             encryption_key_choice,
         }
     }
+
+    /// Check that the estimated security of the LWE and GLWE secret keys generated with these
+    /// parameters is at least `min_bits`.
+    ///
+    /// Boolean ciphertexts live on the native 32 bits modulus, see
+    /// [`crate::core_crypto::commons::security_estimator`] for the attacks considered.
+    ///
+    /// ```rust
+    /// use tfhe::boolean::parameters::{NoiseDistribution, StandardDev, DEFAULT_PARAMETERS};
+    ///
+    /// assert!(DEFAULT_PARAMETERS.validate_security(128).is_ok());
+    ///
+    /// let mut params = DEFAULT_PARAMETERS;
+    /// params.glwe_noise_distribution = NoiseDistribution::Gaussian(StandardDev(2.0f64.powi(-30)));
+    /// assert!(params.validate_security(128).is_err());
+    /// ```
+    pub fn validate_security(&self, min_bits: u32) -> Result<(), SecurityError> {
+        validate_lwe_and_glwe_security(
+            self.lwe_dimension,
+            self.lwe_noise_distribution,
+            self.glwe_dimension,
+            self.polynomial_size,
+            self.glwe_noise_distribution,
+            CiphertextModulus::<u32>::new_native(),
+            min_bits,
+        )
+    }
 }
 
 /// A set of cryptographic parameters for homomorphic Boolean key switching.
@@ -124,8 +155,8 @@ pub const DEFAULT_PARAMETERS: BooleanParameters = BooleanParameters {
     lwe_dimension: LweDimension(722),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.000013071021089943935),
-    glwe_modular_std_dev: StandardDev(0.00000004990272175010415),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000013071021089943935)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000004990272175010415)),
     pbs_base_log: DecompositionBaseLog(6),
     pbs_level: DecompositionLevelCount(3),
     ks_base_log: DecompositionBaseLog(3),
@@ -137,8 +168,8 @@ pub const DEFAULT_PARAMETERS_KS_PBS: BooleanParameters = BooleanParameters {
     lwe_dimension: LweDimension(664),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.00003808282923459771),
-    glwe_modular_std_dev: StandardDev(0.00000004990272175010415),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00003808282923459771)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000004990272175010415)),
     pbs_base_log: DecompositionBaseLog(6),
     pbs_level: DecompositionLevelCount(3),
     ks_base_log: DecompositionBaseLog(3),
@@ -153,8 +184,8 @@ pub const PARAMETERS_ERROR_PROB_2_POW_MINUS_165: BooleanParameters = BooleanPara
     lwe_dimension: LweDimension(767),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000005104350373791501),
-    glwe_modular_std_dev: StandardDev(0.0000000009313225746154785),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000005104350373791501)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000000009313225746154785)),
     pbs_base_log: DecompositionBaseLog(10),
     pbs_level: DecompositionLevelCount(2),
     ks_base_log: DecompositionBaseLog(3),
@@ -166,8 +197,8 @@ pub const PARAMETERS_ERROR_PROB_2_POW_MINUS_165_KS_PBS: BooleanParameters = Bool
     lwe_dimension: LweDimension(700),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.0000196095987892077),
-    glwe_modular_std_dev: StandardDev(0.00000004990272175010415),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000196095987892077)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000004990272175010415)),
     pbs_base_log: DecompositionBaseLog(5),
     pbs_level: DecompositionLevelCount(4),
     ks_base_log: DecompositionBaseLog(2),
@@ -182,8 +213,8 @@ pub const TFHE_LIB_PARAMETERS: BooleanParameters = BooleanParameters {
     lwe_dimension: LweDimension(630),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000030517578125),
-    glwe_modular_std_dev: StandardDev(0.00000002980232238769531),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000030517578125)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000002980232238769531)),
     pbs_base_log: DecompositionBaseLog(7),
     pbs_level: DecompositionLevelCount(3),
     ks_base_log: DecompositionBaseLog(2),
@@ -1,4 +1,5 @@
-use crate::core_crypto::commons::dispersion::StandardDev;
+use crate::c_api::utils::convert_noise_distribution;
+use crate::core_crypto::commons::dispersion::{NoiseDistribution, StandardDev};
 use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
@@ -57,8 +58,12 @@ impl From<BooleanParameters> for crate::boolean::parameters::BooleanParameters {
             lwe_dimension: LweDimension(c_params.lwe_dimension),
             glwe_dimension: GlweDimension(c_params.glwe_dimension),
             polynomial_size: PolynomialSize(c_params.polynomial_size),
-            lwe_modular_std_dev: StandardDev(c_params.lwe_modular_std_dev),
-            glwe_modular_std_dev: StandardDev(c_params.glwe_modular_std_dev),
+            lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+                c_params.lwe_modular_std_dev,
+            )),
+            glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+                c_params.glwe_modular_std_dev,
+            )),
             pbs_base_log: DecompositionBaseLog(c_params.pbs_base_log),
             pbs_level: DecompositionLevelCount(c_params.pbs_level),
             ks_base_log: DecompositionBaseLog(c_params.ks_base_log),
@@ -80,8 +85,8 @@ impl BooleanParameters {
             lwe_dimension: rust_params.lwe_dimension.0,
             glwe_dimension: rust_params.glwe_dimension.0,
             polynomial_size: rust_params.polynomial_size.0,
-            lwe_modular_std_dev: rust_params.lwe_modular_std_dev.0,
-            glwe_modular_std_dev: rust_params.glwe_modular_std_dev.0,
+            lwe_modular_std_dev: convert_noise_distribution(rust_params.lwe_noise_distribution),
+            glwe_modular_std_dev: convert_noise_distribution(rust_params.glwe_noise_distribution),
             pbs_base_log: rust_params.pbs_base_log.0,
             pbs_level: rust_params.pbs_level.0,
             ks_base_log: rust_params.ks_base_log.0,
@@ -1,3 +1,4 @@
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use std::os::raw::c_int;
 
 pub fn catch_panic<F>(closure: F) -> c_int
@@ -71,3 +72,13 @@ pub(super) unsafe fn get_ref_checked<'a, T>(ptr: *const T) -> Result<&'a T, Stri
         Err(e) => Err(e),
     }
 }
+
+// The C parameters structs only carry the standard deviation of a gaussian noise
+pub(super) const fn convert_noise_distribution(noise: NoiseDistribution) -> f64 {
+    match noise {
+        NoiseDistribution::Gaussian(std_dev) => std_dev.0,
+        NoiseDistribution::DiscreteGaussian(_) | NoiseDistribution::TUniform(_) => {
+            panic!("Only gaussian noise distributions are supported by the C API")
+        }
+    }
+}
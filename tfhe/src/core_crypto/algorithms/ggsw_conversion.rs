@@ -1,6 +1,6 @@
 //! Module containing primitives pertaining to the conversion of
 //! [`standard GGSW ciphertexts`](`GgswCiphertext`) to various representations/numerical domains
-//! like the Fourier domain.
+//! like the Fourier or NTT domains.
 
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::traits::*;
@@ -9,6 +9,7 @@ use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     fill_with_forward_fourier_scratch, FourierGgswCiphertext,
 };
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::ntt_impl::ntt64::math::ntt::Ntt64;
 use concrete_fft::c64;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 
@@ -68,3 +69,36 @@ pub fn convert_standard_ggsw_ciphertext_to_fourier_mem_optimized_requirement(
 ) -> Result<StackReq, SizeOverflow> {
     fill_with_forward_fourier_scratch(fft)
 }
+
+/// Convert a [`GGSW ciphertext`](`GgswCiphertext`) with standard coefficients to the NTT domain.
+///
+/// The ciphertext modulus of the GGSW ciphertext must be a prime supported by [`Ntt64`], see
+/// [`Ntt64::is_supported_modulus`].
+///
+/// See [`add_external_product_ntt64_assign`](`crate::core_crypto::algorithms::add_external_product_ntt64_assign`)
+/// for usage.
+pub fn convert_standard_ggsw_ciphertext_to_ntt64<InputCont, OutputCont>(
+    input_ggsw: &GgswCiphertext<InputCont>,
+    output_ggsw: &mut NttGgswCiphertext<OutputCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+{
+    assert_eq!(
+        input_ggsw.ciphertext_modulus(),
+        output_ggsw.ciphertext_modulus(),
+        "Mismatched CiphertextModulus between input_ggsw {:?} and output_ggsw {:?}",
+        input_ggsw.ciphertext_modulus(),
+        output_ggsw.ciphertext_modulus(),
+    );
+
+    let ntt = Ntt64::new(
+        output_ggsw.polynomial_size(),
+        output_ggsw.ciphertext_modulus(),
+    );
+    let ntt = ntt.as_view();
+
+    output_ggsw
+        .as_mut_view()
+        .fill_with_forward_ntt(input_ggsw, ntt);
+}
@@ -1,13 +1,17 @@
 //! Module containing primitives pertaining to [`GGSW ciphertext
 //! encryption`](`GgswCiphertext#ggsw-encryption`).
 
+use crate::core_crypto::algorithms::glwe_encryption::assert_glwe_ciphertext_modulus_is_supported;
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulusKind;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, SignedDecomposer};
 use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
-use crate::core_crypto::commons::parameters::PlaintextCount;
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulus, DecompositionBaseLog, PlaintextCount,
+};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use rayon::prelude::*;
@@ -73,7 +77,7 @@ pub fn encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut GgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -81,6 +85,7 @@ pub fn encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.polynomial_size() == glwe_secret_key.polynomial_size(),
         "Mismatch between polynomial sizes of output ciphertexts and input secret key. \
@@ -111,19 +116,18 @@ pub fn encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     let decomp_base_log = output.decomposition_base_log();
     let ciphertext_modulus = output.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+    assert_glwe_ciphertext_modulus_is_supported(ciphertext_modulus, output_polynomial_size);
 
     for (level_index, (mut level_matrix, mut generator)) in
         output.iter_mut().zip(gen_iter).enumerate()
     {
         let decomp_level = DecompositionLevel(level_index + 1);
-        // We scale the factor down from the native torus to whatever our torus is, the
-        // encryption process will scale it back up
-        let factor = encoded
-            .0
-            .wrapping_neg()
-            .wrapping_mul(Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0)))
-            .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
+        let factor = ggsw_encryption_multiplicative_factor(
+            ciphertext_modulus,
+            decomp_level,
+            decomp_base_log,
+            encoded,
+        );
 
         // We iterate over the rows of the level matrix, the last row needs special treatment
         let gen_iter = generator
@@ -213,7 +217,7 @@ pub fn par_encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut GgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -221,6 +225,7 @@ pub fn par_encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ParallelByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.polynomial_size() == glwe_secret_key.polynomial_size(),
         "Mismatch between polynomial sizes of output ciphertexts and input secret key. \
@@ -251,18 +256,17 @@ pub fn par_encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     let decomp_base_log = output.decomposition_base_log();
     let ciphertext_modulus = output.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+    assert_glwe_ciphertext_modulus_is_supported(ciphertext_modulus, output_polynomial_size);
 
     output.par_iter_mut().zip(gen_iter).enumerate().for_each(
         |(level_index, (mut level_matrix, mut generator))| {
             let decomp_level = DecompositionLevel(level_index + 1);
-            // We scale the factor down from the native torus to whatever our torus is, the
-            // encryption process will scale it back up
-            let factor = encoded
-                .0
-                .wrapping_neg()
-                .wrapping_mul(Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0)))
-                .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus());
+            let factor = ggsw_encryption_multiplicative_factor(
+                ciphertext_modulus,
+                decomp_level,
+                decomp_base_log,
+                encoded,
+            );
 
             // We iterate over the rows of the level matrix, the last row needs special
             // treatment
@@ -291,6 +295,38 @@ pub fn par_encrypt_constant_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     );
 }
 
+/// Compute the factor used to encrypt the rows of a level matrix of a
+/// [`GGSW ciphertext`](`GgswCiphertext`), i.e. $-m \cdot \frac{q}{B^{j}}$ for the level $j$.
+///
+/// For non power of 2 moduli $\frac{q}{B^{j}}$ is floored, matching the non native decomposition.
+fn ggsw_encryption_multiplicative_factor<Scalar: UnsignedTorus>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    decomp_level: DecompositionLevel,
+    decomp_base_log: DecompositionBaseLog,
+    encoded: Plaintext<Scalar>,
+) -> Scalar {
+    match ciphertext_modulus.kind() {
+        CiphertextModulusKind::Native | CiphertextModulusKind::NonNativePowerOfTwo => {
+            // We scale the factor down from the native torus to whatever our torus is, the
+            // encryption process will scale it back up
+            encoded
+                .0
+                .wrapping_neg()
+                .wrapping_mul(Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0)))
+                .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus())
+        }
+        CiphertextModulusKind::Other => {
+            let modulus = ciphertext_modulus.get_custom_modulus();
+            let digit_radix: Scalar = (modulus >> (decomp_base_log.0 * decomp_level.0)).cast_into();
+            let modulus: Scalar = modulus.cast_into();
+            encoded
+                .0
+                .wrapping_neg_custom_mod(modulus)
+                .wrapping_mul_custom_mod(digit_radix, modulus)
+        }
+    }
+}
+
 /// Convenience function to encrypt a row of a [`GgswLevelMatrix`] irrespective of the current row
 /// being encrypted. Allows to share code between sequential ([`encrypt_constant_ggsw_ciphertext`])
 /// and parallel ([`par_encrypt_constant_ggsw_ciphertext`]) variants of the GGSW ciphertext
@@ -302,7 +338,7 @@ fn encrypt_constant_ggsw_level_matrix_row<Scalar, KeyCont, OutputCont, Gen>(
     (row_index, last_row_index): (usize, usize),
     factor: Scalar,
     row_as_glwe: &mut GlweCiphertext<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -310,6 +346,8 @@ fn encrypt_constant_ggsw_level_matrix_row<Scalar, KeyCont, OutputCont, Gen>(
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let ciphertext_modulus = row_as_glwe.ciphertext_modulus();
+
     if row_index < last_row_index {
         // Not the last row
         let sk_poly_list = glwe_secret_key.as_polynomial_list();
@@ -319,13 +357,25 @@ fn encrypt_constant_ggsw_level_matrix_row<Scalar, KeyCont, OutputCont, Gen>(
         let mut body = row_as_glwe.get_mut_body();
         body.as_mut().copy_from_slice(sk_poly.as_ref());
 
-        slice_wrapping_scalar_mul_assign(body.as_mut(), factor);
+        if ciphertext_modulus.is_compatible_with_native_modulus() {
+            slice_wrapping_scalar_mul_assign(body.as_mut(), factor);
+        } else {
+            slice_wrapping_scalar_mul_assign_custom_mod(
+                body.as_mut(),
+                factor,
+                ciphertext_modulus.get_custom_modulus().cast_into(),
+            );
+        }
     } else {
         // The last row needs a slightly different treatment
         let mut body = row_as_glwe.get_mut_body();
 
         body.as_mut().fill(Scalar::ZERO);
-        body.as_mut()[0] = factor.wrapping_neg();
+        body.as_mut()[0] = if ciphertext_modulus.is_compatible_with_native_modulus() {
+            factor.wrapping_neg()
+        } else {
+            factor.wrapping_neg_custom_mod(ciphertext_modulus.get_custom_modulus().cast_into())
+        };
     }
     encrypt_glwe_ciphertext_assign(glwe_secret_key, row_as_glwe, noise_parameters, generator);
 }
@@ -346,7 +396,7 @@ pub fn encrypt_constant_seeded_ggsw_ciphertext_with_existing_generator<
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -354,6 +404,7 @@ pub fn encrypt_constant_seeded_ggsw_ciphertext_with_existing_generator<
     OutputCont: ContainerMut<Element = Scalar> + std::fmt::Debug,
     Gen: ByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     // Generators used to have same sequential and parallel key generation
     let gen_iter = generator
         .fork_ggsw_to_ggsw_levels::<Scalar>(
@@ -468,7 +519,7 @@ pub fn encrypt_constant_seeded_ggsw_ciphertext<Scalar, KeyCont, OutputCont, Nois
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -523,7 +574,7 @@ pub fn par_encrypt_constant_seeded_ggsw_ciphertext_with_existing_generator<
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -531,6 +582,7 @@ pub fn par_encrypt_constant_seeded_ggsw_ciphertext_with_existing_generator<
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ParallelByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     // Generators used to have same sequential and parallel key generation
     let gen_iter = generator
         .par_fork_ggsw_to_ggsw_levels::<Scalar>(
@@ -646,7 +698,7 @@ pub fn par_encrypt_constant_seeded_ggsw_ciphertext<Scalar, KeyCont, OutputCont,
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGgswCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -696,7 +748,7 @@ fn encrypt_constant_seeded_ggsw_level_matrix_row<Scalar, KeyCont, OutputCont, Ge
     (row_index, last_row_index): (usize, usize),
     factor: Scalar,
     row_as_glwe: &mut SeededGlweCiphertext<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -830,7 +882,22 @@ where
     let plaintext_ref = decrypted_plaintext_list.get(0);
 
     let ciphertext_modulus = ggsw_ciphertext.ciphertext_modulus();
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+    assert_glwe_ciphertext_modulus_is_supported(
+        ciphertext_modulus,
+        ggsw_ciphertext.polynomial_size(),
+    );
+
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        // Round to the closest multiple of floor(q / B^level) and remove the scaling
+        let digit_radix =
+            ciphertext_modulus.get_custom_modulus() >> (decomp_base_log.0 * decomp_level.0);
+        let value: u128 = (*plaintext_ref.0).cast_into();
+        let decoded = ((value + digit_radix / 2) / digit_radix)
+            % (1u128 << (decomp_base_log.0 * decomp_level.0));
+
+        return Plaintext(decoded.cast_into());
+    }
 
     // Glwe decryption maps to a smaller torus potentially, map back to the native torus
     let rounded = decomposer.closest_representable(
@@ -0,0 +1,704 @@
+//! Module containing primitives pertaining to [`GLWE ciphertext
+//! automorphisms`](`GlweAutomorphismKey#automorphism-application`) and the homomorphic trace built
+//! on top of them.
+
+use crate::core_crypto::algorithms::glwe_keyswitch::keyswitch_glwe_ciphertext;
+use crate::core_crypto::algorithms::glwe_linear_algebra::{
+    glwe_ciphertext_add_assign, glwe_ciphertext_sub,
+};
+use crate::core_crypto::algorithms::polynomial_algorithms::{
+    polynomial_apply_automorphism, polynomial_wrapping_monic_monomial_mul,
+};
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_opposite_assign;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Apply the automorphism $X \mapsto X^{k}$ of a [`GlweAutomorphismKey`] to a [`GLWE
+/// ciphertext`](`GlweCiphertext`), the output is encrypted under the same [`GlweSecretKey`] as the
+/// input.
+///
+/// # Formal Definition
+///
+/// See [`GLWE automorphism key`](`GlweAutomorphismKey#automorphism-application`).
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::polynomial_apply_automorphism;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweAutomorphismKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(15);
+/// let decomp_level_count = DecompositionLevelCount(3);
+/// let automorphism_exponent = AutomorphismExponent(5);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let ak = allocate_and_generate_new_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     automorphism_exponent,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext
+/// let mut input_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// input_plaintext_list
+///     .iter_mut()
+///     .enumerate()
+///     .for_each(|(idx, dst)| *dst.0 = (idx as u64 % 16) << 60);
+///
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut input_glwe,
+///     &input_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// apply_automorphism_glwe_ciphertext(&ak, &input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &output_glwe, &mut decrypted_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Compute the expected result in the clear
+/// let mut expected_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// polynomial_apply_automorphism(
+///     &mut expected_plaintext_list.as_mut_polynomial(),
+///     &input_plaintext_list.as_polynomial(),
+///     automorphism_exponent,
+/// );
+/// expected_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = *x.0 >> 60);
+///
+/// // Check we recovered the expected result
+/// assert_eq!(expected_plaintext_list, decrypted_plaintext_list);
+/// ```
+pub fn apply_automorphism_glwe_ciphertext<Scalar, AkCont, InputCont, OutputCont>(
+    glwe_automorphism_key: &GlweAutomorphismKey<AkCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    AkCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        glwe_automorphism_key.polynomial_size() == input_glwe_ciphertext.polynomial_size(),
+        "Mismatched input PolynomialSize. \
+        GlweAutomorphismKey PolynomialSize: {:?}, input GlweCiphertext PolynomialSize {:?}.",
+        glwe_automorphism_key.polynomial_size(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+
+    let automorphism_exponent = glwe_automorphism_key.automorphism_exponent();
+
+    // Apply the automorphism on all polynomials, the result is encrypted under tau_k(S)
+    let mut buffer = GlweCiphertext::new(
+        Scalar::ZERO,
+        input_glwe_ciphertext.glwe_size(),
+        input_glwe_ciphertext.polynomial_size(),
+        input_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    for (mut buffer_polynomial, input_polynomial) in buffer
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(input_glwe_ciphertext.as_polynomial_list().iter())
+    {
+        polynomial_apply_automorphism(
+            &mut buffer_polynomial,
+            &input_polynomial,
+            automorphism_exponent,
+        );
+    }
+
+    // Switch back to S
+    keyswitch_glwe_ciphertext(
+        &glwe_automorphism_key.as_glwe_keyswitch_key(),
+        &buffer,
+        output_glwe_ciphertext,
+    );
+}
+
+/// Return the [`AutomorphismExponent`] values $2^{l} + 1$ for $l \in [1, \log\_2(N)]$ required
+/// to compute the homomorphic trace of a [`GLWE ciphertext`](`GlweCiphertext`) with the given
+/// [`PolynomialSize`] $N$, in ascending order.
+///
+/// See [`trace_glwe_ciphertext`] and
+/// [`allocate_and_generate_new_glwe_trace_automorphism_keys`](`super::allocate_and_generate_new_glwe_trace_automorphism_keys`).
+pub fn glwe_trace_automorphism_exponents(
+    polynomial_size: PolynomialSize,
+) -> Vec<AutomorphismExponent> {
+    assert!(
+        polynomial_size.0.is_power_of_two(),
+        "The homomorphic trace requires a power of 2 PolynomialSize, got {polynomial_size:?}"
+    );
+
+    (1..=polynomial_size.log2().0)
+        .map(|l| AutomorphismExponent((1 << l) + 1))
+        .collect()
+}
+
+/// Compute the homomorphic trace of a [`GLWE ciphertext`](`GlweCiphertext`).
+///
+/// The output encrypts the constant coefficient of the input plaintext multiplied by the
+/// [`PolynomialSize`] $N$, all other coefficients are set to 0. As $N$ is not invertible modulo a
+/// power of 2, inputs are expected to be encoded with a scaling factor $\Delta / N$ to get an output
+/// encoded with $\Delta$.
+///
+/// The keys must contain a [`GlweAutomorphismKey`] for each exponent returned by
+/// [`glwe_trace_automorphism_exponents`], which is what
+/// [`allocate_and_generate_new_glwe_trace_automorphism_keys`](`super::allocate_and_generate_new_glwe_trace_automorphism_keys`)
+/// generates.
+///
+/// # Formal Definition
+///
+/// The trace of a polynomial $P$ in $\mathbb{Z}\_q\[X\]/(X^N + 1)$ is $\sum\_{k} \tau\_k(P)$ for
+/// all odd $k < 2N$, it is computed with $\log\_2(N)$ automorphisms by repeating $\mathsf{CT}
+/// \leftarrow \mathsf{CT} + \tau\_{2^{l}+1}(\mathsf{CT})$ for $l \in [1, \log\_2(N)]$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(15);
+/// let decomp_level_count = DecompositionLevelCount(3);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let trace_keys = allocate_and_generate_new_glwe_trace_automorphism_keys(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // The trace multiplies the constant coefficient by N, account for it in the encoding
+/// let msg = 3u64;
+/// let delta = (1u64 << 60) / polynomial_size.0 as u64;
+///
+/// let mut input_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// input_plaintext_list
+///     .iter_mut()
+///     .enumerate()
+///     .for_each(|(idx, dst)| *dst.0 = ((msg + idx as u64) % 16) * delta);
+///
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut input_glwe,
+///     &input_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// trace_glwe_ciphertext(&trace_keys, &input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &output_glwe, &mut decrypted_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Only the constant coefficient is kept
+/// assert_eq!(*decrypted_plaintext_list.get(0).0, msg);
+/// assert!(decrypted_plaintext_list.iter().skip(1).all(|x| *x.0 == 0));
+/// ```
+pub fn trace_glwe_ciphertext<Scalar, AkCont, InputCont, OutputCont>(
+    glwe_trace_automorphism_keys: &[GlweAutomorphismKey<AkCont>],
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    AkCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert!(
+        input_glwe_ciphertext.ciphertext_modulus() == output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        Input GlweCiphertext CiphertextModulus: {:?}, output GlweCiphertext CiphertextModulus {:?}.",
+        input_glwe_ciphertext.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus()
+    );
+
+    output_glwe_ciphertext
+        .as_mut()
+        .copy_from_slice(input_glwe_ciphertext.as_ref());
+
+    let mut buffer = GlweCiphertext::new(
+        Scalar::ZERO,
+        output_glwe_ciphertext.glwe_size(),
+        output_glwe_ciphertext.polynomial_size(),
+        output_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    for automorphism_exponent in
+        glwe_trace_automorphism_exponents(output_glwe_ciphertext.polynomial_size())
+    {
+        let glwe_automorphism_key =
+            find_glwe_automorphism_key(glwe_trace_automorphism_keys, automorphism_exponent);
+
+        apply_automorphism_glwe_ciphertext(
+            glwe_automorphism_key,
+            output_glwe_ciphertext,
+            &mut buffer,
+        );
+        glwe_ciphertext_add_assign(output_glwe_ciphertext, &buffer);
+    }
+}
+
+/// Pack an [`LWE ciphertext`](`LweCiphertext`) in the constant coefficient of a [`GLWE
+/// ciphertext`](`GlweCiphertext`) using the homomorphic trace.
+///
+/// The [`LWE ciphertext`](`LweCiphertext`) must be encrypted under the [`LWE secret
+/// key`](`LweSecretKey`) obtained with [`GlweSecretKey::as_lwe_secret_key`] from the
+/// [`GlweSecretKey`] used to generate the automorphism keys. The output encrypts the input
+/// plaintext multiplied by the [`PolynomialSize`] $N$ in its constant coefficient and 0 elsewhere,
+/// see [`trace_glwe_ciphertext`] for the encoding to use.
+///
+/// # Example
+///
+/// See [`pack_lwe_ciphertext_list_into_glwe_ciphertext_via_trace`].
+pub fn pack_lwe_ciphertext_into_glwe_ciphertext_via_trace<Scalar, AkCont, InputCont, OutputCont>(
+    glwe_trace_automorphism_keys: &[GlweAutomorphismKey<AkCont>],
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    AkCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let mut buffer = GlweCiphertext::new(
+        Scalar::ZERO,
+        output_glwe_ciphertext.glwe_size(),
+        output_glwe_ciphertext.polynomial_size(),
+        output_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    embed_lwe_ciphertext_in_glwe_ciphertext(input_lwe_ciphertext, &mut buffer);
+
+    trace_glwe_ciphertext(
+        glwe_trace_automorphism_keys,
+        &buffer,
+        output_glwe_ciphertext,
+    );
+}
+
+/// Pack an [`LWE ciphertext list`](`LweCiphertextList`) in a [`GLWE ciphertext`](`GlweCiphertext`)
+/// using automorphisms, the $j$-th input ciphertext ends up in the $j$-th coefficient of the
+/// output.
+///
+/// Contrary to
+/// [`keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`](`super::keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`)
+/// which requires a key-switch key per [`LWE secret key`](`LweSecretKey`) coefficient, only
+/// $\log\_2(N)$ [`GLWE automorphism keys`](`GlweAutomorphismKey`) are needed. The input ciphertexts
+/// must be encrypted under the [`LWE secret key`](`LweSecretKey`) obtained with
+/// [`GlweSecretKey::as_lwe_secret_key`] and at most $N$ ciphertexts can be packed.
+///
+/// Each output coefficient holds the corresponding input plaintext multiplied by the
+/// [`PolynomialSize`] $N$, coefficients past the number of input ciphertexts are 0, see
+/// [`trace_glwe_ciphertext`] for the encoding to use.
+///
+/// # Formal Definition
+///
+/// Each LWE ciphertext is first seen as a GLWE ciphertext encrypting its plaintext in the constant
+/// coefficient. Ciphertexts are then recursively merged, two ciphertexts $\mathsf{CT}\_{even}$
+/// and $\mathsf{CT}\_{odd}$ being combined at level $l$ as $\left(\mathsf{CT}\_{even} +
+/// X^{N/2^{l}} \cdot \mathsf{CT}\_{odd}\right) + \tau\_{2^{l}+1}\left(\mathsf{CT}\_{even} -
+/// X^{N/2^{l}} \cdot \mathsf{CT}\_{odd}\right)$, which cancels the unwanted coefficients while
+/// moving the messages in place.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(15);
+/// let decomp_level_count = DecompositionLevelCount(3);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let lwe_secret_key = glwe_secret_key.as_lwe_secret_key();
+///
+/// let trace_keys = allocate_and_generate_new_glwe_trace_automorphism_keys(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // The packing multiplies the plaintexts by N, account for it in the encoding
+/// let delta = (1u64 << 60) / polynomial_size.0 as u64;
+/// let lwe_ciphertext_count = LweCiphertextCount(8);
+/// let input_plaintext_list = PlaintextList::from_container(
+///     (0..lwe_ciphertext_count.0 as u64)
+///         .map(|msg| msg * delta)
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// let mut lwe_list = LweCiphertextList::new(
+///     0u64,
+///     lwe_secret_key.lwe_dimension().to_lwe_size(),
+///     lwe_ciphertext_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_lwe_ciphertext_list(
+///     &lwe_secret_key,
+///     &mut lwe_list,
+///     &input_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// pack_lwe_ciphertext_list_into_glwe_ciphertext_via_trace(&trace_keys, &lwe_list, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &output_glwe, &mut decrypted_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+///
+/// // Check we recovered the messages in the first coefficients and 0 elsewhere
+/// for (idx, decrypted) in decrypted_plaintext_list.iter().enumerate() {
+///     let expected = if idx < lwe_ciphertext_count.0 {
+///         idx as u64
+///     } else {
+///         0
+///     };
+///     assert_eq!(*decrypted.0, expected);
+/// }
+/// ```
+pub fn pack_lwe_ciphertext_list_into_glwe_ciphertext_via_trace<
+    Scalar,
+    AkCont,
+    InputCont,
+    OutputCont,
+>(
+    glwe_trace_automorphism_keys: &[GlweAutomorphismKey<AkCont>],
+    input_lwe_ciphertext_list: &LweCiphertextList<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    AkCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = output_glwe_ciphertext.polynomial_size();
+    let glwe_size = output_glwe_ciphertext.glwe_size();
+    let ciphertext_modulus = output_glwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        polynomial_size.0.is_power_of_two(),
+        "The trace based packing requires a power of 2 PolynomialSize, got {polynomial_size:?}"
+    );
+    assert!(
+        input_lwe_ciphertext_list.lwe_ciphertext_count().0 <= polynomial_size.0,
+        "Cannot pack more LWE ciphertexts than the output PolynomialSize. \
+        Input LweCiphertextCount: {:?}, output PolynomialSize: {:?}.",
+        input_lwe_ciphertext_list.lwe_ciphertext_count(),
+        polynomial_size
+    );
+    assert!(
+        input_lwe_ciphertext_list.ciphertext_modulus() == ciphertext_modulus,
+        "Mismatched CiphertextModulus. \
+        Input LweCiphertextList CiphertextModulus: {:?}, output GlweCiphertext CiphertextModulus {:?}.",
+        input_lwe_ciphertext_list.ciphertext_modulus(),
+        ciphertext_modulus
+    );
+
+    // Missing ciphertexts encrypt 0, they are tracked with None to avoid useless automorphisms
+    let mut current: Vec<Option<GlweCiphertextOwned<Scalar>>> =
+        (0..polynomial_size.0).map(|_| None).collect();
+
+    for (slot, lwe_ciphertext) in current.iter_mut().zip(input_lwe_ciphertext_list.iter()) {
+        let mut glwe_ciphertext =
+            GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+        embed_lwe_ciphertext_in_glwe_ciphertext(&lwe_ciphertext, &mut glwe_ciphertext);
+        *slot = Some(glwe_ciphertext);
+    }
+
+    let mut shifted =
+        GlweCiphertext::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
+    let mut combined = shifted.clone();
+    let mut automorphism_buffer = shifted.clone();
+
+    // Bottom-up evaluation of the recursive packing, at a given level the ciphertexts at index r
+    // and r + half are respectively the even and odd halves of the same sub-packing
+    for level in 1..=polynomial_size.log2().0 {
+        let half = current.len() / 2;
+        let automorphism_exponent = AutomorphismExponent((1 << level) + 1);
+        let monomial_degree = MonomialDegree(polynomial_size.0 >> level);
+        let glwe_automorphism_key =
+            find_glwe_automorphism_key(glwe_trace_automorphism_keys, automorphism_exponent);
+
+        let odd_ciphertexts = current.split_off(half);
+
+        for (even, odd) in current.iter_mut().zip(odd_ciphertexts) {
+            match (even.as_mut(), odd) {
+                (None, None) => (),
+                // even + tau(even)
+                (Some(even), None) => {
+                    apply_automorphism_glwe_ciphertext(
+                        glwe_automorphism_key,
+                        even,
+                        &mut automorphism_buffer,
+                    );
+                    glwe_ciphertext_add_assign(even, &automorphism_buffer);
+                }
+                // X^d * odd - tau(X^d * odd)
+                (None, Some(odd)) => {
+                    glwe_ciphertext_monic_monomial_mul(&mut shifted, &odd, monomial_degree);
+                    apply_automorphism_glwe_ciphertext(
+                        glwe_automorphism_key,
+                        &shifted,
+                        &mut automorphism_buffer,
+                    );
+                    glwe_ciphertext_sub(&mut combined, &shifted, &automorphism_buffer);
+                    *even = Some(combined.clone());
+                }
+                // (even + X^d * odd) + tau(even - X^d * odd)
+                (Some(even), Some(odd)) => {
+                    glwe_ciphertext_monic_monomial_mul(&mut shifted, &odd, monomial_degree);
+                    glwe_ciphertext_sub(&mut combined, even, &shifted);
+                    apply_automorphism_glwe_ciphertext(
+                        glwe_automorphism_key,
+                        &combined,
+                        &mut automorphism_buffer,
+                    );
+                    glwe_ciphertext_add_assign(even, &shifted);
+                    glwe_ciphertext_add_assign(even, &automorphism_buffer);
+                }
+            }
+        }
+    }
+
+    match current.pop().flatten() {
+        Some(packed) => output_glwe_ciphertext
+            .as_mut()
+            .copy_from_slice(packed.as_ref()),
+        None => output_glwe_ciphertext.as_mut().fill(Scalar::ZERO),
+    }
+}
+
+/// Write in `output_glwe_ciphertext` a [`GLWE ciphertext`](`GlweCiphertext`) encrypting the
+/// plaintext of `input_lwe_ciphertext` in its constant coefficient, the other coefficients
+/// encrypt arbitrary values.
+fn embed_lwe_ciphertext_in_glwe_ciphertext<Scalar, InputCont, OutputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = output_glwe_ciphertext.polynomial_size();
+
+    assert!(
+        input_lwe_ciphertext.lwe_size().to_lwe_dimension().0
+            == output_glwe_ciphertext
+                .glwe_size()
+                .to_glwe_dimension()
+                .to_equivalent_lwe_dimension(polynomial_size)
+                .0,
+        "Mismatched LweDimension. \
+        Input LweCiphertext LweDimension: {:?}, output GlweCiphertext equivalent LweDimension {:?}.",
+        input_lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        output_glwe_ciphertext
+            .glwe_size()
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(polynomial_size)
+    );
+
+    // The constant coefficient of A_j * S_j is a_0 * s_0 - sum_{i > 0} A_j[N - i] * s_i, so the
+    // LWE mask is reversed and negated past the first coefficient
+    for (mut mask_polynomial, lwe_mask_chunk) in output_glwe_ciphertext
+        .get_mut_mask()
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(
+            input_lwe_ciphertext
+                .get_mask()
+                .as_ref()
+                .chunks_exact(polynomial_size.0),
+        )
+    {
+        mask_polynomial.as_mut().copy_from_slice(lwe_mask_chunk);
+        mask_polynomial.as_mut()[1..].reverse();
+        slice_wrapping_opposite_assign(&mut mask_polynomial.as_mut()[1..]);
+    }
+
+    let mut body = output_glwe_ciphertext.get_mut_body();
+    let body = body.as_mut();
+    body.fill(Scalar::ZERO);
+    body[0] = *input_lwe_ciphertext.get_body().data;
+}
+
+/// Multiply all polynomials of a [`GLWE ciphertext`](`GlweCiphertext`) by the monic monomial
+/// $X^{d}$.
+fn glwe_ciphertext_monic_monomial_mul<Scalar, OutputCont, InputCont>(
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    monomial_degree: MonomialDegree,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    for (mut output_polynomial, input_polynomial) in output_glwe_ciphertext
+        .as_mut_polynomial_list()
+        .iter_mut()
+        .zip(input_glwe_ciphertext.as_polynomial_list().iter())
+    {
+        polynomial_wrapping_monic_monomial_mul(
+            &mut output_polynomial,
+            &input_polynomial,
+            monomial_degree,
+        );
+    }
+}
+
+fn find_glwe_automorphism_key<Scalar, AkCont>(
+    glwe_automorphism_keys: &[GlweAutomorphismKey<AkCont>],
+    automorphism_exponent: AutomorphismExponent,
+) -> &GlweAutomorphismKey<AkCont>
+where
+    Scalar: UnsignedInteger,
+    AkCont: Container<Element = Scalar>,
+{
+    glwe_automorphism_keys
+        .iter()
+        .find(|key| key.automorphism_exponent() == automorphism_exponent)
+        .unwrap_or_else(|| {
+            panic!(
+                "Missing GlweAutomorphismKey for automorphism exponent {automorphism_exponent:?}"
+            )
+        })
+}
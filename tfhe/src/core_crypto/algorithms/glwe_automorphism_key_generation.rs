@@ -0,0 +1,213 @@
+//! Module containing primitives pertaining to [`GLWE automorphism keys
+//! generation`](`GlweAutomorphismKey`).
+
+use crate::core_crypto::algorithms::glwe_automorphism::glwe_trace_automorphism_exponents;
+use crate::core_crypto::algorithms::glwe_keyswitch_key_generation::generate_glwe_keyswitch_key;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_apply_automorphism;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::random::ByteRandomGenerator;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::{
+    GlweAutomorphismKey, GlweAutomorphismKeyOwned, GlweSecretKey, GlweSecretKeyOwned,
+    PolynomialList,
+};
+
+/// Fill a [`GLWE automorphism key`](`GlweAutomorphismKey`) with an actual automorphism key
+/// constructed from a [`GLWE secret key`](`GlweSecretKey`).
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweAutomorphismKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(15);
+/// let decomp_level_count = DecompositionLevelCount(3);
+/// let automorphism_exponent = AutomorphismExponent(3);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut ak = GlweAutomorphismKey::new(
+///     0u64,
+///     automorphism_exponent,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_dimension,
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// generate_glwe_automorphism_key(
+///     &glwe_secret_key,
+///     &mut ak,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// assert!(ak.as_ref().iter().all(|&x| x == 0) == false);
+/// ```
+pub fn generate_glwe_automorphism_key<Scalar, KeyCont, AkCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    glwe_automorphism_key: &mut GlweAutomorphismKey<AkCont>,
+    noise_parameters: impl Into<NoiseDistribution>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    AkCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        glwe_automorphism_key.glwe_dimension() == glwe_secret_key.glwe_dimension(),
+        "The destination GlweAutomorphismKey GlweDimension is not equal \
+    to the GlweSecretKey GlweDimension. Destination: {:?}, key: {:?}",
+        glwe_automorphism_key.glwe_dimension(),
+        glwe_secret_key.glwe_dimension()
+    );
+    assert!(
+        glwe_automorphism_key.polynomial_size() == glwe_secret_key.polynomial_size(),
+        "The destination GlweAutomorphismKey PolynomialSize is not equal \
+        to the GlweSecretKey PolynomialSize. Destination: {:?}, key: {:?}",
+        glwe_automorphism_key.polynomial_size(),
+        glwe_secret_key.polynomial_size()
+    );
+
+    let input_glwe_secret_key = automorphism_glwe_secret_key(
+        glwe_secret_key,
+        glwe_automorphism_key.automorphism_exponent(),
+    );
+
+    generate_glwe_keyswitch_key(
+        &input_glwe_secret_key,
+        glwe_secret_key,
+        &mut glwe_automorphism_key.as_mut_glwe_keyswitch_key(),
+        noise_parameters,
+        generator,
+    );
+}
+
+/// Allocate a new [`GLWE automorphism key`](`GlweAutomorphismKey`) and fill it with an actual
+/// automorphism key constructed from a [`GLWE secret key`](`GlweSecretKey`).
+///
+/// See [`apply_automorphism_glwe_ciphertext`](`super::apply_automorphism_glwe_ciphertext`) for
+/// usage.
+pub fn allocate_and_generate_new_glwe_automorphism_key<Scalar, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    automorphism_exponent: AutomorphismExponent,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> GlweAutomorphismKeyOwned<Scalar>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let mut new_glwe_automorphism_key = GlweAutomorphismKeyOwned::new(
+        Scalar::ZERO,
+        automorphism_exponent,
+        decomp_base_log,
+        decomp_level_count,
+        glwe_secret_key.glwe_dimension(),
+        glwe_secret_key.polynomial_size(),
+        ciphertext_modulus,
+    );
+
+    generate_glwe_automorphism_key(
+        glwe_secret_key,
+        &mut new_glwe_automorphism_key,
+        noise_parameters,
+        generator,
+    );
+
+    new_glwe_automorphism_key
+}
+
+/// Allocate and generate the [`GLWE automorphism keys`](`GlweAutomorphismKey`) required to
+/// compute the homomorphic trace of a [`GLWE ciphertext`](`super::GlweCiphertext`), one key per
+/// exponent returned by [`glwe_trace_automorphism_exponents`].
+///
+/// See [`trace_glwe_ciphertext`](`super::trace_glwe_ciphertext`) for usage.
+pub fn allocate_and_generate_new_glwe_trace_automorphism_keys<Scalar, KeyCont, Gen>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> Vec<GlweAutomorphismKeyOwned<Scalar>>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
+    glwe_trace_automorphism_exponents(glwe_secret_key.polynomial_size())
+        .into_iter()
+        .map(|automorphism_exponent| {
+            allocate_and_generate_new_glwe_automorphism_key(
+                glwe_secret_key,
+                automorphism_exponent,
+                decomp_base_log,
+                decomp_level_count,
+                noise_parameters,
+                ciphertext_modulus,
+                generator,
+            )
+        })
+        .collect()
+}
+
+/// Return the [`GlweSecretKey`] obtained by applying the automorphism of exponent
+/// `automorphism_exponent` to each polynomial of `glwe_secret_key`.
+fn automorphism_glwe_secret_key<Scalar, KeyCont>(
+    glwe_secret_key: &GlweSecretKey<KeyCont>,
+    automorphism_exponent: AutomorphismExponent,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    KeyCont: Container<Element = Scalar>,
+{
+    let mut output_polynomials = PolynomialList::new(
+        Scalar::ZERO,
+        glwe_secret_key.polynomial_size(),
+        PolynomialCount(glwe_secret_key.glwe_dimension().0),
+    );
+
+    for (mut output_polynomial, input_polynomial) in output_polynomials
+        .iter_mut()
+        .zip(glwe_secret_key.as_polynomial_list().iter())
+    {
+        polynomial_apply_automorphism(
+            &mut output_polynomial,
+            &input_polynomial,
+            automorphism_exponent,
+        );
+    }
+
+    GlweSecretKey::from_container(
+        output_polynomials.into_container(),
+        glwe_secret_key.polynomial_size(),
+    )
+}
@@ -3,14 +3,35 @@
 
 use crate::core_crypto::algorithms::polynomial_algorithms::*;
 use crate::core_crypto::algorithms::slice_algorithms::{
-    slice_wrapping_scalar_div_assign, slice_wrapping_scalar_mul_assign,
+    slice_wrapping_add_assign_custom_mod, slice_wrapping_scalar_div_assign,
+    slice_wrapping_scalar_mul_assign,
 };
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use crate::core_crypto::ntt_impl::ntt64::math::ntt::Ntt64;
+
+/// Check that a ciphertext modulus is supported by the GLWE encryption primitives.
+///
+/// On top of the native modulus and power of 2 moduli, the prime moduli supported by the NTT for
+/// the given [`PolynomialSize`] are accepted, so that keys can be generated for the NTT based
+/// bootstrap, see [`Ntt64::is_supported_modulus`].
+pub(crate) fn assert_glwe_ciphertext_modulus_is_supported<Scalar: UnsignedInteger>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    polynomial_size: PolynomialSize,
+) {
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus()
+            || ciphertext_modulus
+                .try_to::<u64>()
+                .is_ok_and(|modulus| Ntt64::is_supported_modulus(polynomial_size, modulus)),
+        "Unsupported ciphertext modulus {ciphertext_modulus:?} for {polynomial_size:?}, \
+        expected the native modulus, a power of 2 modulus or a prime modulus supported by the NTT"
+    );
+}
 
 /// Convenience function to share the core logic of the GLWE assign encryption between all functions
 /// needing it.
@@ -18,7 +39,7 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<KeyCont, BodyCont, MaskCont
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_mask: &mut GlweMask<MaskCont>,
     output_body: &mut GlweBody<BodyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -37,7 +58,7 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<KeyCont, BodyCont, MaskCont
 
     let ciphertext_modulus = output_body.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+    assert_glwe_ciphertext_modulus_is_supported(ciphertext_modulus, output_body.polynomial_size());
 
     generator.fill_slice_with_random_mask_custom_mod(output_mask.as_mut(), ciphertext_modulus);
     generator.unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign(
@@ -46,6 +67,16 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<KeyCont, BodyCont, MaskCont
         ciphertext_modulus,
     );
 
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        polynomial_wrapping_add_multisum_assign_custom_mod(
+            &mut output_body.as_mut_polynomial(),
+            &output_mask.as_polynomial_list(),
+            &glwe_secret_key.as_polynomial_list(),
+            ciphertext_modulus.get_custom_modulus().cast_into(),
+        );
+        return;
+    }
+
     if !ciphertext_modulus.is_native_modulus() {
         let torus_scaling = ciphertext_modulus.get_power_of_two_scaling_to_native_torus();
         slice_wrapping_scalar_mul_assign(output_mask.as_mut(), torus_scaling);
@@ -136,7 +167,7 @@ pub fn fill_glwe_mask_and_body_for_encryption_assign<KeyCont, BodyCont, MaskCont
 pub fn encrypt_glwe_ciphertext_assign<Scalar, KeyCont, OutputCont, Gen>(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut GlweCiphertext<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -183,7 +214,7 @@ pub fn encrypt_seeded_glwe_ciphertext_assign_with_existing_generator<
 >(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGlweCiphertext<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -235,7 +266,7 @@ pub fn fill_glwe_mask_and_body_for_encryption<KeyCont, InputCont, BodyCont, Mask
     output_mask: &mut GlweMask<MaskCont>,
     output_body: &mut GlweBody<BodyCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -252,7 +283,7 @@ pub fn fill_glwe_mask_and_body_for_encryption<KeyCont, InputCont, BodyCont, Mask
 
     let ciphertext_modulus = output_body.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+    assert_glwe_ciphertext_modulus_is_supported(ciphertext_modulus, output_body.polynomial_size());
 
     generator.fill_slice_with_random_mask_custom_mod(output_mask.as_mut(), ciphertext_modulus);
     generator.fill_slice_with_random_noise_custom_mod(
@@ -261,6 +292,23 @@ pub fn fill_glwe_mask_and_body_for_encryption<KeyCont, InputCont, BodyCont, Mask
         ciphertext_modulus,
     );
 
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        let ciphertext_modulus_as_scalar: Scalar =
+            ciphertext_modulus.get_custom_modulus().cast_into();
+        slice_wrapping_add_assign_custom_mod(
+            output_body.as_mut(),
+            encoded.as_ref(),
+            ciphertext_modulus_as_scalar,
+        );
+        polynomial_wrapping_add_multisum_assign_custom_mod(
+            &mut output_body.as_mut_polynomial(),
+            &output_mask.as_polynomial_list(),
+            &glwe_secret_key.as_polynomial_list(),
+            ciphertext_modulus_as_scalar,
+        );
+        return;
+    }
+
     polynomial_wrapping_add_assign(
         &mut output_body.as_mut_polynomial(),
         &encoded.as_polynomial(),
@@ -356,7 +404,7 @@ pub fn encrypt_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont, Gen>(
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -485,7 +533,7 @@ pub fn encrypt_glwe_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont, Gen>
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_glwe_ciphertext_list: &mut GlweCiphertextList<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -494,6 +542,7 @@ pub fn encrypt_glwe_ciphertext_list<Scalar, KeyCont, InputCont, OutputCont, Gen>
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output_glwe_ciphertext_list.polynomial_size().0
             * output_glwe_ciphertext_list.glwe_ciphertext_count().0
@@ -576,12 +625,26 @@ pub fn decrypt_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont>(
 
     let ciphertext_modulus = input_glwe_ciphertext.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+    assert_glwe_ciphertext_modulus_is_supported(
+        ciphertext_modulus,
+        input_glwe_ciphertext.polynomial_size(),
+    );
 
     let (mask, body) = input_glwe_ciphertext.get_mask_and_body();
     output_plaintext_list
         .as_mut()
         .copy_from_slice(body.as_ref());
+
+    if !ciphertext_modulus.is_compatible_with_native_modulus() {
+        polynomial_wrapping_sub_multisum_assign_custom_mod(
+            &mut output_plaintext_list.as_mut_polynomial(),
+            &mask.as_polynomial_list(),
+            &glwe_secret_key.as_polynomial_list(),
+            ciphertext_modulus.get_custom_modulus().cast_into(),
+        );
+        return;
+    }
+
     polynomial_wrapping_sub_multisum_assign(
         &mut output_plaintext_list.as_mut_polynomial(),
         &mask.as_polynomial_list(),
@@ -726,9 +789,9 @@ pub fn trivially_encrypt_glwe_ciphertext<Scalar, InputCont, OutputCont>(
 
     let ciphertext_modulus = body.ciphertext_modulus();
 
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+    assert_glwe_ciphertext_modulus_is_supported(ciphertext_modulus, body.polynomial_size());
 
-    if !ciphertext_modulus.is_native_modulus() {
+    if ciphertext_modulus.is_non_native_power_of_two() {
         slice_wrapping_scalar_mul_assign(
             body.as_mut(),
             ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
@@ -809,17 +872,17 @@ where
     Scalar: UnsignedTorus,
     InputCont: Container<Element = Scalar>,
 {
-    assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-
     let polynomial_size = PolynomialSize(encoded.plaintext_count().0);
 
+    assert_glwe_ciphertext_modulus_is_supported(ciphertext_modulus, polynomial_size);
+
     let mut new_ct =
         GlweCiphertextOwned::new(Scalar::ZERO, glwe_size, polynomial_size, ciphertext_modulus);
 
     let mut body = new_ct.get_mut_body();
     body.as_mut().copy_from_slice(encoded.as_ref());
 
-    if !ciphertext_modulus.is_native_modulus() {
+    if ciphertext_modulus.is_non_native_power_of_two() {
         slice_wrapping_scalar_mul_assign(
             body.as_mut(),
             ciphertext_modulus.get_power_of_two_scaling_to_native_torus(),
@@ -841,7 +904,7 @@ pub fn encrypt_seeded_glwe_ciphertext_with_existing_generator<
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_glwe_ciphertext: &mut SeededGlweCiphertext<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -973,7 +1036,7 @@ pub fn encrypt_seeded_glwe_ciphertext<Scalar, KeyCont, InputCont, OutputCont, No
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output_glwe_ciphertext: &mut SeededGlweCiphertext<OutputCont>,
     input_plaintext_list: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1014,7 +1077,7 @@ pub fn encrypt_seeded_glwe_ciphertext_list_with_existing_generator<
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGlweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -1023,6 +1086,7 @@ pub fn encrypt_seeded_glwe_ciphertext_list_with_existing_generator<
     InputCont: Container<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.glwe_size().to_glwe_dimension() == glwe_secret_key.glwe_dimension(),
         "Mismatched GlweDimension between input GlweSecretKey {:?} and output \
@@ -1155,7 +1219,7 @@ pub fn encrypt_seeded_glwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCon
     glwe_secret_key: &GlweSecretKey<KeyCont>,
     output: &mut SeededGlweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -0,0 +1,628 @@
+//! Module containing primitives pertaining to [`GLWE ciphertext
+//! keyswitch`](`GlweKeyswitchKey#glwe-keyswitch`).
+
+use crate::core_crypto::algorithms::polynomial_algorithms::{
+    polynomial_wrapping_monic_monomial_mul_assign, polynomial_wrapping_sub_mul_assign,
+};
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use rayon::prelude::*;
+
+/// Keyswitch a [`GLWE ciphertext`](`GlweCiphertext`) encrypted under a
+/// [`GLWE secret key`](`GlweSecretKey`) to another [`GLWE secret key`](`GlweSecretKey`).
+///
+/// The [`GlweDimension`] of the input and output [`GLWE secret keys`](`GlweSecretKey`) may differ
+/// which allows to change the GLWE dimension of a ciphertext, e.g. after packing. When the input
+/// ciphertext and the keyswitching key have different [`PolynomialSize`], the ciphertext is first
+/// mapped to the ring of the output key, see [`ring switching`](`GlweKeyswitchKey#ring-switching`).
+///
+/// # Formal Definition
+///
+/// See [`GLWE keyswitch key`](`GlweKeyswitchKey#glwe-keyswitch`).
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweKeyswitchKey creation
+/// let input_glwe_dimension = GlweDimension(2);
+/// let output_glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKeys
+/// let input_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     input_glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     output_glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+///     &input_glwe_secret_key,
+///     &output_glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext
+/// let mut input_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// input_plaintext_list
+///     .iter_mut()
+///     .enumerate()
+///     .for_each(|(idx, dst)| *dst.0 = (idx as u64 % 16) << 60);
+///
+/// // Create a new GlweCiphertext
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     input_glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &input_glwe_secret_key,
+///     &mut input_glwe,
+///     &input_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     output_glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// keyswitch_glwe_ciphertext(&ksk, &input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(
+///     &output_glwe_secret_key,
+///     &output_glwe,
+///     &mut decrypted_plaintext_list,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+/// input_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = *x.0 >> 60);
+///
+/// // Check we recovered the original message
+/// assert_eq!(input_plaintext_list, decrypted_plaintext_list);
+/// ```
+pub fn keyswitch_glwe_ciphertext<Scalar, KSKCont, InputCont, OutputCont>(
+    glwe_keyswitch_key: &GlweKeyswitchKey<KSKCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KSKCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    if input_glwe_ciphertext.polynomial_size() != glwe_keyswitch_key.polynomial_size() {
+        let ring_switched_input_glwe_ciphertext = ring_switch_glwe_ciphertext(
+            input_glwe_ciphertext,
+            glwe_keyswitch_key.polynomial_size(),
+        );
+        return keyswitch_glwe_ciphertext(
+            glwe_keyswitch_key,
+            &ring_switched_input_glwe_ciphertext,
+            output_glwe_ciphertext,
+        );
+    }
+
+    check_glwe_keyswitch_inputs(
+        glwe_keyswitch_key,
+        input_glwe_ciphertext,
+        output_glwe_ciphertext,
+    );
+
+    // We reset the output
+    output_glwe_ciphertext.as_mut().fill(Scalar::ZERO);
+    output_glwe_ciphertext
+        .get_mut_body()
+        .as_mut()
+        .copy_from_slice(input_glwe_ciphertext.get_body().as_ref());
+
+    // We instantiate a decomposer
+    let decomposer = SignedDecomposer::new(
+        glwe_keyswitch_key.decomposition_base_log(),
+        glwe_keyswitch_key.decomposition_level_count(),
+    );
+
+    // One polynomial per level holding the decomposition of an input mask polynomial
+    let mut decomposition_buffer = PolynomialList::new(
+        Scalar::ZERO,
+        glwe_keyswitch_key.polynomial_size(),
+        PolynomialCount(glwe_keyswitch_key.decomposition_level_count().0),
+    );
+
+    for (keyswitch_key_block, input_mask_polynomial) in glwe_keyswitch_key
+        .iter()
+        .zip(input_glwe_ciphertext.get_mask().as_polynomial_list().iter())
+    {
+        decompose_polynomial(
+            &decomposer,
+            &input_mask_polynomial,
+            &mut decomposition_buffer,
+        );
+
+        // Loop over the number of levels:
+        // We compute the multiplication of a ciphertext from the keyswitching key with a
+        // decomposed polynomial and subtract it to the output
+        for (level_key_ciphertext, decomposed_polynomial) in
+            keyswitch_key_block.iter().zip(decomposition_buffer.iter())
+        {
+            for (mut output_polynomial, key_polynomial) in output_glwe_ciphertext
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(level_key_ciphertext.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_sub_mul_assign(
+                    &mut output_polynomial,
+                    &key_polynomial,
+                    &decomposed_polynomial,
+                );
+            }
+        }
+    }
+}
+
+/// Parallel variant of [`keyswitch_glwe_ciphertext`].
+///
+/// The decomposition of the input mask is computed in parallel over the input polynomials and
+/// the output polynomials are then computed in parallel.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweKeyswitchKey creation
+/// let input_glwe_dimension = GlweDimension(2);
+/// let output_glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKeys
+/// let input_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     input_glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     output_glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+///     &input_glwe_secret_key,
+///     &output_glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the plaintext
+/// let mut input_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// input_plaintext_list
+///     .iter_mut()
+///     .enumerate()
+///     .for_each(|(idx, dst)| *dst.0 = (idx as u64 % 16) << 60);
+///
+/// // Create a new GlweCiphertext
+/// let mut input_glwe = GlweCiphertext::new(
+///     0u64,
+///     input_glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_glwe_ciphertext(
+///     &input_glwe_secret_key,
+///     &mut input_glwe,
+///     &input_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut output_glwe = GlweCiphertext::new(
+///     0u64,
+///     output_glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// par_keyswitch_glwe_ciphertext(&ksk, &input_glwe, &mut output_glwe);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(
+///     &output_glwe_secret_key,
+///     &output_glwe,
+///     &mut decrypted_plaintext_list,
+/// );
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// decrypted_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = decomposer.closest_representable(*x.0) >> 60);
+/// input_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = *x.0 >> 60);
+///
+/// // Check we recovered the original message
+/// assert_eq!(input_plaintext_list, decrypted_plaintext_list);
+/// ```
+pub fn par_keyswitch_glwe_ciphertext<Scalar, KSKCont, InputCont, OutputCont>(
+    glwe_keyswitch_key: &GlweKeyswitchKey<KSKCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger + Send + Sync,
+    KSKCont: Container<Element = Scalar> + Sync,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    if input_glwe_ciphertext.polynomial_size() != glwe_keyswitch_key.polynomial_size() {
+        let ring_switched_input_glwe_ciphertext = ring_switch_glwe_ciphertext(
+            input_glwe_ciphertext,
+            glwe_keyswitch_key.polynomial_size(),
+        );
+        return par_keyswitch_glwe_ciphertext(
+            glwe_keyswitch_key,
+            &ring_switched_input_glwe_ciphertext,
+            output_glwe_ciphertext,
+        );
+    }
+
+    check_glwe_keyswitch_inputs(
+        glwe_keyswitch_key,
+        input_glwe_ciphertext,
+        output_glwe_ciphertext,
+    );
+
+    let level_count = glwe_keyswitch_key.decomposition_level_count();
+
+    // We reset the output
+    output_glwe_ciphertext.as_mut().fill(Scalar::ZERO);
+    output_glwe_ciphertext
+        .get_mut_body()
+        .as_mut()
+        .copy_from_slice(input_glwe_ciphertext.get_body().as_ref());
+
+    // We instantiate a decomposer
+    let decomposer =
+        SignedDecomposer::new(glwe_keyswitch_key.decomposition_base_log(), level_count);
+
+    // level_count polynomials per input mask polynomial
+    let mut decomposition_buffer = PolynomialList::new(
+        Scalar::ZERO,
+        glwe_keyswitch_key.polynomial_size(),
+        PolynomialCount(glwe_keyswitch_key.input_key_glwe_dimension().0 * level_count.0),
+    );
+
+    let input_mask = input_glwe_ciphertext.get_mask();
+    let input_mask_polynomials = input_mask.as_polynomial_list();
+
+    decomposition_buffer
+        .par_chunks_exact_mut(level_count.0)
+        .zip(input_mask_polynomials.par_iter())
+        .for_each(|(mut decomposition_chunk, input_mask_polynomial)| {
+            decompose_polynomial(
+                &decomposer,
+                &input_mask_polynomial,
+                &mut decomposition_chunk,
+            );
+        });
+
+    // Each output polynomial only depends on the matching polynomials of the keyswitching key
+    // ciphertexts, so they can be computed independently
+    output_glwe_ciphertext
+        .as_mut_polynomial_list()
+        .par_iter_mut()
+        .enumerate()
+        .for_each(|(output_polynomial_index, mut output_polynomial)| {
+            for (keyswitch_key_block, decomposition_chunk) in glwe_keyswitch_key
+                .iter()
+                .zip(decomposition_buffer.chunks_exact(level_count.0))
+            {
+                for (level_key_ciphertext, decomposed_polynomial) in
+                    keyswitch_key_block.iter().zip(decomposition_chunk.iter())
+                {
+                    let key_polynomial_list = level_key_ciphertext.as_polynomial_list();
+                    let key_polynomial = key_polynomial_list.get(output_polynomial_index);
+                    polynomial_wrapping_sub_mul_assign(
+                        &mut output_polynomial,
+                        &key_polynomial,
+                        &decomposed_polynomial,
+                    );
+                }
+            }
+        });
+}
+
+fn check_glwe_keyswitch_inputs<Scalar, KSKCont, InputCont, OutputCont>(
+    glwe_keyswitch_key: &GlweKeyswitchKey<KSKCont>,
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    KSKCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: Container<Element = Scalar>,
+{
+    assert!(
+        glwe_keyswitch_key.input_key_glwe_dimension()
+            == input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        "Mismatched input GlweDimension. \
+        GlweKeyswitchKey input GlweDimension: {:?}, input GlweCiphertext GlweDimension {:?}.",
+        glwe_keyswitch_key.input_key_glwe_dimension(),
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension()
+    );
+    assert!(
+        glwe_keyswitch_key.output_key_glwe_dimension()
+            == output_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        "Mismatched output GlweDimension. \
+        GlweKeyswitchKey output GlweDimension: {:?}, output GlweCiphertext GlweDimension {:?}.",
+        glwe_keyswitch_key.output_key_glwe_dimension(),
+        output_glwe_ciphertext.glwe_size().to_glwe_dimension()
+    );
+    assert!(
+        glwe_keyswitch_key.polynomial_size() == input_glwe_ciphertext.polynomial_size(),
+        "Mismatched input PolynomialSize. \
+        GlweKeyswitchKey PolynomialSize: {:?}, input GlweCiphertext PolynomialSize {:?}.",
+        glwe_keyswitch_key.polynomial_size(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        glwe_keyswitch_key.polynomial_size() == output_glwe_ciphertext.polynomial_size(),
+        "Mismatched output PolynomialSize. \
+        GlweKeyswitchKey PolynomialSize: {:?}, output GlweCiphertext PolynomialSize {:?}.",
+        glwe_keyswitch_key.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        glwe_keyswitch_key.ciphertext_modulus() == input_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        GlweKeyswitchKey CiphertextModulus: {:?}, input GlweCiphertext CiphertextModulus {:?}.",
+        glwe_keyswitch_key.ciphertext_modulus(),
+        input_glwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        glwe_keyswitch_key.ciphertext_modulus() == output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        GlweKeyswitchKey CiphertextModulus: {:?}, output GlweCiphertext CiphertextModulus {:?}.",
+        glwe_keyswitch_key.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        glwe_keyswitch_key
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+}
+
+/// Write the signed decomposition of each coefficient of `input` in `output`, the polynomial at
+/// index `i` of `output` storing the terms of the `i`-th level yielded by the decomposer.
+pub(crate) fn decompose_polynomial<Scalar, InputCont, OutputCont>(
+    decomposer: &SignedDecomposer<Scalar>,
+    input: &Polynomial<InputCont>,
+    output: &mut PolynomialList<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    for (coeff_idx, &input_coeff) in input.as_ref().iter().enumerate() {
+        let rounded = decomposer.closest_representable(input_coeff);
+        for (mut level_polynomial, term) in output.iter_mut().zip(decomposer.decompose(rounded)) {
+            level_polynomial.as_mut()[coeff_idx] = term.value();
+        }
+    }
+}
+
+/// Return the [`GLWE secret key`](`GlweSecretKey`) in the ring of degree `output_poly_size` under
+/// which the ciphertexts mapped by [`ring_switch_glwe_ciphertext`] are encrypted, see
+/// [`ring switching`](`GlweKeyswitchKey#ring-switching`).
+pub(crate) fn ring_switch_glwe_secret_key<Scalar, InputCont>(
+    input_glwe_secret_key: &GlweSecretKey<InputCont>,
+    output_poly_size: PolynomialSize,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let input_glwe_dimension = input_glwe_secret_key.glwe_dimension();
+    let input_poly_size = input_glwe_secret_key.polynomial_size();
+    let output_glwe_dimension = glwe_keyswitch_key_input_key_glwe_dimension(
+        input_glwe_dimension,
+        input_poly_size,
+        output_poly_size,
+    );
+    // The input key polynomials are mapped one by one to chunks of output key polynomials
+    let output_chunk_len = output_glwe_dimension.0 / input_glwe_dimension.0 * output_poly_size.0;
+
+    let mut output_glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, output_glwe_dimension, output_poly_size);
+
+    for (input_polynomial, output_polynomials) in input_glwe_secret_key
+        .as_ref()
+        .chunks_exact(input_poly_size.0)
+        .zip(
+            output_glwe_secret_key
+                .as_mut()
+                .chunks_exact_mut(output_chunk_len),
+        )
+    {
+        if input_poly_size.0 >= output_poly_size.0 {
+            let ratio = input_poly_size.0 / output_poly_size.0;
+            for (component_idx, output_polynomial) in output_polynomials
+                .chunks_exact_mut(output_poly_size.0)
+                .enumerate()
+            {
+                // The key components are ordered as S_0, S_{r-1}, ..., S_1 to match the mask
+                // components A_0, Y * A_1, ..., Y * A_{r-1}
+                copy_subring_component(
+                    input_polynomial,
+                    (ratio - component_idx) % ratio,
+                    output_polynomial,
+                );
+            }
+        } else {
+            embed_into_larger_ring(input_polynomial, output_polynomials);
+        }
+    }
+
+    output_glwe_secret_key
+}
+
+/// Map a [`GLWE ciphertext`](`GlweCiphertext`) to the ring of degree `output_poly_size`, see
+/// [`ring switching`](`GlweKeyswitchKey#ring-switching`).
+pub(crate) fn ring_switch_glwe_ciphertext<Scalar, InputCont>(
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_poly_size: PolynomialSize,
+) -> GlweCiphertextOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let input_poly_size = input_glwe_ciphertext.polynomial_size();
+    let output_glwe_dimension = glwe_keyswitch_key_input_key_glwe_dimension(
+        input_glwe_ciphertext.glwe_size().to_glwe_dimension(),
+        input_poly_size,
+        output_poly_size,
+    );
+
+    let mut output_glwe_ciphertext = GlweCiphertext::new(
+        Scalar::ZERO,
+        output_glwe_dimension.to_glwe_size(),
+        output_poly_size,
+        input_glwe_ciphertext.ciphertext_modulus(),
+    );
+
+    if input_poly_size.0 >= output_poly_size.0 {
+        let ratio = input_poly_size.0 / output_poly_size.0;
+        let output_chunk_len = ratio * output_poly_size.0;
+        let (input_mask, input_body) = input_glwe_ciphertext.get_mask_and_body();
+        let (mut output_mask, mut output_body) = output_glwe_ciphertext.get_mut_mask_and_body();
+
+        for (input_polynomial, output_polynomials) in input_mask
+            .as_ref()
+            .chunks_exact(input_poly_size.0)
+            .zip(output_mask.as_mut().chunks_exact_mut(output_chunk_len))
+        {
+            for (component_idx, output_polynomial) in output_polynomials
+                .chunks_exact_mut(output_poly_size.0)
+                .enumerate()
+            {
+                copy_subring_component(input_polynomial, component_idx, output_polynomial);
+                if component_idx != 0 {
+                    // Y = X^ratio is the monomial of degree 1 of the output ring
+                    polynomial_wrapping_monic_monomial_mul_assign(
+                        &mut Polynomial::from_container(output_polynomial),
+                        MonomialDegree(1),
+                    );
+                }
+            }
+        }
+
+        copy_subring_component(input_body.as_ref(), 0, output_body.as_mut());
+    } else {
+        for (input_polynomial, output_polynomial) in input_glwe_ciphertext
+            .as_ref()
+            .chunks_exact(input_poly_size.0)
+            .zip(
+                output_glwe_ciphertext
+                    .as_mut()
+                    .chunks_exact_mut(output_poly_size.0),
+            )
+        {
+            embed_into_larger_ring(input_polynomial, output_polynomial);
+        }
+    }
+
+    output_glwe_ciphertext
+}
+
+// Write in `output` the component of `input` on X^component_idx in its decomposition over the
+// subring generated by Y = X^ratio, i.e. the coefficients of `input` whose index is congruent to
+// component_idx modulo the ratio of the polynomial sizes.
+fn copy_subring_component<Scalar: Copy>(
+    input: &[Scalar],
+    component_idx: usize,
+    output: &mut [Scalar],
+) {
+    let ratio = input.len() / output.len();
+    for (dst, &src) in output
+        .iter_mut()
+        .zip(input.iter().skip(component_idx).step_by(ratio))
+    {
+        *dst = src;
+    }
+}
+
+// Write in `output` the image of `input` by the ring morphism X -> Y^ratio, where ratio is the
+// ratio of the polynomial sizes.
+fn embed_into_larger_ring<Scalar: UnsignedInteger>(input: &[Scalar], output: &mut [Scalar]) {
+    let ratio = output.len() / input.len();
+    output.fill(Scalar::ZERO);
+    for (dst, &src) in output.iter_mut().step_by(ratio).zip(input.iter()) {
+        *dst = src;
+    }
+}
@@ -0,0 +1,151 @@
+//! Module containing primitives pertaining to [`GLWE
+//! relinearization`](`GlweRelinearizationKey#glwe-relinearization`).
+
+use crate::core_crypto::algorithms::glwe_keyswitch::decompose_polynomial;
+use crate::core_crypto::algorithms::glwe_tensor_product_output_glwe_size;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_wrapping_sub_mul_assign;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Relinearize the output of a [`GLWE tensor product`](`super::glwe_tensor_product`) to get a
+/// [`GLWE ciphertext`](`GlweCiphertext`) encrypted under the original
+/// [`GLWE secret key`](`GlweSecretKey`).
+///
+/// The input is expected to be laid out as the output of
+/// [`glwe_tensor_product`](`super::glwe_tensor_product`), the [`GlweRelinearizationKey`] must have
+/// been generated for the [`GLWE secret key`](`GlweSecretKey`) the tensored ciphertexts were
+/// encrypted under.
+///
+/// # Formal Definition
+///
+/// See [`GLWE relinearization key`](`GlweRelinearizationKey#glwe-relinearization`).
+///
+/// # Example
+///
+/// See [`glwe_tensor_product`](`super::glwe_tensor_product`).
+pub fn glwe_relinearization<Scalar, InputCont, OutputCont, RlkCont>(
+    input_glwe_ciphertext: &GlweCiphertext<InputCont>,
+    output_glwe_ciphertext: &mut GlweCiphertext<OutputCont>,
+    glwe_relinearization_key: &GlweRelinearizationKey<RlkCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    RlkCont: Container<Element = Scalar>,
+{
+    assert!(
+        glwe_relinearization_key.glwe_size() == output_glwe_ciphertext.glwe_size(),
+        "Mismatched output GlweSize. \
+        GlweRelinearizationKey GlweSize: {:?}, output GlweCiphertext GlweSize {:?}.",
+        glwe_relinearization_key.glwe_size(),
+        output_glwe_ciphertext.glwe_size()
+    );
+    assert!(
+        glwe_tensor_product_output_glwe_size(glwe_relinearization_key.glwe_size())
+            == input_glwe_ciphertext.glwe_size(),
+        "Mismatched input GlweSize. \
+        Expected tensored GlweSize: {:?}, input GlweCiphertext GlweSize {:?}.",
+        glwe_tensor_product_output_glwe_size(glwe_relinearization_key.glwe_size()),
+        input_glwe_ciphertext.glwe_size()
+    );
+    assert!(
+        glwe_relinearization_key.polynomial_size() == input_glwe_ciphertext.polynomial_size(),
+        "Mismatched input PolynomialSize. \
+        GlweRelinearizationKey PolynomialSize: {:?}, input GlweCiphertext PolynomialSize {:?}.",
+        glwe_relinearization_key.polynomial_size(),
+        input_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        glwe_relinearization_key.polynomial_size() == output_glwe_ciphertext.polynomial_size(),
+        "Mismatched output PolynomialSize. \
+        GlweRelinearizationKey PolynomialSize: {:?}, output GlweCiphertext PolynomialSize {:?}.",
+        glwe_relinearization_key.polynomial_size(),
+        output_glwe_ciphertext.polynomial_size()
+    );
+    assert!(
+        glwe_relinearization_key.ciphertext_modulus() == input_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        GlweRelinearizationKey CiphertextModulus: {:?}, input GlweCiphertext CiphertextModulus {:?}.",
+        glwe_relinearization_key.ciphertext_modulus(),
+        input_glwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        glwe_relinearization_key.ciphertext_modulus()
+            == output_glwe_ciphertext.ciphertext_modulus(),
+        "Mismatched CiphertextModulus. \
+        GlweRelinearizationKey CiphertextModulus: {:?}, output GlweCiphertext CiphertextModulus {:?}.",
+        glwe_relinearization_key.ciphertext_modulus(),
+        output_glwe_ciphertext.ciphertext_modulus()
+    );
+    assert!(
+        glwe_relinearization_key
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+
+    let glwe_dimension = glwe_relinearization_key.glwe_dimension();
+    let input_polynomials = input_glwe_ciphertext.as_polynomial_list();
+
+    // The linear terms of the tensored ciphertext are already encrypted under the key, so they
+    // form the output mask, the body is kept as is
+    output_glwe_ciphertext
+        .get_mut_mask()
+        .as_mut()
+        .copy_from_slice(
+            &input_polynomials.as_ref()
+                [..glwe_dimension.0 * glwe_relinearization_key.polynomial_size().0],
+        );
+    output_glwe_ciphertext
+        .get_mut_body()
+        .as_mut()
+        .copy_from_slice(input_glwe_ciphertext.get_body().as_ref());
+
+    // We instantiate a decomposer
+    let decomposer = SignedDecomposer::new(
+        glwe_relinearization_key.decomposition_base_log(),
+        glwe_relinearization_key.decomposition_level_count(),
+    );
+
+    // One polynomial per level holding the decomposition of a quadratic term polynomial
+    let mut decomposition_buffer = PolynomialList::new(
+        Scalar::ZERO,
+        glwe_relinearization_key.polynomial_size(),
+        PolynomialCount(glwe_relinearization_key.decomposition_level_count().0),
+    );
+
+    // The quadratic terms come after the linear ones and are stored in the same order as the key
+    // products in the relinearization key
+    for (relinearization_key_block, quadratic_term_polynomial) in glwe_relinearization_key
+        .iter()
+        .zip(input_polynomials.iter().skip(glwe_dimension.0))
+    {
+        decompose_polynomial(
+            &decomposer,
+            &quadratic_term_polynomial,
+            &mut decomposition_buffer,
+        );
+
+        // Loop over the number of levels:
+        // We compute the multiplication of a ciphertext from the relinearization key with a
+        // decomposed polynomial and subtract it to the output
+        for (level_key_ciphertext, decomposed_polynomial) in relinearization_key_block
+            .iter()
+            .zip(decomposition_buffer.iter())
+        {
+            for (mut output_polynomial, key_polynomial) in output_glwe_ciphertext
+                .as_mut_polynomial_list()
+                .iter_mut()
+                .zip(level_key_ciphertext.as_polynomial_list().iter())
+            {
+                polynomial_wrapping_sub_mul_assign(
+                    &mut output_polynomial,
+                    &key_polynomial,
+                    &decomposed_polynomial,
+                );
+            }
+        }
+    }
+}
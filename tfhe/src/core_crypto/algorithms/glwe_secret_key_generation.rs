@@ -1,9 +1,14 @@
 //! Module containing primitives pertaining to the generation of
 //! [`GLWE secret keys`](`GlweSecretKey`).
+//!
+//! Non-binary keys store negative coefficients using their wrapping representation (e.g. $-1$
+//! is stored as `Scalar::MAX`), which is compatible with the native modulus and power of 2
+//! ciphertext moduli.
 
+use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
-use crate::core_crypto::commons::numeric::Numeric;
+use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary, UniformTernary};
+use crate::core_crypto::commons::numeric::{CastFrom, Numeric, UnsignedInteger};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -69,3 +74,369 @@ pub fn generate_binary_glwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(glwe_secret_key.as_mut());
 }
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with uniformly random ternary
+/// coefficients.
+///
+/// The coefficient $-1$ is stored using its wrapping representation, i.e. `Scalar::MAX`.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let glwe_secret_key: GlweSecretKeyOwned<u64> =
+///     allocate_and_generate_new_ternary_glwe_secret_key(
+///         glwe_dimension,
+///         polynomial_size,
+///         &mut secret_generator,
+///     );
+///
+/// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+/// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+/// // Check all coefficients are in {-1, 0, 1}
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// ```
+pub fn allocate_and_generate_new_ternary_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformTernary> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_ternary_glwe_secret_key(&mut glwe_secret_key, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with uniformly random ternary coefficients.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_ternary_glwe_secret_key(&mut glwe_secret_key, &mut secret_generator);
+///
+/// // Check all coefficients are in {-1, 0, 1}
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// ```
+pub fn generate_ternary_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformTernary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_uniform_ternary(glwe_secret_key.as_mut());
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with integer coefficients
+/// sampled from a rounded gaussian distribution centered in 0.
+///
+/// See [`generate_gaussian_glwe_secret_key`] for details about the distribution.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let key_std_dev = StandardDev(3.2);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let glwe_secret_key: GlweSecretKeyOwned<u64> =
+///     allocate_and_generate_new_gaussian_glwe_secret_key(
+///         glwe_dimension,
+///         polynomial_size,
+///         key_std_dev,
+///         &mut secret_generator,
+///     );
+///
+/// assert_eq!(glwe_secret_key.glwe_dimension(), glwe_dimension);
+/// assert_eq!(glwe_secret_key.polynomial_size(), polynomial_size);
+/// // Check all coefficients are small integers, a 3.2 standard deviation never yields values
+/// // outside of [-64, 64] in practice
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| (elt as i64).abs() <= 64));
+/// ```
+pub fn allocate_and_generate_new_gaussian_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    std_dev: impl DispersionParameter,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger + CastFrom<i64>,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_gaussian_glwe_secret_key(&mut glwe_secret_key, std_dev, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with integer coefficients sampled from a rounded
+/// gaussian distribution centered in 0.
+///
+/// Unlike encryption noise, the standard deviation is not normalized by the ciphertext modulus:
+/// `std_dev.get_standard_dev()` is directly the standard deviation of the integer coefficients.
+/// Negative coefficients are stored using their wrapping representation.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let key_std_dev = StandardDev(3.2);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_gaussian_glwe_secret_key(&mut glwe_secret_key, key_std_dev, &mut secret_generator);
+///
+/// // Check all coefficients are small integers, a 3.2 standard deviation never yields values
+/// // outside of [-64, 64] in practice
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| (elt as i64).abs() <= 64));
+/// ```
+pub fn generate_gaussian_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    std_dev: impl DispersionParameter,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger + CastFrom<i64>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_gaussian_integers(
+        glwe_secret_key.as_mut(),
+        std_dev.get_standard_dev(),
+    );
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with binary coefficients with
+/// a fixed [`HammingWeight`].
+pub fn allocate_and_generate_new_sparse_binary_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    hamming_weight: HammingWeight,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_sparse_binary_glwe_secret_key(&mut glwe_secret_key, hamming_weight, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with binary coefficients, exactly
+/// `hamming_weight` of them being set to 1, at uniformly random positions.
+///
+/// The Hamming weight is counted over all the polynomials of the key.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let hamming_weight = HammingWeight(128);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_sparse_binary_glwe_secret_key(
+///     &mut glwe_secret_key,
+///     hamming_weight,
+///     &mut secret_generator,
+/// );
+///
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1));
+/// assert_eq!(
+///     glwe_secret_key
+///         .as_ref()
+///         .iter()
+///         .filter(|&&elt| elt != 0)
+///         .count(),
+///     hamming_weight.0
+/// );
+/// ```
+pub fn generate_sparse_binary_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    hamming_weight: HammingWeight,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_fixed_hamming_weight_binary(
+        glwe_secret_key.as_mut(),
+        hamming_weight.0,
+    );
+}
+
+/// Allocate a new [`GLWE secret key`](`GlweSecretKey`) and fill it with ternary coefficients with
+/// a fixed [`HammingWeight`].
+pub fn allocate_and_generate_new_sparse_ternary_glwe_secret_key<Scalar, Gen>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    hamming_weight: HammingWeight,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> GlweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger + RandomGenerable<UniformBinary>,
+    Gen: ByteRandomGenerator,
+{
+    let mut glwe_secret_key =
+        GlweSecretKeyOwned::new_empty_key(Scalar::ZERO, glwe_dimension, polynomial_size);
+
+    generate_sparse_ternary_glwe_secret_key(&mut glwe_secret_key, hamming_weight, generator);
+
+    glwe_secret_key
+}
+
+/// Fill a [`GLWE secret key`](`GlweSecretKey`) with ternary coefficients, exactly
+/// `hamming_weight` of them being non-zero, at uniformly random positions and with uniformly
+/// random signs.
+///
+/// The Hamming weight is counted over all the polynomials of the key.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweSecretKey creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(1024);
+/// let hamming_weight = HammingWeight(128);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut glwe_secret_key =
+///     GlweSecretKey::new_empty_key(0u64, glwe_size.to_glwe_dimension(), polynomial_size);
+///
+/// generate_sparse_ternary_glwe_secret_key(
+///     &mut glwe_secret_key,
+///     hamming_weight,
+///     &mut secret_generator,
+/// );
+///
+/// assert!(glwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// assert_eq!(
+///     glwe_secret_key
+///         .as_ref()
+///         .iter()
+///         .filter(|&&elt| elt != 0)
+///         .count(),
+///     hamming_weight.0
+/// );
+/// ```
+pub fn generate_sparse_ternary_glwe_secret_key<Scalar, InCont, Gen>(
+    glwe_secret_key: &mut GlweSecretKey<InCont>,
+    hamming_weight: HammingWeight,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger + RandomGenerable<UniformBinary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_fixed_hamming_weight_ternary(
+        glwe_secret_key.as_mut(),
+        hamming_weight.0,
+    );
+}
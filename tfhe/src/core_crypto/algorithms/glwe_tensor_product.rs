@@ -0,0 +1,613 @@
+//! Module containing primitives pertaining to the GLWE tensor product.
+//!
+//! The tensor product is the first step of a leveled multiplication of two
+//! [`GLWE ciphertexts`](`GlweCiphertext`), see
+//! [`GLWE relinearization`](`GlweRelinearizationKey#glwe-relinearization`) for the second step.
+
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use crate::core_crypto::fft_impl::fft64::math::polynomial::{
+    FourierPolynomialMutView, FourierPolynomialView,
+};
+use aligned_vec::CACHELINE_ALIGN;
+use concrete_fft::c64;
+use concrete_fft::fft128::f128;
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+
+/// Return the [`GlweSize`] of the output of a [`glwe_tensor_product`] for input
+/// [`GLWE ciphertexts`](`GlweCiphertext`) of [`GlweSize`] `input_glwe_size`.
+///
+/// For an input [`GlweDimension`] $k$ the output has a [`GlweDimension`] of $k + \frac{k(k+1)}{2}$,
+/// one mask polynomial per secret key polynomial and one per pairwise product of secret key
+/// polynomials.
+pub fn glwe_tensor_product_output_glwe_size(input_glwe_size: GlweSize) -> GlweSize {
+    let input_glwe_dimension = input_glwe_size.to_glwe_dimension().0;
+    GlweDimension(input_glwe_dimension + input_glwe_dimension * (input_glwe_dimension + 1) / 2)
+        .to_glwe_size()
+}
+
+/// Compute the rescaled tensor product of two [`GLWE ciphertexts`](`GlweCiphertext`).
+///
+/// Both inputs must be encrypted under the same [`GLWE secret key`](`GlweSecretKey`), their tensor
+/// product is rescaled by `scaling_factor` and the result is written in `output`.
+///
+/// `scaling_factor` is the factor $\Delta$ used to encode messages in the input plaintexts. The
+/// output decrypts to the product of the input plaintext polynomials divided by $\Delta$, i.e. it
+/// encrypts the product of the input messages with the same encoding, under the tensored key
+/// $\left( S\_0, \cdots, S\_{k-1}, S\_0 \cdot S\_0, S\_1 \cdot S\_0, S\_1 \cdot S\_1, \cdots,
+/// S\_{k-1} \cdot S\_{k-1} \right)$. The result can be brought back under the original
+/// [`GLWE secret key`](`GlweSecretKey`) using
+/// [`glwe_relinearization`](`super::glwe_relinearization`).
+///
+/// The output [`GlweSize`] can be obtained with [`glwe_tensor_product_output_glwe_size`].
+///
+/// The products are computed in the Fourier domain using 64 bits floating point numbers, see
+/// [`glwe_tensor_product_f128`] for a variant computing with 128 bits floating point numbers.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`glwe_tensor_product_mem_optimized`].
+///
+/// # Formal Definition
+///
+/// Given $\mathsf{CT} = \left( A\_0, \cdots, A\_{k-1}, B \right)$ and
+/// $\mathsf{CT}' = \left( A'\_0, \cdots, A'\_{k-1}, B' \right)$ the output is made of, in order:
+/// - for all $0 \le i < k$: $R\_i = \left\lfloor \frac{A\_i \cdot B' + A'\_i \cdot B}{\Delta}
+///   \right\rceil$
+/// - for all $0 \le j \le i < k$: $T\_{i,j} = -\left\lfloor \frac{A\_i \cdot A'\_j + A\_j \cdot
+///   A'\_i}{\Delta} \right\rceil$ if $j < i$ and $T\_{i,i} = -\left\lfloor \frac{A\_i \cdot
+///   A'\_i}{\Delta} \right\rceil$
+/// - the body $\left\lfloor \frac{B \cdot B'}{\Delta} \right\rceil$
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GlweRelinearizationKey creation
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let decomp_base_log = DecompositionBaseLog(15);
+/// let decomp_level_count = DecompositionLevelCount(3);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let rlk = allocate_and_generate_new_glwe_relinearization_key(
+///     &glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // 4 bits of message with one bit of padding
+/// let delta = 1u64 << 59;
+/// let lhs_msg = 3u64;
+/// let rhs_msg = 5u64;
+///
+/// // The lhs encrypts a constant message in all coefficients, the rhs a constant polynomial
+/// let lhs_plaintext_list = PlaintextList::new(lhs_msg * delta, PlaintextCount(polynomial_size.0));
+/// let mut rhs_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+/// *rhs_plaintext_list.get_mut(0).0 = rhs_msg * delta;
+///
+/// let mut lhs = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+/// let mut rhs = lhs.clone();
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut lhs,
+///     &lhs_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut rhs,
+///     &rhs_plaintext_list,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut tensor = GlweCiphertext::new(
+///     0u64,
+///     glwe_tensor_product_output_glwe_size(glwe_dimension.to_glwe_size()),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// glwe_tensor_product(&lhs, &rhs, &mut tensor, delta);
+///
+/// let mut output = GlweCiphertext::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     ciphertext_modulus,
+/// );
+///
+/// glwe_relinearization(&tensor, &mut output, &rlk);
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(polynomial_size.0));
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &output, &mut decrypted_plaintext_list);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 5 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// // Check we recovered the product of the messages
+/// assert!(decrypted_plaintext_list
+///     .iter()
+///     .all(|x| (decomposer.closest_representable(*x.0) >> 59) % 16 == lhs_msg * rhs_msg));
+/// ```
+pub fn glwe_tensor_product<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    scaling_factor: Scalar,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let fft = Fft::new(lhs.polynomial_size());
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        glwe_tensor_product_mem_optimized_requirement(lhs.glwe_size(), lhs.polynomial_size(), fft)
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+
+    glwe_tensor_product_mem_optimized(lhs, rhs, output, scaling_factor, fft, buffers.stack());
+}
+
+/// Memory optimized version of [`glwe_tensor_product`].
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`glwe_tensor_product_mem_optimized_requirement`].
+///
+/// See [`glwe_tensor_product`] for usage.
+pub fn glwe_tensor_product_mem_optimized<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    scaling_factor: Scalar,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    check_glwe_tensor_product_inputs(lhs, rhs, output);
+
+    let fourier_poly_size = lhs.polynomial_size().to_fourier_polynomial_size().0;
+    let ciphertext_modulus = output.ciphertext_modulus();
+    let rescaling_factor = tensor_product_rescaling_factor(ciphertext_modulus, scaling_factor);
+
+    let (mut lhs_fourier, stack) =
+        stack.make_aligned_raw::<c64>(fourier_poly_size * lhs.glwe_size().0, CACHELINE_ALIGN);
+    let (mut rhs_fourier, stack) =
+        stack.make_aligned_raw::<c64>(fourier_poly_size * rhs.glwe_size().0, CACHELINE_ALIGN);
+    let (mut output_fourier, mut stack) =
+        stack.make_aligned_raw::<c64>(fourier_poly_size, CACHELINE_ALIGN);
+
+    for (input, input_fourier) in [
+        (lhs.as_polynomial_list(), &mut *lhs_fourier),
+        (rhs.as_polynomial_list(), &mut *rhs_fourier),
+    ] {
+        for (input_polynomial, fourier) in input
+            .iter()
+            .zip(input_fourier.chunks_exact_mut(fourier_poly_size))
+        {
+            fft.forward_as_torus(
+                FourierPolynomialMutView { data: fourier },
+                input_polynomial,
+                stack.rb_mut(),
+            );
+        }
+    }
+
+    let output_fourier = &mut *output_fourier;
+
+    for ((lhs_idx, rhs_idx, negate), output_polynomial) in
+        tensor_product_terms(lhs.glwe_size().to_glwe_dimension())
+            .zip(output.as_mut_polynomial_list().iter_mut())
+    {
+        let factor = if negate {
+            -rescaling_factor
+        } else {
+            rescaling_factor
+        };
+
+        let lhs_i = &lhs_fourier[lhs_idx * fourier_poly_size..][..fourier_poly_size];
+        let rhs_j = &rhs_fourier[rhs_idx * fourier_poly_size..][..fourier_poly_size];
+
+        if lhs_idx == rhs_idx {
+            for (out, lhs_i, rhs_j) in izip!(output_fourier.iter_mut(), lhs_i, rhs_j) {
+                *out = *lhs_i * *rhs_j * factor;
+            }
+        } else {
+            let lhs_j = &lhs_fourier[rhs_idx * fourier_poly_size..][..fourier_poly_size];
+            let rhs_i = &rhs_fourier[lhs_idx * fourier_poly_size..][..fourier_poly_size];
+
+            for (out, lhs_i, rhs_j, lhs_j, rhs_i) in
+                izip!(output_fourier.iter_mut(), lhs_i, rhs_j, lhs_j, rhs_i)
+            {
+                *out = (*lhs_i * *rhs_j + *lhs_j * *rhs_i) * factor;
+            }
+        }
+
+        fft.backward_as_torus(
+            output_polynomial,
+            FourierPolynomialView {
+                data: &*output_fourier,
+            },
+            stack.rb_mut(),
+        );
+    }
+
+    round_to_custom_power_of_two_modulus(output);
+}
+
+/// Return the required memory for [`glwe_tensor_product_mem_optimized`].
+pub fn glwe_tensor_product_mem_optimized_requirement(
+    input_glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let fourier_poly_size = polynomial_size.to_fourier_polynomial_size().0;
+    let input_fourier_scratch =
+        StackReq::try_new_aligned::<c64>(fourier_poly_size * input_glwe_size.0, CACHELINE_ALIGN)?;
+    let output_fourier_scratch =
+        StackReq::try_new_aligned::<c64>(fourier_poly_size, CACHELINE_ALIGN)?;
+
+    StackReq::try_all_of([
+        input_fourier_scratch,
+        input_fourier_scratch,
+        output_fourier_scratch,
+        StackReq::try_any_of([fft.forward_scratch()?, fft.backward_scratch()?])?,
+    ])
+}
+
+/// Compute the rescaled tensor product of two [`GLWE ciphertexts`](`GlweCiphertext`) using a 128
+/// bits FFT.
+///
+/// Both inputs must be encrypted under the same [`GLWE secret key`](`GlweSecretKey`), their tensor
+/// product is rescaled by `scaling_factor` and the result is written in `output`.
+///
+/// This variant computes the products in the Fourier domain using 128 bits floating point
+/// numbers, yielding a smaller error than [`glwe_tensor_product`] at the cost of performance.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`glwe_tensor_product_f128_mem_optimized`].
+///
+/// See [`glwe_tensor_product`] for the formal definition and usage.
+pub fn glwe_tensor_product_f128<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    scaling_factor: Scalar,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let fft = Fft128::new(lhs.polynomial_size());
+    let fft = fft.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        glwe_tensor_product_f128_mem_optimized_requirement(
+            lhs.glwe_size(),
+            lhs.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    glwe_tensor_product_f128_mem_optimized(lhs, rhs, output, scaling_factor, fft, buffers.stack());
+}
+
+/// Memory optimized version of [`glwe_tensor_product_f128`].
+///
+/// The caller must provide a properly configured [`Fft128View`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`glwe_tensor_product_f128_mem_optimized_requirement`].
+///
+/// See [`glwe_tensor_product`] for usage.
+pub fn glwe_tensor_product_f128_mem_optimized<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &mut GlweCiphertext<OutputCont>,
+    scaling_factor: Scalar,
+    fft: Fft128View<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    check_glwe_tensor_product_inputs(lhs, rhs, output);
+
+    let fourier_poly_size = lhs.polynomial_size().to_fourier_polynomial_size().0;
+    let ciphertext_modulus = output.ciphertext_modulus();
+    let rescaling_factor = tensor_product_rescaling_factor(ciphertext_modulus, scaling_factor);
+
+    let input_len = fourier_poly_size * lhs.glwe_size().0;
+    let (mut lhs_re0, stack) = stack.make_aligned_raw::<f64>(input_len, CACHELINE_ALIGN);
+    let (mut lhs_re1, stack) = stack.make_aligned_raw::<f64>(input_len, CACHELINE_ALIGN);
+    let (mut lhs_im0, stack) = stack.make_aligned_raw::<f64>(input_len, CACHELINE_ALIGN);
+    let (mut lhs_im1, stack) = stack.make_aligned_raw::<f64>(input_len, CACHELINE_ALIGN);
+    let (mut rhs_re0, stack) = stack.make_aligned_raw::<f64>(input_len, CACHELINE_ALIGN);
+    let (mut rhs_re1, stack) = stack.make_aligned_raw::<f64>(input_len, CACHELINE_ALIGN);
+    let (mut rhs_im0, stack) = stack.make_aligned_raw::<f64>(input_len, CACHELINE_ALIGN);
+    let (mut rhs_im1, stack) = stack.make_aligned_raw::<f64>(input_len, CACHELINE_ALIGN);
+    let (mut output_re0, stack) = stack.make_aligned_raw::<f64>(fourier_poly_size, CACHELINE_ALIGN);
+    let (mut output_re1, stack) = stack.make_aligned_raw::<f64>(fourier_poly_size, CACHELINE_ALIGN);
+    let (mut output_im0, stack) = stack.make_aligned_raw::<f64>(fourier_poly_size, CACHELINE_ALIGN);
+    let (mut output_im1, mut stack) =
+        stack.make_aligned_raw::<f64>(fourier_poly_size, CACHELINE_ALIGN);
+
+    for (input, re0, re1, im0, im1) in [
+        (
+            lhs.as_polynomial_list(),
+            &mut *lhs_re0,
+            &mut *lhs_re1,
+            &mut *lhs_im0,
+            &mut *lhs_im1,
+        ),
+        (
+            rhs.as_polynomial_list(),
+            &mut *rhs_re0,
+            &mut *rhs_re1,
+            &mut *rhs_im0,
+            &mut *rhs_im1,
+        ),
+    ] {
+        for (input_polynomial, re0, re1, im0, im1) in izip!(
+            input.iter(),
+            re0.chunks_exact_mut(fourier_poly_size),
+            re1.chunks_exact_mut(fourier_poly_size),
+            im0.chunks_exact_mut(fourier_poly_size),
+            im1.chunks_exact_mut(fourier_poly_size),
+        ) {
+            fft.forward_as_torus(re0, re1, im0, im1, input_polynomial.as_ref());
+        }
+    }
+
+    let lhs_fourier = [&*lhs_re0, &*lhs_re1, &*lhs_im0, &*lhs_im1];
+    let rhs_fourier = [&*rhs_re0, &*rhs_re1, &*rhs_im0, &*rhs_im1];
+
+    // Return the f128 real and imaginary parts of the coefficient at index coef_idx of the
+    // polynomial at index poly_idx in a fourier buffer
+    let get = |fourier: &[&[f64]; 4], poly_idx: usize, coef_idx: usize| {
+        let idx = poly_idx * fourier_poly_size + coef_idx;
+        (
+            f128(fourier[0][idx], fourier[1][idx]),
+            f128(fourier[2][idx], fourier[3][idx]),
+        )
+    };
+
+    for ((lhs_idx, rhs_idx, negate), mut output_polynomial) in
+        tensor_product_terms(lhs.glwe_size().to_glwe_dimension())
+            .zip(output.as_mut_polynomial_list().iter_mut())
+    {
+        let factor = f128(
+            if negate {
+                -rescaling_factor
+            } else {
+                rescaling_factor
+            },
+            0.0,
+        );
+
+        for (coef_idx, (out_re0, out_re1, out_im0, out_im1)) in izip!(
+            output_re0.iter_mut(),
+            output_re1.iter_mut(),
+            output_im0.iter_mut(),
+            output_im1.iter_mut(),
+        )
+        .enumerate()
+        {
+            let (lhs_i_re, lhs_i_im) = get(&lhs_fourier, lhs_idx, coef_idx);
+            let (rhs_j_re, rhs_j_im) = get(&rhs_fourier, rhs_idx, coef_idx);
+
+            let mut out_re = lhs_i_re * rhs_j_re - lhs_i_im * rhs_j_im;
+            let mut out_im = lhs_i_im * rhs_j_re + lhs_i_re * rhs_j_im;
+
+            if lhs_idx != rhs_idx {
+                let (lhs_j_re, lhs_j_im) = get(&lhs_fourier, rhs_idx, coef_idx);
+                let (rhs_i_re, rhs_i_im) = get(&rhs_fourier, lhs_idx, coef_idx);
+
+                out_re += lhs_j_re * rhs_i_re - lhs_j_im * rhs_i_im;
+                out_im += lhs_j_im * rhs_i_re + lhs_j_re * rhs_i_im;
+            }
+
+            let out_re = out_re * factor;
+            let out_im = out_im * factor;
+
+            *out_re0 = out_re.0;
+            *out_re1 = out_re.1;
+            *out_im0 = out_im.0;
+            *out_im1 = out_im.1;
+        }
+
+        fft.backward_as_torus(
+            output_polynomial.as_mut(),
+            &output_re0,
+            &output_re1,
+            &output_im0,
+            &output_im1,
+            stack.rb_mut(),
+        );
+    }
+
+    round_to_custom_power_of_two_modulus(output);
+}
+
+/// Return the required memory for [`glwe_tensor_product_f128_mem_optimized`].
+pub fn glwe_tensor_product_f128_mem_optimized_requirement(
+    input_glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let fourier_poly_size = polynomial_size.to_fourier_polynomial_size().0;
+    let input_fourier_scratch =
+        StackReq::try_new_aligned::<f64>(fourier_poly_size * input_glwe_size.0, CACHELINE_ALIGN)?;
+    let output_fourier_scratch =
+        StackReq::try_new_aligned::<f64>(fourier_poly_size, CACHELINE_ALIGN)?;
+
+    StackReq::try_all_of([
+        StackReq::try_all_of([input_fourier_scratch; 8])?,
+        StackReq::try_all_of([output_fourier_scratch; 4])?,
+        fft.backward_scratch()?,
+    ])
+}
+
+/// Iterate over the output polynomials of a tensor product in order, yielding the indices of the
+/// lhs and rhs polynomials to multiply and whether the product must be negated. When both indices
+/// differ the symmetric product is added as well. The index $k$ refers to the body.
+fn tensor_product_terms(
+    glwe_dimension: GlweDimension,
+) -> impl Iterator<Item = (usize, usize, bool)> {
+    let glwe_dimension = glwe_dimension.0;
+    let body_idx = glwe_dimension;
+
+    let linear_terms = (0..glwe_dimension).map(move |idx| (idx, body_idx, false));
+    let quadratic_terms = (0..glwe_dimension)
+        .flat_map(|lhs_idx| (0..=lhs_idx).map(move |rhs_idx| (lhs_idx, rhs_idx, true)));
+
+    linear_terms
+        .chain(quadratic_terms)
+        .chain(core::iter::once((body_idx, body_idx, false)))
+}
+
+/// Return the factor $\frac{q}{\Delta}$ by which the product of the inputs, seen as torus
+/// elements, must be multiplied to get the rescaled tensor product.
+fn tensor_product_rescaling_factor<Scalar: UnsignedTorus>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    scaling_factor: Scalar,
+) -> f64 {
+    let modulus: f64 = if ciphertext_modulus.is_native_modulus() {
+        2.0f64.powi(Scalar::BITS as i32)
+    } else {
+        ciphertext_modulus.get_custom_modulus() as f64
+    };
+    let scaling_factor: f64 = scaling_factor.cast_into();
+
+    modulus / scaling_factor
+}
+
+fn round_to_custom_power_of_two_modulus<Scalar, OutputCont>(output: &mut GlweCiphertext<OutputCont>)
+where
+    Scalar: UnsignedTorus,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let ciphertext_modulus = output.ciphertext_modulus();
+    if !ciphertext_modulus.is_native_modulus() {
+        // When we convert back from the fourier domain, integer values will contain up to 53
+        // MSBs with information. In our representation of power of 2 moduli < native modulus we
+        // fill the MSBs and leave the LSBs empty, this usage of the signed decomposer allows to
+        // round while keeping the data in the MSBs
+        let signed_decomposer = SignedDecomposer::new(
+            DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+            DecompositionLevelCount(1),
+        );
+        output
+            .as_mut()
+            .iter_mut()
+            .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+    }
+}
+
+fn check_glwe_tensor_product_inputs<Scalar, LhsCont, RhsCont, OutputCont>(
+    lhs: &GlweCiphertext<LhsCont>,
+    rhs: &GlweCiphertext<RhsCont>,
+    output: &GlweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedTorus,
+    LhsCont: Container<Element = Scalar>,
+    RhsCont: Container<Element = Scalar>,
+    OutputCont: Container<Element = Scalar>,
+{
+    assert!(
+        lhs.glwe_size() == rhs.glwe_size(),
+        "Mismatched input GlweSize. lhs GlweSize: {:?}, rhs GlweSize {:?}.",
+        lhs.glwe_size(),
+        rhs.glwe_size()
+    );
+    assert!(
+        output.glwe_size() == glwe_tensor_product_output_glwe_size(lhs.glwe_size()),
+        "Mismatched output GlweSize. Expected: {:?}, got {:?}.",
+        glwe_tensor_product_output_glwe_size(lhs.glwe_size()),
+        output.glwe_size()
+    );
+    assert!(
+        lhs.polynomial_size() == rhs.polynomial_size(),
+        "Mismatched input PolynomialSize. lhs PolynomialSize: {:?}, rhs PolynomialSize {:?}.",
+        lhs.polynomial_size(),
+        rhs.polynomial_size()
+    );
+    assert!(
+        lhs.polynomial_size() == output.polynomial_size(),
+        "Mismatched output PolynomialSize. input PolynomialSize: {:?}, output PolynomialSize {:?}.",
+        lhs.polynomial_size(),
+        output.polynomial_size()
+    );
+    assert!(
+        lhs.ciphertext_modulus() == rhs.ciphertext_modulus(),
+        "Mismatched input CiphertextModulus. \
+        lhs CiphertextModulus: {:?}, rhs CiphertextModulus {:?}.",
+        lhs.ciphertext_modulus(),
+        rhs.ciphertext_modulus()
+    );
+    assert!(
+        lhs.ciphertext_modulus() == output.ciphertext_modulus(),
+        "Mismatched output CiphertextModulus. \
+        input CiphertextModulus: {:?}, output CiphertextModulus {:?}.",
+        lhs.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+    assert!(
+        output
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "This operation currently only supports power of 2 moduli"
+    );
+}
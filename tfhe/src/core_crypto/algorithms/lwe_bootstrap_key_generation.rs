@@ -3,12 +3,17 @@
 //! keys`](`SeededLweBootstrapKey`).
 
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::write_fourier_lwe_bootstrap_key_raw_header;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use aligned_vec::ABox;
+use concrete_fft::c64;
 use rayon::prelude::*;
 
 /// Fill an [`LWE bootstrap key`](`LweBootstrapKey`) with an actual bootstrapping key constructed
@@ -77,7 +82,7 @@ pub fn generate_lwe_bootstrap_key<Scalar, InputKeyCont, OutputKeyCont, OutputCon
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     output: &mut LweBootstrapKey<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -86,6 +91,7 @@ pub fn generate_lwe_bootstrap_key<Scalar, InputKeyCont, OutputKeyCont, OutputCon
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
         "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
@@ -145,7 +151,7 @@ pub fn allocate_and_generate_new_lwe_bootstrap_key<Scalar, InputKeyCont, OutputK
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweBootstrapKeyOwned<Scalar>
@@ -238,7 +244,7 @@ pub fn par_generate_lwe_bootstrap_key<Scalar, InputKeyCont, OutputKeyCont, Outpu
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     output: &mut LweBootstrapKey<OutputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -247,6 +253,7 @@ pub fn par_generate_lwe_bootstrap_key<Scalar, InputKeyCont, OutputKeyCont, Outpu
     OutputCont: ContainerMut<Element = Scalar>,
     Gen: ParallelByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
         "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
@@ -304,7 +311,7 @@ pub fn par_allocate_and_generate_new_lwe_bootstrap_key<Scalar, InputKeyCont, Out
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweBootstrapKeyOwned<Scalar>
@@ -350,7 +357,7 @@ pub fn generate_seeded_lwe_bootstrap_key<
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     output: &mut SeededLweBootstrapKey<OutputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -360,6 +367,7 @@ pub fn generate_seeded_lwe_bootstrap_key<
     // Maybe Sized allows to pass Box<dyn Seeder>.
     NoiseSeeder: Seeder + ?Sized,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
         "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
@@ -429,7 +437,7 @@ pub fn allocate_and_generate_new_seeded_lwe_bootstrap_key<
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLweBootstrapKeyOwned<Scalar>
@@ -474,7 +482,7 @@ pub fn par_generate_seeded_lwe_bootstrap_key<
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     output: &mut SeededLweBootstrapKey<OutputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -484,6 +492,7 @@ pub fn par_generate_seeded_lwe_bootstrap_key<
     // Maybe Sized allows to pass Box<dyn Seeder>.
     NoiseSeeder: Seeder + ?Sized,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.input_lwe_dimension() == input_lwe_secret_key.lwe_dimension(),
         "Mismatched LweDimension between input LWE secret key and LWE bootstrap key. \
@@ -549,7 +558,7 @@ pub fn par_allocate_and_generate_new_seeded_lwe_bootstrap_key<
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLweBootstrapKeyOwned<Scalar>
@@ -581,3 +590,577 @@ where
 
     bsk
 }
+
+/// Generate the GGSW ciphertexts of an [`LWE bootstrap key`](`LweBootstrapKey`) by chunks of at
+/// most `chunk_size` ciphertexts, each chunk is encrypted by `encrypt_chunk` then handed to
+/// `process_chunk` before the memory is reused for the next chunk.
+///
+/// The random generator is forked exactly like in [`generate_lwe_bootstrap_key`] so that the
+/// generated key is the same as the one generated in memory for the same generator state.
+#[allow(clippy::too_many_arguments)]
+fn generate_lwe_bootstrap_key_chunks<Scalar, InputKeyCont, OutputKeyCont, Gen>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    chunk_size: GgswCiphertextCount,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    mut encrypt_chunk: impl FnMut(
+        GgswCiphertextListMutView<'_, Scalar>,
+        &[Scalar],
+        Vec<EncryptionRandomGenerator<Gen>>,
+    ),
+    mut process_chunk: impl FnMut(GgswCiphertextListView<'_, Scalar>) -> std::io::Result<()>,
+) -> std::io::Result<()>
+where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(chunk_size.0 > 0, "Got a chunk size of 0 GGSW ciphertexts");
+
+    let glwe_size = output_glwe_secret_key.glwe_dimension().to_glwe_size();
+    let polynomial_size = output_glwe_secret_key.polynomial_size();
+
+    let mut gen_iter = generator
+        .fork_bsk_to_ggsw::<Scalar>(
+            input_lwe_secret_key.lwe_dimension(),
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        )
+        .unwrap();
+
+    let mut buffer =
+        vec![
+            Scalar::ZERO;
+            ggsw_ciphertext_list_size(chunk_size, glwe_size, polynomial_size, decomp_level_count)
+        ];
+
+    for input_key_elements in input_lwe_secret_key.as_ref().chunks(chunk_size.0) {
+        let chunk_len = ggsw_ciphertext_list_size(
+            GgswCiphertextCount(input_key_elements.len()),
+            glwe_size,
+            polynomial_size,
+            decomp_level_count,
+        );
+        let generators: Vec<_> = gen_iter.by_ref().take(input_key_elements.len()).collect();
+
+        encrypt_chunk(
+            GgswCiphertextList::from_container(
+                &mut buffer[..chunk_len],
+                glwe_size,
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+                ciphertext_modulus,
+            ),
+            input_key_elements,
+            generators,
+        );
+        process_chunk(GgswCiphertextList::from_container(
+            &buffer[..chunk_len],
+            glwe_size,
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        ))?;
+    }
+
+    Ok(())
+}
+
+fn encrypt_lwe_bootstrap_key_chunk<Scalar, KeyCont, Gen>(
+    output_glwe_secret_key: &GlweSecretKey<KeyCont>,
+    mut chunk: GgswCiphertextListMutView<'_, Scalar>,
+    input_key_elements: &[Scalar],
+    noise_parameters: impl Into<NoiseDistribution>,
+    generators: Vec<EncryptionRandomGenerator<Gen>>,
+) where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
+    for ((mut ggsw, &input_key_element), mut generator) in
+        chunk.iter_mut().zip(input_key_elements).zip(generators)
+    {
+        encrypt_constant_ggsw_ciphertext(
+            output_glwe_secret_key,
+            &mut ggsw,
+            Plaintext(input_key_element),
+            noise_parameters,
+            &mut generator,
+        );
+    }
+}
+
+fn par_encrypt_lwe_bootstrap_key_chunk<Scalar, KeyCont, Gen>(
+    output_glwe_secret_key: &GlweSecretKey<KeyCont>,
+    mut chunk: GgswCiphertextListMutView<'_, Scalar>,
+    input_key_elements: &[Scalar],
+    noise_parameters: impl Into<NoiseDistribution>,
+    generators: Vec<EncryptionRandomGenerator<Gen>>,
+) where
+    Scalar: UnsignedTorus + Sync + Send,
+    KeyCont: Container<Element = Scalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+{
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
+    chunk
+        .par_iter_mut()
+        .zip(input_key_elements.par_iter())
+        .zip(generators.into_par_iter())
+        .for_each(|((mut ggsw, &input_key_element), mut generator)| {
+            encrypt_constant_ggsw_ciphertext(
+                output_glwe_secret_key,
+                &mut ggsw,
+                Plaintext(input_key_element),
+                noise_parameters,
+                &mut generator,
+            );
+        });
+}
+
+/// Write the Fourier transform of each GGSW ciphertext of a chunk of bootstrapping key to
+/// `writer`, only a single GGSW ciphertext is kept in the Fourier domain at any given time.
+struct FourierGgswChunkWriter<W> {
+    writer: W,
+    fft: Fft,
+    buffers: ComputationBuffers,
+    fourier_ggsw: FourierGgswCiphertext<ABox<[c64]>>,
+}
+
+impl<W: std::io::Write> FourierGgswChunkWriter<W> {
+    fn new(
+        writer: W,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+    ) -> Self {
+        let fft = Fft::new(polynomial_size);
+        let mut buffers = ComputationBuffers::new();
+        buffers.resize(
+            fft.as_view()
+                .forward_scratch()
+                .unwrap()
+                .unaligned_bytes_required(),
+        );
+
+        Self {
+            writer,
+            fft,
+            buffers,
+            fourier_ggsw: FourierGgswCiphertext::new(
+                glwe_size,
+                polynomial_size,
+                decomp_base_log,
+                decomp_level_count,
+            ),
+        }
+    }
+
+    fn write_chunk<Scalar: UnsignedTorus>(
+        &mut self,
+        chunk: GgswCiphertextListView<'_, Scalar>,
+    ) -> std::io::Result<()> {
+        for ggsw in chunk.iter() {
+            self.fourier_ggsw.as_mut_view().fill_with_forward_fourier(
+                ggsw,
+                self.fft.as_view(),
+                self.buffers.stack(),
+            );
+            self.writer
+                .write_all(bytemuck::cast_slice(self.fourier_ggsw.as_view().data()))?;
+        }
+        Ok(())
+    }
+}
+
+/// Generate an [`LWE bootstrap key`](`LweBootstrapKey`) constructed from an input key
+/// [`LWE secret key`](`LweSecretKey`) and an output key [`GLWE secret key`](`GlweSecretKey`) and
+/// write it to `writer` in the raw key format, without ever holding the whole key in memory.
+///
+/// The key is generated by chunks of at most `chunk_size` GGSW ciphertexts, which bounds the
+/// memory used by the generation. The generated key is identical to the one generated by
+/// [`generate_lwe_bootstrap_key`] for the same generator state and can be loaded with
+/// [`LweBootstrapKey::from_raw_bytes`].
+///
+/// Consider using [`par_generate_lwe_bootstrap_key_to_writer`] for better key generation times.
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweBootstrapKey creation
+/// let input_lwe_dimension = LweDimension(742);
+/// let decomp_base_log = DecompositionBaseLog(3);
+/// let decomp_level_count = DecompositionLevelCount(5);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let input_lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Only 16 GGSW ciphertexts are held in memory at once, the writer can be a file
+/// let mut raw_bsk = Vec::new();
+/// generate_lwe_bootstrap_key_to_writer(
+///     &input_lwe_secret_key,
+///     &output_glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     GgswCiphertextCount(16),
+///     &mut encryption_generator,
+///     &mut raw_bsk,
+/// )
+/// .unwrap();
+///
+/// // The raw key data must be aligned to be used in place, this is the case for a memory mapped
+/// // file, here we copy the bytes to a suitably aligned buffer
+/// let mut aligned_raw_bsk = vec![0u64; raw_bsk.len() / std::mem::size_of::<u64>()];
+/// bytemuck::cast_slice_mut::<u64, u8>(&mut aligned_raw_bsk).copy_from_slice(&raw_bsk);
+///
+/// let bsk = LweBootstrapKey::<&[u64]>::from_raw_bytes(bytemuck::cast_slice(&aligned_raw_bsk))
+///     .unwrap();
+///
+/// assert_eq!(bsk.input_lwe_dimension(), input_lwe_dimension);
+/// for (ggsw, &input_key_bit) in bsk.iter().zip(input_lwe_secret_key.as_ref()) {
+///     let decrypted_ggsw = decrypt_constant_ggsw_ciphertext(&output_glwe_secret_key, &ggsw);
+///     assert_eq!(decrypted_ggsw.0, input_key_bit)
+/// }
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn generate_lwe_bootstrap_key_to_writer<Scalar, InputKeyCont, OutputKeyCont, Gen, W>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    chunk_size: GgswCiphertextCount,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    mut writer: W,
+) -> std::io::Result<()>
+where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+    W: std::io::Write,
+{
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
+    write_lwe_bootstrap_key_raw_header(
+        &mut writer,
+        input_lwe_secret_key.lwe_dimension(),
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        output_glwe_secret_key.polynomial_size(),
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+    )?;
+
+    generate_lwe_bootstrap_key_chunks(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+        chunk_size,
+        generator,
+        |chunk, input_key_elements, generators| {
+            encrypt_lwe_bootstrap_key_chunk(
+                output_glwe_secret_key,
+                chunk,
+                input_key_elements,
+                noise_parameters,
+                generators,
+            );
+        },
+        |chunk| writer.write_all(bytemuck::cast_slice(chunk.as_ref())),
+    )
+}
+
+/// Parallel variant of [`generate_lwe_bootstrap_key_to_writer`], the GGSW ciphertexts of a chunk
+/// are encrypted in parallel, larger chunks therefore allow for better key generation times at
+/// the cost of more memory.
+///
+/// The generated key is identical to the one generated by
+/// [`generate_lwe_bootstrap_key_to_writer`] for the same generator state.
+#[allow(clippy::too_many_arguments)]
+pub fn par_generate_lwe_bootstrap_key_to_writer<Scalar, InputKeyCont, OutputKeyCont, Gen, W>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    chunk_size: GgswCiphertextCount,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    mut writer: W,
+) -> std::io::Result<()>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+    W: std::io::Write,
+{
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
+    write_lwe_bootstrap_key_raw_header(
+        &mut writer,
+        input_lwe_secret_key.lwe_dimension(),
+        output_glwe_secret_key.glwe_dimension().to_glwe_size(),
+        output_glwe_secret_key.polynomial_size(),
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+    )?;
+
+    generate_lwe_bootstrap_key_chunks(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+        chunk_size,
+        generator,
+        |chunk, input_key_elements, generators| {
+            par_encrypt_lwe_bootstrap_key_chunk(
+                output_glwe_secret_key,
+                chunk,
+                input_key_elements,
+                noise_parameters,
+                generators,
+            );
+        },
+        |chunk| writer.write_all(bytemuck::cast_slice(chunk.as_ref())),
+    )
+}
+
+/// Generate an [`LWE bootstrap key`](`LweBootstrapKey`) like
+/// [`generate_lwe_bootstrap_key_to_writer`] and convert it to the Fourier domain on the fly, the
+/// key is written to `writer` in the raw key format of a [`FourierLweBootstrapKey`] which can be
+/// loaded with [`FourierLweBootstrapKey::from_raw_bytes`].
+///
+/// Neither the standard nor the Fourier key are ever held in memory as a whole, the memory used
+/// is bounded by `chunk_size` GGSW ciphertexts in the standard domain and a single GGSW
+/// ciphertext in the Fourier domain.
+///
+/// Consider using [`par_generate_fourier_lwe_bootstrap_key_to_writer`] for better key generation
+/// times.
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweBootstrapKey creation
+/// let input_lwe_dimension = LweDimension(742);
+/// let decomp_base_log = DecompositionBaseLog(3);
+/// let decomp_level_count = DecompositionLevelCount(5);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let input_lwe_secret_key: LweSecretKeyOwned<u64> =
+///     allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut raw_fourier_bsk = Vec::new();
+/// generate_fourier_lwe_bootstrap_key_to_writer(
+///     &input_lwe_secret_key,
+///     &output_glwe_secret_key,
+///     decomp_base_log,
+///     decomp_level_count,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     GgswCiphertextCount(16),
+///     &mut encryption_generator,
+///     &mut raw_fourier_bsk,
+/// )
+/// .unwrap();
+///
+/// // The raw key data must be aligned to be used in place, this is the case for a memory mapped
+/// // file, here we copy the bytes to a suitably aligned buffer
+/// let mut aligned_raw_fourier_bsk =
+///     vec![0u64; raw_fourier_bsk.len() / std::mem::size_of::<u64>()];
+/// bytemuck::cast_slice_mut::<u64, u8>(&mut aligned_raw_fourier_bsk)
+///     .copy_from_slice(&raw_fourier_bsk);
+///
+/// let fourier_bsk =
+///     FourierLweBootstrapKey::from_raw_bytes(bytemuck::cast_slice(&aligned_raw_fourier_bsk))
+///         .unwrap();
+///
+/// assert_eq!(fourier_bsk.input_lwe_dimension(), input_lwe_dimension);
+/// assert_eq!(fourier_bsk.polynomial_size(), polynomial_size);
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn generate_fourier_lwe_bootstrap_key_to_writer<Scalar, InputKeyCont, OutputKeyCont, Gen, W>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    chunk_size: GgswCiphertextCount,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    mut writer: W,
+) -> std::io::Result<()>
+where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+    W: std::io::Write,
+{
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
+    let glwe_size = output_glwe_secret_key.glwe_dimension().to_glwe_size();
+    let polynomial_size = output_glwe_secret_key.polynomial_size();
+
+    write_fourier_lwe_bootstrap_key_raw_header(
+        &mut writer,
+        input_lwe_secret_key.lwe_dimension(),
+        glwe_size,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    )?;
+
+    let mut chunk_writer = FourierGgswChunkWriter::new(
+        writer,
+        glwe_size,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    );
+
+    generate_lwe_bootstrap_key_chunks(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+        chunk_size,
+        generator,
+        |chunk, input_key_elements, generators| {
+            encrypt_lwe_bootstrap_key_chunk(
+                output_glwe_secret_key,
+                chunk,
+                input_key_elements,
+                noise_parameters,
+                generators,
+            );
+        },
+        |chunk| chunk_writer.write_chunk(chunk),
+    )
+}
+
+/// Parallel variant of [`generate_fourier_lwe_bootstrap_key_to_writer`], the GGSW ciphertexts of
+/// a chunk are encrypted in parallel, larger chunks therefore allow for better key generation
+/// times at the cost of more memory.
+///
+/// The generated key is identical to the one generated by
+/// [`generate_fourier_lwe_bootstrap_key_to_writer`] for the same generator state.
+#[allow(clippy::too_many_arguments)]
+pub fn par_generate_fourier_lwe_bootstrap_key_to_writer<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    Gen,
+    W,
+>(
+    input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    chunk_size: GgswCiphertextCount,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+    mut writer: W,
+) -> std::io::Result<()>
+where
+    Scalar: UnsignedTorus + Sync + Send,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar> + Sync,
+    Gen: ParallelByteRandomGenerator,
+    W: std::io::Write,
+{
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
+    let glwe_size = output_glwe_secret_key.glwe_dimension().to_glwe_size();
+    let polynomial_size = output_glwe_secret_key.polynomial_size();
+
+    write_fourier_lwe_bootstrap_key_raw_header(
+        &mut writer,
+        input_lwe_secret_key.lwe_dimension(),
+        glwe_size,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    )?;
+
+    let mut chunk_writer = FourierGgswChunkWriter::new(
+        writer,
+        glwe_size,
+        polynomial_size,
+        decomp_base_log,
+        decomp_level_count,
+    );
+
+    generate_lwe_bootstrap_key_chunks(
+        input_lwe_secret_key,
+        output_glwe_secret_key,
+        decomp_base_log,
+        decomp_level_count,
+        ciphertext_modulus,
+        chunk_size,
+        generator,
+        |chunk, input_key_elements, generators| {
+            par_encrypt_lwe_bootstrap_key_chunk(
+                output_glwe_secret_key,
+                chunk,
+                input_key_elements,
+                noise_parameters,
+                generators,
+            );
+        },
+        |chunk| chunk_writer.write_chunk(chunk),
+    )
+}
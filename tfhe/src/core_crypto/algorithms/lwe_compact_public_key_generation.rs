@@ -3,7 +3,7 @@
 
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -15,7 +15,7 @@ use slice_algorithms::*;
 pub fn generate_lwe_compact_public_key<Scalar, InputKeyCont, OutputKeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output: &mut LweCompactPublicKey<OutputKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -55,7 +55,7 @@ pub fn generate_lwe_compact_public_key<Scalar, InputKeyCont, OutputKeyCont, Gen>
 /// See [`encrypt_lwe_ciphertext_with_compact_public_key`] for usage.
 pub fn allocate_and_generate_new_lwe_compact_public_key<Scalar, InputKeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweCompactPublicKeyOwned<Scalar>
@@ -80,7 +80,7 @@ where
 pub fn generate_seeded_lwe_compact_public_key<Scalar, InputKeyCont, OutputKeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output: &mut SeededLweCompactPublicKey<OutputKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -126,7 +126,7 @@ pub fn generate_seeded_lwe_compact_public_key<Scalar, InputKeyCont, OutputKeyCon
 /// an actual public key constructed from a private [`LWE secret key`](`LweSecretKey`).
 pub fn allocate_and_generate_new_seeded_lwe_compact_public_key<Scalar, InputKeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<InputKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLweCompactPublicKeyOwned<Scalar>
@@ -0,0 +1,443 @@
+//! Module containing primitives pertaining to the distributed generation of
+//! [`seeded LWE bootstrap keys`](`SeededLweBootstrapKey`),
+//! [`seeded LWE keyswitch keys`](`SeededLweKeyswitchKey`) and
+//! [`seeded LWE compact public keys`](`SeededLweCompactPublicKey`).
+//!
+//! In a distributed key generation, $N$ parties hold additive shares over $\mathbb{Z}\_q$ of joint
+//! secret keys that no party ever sees in the clear. Each party generates a share of a seeded key
+//! from its secret key shares using a seed common to all the parties, so that all the shares have
+//! the same masks, and an aggregator sums the shares to obtain the seeded key for the joint secret
+//! keys.
+//!
+//! The keyswitch key and the compact public key are linear in their secret keys, shares are
+//! generated with the regular [`generate_seeded_lwe_keyswitch_key`] and
+//! [`generate_seeded_lwe_compact_public_key`] called with secret key shares. The bootstrap key
+//! encrypts the input key under the output key, its shares are generated with
+//! [`generate_seeded_lwe_bootstrap_key_share`] which additionally requires shares of the products
+//! of the input and output secret keys.
+//!
+//! As each party adds its own noise, the noise of the aggregated key is the sum of the noises of
+//! all the shares. Parties should therefore use a standard deviation of $\sigma / \sqrt{N}$ to
+//! obtain a key with the noise standard deviation $\sigma$ of the parameter set.
+//!
+//! Generating the secret key shares and the key product shares is out of the scope of this module,
+//! they must be obtained through a secure multi-party computation protocol.
+
+use crate::core_crypto::algorithms::slice_algorithms::*;
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
+use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+use crate::core_crypto::commons::math::decomposition::DecompositionLevel;
+use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// Fill a [`seeded LWE bootstrap key`](`SeededLweBootstrapKey`) with the share of a party of a
+/// bootstrapping key constructed from joint secret keys.
+///
+/// `input_lwe_secret_key_share` and `output_glwe_secret_key_share` are the additive shares of the
+/// party of the joint input [`LWE secret key`](`LweSecretKey`) $\vec{s}$ and the joint output
+/// [`GLWE secret key`](`GlweSecretKey`) $\vec{S}$. `key_product_shares` contains the additive
+/// shares of the party of the polynomials $s\_j \cdot S\_m$, the polynomial at index
+/// $j \cdot k + m$ being the share of $s\_j \cdot S\_m$ where $k$ is the
+/// [`GlweDimension`] of the output key.
+///
+/// The output must be created with the seed common to all the parties, summing the shares of all
+/// the parties with [`aggregate_seeded_lwe_bootstrap_key_shares`] yields a bootstrap key for the
+/// joint secret keys. See the [`module documentation`](`self`) for the choice of the noise
+/// parameters.
+///
+/// # Example
+///
+/// ```
+/// use rand::Rng;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweBootstrapKey creation
+/// let input_lwe_dimension = LweDimension(742);
+/// let decomp_base_log = DecompositionBaseLog(3);
+/// let decomp_level_count = DecompositionLevelCount(5);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(1024);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let party_count = 3;
+///
+/// // Each party adds its own noise, scale the noise so that the aggregated key has the expected
+/// // noise level
+/// let party_noise = StandardDev(glwe_modular_std_dev.0 / (party_count as f64).sqrt());
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // The parties agree on a common seed
+/// let common_seed = seeder.seed();
+///
+/// // For the sake of the example the secret key shares are dealt from joint secret keys, in a
+/// // real setting they are generated by a multi-party computation protocol and the joint secret
+/// // keys never exist
+/// let input_lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(input_lwe_dimension, &mut secret_generator);
+/// let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// let mut key_products = PolynomialList::new(
+///     0u64,
+///     polynomial_size,
+///     PolynomialCount(input_lwe_dimension.0 * glwe_dimension.0),
+/// );
+/// for (input_key_index, &input_key_element) in input_lwe_secret_key.as_ref().iter().enumerate() {
+///     for (output_key_index, output_key_poly) in output_glwe_secret_key
+///         .as_polynomial_list()
+///         .iter()
+///         .enumerate()
+///     {
+///         let mut key_product =
+///             key_products.get_mut(input_key_index * glwe_dimension.0 + output_key_index);
+///         for (product_coeff, &output_key_coeff) in
+///             key_product.as_mut().iter_mut().zip(output_key_poly.as_ref())
+///         {
+///             *product_coeff = output_key_coeff.wrapping_mul(input_key_element);
+///         }
+///     }
+/// }
+///
+/// // Split a slice in party_count additive shares
+/// let mut rng = rand::thread_rng();
+/// let mut split = |data: &[u64]| -> Vec<Vec<u64>> {
+///     let mut shares: Vec<Vec<u64>> = (1..party_count)
+///         .map(|_| data.iter().map(|_| rng.gen()).collect())
+///         .collect();
+///     let last_share = data
+///         .iter()
+///         .enumerate()
+///         .map(|(idx, value)| {
+///             shares
+///                 .iter()
+///                 .fold(*value, |acc, share| acc.wrapping_sub(share[idx]))
+///         })
+///         .collect();
+///     shares.push(last_share);
+///     shares
+/// };
+///
+/// let input_key_shares = split(input_lwe_secret_key.as_ref());
+/// let output_key_shares = split(output_glwe_secret_key.as_ref());
+/// let key_product_shares = split(key_products.as_ref());
+///
+/// // Each party generates its share of the bootstrap key
+/// let bsk_shares: Vec<_> = input_key_shares
+///     .into_iter()
+///     .zip(output_key_shares)
+///     .zip(key_product_shares)
+///     .map(|((input_key_share, output_key_share), key_product_share)| {
+///         let mut bsk_share = SeededLweBootstrapKey::new(
+///             0u64,
+///             glwe_dimension.to_glwe_size(),
+///             polynomial_size,
+///             decomp_base_log,
+///             decomp_level_count,
+///             input_lwe_dimension,
+///             common_seed.into(),
+///             ciphertext_modulus,
+///         );
+///
+///         // Each party uses its own seeder for the noise
+///         let mut party_seeder = new_seeder();
+///
+///         generate_seeded_lwe_bootstrap_key_share(
+///             &LweSecretKey::from_container(input_key_share),
+///             &GlweSecretKey::from_container(output_key_share, polynomial_size),
+///             &PolynomialList::from_container(key_product_share, polynomial_size),
+///             &mut bsk_share,
+///             party_noise,
+///             party_seeder.as_mut(),
+///         );
+///
+///         bsk_share
+///     })
+///     .collect();
+///
+/// // The aggregator sums the shares
+/// let mut seeded_bsk = SeededLweBootstrapKey::new(
+///     0u64,
+///     glwe_dimension.to_glwe_size(),
+///     polynomial_size,
+///     decomp_base_log,
+///     decomp_level_count,
+///     input_lwe_dimension,
+///     common_seed.into(),
+///     ciphertext_modulus,
+/// );
+///
+/// aggregate_seeded_lwe_bootstrap_key_shares(&bsk_shares, &mut seeded_bsk);
+///
+/// // The aggregated key encrypts the joint input key under the joint output key
+/// let bsk = seeded_bsk.decompress_into_lwe_bootstrap_key();
+///
+/// for (ggsw, &input_key_element) in bsk.iter().zip(input_lwe_secret_key.as_ref()) {
+///     let decrypted = decrypt_constant_ggsw_ciphertext(&output_glwe_secret_key, &ggsw);
+///     assert_eq!(decrypted.0, input_key_element);
+/// }
+/// ```
+pub fn generate_seeded_lwe_bootstrap_key_share<
+    Scalar,
+    InputKeyCont,
+    OutputKeyCont,
+    ProductCont,
+    OutputCont,
+    NoiseSeeder,
+>(
+    input_lwe_secret_key_share: &LweSecretKey<InputKeyCont>,
+    output_glwe_secret_key_share: &GlweSecretKey<OutputKeyCont>,
+    key_product_shares: &PolynomialList<ProductCont>,
+    output: &mut SeededLweBootstrapKey<OutputCont>,
+    noise_parameters: impl Into<NoiseDistribution>,
+    noise_seeder: &mut NoiseSeeder,
+) where
+    Scalar: UnsignedTorus,
+    InputKeyCont: Container<Element = Scalar>,
+    OutputKeyCont: Container<Element = Scalar>,
+    ProductCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    // Maybe Sized allows to pass Box<dyn Seeder>.
+    NoiseSeeder: Seeder + ?Sized,
+{
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
+    assert!(
+        output.ciphertext_modulus().is_native_modulus(),
+        "This operation only supports native moduli"
+    );
+
+    assert!(
+        output.input_lwe_dimension() == input_lwe_secret_key_share.lwe_dimension(),
+        "Mismatched LweDimension between input LWE secret key share and LWE bootstrap key. \
+        Input LWE secret key share LweDimension: {:?}, LWE bootstrap key input LweDimension {:?}.",
+        input_lwe_secret_key_share.lwe_dimension(),
+        output.input_lwe_dimension()
+    );
+
+    assert!(
+        output.glwe_size() == output_glwe_secret_key_share.glwe_dimension().to_glwe_size(),
+        "Mismatched GlweSize between output GLWE secret key share and LWE bootstrap key. \
+        Output GLWE secret key share GlweSize: {:?}, LWE bootstrap key GlweSize {:?}.",
+        output_glwe_secret_key_share.glwe_dimension().to_glwe_size(),
+        output.glwe_size()
+    );
+
+    assert!(
+        output.polynomial_size() == output_glwe_secret_key_share.polynomial_size(),
+        "Mismatched PolynomialSize between output GLWE secret key share and LWE bootstrap key. \
+        Output GLWE secret key share PolynomialSize: {:?}, \
+        LWE bootstrap key PolynomialSize {:?}.",
+        output_glwe_secret_key_share.polynomial_size(),
+        output.polynomial_size()
+    );
+
+    let glwe_dimension = output_glwe_secret_key_share.glwe_dimension();
+
+    assert!(
+        key_product_shares.polynomial_size() == output.polynomial_size()
+            && key_product_shares.polynomial_count().0
+                == output.input_lwe_dimension().0 * glwe_dimension.0,
+        "Mismatched key product shares, expected {:?} polynomials of size {:?}, \
+        got {:?} polynomials of size {:?}.",
+        PolynomialCount(output.input_lwe_dimension().0 * glwe_dimension.0),
+        output.polynomial_size(),
+        key_product_shares.polynomial_count(),
+        key_product_shares.polynomial_size(),
+    );
+
+    let glwe_size = output.glwe_size();
+    let polynomial_size = output.polynomial_size();
+    let decomp_base_log = output.decomposition_base_log();
+    let decomp_level_count = output.decomposition_level_count();
+
+    // Forks mirror the ones of generate_seeded_lwe_bootstrap_key so that all the parties, and the
+    // regular key generation, use the same masks
+    let mut generator = EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+        output.compression_seed().seed,
+        noise_seeder,
+    );
+
+    let gen_iter = generator
+        .fork_bsk_to_ggsw::<Scalar>(
+            output.input_lwe_dimension(),
+            decomp_level_count,
+            glwe_size,
+            polynomial_size,
+        )
+        .unwrap();
+
+    for (((mut ggsw, &input_key_element_share), element_key_product_shares), mut generator) in
+        output
+            .iter_mut()
+            .zip(input_lwe_secret_key_share.as_ref())
+            .zip(key_product_shares.chunks_exact(glwe_dimension.0))
+            .zip(gen_iter)
+    {
+        let gen_iter = generator
+            .fork_ggsw_to_ggsw_levels::<Scalar>(decomp_level_count, glwe_size, polynomial_size)
+            .expect("Failed to split generator into ggsw levels");
+
+        for (level_index, (mut level_matrix, mut loop_generator)) in
+            ggsw.iter_mut().zip(gen_iter).enumerate()
+        {
+            let decomp_level = DecompositionLevel(level_index + 1);
+            let gadget_factor =
+                Scalar::ONE << (Scalar::BITS - (decomp_base_log.0 * decomp_level.0));
+
+            let gen_iter = loop_generator
+                .fork_ggsw_level_to_glwe::<Scalar>(glwe_size, polynomial_size)
+                .expect("Failed to split generator into glwe");
+
+            let last_row_index = level_matrix.glwe_size().0 - 1;
+
+            for ((row_index, mut row_as_glwe), mut loop_generator) in level_matrix
+                .as_mut_seeded_glwe_list()
+                .iter_mut()
+                .enumerate()
+                .zip(gen_iter)
+            {
+                let mut body = row_as_glwe.get_mut_body();
+
+                if row_index < last_row_index {
+                    // Share of -s_j * S_m * g for the rows of the mask
+                    let key_product_share = element_key_product_shares.get(row_index);
+                    body.as_mut().copy_from_slice(key_product_share.as_ref());
+                    slice_wrapping_scalar_mul_assign(body.as_mut(), gadget_factor.wrapping_neg());
+                } else {
+                    // Share of s_j * g for the last row
+                    body.as_mut().fill(Scalar::ZERO);
+                    body.as_mut()[0] = input_key_element_share.wrapping_mul(gadget_factor);
+                }
+
+                encrypt_seeded_glwe_ciphertext_assign_with_existing_generator(
+                    output_glwe_secret_key_share,
+                    &mut row_as_glwe,
+                    noise_parameters,
+                    &mut loop_generator,
+                );
+            }
+        }
+    }
+}
+
+/// Sum the [`seeded LWE bootstrap key`](`SeededLweBootstrapKey`) shares of all the parties to
+/// obtain the seeded bootstrap key for the joint secret keys.
+///
+/// All the shares and the output must have the same parameters and the same seed.
+///
+/// See [`generate_seeded_lwe_bootstrap_key_share`] for usage.
+pub fn aggregate_seeded_lwe_bootstrap_key_shares<Scalar, InputCont, OutputCont>(
+    input_shares: &[SeededLweBootstrapKey<InputCont>],
+    output: &mut SeededLweBootstrapKey<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    for share in input_shares {
+        assert!(
+            share.glwe_size() == output.glwe_size()
+                && share.polynomial_size() == output.polynomial_size()
+                && share.decomposition_base_log() == output.decomposition_base_log()
+                && share.decomposition_level_count() == output.decomposition_level_count()
+                && share.input_lwe_dimension() == output.input_lwe_dimension()
+                && share.ciphertext_modulus() == output.ciphertext_modulus(),
+            "Mismatched parameters between a SeededLweBootstrapKey share and the output."
+        );
+        assert_eq!(
+            share.compression_seed(),
+            output.compression_seed(),
+            "Mismatched CompressionSeed between a SeededLweBootstrapKey share and the output."
+        );
+    }
+
+    aggregate_shares(
+        input_shares.iter().map(|share| share.as_ref()),
+        output.as_mut(),
+    );
+}
+
+/// Sum the [`seeded LWE keyswitch key`](`SeededLweKeyswitchKey`) shares of all the parties to
+/// obtain the seeded keyswitch key for the joint secret keys.
+///
+/// Shares are generated by calling [`generate_seeded_lwe_keyswitch_key`] with the secret key
+/// shares of a party and the seed common to all the parties. All the shares and the output must
+/// have the same parameters and the same seed.
+pub fn aggregate_seeded_lwe_keyswitch_key_shares<Scalar, InputCont, OutputCont>(
+    input_shares: &[SeededLweKeyswitchKey<InputCont>],
+    output: &mut SeededLweKeyswitchKey<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    for share in input_shares {
+        assert!(
+            share.decomposition_base_log() == output.decomposition_base_log()
+                && share.decomposition_level_count() == output.decomposition_level_count()
+                && share.input_key_lwe_dimension() == output.input_key_lwe_dimension()
+                && share.output_key_lwe_dimension() == output.output_key_lwe_dimension()
+                && share.ciphertext_modulus() == output.ciphertext_modulus(),
+            "Mismatched parameters between a SeededLweKeyswitchKey share and the output."
+        );
+        assert_eq!(
+            share.compression_seed(),
+            output.compression_seed(),
+            "Mismatched CompressionSeed between a SeededLweKeyswitchKey share and the output."
+        );
+    }
+
+    aggregate_shares(input_shares.iter().map(AsRef::as_ref), output.as_mut());
+}
+
+/// Sum the [`seeded LWE compact public key`](`SeededLweCompactPublicKey`) shares of all the
+/// parties to obtain the seeded compact public key for the joint secret key.
+///
+/// Shares are generated by calling [`generate_seeded_lwe_compact_public_key`] with the secret key
+/// share of a party and the seed common to all the parties. All the shares and the output must
+/// have the same parameters and the same seed.
+pub fn aggregate_seeded_lwe_compact_public_key_shares<Scalar, InputCont, OutputCont>(
+    input_shares: &[SeededLweCompactPublicKey<InputCont>],
+    output: &mut SeededLweCompactPublicKey<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    for share in input_shares {
+        assert!(
+            share.lwe_dimension() == output.lwe_dimension()
+                && share.ciphertext_modulus() == output.ciphertext_modulus(),
+            "Mismatched parameters between a SeededLweCompactPublicKey share and the output."
+        );
+        assert_eq!(
+            share.compression_seed(),
+            output.compression_seed(),
+            "Mismatched CompressionSeed between a SeededLweCompactPublicKey share and the output."
+        );
+    }
+
+    aggregate_shares(input_shares.iter().map(AsRef::as_ref), output.as_mut());
+}
+
+fn aggregate_shares<'a, Scalar: UnsignedInteger>(
+    input_shares: impl Iterator<Item = &'a [Scalar]>,
+    output: &mut [Scalar],
+) {
+    output.fill(Scalar::ZERO);
+    for share in input_shares {
+        slice_wrapping_add_assign(output, share);
+    }
+}
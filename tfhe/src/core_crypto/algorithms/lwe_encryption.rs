@@ -4,7 +4,7 @@
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::algorithms::*;
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulusKind;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, RandomGenerator};
 use crate::core_crypto::commons::parameters::*;
@@ -19,7 +19,7 @@ pub fn fill_lwe_mask_and_body_for_encryption<Scalar, KeyCont, OutputCont, Gen>(
     output_mask: &mut LweMask<OutputCont>,
     output_body: &mut LweBodyRefMut<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -68,7 +68,7 @@ pub fn fill_lwe_mask_and_body_for_encryption_native_mod_compatible<
     output_mask: &mut LweMask<OutputCont>,
     output_body: &mut LweBodyRefMut<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -121,7 +121,7 @@ pub fn fill_lwe_mask_and_body_for_encryption_other_mod<Scalar, KeyCont, OutputCo
     output_mask: &mut LweMask<OutputCont>,
     output_body: &mut LweBodyRefMut<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -224,7 +224,7 @@ pub fn encrypt_lwe_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -311,7 +311,7 @@ pub fn encrypt_lwe_ciphertext<Scalar, KeyCont, OutputCont, Gen>(
 pub fn allocate_and_encrypt_new_lwe_ciphertext<Scalar, KeyCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LweCiphertextOwned<Scalar>
@@ -668,7 +668,7 @@ pub fn encrypt_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont, Gen>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -677,6 +677,7 @@ pub fn encrypt_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont, Gen>(
     InputCont: Container<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.lwe_ciphertext_count().0 == encoded.plaintext_count().0,
         "Mismatch between number of output ciphertexts and input plaintexts. \
@@ -777,7 +778,7 @@ pub fn par_encrypt_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont, G
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut LweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -786,6 +787,7 @@ pub fn par_encrypt_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont, G
     InputCont: Container<Element = Scalar>,
     Gen: ParallelByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.lwe_ciphertext_count().0 == encoded.plaintext_count().0,
         "Mismatch between number of output ciphertexts and input plaintexts. \
@@ -1103,7 +1105,7 @@ pub fn encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -1112,6 +1114,7 @@ pub fn encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
     InputCont: Container<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.lwe_size().to_lwe_dimension() == lwe_secret_key.lwe_dimension(),
         "Mismatched LweDimension between input LweSecretKey {:?} and output \
@@ -1226,7 +1229,7 @@ pub fn encrypt_seeded_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, InputCont
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1262,7 +1265,7 @@ pub fn par_encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -1271,6 +1274,7 @@ pub fn par_encrypt_seeded_lwe_ciphertext_list_with_existing_generator<
     InputCont: Container<Element = Scalar>,
     Gen: ParallelByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output.lwe_size().to_lwe_dimension() == lwe_secret_key.lwe_dimension(),
         "Mismatched LweDimension between input LweSecretKey {:?} and output \
@@ -1386,7 +1390,7 @@ pub fn par_encrypt_seeded_lwe_ciphertext_list<Scalar, KeyCont, OutputCont, Input
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -1416,7 +1420,7 @@ pub fn encrypt_seeded_lwe_ciphertext_with_existing_generator<Scalar, KeyCont, Ge
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertext<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -1505,7 +1509,7 @@ pub fn encrypt_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     output: &mut SeededLweCiphertext<Scalar>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     noise_seeder: &mut NoiseSeeder,
 ) where
     Scalar: UnsignedTorus,
@@ -1587,7 +1591,7 @@ pub fn encrypt_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
 pub fn allocate_and_encrypt_new_seeded_lwe_ciphertext<Scalar, KeyCont, NoiseSeeder>(
     lwe_secret_key: &LweSecretKey<KeyCont>,
     encoded: Plaintext<Scalar>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     noise_seeder: &mut NoiseSeeder,
 ) -> SeededLweCiphertext<Scalar>
@@ -1691,8 +1695,8 @@ pub fn encrypt_lwe_ciphertext_with_compact_public_key<
     lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
     output: &mut LweCiphertext<OutputCont>,
     encoded: Plaintext<Scalar>,
-    mask_noise_parameters: impl DispersionParameter,
-    body_noise_parameters: impl DispersionParameter,
+    mask_noise_parameters: impl Into<NoiseDistribution>,
+    body_noise_parameters: impl Into<NoiseDistribution>,
     secret_generator: &mut SecretRandomGenerator<SecretGen>,
     encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
 ) where
@@ -1845,8 +1849,8 @@ pub fn encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
     lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
     output: &mut LweCompactCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    mask_noise_parameters: impl DispersionParameter,
-    body_noise_parameters: impl DispersionParameter,
+    mask_noise_parameters: impl Into<NoiseDistribution>,
+    body_noise_parameters: impl Into<NoiseDistribution>,
     secret_generator: &mut SecretRandomGenerator<SecretGen>,
     encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
 ) where
@@ -1857,6 +1861,8 @@ pub fn encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
     SecretGen: ByteRandomGenerator,
     EncryptionGen: ByteRandomGenerator,
 {
+    let mask_noise_parameters: NoiseDistribution = mask_noise_parameters.into();
+    let body_noise_parameters: NoiseDistribution = body_noise_parameters.into();
     assert!(
         output.lwe_size().to_lwe_dimension() == lwe_compact_public_key.lwe_dimension(),
         "Mismatch between LweDimension of output ciphertext and input public key. \
@@ -2053,8 +2059,8 @@ pub fn par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
     lwe_compact_public_key: &LweCompactPublicKey<KeyCont>,
     output: &mut LweCompactCiphertextList<OutputCont>,
     encoded: &PlaintextList<InputCont>,
-    mask_noise_parameters: impl DispersionParameter + Sync,
-    body_noise_parameters: impl DispersionParameter + Sync,
+    mask_noise_parameters: impl Into<NoiseDistribution>,
+    body_noise_parameters: impl Into<NoiseDistribution>,
     secret_generator: &mut SecretRandomGenerator<SecretGen>,
     encryption_generator: &mut EncryptionRandomGenerator<EncryptionGen>,
 ) where
@@ -2065,6 +2071,8 @@ pub fn par_encrypt_lwe_compact_ciphertext_list_with_compact_public_key<
     SecretGen: ByteRandomGenerator,
     EncryptionGen: ParallelByteRandomGenerator,
 {
+    let mask_noise_parameters: NoiseDistribution = mask_noise_parameters.into();
+    let body_noise_parameters: NoiseDistribution = body_noise_parameters.into();
     assert!(
         output.lwe_size().to_lwe_dimension() == lwe_compact_public_key.lwe_dimension(),
         "Mismatch between LweDimension of output ciphertext and input public key. \
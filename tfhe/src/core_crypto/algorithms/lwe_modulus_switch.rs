@@ -0,0 +1,593 @@
+//! Module containing primitives pertaining to the modulus switching of
+//! [`LWE ciphertexts`](`LweCiphertext`) to smaller power of 2 moduli.
+
+use crate::core_crypto::algorithms::blind_rotate_assign_mem_optimized_requirement;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use concrete_fft::c64;
+use dyn_stack::PodStack;
+
+/// Switch a value from the native modulus to the modulus $2^{\text{log\_modulus}}$, rounding to the
+/// closest value.
+///
+/// The returned value is in $[0, 2^{\text{log\_modulus}})$. Power of 2 moduli smaller than the
+/// native modulus are stored in the MSBs of the `Scalar` and can therefore be switched with this
+/// function as well.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let log_modulus = CiphertextModulusLog(12);
+///
+/// assert_eq!(modulus_switch(0u64, log_modulus), 0);
+/// assert_eq!(modulus_switch(3u64 << 52, log_modulus), 3);
+/// // Round to the closest value
+/// assert_eq!(modulus_switch((3u64 << 52) + (1 << 51), log_modulus), 4);
+/// assert_eq!(modulus_switch((3u64 << 52) + (1 << 51) - 1, log_modulus), 3);
+/// // Values close to the native modulus wrap around to 0
+/// assert_eq!(modulus_switch(u64::MAX, log_modulus), 0);
+/// ```
+pub fn modulus_switch<Scalar: UnsignedInteger>(
+    input: Scalar,
+    log_modulus: CiphertextModulusLog,
+) -> Scalar {
+    assert!(
+        log_modulus.0 > 0 && log_modulus.0 < Scalar::BITS,
+        "log_modulus ({}) must be in [1, {})",
+        log_modulus.0,
+        Scalar::BITS
+    );
+
+    // Start doing the right shift, keeping one more bit for the rounding
+    let mut output = input >> (Scalar::BITS - log_modulus.0 - 1);
+    // Do the rounding
+    output = output.wrapping_add(Scalar::ONE);
+    // Finish the right shift
+    output >>= 1;
+    // Wrap around the output modulus
+    output & ((Scalar::ONE << log_modulus.0) - Scalar::ONE)
+}
+
+fn power_of_two_ciphertext_modulus_log<Scalar: UnsignedInteger>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> usize {
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Modulus switching only supports power of 2 moduli, got {ciphertext_modulus:?}"
+    );
+
+    if ciphertext_modulus.is_native_modulus() {
+        Scalar::BITS
+    } else {
+        ciphertext_modulus.get_custom_modulus().ilog2() as usize
+    }
+}
+
+/// Switch an [`LWE ciphertext`](`LweCiphertext`) from its power of 2 [`CiphertextModulus`] $q$ to
+/// the smaller power of 2 [`CiphertextModulus`] $q'$ of the output ciphertext.
+///
+/// Each coefficient $x$ of the input is replaced by $\left\lfloor \frac{q'}{q} x \right\rceil$.
+/// The output ciphertext can be decrypted with the same secret key as the input ciphertext, the
+/// modulus switch adds a rounding noise per coefficient.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// // Switch to a 20 bits modulus
+/// let output_ciphertext_modulus = CiphertextModulus::try_new_power_of_2(20).unwrap();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// // Create a new LweCiphertext
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut switched_lwe = LweCiphertext::new(
+///     0u64,
+///     lwe_dimension.to_lwe_size(),
+///     output_ciphertext_modulus,
+/// );
+///
+/// lwe_ciphertext_modulus_switch(&lwe, &mut switched_lwe);
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &switched_lwe);
+///
+/// // The decrypted plaintext is in [0, 2^20), bring it back to the MSBs of the u64
+/// let decrypted_plaintext = decrypted_plaintext.0 << 44;
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 60;
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn lwe_ciphertext_modulus_switch<Scalar, InputCont, OutputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    output_lwe_ciphertext: &mut LweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input_lwe_ciphertext.lwe_size(),
+        output_lwe_ciphertext.lwe_size(),
+        "Mismatched LweSize between input_lwe_ciphertext ({:?}) and output_lwe_ciphertext ({:?})",
+        input_lwe_ciphertext.lwe_size(),
+        output_lwe_ciphertext.lwe_size(),
+    );
+
+    let input_log_modulus =
+        power_of_two_ciphertext_modulus_log(input_lwe_ciphertext.ciphertext_modulus());
+    let output_log_modulus =
+        power_of_two_ciphertext_modulus_log(output_lwe_ciphertext.ciphertext_modulus());
+
+    assert!(
+        output_log_modulus <= input_log_modulus,
+        "The output ciphertext modulus ({:?}) must not be greater than the input ciphertext \
+        modulus ({:?})",
+        output_lwe_ciphertext.ciphertext_modulus(),
+        input_lwe_ciphertext.ciphertext_modulus(),
+    );
+
+    if output_log_modulus == input_log_modulus {
+        output_lwe_ciphertext
+            .as_mut()
+            .copy_from_slice(input_lwe_ciphertext.as_ref());
+        return;
+    }
+
+    // Power of 2 moduli are stored in the MSBs of the Scalar, so the switched coefficients need to
+    // be shifted back to the MSBs
+    let shift = Scalar::BITS - output_log_modulus;
+    let output_log_modulus = CiphertextModulusLog(output_log_modulus);
+
+    for (dst, &src) in output_lwe_ciphertext
+        .as_mut()
+        .iter_mut()
+        .zip(input_lwe_ciphertext.as_ref().iter())
+    {
+        *dst = modulus_switch(src, output_log_modulus) << shift;
+    }
+}
+
+/// Modulus switch an [`LWE ciphertext`](`LweCiphertext`) and pack it.
+///
+/// The ciphertext is switched from its power of 2 [`CiphertextModulus`] to the modulus
+/// $2^{\text{log\_modulus}}$ and the switched coefficients are packed in a
+/// [`ModulusSwitchedLweCiphertext`].
+///
+/// The returned [`ModulusSwitchedLweCiphertext`] only stores `log_modulus` bits per coefficient.
+/// Choosing `log_modulus` equal to $\log\_2(2N)$, $N$ being the polynomial size of the bootstrap
+/// key that will be used for the next bootstrap, keeps all the information required by the blind
+/// rotation see [`blind_rotate_modulus_switched_lwe_ciphertext_assign`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// let log_modulus = CiphertextModulusLog(12);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Create the plaintext
+/// let msg = 3u64;
+/// let plaintext = Plaintext(msg << 60);
+///
+/// // Create a new LweCiphertext
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let ms_lwe = modulus_switch_and_pack_lwe_ciphertext(&lwe, log_modulus);
+///
+/// // 743 coefficients of 12 bits each, instead of 743 coefficients of 64 bits each
+/// assert_eq!(ms_lwe.packed_coeffs().len(), 140);
+///
+/// // Convert back to an LweCiphertext under the native modulus
+/// let extracted_lwe = ms_lwe.extract();
+///
+/// let decrypted_plaintext = decrypt_lwe_ciphertext(&lwe_secret_key, &extracted_lwe);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 60;
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn modulus_switch_and_pack_lwe_ciphertext<Scalar, InputCont>(
+    input_lwe_ciphertext: &LweCiphertext<InputCont>,
+    log_modulus: CiphertextModulusLog,
+) -> ModulusSwitchedLweCiphertext<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let ciphertext_modulus = input_lwe_ciphertext.ciphertext_modulus();
+    let input_log_modulus = power_of_two_ciphertext_modulus_log(ciphertext_modulus);
+
+    assert!(
+        log_modulus.0 <= input_log_modulus,
+        "log_modulus ({}) must not be greater than the log of the input ciphertext modulus ({})",
+        log_modulus.0,
+        input_log_modulus,
+    );
+
+    let switched_coeffs: Vec<Scalar> = if log_modulus.0 == Scalar::BITS {
+        input_lwe_ciphertext.as_ref().to_vec()
+    } else {
+        input_lwe_ciphertext
+            .as_ref()
+            .iter()
+            .map(|&x| modulus_switch(x, log_modulus))
+            .collect()
+    };
+
+    ModulusSwitchedLweCiphertext::from_switched_coeffs(
+        &switched_coeffs,
+        log_modulus,
+        ciphertext_modulus,
+    )
+}
+
+/// Map a coefficient switched to $2^{\text{log\_modulus}}$ to $\mathbb{Z}\_{2N}$.
+fn switched_coeff_to_monomial_degree<Scalar>(
+    coeff: Scalar,
+    log_modulus: CiphertextModulusLog,
+    polynomial_size: PolynomialSize,
+) -> MonomialDegree
+where
+    Scalar: UnsignedInteger + CastInto<usize>,
+{
+    let log_double_polynomial_size = polynomial_size.log2().0 + 1;
+
+    let degree = if log_modulus.0 > log_double_polynomial_size {
+        // Round to the closest multiple of 2^(log_modulus - log_2N)
+        let shift = log_modulus.0 - log_double_polynomial_size;
+        ((coeff >> (shift - 1)).wrapping_add(Scalar::ONE)) >> 1
+    } else {
+        coeff << (log_double_polynomial_size - log_modulus.0)
+    };
+
+    let degree: usize = degree.cast_into();
+
+    MonomialDegree(degree % (2 * polynomial_size.0))
+}
+
+/// Perform a blind rotation given an input [`ModulusSwitchedLweCiphertext`].
+///
+/// The look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) is modified in place using
+/// an [`LWE bootstrap key`](`LweBootstrapKey`) in the fourier domain see [`fourier LWE bootstrap
+/// key`](`FourierLweBootstrapKey`).
+///
+/// This is the equivalent of
+/// [`blind_rotate_assign`](`crate::core_crypto::algorithms::blind_rotate_assign`) for
+/// ciphertexts which have already been modulus switched, the coefficients are mapped to
+/// $\mathbb{Z}\_{2N}$ directly from their packed representation.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`blind_rotate_modulus_switched_lwe_ciphertext_assign_mem_optimized`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // This example recreates a PBS by combining a modulus switch, a blind rotate and a sample
+/// // extract.
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// // log2(2N), the number of bits used by the blind rotation
+/// let log_modulus = CiphertextModulusLog(polynomial_size.log2().0 + 1);
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// println!("Generating keys...");
+///
+/// // Generate an LweSecretKey with binary coefficients
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+///
+/// // Generate a GlweSecretKey with binary coefficients
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+///
+/// // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // Generate the bootstrapping key, we use the parallel variant for performance reason
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the empty bootstrapping key in the Fourier domain
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+///
+/// // Use the conversion function (a memory optimized version also exists but is more complicated
+/// // to use) to convert the standard bootstrapping key to the Fourier domain
+/// convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// // We don't need the standard bootstrapping key anymore
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+///
+/// // Our input message
+/// let input_message = 3u64;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// // Apply our encoding
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// // Allocate a new LweCiphertext and encrypt our plaintext
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Switch and pack the ciphertext, this is what would be stored or sent over the network
+/// let ms_lwe_ciphertext_in =
+///     modulus_switch_and_pack_lwe_ciphertext(&lwe_ciphertext_in, log_modulus);
+///
+/// // Build the accumulator for our multiplication by 2
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
+///
+/// // Fill each box with the encoded denoised value
+/// for i in 0..message_modulus as usize {
+///     let index = i * box_size;
+///     accumulator_u64[index..index + box_size]
+///         .iter_mut()
+///         .for_each(|a| *a = 2 * (i as u64) * delta);
+/// }
+///
+/// let half_box_size = box_size / 2;
+///
+/// // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+///
+/// // Rotate the accumulator
+/// accumulator_u64.rotate_left(half_box_size);
+///
+/// let mut accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// // Allocate the LweCiphertext to store the result of the PBS
+/// let mut pbs_multiplication_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+/// println!("Performing blind rotation...");
+/// blind_rotate_modulus_switched_lwe_ciphertext_assign(
+///     &ms_lwe_ciphertext_in,
+///     &mut accumulator,
+///     &fourier_bsk,
+/// );
+/// println!("Performing sample extraction...");
+/// extract_lwe_sample_from_glwe_ciphertext(
+///     &accumulator,
+///     &mut pbs_multiplication_ct,
+///     MonomialDegree(0),
+/// );
+///
+/// // Decrypt the PBS multiplication result
+/// let pbs_multiplication_plaintext: Plaintext<u64> =
+///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+///
+/// // Create a SignedDecomposer to perform the rounding of the decrypted plaintext
+/// // We pass a DecompositionBaseLog of 5 and a DecompositionLevelCount of 1 indicating we want to
+/// // round the 5 MSB, 1 bit of padding plus our 4 bits of message
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// // Round and remove our encoding
+/// let pbs_multiplication_result: u64 =
+///     signed_decomposer.closest_representable(pbs_multiplication_plaintext.0) / delta;
+///
+/// println!("Checking result...");
+/// assert_eq!(6, pbs_multiplication_result);
+/// println!(
+///     "Multiplication via PBS result is correct! Expected 6, got {pbs_multiplication_result}"
+/// );
+/// ```
+pub fn blind_rotate_modulus_switched_lwe_ciphertext_assign<Scalar, OutputCont, KeyCont>(
+    input: &ModulusSwitchedLweCiphertext<Scalar>,
+    lut: &mut GlweCiphertext<OutputCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        blind_rotate_assign_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    blind_rotate_modulus_switched_lwe_ciphertext_assign_mem_optimized(
+        input,
+        lut,
+        fourier_bsk,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`blind_rotate_modulus_switched_lwe_ciphertext_assign`].
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`blind_rotate_assign_mem_optimized_requirement`].
+pub fn blind_rotate_modulus_switched_lwe_ciphertext_assign_mem_optimized<
+    Scalar,
+    OutputCont,
+    KeyCont,
+>(
+    input: &ModulusSwitchedLweCiphertext<Scalar>,
+    lut: &mut GlweCiphertext<OutputCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_eq!(
+        input.uncompressed_ciphertext_modulus(),
+        lut.ciphertext_modulus()
+    );
+    assert_eq!(
+        input.lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+        "Mismatched LweDimension between input ({:?}) and fourier_bsk input ({:?})",
+        input.lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+    );
+
+    let log_modulus = input.log_modulus();
+    let polynomial_size = lut.polynomial_size();
+
+    let monomial_degrees: Vec<MonomialDegree> = input
+        .switched_coeffs()
+        .map(|coeff| switched_coeff_to_monomial_degree(coeff, log_modulus, polynomial_size))
+        .collect();
+    let (body_monomial_degree, mask_monomial_degrees) = monomial_degrees.split_last().unwrap();
+
+    // Blind rotate assign manages the rounding to go back to the proper torus if the ciphertext
+    // modulus is not the native one
+    fourier_bsk
+        .as_view()
+        .blind_rotate_assign_with_monomial_degrees(
+            lut.as_mut_view(),
+            *body_monomial_degree,
+            mask_monomial_degrees.iter().copied(),
+            fft,
+            stack,
+        );
+}
@@ -0,0 +1,555 @@
+//! Module containing primitives pertaining to the [`LWE programmable
+//! bootstrap`](`LweBootstrapKey#programmable-bootstrapping`) in the NTT domain, for prime
+//! ciphertext moduli.
+//!
+//! As opposed to the FFT based implementations, the polynomial products computed in the NTT domain
+//! are exact, the only noise added by the external product comes from the gadget decomposition.
+
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::ntt_impl::ntt64::crypto::bootstrap::bootstrap_scratch;
+use crate::core_crypto::ntt_impl::ntt64::crypto::ggsw::{
+    add_external_product_assign as impl_add_external_product_assign,
+    add_external_product_assign_scratch as impl_add_external_product_assign_scratch, cmux,
+    cmux_scratch,
+};
+use crate::core_crypto::ntt_impl::ntt64::math::ntt::{Ntt64, Ntt64View};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// Compute the external product of `ggsw` and `glwe`, and add the result to `out`, with the
+/// [`GGSW ciphertext`](`GgswCiphertext`) in the NTT domain see [`NTT GGSW
+/// ciphertext`](`NttGgswCiphertext`).
+///
+/// Strictly speaking this function computes:
+///
+/// ```text
+/// out <- out + glwe * ggsw
+/// ```
+///
+/// If you want to manage the computation memory manually you can use
+/// [`add_external_product_ntt64_assign_mem_optimized`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for GgswCiphertext creation
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(2048);
+/// let decomp_base_log = DecompositionBaseLog(23);
+/// let decomp_level_count = DecompositionLevelCount(1);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// // The Solinas prime 2^64 - 2^32 + 1 supports NTTs up to size 2^32
+/// let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the GlweSecretKey
+/// let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_size.to_glwe_dimension(),
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg_ggsw = Plaintext(2u64);
+///
+/// // Create a new GgswCiphertext
+/// let mut ggsw = GgswCiphertext::new(
+///     0u64,
+///     glwe_size,
+///     polynomial_size,
+///     decomp_base_log,
+///     decomp_level_count,
+///     ciphertext_modulus,
+/// );
+///
+/// encrypt_constant_ggsw_ciphertext(
+///     &glwe_secret_key,
+///     &mut ggsw,
+///     msg_ggsw,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let ct_plaintext = Plaintext(3u64 << 55);
+///
+/// let ct_plaintexts = PlaintextList::new(ct_plaintext.0, PlaintextCount(polynomial_size.0));
+/// let mut ct = GlweCiphertext::new(0u64, glwe_size, polynomial_size, ciphertext_modulus);
+///
+/// encrypt_glwe_ciphertext(
+///     &glwe_secret_key,
+///     &mut ct,
+///     &ct_plaintexts,
+///     glwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut res = ct.clone();
+///
+/// // Convert the GGSW ciphertext to the NTT domain
+/// let mut ntt_ggsw = NttGgswCiphertextOwned::new(
+///     glwe_size,
+///     polynomial_size,
+///     decomp_base_log,
+///     decomp_level_count,
+///     ciphertext_modulus,
+/// );
+/// convert_standard_ggsw_ciphertext_to_ntt64(&ggsw, &mut ntt_ggsw);
+///
+/// add_external_product_ntt64_assign(&mut res, &ntt_ggsw, &ct);
+///
+/// let mut output_plaintext_list = PlaintextList::new(0u64, ct_plaintexts.plaintext_count());
+///
+/// decrypt_glwe_ciphertext(&glwe_secret_key, &res, &mut output_plaintext_list);
+///
+/// // Round to the 9 MSBs of the plaintexts, the remaining bits are noise
+/// let delta = 1u64 << 55;
+/// output_plaintext_list
+///     .iter_mut()
+///     .for_each(|x| *x.0 = (*x.0 + delta / 2) / delta * delta);
+///
+/// // As we cloned the input ciphertext for the output, the external product result is added to the
+/// // originally contained value, hence why we expect ct_plaintext + ct_plaintext * msg_ggsw
+/// assert!(output_plaintext_list
+///     .iter()
+///     .all(|x| *x.0 == ct_plaintext.0 + ct_plaintext.0 * msg_ggsw.0));
+/// ```
+pub fn add_external_product_ntt64_assign<OutputGlweCont, InputGlweCont, GgswCont>(
+    out: &mut GlweCiphertext<OutputGlweCont>,
+    ggsw: &NttGgswCiphertext<GgswCont>,
+    glwe: &GlweCiphertext<InputGlweCont>,
+) where
+    OutputGlweCont: ContainerMut<Element = u64>,
+    GgswCont: Container<Element = u64>,
+    InputGlweCont: Container<Element = u64>,
+{
+    let ntt = Ntt64::new(ggsw.polynomial_size(), ggsw.ciphertext_modulus());
+    let ntt = ntt.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        add_external_product_ntt64_assign_mem_optimized_requirement(
+            ggsw.glwe_size(),
+            ggsw.polynomial_size(),
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    add_external_product_ntt64_assign_mem_optimized(out, ggsw, glwe, ntt, buffers.stack());
+}
+
+/// Memory optimized version of [`add_external_product_ntt64_assign`], the caller must provide a
+/// properly configured [`Ntt64View`] object and a `PodStack` used as a memory buffer having a
+/// capacity at least as large as the result of
+/// [`add_external_product_ntt64_assign_mem_optimized_requirement`].
+pub fn add_external_product_ntt64_assign_mem_optimized<OutputGlweCont, InputGlweCont, GgswCont>(
+    out: &mut GlweCiphertext<OutputGlweCont>,
+    ggsw: &NttGgswCiphertext<GgswCont>,
+    glwe: &GlweCiphertext<InputGlweCont>,
+    ntt: Ntt64View<'_>,
+    stack: PodStack<'_>,
+) where
+    OutputGlweCont: ContainerMut<Element = u64>,
+    GgswCont: Container<Element = u64>,
+    InputGlweCont: Container<Element = u64>,
+{
+    assert_eq!(out.ciphertext_modulus(), glwe.ciphertext_modulus());
+    assert_eq!(out.ciphertext_modulus(), ggsw.ciphertext_modulus());
+    assert_eq!(
+        ntt.custom_modulus() as u128,
+        ggsw.ciphertext_modulus().get_custom_modulus()
+    );
+
+    impl_add_external_product_assign(out, ggsw, glwe, ntt, stack);
+}
+
+/// Return the required memory for [`add_external_product_ntt64_assign_mem_optimized`].
+pub fn add_external_product_ntt64_assign_mem_optimized_requirement(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    impl_add_external_product_assign_scratch(glwe_size, polynomial_size)
+}
+
+/// Compute a cmux on the input `ct0` and `ct1` using `ggsw` as selector, with `ggsw` in the NTT
+/// domain.
+///
+/// `ct0` and `ct1` are both modified by this operation, the result is stored in `ct0` at the end
+/// of the computation.
+///
+/// Strictly speaking this function computes:
+///
+/// ```text
+/// ct1 <- ct1 - ct0
+/// ct0 <- ct1 * ggsw + ct0
+/// ```
+///
+/// See [`cmux_assign`](`crate::core_crypto::algorithms::cmux_assign`) for the semantics of the
+/// operation.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`cmux_ntt64_assign_mem_optimized`].
+pub fn cmux_ntt64_assign<Cont0, Cont1, GgswCont>(
+    ct0: &mut GlweCiphertext<Cont0>,
+    ct1: &mut GlweCiphertext<Cont1>,
+    ggsw: &NttGgswCiphertext<GgswCont>,
+) where
+    Cont0: ContainerMut<Element = u64>,
+    Cont1: ContainerMut<Element = u64>,
+    GgswCont: Container<Element = u64>,
+{
+    let ntt = Ntt64::new(ggsw.polynomial_size(), ggsw.ciphertext_modulus());
+    let ntt = ntt.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        cmux_ntt64_assign_mem_optimized_requirement(ggsw.glwe_size(), ggsw.polynomial_size())
+            .unwrap()
+            .unaligned_bytes_required(),
+    );
+
+    cmux_ntt64_assign_mem_optimized(ct0, ct1, ggsw, ntt, buffers.stack());
+}
+
+/// Memory optimized version of [`cmux_ntt64_assign`], the caller must provide a properly
+/// configured [`Ntt64View`] object and a `PodStack` used as a memory buffer having a capacity at
+/// least as large as the result of [`cmux_ntt64_assign_mem_optimized_requirement`].
+pub fn cmux_ntt64_assign_mem_optimized<Cont0, Cont1, GgswCont>(
+    ct0: &mut GlweCiphertext<Cont0>,
+    ct1: &mut GlweCiphertext<Cont1>,
+    ggsw: &NttGgswCiphertext<GgswCont>,
+    ntt: Ntt64View<'_>,
+    stack: PodStack<'_>,
+) where
+    Cont0: ContainerMut<Element = u64>,
+    Cont1: ContainerMut<Element = u64>,
+    GgswCont: Container<Element = u64>,
+{
+    assert_eq!(ct0.ciphertext_modulus(), ct1.ciphertext_modulus());
+    assert_eq!(ct0.ciphertext_modulus(), ggsw.ciphertext_modulus());
+    assert_eq!(
+        ntt.custom_modulus() as u128,
+        ggsw.ciphertext_modulus().get_custom_modulus()
+    );
+
+    cmux(ct0, ct1, ggsw, ntt, stack);
+}
+
+/// Return the required memory for [`cmux_ntt64_assign_mem_optimized`].
+pub fn cmux_ntt64_assign_mem_optimized_requirement(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    cmux_scratch(glwe_size, polynomial_size)
+}
+
+/// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
+/// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
+/// key`](`LweBootstrapKey`) in the NTT domain see [`NTT LWE bootstrap
+/// key`](`NttLweBootstrapKey`). The result is written in the provided output
+/// [`LWE ciphertext`](`LweCiphertext`).
+///
+/// All the inputs must share the same prime [`CiphertextModulus`], compatible with the NTT, see
+/// [`Ntt64::is_supported_modulus`].
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define the parameters for a 4 bits message able to hold the doubled 2 bits message
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// // The Solinas prime 2^64 - 2^32 + 1 supports NTTs up to size 2^32
+/// let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+///
+/// // Request the best seeder possible, starting with hardware entropy sources and falling back to
+/// // /dev/random on Unix systems if enabled via cargo features
+/// let mut boxed_seeder = new_seeder();
+/// // Get a mutable reference to the seeder as a trait object from the Box returned by new_seeder
+/// let seeder = boxed_seeder.as_mut();
+///
+/// // Create a generator which uses a CSPRNG to generate secret keys
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create a generator which uses two CSPRNGs to generate public masks and secret encryption
+/// // noise
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// println!("Generating keys...");
+///
+/// // Generate an LweSecretKey with binary coefficients
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+///
+/// // Generate a GlweSecretKey with binary coefficients
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+///
+/// // Create a copy of the GlweSecretKey re-interpreted as an LweSecretKey
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // Generate the bootstrapping key, we use the parallel variant for performance reason
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Create the empty bootstrapping key in the NTT domain
+/// let mut ntt_bsk = NttLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+///     ciphertext_modulus,
+/// );
+///
+/// // Use the conversion function to convert the standard bootstrapping key to the NTT domain
+/// par_convert_standard_lwe_bootstrap_key_to_ntt64(&std_bootstrapping_key, &mut ntt_bsk);
+/// // We don't need the standard bootstrapping key anymore
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+///
+/// // Our input message
+/// let input_message = 3u64;
+///
+/// // Delta used to encode 4 bits of message + a bit of padding on our prime modulus
+/// let delta = (ciphertext_modulus.get_custom_modulus() as u64 / 2) / message_modulus;
+///
+/// // Apply our encoding
+/// let plaintext = Plaintext(input_message * delta);
+///
+/// // Allocate a new LweCiphertext and encrypt our plaintext
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     plaintext,
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Now we will use a PBS to compute a multiplication by 2, it is NOT the recommended way of
+/// // doing this operation in terms of performance as it's much more costly than a multiplication
+/// // with a cleartext, however it resets the noise in a ciphertext to a nominal level and allows
+/// // to evaluate arbitrary functions so depending on your use case it can be a better fit.
+///
+/// // Here we will define a helper function to generate an accumulator for a PBS
+/// fn generate_accumulator<F>(
+///     polynomial_size: PolynomialSize,
+///     glwe_size: GlweSize,
+///     message_modulus: usize,
+///     ciphertext_modulus: CiphertextModulus<u64>,
+///     delta: u64,
+///     f: F,
+/// ) -> GlweCiphertextOwned<u64>
+/// where
+///     F: Fn(u64) -> u64,
+/// {
+///     // N/(p/2) = size of each block, to correct noise from the input we introduce the notion of
+///     // box, which manages redundancy to yield a denoised value for several noisy values around
+///     // a true input value.
+///     let box_size = polynomial_size.0 / message_modulus;
+///     let modulus = ciphertext_modulus.get_custom_modulus() as u64;
+///
+///     // Create the accumulator
+///     let mut accumulator_u64 = vec![0_u64; polynomial_size.0];
+///
+///     // Fill each box with the encoded denoised value
+///     for i in 0..message_modulus {
+///         let index = i * box_size;
+///         accumulator_u64[index..index + box_size]
+///             .iter_mut()
+///             .for_each(|a| *a = f(i as u64) * delta);
+///     }
+///
+///     let half_box_size = box_size / 2;
+///
+///     // Negate the first half_box_size coefficients to manage negacyclicity and rotate
+///     for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///         *a_i = (modulus - *a_i) % modulus;
+///     }
+///
+///     // Rotate the accumulator
+///     accumulator_u64.rotate_left(half_box_size);
+///
+///     let accumulator_plaintext = PlaintextList::from_container(accumulator_u64);
+///
+///     allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///         glwe_size,
+///         &accumulator_plaintext,
+///         ciphertext_modulus,
+///     )
+/// }
+///
+/// // Generate the accumulator for our multiplication by 2 using a simple closure
+/// let accumulator: GlweCiphertextOwned<u64> = generate_accumulator(
+///     polynomial_size,
+///     glwe_dimension.to_glwe_size(),
+///     message_modulus as usize,
+///     ciphertext_modulus,
+///     delta,
+///     |x: u64| 2 * x,
+/// );
+///
+/// // Allocate the LweCiphertext to store the result of the PBS
+/// let mut pbs_multiplication_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+/// println!("Computing PBS...");
+/// programmable_bootstrap_ntt64_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut pbs_multiplication_ct,
+///     &accumulator,
+///     &ntt_bsk,
+/// );
+///
+/// // Decrypt the PBS multiplication result
+/// let pbs_multiplication_plaintext: Plaintext<u64> =
+///     decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_multiplication_ct);
+///
+/// // Round and remove our encoding
+/// let pbs_multiplication_result: u64 =
+///     ((pbs_multiplication_plaintext.0 as u128 + delta as u128 / 2) / delta as u128) as u64
+///         % message_modulus;
+///
+/// println!("Checking result...");
+/// assert_eq!(6, pbs_multiplication_result);
+/// println!(
+///     "Multiplication via PBS result is correct! Expected 6, got {pbs_multiplication_result}"
+/// );
+/// ```
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext<InputCont, OutputCont, AccCont, KeyCont>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let ntt = Ntt64::new(ntt_bsk.polynomial_size(), ntt_bsk.ciphertext_modulus());
+    let ntt = ntt.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+            ntt_bsk.glwe_size(),
+            ntt_bsk.polynomial_size(),
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        ntt_bsk,
+        ntt,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`programmable_bootstrap_ntt64_lwe_ciphertext`], the caller must
+/// provide a properly configured [`Ntt64View`] object and a `PodStack` used as a memory buffer
+/// having a capacity at least as large as the result of
+/// [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement`].
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized<
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    ntt_bsk: &NttLweBootstrapKey<KeyCont>,
+    ntt: Ntt64View<'_>,
+    stack: PodStack<'_>,
+) where
+    InputCont: Container<Element = u64>,
+    OutputCont: ContainerMut<Element = u64>,
+    AccCont: Container<Element = u64>,
+    KeyCont: Container<Element = u64>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        ntt_bsk.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between ntt_bsk ({:?}) and output ({:?})",
+        ntt_bsk.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        ntt.custom_modulus() as u128,
+        ntt_bsk.ciphertext_modulus().get_custom_modulus()
+    );
+
+    ntt_bsk
+        .as_view()
+        .bootstrap(output, input, accumulator, ntt, stack);
+}
+
+/// Return the required memory for [`programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized`].
+pub fn programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    bootstrap_scratch(glwe_size, polynomial_size)
+}
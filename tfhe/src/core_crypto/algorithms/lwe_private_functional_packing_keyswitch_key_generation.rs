@@ -3,7 +3,7 @@
 
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::math::decomposition::{DecompositionLevel, DecompositionTerm};
 use crate::core_crypto::commons::parameters::*;
@@ -28,7 +28,7 @@ pub fn generate_lwe_private_functional_packing_keyswitch_key<
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     lwe_pfpksk: &mut LwePrivateFunctionalPackingKeyswitchKey<KSKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
     f: ScalarFunc,
     polynomial: &Polynomial<PolyCont>,
@@ -41,6 +41,7 @@ pub fn generate_lwe_private_functional_packing_keyswitch_key<
     ScalarFunc: Fn(Scalar) -> Scalar,
     PolyCont: Container<Element = Scalar>,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         input_lwe_secret_key.lwe_dimension() == lwe_pfpksk.input_key_lwe_dimension(),
         "Mismatched LweDimension between input_lwe_secret_key {:?} and lwe_pfpksk input dimension \
@@ -147,7 +148,7 @@ pub fn par_generate_lwe_private_functional_packing_keyswitch_key<
     input_lwe_secret_key: &LweSecretKey<InputKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<OutputKeyCont>,
     lwe_pfpksk: &mut LwePrivateFunctionalPackingKeyswitchKey<KSKeyCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
     f: ScalarFunc,
     polynomial: &Polynomial<PolyCont>,
@@ -160,6 +161,7 @@ pub fn par_generate_lwe_private_functional_packing_keyswitch_key<
     ScalarFunc: Fn(Scalar) -> Scalar + Sync,
     PolyCont: Container<Element = Scalar> + Sync,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         input_lwe_secret_key.lwe_dimension() == lwe_pfpksk.input_key_lwe_dimension(),
         "Mismatched LweDimension between input_lwe_secret_key {:?} and lwe_pfpksk input dimension \
@@ -1,6 +1,7 @@
 //! Module containing primitives pertaining to the [`LWE programmable
 //! bootstrap`](`LweBootstrapKey#programmable-bootstrapping`).
 
+use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
 use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
 use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
 use crate::core_crypto::commons::parameters::*;
@@ -11,7 +12,7 @@ use crate::core_crypto::fft_impl::fft128::crypto::bootstrap::{
 };
 use crate::core_crypto::fft_impl::fft128::math::fft::{Fft128, Fft128View};
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
-    bootstrap_scratch, FourierLweBootstrapKey,
+    batch_bootstrap_scratch, bootstrap_scratch, FourierLweBootstrapKey,
 };
 use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     add_external_product_assign as impl_add_external_product_assign,
@@ -20,6 +21,7 @@ use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
 };
 use crate::core_crypto::fft_impl::fft64::crypto::wop_pbs::blind_rotate_assign_scratch;
 use crate::core_crypto::fft_impl::fft64::math::fft::{Fft, FftView};
+use aligned_vec::CACHELINE_ALIGN;
 use concrete_fft::c64;
 use dyn_stack::{PodStack, SizeOverflow, StackReq};
 
@@ -1119,6 +1121,1582 @@ pub fn programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement<Scalar>(
     bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, fft)
 }
 
+/// Perform a programmable bootstrap of each [`LWE ciphertext`](`LweCiphertext`) of an input
+/// [`LWE ciphertext list`](`LweCiphertextList`) with the same look-up table passed as a
+/// [`GLWE ciphertext`](`GlweCiphertext`) and [`fourier LWE bootstrap
+/// key`](`FourierLweBootstrapKey`). The results are written in the provided output
+/// [`LWE ciphertext list`](`LweCiphertextList`).
+///
+/// Compared to calling [`programmable_bootstrap_lwe_ciphertext`] in a loop, the key is iterated
+/// over once for the whole list, each GGSW ciphertext being applied to all the inputs before
+/// moving on to the next one, and the computation buffers are shared by all the bootstraps. This
+/// improves throughput when many ciphertexts need to be bootstrapped with the same key, the caller
+/// can still split a large list in chunks to process them in parallel.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_lwe_ciphertext_list_mem_optimized`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+/// par_convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+/// // Delta used to encode 4 bits of message + a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// let input_messages = [0u64, 1, 2, 3, 4, 5, 6, 7];
+/// let plaintext_list = PlaintextList::from_container(
+///     input_messages
+///         .iter()
+///         .map(|&m| m * delta)
+///         .collect::<Vec<_>>(),
+/// );
+///
+/// let mut input_lwe_list = LweCiphertextList::new(
+///     0u64,
+///     small_lwe_dimension.to_lwe_size(),
+///     LweCiphertextCount(input_messages.len()),
+///     ciphertext_modulus,
+/// );
+/// encrypt_lwe_ciphertext_list(
+///     &small_lwe_sk,
+///     &mut input_lwe_list,
+///     &plaintext_list,
+///     lwe_modular_std_dev,
+///     &mut encryption_generator,
+/// );
+///
+/// // Build the accumulator computing a multiplication by 2: each of the message_modulus boxes of
+/// // the look-up table holds the encoded output, rotated by half a box to center the boxes on the
+/// // input values
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|idx| 2 * (idx / box_size) as u64 * delta)
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut output_lwe_list = LweCiphertextList::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     input_lwe_list.lwe_ciphertext_count(),
+///     ciphertext_modulus,
+/// );
+///
+/// programmable_bootstrap_lwe_ciphertext_list(
+///     &input_lwe_list,
+///     &mut output_lwe_list,
+///     &accumulator,
+///     &fourier_bsk,
+/// );
+///
+/// let mut decrypted_plaintext_list =
+///     PlaintextList::new(0u64, PlaintextCount(input_messages.len()));
+/// decrypt_lwe_ciphertext_list(&big_lwe_sk, &output_lwe_list, &mut decrypted_plaintext_list);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// for (decrypted, input) in decrypted_plaintext_list.iter().zip(input_messages.iter()) {
+///     let result = signed_decomposer.closest_representable(*decrypted.0) / delta;
+///     assert_eq!(result, 2 * input);
+/// }
+/// ```
+pub fn programmable_bootstrap_lwe_ciphertext_list<Scalar, InputCont, OutputCont, AccCont, KeyCont>(
+    input: &LweCiphertextList<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_lwe_ciphertext_list_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            input.lwe_ciphertext_count(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_lwe_ciphertext_list_mem_optimized(
+        input,
+        output,
+        accumulator,
+        fourier_bsk,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`programmable_bootstrap_lwe_ciphertext_list`], the caller must
+/// provide a properly configured [`FftView`] object and a `PodStack` used as a memory buffer having
+/// a capacity at least as large as the result of
+/// [`programmable_bootstrap_lwe_ciphertext_list_mem_optimized_requirement`].
+pub fn programmable_bootstrap_lwe_ciphertext_list_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertextList<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_eq!(
+        input.lwe_ciphertext_count(),
+        output.lwe_ciphertext_count(),
+        "Mismatched LweCiphertextCount between input ({:?}) and output ({:?})",
+        input.lwe_ciphertext_count(),
+        output.lwe_ciphertext_count()
+    );
+
+    assert_eq!(
+        input.lwe_size().to_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+        "Mismatched input LweDimension. LweCiphertextList input LweDimension {:?}. \
+        FourierLweBootstrapKey input LweDimension {:?}.",
+        input.lwe_size().to_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+    );
+
+    assert_eq!(
+        output.lwe_size().to_lwe_dimension(),
+        fourier_bsk.output_lwe_dimension(),
+        "Mismatched output LweDimension. LweCiphertextList output LweDimension {:?}. \
+        FourierLweBootstrapKey output LweDimension {:?}.",
+        output.lwe_size().to_lwe_dimension(),
+        fourier_bsk.output_lwe_dimension(),
+    );
+
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    fourier_bsk.as_view().batch_bootstrap(
+        output.as_mut_view(),
+        input.as_view(),
+        accumulator.as_view(),
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for [`programmable_bootstrap_lwe_ciphertext_list_mem_optimized`].
+pub fn programmable_bootstrap_lwe_ciphertext_list_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    lwe_ciphertext_count: LweCiphertextCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    batch_bootstrap_scratch::<Scalar>(glwe_size, polynomial_size, lwe_ciphertext_count, fft)
+}
+
+/// Perform a programmable bootstrap evaluating several look-up tables at once on an input
+/// [`LWE ciphertext`](`LweCiphertext`), the look-up tables being packed in a single accumulator
+/// passed as a [`GLWE ciphertext`](`GlweCiphertext`) and using a [`fourier LWE bootstrap
+/// key`](`FourierLweBootstrapKey`). The results are written in the provided output
+/// [`LWE ciphertext list`](`LweCiphertextList`), one ciphertext per look-up table.
+///
+/// The accumulator is split in $2^{\text{lut\_count\_log}}$ chunks of
+/// $N / 2^{\text{lut\_count\_log}}$ coefficients, the $i$-th chunk containing the $i$-th look-up
+/// table. A single blind rotation is computed and the $i$-th output is sample extracted at
+/// [`MonomialDegree`] $i \cdot N / 2^{\text{lut\_count\_log}}$. The input message space is
+/// therefore reduced by a factor $2^{\text{lut\_count\_log}}$ compared to a regular PBS: the
+/// rotation induced by the input must stay within the first chunk for the outputs to be correct.
+///
+/// The output list can contain fewer ciphertexts than there are chunks in the accumulator, in
+/// which case the trailing chunks are ignored.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let small_lwe_sk =
+///     LweSecretKey::generate_new_binary(small_lwe_dimension, &mut secret_generator);
+/// let glwe_sk =
+///     GlweSecretKey::generate_new_binary(glwe_dimension, polynomial_size, &mut secret_generator);
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+/// par_convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+/// // Delta used to encode 4 bits of message + a bit of padding on u64
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// // We evaluate 2 look-up tables, so the input must fit on 3 bits
+/// let lut_count_log = LutCountLog(1);
+/// let input_message_modulus = message_modulus >> lut_count_log.0;
+/// let input_message = 5u64;
+///
+/// let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(input_message * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // The functions to evaluate, their outputs must fit in the 4 bits message space
+/// let functions = [|x: u64| x, |x: u64| 2 * x];
+///
+/// // Each of the message_modulus boxes of the accumulator holds the output of the function
+/// // associated to its chunk, the whole accumulator is then rotated by half a box to center the
+/// // boxes on the input values
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|idx| {
+///         let box_idx = (idx / box_size) as u64;
+///         let function = functions[(box_idx / input_message_modulus) as usize];
+///         function(box_idx % input_message_modulus) * delta
+///     })
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut output_lwe_list = LweCiphertextList::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     LweCiphertextCount(functions.len()),
+///     ciphertext_modulus,
+/// );
+///
+/// programmable_bootstrap_lwe_ciphertext_many_lut(
+///     &lwe_ciphertext_in,
+///     &mut output_lwe_list,
+///     &accumulator,
+///     &fourier_bsk,
+///     lut_count_log,
+/// );
+///
+/// let mut decrypted_plaintext_list = PlaintextList::new(0u64, PlaintextCount(functions.len()));
+/// decrypt_lwe_ciphertext_list(&big_lwe_sk, &output_lwe_list, &mut decrypted_plaintext_list);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// for (decrypted, function) in decrypted_plaintext_list.iter().zip(functions.iter()) {
+///     let result = signed_decomposer.closest_representable(*decrypted.0) / delta;
+///     assert_eq!(result, function(input_message));
+/// }
+/// ```
+pub fn programmable_bootstrap_lwe_ciphertext_many_lut<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    lut_count_log: LutCountLog,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized(
+        input,
+        output,
+        accumulator,
+        fourier_bsk,
+        lut_count_log,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`programmable_bootstrap_lwe_ciphertext_many_lut`], the caller must
+/// provide a properly configured [`FftView`] object and a `PodStack` used as a memory buffer having
+/// a capacity at least as large as the result of
+/// [`programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized_requirement`].
+pub fn programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    lut_count_log: LutCountLog,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert!(
+        output.lwe_ciphertext_count().0 <= 1 << lut_count_log.0,
+        "Got {:?} output ciphertexts for {:?}, at most {} outputs can be extracted",
+        output.lwe_ciphertext_count(),
+        lut_count_log,
+        1 << lut_count_log.0
+    );
+
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert_eq!(
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between accumulator ({:?}) and output ({:?})",
+        accumulator.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    let (mut local_accumulator_data, stack) =
+        stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+    let mut local_accumulator = GlweCiphertextMutView::from_container(
+        &mut *local_accumulator_data,
+        accumulator.polynomial_size(),
+        accumulator.ciphertext_modulus(),
+    );
+
+    blind_rotate_assign_mem_optimized(input, &mut local_accumulator, fourier_bsk, fft, stack);
+
+    extract_lwe_sample_list_from_many_lut_accumulator(&local_accumulator, output, lut_count_log);
+}
+
+/// Return the required memory for [`programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized`].
+pub fn programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized_requirement<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?.try_and(
+        blind_rotate_assign_mem_optimized_requirement::<Scalar>(glwe_size, polynomial_size, fft)?,
+    )
+}
+
+/// Sample extract the outputs of a many-LUT blind rotation, the $i$-th
+/// [`LWE ciphertext`](`LweCiphertext`) of the output list being extracted at [`MonomialDegree`]
+/// $i \cdot N / 2^{\text{lut\_count\_log}}$.
+pub(crate) fn extract_lwe_sample_list_from_many_lut_accumulator<Scalar, InputCont, OutputCont>(
+    rotated_accumulator: &GlweCiphertext<InputCont>,
+    output: &mut LweCiphertextList<OutputCont>,
+    lut_count_log: LutCountLog,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let polynomial_size = rotated_accumulator.polynomial_size();
+    assert!(
+        polynomial_size.0 >> lut_count_log.0 != 0,
+        "{lut_count_log:?} is too large for {polynomial_size:?}"
+    );
+    let sample_extraction_stride = polynomial_size.0 >> lut_count_log.0;
+
+    for (lut_idx, mut lwe_out) in output.iter_mut().enumerate() {
+        extract_lwe_sample_from_glwe_ciphertext(
+            rotated_accumulator,
+            &mut lwe_out,
+            MonomialDegree(lut_idx * sample_extraction_stride),
+        );
+    }
+}
+
+/// Convert an [`LWE ciphertext`](`LweCiphertext`) to the expanded binary representation of its
+/// non-binary secret key.
+///
+/// The input is encrypted under an [`LWE secret key`](`LweSecretKey`) $\vec{s}$ with small signed
+/// integer coefficients, the output encrypts the same plaintext under the expanded binary key
+/// computed by
+/// [`convert_integer_lwe_secret_key_to_expanded_binary`](`super::convert_integer_lwe_secret_key_to_expanded_binary`),
+/// or by
+/// [`convert_ternary_lwe_secret_key_to_expanded_binary`](`super::convert_ternary_lwe_secret_key_to_expanded_binary`)
+/// for a ternary key.
+///
+/// For an expanded key made of $b$ bits per coefficient, the input mask $\vec{a}$ is mapped to
+/// $\vec{a} || 2\vec{a} || \dots || 2^{b-1}\vec{a} || -\vec{a} || \dots || -2^{b-1}\vec{a}$ and
+/// the body is copied as is, the number of bits being deduced from the output [`LweDimension`].
+///
+/// The output [`LweDimension`] must therefore be the one of the expanded key: $2bn$ for an input
+/// of [`LweDimension`] $n$, i.e. $2n$ for a ternary key. The output only decrypts correctly under a
+/// key expanded with the same $b$, which is the smallest number of bits able to represent the
+/// input key coefficients when using
+/// [`allocate_and_convert_integer_lwe_secret_key_to_expanded_binary`](`super::allocate_and_convert_integer_lwe_secret_key_to_expanded_binary`).
+/// As $\langle \vec{a}, \vec{s} \rangle$ is left unchanged by the conversion, no noise is added.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let lwe_dimension = LweDimension(742);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// let lwe_secret_key = allocate_and_generate_new_gaussian_lwe_secret_key(
+///     lwe_dimension,
+///     StandardDev(3.2),
+///     &mut secret_generator,
+/// );
+/// let expanded_lwe_secret_key =
+///     allocate_and_convert_integer_lwe_secret_key_to_expanded_binary(&lwe_secret_key);
+///
+/// let lwe_ciphertext: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     Plaintext(3 << 60),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // The output has the LweDimension of the expanded key
+/// let mut expanded_lwe_ciphertext = LweCiphertext::new(
+///     0u64,
+///     expanded_lwe_secret_key.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// convert_lwe_ciphertext_to_expanded_binary_key(&lwe_ciphertext, &mut expanded_lwe_ciphertext);
+///
+/// // Both ciphertexts decrypt to the same noisy plaintext
+/// let decrypted = decrypt_lwe_ciphertext(&lwe_secret_key, &lwe_ciphertext);
+/// let expanded_decrypted =
+///     decrypt_lwe_ciphertext(&expanded_lwe_secret_key, &expanded_lwe_ciphertext);
+///
+/// assert_eq!(decrypted, expanded_decrypted);
+/// ```
+///
+/// # Panics
+///
+/// Panics if the output [`LweDimension`] is not a non-zero multiple of twice the input
+/// [`LweDimension`].
+///
+/// Panics if the ciphertext modulus is not compatible with the native modulus: negative key
+/// coefficients are stored using their wrapping representation (e.g. $-1$ is stored as
+/// `Scalar::MAX`), which is only valid for the native modulus and power of 2 moduli.
+pub fn convert_lwe_ciphertext_to_expanded_binary_key<Scalar, InputCont, OutputCont>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus(),
+        "Mismatched moduli between input ({:?}) and output ({:?})",
+        input.ciphertext_modulus(),
+        output.ciphertext_modulus()
+    );
+
+    assert!(
+        input
+            .ciphertext_modulus()
+            .is_compatible_with_native_modulus(),
+        "Non-binary secret keys are only supported with the native modulus or power of 2 moduli, \
+        got {:?}",
+        input.ciphertext_modulus()
+    );
+
+    let input_lwe_dimension = input.lwe_size().to_lwe_dimension();
+    let output_lwe_dimension = output.lwe_size().to_lwe_dimension();
+
+    assert!(
+        input_lwe_dimension.0 != 0
+            && output_lwe_dimension.0 != 0
+            && output_lwe_dimension.0 % (2 * input_lwe_dimension.0) == 0,
+        "Mismatched LweDimension between input ({input_lwe_dimension:?}) and output \
+        ({output_lwe_dimension:?}), expected output to be a non-zero multiple of twice the input.",
+    );
+
+    let (mut output_mask, output_body) = output.get_mut_mask_and_body();
+    let (positive_mask, negative_mask) = output_mask
+        .as_mut()
+        .split_at_mut(output_lwe_dimension.0 / 2);
+
+    for (bit_idx, (positive_chunk, negative_chunk)) in positive_mask
+        .chunks_exact_mut(input_lwe_dimension.0)
+        .zip(negative_mask.chunks_exact_mut(input_lwe_dimension.0))
+        .enumerate()
+    {
+        for ((&input_mask_element, positive), negative) in input
+            .get_mask()
+            .as_ref()
+            .iter()
+            .zip(positive_chunk.iter_mut())
+            .zip(negative_chunk.iter_mut())
+        {
+            *positive = input_mask_element << bit_idx;
+            *negative = positive.wrapping_neg();
+        }
+    }
+
+    *output_body.data = *input.get_body().data;
+}
+
+/// Perform a programmable bootstrap of an [`LWE ciphertext`](`LweCiphertext`) encrypted under a
+/// ternary [`LWE secret key`](`LweSecretKey`).
+///
+/// The blind rotation requires binary key coefficients, so the [`fourier LWE bootstrap
+/// key`](`FourierLweBootstrapKey`) must have been generated using the expanded binary key returned
+/// by
+/// [`allocate_and_convert_ternary_lwe_secret_key_to_expanded_binary`](`super::allocate_and_convert_ternary_lwe_secret_key_to_expanded_binary`)
+/// as input key. Its input [`LweDimension`] is therefore twice the dimension of the input
+/// ciphertext, and so are its size and the number of external products computed by the blind
+/// rotation, compared to a binary key of the same dimension. The input ciphertext is converted
+/// using [`convert_lwe_ciphertext_to_expanded_binary_key`] before calling
+/// [`programmable_bootstrap_lwe_ciphertext`].
+///
+/// The output is encrypted under the [`LWE secret key`](`LweSecretKey`) obtained from the [`GLWE
+/// secret key`](`GlweSecretKey`) of the bootstrapping key, its [`LweDimension`] is the output
+/// [`LweDimension`] of the bootstrapping key, i.e. $kN$, and does not depend on the expansion.
+///
+/// See [`programmable_bootstrap_integer_key_lwe_ciphertext`] for keys with larger coefficients,
+/// like gaussian keys.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized`].
+///
+/// # Panics
+///
+/// Panics if the input [`LweDimension`] of the bootstrapping key is not twice the input
+/// ciphertext [`LweDimension`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate ternary secret keys
+/// let small_lwe_sk = allocate_and_generate_new_ternary_lwe_secret_key(
+///     small_lwe_dimension,
+///     &mut secret_generator,
+/// );
+/// let glwe_sk = allocate_and_generate_new_ternary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // The bootstrapping key is generated from the expanded binary representation of the ternary
+/// // input key
+/// let expanded_small_lwe_sk =
+///     allocate_and_convert_ternary_lwe_secret_key_to_expanded_binary(&small_lwe_sk);
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &expanded_small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+///
+/// par_convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+/// let input_message = 3u64;
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(input_message * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Identity look-up table, see programmable_bootstrap_lwe_ciphertext for details about the
+/// // accumulator construction
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|idx| (idx / box_size) as u64 * delta)
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+///
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut pbs_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// programmable_bootstrap_ternary_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut pbs_ct,
+///     &accumulator,
+///     &fourier_bsk,
+/// );
+///
+/// let pbs_plaintext: Plaintext<u64> = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_ct);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// let pbs_result: u64 = signed_decomposer.closest_representable(pbs_plaintext.0) / delta;
+///
+/// assert_eq!(input_message, pbs_result);
+/// ```
+pub fn programmable_bootstrap_ternary_lwe_ciphertext<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.input_lwe_dimension(),
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        fourier_bsk,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`programmable_bootstrap_ternary_lwe_ciphertext`].
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized_requirement`].
+///
+/// See [`programmable_bootstrap_ternary_lwe_ciphertext`] for the bootstrapping key generation and
+/// the [`LweDimension`] of the output.
+///
+/// # Panics
+///
+/// Panics if the input [`LweDimension`] of the bootstrapping key is not twice the input
+/// ciphertext [`LweDimension`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate ternary secret keys
+/// let small_lwe_sk = allocate_and_generate_new_ternary_lwe_secret_key(
+///     small_lwe_dimension,
+///     &mut secret_generator,
+/// );
+/// let glwe_sk = allocate_and_generate_new_ternary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // The bootstrapping key is generated from the expanded binary representation of the ternary
+/// // input key
+/// let expanded_small_lwe_sk =
+///     allocate_and_convert_ternary_lwe_secret_key_to_expanded_binary(&small_lwe_sk);
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &expanded_small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+///
+/// par_convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+/// let input_message = 3u64;
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(input_message * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Identity look-up table, see programmable_bootstrap_lwe_ciphertext for details about the
+/// // accumulator construction
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|idx| (idx / box_size) as u64 * delta)
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+///
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut pbs_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+///
+/// // The requirement depends on the bootstrapping key input LweDimension, which is twice the
+/// // LweDimension of the ternary input ciphertext
+/// let mut buffers = ComputationBuffers::new();
+/// buffers.resize(
+///     programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized_requirement::<u64>(
+///         fourier_bsk.input_lwe_dimension(),
+///         fourier_bsk.glwe_size(),
+///         fourier_bsk.polynomial_size(),
+///         fft,
+///     )
+///     .unwrap()
+///     .unaligned_bytes_required(),
+/// );
+///
+/// programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized(
+///     &lwe_ciphertext_in,
+///     &mut pbs_ct,
+///     &accumulator,
+///     &fourier_bsk,
+///     fft,
+///     buffers.stack(),
+/// );
+///
+/// let pbs_plaintext: Plaintext<u64> = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_ct);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// let pbs_result: u64 = signed_decomposer.closest_representable(pbs_plaintext.0) / delta;
+///
+/// assert_eq!(input_message, pbs_result);
+/// ```
+pub fn programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    assert_eq!(
+        fourier_bsk.input_lwe_dimension().0,
+        2 * input.lwe_size().to_lwe_dimension().0,
+        "Mismatched LweDimension between the input ciphertext ({:?}) and the bootstrapping key \
+        ({:?}), the bootstrapping key input LweDimension must be twice the ternary input \
+        LweDimension.",
+        input.lwe_size().to_lwe_dimension(),
+        fourier_bsk.input_lwe_dimension(),
+    );
+
+    programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        fourier_bsk,
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for
+/// [`programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized`].
+///
+/// `bsk_input_lwe_dimension` is the input [`LweDimension`] of the bootstrapping key, i.e. twice
+/// the [`LweDimension`] of the ternary input ciphertext. On top of the memory needed by
+/// [`programmable_bootstrap_lwe_ciphertext_mem_optimized`], a buffer holding the expanded input
+/// ciphertext is required.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let ternary_lwe_dimension = LweDimension(742);
+/// let glwe_size = GlweSize(2);
+/// let polynomial_size = PolynomialSize(2048);
+///
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+///
+/// let ternary_requirement =
+///     programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized_requirement::<u64>(
+///         LweDimension(2 * ternary_lwe_dimension.0),
+///         glwe_size,
+///         polynomial_size,
+///         fft,
+///     )
+///     .unwrap();
+///
+/// let binary_requirement =
+///     programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<u64>(
+///         glwe_size,
+///         polynomial_size,
+///         fft,
+///     )
+///     .unwrap();
+///
+/// // The expanded input ciphertext is stored in the buffer
+/// assert!(
+///     ternary_requirement.unaligned_bytes_required()
+///         >= binary_requirement.unaligned_bytes_required()
+///             + (2 * ternary_lwe_dimension.0 + 1) * std::mem::size_of::<u64>()
+/// );
+/// ```
+pub fn programmable_bootstrap_ternary_lwe_ciphertext_mem_optimized_requirement<Scalar>(
+    bsk_input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+        bsk_input_lwe_dimension,
+        glwe_size,
+        polynomial_size,
+        fft,
+    )
+}
+
+/// Perform a programmable bootstrap of an [`LWE ciphertext`](`LweCiphertext`) encrypted under an
+/// [`LWE secret key`](`LweSecretKey`) with small signed integer coefficients, e.g. a gaussian key.
+///
+/// The blind rotation requires binary key coefficients, so the [`fourier LWE bootstrap
+/// key`](`FourierLweBootstrapKey`) must have been generated using the expanded binary key returned
+/// by
+/// [`allocate_and_convert_integer_lwe_secret_key_to_expanded_binary`](`super::allocate_and_convert_integer_lwe_secret_key_to_expanded_binary`)
+/// as input key. For coefficients decomposed on $b$ bits, its input [`LweDimension`] is therefore
+/// $2b$ times the dimension of the input ciphertext, and so are its size and the number of
+/// external products computed by the blind rotation, compared to a binary key of the same
+/// dimension. The input ciphertext is converted using
+/// [`convert_lwe_ciphertext_to_expanded_binary_key`] before calling
+/// [`programmable_bootstrap_lwe_ciphertext`].
+///
+/// The number of bits $b$ is deduced from the input [`LweDimension`] of the bootstrapping key:
+/// every input key coefficient $s\_i$ must satisfy $|s\_i| < 2^b$ for the expanded key to
+/// represent it, which is checked when expanding the key. The expansion picks the smallest such
+/// $b$, e.g. a gaussian key with a standard deviation of $3.2$ usually has coefficients smaller
+/// than $16$ in absolute value, i.e. $b = 4$ and a bootstrapping key with an input
+/// [`LweDimension`] of $8n$. The blind rotation noise grows with the input [`LweDimension`] of
+/// the bootstrapping key, so the parameters must be chosen for the expanded dimension.
+///
+/// The output is encrypted under the [`LWE secret key`](`LweSecretKey`) obtained from the [`GLWE
+/// secret key`](`GlweSecretKey`) of the bootstrapping key, its [`LweDimension`] is the output
+/// [`LweDimension`] of the bootstrapping key, i.e. $kN$, and does not depend on $b$.
+///
+/// If you want to manage the computation memory manually you can use
+/// [`programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized`].
+///
+/// # Panics
+///
+/// Panics if the input [`LweDimension`] of the bootstrapping key is not a non-zero multiple of
+/// twice the input ciphertext [`LweDimension`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate a gaussian input secret key, the output key is binary
+/// let small_lwe_sk = allocate_and_generate_new_gaussian_lwe_secret_key(
+///     small_lwe_dimension,
+///     StandardDev(1.0),
+///     &mut secret_generator,
+/// );
+/// let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // The bootstrapping key is generated from the expanded binary representation of the gaussian
+/// // input key
+/// let expanded_small_lwe_sk =
+///     allocate_and_convert_integer_lwe_secret_key_to_expanded_binary(&small_lwe_sk);
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &expanded_small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+///
+/// par_convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+/// let input_message = 3u64;
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(input_message * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Identity look-up table, see programmable_bootstrap_lwe_ciphertext for details about the
+/// // accumulator construction
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|idx| (idx / box_size) as u64 * delta)
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+///
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut pbs_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// programmable_bootstrap_integer_key_lwe_ciphertext(
+///     &lwe_ciphertext_in,
+///     &mut pbs_ct,
+///     &accumulator,
+///     &fourier_bsk,
+/// );
+///
+/// let pbs_plaintext: Plaintext<u64> = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_ct);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// let pbs_result: u64 = signed_decomposer.closest_representable(pbs_plaintext.0) / delta;
+///
+/// assert_eq!(input_message, pbs_result);
+/// ```
+pub fn programmable_bootstrap_integer_key_lwe_ciphertext<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let mut buffers = ComputationBuffers::new();
+
+    let fft = Fft::new(fourier_bsk.polynomial_size());
+    let fft = fft.as_view();
+
+    buffers.resize(
+        programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+            fourier_bsk.input_lwe_dimension(),
+            fourier_bsk.glwe_size(),
+            fourier_bsk.polynomial_size(),
+            fft,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    let stack = buffers.stack();
+
+    programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized(
+        input,
+        output,
+        accumulator,
+        fourier_bsk,
+        fft,
+        stack,
+    );
+}
+
+/// Memory optimized version of [`programmable_bootstrap_integer_key_lwe_ciphertext`].
+///
+/// The caller must provide a properly configured [`FftView`] object and a `PodStack` used as a
+/// memory buffer having a capacity at least as large as the result of
+/// [`programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized_requirement`].
+///
+/// See [`programmable_bootstrap_integer_key_lwe_ciphertext`] for the bound on the input key
+/// coefficients and the [`LweDimension`] of the bootstrapping key and of the output.
+///
+/// # Panics
+///
+/// Panics if the input [`LweDimension`] of the bootstrapping key is not a non-zero multiple of
+/// twice the input ciphertext [`LweDimension`].
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let small_lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_base_log = DecompositionBaseLog(23);
+/// let pbs_level = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+///
+/// // Generate a gaussian input secret key, the output key is binary
+/// let small_lwe_sk = allocate_and_generate_new_gaussian_lwe_secret_key(
+///     small_lwe_dimension,
+///     StandardDev(1.0),
+///     &mut secret_generator,
+/// );
+/// let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+///     glwe_dimension,
+///     polynomial_size,
+///     &mut secret_generator,
+/// );
+/// let big_lwe_sk = glwe_sk.clone().into_lwe_secret_key();
+///
+/// // The bootstrapping key is generated from the expanded binary representation of the gaussian
+/// // input key
+/// let expanded_small_lwe_sk =
+///     allocate_and_convert_integer_lwe_secret_key_to_expanded_binary(&small_lwe_sk);
+///
+/// let std_bootstrapping_key = par_allocate_and_generate_new_lwe_bootstrap_key(
+///     &expanded_small_lwe_sk,
+///     &glwe_sk,
+///     pbs_base_log,
+///     pbs_level,
+///     glwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// let mut fourier_bsk = FourierLweBootstrapKey::new(
+///     std_bootstrapping_key.input_lwe_dimension(),
+///     std_bootstrapping_key.glwe_size(),
+///     std_bootstrapping_key.polynomial_size(),
+///     std_bootstrapping_key.decomposition_base_log(),
+///     std_bootstrapping_key.decomposition_level_count(),
+/// );
+///
+/// par_convert_standard_lwe_bootstrap_key_to_fourier(&std_bootstrapping_key, &mut fourier_bsk);
+/// drop(std_bootstrapping_key);
+///
+/// // Our 4 bits message space
+/// let message_modulus = 1u64 << 4;
+/// let input_message = 3u64;
+/// let delta = (1_u64 << 63) / message_modulus;
+///
+/// let lwe_ciphertext_in: LweCiphertextOwned<u64> = allocate_and_encrypt_new_lwe_ciphertext(
+///     &small_lwe_sk,
+///     Plaintext(input_message * delta),
+///     lwe_modular_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // Identity look-up table, see programmable_bootstrap_lwe_ciphertext for details about the
+/// // accumulator construction
+/// let box_size = polynomial_size.0 / message_modulus as usize;
+/// let mut accumulator_u64: Vec<u64> = (0..polynomial_size.0)
+///     .map(|idx| (idx / box_size) as u64 * delta)
+///     .collect();
+/// let half_box_size = box_size / 2;
+/// for a_i in accumulator_u64[0..half_box_size].iter_mut() {
+///     *a_i = (*a_i).wrapping_neg();
+/// }
+/// accumulator_u64.rotate_left(half_box_size);
+///
+/// let accumulator = allocate_and_trivially_encrypt_new_glwe_ciphertext(
+///     glwe_dimension.to_glwe_size(),
+///     &PlaintextList::from_container(accumulator_u64),
+///     ciphertext_modulus,
+/// );
+///
+/// let mut pbs_ct = LweCiphertext::new(
+///     0u64,
+///     big_lwe_sk.lwe_dimension().to_lwe_size(),
+///     ciphertext_modulus,
+/// );
+///
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+///
+/// // The requirement depends on the bootstrapping key input LweDimension, i.e. the LweDimension of
+/// // the expanded key
+/// let mut buffers = ComputationBuffers::new();
+/// buffers.resize(
+///     programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized_requirement::<u64>(
+///         fourier_bsk.input_lwe_dimension(),
+///         fourier_bsk.glwe_size(),
+///         fourier_bsk.polynomial_size(),
+///         fft,
+///     )
+///     .unwrap()
+///     .unaligned_bytes_required(),
+/// );
+///
+/// programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized(
+///     &lwe_ciphertext_in,
+///     &mut pbs_ct,
+///     &accumulator,
+///     &fourier_bsk,
+///     fft,
+///     buffers.stack(),
+/// );
+///
+/// let pbs_plaintext: Plaintext<u64> = decrypt_lwe_ciphertext(&big_lwe_sk, &pbs_ct);
+///
+/// let signed_decomposer =
+///     SignedDecomposer::new(DecompositionBaseLog(5), DecompositionLevelCount(1));
+///
+/// let pbs_result: u64 = signed_decomposer.closest_representable(pbs_plaintext.0) / delta;
+///
+/// assert_eq!(input_message, pbs_result);
+/// ```
+pub fn programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized<
+    Scalar,
+    InputCont,
+    OutputCont,
+    AccCont,
+    KeyCont,
+>(
+    input: &LweCiphertext<InputCont>,
+    output: &mut LweCiphertext<OutputCont>,
+    accumulator: &GlweCiphertext<AccCont>,
+    fourier_bsk: &FourierLweBootstrapKey<KeyCont>,
+    fft: FftView<'_>,
+    stack: PodStack<'_>,
+) where
+    // CastInto required for PBS modulus switch which returns a usize
+    Scalar: UnsignedTorus + CastInto<usize>,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+    AccCont: Container<Element = Scalar>,
+    KeyCont: Container<Element = c64>,
+{
+    let (mut expanded_input_data, stack) = stack.make_aligned_raw::<Scalar>(
+        fourier_bsk.input_lwe_dimension().to_lwe_size().0,
+        CACHELINE_ALIGN,
+    );
+    let mut expanded_input =
+        LweCiphertext::from_container(&mut *expanded_input_data, input.ciphertext_modulus());
+
+    convert_lwe_ciphertext_to_expanded_binary_key(input, &mut expanded_input);
+
+    programmable_bootstrap_lwe_ciphertext_mem_optimized(
+        &expanded_input,
+        output,
+        accumulator,
+        fourier_bsk,
+        fft,
+        stack,
+    );
+}
+
+/// Return the required memory for
+/// [`programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized`].
+///
+/// `bsk_input_lwe_dimension` is the input [`LweDimension`] of the bootstrapping key, i.e. the
+/// [`LweDimension`] of the expanded binary key: $2bn$ for an input ciphertext of [`LweDimension`]
+/// $n$ and key coefficients decomposed on $b$ bits. The expanded input ciphertext is stored in the
+/// buffer, so the requirement grows with $b$.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+///
+/// let mut boxed_seeder = new_seeder();
+/// let seeder = boxed_seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let lwe_secret_key: LweSecretKeyOwned<u64> = allocate_and_generate_new_gaussian_lwe_secret_key(
+///     lwe_dimension,
+///     StandardDev(3.2),
+///     &mut secret_generator,
+/// );
+///
+/// // The bootstrapping key would be generated from this key, its LweDimension is the one to use
+/// let expanded_lwe_secret_key =
+///     allocate_and_convert_integer_lwe_secret_key_to_expanded_binary(&lwe_secret_key);
+/// let bsk_input_lwe_dimension = expanded_lwe_secret_key.lwe_dimension();
+/// assert_eq!(bsk_input_lwe_dimension.0 % (2 * lwe_dimension.0), 0);
+///
+/// let fft = Fft::new(polynomial_size);
+/// let fft = fft.as_view();
+///
+/// let mut buffers = ComputationBuffers::new();
+/// buffers.resize(
+///     programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized_requirement::<u64>(
+///         bsk_input_lwe_dimension,
+///         glwe_dimension.to_glwe_size(),
+///         polynomial_size,
+///         fft,
+///     )
+///     .unwrap()
+///     .unaligned_bytes_required(),
+/// );
+/// ```
+pub fn programmable_bootstrap_integer_key_lwe_ciphertext_mem_optimized_requirement<Scalar>(
+    bsk_input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    programmable_bootstrap_lwe_ciphertext_mem_optimized_requirement::<Scalar>(
+        glwe_size,
+        polynomial_size,
+        fft,
+    )?
+    .try_and(StackReq::try_new_aligned::<Scalar>(
+        bsk_input_lwe_dimension.to_lwe_size().0,
+        CACHELINE_ALIGN,
+    )?)
+}
+
 /// Perform a programmable bootstrap given an input [`LWE ciphertext`](`LweCiphertext`), a
 /// look-up table passed as a [`GLWE ciphertext`](`GlweCiphertext`) and an [`LWE bootstrap
 /// key`](`LweBootstrapKey`) in the fourier domain using f128 see [`fourier LWE bootstrap
@@ -1,9 +1,14 @@
 //! Module containing primitives pertaining to the generation of
 //! [`LWE secret keys`](`LweSecretKey`).
+//!
+//! Non-binary keys store negative coefficients using their wrapping representation (e.g. $-1$
+//! is stored as `Scalar::MAX`), which is compatible with the native modulus and power of 2
+//! ciphertext moduli.
 
+use crate::core_crypto::commons::dispersion::DispersionParameter;
 use crate::core_crypto::commons::generators::SecretRandomGenerator;
-use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary};
-use crate::core_crypto::commons::numeric::Numeric;
+use crate::core_crypto::commons::math::random::{RandomGenerable, UniformBinary, UniformTernary};
+use crate::core_crypto::commons::numeric::{CastFrom, Numeric, UnsignedInteger};
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
@@ -64,3 +69,515 @@ pub fn generate_binary_lwe_secret_key<Scalar, InCont, Gen>(
 {
     generator.fill_slice_with_random_uniform_binary(lwe_secret_key.as_mut());
 }
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with uniformly random ternary
+/// coefficients.
+///
+/// The coefficient $-1$ is stored using its wrapping representation, i.e. `Scalar::MAX`.
+pub fn allocate_and_generate_new_ternary_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: RandomGenerable<UniformTernary> + Numeric,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_ternary_lwe_secret_key(&mut lwe_secret_key, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with uniformly random ternary coefficients.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_ternary_lwe_secret_key(&mut lwe_secret_key, &mut secret_generator);
+///
+/// // Check all coefficients are in {-1, 0, 1}
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// ```
+pub fn generate_ternary_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: RandomGenerable<UniformTernary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_uniform_ternary(lwe_secret_key.as_mut());
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with integer coefficients
+/// sampled from a rounded gaussian distribution centered in 0.
+///
+/// See [`generate_gaussian_lwe_secret_key`] for details about the distribution.
+pub fn allocate_and_generate_new_gaussian_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    std_dev: impl DispersionParameter,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger + CastFrom<i64>,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_gaussian_lwe_secret_key(&mut lwe_secret_key, std_dev, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with integer coefficients sampled from a rounded
+/// gaussian distribution centered in 0.
+///
+/// Unlike encryption noise, the standard deviation is not normalized by the ciphertext modulus:
+/// `std_dev.get_standard_dev()` is directly the standard deviation of the integer coefficients.
+/// Negative coefficients are stored using their wrapping representation.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let key_std_dev = StandardDev(3.2);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_gaussian_lwe_secret_key(&mut lwe_secret_key, key_std_dev, &mut secret_generator);
+///
+/// // Check all coefficients are small integers, a 3.2 standard deviation never yields values
+/// // outside of [-64, 64] in practice
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| (elt as i64).abs() <= 64));
+/// ```
+pub fn generate_gaussian_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    std_dev: impl DispersionParameter,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger + CastFrom<i64>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_gaussian_integers(
+        lwe_secret_key.as_mut(),
+        std_dev.get_standard_dev(),
+    );
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with binary coefficients with a
+/// fixed [`HammingWeight`].
+pub fn allocate_and_generate_new_sparse_binary_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    hamming_weight: HammingWeight,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_sparse_binary_lwe_secret_key(&mut lwe_secret_key, hamming_weight, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with binary coefficients, exactly `hamming_weight`
+/// of them being set to 1, at uniformly random positions.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let hamming_weight = HammingWeight(64);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_sparse_binary_lwe_secret_key(
+///     &mut lwe_secret_key,
+///     hamming_weight,
+///     &mut secret_generator,
+/// );
+///
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1));
+/// assert_eq!(
+///     lwe_secret_key
+///         .as_ref()
+///         .iter()
+///         .filter(|&&elt| elt != 0)
+///         .count(),
+///     hamming_weight.0
+/// );
+/// ```
+pub fn generate_sparse_binary_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    hamming_weight: HammingWeight,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_fixed_hamming_weight_binary(
+        lwe_secret_key.as_mut(),
+        hamming_weight.0,
+    );
+}
+
+/// Allocate a new [`LWE secret key`](`LweSecretKey`) and fill it with ternary coefficients with a
+/// fixed [`HammingWeight`].
+pub fn allocate_and_generate_new_sparse_ternary_lwe_secret_key<Scalar, Gen>(
+    lwe_dimension: LweDimension,
+    hamming_weight: HammingWeight,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger + RandomGenerable<UniformBinary>,
+    Gen: ByteRandomGenerator,
+{
+    let mut lwe_secret_key = LweSecretKeyOwned::new_empty_key(Scalar::ZERO, lwe_dimension);
+
+    generate_sparse_ternary_lwe_secret_key(&mut lwe_secret_key, hamming_weight, generator);
+
+    lwe_secret_key
+}
+
+/// Fill an [`LWE secret key`](`LweSecretKey`) with ternary coefficients, exactly `hamming_weight`
+/// of them being non-zero, at uniformly random positions and with uniformly random signs.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(742);
+/// let hamming_weight = HammingWeight(64);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let mut lwe_secret_key = LweSecretKey::new_empty_key(0u64, lwe_dimension);
+///
+/// generate_sparse_ternary_lwe_secret_key(
+///     &mut lwe_secret_key,
+///     hamming_weight,
+///     &mut secret_generator,
+/// );
+///
+/// assert!(lwe_secret_key
+///     .as_ref()
+///     .iter()
+///     .all(|&elt| elt == 0 || elt == 1 || elt == u64::MAX));
+/// assert_eq!(
+///     lwe_secret_key
+///         .as_ref()
+///         .iter()
+///         .filter(|&&elt| elt != 0)
+///         .count(),
+///     hamming_weight.0
+/// );
+/// ```
+pub fn generate_sparse_ternary_lwe_secret_key<Scalar, InCont, Gen>(
+    lwe_secret_key: &mut LweSecretKey<InCont>,
+    hamming_weight: HammingWeight,
+    generator: &mut SecretRandomGenerator<Gen>,
+) where
+    Scalar: UnsignedInteger + RandomGenerable<UniformBinary>,
+    InCont: ContainerMut<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    generator.fill_slice_with_random_fixed_hamming_weight_ternary(
+        lwe_secret_key.as_mut(),
+        hamming_weight.0,
+    );
+}
+
+/// Convert a ternary [`LWE secret key`](`LweSecretKey`) to its expanded binary representation.
+///
+/// The ternary key $\vec{s}$ of dimension $n$ is mapped to the binary [`LWE secret
+/// key`](`LweSecretKey`) $\vec{s}^{+} || \vec{s}^{-}$ of dimension $2n$ such that
+/// $\vec{s} = \vec{s}^{+} - \vec{s}^{-}$.
+///
+/// The blind rotation requires binary key coefficients, the output of this function is the key
+/// to use as input key when generating an [`LWE bootstrap key`](`LweBootstrapKey`) to bootstrap
+/// ciphertexts encrypted under a ternary key. See
+/// [`programmable_bootstrap_ternary_lwe_ciphertext`](`super::programmable_bootstrap_ternary_lwe_ciphertext`)
+/// for usage.
+///
+/// As the expanded key is twice as large as the ternary key, so are the bootstrapping key
+/// generated from it and the number of external products computed by the blind rotation,
+/// compared to a binary key of the same dimension.
+///
+/// This is the special case of [`convert_integer_lwe_secret_key_to_expanded_binary`] with a
+/// single bit per coefficient.
+///
+/// # Panics
+///
+/// Panics if the input key has a coefficient that is not in $\lbrace -1, 0, 1 \rbrace$.
+pub fn convert_ternary_lwe_secret_key_to_expanded_binary<Scalar, InputCont, OutputCont>(
+    input_ternary_lwe_secret_key: &LweSecretKey<InputCont>,
+    output_binary_lwe_secret_key: &mut LweSecretKey<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    assert_eq!(
+        output_binary_lwe_secret_key.lwe_dimension().0,
+        2 * input_ternary_lwe_secret_key.lwe_dimension().0,
+        "Mismatched LweDimension between input ternary LWE secret key ({:?}) and output binary \
+        LWE secret key ({:?}), expected output to be twice as big as the input.",
+        input_ternary_lwe_secret_key.lwe_dimension(),
+        output_binary_lwe_secret_key.lwe_dimension(),
+    );
+
+    convert_integer_lwe_secret_key_to_expanded_binary(
+        input_ternary_lwe_secret_key,
+        output_binary_lwe_secret_key,
+    );
+}
+
+/// Allocate a new binary [`LWE secret key`](`LweSecretKey`) and fill it with the expanded binary
+/// representation of a ternary [`LWE secret key`](`LweSecretKey`).
+///
+/// See [`convert_ternary_lwe_secret_key_to_expanded_binary`] for details.
+pub fn allocate_and_convert_ternary_lwe_secret_key_to_expanded_binary<Scalar, InputCont>(
+    input_ternary_lwe_secret_key: &LweSecretKey<InputCont>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let mut output_binary_lwe_secret_key = LweSecretKeyOwned::new_empty_key(
+        Scalar::ZERO,
+        LweDimension(2 * input_ternary_lwe_secret_key.lwe_dimension().0),
+    );
+
+    convert_ternary_lwe_secret_key_to_expanded_binary(
+        input_ternary_lwe_secret_key,
+        &mut output_binary_lwe_secret_key,
+    );
+
+    output_binary_lwe_secret_key
+}
+
+/// Convert an [`LWE secret key`](`LweSecretKey`) with small signed integer coefficients, e.g. a
+/// gaussian key, to its expanded binary representation.
+///
+/// The key $\vec{s}$ of dimension $n$ is mapped to a binary [`LWE secret key`](`LweSecretKey`) of
+/// dimension $2bn$ holding the signed binary decomposition of $\vec{s}$ on $b$ bits.
+///
+/// The output key is made of $b$ keys $\vec{s}^{+}\_{j}$ holding the bits of the positive
+/// coefficients followed by $b$ keys $\vec{s}^{-}\_{j}$ holding the bits of the absolute value of
+/// the negative coefficients, such that
+/// $\vec{s} = \sum\_{j=0}^{b-1} 2^j \left( \vec{s}^{+}\_{j} - \vec{s}^{-}\_{j} \right)$.
+/// The number of bits $b$ is deduced from the output [`LweDimension`].
+///
+/// The output of this function is the key to use as input key when generating an [`LWE bootstrap
+/// key`](`LweBootstrapKey`) to bootstrap ciphertexts encrypted under a non-binary key, see
+/// [`programmable_bootstrap_integer_key_lwe_ciphertext`](`super::programmable_bootstrap_integer_key_lwe_ciphertext`).
+/// The bootstrapping key generated from it and the number of external products computed by the
+/// blind rotation are $2b$ times larger than for a binary key of the same dimension.
+///
+/// # Panics
+///
+/// Panics if the output [`LweDimension`] is not a non-zero multiple of twice the input
+/// [`LweDimension`], or if the absolute value of an input key coefficient does not fit on $b$
+/// bits.
+pub fn convert_integer_lwe_secret_key_to_expanded_binary<Scalar, InputCont, OutputCont>(
+    input_lwe_secret_key: &LweSecretKey<InputCont>,
+    output_binary_lwe_secret_key: &mut LweSecretKey<OutputCont>,
+) where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+    OutputCont: ContainerMut<Element = Scalar>,
+{
+    let input_lwe_dimension = input_lwe_secret_key.lwe_dimension().0;
+    let output_lwe_dimension = output_binary_lwe_secret_key.lwe_dimension().0;
+
+    assert!(
+        input_lwe_dimension != 0
+            && output_lwe_dimension != 0
+            && output_lwe_dimension % (2 * input_lwe_dimension) == 0,
+        "Mismatched LweDimension between input LWE secret key ({:?}) and output binary \
+        LWE secret key ({:?}), expected output to be a non-zero multiple of twice the input.",
+        input_lwe_secret_key.lwe_dimension(),
+        output_binary_lwe_secret_key.lwe_dimension(),
+    );
+
+    let bit_count = output_lwe_dimension / (2 * input_lwe_dimension);
+
+    let (positive_part, negative_part) = output_binary_lwe_secret_key
+        .as_mut()
+        .split_at_mut(output_lwe_dimension / 2);
+
+    for (coefficient_idx, &input) in input_lwe_secret_key.as_ref().iter().enumerate() {
+        let (is_negative, magnitude) = signed_key_coefficient_magnitude(input);
+
+        assert!(
+            signed_key_coefficient_bit_count(magnitude) <= bit_count,
+            "Input LWE secret key coefficient {input:?} does not fit on {bit_count} bits"
+        );
+
+        let (set_part, cleared_part) = if is_negative {
+            (&mut *negative_part, &mut *positive_part)
+        } else {
+            (&mut *positive_part, &mut *negative_part)
+        };
+
+        for bit_idx in 0..bit_count {
+            let output_idx = bit_idx * input_lwe_dimension + coefficient_idx;
+            set_part[output_idx] = (magnitude >> bit_idx) & Scalar::ONE;
+            cleared_part[output_idx] = Scalar::ZERO;
+        }
+    }
+}
+
+/// Allocate a new binary [`LWE secret key`](`LweSecretKey`) and fill it with the expanded binary
+/// representation of an [`LWE secret key`](`LweSecretKey`) with small signed integer
+/// coefficients.
+///
+/// The number of bits of the decomposition is the smallest one able to represent the absolute
+/// value of all the input coefficients, see
+/// [`convert_integer_lwe_secret_key_to_expanded_binary`] for details.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// let lwe_dimension = LweDimension(742);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// let lwe_secret_key: LweSecretKeyOwned<u64> = allocate_and_generate_new_gaussian_lwe_secret_key(
+///     lwe_dimension,
+///     StandardDev(3.2),
+///     &mut secret_generator,
+/// );
+///
+/// let expanded_lwe_secret_key =
+///     allocate_and_convert_integer_lwe_secret_key_to_expanded_binary(&lwe_secret_key);
+///
+/// let bit_count = expanded_lwe_secret_key.lwe_dimension().0 / (2 * lwe_dimension.0);
+/// let (positive_part, negative_part) = expanded_lwe_secret_key
+///     .as_ref()
+///     .split_at(expanded_lwe_secret_key.lwe_dimension().0 / 2);
+///
+/// // Recompose the input key from its signed binary decomposition
+/// for (idx, &coefficient) in lwe_secret_key.as_ref().iter().enumerate() {
+///     let recomposed = (0..bit_count).fold(0u64, |acc, bit_idx| {
+///         let positive = positive_part[bit_idx * lwe_dimension.0 + idx] << bit_idx;
+///         let negative = negative_part[bit_idx * lwe_dimension.0 + idx] << bit_idx;
+///         acc.wrapping_add(positive).wrapping_sub(negative)
+///     });
+///     assert_eq!(recomposed, coefficient);
+/// }
+/// ```
+pub fn allocate_and_convert_integer_lwe_secret_key_to_expanded_binary<Scalar, InputCont>(
+    input_lwe_secret_key: &LweSecretKey<InputCont>,
+) -> LweSecretKeyOwned<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    // Always use at least one bit so that the output key is not empty
+    let bit_count = input_lwe_secret_key
+        .as_ref()
+        .iter()
+        .map(|&coefficient| {
+            signed_key_coefficient_bit_count(signed_key_coefficient_magnitude(coefficient).1)
+        })
+        .max()
+        .unwrap_or(0)
+        .max(1);
+
+    let mut output_binary_lwe_secret_key = LweSecretKeyOwned::new_empty_key(
+        Scalar::ZERO,
+        LweDimension(2 * bit_count * input_lwe_secret_key.lwe_dimension().0),
+    );
+
+    convert_integer_lwe_secret_key_to_expanded_binary(
+        input_lwe_secret_key,
+        &mut output_binary_lwe_secret_key,
+    );
+
+    output_binary_lwe_secret_key
+}
+
+// Split a key coefficient stored using its wrapping representation in its sign and absolute value
+fn signed_key_coefficient_magnitude<Scalar: UnsignedInteger>(
+    coefficient: Scalar,
+) -> (bool, Scalar) {
+    let is_negative = coefficient > Scalar::MAX >> 1;
+    let magnitude = if is_negative {
+        coefficient.wrapping_neg()
+    } else {
+        coefficient
+    };
+    (is_negative, magnitude)
+}
+
+fn signed_key_coefficient_bit_count<Scalar: UnsignedInteger>(magnitude: Scalar) -> usize {
+    Scalar::BITS - magnitude.leading_zeros() as usize
+}
@@ -0,0 +1,433 @@
+//! Module containing primitives pertaining to the threshold decryption of
+//! [`LWE ciphertexts`](`LweCiphertext`) using [`LWE secret key shares`](`LweSecretKeyShare`).
+//!
+//! The secret key is split using a $t$-out-of-$n$ replicated secret sharing over $\mathbb{Z}\_q$,
+//! the ring of the ciphertexts, see [`LweSecretKeyShare`] for the details. Each participant
+//! computes a partial decryption with its share, adding a flooding noise which statistically hides
+//! the noise of the ciphertext, and the partial decryptions computed by $t$ participants or more
+//! for the same set of participants are combined to recover the noisy plaintext.
+//!
+//! The combined plaintext carries the ciphertext noise plus the sum of the flooding noises of the
+//! participants. As the flooding noise is $2^{\lambda}$ times larger than the ciphertext noise for
+//! a statistical security parameter $\lambda$, the ciphertext noise must be small enough for the
+//! sum of the flooding noises to stay within the decoding margin, which is typically the case of
+//! ciphertexts with 128 bits of modulus.
+
+use crate::core_crypto::algorithms::slice_algorithms::slice_wrapping_dot_product;
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulusKind;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
+use crate::core_crypto::commons::generators::{EncryptionRandomGenerator, SecretRandomGenerator};
+use crate::core_crypto::commons::math::random::{RandomGenerable, Uniform};
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::lwe_secret_key_share::share_index_combinations;
+use crate::core_crypto::entities::*;
+use sha3::{Digest, Sha3_256};
+
+// The domain separator of the ciphertext digests recorded in the partial decryptions.
+const LWE_CIPHERTEXT_DIGEST_DOMAIN: &[u8] = b"tfhe-lwe-threshold-decryption-ciphertext";
+
+/// Generate `share_count` [`LWE secret key shares`](`LweSecretKeyShare`) of an
+/// [`LWE secret key`](`LweSecretKey`), any `threshold` of them allow to decrypt.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::prelude::*;
+///
+/// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+/// // computations
+/// // Define parameters for LweCiphertext creation
+/// let lwe_dimension = LweDimension(2048);
+/// let lwe_noise_std_dev = StandardDev(2.0f64.powi(-100));
+/// let ciphertext_modulus = CiphertextModulus::new_native();
+/// // Any 2 out of the 3 shares can decrypt
+/// let threshold = ShareThreshold(2);
+/// let share_count = ShareCount(3);
+/// // The combined plaintext is statistically independent from the shares up to 2^-40
+/// let statistical_security = StatisticalSecurityParameter(40);
+///
+/// // Create the PRNG
+/// let mut seeder = new_seeder();
+/// let seeder = seeder.as_mut();
+/// let mut encryption_generator =
+///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+/// let mut secret_generator =
+///     SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+///
+/// // Create the LweSecretKey
+/// let lwe_secret_key: LweSecretKeyOwned<u128> =
+///     allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+///
+/// // Split it in shares, each share is then given to a different participant
+/// let key_shares = generate_lwe_secret_key_shares(
+///     &lwe_secret_key,
+///     threshold,
+///     share_count,
+///     &mut secret_generator,
+/// );
+///
+/// // Create the plaintext
+/// let msg = 3u128;
+/// let plaintext = Plaintext(msg << 124);
+///
+/// let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+///     &lwe_secret_key,
+///     plaintext,
+///     lwe_noise_std_dev,
+///     ciphertext_modulus,
+///     &mut encryption_generator,
+/// );
+///
+/// // The participants 1 and 3 decrypt together
+/// let participants = [key_shares[0].share_index(), key_shares[2].share_index()];
+///
+/// let partial_decryptions: Vec<_> = [&key_shares[0], &key_shares[2]]
+///     .into_iter()
+///     .map(|key_share| {
+///         partial_decrypt_lwe_ciphertext(
+///             key_share,
+///             &lwe,
+///             &participants,
+///             lwe_noise_std_dev,
+///             statistical_security,
+///             &mut encryption_generator,
+///         )
+///     })
+///     .collect();
+///
+/// let decrypted_plaintext = combine_lwe_partial_decryptions(&lwe, &partial_decryptions);
+///
+/// // Round and remove encoding
+/// // First create a decomposer working on the high 4 bits corresponding to our encoding.
+/// let decomposer = SignedDecomposer::new(DecompositionBaseLog(4), DecompositionLevelCount(1));
+///
+/// let rounded = decomposer.closest_representable(decrypted_plaintext.0);
+///
+/// // Remove the encoding
+/// let cleartext = rounded >> 124;
+///
+/// // Check we recovered the original message
+/// assert_eq!(cleartext, msg);
+/// ```
+pub fn generate_lwe_secret_key_shares<Scalar, KeyCont, Gen>(
+    lwe_secret_key: &LweSecretKey<KeyCont>,
+    threshold: ShareThreshold,
+    share_count: ShareCount,
+    generator: &mut SecretRandomGenerator<Gen>,
+) -> Vec<LweSecretKeyShareOwned<Scalar>>
+where
+    Scalar: UnsignedInteger + RandomGenerable<Uniform>,
+    KeyCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        threshold.0 > 0 && threshold.0 <= share_count.0,
+        "Got an invalid threshold {threshold:?} for {share_count:?}, \
+        the threshold must be between 1 and the share count."
+    );
+
+    let lwe_dimension = lwe_secret_key.lwe_dimension();
+
+    // One key part per set of threshold - 1 participants, the last one is chosen so that all the
+    // key parts sum up to the secret key
+    let participants: Vec<_> = (1..=share_count.0).map(ShareIndex).collect();
+    let excluded_sets = share_index_combinations(&participants, threshold.0 - 1);
+
+    let mut key_parts = vec![Scalar::ZERO; excluded_sets.len() * lwe_dimension.0];
+    let (random_key_parts, last_key_part) =
+        key_parts.split_at_mut((excluded_sets.len() - 1) * lwe_dimension.0);
+    generator.fill_slice_with_random_uniform(random_key_parts);
+
+    last_key_part.copy_from_slice(lwe_secret_key.as_ref());
+    for random_key_part in random_key_parts.chunks_exact(lwe_dimension.0) {
+        for (last, &random) in last_key_part.iter_mut().zip(random_key_part.iter()) {
+            *last = (*last).wrapping_sub(random);
+        }
+    }
+
+    let key_parts: Vec<_> = key_parts.chunks_exact(lwe_dimension.0).collect();
+
+    participants
+        .into_iter()
+        .map(|share_index| {
+            let mut key_share = LweSecretKeyShareOwned::new_empty_key_share(
+                Scalar::ZERO,
+                lwe_dimension,
+                share_index,
+                threshold,
+                share_count,
+            );
+
+            for (excluded_set, mut key_part) in key_share.key_part_iter_mut() {
+                let key_part_index = excluded_sets
+                    .iter()
+                    .position(|set| *set == excluded_set)
+                    .unwrap();
+                key_part.as_mut().copy_from_slice(key_parts[key_part_index]);
+            }
+
+            key_share
+        })
+        .collect()
+}
+
+/// Return the standard deviation of the flooding noise a participant adds to its partial
+/// decryption of a ciphertext whose noise has a standard deviation of `ciphertext_noise`.
+///
+/// The ciphertext noise $e$ is bounded by $k\sigma$ except with probability $2^{-(\lambda + 1)}$,
+/// with $k = \sqrt{2 (\lambda + 2) \ln 2}$. The flooding noise has a standard deviation
+/// $\sigma\_f = 2^{\lambda} k \sigma$, so that the statistical distance between a gaussian of
+/// standard deviation $\sigma\_f$ shifted by $e$ and the same gaussian without shift is at most
+/// $\frac{|e|}{2 \sigma\_f} \leq 2^{-(\lambda + 1)}$. The combined result of a decryption is then
+/// within a statistical distance of $2^{-\lambda}$ from a value computed from the plaintext only,
+/// and reveals nothing about the shares.
+///
+/// The flooding noises of all the participants add up in the combined result, it is the
+/// responsibility of the caller to check that their sum fits in the decoding margin.
+pub fn lwe_partial_decryption_flooding_std_dev(
+    ciphertext_noise: impl DispersionParameter,
+    statistical_security: StatisticalSecurityParameter,
+) -> StandardDev {
+    let lambda = statistical_security.0 as f64;
+    let tail_factor = (2.0 * (lambda + 2.0) * std::f64::consts::LN_2).sqrt();
+
+    StandardDev(2.0f64.powf(lambda) * tail_factor * ciphertext_noise.get_standard_dev())
+}
+
+/// Compute the [`partial decryption`](`LwePartialDecryption`) of an
+/// [`LWE ciphertext`](`LweCiphertext`) using an [`LWE secret key share`](`LweSecretKeyShare`).
+///
+/// `participants` is the set of share indices of all the participants to the decryption, it must
+/// contain the index of the input share and at least as many indices as the threshold of the
+/// sharing. All participants must use the same set to compute their partial decryptions.
+///
+/// A gaussian flooding noise is added to the partial decryption, its standard deviation is derived
+/// from `ciphertext_noise`, the standard deviation of the noise of the input ciphertext, and from
+/// `statistical_security` with [`lwe_partial_decryption_flooding_std_dev`]. The flooding noises of
+/// all the participants add up in the combined plaintext, their sum must stay within the decoding
+/// margin of the ciphertext for the decryption to be correct.
+///
+/// See [`generate_lwe_secret_key_shares`] for usage.
+pub fn partial_decrypt_lwe_ciphertext<Scalar, KeyCont, InputCont, Gen>(
+    lwe_secret_key_share: &LweSecretKeyShare<KeyCont>,
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    participants: &[ShareIndex],
+    ciphertext_noise: impl DispersionParameter,
+    statistical_security: StatisticalSecurityParameter,
+    generator: &mut EncryptionRandomGenerator<Gen>,
+) -> LwePartialDecryption<Scalar>
+where
+    Scalar: UnsignedTorus,
+    KeyCont: Container<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+    Gen: ByteRandomGenerator,
+{
+    assert!(
+        lwe_ciphertext.lwe_size().to_lwe_dimension() == lwe_secret_key_share.lwe_dimension(),
+        "Mismatch between LweDimension of input ciphertext and input secret key share. \
+        Got {:?} in input, and {:?} in secret key share.",
+        lwe_ciphertext.lwe_size().to_lwe_dimension(),
+        lwe_secret_key_share.lwe_dimension()
+    );
+
+    let ciphertext_modulus = lwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Threshold decryption only supports the native modulus and power of 2 moduli, \
+        got {ciphertext_modulus:?}."
+    );
+
+    let share_index = lwe_secret_key_share.share_index();
+    let participants = sorted_participants(
+        participants,
+        share_index,
+        lwe_secret_key_share.threshold(),
+        lwe_secret_key_share.share_count(),
+    );
+
+    let mask = lwe_ciphertext.get_mask();
+
+    // Each key part is used by the first participant which holds it, i.e. which is not in the set
+    // the key part is excluded from, so that every key part is used exactly once
+    let mask_key_dot_product = lwe_secret_key_share
+        .key_part_iter()
+        .filter(|(excluded_set, _)| {
+            participants
+                .iter()
+                .find(|&participant| !excluded_set.contains(participant))
+                == Some(&share_index)
+        })
+        .fold(Scalar::ZERO, |acc, (_, key_part)| {
+            acc.wrapping_add(slice_wrapping_dot_product(mask.as_ref(), key_part.as_ref()))
+        });
+
+    let flooding_std_dev =
+        lwe_partial_decryption_flooding_std_dev(ciphertext_noise, statistical_security);
+    let flooding_noise: Scalar = if ciphertext_modulus.is_native_modulus() {
+        generator.random_noise(flooding_std_dev)
+    } else {
+        generator.random_noise_custom_mod(flooding_std_dev, ciphertext_modulus)
+    };
+
+    LwePartialDecryption::new(
+        share_index,
+        participants,
+        lwe_ciphertext_digest(lwe_ciphertext),
+        mask_key_dot_product.wrapping_add(flooding_noise),
+    )
+}
+
+/// Return a digest of an [`LWE ciphertext`](`LweCiphertext`), used to check that partial
+/// decryptions were all computed for the same ciphertext.
+///
+/// The digest is the SHA3-256 hash of the scalar size, the ciphertext modulus, the LWE size and the
+/// coefficients of the ciphertext.
+pub fn lwe_ciphertext_digest<Scalar, InputCont>(
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+) -> [u8; 32]
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    let mut hasher = Sha3_256::new();
+    hasher.update(LWE_CIPHERTEXT_DIGEST_DOMAIN);
+    hasher.update((Scalar::BITS as u64).to_le_bytes());
+    // The native modulus is hashed as 0
+    let ciphertext_modulus = lwe_ciphertext.ciphertext_modulus();
+    let modulus = if ciphertext_modulus.is_native_modulus() {
+        0
+    } else {
+        ciphertext_modulus.get_custom_modulus()
+    };
+    hasher.update(modulus.to_le_bytes());
+    hasher.update((lwe_ciphertext.lwe_size().0 as u64).to_le_bytes());
+    for &coefficient in lwe_ciphertext.as_ref() {
+        let coefficient: u128 = coefficient.cast_into();
+        hasher.update(coefficient.to_le_bytes());
+    }
+    hasher.finalize().into()
+}
+
+/// Combine [`partial decryptions`](`LwePartialDecryption`) of an
+/// [`LWE ciphertext`](`LweCiphertext`) and return a noisy plaintext.
+///
+/// The partial decryptions must have been computed for `lwe_ciphertext` by all the participants of
+/// the set passed to [`partial_decrypt_lwe_ciphertext`], and by them only.
+///
+/// # Panics
+///
+/// Panics if the partial decryptions were computed for different sets of participants or for
+/// another ciphertext, or if they do not come from exactly the participants of their set.
+///
+/// See [`generate_lwe_secret_key_shares`] for usage.
+pub fn combine_lwe_partial_decryptions<Scalar, InputCont>(
+    lwe_ciphertext: &LweCiphertext<InputCont>,
+    partial_decryptions: &[LwePartialDecryption<Scalar>],
+) -> Plaintext<Scalar>
+where
+    Scalar: UnsignedInteger,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        !partial_decryptions.is_empty(),
+        "Got no partial decryption to combine."
+    );
+
+    let participants = partial_decryptions[0].participants();
+    assert!(
+        partial_decryptions
+            .iter()
+            .all(|partial_decryption| partial_decryption.participants() == participants),
+        "Got partial decryptions computed for different sets of participants."
+    );
+
+    let mut share_indices: Vec<_> = partial_decryptions
+        .iter()
+        .map(LwePartialDecryption::share_index)
+        .collect();
+    share_indices.sort_unstable();
+    share_indices.dedup();
+    assert!(
+        share_indices.len() == partial_decryptions.len(),
+        "Got several partial decryptions for the same share index."
+    );
+    assert!(
+        share_indices == participants,
+        "Got partial decryptions from the participants {share_indices:?}, \
+        expected exactly the participants {participants:?} they were computed for."
+    );
+
+    let ciphertext_digest = lwe_ciphertext_digest(lwe_ciphertext);
+    assert!(
+        partial_decryptions
+            .iter()
+            .all(|partial_decryption| partial_decryption.ciphertext_digest() == ciphertext_digest),
+        "Got partial decryptions computed for another ciphertext than the input ciphertext."
+    );
+
+    let ciphertext_modulus = lwe_ciphertext.ciphertext_modulus();
+
+    assert!(
+        ciphertext_modulus.is_compatible_with_native_modulus(),
+        "Threshold decryption only supports the native modulus and power of 2 moduli, \
+        got {ciphertext_modulus:?}."
+    );
+
+    let mask_key_dot_product = partial_decryptions
+        .iter()
+        .fold(Scalar::ZERO, |acc, partial_decryption| {
+            acc.wrapping_add(partial_decryption.value())
+        });
+
+    let plaintext = (*lwe_ciphertext.get_body().data).wrapping_sub(mask_key_dot_product);
+
+    match ciphertext_modulus.kind() {
+        CiphertextModulusKind::Native => Plaintext(plaintext),
+        CiphertextModulusKind::NonNativePowerOfTwo => {
+            // Manage power of 2 encoding
+            Plaintext(
+                plaintext
+                    .wrapping_div(ciphertext_modulus.get_power_of_two_scaling_to_native_torus()),
+            )
+        }
+        CiphertextModulusKind::Other => unreachable!(),
+    }
+}
+
+/// Check the set of participants to a decryption and return it sorted.
+fn sorted_participants(
+    participants: &[ShareIndex],
+    share_index: ShareIndex,
+    threshold: ShareThreshold,
+    share_count: ShareCount,
+) -> Vec<ShareIndex> {
+    assert!(
+        participants.contains(&share_index),
+        "The participants {participants:?} do not contain the share index {share_index:?}."
+    );
+
+    let mut sorted_participants = participants.to_vec();
+    sorted_participants.sort_unstable();
+    sorted_participants.dedup();
+
+    assert!(
+        sorted_participants.len() == participants.len(),
+        "The participants {participants:?} contain the same share index several times."
+    );
+    assert!(
+        sorted_participants
+            .iter()
+            .all(|participant| participant.0 > 0 && participant.0 <= share_count.0),
+        "The participants {participants:?} contain share indices which are not between 1 and \
+        the share count {share_count:?}."
+    );
+    assert!(
+        sorted_participants.len() >= threshold.0,
+        "Got {} participants, at least {threshold:?} are required to decrypt.",
+        sorted_participants.len(),
+    );
+
+    sorted_participants
+}
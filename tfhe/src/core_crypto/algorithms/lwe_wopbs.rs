@@ -1,7 +1,7 @@
 //! Module containing primitives pertaining to the Wopbs (WithOut padding PBS).
 
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
 use crate::core_crypto::commons::parameters::*;
 use crate::core_crypto::commons::traits::*;
@@ -32,7 +32,7 @@ pub fn allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list<
     output_glwe_secret_key: &GlweSecretKey<GlweKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LwePrivateFunctionalPackingKeyswitchKeyListOwned<Scalar>
@@ -87,7 +87,7 @@ pub fn generate_circuit_bootstrap_lwe_pfpksk_list<
     output_cbs_pfpksk_list: &mut LwePrivateFunctionalPackingKeyswitchKeyList<OutputCont>,
     input_lwe_secret_key: &LweSecretKey<LweKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<GlweKeyCont>,
-    noise_parameters: impl DispersionParameter,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus,
@@ -96,6 +96,7 @@ pub fn generate_circuit_bootstrap_lwe_pfpksk_list<
     GlweKeyCont: Container<Element = Scalar>,
     Gen: ByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output_cbs_pfpksk_list.lwe_pfpksk_count().0
             == output_glwe_secret_key.glwe_dimension().to_glwe_size().0,
@@ -172,7 +173,7 @@ pub fn par_allocate_and_generate_new_circuit_bootstrap_lwe_pfpksk_list<
     output_glwe_secret_key: &GlweSecretKey<GlweKeyCont>,
     decomp_base_log: DecompositionBaseLog,
     decomp_level_count: DecompositionLevelCount,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     ciphertext_modulus: CiphertextModulus<Scalar>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) -> LwePrivateFunctionalPackingKeyswitchKeyListOwned<Scalar>
@@ -223,7 +224,7 @@ pub fn par_generate_circuit_bootstrap_lwe_pfpksk_list<
     output_cbs_pfpksk_list: &mut LwePrivateFunctionalPackingKeyswitchKeyList<OutputCont>,
     input_lwe_secret_key: &LweSecretKey<LweKeyCont>,
     output_glwe_secret_key: &GlweSecretKey<GlweKeyCont>,
-    noise_parameters: impl DispersionParameter + Sync,
+    noise_parameters: impl Into<NoiseDistribution>,
     generator: &mut EncryptionRandomGenerator<Gen>,
 ) where
     Scalar: UnsignedTorus + Sync + Send,
@@ -232,6 +233,7 @@ pub fn par_generate_circuit_bootstrap_lwe_pfpksk_list<
     GlweKeyCont: Container<Element = Scalar> + Sync,
     Gen: ParallelByteRandomGenerator,
 {
+    let noise_parameters: NoiseDistribution = noise_parameters.into();
     assert!(
         output_cbs_pfpksk_list.lwe_pfpksk_count().0
             == output_glwe_secret_key.glwe_dimension().to_glwe_size().0,
@@ -4,21 +4,31 @@
 
 pub mod ggsw_conversion;
 pub mod ggsw_encryption;
+pub mod glwe_automorphism;
+pub mod glwe_automorphism_key_generation;
 pub mod glwe_encryption;
+pub mod glwe_keyswitch;
+pub mod glwe_keyswitch_key_generation;
 pub mod glwe_linear_algebra;
+pub mod glwe_relinearization;
+pub mod glwe_relinearization_key_generation;
 pub mod glwe_sample_extraction;
 pub mod glwe_secret_key_generation;
+pub mod glwe_tensor_product;
 pub mod lwe_bootstrap_key_conversion;
 pub mod lwe_bootstrap_key_generation;
 pub mod lwe_compact_ciphertext_list_expansion;
 pub mod lwe_compact_public_key_generation;
+pub mod lwe_distributed_key_generation;
 pub mod lwe_encryption;
 pub mod lwe_keyswitch;
 pub mod lwe_keyswitch_key_generation;
 pub mod lwe_linear_algebra;
+pub mod lwe_modulus_switch;
 pub mod lwe_multi_bit_bootstrap_key_conversion;
 pub mod lwe_multi_bit_bootstrap_key_generation;
 pub mod lwe_multi_bit_programmable_bootstrapping;
+pub mod lwe_ntt64_programmable_bootstrapping;
 pub mod lwe_packing_keyswitch;
 pub mod lwe_packing_keyswitch_key_generation;
 pub mod lwe_private_functional_packing_keyswitch;
@@ -26,6 +36,7 @@ pub mod lwe_private_functional_packing_keyswitch_key_generation;
 pub mod lwe_programmable_bootstrapping;
 pub mod lwe_public_key_generation;
 pub mod lwe_secret_key_generation;
+pub mod lwe_threshold_decryption;
 pub mod lwe_wopbs;
 pub mod misc;
 pub mod polynomial_algorithms;
@@ -33,6 +44,7 @@ pub mod seeded_ggsw_ciphertext_decompression;
 pub mod seeded_ggsw_ciphertext_list_decompression;
 pub mod seeded_glwe_ciphertext_decompression;
 pub mod seeded_glwe_ciphertext_list_decompression;
+pub mod seeded_glwe_keyswitch_key_decompression;
 pub mod seeded_lwe_bootstrap_key_decompression;
 pub mod seeded_lwe_ciphertext_decompression;
 pub mod seeded_lwe_ciphertext_list_decompression;
@@ -50,21 +62,31 @@ pub(crate) mod test;
 // They can still be used via `use crate::core_crypto::algorithms::slice_algorithms::*;`
 pub use ggsw_conversion::*;
 pub use ggsw_encryption::*;
+pub use glwe_automorphism::*;
+pub use glwe_automorphism_key_generation::*;
 pub use glwe_encryption::*;
+pub use glwe_keyswitch::*;
+pub use glwe_keyswitch_key_generation::*;
 pub use glwe_linear_algebra::*;
+pub use glwe_relinearization::*;
+pub use glwe_relinearization_key_generation::*;
 pub use glwe_sample_extraction::*;
 pub use glwe_secret_key_generation::*;
+pub use glwe_tensor_product::*;
 pub use lwe_bootstrap_key_conversion::*;
 pub use lwe_bootstrap_key_generation::*;
 pub use lwe_compact_ciphertext_list_expansion::*;
 pub use lwe_compact_public_key_generation::*;
+pub use lwe_distributed_key_generation::*;
 pub use lwe_encryption::*;
 pub use lwe_keyswitch::*;
 pub use lwe_keyswitch_key_generation::*;
 pub use lwe_linear_algebra::*;
+pub use lwe_modulus_switch::*;
 pub use lwe_multi_bit_bootstrap_key_conversion::*;
 pub use lwe_multi_bit_bootstrap_key_generation::*;
 pub use lwe_multi_bit_programmable_bootstrapping::*;
+pub use lwe_ntt64_programmable_bootstrapping::*;
 pub use lwe_packing_keyswitch::*;
 pub use lwe_packing_keyswitch_key_generation::*;
 pub use lwe_private_functional_packing_keyswitch::*;
@@ -72,11 +94,13 @@ pub use lwe_private_functional_packing_keyswitch_key_generation::*;
 pub use lwe_programmable_bootstrapping::*;
 pub use lwe_public_key_generation::*;
 pub use lwe_secret_key_generation::*;
+pub use lwe_threshold_decryption::*;
 pub use lwe_wopbs::*;
 pub use seeded_ggsw_ciphertext_decompression::*;
 pub use seeded_ggsw_ciphertext_list_decompression::*;
 pub use seeded_glwe_ciphertext_decompression::*;
 pub use seeded_glwe_ciphertext_list_decompression::*;
+pub use seeded_glwe_keyswitch_key_decompression::*;
 pub use seeded_lwe_bootstrap_key_decompression::*;
 pub use seeded_lwe_ciphertext_decompression::*;
 pub use seeded_lwe_ciphertext_list_decompression::*;
@@ -2,7 +2,7 @@
 
 use crate::core_crypto::algorithms::slice_algorithms::*;
 use crate::core_crypto::commons::numeric::UnsignedInteger;
-use crate::core_crypto::commons::parameters::MonomialDegree;
+use crate::core_crypto::commons::parameters::{AutomorphismExponent, MonomialDegree};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 
@@ -413,6 +413,64 @@ pub fn polynomial_wrapping_monic_monomial_mul<Scalar, OutputCont, InputCont>(
     }
 }
 
+/// Apply the ring automorphism $X \mapsto X^{k}$ (mod $(X^{N}+1)$) to the input polynomial and
+/// write the result in the output polynomial, $k$ being the automorphism exponent.
+///
+/// The automorphism exponent must be odd and smaller than $2N$.
+///
+/// # Note
+///
+/// Computations wrap around (similar to computing modulo $2^{n\_{bits}}$) when exceeding the
+/// unsigned integer capacity.
+///
+/// # Examples
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let input = Polynomial::from_container(vec![1u8, 2, 3, 4]);
+/// let mut output = Polynomial::from_container(vec![0, 0, 0, 0]);
+/// polynomial_apply_automorphism(&mut output, &input, AutomorphismExponent(3));
+/// assert_eq!(output.as_ref(), &[1, 4, 253, 2]);
+/// ```
+pub fn polynomial_apply_automorphism<Scalar, OutputCont, InputCont>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    automorphism_exponent: AutomorphismExponent,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == input.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input polynomial size {:?}.",
+        output.polynomial_size(),
+        input.polynomial_size(),
+    );
+
+    let polynomial_size = output.polynomial_size().0;
+
+    assert!(
+        automorphism_exponent.0 % 2 == 1 && automorphism_exponent.0 < 2 * polynomial_size,
+        "Invalid automorphism exponent {automorphism_exponent:?}, \
+        expected an odd exponent smaller than {}.",
+        2 * polynomial_size,
+    );
+
+    // X^i is mapped to X^(i * k mod 2N), as X^N = -1 the coefficient is negated when the new
+    // degree is greater or equal to N
+    for (degree, input_coeff) in input.iter().enumerate() {
+        let new_degree = (degree * automorphism_exponent.0) % (2 * polynomial_size);
+        if new_degree < polynomial_size {
+            output[new_degree] = *input_coeff;
+        } else {
+            output[new_degree - polynomial_size] = input_coeff.wrapping_neg();
+        }
+    }
+}
+
 /// Multiply (mod $(X^{N}+1)$), the input polynomial with a monic monomial of a given degree i.e.
 /// $X^{degree}$, then subtract the input from the result and assign to the output.
 ///
@@ -782,6 +840,389 @@ where
     }
 }
 
+/// Compute `lhs + rhs` modulo `custom_modulus`, for operands already reduced modulo
+/// `custom_modulus`.
+#[inline]
+fn reduced_add_custom_mod<Scalar: UnsignedInteger>(
+    lhs: Scalar,
+    rhs: Scalar,
+    custom_modulus: Scalar,
+) -> Scalar {
+    let (sum, overflowed) = lhs.overflowing_add(rhs);
+    if overflowed || sum >= custom_modulus {
+        sum.wrapping_sub(custom_modulus)
+    } else {
+        sum
+    }
+}
+
+/// Compute `lhs - rhs` modulo `custom_modulus`, for operands already reduced modulo
+/// `custom_modulus`.
+#[inline]
+fn reduced_sub_custom_mod<Scalar: UnsignedInteger>(
+    lhs: Scalar,
+    rhs: Scalar,
+    custom_modulus: Scalar,
+) -> Scalar {
+    if lhs >= rhs {
+        lhs - rhs
+    } else {
+        lhs.wrapping_sub(rhs).wrapping_add(custom_modulus)
+    }
+}
+
+/// Add the product of two polynomials to the output polynomial, modulo $(X^{N} + 1)$ and modulo a
+/// custom modulus.
+///
+/// The inputs are expected to be reduced modulo `custom_modulus`. This uses the schoolbook
+/// algorithm, coefficients of `rhs` equal to $0$, $1$ or $-1$ (as is the case for secret keys)
+/// are handled without multiplications.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_1 = Polynomial::from_container(vec![1_u64, 2, 3]);
+/// let poly_2 = Polynomial::from_container(vec![0, 1, 16]);
+/// let mut res = Polynomial::from_container(vec![1, 0, 1]);
+/// polynomial_wrapping_add_mul_assign_custom_mod(&mut res, &poly_1, &poly_2, 17);
+/// assert_eq!(res.as_ref(), &[0, 4, 2]);
+/// ```
+pub fn polynomial_wrapping_add_mul_assign_custom_mod<Scalar, OutputCont, InputCont1, InputCont2>(
+    output: &mut Polynomial<OutputCont>,
+    lhs: &Polynomial<InputCont1>,
+    rhs: &Polynomial<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    polynomial_wrapping_add_or_sub_mul_assign_custom_mod(output, lhs, rhs, custom_modulus, false);
+}
+
+/// Subtract the product of two polynomials from the output polynomial, modulo $(X^{N} + 1)$ and
+/// modulo a custom modulus.
+///
+/// The inputs are expected to be reduced modulo `custom_modulus`. This uses the schoolbook
+/// algorithm, coefficients of `rhs` equal to $0$, $1$ or $-1$ (as is the case for secret keys)
+/// are handled without multiplications.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_1 = Polynomial::from_container(vec![1_u64, 2, 3]);
+/// let poly_2 = Polynomial::from_container(vec![0, 1, 16]);
+/// let mut res = Polynomial::from_container(vec![1, 0, 1]);
+/// polynomial_wrapping_sub_mul_assign_custom_mod(&mut res, &poly_1, &poly_2, 17);
+/// assert_eq!(res.as_ref(), &[2, 13, 0]);
+/// ```
+pub fn polynomial_wrapping_sub_mul_assign_custom_mod<Scalar, OutputCont, InputCont1, InputCont2>(
+    output: &mut Polynomial<OutputCont>,
+    lhs: &Polynomial<InputCont1>,
+    rhs: &Polynomial<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    polynomial_wrapping_add_or_sub_mul_assign_custom_mod(output, lhs, rhs, custom_modulus, true);
+}
+
+fn polynomial_wrapping_add_or_sub_mul_assign_custom_mod<
+    Scalar,
+    OutputCont,
+    InputCont1,
+    InputCont2,
+>(
+    output: &mut Polynomial<OutputCont>,
+    lhs: &Polynomial<InputCont1>,
+    rhs: &Polynomial<InputCont2>,
+    custom_modulus: Scalar,
+    subtract: bool,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == lhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input lhs polynomial {:?}.",
+        output.polynomial_size(),
+        lhs.polynomial_size(),
+    );
+    assert!(
+        output.polynomial_size() == rhs.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input rhs polynomial {:?}.",
+        output.polynomial_size(),
+        rhs.polynomial_size(),
+    );
+
+    let polynomial_size = output.polynomial_size().0;
+    let minus_one = custom_modulus - Scalar::ONE;
+    let output = output.as_mut();
+    let lhs = lhs.as_ref();
+
+    for (rhs_degree, &rhs_coeff) in rhs.iter().enumerate() {
+        if rhs_coeff == Scalar::ZERO {
+            continue;
+        }
+
+        // Coefficients of lhs with a degree below the split do not wrap around X^N + 1 once
+        // multiplied by X^rhs_degree, the other ones are negated
+        let (output_low, output_high) = output.split_at_mut(rhs_degree);
+        let (lhs_low, lhs_high) = lhs.split_at(polynomial_size - rhs_degree);
+
+        // With the sign of the monomial accounted for, the product is either added or subtracted
+        let accumulate = |out: &mut [Scalar], input: &[Scalar], negate: bool| {
+            let negate = negate != subtract;
+            if rhs_coeff == Scalar::ONE || rhs_coeff == minus_one {
+                let negate = negate != (rhs_coeff == minus_one);
+                if negate {
+                    for (out, &input) in out.iter_mut().zip(input.iter()) {
+                        *out = reduced_sub_custom_mod(*out, input, custom_modulus);
+                    }
+                } else {
+                    for (out, &input) in out.iter_mut().zip(input.iter()) {
+                        *out = reduced_add_custom_mod(*out, input, custom_modulus);
+                    }
+                }
+            } else {
+                for (out, &input) in out.iter_mut().zip(input.iter()) {
+                    let product = input.wrapping_mul_custom_mod(rhs_coeff, custom_modulus);
+                    *out = if negate {
+                        reduced_sub_custom_mod(*out, product, custom_modulus)
+                    } else {
+                        reduced_add_custom_mod(*out, product, custom_modulus)
+                    };
+                }
+            }
+        };
+
+        accumulate(output_high, lhs_low, false);
+        accumulate(output_low, lhs_high, true);
+    }
+}
+
+/// Add the sum of the element-wise product between two lists of polynomials to the output
+/// polynomial, modulo $(X^{N} + 1)$ and modulo a custom modulus.
+///
+/// See [`polynomial_wrapping_add_mul_assign_custom_mod`] for the requirements on the inputs.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_list = PolynomialList::from_container(vec![10_u64, 2, 3, 4, 5, 6], PolynomialSize(3));
+/// let bin_poly_list = PolynomialList::from_container(vec![0, 1, 1, 1, 0, 0], PolynomialSize(3));
+/// let mut output = Polynomial::new(15, PolynomialSize(3));
+/// polynomial_wrapping_add_multisum_assign_custom_mod(&mut output, &poly_list, &bin_poly_list, 17);
+/// assert_eq!(output.as_ref(), &[14, 10, 16]);
+/// ```
+pub fn polynomial_wrapping_add_multisum_assign_custom_mod<
+    Scalar,
+    OutputCont,
+    InputCont1,
+    InputCont2,
+>(
+    output: &mut Polynomial<OutputCont>,
+    poly_list_1: &PolynomialList<InputCont1>,
+    poly_list_2: &PolynomialList<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    for (poly_1, poly_2) in poly_list_1.iter().zip(poly_list_2.iter()) {
+        polynomial_wrapping_add_mul_assign_custom_mod(output, &poly_1, &poly_2, custom_modulus);
+    }
+}
+
+/// Subtract the sum of the element-wise product between two lists of polynomials from the output
+/// polynomial, modulo $(X^{N} + 1)$ and modulo a custom modulus.
+///
+/// See [`polynomial_wrapping_sub_mul_assign_custom_mod`] for the requirements on the inputs.
+///
+/// # Example
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let poly_list = PolynomialList::from_container(vec![10_u64, 2, 3, 4, 5, 6], PolynomialSize(3));
+/// let bin_poly_list = PolynomialList::from_container(vec![0, 1, 1, 1, 0, 0], PolynomialSize(3));
+/// let mut output = Polynomial::new(15, PolynomialSize(3));
+/// polynomial_wrapping_sub_multisum_assign_custom_mod(&mut output, &poly_list, &bin_poly_list, 17);
+/// assert_eq!(output.as_ref(), &[16, 3, 14]);
+/// ```
+pub fn polynomial_wrapping_sub_multisum_assign_custom_mod<
+    Scalar,
+    OutputCont,
+    InputCont1,
+    InputCont2,
+>(
+    output: &mut Polynomial<OutputCont>,
+    poly_list_1: &PolynomialList<InputCont1>,
+    poly_list_2: &PolynomialList<InputCont2>,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont1: Container<Element = Scalar>,
+    InputCont2: Container<Element = Scalar>,
+{
+    for (poly_1, poly_2) in poly_list_1.iter().zip(poly_list_2.iter()) {
+        polynomial_wrapping_sub_mul_assign_custom_mod(output, &poly_1, &poly_2, custom_modulus);
+    }
+}
+
+/// Divides (mod $(X^{N}+1)$), the input polynomial with a monic monomial of a given degree i.e.
+/// $X^{degree}$, the coefficients being reduced modulo a custom modulus.
+///
+/// # Examples
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let input = Polynomial::from_container(vec![1u64, 2, 3]);
+/// let mut output = Polynomial::from_container(vec![0, 0, 0]);
+/// polynomial_wrapping_monic_monomial_div_custom_mod(&mut output, &input, MonomialDegree(2), 17);
+/// assert_eq!(output.as_ref(), &[3, 16, 15]);
+/// ```
+pub fn polynomial_wrapping_monic_monomial_div_custom_mod<Scalar, OutputCont, InputCont>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    monomial_degree: MonomialDegree,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == input.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input polynomial size {:?}.",
+        output.polynomial_size(),
+        input.polynomial_size(),
+    );
+
+    let polynomial_size = output.polynomial_size().0;
+    let remaining_degree = monomial_degree.0 % polynomial_size;
+    let full_cycles_count = monomial_degree.0 / polynomial_size;
+
+    let (output_low, output_high) = output
+        .as_mut()
+        .split_at_mut(polynomial_size - remaining_degree);
+    let (input_low, input_high) = input.as_ref().split_at(remaining_degree);
+
+    let copy = |dst: &mut [Scalar], src: &[Scalar], negate: bool| {
+        if negate {
+            for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+                *dst = src.wrapping_neg_custom_mod(custom_modulus);
+            }
+        } else {
+            dst.copy_from_slice(src);
+        }
+    };
+
+    let odd_cycles = full_cycles_count % 2 != 0;
+    copy(output_low, input_high, odd_cycles);
+    copy(output_high, input_low, !odd_cycles);
+}
+
+/// Multiply (mod $(X^{N}+1)$), the input polynomial with a monic monomial of a given degree i.e.
+/// $X^{degree}$, the coefficients being reduced modulo a custom modulus.
+///
+/// # Examples
+///
+/// ```
+/// use tfhe::core_crypto::algorithms::polynomial_algorithms::*;
+/// use tfhe::core_crypto::commons::parameters::*;
+/// use tfhe::core_crypto::entities::*;
+/// let input = Polynomial::from_container(vec![1u64, 2, 3]);
+/// let mut output = Polynomial::from_container(vec![0, 0, 0]);
+/// polynomial_wrapping_monic_monomial_mul_custom_mod(&mut output, &input, MonomialDegree(2), 17);
+/// assert_eq!(output.as_ref(), &[15, 14, 1]);
+/// ```
+pub fn polynomial_wrapping_monic_monomial_mul_custom_mod<Scalar, OutputCont, InputCont>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    monomial_degree: MonomialDegree,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    assert!(
+        output.polynomial_size() == input.polynomial_size(),
+        "Output polynomial size {:?} is not the same as input polynomial size {:?}.",
+        output.polynomial_size(),
+        input.polynomial_size(),
+    );
+
+    let polynomial_size = output.polynomial_size().0;
+    let remaining_degree = monomial_degree.0 % polynomial_size;
+    let full_cycles_count = monomial_degree.0 / polynomial_size;
+
+    let (output_low, output_high) = output.as_mut().split_at_mut(remaining_degree);
+    let (input_low, input_high) = input.as_ref().split_at(polynomial_size - remaining_degree);
+
+    let copy = |dst: &mut [Scalar], src: &[Scalar], negate: bool| {
+        if negate {
+            for (dst, &src) in dst.iter_mut().zip(src.iter()) {
+                *dst = src.wrapping_neg_custom_mod(custom_modulus);
+            }
+        } else {
+            dst.copy_from_slice(src);
+        }
+    };
+
+    let odd_cycles = full_cycles_count % 2 != 0;
+    copy(output_low, input_high, !odd_cycles);
+    copy(output_high, input_low, odd_cycles);
+}
+
+/// Multiply (mod $(X^{N}+1)$), the input polynomial with a monic monomial of a given degree i.e.
+/// $X^{degree}$, then subtract the input from the result and assign to the output, the
+/// coefficients being reduced modulo a custom modulus.
+///
+/// output = input * X^degree - input
+pub(crate) fn polynomial_wrapping_monic_monomial_mul_and_subtract_custom_mod<
+    Scalar,
+    OutputCont,
+    InputCont,
+>(
+    output: &mut Polynomial<OutputCont>,
+    input: &Polynomial<InputCont>,
+    monomial_degree: MonomialDegree,
+    custom_modulus: Scalar,
+) where
+    Scalar: UnsignedInteger,
+    OutputCont: ContainerMut<Element = Scalar>,
+    InputCont: Container<Element = Scalar>,
+{
+    polynomial_wrapping_monic_monomial_mul_custom_mod(
+        output,
+        input,
+        monomial_degree,
+        custom_modulus,
+    );
+    slice_wrapping_sub_assign_custom_mod(output.as_mut(), input.as_ref(), custom_modulus);
+}
+
 #[cfg(test)]
 mod test {
     use rand::Rng;
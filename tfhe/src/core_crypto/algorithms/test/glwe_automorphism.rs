@@ -0,0 +1,312 @@
+use super::*;
+use crate::core_crypto::algorithms::polynomial_algorithms::polynomial_apply_automorphism;
+
+#[cfg(not(feature = "__coverage"))]
+const NB_TESTS: usize = 10;
+#[cfg(feature = "__coverage")]
+const NB_TESTS: usize = 1;
+
+// The trace chains log2(N) automorphisms, each one adding a keyswitch noise, more levels than the
+// packing keyswitch are required
+const AUTOMORPHISM_DECOMP_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(15);
+const AUTOMORPHISM_DECOMP_LEVEL_COUNT: DecompositionLevelCount = DecompositionLevelCount(3);
+
+fn glwe_encrypt_automorphism_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus + CastFrom<usize> + CastInto<usize>,
+{
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        // Cycle through the exponents to cover different automorphisms
+        let automorphism_exponent = AutomorphismExponent(
+            (2 * CastInto::<usize>::cast_into(msg) + 3) % (2 * polynomial_size.0),
+        );
+
+        let ak = allocate_and_generate_new_glwe_automorphism_key(
+            &glwe_sk,
+            automorphism_exponent,
+            AUTOMORPHISM_DECOMP_BASE_LOG,
+            AUTOMORPHISM_DECOMP_LEVEL_COUNT,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &*ak,
+            ciphertext_modulus
+        ));
+
+        // Use a different message per coefficient to check they are moved around as expected
+        let clear_polynomial = Polynomial::from_container(
+            (0..polynomial_size.0)
+                .map(|idx| (msg + Scalar::cast_from(idx)) % msg_modulus)
+                .collect::<Vec<_>>(),
+        );
+        let mut expected_polynomial = Polynomial::new(Scalar::ZERO, polynomial_size);
+        polynomial_apply_automorphism(
+            &mut expected_polynomial,
+            &clear_polynomial,
+            automorphism_exponent,
+        );
+
+        for _ in 0..NB_TESTS {
+            let plaintext_list = PlaintextList::from_container(
+                clear_polynomial
+                    .iter()
+                    .map(|&x| x * delta)
+                    .collect::<Vec<_>>(),
+            );
+
+            let mut input_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut input_glwe,
+                &plaintext_list,
+                glwe_modular_std_dev,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = input_glwe.clone();
+
+            apply_automorphism_glwe_ciphertext(&ak, &input_glwe, &mut output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let mut decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&glwe_sk, &output_glwe, &mut decrypted_plaintext_list);
+
+            let decoded: Vec<_> = decrypted_plaintext_list
+                .iter()
+                .map(|x| round_decode(*x.0, delta) % msg_modulus)
+                .collect();
+            let expected: Vec<_> = expected_polynomial
+                .iter()
+                .map(|&x| x % msg_modulus)
+                .collect();
+
+            assert_eq!(decoded, expected);
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_automorphism_decrypt_custom_mod);
+
+fn lwe_encrypt_trace_pack_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus + CastFrom<usize> + CastInto<usize>,
+{
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    // The trace multiplies the message by N
+    let packing_delta = delta / Scalar::cast_from(polynomial_size.0);
+
+    // Generating the log2(N) trace keys is costly, they are shared by all messages
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let lwe_sk = glwe_sk.as_lwe_secret_key();
+
+    let trace_keys = allocate_and_generate_new_glwe_trace_automorphism_keys(
+        &glwe_sk,
+        AUTOMORPHISM_DECOMP_BASE_LOG,
+        AUTOMORPHISM_DECOMP_LEVEL_COUNT,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    assert_eq!(trace_keys.len(), polynomial_size.log2().0);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let lwe = allocate_and_encrypt_new_lwe_ciphertext(
+            &lwe_sk,
+            Plaintext(msg * packing_delta),
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut output_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        pack_lwe_ciphertext_into_glwe_ciphertext_via_trace(&trace_keys, &lwe, &mut output_glwe);
+
+        assert!(check_encrypted_content_respects_mod(
+            &output_glwe,
+            ciphertext_modulus
+        ));
+
+        let mut decrypted_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+        decrypt_glwe_ciphertext(&glwe_sk, &output_glwe, &mut decrypted_plaintext_list);
+
+        let decoded: Vec<_> = decrypted_plaintext_list
+            .iter()
+            .map(|x| round_decode(*x.0, delta) % msg_modulus)
+            .collect();
+
+        assert_eq!(decoded[0], msg);
+        assert!(decoded[1..].iter().all(|&x| x == Scalar::ZERO));
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_trace_pack_decrypt_custom_mod);
+
+fn lwe_list_encrypt_trace_pack_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus + CastFrom<usize> + CastInto<usize>,
+{
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    // The packing multiplies the messages by N
+    let packing_delta = delta / Scalar::cast_from(polynomial_size.0);
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+    let lwe_sk = glwe_sk.as_lwe_secret_key();
+
+    let trace_keys = allocate_and_generate_new_glwe_trace_automorphism_keys(
+        &glwe_sk,
+        AUTOMORPHISM_DECOMP_BASE_LOG,
+        AUTOMORPHISM_DECOMP_LEVEL_COUNT,
+        glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    // Pack an odd number of ciphertexts to go through the zero padding code paths
+    let lwe_ciphertext_count =
+        LweCiphertextCount(2 * CastInto::<usize>::cast_into(msg_modulus) + 1);
+
+    for _ in 0..NB_TESTS {
+        let msgs: Vec<Scalar> = (0..lwe_ciphertext_count.0)
+            .map(|idx| Scalar::cast_from(idx) % msg_modulus)
+            .collect();
+        let plaintext_list = PlaintextList::from_container(
+            msgs.iter().map(|&x| x * packing_delta).collect::<Vec<_>>(),
+        );
+
+        let mut lwe_list = LweCiphertextList::new(
+            Scalar::ZERO,
+            lwe_sk.lwe_dimension().to_lwe_size(),
+            lwe_ciphertext_count,
+            ciphertext_modulus,
+        );
+
+        encrypt_lwe_ciphertext_list(
+            &lwe_sk,
+            &mut lwe_list,
+            &plaintext_list,
+            glwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut output_glwe = GlweCiphertext::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            ciphertext_modulus,
+        );
+
+        pack_lwe_ciphertext_list_into_glwe_ciphertext_via_trace(
+            &trace_keys,
+            &lwe_list,
+            &mut output_glwe,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &output_glwe,
+            ciphertext_modulus
+        ));
+
+        let mut decrypted_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+        decrypt_glwe_ciphertext(&glwe_sk, &output_glwe, &mut decrypted_plaintext_list);
+
+        let decoded: Vec<_> = decrypted_plaintext_list
+            .iter()
+            .map(|x| round_decode(*x.0, delta) % msg_modulus)
+            .collect();
+
+        assert_eq!(&decoded[..lwe_ciphertext_count.0], &msgs);
+        assert!(decoded[lwe_ciphertext_count.0..]
+            .iter()
+            .all(|&x| x == Scalar::ZERO));
+    }
+}
+
+create_parametrized_test!(lwe_list_encrypt_trace_pack_decrypt_custom_mod);
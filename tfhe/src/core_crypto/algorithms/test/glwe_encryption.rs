@@ -141,7 +141,39 @@ fn glwe_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestPar
     }
 }
 
-create_parametrized_test!(glwe_encrypt_decrypt_custom_mod);
+create_parametrized_test_with_non_native_parameters!(glwe_encrypt_decrypt_custom_mod);
+
+#[test]
+#[should_panic(expected = "Unsupported ciphertext modulus")]
+fn glwe_encrypt_unsupported_prime_modulus() {
+    let glwe_dimension = GlweDimension(1);
+    let polynomial_size = PolynomialSize(1024);
+    // Prime modulus for which no primitive 2N-th root of unity exists, so the NTT cannot be used
+    let ciphertext_modulus = CiphertextModulus::<u64>::try_new((1 << 64) - 59).unwrap();
+
+    let mut rsc = TestResources::new();
+
+    let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+        glwe_dimension,
+        polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let mut glwe = GlweCiphertext::new(
+        0u64,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        ciphertext_modulus,
+    );
+
+    encrypt_glwe_ciphertext(
+        &glwe_sk,
+        &mut glwe,
+        &PlaintextList::new(0u64, PlaintextCount(polynomial_size.0)),
+        StandardDev(0.0),
+        &mut rsc.encryption_random_generator,
+    );
+}
 
 fn glwe_list_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestParams<Scalar>) {
     let glwe_dimension = params.glwe_dimension;
@@ -0,0 +1,349 @@
+use super::*;
+
+#[cfg(not(feature = "__coverage"))]
+const NB_TESTS: usize = 10;
+#[cfg(feature = "__coverage")]
+const NB_TESTS: usize = 1;
+
+fn glwe_encrypt_ks_decrypt_custom_mod<Scalar: UnsignedTorus + Send + Sync>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ks_decomp_base_log = params.pfks_base_log;
+    let ks_decomp_level_count = params.pfks_level;
+
+    // Switch from a bigger GLWE dimension to the one of the parameters
+    let input_glwe_dimension = GlweDimension(params.glwe_dimension.0 + 1);
+    let output_glwe_dimension = params.glwe_dimension;
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let input_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            input_glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let output_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            output_glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+            &input_glwe_sk,
+            &output_glwe_sk,
+            ks_decomp_base_log,
+            ks_decomp_level_count,
+            params.pfks_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &ksk,
+            ciphertext_modulus
+        ));
+
+        for _ in 0..NB_TESTS {
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let mut input_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                input_glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &input_glwe_sk,
+                &mut input_glwe,
+                &plaintext_list,
+                glwe_modular_std_dev,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                output_glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            keyswitch_glwe_ciphertext(&ksk, &input_glwe, &mut output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let mut decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&output_glwe_sk, &output_glwe, &mut decrypted_plaintext_list);
+
+            let decoded: Vec<_> = decrypted_plaintext_list
+                .iter()
+                .map(|x| round_decode(*x.0, delta) % msg_modulus)
+                .collect();
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_ks_decrypt_custom_mod);
+
+fn glwe_encrypt_par_ks_decrypt_custom_mod<Scalar: UnsignedTorus + Send + Sync>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ks_decomp_base_log = params.pfks_base_log;
+    let ks_decomp_level_count = params.pfks_level;
+
+    // Switch from a bigger GLWE dimension to the one of the parameters
+    let input_glwe_dimension = GlweDimension(params.glwe_dimension.0 + 1);
+    let output_glwe_dimension = params.glwe_dimension;
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let input_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            input_glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let output_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            output_glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+            &input_glwe_sk,
+            &output_glwe_sk,
+            ks_decomp_base_log,
+            ks_decomp_level_count,
+            params.pfks_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &ksk,
+            ciphertext_modulus
+        ));
+
+        for _ in 0..NB_TESTS {
+            let plaintext_list = PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+
+            let mut input_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                input_glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &input_glwe_sk,
+                &mut input_glwe,
+                &plaintext_list,
+                glwe_modular_std_dev,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                output_glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            par_keyswitch_glwe_ciphertext(&ksk, &input_glwe, &mut output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let mut decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&output_glwe_sk, &output_glwe, &mut decrypted_plaintext_list);
+
+            let decoded: Vec<_> = decrypted_plaintext_list
+                .iter()
+                .map(|x| round_decode(*x.0, delta) % msg_modulus)
+                .collect();
+
+            assert!(decoded.iter().all(|&x| x == msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_par_ks_decrypt_custom_mod);
+
+fn glwe_encrypt_ring_switch_ks_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus + CastFrom<usize> + Send + Sync,
+{
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ks_decomp_base_log = params.pfks_base_log;
+    let ks_decomp_level_count = params.pfks_level;
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    // Switch from a ring twice as large, then from a ring twice as small, to the one of the
+    // parameters
+    for input_polynomial_size in [
+        PolynomialSize(polynomial_size.0 * 2),
+        PolynomialSize(polynomial_size.0 / 2),
+    ] {
+        let input_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            input_polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+        let output_glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        let ksk = allocate_and_generate_new_glwe_keyswitch_key(
+            &input_glwe_sk,
+            &output_glwe_sk,
+            ks_decomp_base_log,
+            ks_decomp_level_count,
+            params.pfks_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert_eq!(
+            ksk.input_key_glwe_dimension(),
+            glwe_keyswitch_key_input_key_glwe_dimension(
+                glwe_dimension,
+                input_polynomial_size,
+                polynomial_size
+            )
+        );
+        assert!(check_encrypted_content_respects_mod(
+            &ksk,
+            ciphertext_modulus
+        ));
+
+        for test_idx in 0..NB_TESTS {
+            let msgs: Vec<Scalar> = (0..input_polynomial_size.0)
+                .map(|idx| Scalar::cast_from(idx + test_idx) % msg_modulus)
+                .collect();
+
+            let plaintext_list = PlaintextList::from_container(
+                msgs.iter().map(|&msg| msg * delta).collect::<Vec<_>>(),
+            );
+
+            let mut input_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                input_polynomial_size,
+                ciphertext_modulus,
+            );
+
+            encrypt_glwe_ciphertext(
+                &input_glwe_sk,
+                &mut input_glwe,
+                &plaintext_list,
+                glwe_modular_std_dev,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            keyswitch_glwe_ciphertext(&ksk, &input_glwe, &mut output_glwe);
+
+            let mut par_output_glwe = output_glwe.clone();
+            par_keyswitch_glwe_ciphertext(&ksk, &input_glwe, &mut par_output_glwe);
+            assert_eq!(output_glwe, par_output_glwe);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let mut decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&output_glwe_sk, &output_glwe, &mut decrypted_plaintext_list);
+
+            let decoded: Vec<_> = decrypted_plaintext_list
+                .iter()
+                .map(|x| round_decode(*x.0, delta) % msg_modulus)
+                .collect();
+
+            // Switching to a smaller ring keeps the coefficients whose index is a multiple of the
+            // ratio, switching to a larger ring spreads the coefficients with the ratio as stride
+            let expected: Vec<_> = if input_polynomial_size.0 > polynomial_size.0 {
+                let ratio = input_polynomial_size.0 / polynomial_size.0;
+                msgs.iter().step_by(ratio).copied().collect()
+            } else {
+                let ratio = polynomial_size.0 / input_polynomial_size.0;
+                (0..polynomial_size.0)
+                    .map(|idx| {
+                        if idx % ratio == 0 {
+                            msgs[idx / ratio]
+                        } else {
+                            Scalar::ZERO
+                        }
+                    })
+                    .collect()
+            };
+
+            assert_eq!(decoded, expected);
+        }
+    }
+}
+
+create_parametrized_test!(glwe_encrypt_ring_switch_ks_decrypt_custom_mod);
@@ -0,0 +1,157 @@
+use super::*;
+
+#[cfg(not(feature = "__coverage"))]
+const NB_TESTS: usize = 10;
+#[cfg(feature = "__coverage")]
+const NB_TESTS: usize = 1;
+
+// The relinearization adds the decomposition error of the quadratic terms multiplied by the
+// products of secret key polynomials, more levels than the packing keyswitch are required
+const RELIN_DECOMP_BASE_LOG: DecompositionBaseLog = DecompositionBaseLog(15);
+const RELIN_DECOMP_LEVEL_COUNT: DecompositionLevelCount = DecompositionLevelCount(3);
+
+type TensorProductFn<Scalar> = fn(
+    &GlweCiphertextOwned<Scalar>,
+    &GlweCiphertextOwned<Scalar>,
+    &mut GlweCiphertextOwned<Scalar>,
+    Scalar,
+);
+
+fn glwe_encrypt_tensor_relin_decrypt<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+    tensor_product: TensorProductFn<Scalar>,
+) {
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut rsc.secret_random_generator,
+        );
+
+        let rlk = allocate_and_generate_new_glwe_relinearization_key(
+            &glwe_sk,
+            RELIN_DECOMP_BASE_LOG,
+            RELIN_DECOMP_LEVEL_COUNT,
+            glwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &rlk,
+            ciphertext_modulus
+        ));
+
+        // The rhs message is a constant polynomial so that the product is computed coefficient
+        // wise
+        let rhs_msg = (msg + Scalar::ONE) % msg_modulus;
+        let expected = msg.wrapping_mul(rhs_msg) % msg_modulus;
+
+        for _ in 0..NB_TESTS {
+            let lhs_plaintext_list =
+                PlaintextList::new(msg * delta, PlaintextCount(polynomial_size.0));
+            let mut rhs_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+            *rhs_plaintext_list.get_mut(0).0 = rhs_msg * delta;
+
+            let mut lhs = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+            let mut rhs = lhs.clone();
+
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut lhs,
+                &lhs_plaintext_list,
+                glwe_modular_std_dev,
+                &mut rsc.encryption_random_generator,
+            );
+            encrypt_glwe_ciphertext(
+                &glwe_sk,
+                &mut rhs,
+                &rhs_plaintext_list,
+                glwe_modular_std_dev,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut tensor = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_tensor_product_output_glwe_size(glwe_dimension.to_glwe_size()),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            tensor_product(&lhs, &rhs, &mut tensor, delta);
+
+            assert!(check_encrypted_content_respects_mod(
+                &tensor,
+                ciphertext_modulus
+            ));
+
+            let mut output_glwe = GlweCiphertext::new(
+                Scalar::ZERO,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                ciphertext_modulus,
+            );
+
+            glwe_relinearization(&tensor, &mut output_glwe, &rlk);
+
+            assert!(check_encrypted_content_respects_mod(
+                &output_glwe,
+                ciphertext_modulus
+            ));
+
+            let mut decrypted_plaintext_list =
+                PlaintextList::new(Scalar::ZERO, PlaintextCount(polynomial_size.0));
+
+            decrypt_glwe_ciphertext(&glwe_sk, &output_glwe, &mut decrypted_plaintext_list);
+
+            let decoded: Vec<_> = decrypted_plaintext_list
+                .iter()
+                .map(|x| round_decode(*x.0, delta) % msg_modulus)
+                .collect();
+
+            assert!(decoded.iter().all(|&x| x == expected));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+fn glwe_encrypt_tensor_relin_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    glwe_encrypt_tensor_relin_decrypt(params, glwe_tensor_product)
+}
+
+create_parametrized_test!(glwe_encrypt_tensor_relin_decrypt_custom_mod);
+
+fn glwe_encrypt_tensor_f128_relin_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    glwe_encrypt_tensor_relin_decrypt(params, glwe_tensor_product_f128)
+}
+
+create_parametrized_test!(glwe_encrypt_tensor_f128_relin_decrypt_custom_mod);
@@ -4,8 +4,8 @@ use crate::core_crypto::commons::generators::{DeterministicSeeder, EncryptionRan
 use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed};
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::parameters::{
-    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension,
-    PolynomialSize,
+    CiphertextModulus, DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount,
+    GlweDimension, LweDimension, PolynomialSize,
 };
 use crate::core_crypto::commons::test_tools::new_secret_random_generator;
 use crate::core_crypto::entities::*;
@@ -165,3 +165,145 @@ fn test_parallel_and_seeded_bsk_gen_equivalence_u64_custom_mod() {
         CiphertextModulus::try_new_power_of_2(63).unwrap(),
     );
 }
+
+fn test_streamed_bsk_gen_equivalence<T: UnsignedTorus + Sync + Send>(
+    ciphertext_modulus: CiphertextModulus<T>,
+) {
+    for _ in 0..NB_TESTS {
+        let lwe_dim =
+            LweDimension(crate::core_crypto::commons::test_tools::random_usize_between(5..10));
+        let glwe_dim =
+            GlweDimension(crate::core_crypto::commons::test_tools::random_usize_between(1..3));
+        // The Fourier conversion requires a power of 2 polynomial size
+        let poly_size = PolynomialSize(64);
+        let level = DecompositionLevelCount(
+            crate::core_crypto::commons::test_tools::random_usize_between(2..5),
+        );
+        let base_log = DecompositionBaseLog(
+            crate::core_crypto::commons::test_tools::random_usize_between(2..5),
+        );
+        let chunk_size = GgswCiphertextCount(
+            crate::core_crypto::commons::test_tools::random_usize_between(1..lwe_dim.0 + 1),
+        );
+        let mask_seed = Seed(crate::core_crypto::commons::test_tools::any_usize() as u128);
+        let deterministic_seeder_seed =
+            Seed(crate::core_crypto::commons::test_tools::any_usize() as u128);
+
+        let mut secret_generator = new_secret_random_generator();
+        let lwe_sk =
+            allocate_and_generate_new_binary_lwe_secret_key(lwe_dim, &mut secret_generator);
+        let glwe_sk = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dim,
+            poly_size,
+            &mut secret_generator,
+        );
+
+        let new_encryption_generator = || {
+            EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(
+                mask_seed,
+                &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(
+                    deterministic_seeder_seed,
+                ),
+            )
+        };
+
+        let bsk = allocate_and_generate_new_lwe_bootstrap_key(
+            &lwe_sk,
+            &glwe_sk,
+            base_log,
+            level,
+            StandardDev::from_standard_dev(10.),
+            ciphertext_modulus,
+            &mut new_encryption_generator(),
+        );
+
+        let mut fourier_bsk = FourierLweBootstrapKey::new(
+            bsk.input_lwe_dimension(),
+            bsk.glwe_size(),
+            bsk.polynomial_size(),
+            bsk.decomposition_base_log(),
+            bsk.decomposition_level_count(),
+        );
+        convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fourier_bsk);
+
+        let mut raw_bsk = Vec::new();
+        bsk.write_raw(&mut raw_bsk).unwrap();
+        let mut raw_fourier_bsk = Vec::new();
+        fourier_bsk.write_raw(&mut raw_fourier_bsk).unwrap();
+
+        let mut streamed_raw_bsk = Vec::new();
+        generate_lwe_bootstrap_key_to_writer(
+            &lwe_sk,
+            &glwe_sk,
+            base_log,
+            level,
+            StandardDev::from_standard_dev(10.),
+            ciphertext_modulus,
+            chunk_size,
+            &mut new_encryption_generator(),
+            &mut streamed_raw_bsk,
+        )
+        .unwrap();
+        assert_eq!(streamed_raw_bsk, raw_bsk);
+
+        let mut par_streamed_raw_bsk = Vec::new();
+        par_generate_lwe_bootstrap_key_to_writer(
+            &lwe_sk,
+            &glwe_sk,
+            base_log,
+            level,
+            StandardDev::from_standard_dev(10.),
+            ciphertext_modulus,
+            chunk_size,
+            &mut new_encryption_generator(),
+            &mut par_streamed_raw_bsk,
+        )
+        .unwrap();
+        assert_eq!(par_streamed_raw_bsk, raw_bsk);
+
+        let mut streamed_raw_fourier_bsk = Vec::new();
+        generate_fourier_lwe_bootstrap_key_to_writer(
+            &lwe_sk,
+            &glwe_sk,
+            base_log,
+            level,
+            StandardDev::from_standard_dev(10.),
+            ciphertext_modulus,
+            chunk_size,
+            &mut new_encryption_generator(),
+            &mut streamed_raw_fourier_bsk,
+        )
+        .unwrap();
+        assert_eq!(streamed_raw_fourier_bsk, raw_fourier_bsk);
+
+        let mut par_streamed_raw_fourier_bsk = Vec::new();
+        par_generate_fourier_lwe_bootstrap_key_to_writer(
+            &lwe_sk,
+            &glwe_sk,
+            base_log,
+            level,
+            StandardDev::from_standard_dev(10.),
+            ciphertext_modulus,
+            chunk_size,
+            &mut new_encryption_generator(),
+            &mut par_streamed_raw_fourier_bsk,
+        )
+        .unwrap();
+        assert_eq!(par_streamed_raw_fourier_bsk, raw_fourier_bsk);
+    }
+}
+
+#[test]
+fn test_streamed_bsk_gen_equivalence_u32_native_mod() {
+    test_streamed_bsk_gen_equivalence::<u32>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_streamed_bsk_gen_equivalence_u64_native_mod() {
+    test_streamed_bsk_gen_equivalence::<u64>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_streamed_bsk_gen_equivalence_u64_custom_mod() {
+    test_streamed_bsk_gen_equivalence::<u64>(CiphertextModulus::try_new_power_of_2(63).unwrap());
+}
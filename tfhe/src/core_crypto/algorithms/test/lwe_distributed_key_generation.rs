@@ -0,0 +1,296 @@
+use super::*;
+
+use crate::core_crypto::commons::generators::{
+    DeterministicSeeder, EncryptionRandomGenerator, SecretRandomGenerator,
+};
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed};
+use crate::core_crypto::commons::test_tools::random_usize_between;
+
+#[cfg(not(feature = "__coverage"))]
+const NB_TESTS: usize = 10;
+#[cfg(feature = "__coverage")]
+const NB_TESTS: usize = 1;
+
+const PARTY_COUNT: usize = 3;
+
+// Split the input in PARTY_COUNT additive shares, plays the role of the multi-party computation
+// protocol generating the secret key shares
+fn split_in_additive_shares<Scalar: UnsignedTorus>(
+    data: &[Scalar],
+    encryption_generator: &mut EncryptionRandomGenerator<ActivatedRandomGenerator>,
+) -> Vec<Vec<Scalar>> {
+    let mut shares: Vec<Vec<Scalar>> = (1..PARTY_COUNT)
+        .map(|_| {
+            let mut share = vec![Scalar::ZERO; data.len()];
+            encryption_generator.fill_slice_with_random_mask(&mut share);
+            share
+        })
+        .collect();
+
+    let last_share = data
+        .iter()
+        .enumerate()
+        .map(|(idx, &value)| {
+            shares
+                .iter()
+                .fold(value, |acc, share| acc.wrapping_sub(share[idx]))
+        })
+        .collect();
+    shares.push(last_share);
+
+    shares
+}
+
+// Without noise the aggregated keys must match exactly the keys generated from the joint secret
+// keys as all the masks come from the common seed
+fn test_distributed_key_gen_equivalence<Scalar: UnsignedTorus>() {
+    let ciphertext_modulus = CiphertextModulus::<Scalar>::new_native();
+    let no_noise = StandardDev(0.0);
+
+    let mut seeder = new_seeder();
+    let seeder = seeder.as_mut();
+    let mut secret_generator =
+        SecretRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed());
+    let mut encryption_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(seeder.seed(), seeder);
+
+    for _ in 0..NB_TESTS {
+        let lwe_dimension = LweDimension(random_usize_between(5..10));
+        // Keep the large LWE dimension a power of 2 for the compact public key
+        let glwe_dimension = GlweDimension(random_usize_between(1..3));
+        let polynomial_size = PolynomialSize(1 << random_usize_between(3..6));
+        let decomp_level_count = DecompositionLevelCount(random_usize_between(2..5));
+        let decomp_base_log = DecompositionBaseLog(random_usize_between(2..5));
+        let common_seed = seeder.seed();
+
+        let lwe_secret_key =
+            allocate_and_generate_new_binary_lwe_secret_key(lwe_dimension, &mut secret_generator);
+        let glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            glwe_dimension,
+            polynomial_size,
+            &mut secret_generator,
+        );
+        let large_lwe_secret_key: LweSecretKey<&[Scalar]> = glwe_secret_key.as_lwe_secret_key();
+
+        let mut key_products = PolynomialList::new(
+            Scalar::ZERO,
+            polynomial_size,
+            PolynomialCount(lwe_dimension.0 * glwe_dimension.0),
+        );
+        for (mut key_product, (&lwe_key_element, glwe_key_poly)) in key_products.iter_mut().zip(
+            lwe_secret_key
+                .as_ref()
+                .iter()
+                .flat_map(|lwe_key_element| {
+                    std::iter::repeat(lwe_key_element).take(glwe_dimension.0)
+                })
+                .zip(
+                    glwe_secret_key
+                        .as_ref()
+                        .chunks_exact(polynomial_size.0)
+                        .cycle(),
+                ),
+        ) {
+            for (product_coeff, &glwe_key_coeff) in
+                key_product.as_mut().iter_mut().zip(glwe_key_poly)
+            {
+                *product_coeff = glwe_key_coeff.wrapping_mul(lwe_key_element);
+            }
+        }
+
+        let lwe_key_shares =
+            split_in_additive_shares(lwe_secret_key.as_ref(), &mut encryption_generator);
+        let glwe_key_shares =
+            split_in_additive_shares(glwe_secret_key.as_ref(), &mut encryption_generator);
+        let key_product_shares =
+            split_in_additive_shares(key_products.as_ref(), &mut encryption_generator);
+
+        // Bootstrap key
+        let mut expected_bsk = SeededLweBootstrapKey::new(
+            Scalar::ZERO,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            lwe_dimension,
+            common_seed.into(),
+            ciphertext_modulus,
+        );
+        generate_seeded_lwe_bootstrap_key(
+            &lwe_secret_key,
+            &glwe_secret_key,
+            &mut expected_bsk,
+            no_noise,
+            seeder,
+        );
+
+        let bsk_shares: Vec<_> = lwe_key_shares
+            .iter()
+            .zip(glwe_key_shares.iter())
+            .zip(key_product_shares.iter())
+            .map(|((lwe_key_share, glwe_key_share), key_product_share)| {
+                let mut bsk_share = SeededLweBootstrapKey::new(
+                    Scalar::ZERO,
+                    glwe_dimension.to_glwe_size(),
+                    polynomial_size,
+                    decomp_base_log,
+                    decomp_level_count,
+                    lwe_dimension,
+                    common_seed.into(),
+                    ciphertext_modulus,
+                );
+                generate_seeded_lwe_bootstrap_key_share(
+                    &LweSecretKey::from_container(lwe_key_share.as_slice()),
+                    &GlweSecretKey::from_container(glwe_key_share.as_slice(), polynomial_size),
+                    &PolynomialList::from_container(key_product_share.as_slice(), polynomial_size),
+                    &mut bsk_share,
+                    no_noise,
+                    &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(seeder.seed()),
+                );
+                bsk_share
+            })
+            .collect();
+
+        // Make sure the output content does not matter
+        let mut aggregated_bsk = SeededLweBootstrapKey::new(
+            Scalar::ONE,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            lwe_dimension,
+            common_seed.into(),
+            ciphertext_modulus,
+        );
+        aggregate_seeded_lwe_bootstrap_key_shares(&bsk_shares, &mut aggregated_bsk);
+
+        assert_eq!(aggregated_bsk, expected_bsk);
+
+        // Keyswitch key from the large key to the small key
+        let mut expected_ksk = SeededLweKeyswitchKey::new(
+            Scalar::ZERO,
+            decomp_base_log,
+            decomp_level_count,
+            large_lwe_secret_key.lwe_dimension(),
+            lwe_dimension,
+            common_seed.into(),
+            ciphertext_modulus,
+        );
+        generate_seeded_lwe_keyswitch_key(
+            &large_lwe_secret_key,
+            &lwe_secret_key,
+            &mut expected_ksk,
+            no_noise,
+            seeder,
+        );
+
+        let ksk_shares: Vec<_> = lwe_key_shares
+            .iter()
+            .zip(glwe_key_shares.iter())
+            .map(|(lwe_key_share, glwe_key_share)| {
+                let mut ksk_share = SeededLweKeyswitchKey::new(
+                    Scalar::ZERO,
+                    decomp_base_log,
+                    decomp_level_count,
+                    large_lwe_secret_key.lwe_dimension(),
+                    lwe_dimension,
+                    common_seed.into(),
+                    ciphertext_modulus,
+                );
+                generate_seeded_lwe_keyswitch_key(
+                    &LweSecretKey::from_container(glwe_key_share.as_slice()),
+                    &LweSecretKey::from_container(lwe_key_share.as_slice()),
+                    &mut ksk_share,
+                    no_noise,
+                    &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(seeder.seed()),
+                );
+                ksk_share
+            })
+            .collect();
+
+        let mut aggregated_ksk = SeededLweKeyswitchKey::new(
+            Scalar::ONE,
+            decomp_base_log,
+            decomp_level_count,
+            large_lwe_secret_key.lwe_dimension(),
+            lwe_dimension,
+            common_seed.into(),
+            ciphertext_modulus,
+        );
+        aggregate_seeded_lwe_keyswitch_key_shares(&ksk_shares, &mut aggregated_ksk);
+
+        assert_eq!(aggregated_ksk, expected_ksk);
+
+        // Compact public key for the large key, which has a power of 2 dimension
+        let mut expected_cpk = SeededLweCompactPublicKey::new(
+            Scalar::ZERO,
+            large_lwe_secret_key.lwe_dimension(),
+            common_seed.into(),
+            ciphertext_modulus,
+        );
+        generate_seeded_lwe_compact_public_key(
+            &large_lwe_secret_key,
+            &mut expected_cpk,
+            no_noise,
+            seeder,
+        );
+
+        let cpk_shares: Vec<_> = glwe_key_shares
+            .iter()
+            .map(|glwe_key_share| {
+                let mut cpk_share = SeededLweCompactPublicKey::new(
+                    Scalar::ZERO,
+                    large_lwe_secret_key.lwe_dimension(),
+                    common_seed.into(),
+                    ciphertext_modulus,
+                );
+                generate_seeded_lwe_compact_public_key(
+                    &LweSecretKey::from_container(glwe_key_share.as_slice()),
+                    &mut cpk_share,
+                    no_noise,
+                    &mut DeterministicSeeder::<ActivatedRandomGenerator>::new(seeder.seed()),
+                );
+                cpk_share
+            })
+            .collect();
+
+        let mut aggregated_cpk = SeededLweCompactPublicKey::new(
+            Scalar::ONE,
+            large_lwe_secret_key.lwe_dimension(),
+            common_seed.into(),
+            ciphertext_modulus,
+        );
+        aggregate_seeded_lwe_compact_public_key_shares(&cpk_shares, &mut aggregated_cpk);
+
+        assert_eq!(aggregated_cpk, expected_cpk);
+    }
+}
+
+#[test]
+fn test_distributed_key_gen_equivalence_u32() {
+    test_distributed_key_gen_equivalence::<u32>();
+}
+
+#[test]
+fn test_distributed_key_gen_equivalence_u64() {
+    test_distributed_key_gen_equivalence::<u64>();
+}
+
+#[test]
+#[should_panic(expected = "Mismatched CompressionSeed")]
+fn test_distributed_key_gen_mismatched_seeds() {
+    let share = SeededLweCompactPublicKey::new(
+        0u64,
+        LweDimension(16),
+        Seed(0).into(),
+        CiphertextModulus::new_native(),
+    );
+    let mut output = SeededLweCompactPublicKey::new(
+        0u64,
+        LweDimension(16),
+        Seed(1).into(),
+        CiphertextModulus::new_native(),
+    );
+
+    aggregate_seeded_lwe_compact_public_key_shares(&[share], &mut output);
+}
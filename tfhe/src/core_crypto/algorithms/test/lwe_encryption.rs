@@ -196,6 +196,84 @@ fn lwe_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(params: ClassicTestPara
 
 create_parametrized_test_with_non_native_parameters!(lwe_encrypt_decrypt_custom_mod);
 
+fn lwe_encrypt_decrypt_noise_distributions_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    // Use a TUniform noise whose bound is below the standard deviation of the gaussian noise of
+    // the parameters, so that the correctness is preserved
+    let log2_modulus = if ciphertext_modulus.is_native_modulus() {
+        Scalar::BITS as f64
+    } else {
+        (ciphertext_modulus.get_custom_modulus() as f64).log2()
+    };
+    let t_uniform_bound_log2 = (lwe_modular_std_dev.0.log2() + log2_modulus).floor() as u32;
+
+    let noise_distributions = [
+        NoiseDistribution::DiscreteGaussian(lwe_modular_std_dev),
+        NoiseDistribution::TUniform(TUniform::new(t_uniform_bound_log2)),
+    ];
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    for noise_distribution in noise_distributions {
+        let mut msg = msg_modulus;
+        while msg != Scalar::ZERO {
+            msg = msg.wrapping_sub(Scalar::ONE);
+            for _ in 0..NB_TESTS {
+                let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+                    lwe_dimension,
+                    &mut rsc.secret_random_generator,
+                );
+
+                let mut ct = LweCiphertext::new(
+                    Scalar::ZERO,
+                    lwe_dimension.to_lwe_size(),
+                    ciphertext_modulus,
+                );
+
+                let plaintext = Plaintext(msg * delta);
+
+                encrypt_lwe_ciphertext(
+                    &lwe_sk,
+                    &mut ct,
+                    plaintext,
+                    noise_distribution,
+                    &mut rsc.encryption_random_generator,
+                );
+
+                assert!(check_encrypted_content_respects_mod(
+                    &ct,
+                    ciphertext_modulus
+                ));
+
+                let decrypted = decrypt_lwe_ciphertext(&lwe_sk, &ct);
+
+                let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+                assert_eq!(msg, decoded);
+            }
+
+            // In coverage, we break after one while loop iteration, changing message values does
+            // not yield higher coverage
+            #[cfg(feature = "__coverage")]
+            break;
+        }
+    }
+}
+
+create_parametrized_test_with_non_native_parameters!(
+    lwe_encrypt_decrypt_noise_distributions_custom_mod
+);
+
 fn lwe_allocate_encrypt_decrypt_custom_mod<Scalar: UnsignedTorus>(
     params: ClassicTestParams<Scalar>,
 ) {
@@ -0,0 +1,304 @@
+use super::*;
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::algorithms::test::lwe_programmable_bootstrapping::generate_keys;
+use crate::core_crypto::keycache::KeyCacheAccess;
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+#[cfg(not(feature = "__coverage"))]
+const NB_TESTS: usize = 10;
+#[cfg(feature = "__coverage")]
+const NB_TESTS: usize = 1;
+
+fn lwe_encrypt_modulus_switch_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    // Keep enough bits for the message, the padding and some noise
+    let output_log_modulus = message_modulus_log.0 + 16;
+    let output_ciphertext_modulus =
+        CiphertextModulus::try_new_power_of_2(output_log_modulus).unwrap();
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let output_delta: Scalar = get_encoding_with_padding(output_ciphertext_modulus) / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+                lwe_dimension,
+                &mut rsc.secret_random_generator,
+            );
+
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &lwe_sk,
+                Plaintext(msg * delta),
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut switched_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                lwe_dimension.to_lwe_size(),
+                output_ciphertext_modulus,
+            );
+
+            lwe_ciphertext_modulus_switch(&ct, &mut switched_ct);
+
+            assert!(check_encrypted_content_respects_mod(
+                &switched_ct,
+                output_ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&lwe_sk, &switched_ct);
+
+            let decoded = round_decode(decrypted.0, output_delta) % msg_modulus;
+
+            assert_eq!(msg, decoded);
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_modulus_switch_decrypt_custom_mod);
+
+fn lwe_encrypt_modulus_switch_pack_extract_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let polynomial_size = params.polynomial_size;
+
+    // The number of bits used by the blind rotation
+    let log_modulus = CiphertextModulusLog(polynomial_size.log2().0 + 1);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+                lwe_dimension,
+                &mut rsc.secret_random_generator,
+            );
+
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &lwe_sk,
+                Plaintext(msg * delta),
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let ms_ct = modulus_switch_and_pack_lwe_ciphertext(&ct, log_modulus);
+
+            assert_eq!(ms_ct.lwe_dimension(), lwe_dimension);
+            assert_eq!(ms_ct.log_modulus(), log_modulus);
+            assert_eq!(ms_ct.uncompressed_ciphertext_modulus(), ciphertext_modulus);
+            assert_eq!(
+                ms_ct.packed_coeffs().len(),
+                modulus_switched_lwe_ciphertext_packed_len::<Scalar>(
+                    lwe_dimension.to_lwe_size(),
+                    log_modulus
+                )
+            );
+
+            for (switched, &original) in ms_ct.switched_coeffs().zip(ct.as_ref().iter()) {
+                assert_eq!(switched, modulus_switch(original, log_modulus));
+            }
+
+            let extracted_ct = ms_ct.extract();
+
+            assert!(check_encrypted_content_respects_mod(
+                &extracted_ct,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&lwe_sk, &extracted_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(msg, decoded);
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_modulus_switch_pack_extract_decrypt_custom_mod);
+
+fn lwe_encrypt_modulus_switch_pack_blind_rotate_decrypt_custom_mod<Scalar>(
+    params: ClassicTestParams<Scalar>,
+) where
+    Scalar: UnsignedTorus
+        + Sync
+        + Send
+        + CastFrom<usize>
+        + CastInto<usize>
+        + Serialize
+        + DeserializeOwned,
+    ClassicTestParams<Scalar>: KeyCacheAccess<Keys = ClassicBootstrapKeys<Scalar>>,
+{
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    // The number of bits used by the blind rotation
+    let log_modulus = CiphertextModulusLog(polynomial_size.log2().0 + 1);
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x;
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let mut keys_gen = |params| generate_keys(params, &mut rsc);
+        let keys = gen_keys_or_get_from_cache_if_enabled(params, &mut keys_gen);
+        let (input_lwe_secret_key, output_lwe_secret_key, fbsk) =
+            (keys.small_lwe_sk, keys.big_lwe_sk, keys.fbsk);
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let ms_ct = modulus_switch_and_pack_lwe_ciphertext(&lwe_ciphertext_in, log_modulus);
+
+            let mut local_accumulator = accumulator.clone();
+
+            blind_rotate_modulus_switched_lwe_ciphertext_assign(
+                &ms_ct,
+                &mut local_accumulator,
+                &fbsk,
+            );
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            extract_lwe_sample_from_glwe_ciphertext(
+                &local_accumulator,
+                &mut out_pbs_ct,
+                MonomialDegree(0),
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_ct,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_modulus_switch_pack_blind_rotate_decrypt_custom_mod);
+
+#[test]
+fn modulus_switched_lwe_ciphertext_conformance() {
+    let lwe_dimension = LweDimension(742);
+    let log_modulus = CiphertextModulusLog(12);
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    let switched_coeffs: Vec<u64> = (0..lwe_dimension.to_lwe_size().0 as u64)
+        .map(|x| x % (1 << log_modulus.0))
+        .collect();
+
+    let ms_ct = ModulusSwitchedLweCiphertext::from_switched_coeffs(
+        &switched_coeffs,
+        log_modulus,
+        ciphertext_modulus,
+    );
+
+    let ms_ct_params = ModulusSwitchedLweCiphertextParameters {
+        lwe_dimension,
+        log_modulus,
+        uncompressed_ciphertext_modulus: ciphertext_modulus,
+    };
+
+    assert!(ms_ct.is_conformant(&ms_ct_params));
+
+    let mut truncated_ct = ms_ct.clone();
+    truncated_ct.get_mut_packed_coeffs().pop();
+    assert!(!truncated_ct.is_conformant(&ms_ct_params));
+
+    // A larger dimension would make the unpacking read past the packed coefficients
+    let mut wrong_dimension_ct = ms_ct.clone();
+    *wrong_dimension_ct.get_mut_lwe_dimension() = LweDimension(lwe_dimension.0 + 100);
+    assert!(!wrong_dimension_ct.is_conformant(&ms_ct_params));
+    assert!(
+        !wrong_dimension_ct.is_conformant(&ModulusSwitchedLweCiphertextParameters {
+            lwe_dimension: LweDimension(lwe_dimension.0 + 100),
+            ..ms_ct_params
+        })
+    );
+
+    for invalid_log_modulus in [CiphertextModulusLog(0), CiphertextModulusLog(65)] {
+        let mut wrong_log_modulus_ct = ms_ct.clone();
+        *wrong_log_modulus_ct.get_mut_log_modulus() = invalid_log_modulus;
+        assert!(
+            !wrong_log_modulus_ct.is_conformant(&ModulusSwitchedLweCiphertextParameters {
+                log_modulus: invalid_log_modulus,
+                ..ms_ct_params
+            })
+        );
+    }
+}
@@ -174,6 +174,110 @@ where
     }
 }
 
+fn lwe_list_encrypt_multi_bit_pbs_decrypt_custom_mod<Scalar>(params: MultiBitTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus
+        + Sync
+        + Send
+        + CastFrom<usize>
+        + CastInto<usize>
+        + Serialize
+        + DeserializeOwned,
+    MultiBitTestParams<Scalar>: KeyCacheAccess<Keys = MultiBitBootstrapKeys<Scalar>>,
+{
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let thread_count = params.thread_count;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x;
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let mut keys_gen = |params| generate_keys(params, &mut rsc);
+
+    let keys = gen_keys_or_get_from_cache_if_enabled(params, &mut keys_gen);
+    let (input_lwe_secret_key, output_lwe_secret_key, fbsk) =
+        (keys.small_lwe_sk, keys.big_lwe_sk, keys.fbsk);
+
+    // All messages twice and an extra one so that chunks processed by threads are uneven
+    let msgs: Vec<Scalar> = (0..2 * CastInto::<usize>::cast_into(msg_modulus) + 1)
+        .map(|idx| Scalar::cast_from(idx) % msg_modulus)
+        .collect();
+
+    for _ in 0..NB_TESTS_LIGHT {
+        let plaintext_list =
+            PlaintextList::from_container(msgs.iter().map(|&x| x * delta).collect::<Vec<_>>());
+
+        let mut lwe_list_in = LweCiphertextList::new(
+            Scalar::ZERO,
+            input_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(msgs.len()),
+            ciphertext_modulus,
+        );
+
+        encrypt_lwe_ciphertext_list(
+            &input_lwe_secret_key,
+            &mut lwe_list_in,
+            &plaintext_list,
+            lwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut lwe_list_out = LweCiphertextList::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(msgs.len()),
+            ciphertext_modulus,
+        );
+
+        multi_bit_programmable_bootstrap_lwe_ciphertext_list(
+            &lwe_list_in,
+            &mut lwe_list_out,
+            &accumulator,
+            &fbsk,
+            thread_count,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &lwe_list_out,
+            ciphertext_modulus
+        ));
+
+        let mut decrypted_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(msgs.len()));
+
+        decrypt_lwe_ciphertext_list(
+            &output_lwe_secret_key,
+            &lwe_list_out,
+            &mut decrypted_plaintext_list,
+        );
+
+        let decoded: Vec<_> = decrypted_plaintext_list
+            .iter()
+            .map(|x| round_decode(*x.0, delta) % msg_modulus)
+            .collect();
+        let expected: Vec<_> = msgs.iter().map(|&x| f(x)).collect();
+
+        assert_eq!(decoded, expected);
+    }
+}
+
 fn lwe_encrypt_multi_bit_deterministic_pbs_decrypt_custom_mod<Scalar>(
     params: MultiBitTestParams<Scalar>,
 ) where
@@ -533,6 +637,122 @@ fn std_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_custom_mod<Scalar>(
     }
 }
 
+fn lwe_encrypt_multi_bit_pbs_f128_decrypt_custom_mod<Scalar>(params: MultiBitTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus
+        + Sync
+        + Send
+        + CastFrom<usize>
+        + CastInto<usize>
+        + Serialize
+        + DeserializeOwned,
+    MultiBitTestParams<Scalar>: KeyCacheAccess<Keys = MultiBitBootstrapKeys<Scalar>>,
+{
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let thread_count = params.thread_count;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x;
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    assert!(check_encrypted_content_respects_mod(
+        &accumulator,
+        ciphertext_modulus
+    ));
+
+    let mut keys_gen = |params| generate_keys(params, &mut rsc);
+
+    let keys = gen_keys_or_get_from_cache_if_enabled(params, &mut keys_gen);
+    let (input_lwe_secret_key, output_lwe_secret_key, bsk) =
+        (keys.small_lwe_sk, keys.big_lwe_sk, keys.bsk);
+
+    assert!(check_encrypted_content_respects_mod(
+        &*bsk,
+        ciphertext_modulus
+    ));
+
+    let mut fbsk = Fourier128LweMultiBitBootstrapKey::new(
+        params.input_lwe_dimension,
+        glwe_dimension.to_glwe_size(),
+        polynomial_size,
+        params.decomp_base_log,
+        params.decomp_level_count,
+        params.grouping_factor,
+    );
+
+    convert_standard_lwe_multi_bit_bootstrap_key_to_fourier_128(&bsk, &mut fbsk);
+
+    drop(bsk);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &lwe_ciphertext_in,
+                ciphertext_modulus
+            ));
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            multi_bit_programmable_bootstrap_f128_lwe_ciphertext(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct,
+                &accumulator,
+                &fbsk,
+                thread_count,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_ct,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
 #[test]
 pub fn test_lwe_encrypt_multi_bit_pbs_decrypt_factor_2_thread_5_native_mod() {
     lwe_encrypt_multi_bit_pbs_decrypt_custom_mod::<u64>(MULTI_BIT_2_2_2_PARAMS);
@@ -553,6 +773,16 @@ pub fn test_lwe_encrypt_multi_bit_pbs_decrypt_factor_3_thread_12_custom_mod() {
     lwe_encrypt_multi_bit_pbs_decrypt_custom_mod::<u64>(MULTI_BIT_2_2_3_CUSTOM_MOD_PARAMS);
 }
 
+#[test]
+pub fn test_lwe_list_encrypt_multi_bit_pbs_decrypt_factor_2_thread_5_native_mod() {
+    lwe_list_encrypt_multi_bit_pbs_decrypt_custom_mod::<u64>(MULTI_BIT_2_2_2_PARAMS);
+}
+
+#[test]
+pub fn test_lwe_list_encrypt_multi_bit_pbs_decrypt_factor_3_thread_12_custom_mod() {
+    lwe_list_encrypt_multi_bit_pbs_decrypt_custom_mod::<u64>(MULTI_BIT_2_2_3_CUSTOM_MOD_PARAMS);
+}
+
 #[test]
 pub fn test_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_factor_2_thread_5_native_mod() {
     lwe_encrypt_multi_bit_deterministic_pbs_decrypt_custom_mod::<u64>(MULTI_BIT_2_2_2_PARAMS);
@@ -620,3 +850,27 @@ pub fn std_test_lwe_encrypt_multi_bit_deterministic_pbs_decrypt_factor_3_thread_
         MULTI_BIT_2_2_3_CUSTOM_MOD_PARAMS,
     );
 }
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_pbs_f128_decrypt_factor_2_thread_5_native_mod() {
+    lwe_encrypt_multi_bit_pbs_f128_decrypt_custom_mod::<u128>(MULTI_BIT_2_2_2_U128_PARAMS);
+}
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_pbs_f128_decrypt_factor_3_thread_12_native_mod() {
+    lwe_encrypt_multi_bit_pbs_f128_decrypt_custom_mod::<u128>(MULTI_BIT_2_2_3_U128_PARAMS);
+}
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_pbs_f128_decrypt_factor_2_thread_5_custom_mod() {
+    lwe_encrypt_multi_bit_pbs_f128_decrypt_custom_mod::<u128>(
+        MULTI_BIT_2_2_2_U128_CUSTOM_MOD_PARAMS,
+    );
+}
+
+#[test]
+pub fn test_lwe_encrypt_multi_bit_pbs_f128_decrypt_factor_3_thread_12_custom_mod() {
+    lwe_encrypt_multi_bit_pbs_f128_decrypt_custom_mod::<u128>(
+        MULTI_BIT_2_2_3_U128_CUSTOM_MOD_PARAMS,
+    );
+}
@@ -165,6 +165,415 @@ where
 
 create_parametrized_test!(lwe_encrypt_pbs_decrypt_custom_mod);
 
+fn lwe_list_encrypt_pbs_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus
+        + Sync
+        + Send
+        + CastFrom<usize>
+        + CastInto<usize>
+        + Serialize
+        + DeserializeOwned,
+    ClassicTestParams<Scalar>: KeyCacheAccess<Keys = ClassicBootstrapKeys<Scalar>>,
+{
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x;
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let mut keys_gen = |params| generate_keys(params, &mut rsc);
+    let keys = gen_keys_or_get_from_cache_if_enabled(params, &mut keys_gen);
+    let (input_lwe_secret_key, output_lwe_secret_key, fbsk) =
+        (keys.small_lwe_sk, keys.big_lwe_sk, keys.fbsk);
+
+    // All messages twice and an extra one to avoid a count aligned on anything
+    let msgs: Vec<Scalar> = (0..2 * CastInto::<usize>::cast_into(msg_modulus) + 1)
+        .map(|idx| Scalar::cast_from(idx) % msg_modulus)
+        .collect();
+
+    for _ in 0..NB_TESTS {
+        let plaintext_list =
+            PlaintextList::from_container(msgs.iter().map(|&x| x * delta).collect::<Vec<_>>());
+
+        let mut lwe_list_in = LweCiphertextList::new(
+            Scalar::ZERO,
+            input_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(msgs.len()),
+            ciphertext_modulus,
+        );
+
+        encrypt_lwe_ciphertext_list(
+            &input_lwe_secret_key,
+            &mut lwe_list_in,
+            &plaintext_list,
+            lwe_modular_std_dev,
+            &mut rsc.encryption_random_generator,
+        );
+
+        let mut lwe_list_out = LweCiphertextList::new(
+            Scalar::ZERO,
+            output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(msgs.len()),
+            ciphertext_modulus,
+        );
+
+        programmable_bootstrap_lwe_ciphertext_list(
+            &lwe_list_in,
+            &mut lwe_list_out,
+            &accumulator,
+            &fbsk,
+        );
+
+        assert!(check_encrypted_content_respects_mod(
+            &lwe_list_out,
+            ciphertext_modulus
+        ));
+
+        let mut decrypted_plaintext_list =
+            PlaintextList::new(Scalar::ZERO, PlaintextCount(msgs.len()));
+
+        decrypt_lwe_ciphertext_list(
+            &output_lwe_secret_key,
+            &lwe_list_out,
+            &mut decrypted_plaintext_list,
+        );
+
+        let decoded: Vec<_> = decrypted_plaintext_list
+            .iter()
+            .map(|x| round_decode(*x.0, delta) % msg_modulus)
+            .collect();
+        let expected: Vec<_> = msgs.iter().map(|&x| f(x)).collect();
+
+        assert_eq!(decoded, expected);
+    }
+}
+
+create_parametrized_test!(lwe_list_encrypt_pbs_decrypt_custom_mod);
+
+fn lwe_encrypt_many_lut_pbs_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus
+        + Sync
+        + Send
+        + CastFrom<usize>
+        + CastInto<usize>
+        + Serialize
+        + DeserializeOwned,
+    ClassicTestParams<Scalar>: KeyCacheAccess<Keys = ClassicBootstrapKeys<Scalar>>,
+{
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let mut rsc = TestResources::new();
+
+    // Two look-up tables, each input value leads to two outputs
+    let lut_count_log = LutCountLog(1);
+    let input_msg_modulus = msg_modulus >> lut_count_log.0;
+    let f0 = |x: Scalar| x;
+    let f1 = |x: Scalar| x + Scalar::ONE;
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = input_msg_modulus;
+
+    // The first half of the accumulator holds f0, the second half f1
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        |x: Scalar| {
+            if x < input_msg_modulus {
+                f0(x)
+            } else {
+                f1(x - input_msg_modulus)
+            }
+        },
+    );
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let mut keys_gen = |params| generate_keys(params, &mut rsc);
+        let keys = gen_keys_or_get_from_cache_if_enabled(params, &mut keys_gen);
+        let (input_lwe_secret_key, output_lwe_secret_key, fbsk) =
+            (keys.small_lwe_sk, keys.big_lwe_sk, keys.fbsk);
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut out_pbs_list = LweCiphertextList::new(
+                Scalar::ZERO,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                LweCiphertextCount(2),
+                ciphertext_modulus,
+            );
+
+            programmable_bootstrap_lwe_ciphertext_many_lut(
+                &lwe_ciphertext_in,
+                &mut out_pbs_list,
+                &accumulator,
+                &fbsk,
+                lut_count_log,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_list,
+                ciphertext_modulus
+            ));
+
+            let decoded: Vec<_> = out_pbs_list
+                .iter()
+                .map(|ct| {
+                    let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &ct);
+                    round_decode(decrypted.0, delta) % msg_modulus
+                })
+                .collect();
+
+            assert_eq!(decoded, vec![f0(msg), f1(msg)]);
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+create_parametrized_test!(lwe_encrypt_many_lut_pbs_decrypt_custom_mod);
+
+fn lwe_encrypt_expanded_key_pbs_decrypt<Scalar>(
+    params: ClassicTestParams<Scalar>,
+    input_lwe_secret_key: &LweSecretKeyOwned<Scalar>,
+    expanded_input_lwe_secret_key: &LweSecretKeyOwned<Scalar>,
+    output_glwe_secret_key: &GlweSecretKeyOwned<Scalar>,
+    rsc: &mut TestResources,
+    pbs: impl Fn(
+        &LweCiphertextOwned<Scalar>,
+        &mut LweCiphertextOwned<Scalar>,
+        &GlweCiphertextOwned<Scalar>,
+        &FourierLweBootstrapKeyOwned,
+    ),
+) where
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+{
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let f = |x: Scalar| x;
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let output_lwe_secret_key = output_glwe_secret_key.clone().into_lwe_secret_key();
+
+    let bsk = par_allocate_and_generate_new_lwe_bootstrap_key(
+        expanded_input_lwe_secret_key,
+        output_glwe_secret_key,
+        params.pbs_base_log,
+        params.pbs_level,
+        params.glwe_modular_std_dev,
+        ciphertext_modulus,
+        &mut rsc.encryption_random_generator,
+    );
+
+    let mut fbsk = FourierLweBootstrapKey::new(
+        bsk.input_lwe_dimension(),
+        bsk.glwe_size(),
+        bsk.polynomial_size(),
+        bsk.decomposition_base_log(),
+        bsk.decomposition_level_count(),
+    );
+
+    par_convert_standard_lwe_bootstrap_key_to_fourier(&bsk, &mut fbsk);
+
+    drop(bsk);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut expanded_lwe_ciphertext_in = LweCiphertext::new(
+                Scalar::ZERO,
+                expanded_input_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            convert_lwe_ciphertext_to_expanded_binary_key(
+                &lwe_ciphertext_in,
+                &mut expanded_lwe_ciphertext_in,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &expanded_lwe_ciphertext_in,
+                ciphertext_modulus
+            ));
+
+            // The expanded ciphertext must decrypt to the same value under the expanded key
+            assert_eq!(
+                decrypt_lwe_ciphertext(input_lwe_secret_key, &lwe_ciphertext_in),
+                decrypt_lwe_ciphertext(expanded_input_lwe_secret_key, &expanded_lwe_ciphertext_in)
+            );
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            pbs(&lwe_ciphertext_in, &mut out_pbs_ct, &accumulator, &fbsk);
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_ct,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+fn lwe_encrypt_ternary_pbs_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize>,
+{
+    let mut rsc = TestResources::new();
+
+    // Keys are not taken from the key cache as they don't have the same distribution as the ones
+    // generated for the binary PBS with the same parameters
+    let input_lwe_secret_key = allocate_and_generate_new_ternary_lwe_secret_key(
+        params.lwe_dimension,
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_ternary_glwe_secret_key(
+        params.glwe_dimension,
+        params.polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let expanded_input_lwe_secret_key =
+        allocate_and_convert_ternary_lwe_secret_key_to_expanded_binary(&input_lwe_secret_key);
+
+    assert_eq!(
+        expanded_input_lwe_secret_key.lwe_dimension().0,
+        2 * params.lwe_dimension.0
+    );
+
+    lwe_encrypt_expanded_key_pbs_decrypt(
+        params,
+        &input_lwe_secret_key,
+        &expanded_input_lwe_secret_key,
+        &output_glwe_secret_key,
+        &mut rsc,
+        |input, output, accumulator, fbsk| {
+            programmable_bootstrap_ternary_lwe_ciphertext(input, output, accumulator, fbsk);
+        },
+    );
+}
+
+create_parametrized_test!(lwe_encrypt_ternary_pbs_decrypt_custom_mod);
+
+fn lwe_encrypt_gaussian_key_pbs_decrypt_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus + Sync + Send + CastFrom<usize> + CastInto<usize> + CastFrom<i64>,
+{
+    let mut rsc = TestResources::new();
+
+    let input_lwe_secret_key = allocate_and_generate_new_gaussian_lwe_secret_key(
+        params.lwe_dimension,
+        StandardDev(1.0),
+        &mut rsc.secret_random_generator,
+    );
+    let output_glwe_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+        params.glwe_dimension,
+        params.polynomial_size,
+        &mut rsc.secret_random_generator,
+    );
+
+    let expanded_input_lwe_secret_key =
+        allocate_and_convert_integer_lwe_secret_key_to_expanded_binary(&input_lwe_secret_key);
+
+    // A unit standard deviation yields coefficients needing more than one bit
+    assert!(expanded_input_lwe_secret_key.lwe_dimension().0 > 2 * params.lwe_dimension.0);
+
+    lwe_encrypt_expanded_key_pbs_decrypt(
+        params,
+        &input_lwe_secret_key,
+        &expanded_input_lwe_secret_key,
+        &output_glwe_secret_key,
+        &mut rsc,
+        |input, output, accumulator, fbsk| {
+            programmable_bootstrap_integer_key_lwe_ciphertext(input, output, accumulator, fbsk);
+        },
+    );
+}
+
+create_parametrized_test!(lwe_encrypt_gaussian_key_pbs_decrypt_custom_mod);
+
 // DISCLAIMER: all parameters here are not guaranteed to be secure or yield correct computations
 pub const TEST_PARAMS_4_BITS_NATIVE_U128: ClassicTestParams<u128> = ClassicTestParams {
     lwe_dimension: LweDimension(742),
@@ -324,3 +733,124 @@ fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_4_bits_native_u128() {
 fn lwe_encrypt_pbs_f128_decrypt_custom_mod_test_params_3_bits_127_u128() {
     lwe_encrypt_pbs_f128_decrypt_custom_mod(TEST_PARAMS_3_BITS_127_U128);
 }
+
+fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod(params: ClassicTestParams<u64>) {
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = 1u64 << message_modulus_log.0;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+    let decomp_base_log = params.pbs_base_log;
+    let decomp_level_count = params.pbs_level;
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: u64| x;
+
+    let delta: u64 = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    assert!(check_encrypted_content_respects_mod(
+        &accumulator,
+        ciphertext_modulus
+    ));
+
+    let ntt = Ntt64::new(polynomial_size, ciphertext_modulus);
+    let ntt = ntt.as_view();
+
+    let mut buffers = ComputationBuffers::new();
+    buffers.resize(
+        programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized_requirement(
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+        )
+        .unwrap()
+        .unaligned_bytes_required(),
+    );
+
+    while msg != 0 {
+        msg = msg.wrapping_sub(1);
+
+        let keys = generate_keys(params, &mut rsc);
+        let (input_lwe_secret_key, output_lwe_secret_key, bsk) =
+            (keys.small_lwe_sk, keys.big_lwe_sk, keys.bsk);
+
+        let mut nbsk = NttLweBootstrapKey::new(
+            input_lwe_dimension,
+            glwe_dimension.to_glwe_size(),
+            polynomial_size,
+            decomp_base_log,
+            decomp_level_count,
+            ciphertext_modulus,
+        );
+
+        par_convert_standard_lwe_bootstrap_key_to_ntt64(&bsk, &mut nbsk);
+
+        drop(bsk);
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_secret_key,
+                plaintext,
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &lwe_ciphertext_in,
+                ciphertext_modulus
+            ));
+
+            let mut out_pbs_ct = LweCiphertext::new(
+                0u64,
+                output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            programmable_bootstrap_ntt64_lwe_ciphertext_mem_optimized(
+                &lwe_ciphertext_in,
+                &mut out_pbs_ct,
+                &accumulator,
+                &nbsk,
+                ntt,
+                buffers.stack(),
+            );
+
+            assert!(check_encrypted_content_respects_mod(
+                &out_pbs_ct,
+                ciphertext_modulus
+            ));
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(decoded, f(msg));
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+#[test]
+fn lwe_encrypt_pbs_ntt64_decrypt_custom_mod_test_params_3_bits_solinas_u64() {
+    lwe_encrypt_pbs_ntt64_decrypt_custom_mod(TEST_PARAMS_3_BITS_SOLINAS_U64);
+}
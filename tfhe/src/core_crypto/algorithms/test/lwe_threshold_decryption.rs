@@ -0,0 +1,309 @@
+use super::*;
+
+#[cfg(not(feature = "__coverage"))]
+const NB_TESTS: usize = 10;
+#[cfg(feature = "__coverage")]
+const NB_TESTS: usize = 1;
+
+const STATISTICAL_SECURITY: StatisticalSecurityParameter = StatisticalSecurityParameter(40);
+
+fn lwe_encrypt_threshold_decrypt<Scalar: UnsignedTorus>(
+    lwe_dimension: LweDimension,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    message_modulus_log: CiphertextModulusLog,
+    lwe_noise_std_dev: StandardDev,
+) {
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let sharings = [
+        (ShareThreshold(1), ShareCount(1)),
+        (ShareThreshold(1), ShareCount(3)),
+        (ShareThreshold(2), ShareCount(3)),
+        (ShareThreshold(3), ShareCount(5)),
+        (ShareThreshold(5), ShareCount(5)),
+    ];
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+                lwe_dimension,
+                &mut rsc.secret_random_generator,
+            );
+
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &lwe_sk,
+                Plaintext(msg * delta),
+                lwe_noise_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            for (threshold, share_count) in sharings {
+                let key_shares = generate_lwe_secret_key_shares(
+                    &lwe_sk,
+                    threshold,
+                    share_count,
+                    &mut rsc.secret_random_generator,
+                );
+
+                assert_eq!(key_shares.len(), share_count.0);
+
+                // Use the last shares to make sure the decryption does not depend on the share
+                // indices being contiguous from 1, then all the shares at once
+                for participant_count in [threshold.0, share_count.0] {
+                    let participating_shares = &key_shares[share_count.0 - participant_count..];
+                    let participants: Vec<_> = participating_shares
+                        .iter()
+                        .map(LweSecretKeyShare::share_index)
+                        .collect();
+
+                    let partial_decryptions: Vec<_> = participating_shares
+                        .iter()
+                        .map(|key_share| {
+                            partial_decrypt_lwe_ciphertext(
+                                key_share,
+                                &ct,
+                                &participants,
+                                lwe_noise_std_dev,
+                                STATISTICAL_SECURITY,
+                                &mut rsc.encryption_random_generator,
+                            )
+                        })
+                        .collect();
+
+                    let decrypted = combine_lwe_partial_decryptions(&ct, &partial_decryptions);
+
+                    let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+                    assert_eq!(msg, decoded);
+                }
+            }
+        }
+
+        // In coverage, we break after one while loop iteration, changing message values does not
+        // yield higher coverage
+        #[cfg(feature = "__coverage")]
+        break;
+    }
+}
+
+fn lwe_encrypt_threshold_decrypt_custom_mod<Scalar: UnsignedTorus>(
+    params: ClassicTestParams<Scalar>,
+) {
+    // The flooding noise is 2^40 times larger than the ciphertext noise, which must be much smaller
+    // than in the test parameters for the decryption to be correct
+    let lwe_noise_std_dev = StandardDev(2.0f64.powi(-(Scalar::BITS as i32 - 8)));
+
+    lwe_encrypt_threshold_decrypt(
+        params.lwe_dimension,
+        params.ciphertext_modulus,
+        params.message_modulus_log,
+        lwe_noise_std_dev,
+    );
+}
+
+create_parametrized_test!(lwe_encrypt_threshold_decrypt_custom_mod);
+
+#[test]
+fn lwe_encrypt_threshold_decrypt_u128() {
+    lwe_encrypt_threshold_decrypt::<u128>(
+        LweDimension(742),
+        CiphertextModulus::new_native(),
+        CiphertextModulusLog(4),
+        StandardDev(2.0f64.powi(-100)),
+    );
+}
+
+#[test]
+fn lwe_secret_key_shares_replicate_key_parts() {
+    let mut rsc = TestResources::new();
+
+    let threshold = ShareThreshold(3);
+    let share_count = ShareCount(5);
+
+    let lwe_sk: LweSecretKeyOwned<u64> = allocate_and_generate_new_binary_lwe_secret_key(
+        LweDimension(16),
+        &mut rsc.secret_random_generator,
+    );
+
+    let key_shares = generate_lwe_secret_key_shares(
+        &lwe_sk,
+        threshold,
+        share_count,
+        &mut rsc.secret_random_generator,
+    );
+
+    // Each share holds the C(4, 2) key parts it is not excluded from, and a key part is the same
+    // in all the shares holding it
+    for key_share in &key_shares {
+        assert_eq!(key_share.excluded_sets().len(), 6);
+        for (excluded_set, key_part) in key_share.key_part_iter() {
+            assert!(!excluded_set.contains(&key_share.share_index()));
+            for other_key_share in &key_shares {
+                if let Some((_, other_key_part)) = other_key_share
+                    .key_part_iter()
+                    .find(|(other_excluded_set, _)| *other_excluded_set == excluded_set)
+                {
+                    assert_eq!(key_part, other_key_part);
+                }
+            }
+        }
+    }
+
+    // The key parts sum up to the secret key
+    let mut key_part_sum = vec![0u64; lwe_sk.lwe_dimension().0];
+    let mut summed_excluded_sets = vec![];
+    for key_share in &key_shares {
+        for (excluded_set, key_part) in key_share.key_part_iter() {
+            if !summed_excluded_sets.contains(&excluded_set) {
+                for (sum, &coefficient) in key_part_sum.iter_mut().zip(key_part.as_ref()) {
+                    *sum = sum.wrapping_add(coefficient);
+                }
+                summed_excluded_sets.push(excluded_set);
+            }
+        }
+    }
+    assert_eq!(summed_excluded_sets.len(), 10);
+    assert_eq!(key_part_sum, lwe_sk.as_ref());
+}
+
+#[test]
+#[should_panic(expected = "at least")]
+fn lwe_threshold_decrypt_not_enough_participants() {
+    let mut rsc = TestResources::new();
+
+    let lwe_sk: LweSecretKeyOwned<u64> = allocate_and_generate_new_binary_lwe_secret_key(
+        LweDimension(16),
+        &mut rsc.secret_random_generator,
+    );
+
+    let key_shares = generate_lwe_secret_key_shares(
+        &lwe_sk,
+        ShareThreshold(2),
+        ShareCount(3),
+        &mut rsc.secret_random_generator,
+    );
+
+    let ct = allocate_and_trivially_encrypt_new_lwe_ciphertext(
+        LweSize(17),
+        Plaintext(0u64),
+        CiphertextModulus::new_native(),
+    );
+
+    let _ = partial_decrypt_lwe_ciphertext(
+        &key_shares[0],
+        &ct,
+        &[key_shares[0].share_index()],
+        StandardDev(2.0f64.powi(-56)),
+        STATISTICAL_SECURITY,
+        &mut rsc.encryption_random_generator,
+    );
+}
+
+fn threshold_decryption_setup(
+    rsc: &mut TestResources,
+) -> (LweCiphertextOwned<u128>, Vec<LweSecretKeyShareOwned<u128>>) {
+    let lwe_noise_std_dev = StandardDev(2.0f64.powi(-100));
+
+    let lwe_sk: LweSecretKeyOwned<u128> = allocate_and_generate_new_binary_lwe_secret_key(
+        LweDimension(16),
+        &mut rsc.secret_random_generator,
+    );
+
+    let key_shares = generate_lwe_secret_key_shares(
+        &lwe_sk,
+        ShareThreshold(2),
+        ShareCount(3),
+        &mut rsc.secret_random_generator,
+    );
+
+    let ct = allocate_and_encrypt_new_lwe_ciphertext(
+        &lwe_sk,
+        Plaintext(3u128 << 124),
+        lwe_noise_std_dev,
+        CiphertextModulus::new_native(),
+        &mut rsc.encryption_random_generator,
+    );
+
+    (ct, key_shares)
+}
+
+fn threshold_partial_decrypt(
+    rsc: &mut TestResources,
+    key_share: &LweSecretKeyShareOwned<u128>,
+    ct: &LweCiphertextOwned<u128>,
+    participants: &[ShareIndex],
+) -> LwePartialDecryption<u128> {
+    partial_decrypt_lwe_ciphertext(
+        key_share,
+        ct,
+        participants,
+        StandardDev(2.0f64.powi(-100)),
+        STATISTICAL_SECURITY,
+        &mut rsc.encryption_random_generator,
+    )
+}
+
+#[test]
+#[should_panic(expected = "different sets of participants")]
+fn lwe_threshold_decrypt_mismatched_participant_sets() {
+    let mut rsc = TestResources::new();
+    let (ct, key_shares) = threshold_decryption_setup(&mut rsc);
+
+    let partial_decryptions = [
+        threshold_partial_decrypt(
+            &mut rsc,
+            &key_shares[0],
+            &ct,
+            &[ShareIndex(1), ShareIndex(2)],
+        ),
+        threshold_partial_decrypt(
+            &mut rsc,
+            &key_shares[1],
+            &ct,
+            &[ShareIndex(2), ShareIndex(3)],
+        ),
+    ];
+
+    let _ = combine_lwe_partial_decryptions(&ct, &partial_decryptions);
+}
+
+#[test]
+#[should_panic(expected = "expected exactly the participants")]
+fn lwe_threshold_decrypt_missing_participant() {
+    let mut rsc = TestResources::new();
+    let (ct, key_shares) = threshold_decryption_setup(&mut rsc);
+
+    let participants = [ShareIndex(1), ShareIndex(2), ShareIndex(3)];
+    let partial_decryptions = [
+        threshold_partial_decrypt(&mut rsc, &key_shares[0], &ct, &participants),
+        threshold_partial_decrypt(&mut rsc, &key_shares[1], &ct, &participants),
+    ];
+
+    let _ = combine_lwe_partial_decryptions(&ct, &partial_decryptions);
+}
+
+#[test]
+#[should_panic(expected = "another ciphertext")]
+fn lwe_threshold_decrypt_mismatched_ciphertext() {
+    let mut rsc = TestResources::new();
+    let (ct, key_shares) = threshold_decryption_setup(&mut rsc);
+
+    let participants = [ShareIndex(1), ShareIndex(2)];
+    let partial_decryptions = [
+        threshold_partial_decrypt(&mut rsc, &key_shares[0], &ct, &participants),
+        threshold_partial_decrypt(&mut rsc, &key_shares[1], &ct, &participants),
+    ];
+
+    let mut other_ct = ct.clone();
+    *other_ct.get_mut_body().data = other_ct.get_body().data.wrapping_add(1);
+
+    let _ = combine_lwe_partial_decryptions(&other_ct, &partial_decryptions);
+}
@@ -9,21 +9,28 @@ use paste::paste;
 use std::fmt::Debug;
 
 mod ggsw_encryption;
+mod glwe_automorphism;
 mod glwe_encryption;
+mod glwe_keyswitch;
+mod glwe_keyswitch_key_generation;
 mod glwe_linear_algebra;
 mod glwe_sample_extraction;
+mod glwe_tensor_product;
 mod lwe_bootstrap_key_generation;
 mod lwe_compact_public_key_generation;
+mod lwe_distributed_key_generation;
 mod lwe_encryption;
 mod lwe_keyswitch;
 mod lwe_keyswitch_key_generation;
 mod lwe_linear_algebra;
+mod lwe_modulus_switch;
 mod lwe_multi_bit_bootstrap_key_generation;
 pub(crate) mod lwe_multi_bit_programmable_bootstrapping;
 mod lwe_packing_keyswitch;
 mod lwe_packing_keyswitch_key_generation;
 mod lwe_private_functional_packing_keyswitch;
 pub(crate) mod lwe_programmable_bootstrapping;
+mod lwe_threshold_decryption;
 mod noise_distribution;
 
 pub struct TestResources {
@@ -204,6 +211,62 @@ pub const MULTI_BIT_2_2_3_CUSTOM_MOD_PARAMS: MultiBitTestParams<u64> = MultiBitT
     thread_count: ThreadCount(12),
 };
 
+pub const MULTI_BIT_2_2_2_U128_PARAMS: MultiBitTestParams<u128> = MultiBitTestParams {
+    input_lwe_dimension: LweDimension(818),
+    lwe_modular_std_dev: StandardDev(4.9571233e-12),
+    decomp_base_log: DecompositionBaseLog(22),
+    decomp_level_count: DecompositionLevelCount(1),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    glwe_modular_std_dev: StandardDev(9.9409771e-32),
+    message_modulus_log: CiphertextModulusLog(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    grouping_factor: LweBskGroupingFactor(2),
+    thread_count: ThreadCount(5),
+};
+
+pub const MULTI_BIT_2_2_2_U128_CUSTOM_MOD_PARAMS: MultiBitTestParams<u128> = MultiBitTestParams {
+    input_lwe_dimension: LweDimension(818),
+    lwe_modular_std_dev: StandardDev(4.9571233e-12),
+    decomp_base_log: DecompositionBaseLog(22),
+    decomp_level_count: DecompositionLevelCount(1),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    glwe_modular_std_dev: StandardDev(9.9409771e-32),
+    message_modulus_log: CiphertextModulusLog(3),
+    ciphertext_modulus: CiphertextModulus::new(1 << 127),
+    grouping_factor: LweBskGroupingFactor(2),
+    thread_count: ThreadCount(5),
+};
+
+pub const MULTI_BIT_2_2_3_U128_PARAMS: MultiBitTestParams<u128> = MultiBitTestParams {
+    input_lwe_dimension: LweDimension(888),
+    lwe_modular_std_dev: StandardDev(3.7516012e-13),
+    decomp_base_log: DecompositionBaseLog(21),
+    decomp_level_count: DecompositionLevelCount(1),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    glwe_modular_std_dev: StandardDev(9.9409771e-32),
+    message_modulus_log: CiphertextModulusLog(4),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+    grouping_factor: LweBskGroupingFactor(3),
+    thread_count: ThreadCount(12),
+};
+
+pub const MULTI_BIT_2_2_3_U128_CUSTOM_MOD_PARAMS: MultiBitTestParams<u128> = MultiBitTestParams {
+    input_lwe_dimension: LweDimension(888),
+    lwe_modular_std_dev: StandardDev(3.7516012e-13),
+    decomp_base_log: DecompositionBaseLog(21),
+    decomp_level_count: DecompositionLevelCount(1),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(2048),
+    glwe_modular_std_dev: StandardDev(9.9409771e-32),
+    message_modulus_log: CiphertextModulusLog(3),
+    ciphertext_modulus: CiphertextModulus::new(1 << 127),
+    grouping_factor: LweBskGroupingFactor(3),
+    thread_count: ThreadCount(12),
+};
+
 // DISCLAIMER: example parameters tailored for FFT implementation tests. There are not guaranteed
 // to be secure or yield correct computations.
 // Define the parameters for a 4 bits message able to hold the doubled 2 bits message.
@@ -0,0 +1,113 @@
+use super::*;
+use crate::core_crypto::commons::noise_formulas::keyswitch_lwe_ciphertext_output_variance;
+use crate::core_crypto::commons::test_tools::{torus_modular_diff, variance};
+
+// This is 1 / 16 which is exactly representable in an f64 (even an f32)
+// 1 / 32 is too strict and fails the tests
+const RELATIVE_TOLERANCE: f64 = 0.0625;
+
+const NB_TESTS: usize = 1000;
+
+fn lwe_encrypt_ks_decrypt_noise_distribution_custom_mod<Scalar: UnsignedTorus + CastInto<usize>>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let ks_decomp_base_log = params.ks_base_log;
+    let ks_decomp_level_count = params.ks_level;
+    let big_lwe_dimension = params
+        .glwe_dimension
+        .to_equivalent_lwe_dimension(params.polynomial_size);
+
+    // The input ciphertexts are encrypted with the small GLWE noise, as they would be at the
+    // output of a bootstrap
+    let expected_variance = keyswitch_lwe_ciphertext_output_variance(
+        Variance(glwe_modular_std_dev.get_variance()),
+        big_lwe_dimension,
+        lwe_modular_std_dev,
+        ks_decomp_base_log,
+        ks_decomp_level_count,
+        ciphertext_modulus,
+    );
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+
+    let num_samples = NB_TESTS * <Scalar as CastInto<usize>>::cast_into(msg);
+    let mut noise_samples = Vec::with_capacity(num_samples);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+
+        let input_lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+            big_lwe_dimension,
+            &mut rsc.secret_random_generator,
+        );
+        let output_lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+            lwe_dimension,
+            &mut rsc.secret_random_generator,
+        );
+
+        let ksk = allocate_and_generate_new_lwe_keyswitch_key(
+            &input_lwe_sk,
+            &output_lwe_sk,
+            ks_decomp_base_log,
+            ks_decomp_level_count,
+            lwe_modular_std_dev,
+            ciphertext_modulus,
+            &mut rsc.encryption_random_generator,
+        );
+
+        for _ in 0..NB_TESTS {
+            let plaintext = Plaintext(msg * delta);
+
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &input_lwe_sk,
+                plaintext,
+                glwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut output_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                lwe_dimension.to_lwe_size(),
+                ciphertext_modulus,
+            );
+
+            keyswitch_lwe_ciphertext(&ksk, &ct, &mut output_ct);
+
+            let decrypted = decrypt_lwe_ciphertext(&output_lwe_sk, &output_ct);
+
+            let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+            assert_eq!(msg, decoded);
+
+            let torus_diff = torus_modular_diff(plaintext.0, decrypted.0, ciphertext_modulus);
+            noise_samples.push(torus_diff);
+        }
+    }
+
+    let measured_variance = variance(&noise_samples);
+    let var_abs_diff = (expected_variance.0 - measured_variance.0).abs();
+    let tolerance_threshold = RELATIVE_TOLERANCE * expected_variance.0;
+    assert!(
+        var_abs_diff < tolerance_threshold,
+        "Absolute difference for variance: {var_abs_diff}, \
+        tolerance threshold: {tolerance_threshold}, \
+        got variance: {measured_variance:?}, \
+        expected variance: {expected_variance:?}"
+    );
+}
+
+create_parametrized_test!(lwe_encrypt_ks_decrypt_noise_distribution_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64,
+    TEST_PARAMS_3_BITS_63_U64
+});
@@ -0,0 +1,97 @@
+use super::*;
+use crate::core_crypto::commons::noise_formulas::lwe_ciphertext_modulus_switch_additive_variance;
+use crate::core_crypto::commons::test_tools::{torus_modular_diff, variance};
+
+// This is 1 / 16 which is exactly representable in an f64 (even an f32)
+// 1 / 32 is too strict and fails the tests
+const RELATIVE_TOLERANCE: f64 = 0.0625;
+
+const NB_TESTS: usize = 1000;
+
+fn lwe_encrypt_ms_decrypt_noise_distribution_custom_mod<Scalar: UnsignedTorus + CastInto<usize>>(
+    params: ClassicTestParams<Scalar>,
+) {
+    let lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+
+    // Switch to the modulus used by the blind rotation
+    let output_log_modulus = CiphertextModulusLog(params.polynomial_size.log2().0 + 1);
+    let output_ciphertext_modulus =
+        CiphertextModulus::try_new_power_of_2(output_log_modulus.0).unwrap();
+    let output_encoding_with_padding = get_encoding_with_padding(output_ciphertext_modulus);
+
+    let expected_variance = Variance(
+        lwe_modular_std_dev.get_variance()
+            + lwe_ciphertext_modulus_switch_additive_variance(
+                lwe_dimension,
+                output_log_modulus,
+                ciphertext_modulus,
+            )
+            .0,
+    );
+
+    let mut rsc = TestResources::new();
+
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let mut msg = msg_modulus;
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let output_delta: Scalar = output_encoding_with_padding / msg_modulus;
+
+    let num_samples = NB_TESTS * <Scalar as CastInto<usize>>::cast_into(msg);
+    let mut noise_samples = Vec::with_capacity(num_samples);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        for _ in 0..NB_TESTS {
+            let lwe_sk = allocate_and_generate_new_binary_lwe_secret_key(
+                lwe_dimension,
+                &mut rsc.secret_random_generator,
+            );
+
+            let ct = allocate_and_encrypt_new_lwe_ciphertext(
+                &lwe_sk,
+                Plaintext(msg * delta),
+                lwe_modular_std_dev,
+                ciphertext_modulus,
+                &mut rsc.encryption_random_generator,
+            );
+
+            let mut switched_ct = LweCiphertext::new(
+                Scalar::ZERO,
+                lwe_dimension.to_lwe_size(),
+                output_ciphertext_modulus,
+            );
+
+            lwe_ciphertext_modulus_switch(&ct, &mut switched_ct);
+
+            let decrypted = decrypt_lwe_ciphertext(&lwe_sk, &switched_ct);
+
+            let decoded = round_decode(decrypted.0, output_delta) % msg_modulus;
+
+            assert_eq!(msg, decoded);
+
+            let torus_diff =
+                torus_modular_diff(msg * output_delta, decrypted.0, output_ciphertext_modulus);
+            noise_samples.push(torus_diff);
+        }
+    }
+
+    let measured_variance = variance(&noise_samples);
+    let var_abs_diff = (expected_variance.0 - measured_variance.0).abs();
+    let tolerance_threshold = RELATIVE_TOLERANCE * expected_variance.0;
+    assert!(
+        var_abs_diff < tolerance_threshold,
+        "Absolute difference for variance: {var_abs_diff}, \
+        tolerance threshold: {tolerance_threshold}, \
+        got variance: {measured_variance:?}, \
+        expected variance: {expected_variance:?}"
+    );
+}
+
+create_parametrized_test!(lwe_encrypt_ms_decrypt_noise_distribution_custom_mod {
+    TEST_PARAMS_4_BITS_NATIVE_U64,
+    TEST_PARAMS_3_BITS_63_U64
+});
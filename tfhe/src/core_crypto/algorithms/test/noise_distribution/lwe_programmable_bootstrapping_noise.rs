@@ -0,0 +1,160 @@
+use super::*;
+use crate::core_crypto::algorithms::test::lwe_programmable_bootstrapping::generate_keys;
+use crate::core_crypto::commons::noise_formulas::programmable_bootstrap_lwe_ciphertext_output_variance;
+use crate::core_crypto::commons::test_tools::{torus_modular_diff, variance};
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+// This is 1 / 16 which is exactly representable in an f64 (even an f32)
+// 1 / 32 is too strict and fails the tests
+const RELATIVE_TOLERANCE: f64 = 0.0625;
+
+const NB_KEYS: usize = 10;
+
+const NB_TESTS: usize = 100;
+
+// Toy parameters, small enough to run thousands of bootstraps, where the key noise and the
+// decomposition error contribute to the output noise in similar proportions
+const NOISE_TEST_PARAMS_2_BITS_NATIVE_U64: ClassicTestParams<u64> = ClassicTestParams {
+    lwe_dimension: LweDimension(64),
+    glwe_dimension: GlweDimension(1),
+    polynomial_size: PolynomialSize(256),
+    // 2^-20
+    lwe_modular_std_dev: StandardDev(0.00000095367431640625),
+    // 2^-30
+    glwe_modular_std_dev: StandardDev(0.0000000009313225746154785),
+    pbs_base_log: DecompositionBaseLog(8),
+    pbs_level: DecompositionLevelCount(2),
+    ks_level: DecompositionLevelCount(0),
+    ks_base_log: DecompositionBaseLog(0),
+    pfks_level: DecompositionLevelCount(0),
+    pfks_base_log: DecompositionBaseLog(0),
+    pfks_modular_std_dev: StandardDev(0.0),
+    cbs_level: DecompositionLevelCount(0),
+    cbs_base_log: DecompositionBaseLog(0),
+    message_modulus_log: CiphertextModulusLog(2),
+    ciphertext_modulus: CiphertextModulus::new_native(),
+};
+
+fn lwe_encrypt_pbs_decrypt_noise_distribution_custom_mod<Scalar>(params: ClassicTestParams<Scalar>)
+where
+    Scalar: UnsignedTorus
+        + Sync
+        + Send
+        + CastFrom<usize>
+        + CastInto<usize>
+        + Serialize
+        + DeserializeOwned,
+{
+    let input_lwe_dimension = params.lwe_dimension;
+    let lwe_modular_std_dev = params.lwe_modular_std_dev;
+    let glwe_modular_std_dev = params.glwe_modular_std_dev;
+    let ciphertext_modulus = params.ciphertext_modulus;
+    let message_modulus_log = params.message_modulus_log;
+    let msg_modulus = Scalar::ONE.shl(message_modulus_log.0);
+    let encoding_with_padding = get_encoding_with_padding(ciphertext_modulus);
+    let glwe_dimension = params.glwe_dimension;
+    let polynomial_size = params.polynomial_size;
+
+    let expected_variance = programmable_bootstrap_lwe_ciphertext_output_variance(
+        input_lwe_dimension,
+        glwe_dimension,
+        polynomial_size,
+        glwe_modular_std_dev,
+        params.pbs_base_log,
+        params.pbs_level,
+        ciphertext_modulus,
+    );
+
+    let mut rsc = TestResources::new();
+
+    let f = |x: Scalar| x;
+
+    let delta: Scalar = encoding_with_padding / msg_modulus;
+    let mut msg = msg_modulus;
+
+    let accumulator = generate_accumulator(
+        polynomial_size,
+        glwe_dimension.to_glwe_size(),
+        msg_modulus.cast_into(),
+        ciphertext_modulus,
+        delta,
+        f,
+    );
+
+    let num_samples = NB_KEYS * NB_TESTS * <Scalar as CastInto<usize>>::cast_into(msg);
+    let mut noise_samples = Vec::with_capacity(num_samples);
+
+    while msg != Scalar::ZERO {
+        msg = msg.wrapping_sub(Scalar::ONE);
+        // The noise depends on the Hamming weight of the keys, which varies a lot for small
+        // dimensions, average it over many keys
+        for _ in 0..NB_KEYS {
+            let keys = generate_keys(params, &mut rsc);
+            let (input_lwe_secret_key, output_lwe_secret_key, bsk) =
+                (keys.small_lwe_sk, keys.big_lwe_sk, keys.bsk);
+
+            // The formula does not take the FFT error into account, use the 128 bits FFT for
+            // which it is negligible
+            let mut fbsk = Fourier128LweBootstrapKey::new(
+                input_lwe_dimension,
+                glwe_dimension.to_glwe_size(),
+                polynomial_size,
+                params.pbs_base_log,
+                params.pbs_level,
+            );
+
+            convert_standard_lwe_bootstrap_key_to_fourier_128(&bsk, &mut fbsk);
+
+            for _ in 0..NB_TESTS {
+                let plaintext = Plaintext(msg * delta);
+
+                let lwe_ciphertext_in = allocate_and_encrypt_new_lwe_ciphertext(
+                    &input_lwe_secret_key,
+                    plaintext,
+                    lwe_modular_std_dev,
+                    ciphertext_modulus,
+                    &mut rsc.encryption_random_generator,
+                );
+
+                let mut out_pbs_ct = LweCiphertext::new(
+                    Scalar::ZERO,
+                    output_lwe_secret_key.lwe_dimension().to_lwe_size(),
+                    ciphertext_modulus,
+                );
+
+                programmable_bootstrap_f128_lwe_ciphertext(
+                    &lwe_ciphertext_in,
+                    &mut out_pbs_ct,
+                    &accumulator,
+                    &fbsk,
+                );
+
+                let decrypted = decrypt_lwe_ciphertext(&output_lwe_secret_key, &out_pbs_ct);
+
+                let decoded = round_decode(decrypted.0, delta) % msg_modulus;
+
+                assert_eq!(decoded, f(msg));
+
+                let torus_diff =
+                    torus_modular_diff(f(msg) * delta, decrypted.0, ciphertext_modulus);
+                noise_samples.push(torus_diff);
+            }
+        }
+    }
+
+    let measured_variance = variance(&noise_samples);
+    let var_abs_diff = (expected_variance.0 - measured_variance.0).abs();
+    let tolerance_threshold = RELATIVE_TOLERANCE * expected_variance.0;
+    assert!(
+        var_abs_diff < tolerance_threshold,
+        "Absolute difference for variance: {var_abs_diff}, \
+        tolerance threshold: {tolerance_threshold}, \
+        got variance: {measured_variance:?}, \
+        expected variance: {expected_variance:?}"
+    );
+}
+
+create_parametrized_test!(lwe_encrypt_pbs_decrypt_noise_distribution_custom_mod {
+    NOISE_TEST_PARAMS_2_BITS_NATIVE_U64
+});
@@ -12,7 +12,13 @@
 //! In any of those cases, the corresponding type implements the `DispersionParameter` trait,
 //! which makes if possible to use any of those representations generically when noise must be
 //! defined.
+//!
+//! The distribution the noise is sampled from is described by the [`NoiseDistribution`] type,
+//! which is either a continuous gaussian, a discrete gaussian or a bounded
+//! [`TUniform`](`crate::core_crypto::commons::math::random::TUniform`) distribution. Any
+//! `DispersionParameter` can be converted to a continuous gaussian [`NoiseDistribution`].
 
+use crate::core_crypto::commons::math::random::TUniform;
 use serde::{Deserialize, Serialize};
 
 /// A trait for types representing distribution parameters, for a given unsigned integer type.
@@ -194,3 +200,72 @@ impl DispersionParameter for Variance {
         log2_modulus as f64 + self.0.sqrt().log2()
     }
 }
+
+/// The distribution used to sample the noise of ciphertexts and keys.
+///
+/// # Example:
+///
+/// ```
+/// use tfhe::core_crypto::commons::dispersion::{NoiseDistribution, StandardDev, Variance};
+/// use tfhe::core_crypto::commons::math::random::TUniform;
+///
+/// // Any dispersion parameter can be used as a continuous gaussian distribution
+/// let gaussian: NoiseDistribution = StandardDev(2_f64.powi(-25)).into();
+/// assert_eq!(
+///     gaussian,
+///     NoiseDistribution::Gaussian(StandardDev(2_f64.powi(-25)))
+/// );
+/// assert_eq!(gaussian.variance(64), Variance(2_f64.powi(-50)));
+///
+/// let t_uniform = NoiseDistribution::TUniform(TUniform::new(10));
+/// assert_eq!(
+///     t_uniform.variance(64),
+///     Variance((2_f64.powi(21) + 1.) / 6. / 2_f64.powi(128))
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Serialize, Deserialize)]
+pub enum NoiseDistribution {
+    /// A continuous gaussian distribution, rounded to the closest integer.
+    Gaussian(StandardDev),
+    /// A discrete gaussian distribution over the integers.
+    DiscreteGaussian(StandardDev),
+    /// A TUniform distribution, sampling bounded values without floating point computations.
+    TUniform(TUniform),
+}
+
+impl NoiseDistribution {
+    /// Return the variance of the distribution relative to the torus, for a `Uint` type
+    /// representing $\mathbb{Z}/2^q\mathbb{Z}$.
+    ///
+    /// The variance of the gaussian distributions does not depend on `log2_modulus`, as their
+    /// standard deviation is already given relative to the torus.
+    pub fn variance(&self, log2_modulus: u32) -> Variance {
+        match self {
+            Self::Gaussian(std) | Self::DiscreteGaussian(std) => Variance(std.get_variance()),
+            Self::TUniform(t_uniform) => {
+                Variance::from_modular_variance(t_uniform.modular_variance(), log2_modulus)
+            }
+        }
+    }
+
+    /// Return the standard deviation of the distribution if it is a gaussian, continuous or
+    /// discrete.
+    pub fn gaussian_std_dev(&self) -> Option<StandardDev> {
+        match self {
+            Self::Gaussian(std) | Self::DiscreteGaussian(std) => Some(*std),
+            Self::TUniform(_) => None,
+        }
+    }
+}
+
+impl<D: DispersionParameter> From<D> for NoiseDistribution {
+    fn from(dispersion: D) -> Self {
+        Self::Gaussian(StandardDev(dispersion.get_standard_dev()))
+    }
+}
+
+impl From<TUniform> for NoiseDistribution {
+    fn from(t_uniform: TUniform) -> Self {
+        Self::TUniform(t_uniform)
+    }
+}
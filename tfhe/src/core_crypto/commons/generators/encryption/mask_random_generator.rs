@@ -1,8 +1,8 @@
 //! Module containing primitives pertaining to random mask generation in the context of encryption.
 
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, ParallelByteRandomGenerator, RandomGenerable, RandomGenerator, Seed,
-    Uniform,
+    ByteRandomGenerator, GeneratorState, ParallelByteRandomGenerator, RandomGenerable,
+    RandomGenerator, Seed, StateError, TableIndex, Uniform,
 };
 use crate::core_crypto::commons::numeric::UnsignedInteger;
 use crate::core_crypto::commons::parameters::{
@@ -40,6 +40,18 @@ impl<G: ByteRandomGenerator> MaskRandomGenerator<G> {
         self.gen.remaining_bytes()
     }
 
+    pub fn state(&self) -> Result<GeneratorState, StateError> {
+        self.gen.state()
+    }
+
+    pub fn try_restore(state: GeneratorState) -> Result<Self, StateError> {
+        RandomGenerator::try_restore(state).map(|gen| Self { gen })
+    }
+
+    pub fn try_skip_to(&mut self, index: TableIndex) -> Result<(), StateError> {
+        self.gen.try_skip_to(index)
+    }
+
     // Fills the slice with random uniform values, using the mask generator.
     pub(crate) fn fill_slice_with_random_mask<Scalar>(&mut self, output: &mut [Scalar])
     where
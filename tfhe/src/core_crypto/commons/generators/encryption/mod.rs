@@ -4,10 +4,10 @@ pub(crate) mod mask_random_generator;
 pub(crate) mod noise_random_generator;
 #[cfg(test)]
 mod test;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable, Seed, Seeder,
-    Uniform,
+    ByteRandomGenerator, Gaussian, GeneratorState, GeneratorStateSerdeDef,
+    ParallelByteRandomGenerator, RandomGenerable, Seed, Seeder, StateError, Uniform,
 };
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::UnsignedInteger;
@@ -19,6 +19,11 @@ use concrete_csprng::generators::ForkError;
 use mask_random_generator::MaskRandomGenerator;
 use noise_random_generator::NoiseRandomGenerator;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+// The labels used to derive the seeds of the mask and noise generators from a master seed.
+const MASK_SEED_DERIVATION_LABEL: &[u8] = b"tfhe-encryption-mask";
+const NOISE_SEED_DERIVATION_LABEL: &[u8] = b"tfhe-encryption-noise";
 
 /// A random number generator which can be used to encrypt messages.
 pub struct EncryptionRandomGenerator<G: ByteRandomGenerator> {
@@ -28,6 +33,29 @@ pub struct EncryptionRandomGenerator<G: ByteRandomGenerator> {
     noise: NoiseRandomGenerator<G>,
 }
 
+/// A snapshot of the state of an [`EncryptionRandomGenerator`], from which it can be restored.
+///
+/// # Note
+///
+/// The state of the noise generator contains the seed of the noise generator. Anyone knowing this
+/// state can recover the noise of the ciphertexts encrypted after the snapshot was taken, and thus
+/// the secret key used for the encryption. The state must be stored with the same care as the
+/// secret keys.
+///
+/// A state is a one-shot checkpoint: it must be restored at most once, and the generator it was
+/// taken from must not be used anymore once it is restored. Restoring the same state twice, or
+/// using both the original and the restored generators, reuses the mask and the noise of the
+/// encryptions, which leaks the secret key. For this reason the state is neither `Copy` nor
+/// `Clone`, and is consumed by [`EncryptionRandomGenerator::try_restore`]. A serialized state must
+/// likewise be discarded, or overwritten by a newer checkpoint, once it has been restored.
+#[derive(Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EncryptionRandomGeneratorState {
+    #[serde(with = "GeneratorStateSerdeDef")]
+    mask: GeneratorState,
+    #[serde(with = "GeneratorStateSerdeDef")]
+    noise: GeneratorState,
+}
+
 impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     /// Create a new [`EncryptionRandomGenerator`], using the provided [`Seed`] to seed the public
     /// mask generator and using the provided [`Seeder`] to privately seed the noise generator.
@@ -39,6 +67,151 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         }
     }
 
+    /// Create a new [`EncryptionRandomGenerator`], using seeds derived from `master_seed` to seed
+    /// both the mask and the noise generators.
+    ///
+    /// The seeds are derived with [`Seed::derive`] under distinct labels for the mask and the
+    /// noise, and the `index` allows to derive several independent generators from the same master
+    /// seed. The generator is therefore fully reproducible from the master seed and the index,
+    /// which is useful to produce test vectors.
+    ///
+    /// # Note
+    ///
+    /// As the noise generator is seeded from `master_seed`, the master seed must be kept as secret
+    /// as the secret keys used with this generator.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::generators::EncryptionRandomGenerator;
+    /// use tfhe::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed};
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let master_seed = Seed(42);
+    /// let mut first_generator =
+    ///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new_from_master_seed(master_seed, 0);
+    /// let mut second_generator =
+    ///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new_from_master_seed(master_seed, 0);
+    ///
+    /// let lwe_secret_key = LweSecretKey::from_container(vec![0u64, 1, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0]);
+    /// let mut first_ciphertext =
+    ///     LweCiphertext::new(0u64, LweSize(13), CiphertextModulus::new_native());
+    /// let mut second_ciphertext = first_ciphertext.clone();
+    ///
+    /// let plaintext = Plaintext(3u64 << 60);
+    /// let noise = Variance(2f64.powi(-50));
+    /// encrypt_lwe_ciphertext(
+    ///     &lwe_secret_key,
+    ///     &mut first_ciphertext,
+    ///     plaintext,
+    ///     noise,
+    ///     &mut first_generator,
+    /// );
+    /// encrypt_lwe_ciphertext(
+    ///     &lwe_secret_key,
+    ///     &mut second_ciphertext,
+    ///     plaintext,
+    ///     noise,
+    ///     &mut second_generator,
+    /// );
+    ///
+    /// assert_eq!(first_ciphertext, second_ciphertext);
+    /// ```
+    pub fn new_from_master_seed(master_seed: Seed, index: u64) -> Self {
+        Self {
+            mask: MaskRandomGenerator::new(master_seed.derive(MASK_SEED_DERIVATION_LABEL, index)),
+            noise: NoiseRandomGenerator::new(
+                master_seed.derive(NOISE_SEED_DERIVATION_LABEL, index),
+            ),
+        }
+    }
+
+    /// Try to return a snapshot of the state of the mask and noise generators.
+    ///
+    /// This allows to resume a long running encryption job after a shutdown, using
+    /// [`EncryptionRandomGenerator::try_restore`]. An error is returned if the underlying byte
+    /// generator does not support snapshots.
+    ///
+    /// # Note
+    ///
+    /// See [`EncryptionRandomGeneratorState`] for the precautions to take when storing and
+    /// restoring the state. In particular, the generator must not be used anymore once the
+    /// snapshot it returned has been restored, and a snapshot must never be restored twice.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use tfhe::core_crypto::commons::generators::{
+    ///     EncryptionRandomGenerator, EncryptionRandomGeneratorState,
+    /// };
+    /// use tfhe::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed};
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let mut seeder = new_seeder();
+    /// let mut generator =
+    ///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::new(Seed(0), seeder.as_mut());
+    ///
+    /// let lwe_secret_key = LweSecretKey::from_container(vec![0u64, 1, 1, 0, 1, 0, 0, 1, 1, 0, 1, 0]);
+    /// let plaintext = Plaintext(3u64 << 60);
+    /// let noise = Variance(2f64.powi(-50));
+    ///
+    /// let mut ciphertext = LweCiphertext::new(0u64, LweSize(13), CiphertextModulus::new_native());
+    /// encrypt_lwe_ciphertext(
+    ///     &lwe_secret_key,
+    ///     &mut ciphertext,
+    ///     plaintext,
+    ///     noise,
+    ///     &mut generator,
+    /// );
+    ///
+    /// // Save the state and stop using the generator, e.g. before a shutdown
+    /// let serialized_state = bincode::serialize(&generator.state().unwrap()).unwrap();
+    /// drop(generator);
+    ///
+    /// // Restore the generator from the saved state, which is consumed, and discard the saved
+    /// // state so that it can not be restored a second time
+    /// let state: EncryptionRandomGeneratorState = bincode::deserialize(&serialized_state).unwrap();
+    /// drop(serialized_state);
+    /// let mut generator =
+    ///     EncryptionRandomGenerator::<ActivatedRandomGenerator>::try_restore(state).unwrap();
+    ///
+    /// // Resume the encryptions, the restored generator does not reuse the randomness used before
+    /// // the shutdown
+    /// let mut next_ciphertext =
+    ///     LweCiphertext::new(0u64, LweSize(13), CiphertextModulus::new_native());
+    /// encrypt_lwe_ciphertext(
+    ///     &lwe_secret_key,
+    ///     &mut next_ciphertext,
+    ///     plaintext,
+    ///     noise,
+    ///     &mut generator,
+    /// );
+    ///
+    /// assert_ne!(ciphertext, next_ciphertext);
+    /// ```
+    pub fn state(&self) -> Result<EncryptionRandomGeneratorState, StateError> {
+        Ok(EncryptionRandomGeneratorState {
+            mask: self.mask.state()?,
+            noise: self.noise.state()?,
+        })
+    }
+
+    /// Try to restore an [`EncryptionRandomGenerator`] from a snapshot of its state.
+    ///
+    /// The states of both generators are checked before restoring them, and an error is returned
+    /// if one of them is inconsistent.
+    ///
+    /// # Note
+    ///
+    /// The state is consumed. A given snapshot must never be restored twice, nor restored while
+    /// the generator it was taken from is still in use, see [`EncryptionRandomGeneratorState`].
+    pub fn try_restore(state: EncryptionRandomGeneratorState) -> Result<Self, StateError> {
+        Ok(Self {
+            mask: MaskRandomGenerator::try_restore(state.mask)?,
+            noise: NoiseRandomGenerator::try_restore(state.noise)?,
+        })
+    }
+
     // Allows to seed the noise generator. For testing purpose only.
     #[cfg(test)]
     pub(crate) fn seed_noise_generator(&mut self, seed: Seed) {
@@ -255,69 +428,69 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
     }
 
     // Sample a noise value, using the noise generator.
-    pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
+    pub(crate) fn random_noise<Scalar>(&mut self, noise: impl Into<NoiseDistribution>) -> Scalar
     where
-        Scalar: UnsignedTorus + RandomGenerable<Gaussian<f64>>,
+        Scalar: UnsignedTorus,
     {
-        self.noise.random_noise(std)
+        self.noise.random_noise(noise)
     }
 
     // Sample a noise value, using the noise generator.
     pub(crate) fn random_noise_custom_mod<Scalar>(
         &mut self,
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
         custom_modulus: CiphertextModulus<Scalar>,
     ) -> Scalar
     where
-        Scalar: UnsignedTorus + RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
+        Scalar: UnsignedTorus,
     {
-        self.noise.random_noise_custom_mod(std, custom_modulus)
+        self.noise.random_noise_custom_mod(noise, custom_modulus)
     }
 
     // Fills the input slice with random noise, using the noise generator.
     pub(crate) fn fill_slice_with_random_noise<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
-        self.noise.fill_slice_with_random_noise(output, std);
+        self.noise.fill_slice_with_random_noise(output, noise);
     }
 
     // Fills the input slice with random noise, using the noise generator.
     pub(crate) fn fill_slice_with_random_noise_custom_mod<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
     {
         self.noise
-            .fill_slice_with_random_noise_custom_mod(output, std, custom_modulus);
+            .fill_slice_with_random_noise_custom_mod(output, noise, custom_modulus);
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
         self.noise
-            .unsigned_torus_slice_wrapping_add_random_noise_assign(output, std);
+            .unsigned_torus_slice_wrapping_add_random_noise_assign(output, noise);
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
@@ -326,7 +499,7 @@ impl<G: ByteRandomGenerator> EncryptionRandomGenerator<G> {
         self.noise
             .unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign(
                 output,
-                std,
+                noise,
                 custom_modulus,
             );
     }
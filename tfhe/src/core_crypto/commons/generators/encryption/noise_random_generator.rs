@@ -1,12 +1,11 @@
 //! Module containing primitives pertaining to random noise generation in the context of encryption.
 
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, NoiseDistribution};
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, Gaussian, ParallelByteRandomGenerator, RandomGenerable, RandomGenerator,
-    Seed,
+    ByteRandomGenerator, DiscreteGaussian, Gaussian, GeneratorState, ParallelByteRandomGenerator,
+    RandomGenerable, RandomGenerator, Seed, StateError, TableIndex,
 };
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
-use crate::core_crypto::commons::numeric::{CastInto, UnsignedInteger};
 use crate::core_crypto::commons::parameters::{
     CiphertextModulus, DecompositionLevelCount, FunctionalPackingKeyswitchKeyCount, GlweSize,
     LweBskGroupingFactor, LweCiphertextCount, LweDimension, LweMaskCount, LweSize, PolynomialSize,
@@ -42,109 +41,212 @@ impl<G: ByteRandomGenerator> NoiseRandomGenerator<G> {
         self.gen.remaining_bytes()
     }
 
+    pub fn state(&self) -> Result<GeneratorState, StateError> {
+        self.gen.state()
+    }
+
+    pub fn try_restore(state: GeneratorState) -> Result<Self, StateError> {
+        RandomGenerator::try_restore(state).map(|gen| Self { gen })
+    }
+
+    pub fn try_skip_to(&mut self, index: TableIndex) -> Result<(), StateError> {
+        self.gen.try_skip_to(index)
+    }
+
     // Sample a noise value, using the random generator.
-    pub(crate) fn random_noise<Scalar>(&mut self, std: impl DispersionParameter) -> Scalar
+    pub(crate) fn random_noise<Scalar>(&mut self, noise: impl Into<NoiseDistribution>) -> Scalar
     where
-        Scalar: RandomGenerable<Gaussian<f64>>,
+        Scalar: UnsignedTorus,
     {
-        <Scalar>::generate_one(
-            &mut self.gen,
-            Gaussian {
+        match noise.into() {
+            NoiseDistribution::Gaussian(std) => self.gen.random_from_distribution(Gaussian {
                 std: std.get_standard_dev(),
                 mean: 0.,
-            },
-        )
+            }),
+            NoiseDistribution::DiscreteGaussian(std) => {
+                self.gen.random_from_distribution(DiscreteGaussian {
+                    std: std.get_standard_dev(),
+                })
+            }
+            NoiseDistribution::TUniform(t_uniform) => self.gen.random_from_distribution(t_uniform),
+        }
     }
 
     // Sample a noise value, using the random generator.
     pub(crate) fn random_noise_custom_mod<Scalar>(
         &mut self,
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
         custom_modulus: CiphertextModulus<Scalar>,
     ) -> Scalar
     where
-        Scalar: UnsignedInteger + RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
+        Scalar: UnsignedTorus,
     {
-        if custom_modulus.is_native_modulus() {
-            return self.random_noise(std);
+        match noise.into() {
+            NoiseDistribution::Gaussian(std) => self.gen.random_from_distribution_custom_mod(
+                Gaussian {
+                    std: std.get_standard_dev(),
+                    mean: 0.,
+                },
+                custom_modulus,
+            ),
+            NoiseDistribution::DiscreteGaussian(std) => {
+                self.gen.random_from_distribution_custom_mod(
+                    DiscreteGaussian {
+                        std: std.get_standard_dev(),
+                    },
+                    custom_modulus,
+                )
+            }
+            NoiseDistribution::TUniform(t_uniform) => self
+                .gen
+                .random_from_distribution_custom_mod(t_uniform, custom_modulus),
         }
-
-        let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
-        Scalar::generate_one_custom_modulus(
-            &mut self.gen,
-            Gaussian {
-                std: std.get_standard_dev(),
-                mean: 0.,
-            },
-            custom_modulus_as_scalar,
-        )
     }
 
     // Fills the input slice with random noise, using the random generator.
     pub(crate) fn fill_slice_with_random_noise<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
-        self.gen
-            .fill_slice_with_random_gaussian(output, 0., std.get_standard_dev());
+        match noise.into() {
+            NoiseDistribution::Gaussian(std) => {
+                self.gen
+                    .fill_slice_with_random_gaussian(output, 0., std.get_standard_dev());
+            }
+            NoiseDistribution::DiscreteGaussian(std) => {
+                self.gen.fill_slice_with_random_from_distribution(
+                    output,
+                    DiscreteGaussian {
+                        std: std.get_standard_dev(),
+                    },
+                );
+            }
+            NoiseDistribution::TUniform(t_uniform) => {
+                self.gen
+                    .fill_slice_with_random_from_distribution(output, t_uniform);
+            }
+        }
     }
 
     // Fills the input slice with random noise, using the random generator.
     pub(crate) fn fill_slice_with_random_noise_custom_mod<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
     {
-        self.gen.fill_slice_with_random_gaussian_custom_mod(
-            output,
-            0.,
-            std.get_standard_dev(),
-            custom_modulus,
-        );
+        match noise.into() {
+            NoiseDistribution::Gaussian(std) => {
+                self.gen.fill_slice_with_random_gaussian_custom_mod(
+                    output,
+                    0.,
+                    std.get_standard_dev(),
+                    custom_modulus,
+                );
+            }
+            NoiseDistribution::DiscreteGaussian(std) => {
+                self.gen
+                    .fill_slice_with_random_from_distribution_custom_mod(
+                        output,
+                        DiscreteGaussian {
+                            std: std.get_standard_dev(),
+                        },
+                        custom_modulus,
+                    );
+            }
+            NoiseDistribution::TUniform(t_uniform) => {
+                self.gen
+                    .fill_slice_with_random_from_distribution_custom_mod(
+                        output,
+                        t_uniform,
+                        custom_modulus,
+                    );
+            }
+        }
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>>,
     {
-        self.gen
-            .unsigned_torus_slice_wrapping_add_random_gaussian_assign(
-                output,
-                0.,
-                std.get_standard_dev(),
-            );
+        match noise.into() {
+            NoiseDistribution::Gaussian(std) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_gaussian_assign(
+                        output,
+                        0.,
+                        std.get_standard_dev(),
+                    );
+            }
+            NoiseDistribution::DiscreteGaussian(std) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_from_distribution_assign(
+                        output,
+                        DiscreteGaussian {
+                            std: std.get_standard_dev(),
+                        },
+                    );
+            }
+            NoiseDistribution::TUniform(t_uniform) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_from_distribution_assign(
+                        output, t_uniform,
+                    );
+            }
+        }
     }
 
     // Adds noise on top of existing data for in place encryption
     pub(crate) fn unsigned_torus_slice_wrapping_add_random_noise_custom_mod_assign<Scalar>(
         &mut self,
         output: &mut [Scalar],
-        std: impl DispersionParameter,
+        noise: impl Into<NoiseDistribution>,
         custom_modulus: CiphertextModulus<Scalar>,
     ) where
         Scalar: UnsignedTorus,
         (Scalar, Scalar): RandomGenerable<Gaussian<f64>, CustomModulus = Scalar>,
     {
-        self.gen
-            .unsigned_torus_slice_wrapping_add_random_gaussian_custom_mod_assign(
-                output,
-                0.,
-                std.get_standard_dev(),
-                custom_modulus,
-            );
+        match noise.into() {
+            NoiseDistribution::Gaussian(std) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_gaussian_custom_mod_assign(
+                        output,
+                        0.,
+                        std.get_standard_dev(),
+                        custom_modulus,
+                    );
+            }
+            NoiseDistribution::DiscreteGaussian(std) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_from_distribution_custom_mod_assign(
+                        output,
+                        DiscreteGaussian {
+                            std: std.get_standard_dev(),
+                        },
+                        custom_modulus,
+                    );
+            }
+            NoiseDistribution::TUniform(t_uniform) => {
+                self.gen
+                    .unsigned_torus_slice_wrapping_add_random_from_distribution_custom_mod_assign(
+                        output,
+                        t_uniform,
+                        custom_modulus,
+                    );
+            }
+        }
     }
 
     // Forks the generator, when splitting a bootstrap key into ggsw ct.
@@ -627,3 +627,86 @@ fn mask_gen_slice_native_custom_mod_u64() {
 fn mask_gen_slice_native_custom_mod_u128() {
     mask_gen_slice_custom_mod::<u128>(CiphertextModulus::new_native());
 }
+
+#[test]
+fn test_new_from_master_seed_reproducible() {
+    use crate::core_crypto::commons::generators::EncryptionRandomGenerator;
+    use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed};
+
+    let master_seed = Seed(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+    let std = StandardDev(2.0f64.powi(-20));
+
+    let generate = |index: u64| {
+        let mut generator =
+            EncryptionRandomGenerator::<ActivatedRandomGenerator>::new_from_master_seed(
+                master_seed,
+                index,
+            );
+        let mut mask = vec![0u64; 16];
+        generator.fill_slice_with_random_mask(&mut mask);
+        let noise: Vec<u64> = (0..16).map(|_| generator.random_noise(std)).collect();
+        (mask, noise)
+    };
+
+    let (first_mask, first_noise) = generate(0);
+    let (second_mask, second_noise) = generate(0);
+    let (other_mask, other_noise) = generate(1);
+
+    assert_eq!(first_mask, second_mask);
+    assert_eq!(first_noise, second_noise);
+    assert_ne!(first_mask, other_mask);
+    assert_ne!(first_noise, other_noise);
+}
+
+#[test]
+fn test_state_restore_resumes_streams() {
+    use crate::core_crypto::commons::generators::{
+        EncryptionRandomGenerator, EncryptionRandomGeneratorState,
+    };
+    use crate::core_crypto::commons::math::random::ActivatedRandomGenerator;
+
+    let mut generator = new_encryption_random_generator();
+    let std = StandardDev(2.0f64.powi(-20));
+
+    let mut mask = vec![0u64; 16];
+    generator.fill_slice_with_random_mask(&mut mask);
+    let _: u64 = generator.random_noise(std);
+
+    let serialized_state = bincode::serialize(&generator.state().unwrap()).unwrap();
+    let state: EncryptionRandomGeneratorState = bincode::deserialize(&serialized_state).unwrap();
+    assert_eq!(state, generator.state().unwrap());
+
+    let mut restored_generator =
+        EncryptionRandomGenerator::<ActivatedRandomGenerator>::try_restore(state).unwrap();
+
+    let mut expected_mask = vec![0u64; 16];
+    generator.fill_slice_with_random_mask(&mut expected_mask);
+    let expected_noise: Vec<u64> = (0..16).map(|_| generator.random_noise(std)).collect();
+
+    let mut restored_mask = vec![0u64; 16];
+    restored_generator.fill_slice_with_random_mask(&mut restored_mask);
+    let restored_noise: Vec<u64> = (0..16)
+        .map(|_| restored_generator.random_noise(std))
+        .collect();
+
+    assert_eq!(expected_mask, restored_mask);
+    assert_eq!(expected_noise, restored_noise);
+    assert_eq!(
+        generator.state().unwrap(),
+        restored_generator.state().unwrap()
+    );
+}
+
+#[test]
+fn test_state_deserialization_rejects_invalid_table_index() {
+    use crate::core_crypto::commons::generators::EncryptionRandomGeneratorState;
+
+    let generator = new_encryption_random_generator();
+    let mut serialized_state = bincode::serialize(&generator.state().unwrap()).unwrap();
+
+    // The byte index of the next index of the mask generator follows the 128 bits seed and aes
+    // index, a table index only has 16 bytes per aes index
+    serialized_state[32..40].copy_from_slice(&16u64.to_le_bytes());
+
+    assert!(bincode::deserialize::<EncryptionRandomGeneratorState>(&serialized_state).is_err());
+}
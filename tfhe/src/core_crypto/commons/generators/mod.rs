@@ -4,7 +4,7 @@
 mod encryption;
 pub use encryption::mask_random_generator::MaskRandomGenerator;
 pub use encryption::noise_random_generator::NoiseRandomGenerator;
-pub use encryption::EncryptionRandomGenerator;
+pub use encryption::{EncryptionRandomGenerator, EncryptionRandomGeneratorState};
 
 mod secret;
 pub use secret::SecretRandomGenerator;
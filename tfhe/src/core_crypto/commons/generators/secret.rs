@@ -2,8 +2,10 @@
 //! generation.
 
 use crate::core_crypto::commons::math::random::{
-    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, UniformBinary,
+    ByteRandomGenerator, RandomGenerable, RandomGenerator, Seed, Uniform, UniformBinary,
+    UniformTernary,
 };
+use crate::core_crypto::commons::numeric::{CastFrom, UnsignedInteger};
 
 /// A random number generator which can be used to generate secret keys.
 pub struct SecretRandomGenerator<G: ByteRandomGenerator>(RandomGenerator<G>);
@@ -25,4 +27,97 @@ impl<G: ByteRandomGenerator> SecretRandomGenerator<G> {
     {
         self.0.fill_slice_with_random_uniform_binary(slice);
     }
+
+    pub(crate) fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, slice: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        self.0.fill_slice_with_random_uniform_ternary(slice);
+    }
+
+    pub(crate) fn fill_slice_with_random_gaussian_integers<Scalar>(
+        &mut self,
+        slice: &mut [Scalar],
+        std: f64,
+    ) where
+        Scalar: UnsignedInteger + CastFrom<i64>,
+    {
+        slice.chunks_mut(2).for_each(|s| {
+            let (g1, g2): (f64, f64) = self.0.random_gaussian(0., std);
+            // Negative values are stored using the wrapping (two's complement) representation
+            for (elem, g) in s
+                .iter_mut()
+                .zip(std::iter::once(g1).chain(std::iter::once(g2)))
+            {
+                *elem = Scalar::cast_from(g.round() as i64);
+            }
+        });
+    }
+
+    pub(crate) fn fill_slice_with_random_fixed_hamming_weight_binary<Scalar>(
+        &mut self,
+        slice: &mut [Scalar],
+        hamming_weight: usize,
+    ) where
+        Scalar: UnsignedInteger,
+    {
+        slice.fill(Scalar::ZERO);
+        for idx in self.random_distinct_indices(slice.len(), hamming_weight) {
+            slice[idx] = Scalar::ONE;
+        }
+    }
+
+    pub(crate) fn fill_slice_with_random_fixed_hamming_weight_ternary<Scalar>(
+        &mut self,
+        slice: &mut [Scalar],
+        hamming_weight: usize,
+    ) where
+        Scalar: UnsignedInteger + RandomGenerable<UniformBinary>,
+    {
+        slice.fill(Scalar::ZERO);
+        for idx in self.random_distinct_indices(slice.len(), hamming_weight) {
+            let sign: Scalar = self.0.random_uniform_binary();
+            slice[idx] = if sign == Scalar::ZERO {
+                Scalar::ONE
+            } else {
+                Scalar::ZERO.wrapping_sub(Scalar::ONE)
+            };
+        }
+    }
+
+    /// Fill the slice with integers sampled uniformly over the whole range of `Scalar`.
+    pub(crate) fn fill_slice_with_random_uniform<Scalar>(&mut self, slice: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<Uniform>,
+    {
+        self.0.fill_slice_with_random_uniform(slice);
+    }
+
+    /// Sample `count` distinct indices uniformly in `[0, bound[` using a partial Fisher-Yates
+    /// shuffle.
+    fn random_distinct_indices(&mut self, bound: usize, count: usize) -> Vec<usize> {
+        assert!(
+            count <= bound,
+            "Cannot sample {count} distinct indices in a range of size {bound}"
+        );
+
+        let mut indices: Vec<usize> = (0..bound).collect();
+        for current_idx in 0..count {
+            let remaining = (bound - current_idx) as u64;
+            // Uniform sampling with a custom modulus requires a modulus greater than 1
+            let offset = if remaining > 1 {
+                <u64 as RandomGenerable<Uniform>>::generate_one_custom_modulus(
+                    &mut self.0,
+                    Uniform,
+                    remaining,
+                )
+            } else {
+                0
+            };
+            indices.swap(current_idx, current_idx + offset as usize);
+        }
+
+        indices.truncate(count);
+        indices
+    }
 }
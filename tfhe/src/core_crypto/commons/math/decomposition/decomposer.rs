@@ -389,4 +389,20 @@ where
             self.ciphertext_modulus,
         )
     }
+
+    /// Return the internal state used by the decomposition of the input, i.e. the closest
+    /// representable value of the input divided by $\lfloor \frac{q}{B^{l}} \rfloor$.
+    ///
+    /// Successive calls to `decompose_one_level_non_native` on this state yield the terms of
+    /// the decomposition, starting with the least significant one, which allows to decompose
+    /// several values level by level without keeping iterators around.
+    #[inline]
+    pub(crate) fn init_decomposition_state(&self, input: Scalar) -> Scalar {
+        let base_to_the_level = 1 << (self.base_log * self.level_count);
+        let smallest_representable =
+            self.ciphertext_modulus.get_custom_modulus() / base_to_the_level;
+
+        let input_128: u128 = self.closest_representable(input).cast_into();
+        Scalar::cast_from(input_128 / smallest_representable)
+    }
 }
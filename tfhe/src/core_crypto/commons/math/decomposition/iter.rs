@@ -265,7 +265,7 @@ where
     }
 }
 
-fn decompose_one_level_non_native<S: UnsignedInteger>(
+pub(crate) fn decompose_one_level_non_native<S: UnsignedInteger>(
     base_log: usize,
     state: &mut S,
     mod_b_mask: S,
@@ -1,10 +1,17 @@
 #[cfg(feature = "generator_x86_64_aesni")]
 use concrete_csprng::generators::AesniRandomGenerator;
+#[cfg(all(
+    not(feature = "generator_x86_64_aesni"),
+    not(feature = "generator_aarch64_aes"),
+    feature = "generator_chacha"
+))]
+use concrete_csprng::generators::ChaChaRandomGenerator;
 #[cfg(feature = "generator_aarch64_aes")]
 use concrete_csprng::generators::NeonAesRandomGenerator;
 #[cfg(all(
     not(feature = "generator_x86_64_aesni"),
-    not(feature = "generator_aarch64_aes")
+    not(feature = "generator_aarch64_aes"),
+    not(feature = "generator_chacha")
 ))]
 use concrete_csprng::generators::SoftwareRandomGenerator;
 
@@ -14,6 +21,13 @@ pub type ActivatedRandomGenerator = AesniRandomGenerator;
 pub type ActivatedRandomGenerator = NeonAesRandomGenerator;
 #[cfg(all(
     not(feature = "generator_x86_64_aesni"),
-    not(feature = "generator_aarch64_aes")
+    not(feature = "generator_aarch64_aes"),
+    feature = "generator_chacha"
+))]
+pub type ActivatedRandomGenerator = ChaChaRandomGenerator;
+#[cfg(all(
+    not(feature = "generator_x86_64_aesni"),
+    not(feature = "generator_aarch64_aes"),
+    not(feature = "generator_chacha")
 ))]
 pub type ActivatedRandomGenerator = SoftwareRandomGenerator;
@@ -0,0 +1,141 @@
+use super::*;
+use crate::core_crypto::commons::numeric::{CastInto, Numeric, UnsignedInteger};
+
+/// The number of standard deviations after which the discrete gaussian distribution is cut.
+const DISCRETE_GAUSSIAN_TAIL_CUT: f64 = 6.;
+
+/// A distribution type representing random sampling of integers following a discrete gaussian
+/// distribution centered on zero, for unsigned integer types.
+///
+/// As for the [`Gaussian`] distribution, the standard deviation is given relative to the torus,
+/// i.e. the standard deviation of the sampled integers is `std` times the modulus. The integer
+/// $x$ is sampled with a probability proportional to $e^{-\frac{x^2}{2\sigma^2}}$, contrary to the
+/// rounded gaussian which is obtained by rounding a continuous gaussian sample. The distribution
+/// is cut at `6` standard deviations.
+///
+/// # Note
+///
+/// The sampling is performed by rejection, using rounded continuous gaussian samples as
+/// candidates. The rejection rate is negligible for standard deviations much larger than 1 in the
+/// integer domain, which is the case for the noise of all the parameter sets of the library. For
+/// standard deviations of a few units or less, uniform candidates on the support of the
+/// distribution are used instead, a zero standard deviation always gives 0.
+#[derive(Clone, Copy)]
+pub struct DiscreteGaussian<T: FloatingPoint> {
+    /// The standard deviation of the distribution, relative to the torus.
+    pub std: T,
+}
+
+// Samples an integer following the discrete gaussian distribution of standard deviation
+// `modular_std`, expressed in the integer domain.
+fn sample_discrete_gaussian<G: ByteRandomGenerator>(
+    generator: &mut RandomGenerator<G>,
+    modular_std: f64,
+) -> f64 {
+    assert!(
+        modular_std >= 0.,
+        "The standard deviation of a discrete gaussian must be non negative, got {modular_std}"
+    );
+    let bound = (DISCRETE_GAUSSIAN_TAIL_CUT * modular_std).ceil();
+    let two_variance = 2. * modular_std * modular_std;
+
+    // The acceptance of the rounded gaussian candidates below is normalized by
+    // exp((bound + 1/4) / 2σ^2), which makes almost all candidates rejected for small standard
+    // deviations, use uniform candidates on the small support of the distribution instead
+    if bound + 0.25 > two_variance {
+        return sample_small_discrete_gaussian(generator, bound, two_variance);
+    }
+
+    loop {
+        let (candidate, _) = <(f64, f64)>::generate_one(
+            generator,
+            Gaussian {
+                std: modular_std,
+                mean: 0.,
+            },
+        );
+        let rounded = candidate.round();
+        if rounded.abs() > bound {
+            continue;
+        }
+        // The candidate y is accepted with a probability proportional to
+        // exp((y^2 - x^2) / 2σ^2) with x = round(y), which is constant over the rounding interval
+        // of x. We have y^2 - x^2 <= |x| + 1/4 <= bound + 1/4, hence the normalization.
+        let log_acceptance =
+            ((candidate - rounded) * (candidate + rounded) - bound - 0.25) / two_variance;
+        if random_unit_interval_f64(generator) < log_acceptance.exp() {
+            return rounded;
+        }
+    }
+}
+
+// Samples an integer x in [-bound, bound] with a probability proportional to exp(-x^2 / 2σ^2),
+// using uniform candidates, each one being accepted with a probability above 1 / (2 * bound + 1).
+fn sample_small_discrete_gaussian<G: ByteRandomGenerator>(
+    generator: &mut RandomGenerator<G>,
+    bound: f64,
+    two_variance: f64,
+) -> f64 {
+    // A zero standard deviation only gives 0
+    if bound == 0. {
+        return 0.;
+    }
+
+    let bound = bound as u64;
+    loop {
+        let candidate = u64::generate_one_custom_modulus(generator, Uniform, 2 * bound + 1) as f64
+            - bound as f64;
+        if random_unit_interval_f64(generator) < (-candidate * candidate / two_variance).exp() {
+            return candidate;
+        }
+    }
+}
+
+fn random_unit_interval_f64<G: ByteRandomGenerator>(generator: &mut RandomGenerator<G>) -> f64 {
+    let uniform: u64 = generator.random_uniform();
+    // 53 random bits give an f64 uniformly distributed in [0, 1[
+    (uniform >> 11) as f64 * 2f64.powi(-53)
+}
+
+macro_rules! implement_discrete_gaussian {
+    ($T:ty) => {
+        impl RandomGenerable<DiscreteGaussian<f64>> for $T {
+            type CustomModulus = $T;
+
+            fn generate_one<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                DiscreteGaussian { std }: DiscreteGaussian<f64>,
+            ) -> Self {
+                let modular_std = std * 2f64.powi(<$T as Numeric>::BITS as i32);
+                let sample = sample_discrete_gaussian(generator, modular_std);
+                let signed: <$T as UnsignedInteger>::Signed = sample.cast_into();
+                signed.cast_into()
+            }
+
+            fn generate_one_custom_modulus<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                DiscreteGaussian { std }: DiscreteGaussian<f64>,
+                custom_modulus: Self::CustomModulus,
+            ) -> Self {
+                let custom_modulus_float: f64 = custom_modulus.cast_into();
+                let modular_std = std * custom_modulus_float;
+                let sample = sample_discrete_gaussian(generator, modular_std);
+                let signed: <$T as UnsignedInteger>::Signed = sample.cast_into();
+                if signed >= 0 {
+                    signed.cast_into()
+                } else {
+                    // As the value is negative we subtract its absolute value from the modulus
+                    // to get the proper representant
+                    let unsigned: $T = (-signed).cast_into();
+                    custom_modulus - unsigned
+                }
+            }
+        }
+    };
+}
+
+implement_discrete_gaussian!(u8);
+implement_discrete_gaussian!(u16);
+implement_discrete_gaussian!(u32);
+implement_discrete_gaussian!(u64);
+implement_discrete_gaussian!(u128);
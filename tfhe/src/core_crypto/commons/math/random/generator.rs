@@ -1,6 +1,6 @@
 use crate::core_crypto::commons::math::random::{
-    Gaussian, RandomGenerable, Uniform, UniformBinary, UniformLsb, UniformMsb, UniformTernary,
-    UniformWithZeros,
+    Distribution, Gaussian, RandomGenerable, Uniform, UniformBinary, UniformLsb, UniformMsb,
+    UniformTernary, UniformWithZeros,
 };
 use crate::core_crypto::commons::math::torus::{UnsignedInteger, UnsignedTorus};
 use crate::core_crypto::commons::numeric::{CastInto, FloatingPoint};
@@ -10,15 +10,19 @@ use rayon::prelude::*;
 use std::convert::TryInto;
 
 pub use concrete_csprng::generators::{
-    ParallelRandomGenerator as ParallelByteRandomGenerator, RandomGenerator as ByteRandomGenerator,
+    GeneratorState, ParallelRandomGenerator as ParallelByteRandomGenerator,
+    RandomGenerator as ByteRandomGenerator, StateError, TableIndex,
 };
 pub use concrete_csprng::seeders::{Seed, Seeder};
 
 /// Module to proxy the serialization for `concrete-csprng::Seed` to avoid adding serde as a
 /// dependency to `concrete-csprng`
 pub mod serialization_proxy {
+    pub(crate) use concrete_csprng::generators::{AesIndex, ByteIndex, GeneratorState, TableIndex};
     pub(crate) use concrete_csprng::seeders::Seed;
+    use serde::de::Error;
     pub(crate) use serde::{Deserialize, Serialize};
+    use serde::{Deserializer, Serializer};
 
     // See https://serde.rs/remote-derive.html
     // Serde calls this the definition of the remote type. It is just a copy of the remote data
@@ -27,6 +31,63 @@ pub mod serialization_proxy {
     #[derive(Serialize, Deserialize)]
     #[serde(remote = "Seed")]
     pub(crate) struct SeedSerdeDef(pub u128);
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "AesIndex")]
+    pub(crate) struct AesIndexSerdeDef(pub u128);
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "ByteIndex")]
+    pub(crate) struct ByteIndexSerdeDef(pub usize);
+
+    // The fields of `TableIndex` are private and its constructor panics on an invalid byte index,
+    // so it is (de)serialized through this copy which is validated before building the remote type.
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "TableIndexSerdeDef")]
+    struct TableIndexRepr {
+        #[serde(with = "AesIndexSerdeDef")]
+        aes_index: AesIndex,
+        #[serde(with = "ByteIndexSerdeDef")]
+        byte_index: ByteIndex,
+    }
+
+    pub(crate) struct TableIndexSerdeDef;
+
+    impl TableIndexSerdeDef {
+        pub(crate) fn serialize<S: Serializer>(
+            index: &TableIndex,
+            serializer: S,
+        ) -> Result<S::Ok, S::Error> {
+            TableIndexRepr {
+                aes_index: index.aes_index(),
+                byte_index: index.byte_index(),
+            }
+            .serialize(serializer)
+        }
+
+        pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+            deserializer: D,
+        ) -> Result<TableIndex, D::Error> {
+            let repr = TableIndexRepr::deserialize(deserializer)?;
+            TableIndex::try_new(repr.aes_index, repr.byte_index).ok_or_else(|| {
+                D::Error::custom(format!(
+                    "Invalid byte index {} in a table index",
+                    repr.byte_index.0
+                ))
+            })
+        }
+    }
+
+    #[derive(Serialize, Deserialize)]
+    #[serde(remote = "GeneratorState")]
+    pub(crate) struct GeneratorStateSerdeDef {
+        #[serde(with = "SeedSerdeDef")]
+        pub seed: Seed,
+        #[serde(with = "TableIndexSerdeDef")]
+        pub next_index: TableIndex,
+        #[serde(with = "TableIndexSerdeDef")]
+        pub bound: TableIndex,
+    }
 }
 
 pub(crate) use serialization_proxy::*;
@@ -140,6 +201,73 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
             .map(|iter| iter.map(Self))
     }
 
+    /// Try to return a snapshot of the state of the generator, from which it can be restored with
+    /// [`RandomGenerator::try_restore`].
+    ///
+    /// An error is returned if the underlying byte generator does not support snapshots.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let _ = generator.random_uniform::<u64>();
+    ///
+    /// // Take a snapshot and stop using the generator, e.g. before a shutdown
+    /// let state = generator.state().unwrap();
+    /// let next_index = state.next_index;
+    /// drop(generator);
+    ///
+    /// let mut restored = RandomGenerator::<SoftwareRandomGenerator>::try_restore(state).unwrap();
+    /// assert_eq!(restored.state().unwrap().next_index, next_index);
+    /// let _ = restored.random_uniform::<u64>();
+    /// ```
+    pub fn state(&self) -> Result<GeneratorState, StateError> {
+        self.0.state()
+    }
+
+    /// Try to restore a generator from a snapshot of its state.
+    ///
+    /// The state is checked before restoring the generator, and an error is returned if it is
+    /// inconsistent. The restored generator outputs the bytes the original generator would have
+    /// outputted after the snapshot was taken.
+    ///
+    /// # Note
+    ///
+    /// The state is consumed, and a given snapshot must never be restored twice, nor restored
+    /// while the original generator is still in use, as the same randomness would be outputted
+    /// twice.
+    pub fn try_restore(state: GeneratorState) -> Result<Self, StateError> {
+        G::try_restore(state).map(Self)
+    }
+
+    /// Try to move the generator forward, so that the next generated byte is the one at `index`.
+    ///
+    /// The generator can not be moved backward, nor after its bound, as this would respectively
+    /// reuse randomness or break the guarantees of a fork.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut skipped = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let index = generator.state().unwrap().next_index.increased(8);
+    /// let _ = generator.random_uniform::<u64>();
+    /// skipped.try_skip_to(index).unwrap();
+    /// assert_eq!(
+    ///     skipped.random_uniform::<u64>(),
+    ///     generator.random_uniform::<u64>()
+    /// );
+    /// ```
+    pub fn try_skip_to(&mut self, index: TableIndex) -> Result<(), StateError> {
+        self.0.try_skip_to(index)
+    }
+
     /// Generate a random uniform unsigned integer.
     ///
     /// # Example
@@ -320,6 +448,27 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         Scalar::generate_one(self, UniformTernary)
     }
 
+    /// Fill a slice with random uniform ternary values.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::RandomGenerator;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u32; 1000];
+    /// generator.fill_slice_with_random_uniform_ternary(&mut vec);
+    /// assert!(vec.iter().all(|&x| x == 0 || x == 1 || x == u32::MAX));
+    /// assert!(vec.iter().any(|&x| x != 0));
+    /// ```
+    pub fn fill_slice_with_random_uniform_ternary<Scalar>(&mut self, output: &mut [Scalar])
+    where
+        Scalar: RandomGenerable<UniformTernary>,
+    {
+        Scalar::fill_slice(self, UniformTernary, output);
+    }
+
     /// Generate an unsigned integer whose n least significant bits are uniformly random, and the
     /// other bits are zero.
     ///
@@ -567,6 +716,16 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
         }
 
         let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
+        // Non native power of 2 values are scaled to the native torus afterwards, so wrapping
+        // arithmetic is fine there, other moduli require a proper modular reduction
+        let is_power_of_two = custom_modulus.is_power_of_two();
+        let add = |lhs: Scalar, rhs: Scalar| {
+            if is_power_of_two {
+                lhs.wrapping_add(rhs)
+            } else {
+                lhs.wrapping_add_custom_mod(rhs, custom_modulus_as_scalar)
+            }
+        };
         output.chunks_mut(2).for_each(|s| {
             let (g1, g2) = <(Scalar, Scalar)>::generate_one_custom_modulus(
                 self,
@@ -574,13 +733,209 @@ impl<G: ByteRandomGenerator> RandomGenerator<G> {
                 custom_modulus_as_scalar,
             );
             if let Some(elem) = s.get_mut(0) {
-                *elem = (*elem).wrapping_add(g1);
+                *elem = add(*elem, g1);
             }
             if let Some(elem) = s.get_mut(1) {
-                *elem = (*elem).wrapping_add(g2);
+                *elem = add(*elem, g2);
             }
         });
     }
+
+    /// Generate a random value following the given distribution.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let random: u64 = generator.random_from_distribution(TUniform::new(10));
+    /// // The value is in [-2^10, 2^10]
+    /// assert!(random.wrapping_add(1 << 10) <= 1 << 11);
+    /// ```
+    pub fn random_from_distribution<D, Scalar>(&mut self, distribution: D) -> Scalar
+    where
+        D: Distribution,
+        Scalar: RandomGenerable<D>,
+    {
+        Scalar::generate_one(self, distribution)
+    }
+
+    /// Generate a random value following the given distribution, under a custom modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+    /// use tfhe::core_crypto::commons::parameters::CiphertextModulus;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let modulus = (1u64 << 64) - (1 << 32) + 1;
+    /// let random: u64 = generator.random_from_distribution_custom_mod(
+    ///     TUniform::new(10),
+    ///     CiphertextModulus::try_new(modulus as u128).unwrap(),
+    /// );
+    /// assert!(random <= 1 << 10 || random >= modulus - (1 << 10));
+    /// ```
+    pub fn random_from_distribution_custom_mod<D, Scalar>(
+        &mut self,
+        distribution: D,
+        custom_modulus: CiphertextModulus<Scalar>,
+    ) -> Scalar
+    where
+        D: Distribution,
+        Scalar: UnsignedInteger + RandomGenerable<D, CustomModulus = Scalar>,
+    {
+        if custom_modulus.is_native_modulus() {
+            return self.random_from_distribution(distribution);
+        }
+
+        let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
+        Scalar::generate_one_custom_modulus(self, distribution, custom_modulus_as_scalar)
+    }
+
+    /// Fill a slice with random values following the given distribution.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u64; 1000];
+    /// generator.fill_slice_with_random_from_distribution(&mut vec, TUniform::new(10));
+    /// assert!(vec.iter().any(|&x| x != 0));
+    /// ```
+    pub fn fill_slice_with_random_from_distribution<D, Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        distribution: D,
+    ) where
+        D: Distribution,
+        Scalar: RandomGenerable<D>,
+    {
+        Scalar::fill_slice(self, distribution, output);
+    }
+
+    /// Fill a slice with random values following the given distribution, under a custom modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+    /// use tfhe::core_crypto::commons::parameters::CiphertextModulus;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u64; 1000];
+    /// generator.fill_slice_with_random_from_distribution_custom_mod(
+    ///     &mut vec,
+    ///     TUniform::new(10),
+    ///     CiphertextModulus::try_new_power_of_2(63).unwrap(),
+    /// );
+    /// assert!(vec.iter().any(|&x| x != 0));
+    /// ```
+    pub fn fill_slice_with_random_from_distribution_custom_mod<D, Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        distribution: D,
+        custom_modulus: CiphertextModulus<Scalar>,
+    ) where
+        D: Distribution,
+        Scalar: UnsignedInteger + RandomGenerable<D, CustomModulus = Scalar>,
+    {
+        if custom_modulus.is_native_modulus() {
+            self.fill_slice_with_random_from_distribution(output, distribution);
+            return;
+        }
+
+        let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
+        Scalar::fill_slice_custom_mod(self, distribution, output, custom_modulus_as_scalar);
+    }
+
+    /// Add a random value following the given distribution to each element in a slice.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u64; 1000];
+    /// generator.unsigned_torus_slice_wrapping_add_random_from_distribution_assign(
+    ///     &mut vec,
+    ///     TUniform::new(10),
+    /// );
+    /// assert!(vec.iter().any(|&x| x != 0));
+    /// ```
+    pub fn unsigned_torus_slice_wrapping_add_random_from_distribution_assign<D, Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        distribution: D,
+    ) where
+        D: Distribution,
+        Scalar: UnsignedTorus + RandomGenerable<D>,
+    {
+        for elem in output.iter_mut() {
+            let sample: Scalar = self.random_from_distribution(distribution);
+            *elem = (*elem).wrapping_add(sample);
+        }
+    }
+
+    /// Add a random value following the given distribution to each element in a slice, under a
+    /// custom modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use concrete_csprng::generators::SoftwareRandomGenerator;
+    /// use concrete_csprng::seeders::Seed;
+    /// use tfhe::core_crypto::commons::math::random::{RandomGenerator, TUniform};
+    /// use tfhe::core_crypto::commons::parameters::CiphertextModulus;
+    /// let mut generator = RandomGenerator::<SoftwareRandomGenerator>::new(Seed(0));
+    /// let mut vec = vec![0u64; 1000];
+    /// generator.unsigned_torus_slice_wrapping_add_random_from_distribution_custom_mod_assign(
+    ///     &mut vec,
+    ///     TUniform::new(10),
+    ///     CiphertextModulus::try_new_power_of_2(63).unwrap(),
+    /// );
+    /// assert!(vec.iter().any(|&x| x != 0));
+    /// ```
+    pub fn unsigned_torus_slice_wrapping_add_random_from_distribution_custom_mod_assign<D, Scalar>(
+        &mut self,
+        output: &mut [Scalar],
+        distribution: D,
+        custom_modulus: CiphertextModulus<Scalar>,
+    ) where
+        D: Distribution,
+        Scalar: UnsignedTorus + RandomGenerable<D, CustomModulus = Scalar>,
+    {
+        if custom_modulus.is_native_modulus() {
+            self.unsigned_torus_slice_wrapping_add_random_from_distribution_assign(
+                output,
+                distribution,
+            );
+            return;
+        }
+
+        let custom_modulus_as_scalar: Scalar = custom_modulus.get_custom_modulus().cast_into();
+        // Non native power of 2 values are scaled to the native torus afterwards, so wrapping
+        // arithmetic is fine there, other moduli require a proper modular reduction
+        let is_power_of_two = custom_modulus.is_power_of_two();
+        for elem in output.iter_mut() {
+            let sample =
+                Scalar::generate_one_custom_modulus(self, distribution, custom_modulus_as_scalar);
+            *elem = if is_power_of_two {
+                (*elem).wrapping_add(sample)
+            } else {
+                (*elem).wrapping_add_custom_mod(sample, custom_modulus_as_scalar)
+            };
+        }
+    }
 }
 
 impl<G: ParallelByteRandomGenerator> RandomGenerator<G> {
@@ -17,8 +17,10 @@ use crate::core_crypto::commons::numeric::FloatingPoint;
 
 /// Convenience alias for the most efficient CSPRNG implementation available.
 pub use activated_random_generator::ActivatedRandomGenerator;
+pub use discrete_gaussian::*;
 pub use gaussian::*;
 pub use generator::*;
+pub use t_uniform::*;
 pub use uniform::*;
 pub use uniform_binary::*;
 pub use uniform_lsb::*;
@@ -30,8 +32,10 @@ pub use uniform_with_zeros::*;
 mod tests;
 
 mod activated_random_generator;
+mod discrete_gaussian;
 mod gaussian;
 mod generator;
+mod t_uniform;
 mod uniform;
 mod uniform_binary;
 mod uniform_lsb;
@@ -99,6 +103,8 @@ mod seal {
     impl Sealed for super::UniformBinary {}
     impl Sealed for super::UniformTernary {}
     impl<T: FloatingPoint> Sealed for super::Gaussian<T> {}
+    impl<T: FloatingPoint> Sealed for super::DiscreteGaussian<T> {}
+    impl Sealed for super::TUniform {}
 }
 impl Distribution for Uniform {}
 impl Distribution for UniformMsb {}
@@ -107,3 +113,5 @@ impl Distribution for UniformWithZeros {}
 impl Distribution for UniformBinary {}
 impl Distribution for UniformTernary {}
 impl<T: FloatingPoint> Distribution for Gaussian<T> {}
+impl<T: FloatingPoint> Distribution for DiscreteGaussian<T> {}
+impl Distribution for TUniform {}
@@ -0,0 +1,114 @@
+use super::*;
+use crate::core_crypto::algorithms::misc::divide_ceil;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use serde::{Deserialize, Serialize};
+
+/// A distribution type representing the TUniform distribution of parameter $b$ for unsigned
+/// integer types.
+///
+/// The value is sampled in $[-2^b, 2^b]$, the two bounds being sampled with probability
+/// $\frac{1}{2^{b+2}}$ and all the other values with probability $\frac{1}{2^{b+1}}$. Negative
+/// values are returned as their representative modulo the integer type size (or the custom
+/// modulus, if any).
+///
+/// Sampling a value only requires $b+2$ uniform random bits and no floating point computation.
+/// Since the sampled values are bounded, this distribution allows for a worst-case analysis of the
+/// noise growth.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TUniform {
+    bound_log2: u32,
+}
+
+impl TUniform {
+    /// Create a new TUniform distribution, sampling values in $[-2^b, 2^b]$ with $b$ =
+    /// `bound_log2`.
+    pub const fn new(bound_log2: u32) -> Self {
+        Self { bound_log2 }
+    }
+
+    /// Return the base 2 logarithm of the bound of the distribution.
+    pub const fn bound_log2(&self) -> u32 {
+        self.bound_log2
+    }
+
+    /// Return the variance of the distribution, expressed in the integer domain, i.e.
+    /// $\frac{2^{2b+1} + 1}{6}$.
+    pub fn modular_variance(&self) -> f64 {
+        (2f64.powi(2 * self.bound_log2 as i32 + 1) + 1.) / 6.
+    }
+}
+
+macro_rules! implement_t_uniform {
+    ($T:ty) => {
+        impl RandomGenerable<TUniform> for $T {
+            type CustomModulus = $T;
+
+            fn generate_one<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform,
+            ) -> Self {
+                let (value, bound) = sample_shifted_t_uniform::<G, $T>(generator, distribution);
+                // The value is in [0, 2^(b+1)], shifting it by -2^b gives the proper representant
+                // in the native modulus
+                value.wrapping_sub(bound)
+            }
+
+            fn generate_one_custom_modulus<G: ByteRandomGenerator>(
+                generator: &mut RandomGenerator<G>,
+                distribution: TUniform,
+                custom_modulus: Self::CustomModulus,
+            ) -> Self {
+                let (value, bound) = sample_shifted_t_uniform::<G, $T>(generator, distribution);
+                debug_assert!(
+                    bound < custom_modulus,
+                    "TUniform bound 2^{} does not fit in the custom modulus {custom_modulus}",
+                    distribution.bound_log2
+                );
+                if value >= bound {
+                    value - bound
+                } else {
+                    // As the value is negative we subtract its absolute value from the modulus
+                    // to get the proper representant
+                    custom_modulus - (bound - value)
+                }
+            }
+        }
+    };
+}
+
+// Samples a value from the TUniform distribution shifted by 2^b, i.e. in [0, 2^(b+1)], and returns
+// it along with 2^b.
+fn sample_shifted_t_uniform<G, T>(
+    generator: &mut RandomGenerator<G>,
+    TUniform { bound_log2 }: TUniform,
+) -> (T, T)
+where
+    G: ByteRandomGenerator,
+    T: UnsignedInteger,
+{
+    let required_bits = bound_log2 + 2;
+    assert!(
+        required_bits <= T::BITS as u32,
+        "TUniform with bound 2^{bound_log2} can not be sampled for a {}-bits integer",
+        T::BITS
+    );
+    let required_bytes = divide_ceil(required_bits, u8::BITS) as usize;
+
+    let mut random = T::ZERO;
+    for byte_idx in 0..required_bytes {
+        let byte: T = T::cast_from(generator.generate_next() as u128);
+        random |= byte << (byte_idx * 8);
+    }
+    random &= T::MAX >> (T::BITS - required_bits as usize);
+
+    // The low bit is added to the remaining b+1 bits, this gives the extremal values half the
+    // probability of the other ones.
+    let value = (random >> 1) + (random & T::ONE);
+    (value, T::ONE << bound_log2 as usize)
+}
+
+implement_t_uniform!(u8);
+implement_t_uniform!(u16);
+implement_t_uniform!(u32);
+implement_t_uniform!(u64);
+implement_t_uniform!(u128);
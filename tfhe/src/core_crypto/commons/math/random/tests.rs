@@ -1,6 +1,8 @@
 use crate::core_crypto::algorithms::misc::check_clear_content_respects_mod;
 use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
-use crate::core_crypto::commons::math::random::{RandomGenerable, Uniform};
+use crate::core_crypto::commons::math::random::{
+    DiscreteGaussian, RandomGenerable, TUniform, Uniform,
+};
 use crate::core_crypto::commons::math::torus::{CastInto, UnsignedInteger, UnsignedTorus};
 use crate::core_crypto::commons::test_tools::*;
 
@@ -513,3 +515,188 @@ fn test_uniform_random_custom_mod_u64() {
         CiphertextModulus::try_new((1 << 10) + (1 << 9)).unwrap(),
     );
 }
+
+// Maps a value modulo the ciphertext modulus to its signed representative, as a float
+fn modular_value_to_signed_f64<Scalar: UnsignedTorus>(
+    value: Scalar,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> f64 {
+    let (is_negative, negated_value) = if ciphertext_modulus.is_native_modulus() {
+        (
+            value >> (Scalar::BITS - 1) == Scalar::ONE,
+            value.wrapping_neg(),
+        )
+    } else {
+        let modulus: Scalar = ciphertext_modulus.get_custom_modulus().cast_into();
+        (value > modulus / Scalar::TWO, modulus.wrapping_sub(value))
+    };
+    if is_negative {
+        let negated_value: f64 = negated_value.cast_into();
+        -negated_value
+    } else {
+        value.cast_into()
+    }
+}
+
+fn test_t_uniform_random<Scalar: UnsignedTorus>(ciphertext_modulus: CiphertextModulus<Scalar>) {
+    const NUMBER_OF_SAMPLES: usize = 1_000_000;
+    let bound_log2 = 10;
+    let distribution = TUniform::new(bound_log2);
+    let bound = 2f64.powi(bound_log2 as i32);
+    let mut rng = new_random_generator();
+
+    let mut samples = vec![Scalar::ZERO; NUMBER_OF_SAMPLES];
+    rng.fill_slice_with_random_from_distribution_custom_mod(
+        &mut samples,
+        distribution,
+        ciphertext_modulus,
+    );
+
+    assert!(check_clear_content_respects_mod(
+        &samples,
+        ciphertext_modulus
+    ));
+
+    let samples: Vec<f64> = samples
+        .iter()
+        .map(|&x| modular_value_to_signed_f64(x, ciphertext_modulus))
+        .collect();
+
+    assert!(samples.iter().all(|&x| x.abs() <= bound));
+    // Both bounds are reached with probability 1 - (1 - 2^-12)^(10^6) which is 1 for all
+    // practical purposes
+    assert!(samples.contains(&bound));
+    assert!(samples.contains(&-bound));
+
+    let mean = samples.iter().sum::<f64>() / NUMBER_OF_SAMPLES as f64;
+    let variance = samples.iter().map(|x| x * x).sum::<f64>() / NUMBER_OF_SAMPLES as f64;
+    let expected_variance = distribution.modular_variance();
+    assert!(mean.abs() < 2.0, "mean={mean}");
+    assert!(
+        (variance - expected_variance).abs() / expected_variance < 0.01,
+        "variance={variance}, expected_variance={expected_variance}"
+    );
+}
+
+#[test]
+fn test_t_uniform_random_native_u32() {
+    test_t_uniform_random::<u32>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_t_uniform_random_native_u64() {
+    test_t_uniform_random::<u64>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_t_uniform_random_native_u128() {
+    test_t_uniform_random::<u128>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_t_uniform_random_solinas_custom_mod_u64() {
+    test_t_uniform_random::<u64>(CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap());
+}
+
+fn test_discrete_gaussian_random<Scalar: UnsignedTorus>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) {
+    const NUMBER_OF_SAMPLES: usize = 1_000_000;
+    let modular_std_dev = 1024.0;
+    let modulus_f64 = if ciphertext_modulus.is_native_modulus() {
+        2f64.powi(Scalar::BITS as i32)
+    } else {
+        ciphertext_modulus.get_custom_modulus() as f64
+    };
+    let distribution = DiscreteGaussian {
+        std: modular_std_dev / modulus_f64,
+    };
+    let mut rng = new_random_generator();
+
+    let mut samples = vec![Scalar::ZERO; NUMBER_OF_SAMPLES];
+    rng.fill_slice_with_random_from_distribution_custom_mod(
+        &mut samples,
+        distribution,
+        ciphertext_modulus,
+    );
+
+    assert!(check_clear_content_respects_mod(
+        &samples,
+        ciphertext_modulus
+    ));
+
+    let samples: Vec<f64> = samples
+        .iter()
+        .map(|&x| modular_value_to_signed_f64(x, ciphertext_modulus))
+        .collect();
+
+    // The distribution is cut at 6 standard deviations
+    assert!(samples.iter().all(|&x| x.abs() <= 6.0 * modular_std_dev));
+
+    let mean = samples.iter().sum::<f64>() / NUMBER_OF_SAMPLES as f64;
+    let variance = samples.iter().map(|x| x * x).sum::<f64>() / NUMBER_OF_SAMPLES as f64;
+    let expected_variance = modular_std_dev * modular_std_dev;
+    assert!(mean.abs() < 5.0, "mean={mean}");
+    assert!(
+        (variance - expected_variance).abs() / expected_variance < 0.01,
+        "variance={variance}, expected_variance={expected_variance}"
+    );
+}
+
+#[test]
+fn test_discrete_gaussian_random_native_u32() {
+    test_discrete_gaussian_random::<u32>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_discrete_gaussian_random_native_u64() {
+    test_discrete_gaussian_random::<u64>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_discrete_gaussian_random_native_u128() {
+    test_discrete_gaussian_random::<u128>(CiphertextModulus::new_native());
+}
+
+#[test]
+fn test_discrete_gaussian_random_solinas_custom_mod_u64() {
+    test_discrete_gaussian_random::<u64>(
+        CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap(),
+    );
+}
+
+#[test]
+fn test_discrete_gaussian_random_small_std_dev() {
+    const NUMBER_OF_SAMPLES: usize = 100_000;
+    let mut rng = new_random_generator();
+
+    for modular_std_dev in [0.0, 0.5, 2.0] {
+        let distribution = DiscreteGaussian {
+            std: modular_std_dev / 2f64.powi(64),
+        };
+
+        let mut samples = vec![0u64; NUMBER_OF_SAMPLES];
+        rng.fill_slice_with_random_from_distribution(&mut samples, distribution);
+
+        let samples: Vec<f64> = samples.iter().map(|&x| x as i64 as f64).collect();
+
+        let bound = (6.0 * modular_std_dev).ceil();
+        assert!(samples.iter().all(|&x| x.abs() <= bound));
+
+        // Exact variance of the discrete gaussian cut at 6 standard deviations, which differs from
+        // the variance of the continuous gaussian for small standard deviations
+        let expected_variance = if modular_std_dev == 0.0 {
+            0.0
+        } else {
+            let support = (-(bound as i64)..=bound as i64).map(|x| x as f64);
+            let weight = |x: f64| (-x * x / (2.0 * modular_std_dev * modular_std_dev)).exp();
+            support.clone().map(|x| x * x * weight(x)).sum::<f64>()
+                / support.map(weight).sum::<f64>()
+        };
+        let variance = samples.iter().map(|x| x * x).sum::<f64>() / NUMBER_OF_SAMPLES as f64;
+        assert!(
+            (variance - expected_variance).abs() <= 0.02 * expected_variance,
+            "std_dev={modular_std_dev}, variance={variance}, expected_variance={expected_variance}"
+        );
+    }
+}
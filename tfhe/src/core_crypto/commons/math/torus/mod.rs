@@ -13,7 +13,7 @@
 //! floating point representation.
 
 use crate::core_crypto::commons::math::random::{
-    Gaussian, RandomGenerable, Uniform, UniformBinary, UniformTernary,
+    DiscreteGaussian, Gaussian, RandomGenerable, TUniform, Uniform, UniformBinary, UniformTernary,
 };
 pub use crate::core_crypto::commons::numeric::{CastInto, FloatingPoint, Numeric, UnsignedInteger};
 use std::fmt::{Debug, Display};
@@ -123,6 +123,8 @@ pub trait UnsignedTorus:
     + FromTorus<f64>
     + IntoTorus<f64>
     + RandomGenerable<Gaussian<f64>, CustomModulus = Self>
+    + RandomGenerable<DiscreteGaussian<f64>, CustomModulus = Self>
+    + RandomGenerable<TUniform, CustomModulus = Self>
     + RandomGenerable<UniformBinary, CustomModulus = Self>
     + RandomGenerable<UniformTernary, CustomModulus = Self>
     + RandomGenerable<Uniform, CustomModulus = Self>
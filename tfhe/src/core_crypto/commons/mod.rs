@@ -44,8 +44,11 @@ pub mod computation_buffers;
 pub mod dispersion;
 pub mod generators;
 pub mod math;
+pub mod noise_formulas;
 pub mod numeric;
 pub mod parameters;
+pub mod raw_key_format;
+pub mod security_estimator;
 pub mod utils;
 
 // Refactor modules
@@ -0,0 +1,79 @@
+//! Noise formulas for the encryption of [`LWE
+//! ciphertexts`](`crate::core_crypto::entities::LweCiphertext`).
+
+use super::BINARY_KEY_SQUARED_MEAN;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::parameters::{LweDimension, LwePublicKeyZeroEncryptionCount};
+
+/// Return the [`Variance`] of the noise of an LWE ciphertext encrypted with
+/// [`encrypt_lwe_ciphertext`](`crate::core_crypto::algorithms::encrypt_lwe_ciphertext`).
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::encrypt_lwe_ciphertext_output_variance;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let variance = encrypt_lwe_ciphertext_output_variance(StandardDev(2.0f64.powi(-20)));
+/// assert_eq!(variance.get_variance(), 2.0f64.powi(-40));
+/// ```
+pub fn encrypt_lwe_ciphertext_output_variance(
+    noise_parameters: impl DispersionParameter,
+) -> Variance {
+    Variance(noise_parameters.get_variance())
+}
+
+/// Return the [`Variance`] of the noise of an LWE ciphertext encrypted with
+/// [`encrypt_lwe_ciphertext_with_public_key`](`crate::core_crypto::algorithms::encrypt_lwe_ciphertext_with_public_key`).
+///
+/// The encryption sums a random subset of the zero encryptions of the public key, each one being
+/// selected with probability $\frac{1}{2}$, the output variance is therefore
+/// $\frac{m}{2}\sigma^2$ with $m$ the number of zero encryptions and $\sigma^2$ the variance used
+/// to generate the public key.
+pub fn encrypt_lwe_ciphertext_with_public_key_output_variance(
+    public_key_noise_parameters: impl DispersionParameter,
+    zero_encryption_count: LwePublicKeyZeroEncryptionCount,
+) -> Variance {
+    Variance(
+        zero_encryption_count.0 as f64
+            * BINARY_KEY_SQUARED_MEAN
+            * public_key_noise_parameters.get_variance(),
+    )
+}
+
+/// Return the [`Variance`] of the noise of an LWE ciphertext encrypted with a compact public key.
+///
+/// See
+/// [`encrypt_lwe_ciphertext_with_compact_public_key`](`crate::core_crypto::algorithms::encrypt_lwe_ciphertext_with_compact_public_key`).
+///
+/// With $n$ the [`LweDimension`] of the compact public key, the public key noise is multiplied by
+/// the binary random vector drawn during the encryption and the mask noise is multiplied by the
+/// binary secret key at decryption, the output variance is therefore
+/// $\frac{n}{2}\left(\sigma\_{pk}^2 + \sigma\_{mask}^2\right) + \sigma\_{body}^2$.
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::encrypt_lwe_ciphertext_with_compact_public_key_output_variance;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let noise = StandardDev(2.0f64.powi(-40));
+/// let variance = encrypt_lwe_ciphertext_with_compact_public_key_output_variance(
+///     LweDimension(1024),
+///     noise,
+///     noise,
+///     noise,
+/// );
+/// // With the same noise everywhere the variance is multiplied by the LweSize
+/// assert_eq!(variance.get_variance(), 1025.0 * noise.get_variance());
+/// ```
+pub fn encrypt_lwe_ciphertext_with_compact_public_key_output_variance(
+    lwe_dimension: LweDimension,
+    public_key_noise_parameters: impl DispersionParameter,
+    mask_noise_parameters: impl DispersionParameter,
+    body_noise_parameters: impl DispersionParameter,
+) -> Variance {
+    let lwe_dimension = lwe_dimension.0 as f64;
+    Variance(
+        lwe_dimension
+            * BINARY_KEY_SQUARED_MEAN
+            * (public_key_noise_parameters.get_variance() + mask_noise_parameters.get_variance())
+            + body_noise_parameters.get_variance(),
+    )
+}
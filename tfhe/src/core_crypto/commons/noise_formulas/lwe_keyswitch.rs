@@ -0,0 +1,107 @@
+//! Noise formulas for the [`LWE
+//! keyswitch`](`crate::core_crypto::entities::LweKeyswitchKey#lwe-keyswitch`).
+
+use super::{
+    ciphertext_modulus_as_f64, decomposition_error_variance, decomposition_term_squared_mean,
+    BINARY_KEY_SQUARED_MEAN,
+};
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+};
+
+/// Return the [`Variance`] added by
+/// [`keyswitch_lwe_ciphertext`](`crate::core_crypto::algorithms::keyswitch_lwe_ciphertext`) to
+/// the noise of its input.
+///
+/// With $n$ the input [`LweDimension`], $B$ the decomposition base, $l$ the level count and
+/// $\sigma\_{ksk}^2$ the variance used to generate the key-switching key, the added variance is:
+/// $$
+/// n \cdot l \cdot \frac{B^2 + 2}{12} \cdot \sigma\_{ksk}^2 + \frac{n}{2} \cdot \frac{1}{12}
+/// \left(\frac{1}{B^{2l}} - \frac{1}{q^2}\right)
+/// $$
+/// the first term comes from the key-switching key noise multiplied by the decomposition terms of
+/// the input mask, the second from the decomposition error of the input mask multiplied by the
+/// input secret key.
+pub fn keyswitch_lwe_ciphertext_additive_variance<Scalar: UnsignedInteger>(
+    input_lwe_dimension: LweDimension,
+    keyswitch_key_noise_parameters: impl DispersionParameter,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Variance {
+    let input_lwe_dimension = input_lwe_dimension.0 as f64;
+    let ciphertext_modulus = ciphertext_modulus_as_f64(ciphertext_modulus);
+
+    let key_noise_variance = input_lwe_dimension
+        * decomposition_level_count.0 as f64
+        * decomposition_term_squared_mean(decomposition_base_log)
+        * keyswitch_key_noise_parameters.get_variance();
+
+    let decomposition_error_variance = input_lwe_dimension
+        * BINARY_KEY_SQUARED_MEAN
+        * decomposition_error_variance(
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        );
+
+    Variance(key_noise_variance + decomposition_error_variance)
+}
+
+/// Return the [`Variance`] of the noise of the output of
+/// [`keyswitch_lwe_ciphertext`](`crate::core_crypto::algorithms::keyswitch_lwe_ciphertext`) given
+/// the [`Variance`] of the noise of its input.
+///
+/// See [`keyswitch_lwe_ciphertext_additive_variance`] for the formula of the added noise.
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::{
+///     encrypt_lwe_ciphertext_output_variance, keyswitch_lwe_ciphertext_output_variance,
+/// };
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let lwe_modular_std_dev = StandardDev(0.000007069849454709433);
+/// let ks_decomp_base_log = DecompositionBaseLog(3);
+/// let ks_decomp_level_count = DecompositionLevelCount(5);
+/// let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+///
+/// let input_variance = encrypt_lwe_ciphertext_output_variance(glwe_modular_std_dev);
+///
+/// let output_variance = keyswitch_lwe_ciphertext_output_variance(
+///     input_variance,
+///     glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+///     lwe_modular_std_dev,
+///     ks_decomp_base_log,
+///     ks_decomp_level_count,
+///     ciphertext_modulus,
+/// );
+///
+/// // The keyswitch noise dominates the input noise
+/// assert!(output_variance.get_variance() > 1000.0 * input_variance.get_variance());
+/// // The noise stays far below the 1/32 bound of a 4 bits message with a padding bit
+/// assert!(output_variance.get_standard_dev() < 2.0f64.powi(-5) / 10.0);
+/// ```
+pub fn keyswitch_lwe_ciphertext_output_variance<Scalar: UnsignedInteger>(
+    input_variance: Variance,
+    input_lwe_dimension: LweDimension,
+    keyswitch_key_noise_parameters: impl DispersionParameter,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Variance {
+    let additive_variance = keyswitch_lwe_ciphertext_additive_variance(
+        input_lwe_dimension,
+        keyswitch_key_noise_parameters,
+        decomposition_base_log,
+        decomposition_level_count,
+        ciphertext_modulus,
+    );
+
+    Variance(input_variance.0 + additive_variance.0)
+}
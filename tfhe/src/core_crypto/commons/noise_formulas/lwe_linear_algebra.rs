@@ -0,0 +1,88 @@
+//! Noise formulas for the [`linear algebra`](`crate::core_crypto::algorithms::lwe_linear_algebra`)
+//! primitives on LWE ciphertexts.
+
+use crate::core_crypto::commons::dispersion::Variance;
+
+/// Return the [`Variance`] of the noise of the sum or difference of two LWE ciphertexts.
+///
+/// This applies to [`lwe_ciphertext_add`](`crate::core_crypto::algorithms::lwe_ciphertext_add`)
+/// and [`lwe_ciphertext_sub`](`crate::core_crypto::algorithms::lwe_ciphertext_sub`) given the
+/// [`Variance`] of the noise of independent inputs.
+///
+/// Adding or subtracting a plaintext does not change the noise.
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::lwe_ciphertext_add_output_variance;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let variance = lwe_ciphertext_add_output_variance(Variance(1.0), Variance(2.0));
+/// assert_eq!(variance, Variance(3.0));
+/// ```
+pub fn lwe_ciphertext_add_output_variance(
+    lhs_variance: Variance,
+    rhs_variance: Variance,
+) -> Variance {
+    Variance(lhs_variance.0 + rhs_variance.0)
+}
+
+/// Return the [`Variance`] of the noise of an LWE ciphertext multiplied by a cleartext.
+///
+/// See
+/// [`lwe_ciphertext_cleartext_mul`](`crate::core_crypto::algorithms::lwe_ciphertext_cleartext_mul`),
+/// the input noise [`Variance`] is multiplied by the square of the cleartext.
+///
+/// The cleartext is given as a signed value, as multiplying by the unsigned representation of a
+/// negative cleartext modulo $q$ yields the same ciphertext.
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::lwe_ciphertext_cleartext_mul_output_variance;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let variance = lwe_ciphertext_cleartext_mul_output_variance(Variance(1.0), -3);
+/// assert_eq!(variance, Variance(9.0));
+/// ```
+pub fn lwe_ciphertext_cleartext_mul_output_variance(
+    input_variance: Variance,
+    cleartext: i64,
+) -> Variance {
+    let cleartext = cleartext as f64;
+    Variance(cleartext * cleartext * input_variance.0)
+}
+
+/// Return the [`Variance`] of the noise of a linear combination of independent LWE ciphertexts.
+///
+/// For $\sum\_{i} w\_i \cdot \mathsf{CT}\_i$ given the [`Variance`] $\sigma\_i^2$ of the noise of
+/// each input, the output variance is $\sum\_{i} w\_i^2 \sigma\_i^2$.
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::lwe_ciphertext_linear_combination_output_variance;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let variance = lwe_ciphertext_linear_combination_output_variance(
+///     &[Variance(1.0), Variance(2.0)],
+///     &[2, -1],
+/// );
+/// assert_eq!(variance, Variance(6.0));
+/// ```
+pub fn lwe_ciphertext_linear_combination_output_variance(
+    input_variances: &[Variance],
+    cleartexts: &[i64],
+) -> Variance {
+    assert_eq!(
+        input_variances.len(),
+        cleartexts.len(),
+        "Mismatched number of input variances ({}) and cleartexts ({})",
+        input_variances.len(),
+        cleartexts.len(),
+    );
+
+    Variance(
+        input_variances
+            .iter()
+            .zip(cleartexts.iter())
+            .map(|(&input_variance, &cleartext)| {
+                lwe_ciphertext_cleartext_mul_output_variance(input_variance, cleartext).0
+            })
+            .sum(),
+    )
+}
@@ -0,0 +1,52 @@
+//! Noise formulas for the modulus switch of LWE ciphertexts.
+
+use super::{ciphertext_modulus_as_f64, BINARY_KEY_SQUARED_MEAN};
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{CiphertextModulusLog, LweDimension};
+
+/// Return the [`Variance`] added when switching an LWE ciphertext to a smaller power of 2 modulus.
+///
+/// The ciphertext is switched from the ciphertext modulus $q$ to the modulus $2^{w}$, as done by
+/// [`lwe_ciphertext_modulus_switch`](`crate::core_crypto::algorithms::lwe_ciphertext_modulus_switch`)
+/// or at the start of a programmable bootstrap where $2^{w} = 2N$.
+///
+/// Each coefficient is rounded to a multiple of $\frac{1}{2^{w}}$ on the torus, with $n$ the
+/// [`LweDimension`] the added variance is:
+/// $$
+/// \left(1 + \frac{n}{2}\right) \cdot \frac{1}{12} \left(\frac{1}{2^{2w}} - \frac{1}{q^2}\right)
+/// $$
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::lwe_ciphertext_modulus_switch_additive_variance;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let lwe_dimension = LweDimension(742);
+/// let polynomial_size = PolynomialSize(2048);
+/// let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+///
+/// // The blind rotation of a programmable bootstrap works modulo 2N
+/// let modulus_switch_variance = lwe_ciphertext_modulus_switch_additive_variance(
+///     lwe_dimension,
+///     CiphertextModulusLog(polynomial_size.log2().0 + 1),
+///     ciphertext_modulus,
+/// );
+///
+/// // The rounding error to 1/(2N) dominates, it is the main source of PBS failures
+/// assert!(modulus_switch_variance.get_standard_dev() > 2.0f64.powi(-15));
+/// assert!(modulus_switch_variance.get_standard_dev() < 2.0f64.powi(-5) / 10.0);
+/// ```
+pub fn lwe_ciphertext_modulus_switch_additive_variance<Scalar: UnsignedInteger>(
+    lwe_dimension: LweDimension,
+    output_ciphertext_modulus_log: CiphertextModulusLog,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Variance {
+    let ciphertext_modulus = ciphertext_modulus_as_f64(ciphertext_modulus);
+    let rounding_variance = ((2.0f64.powi(-2 * output_ciphertext_modulus_log.0 as i32)
+        - ciphertext_modulus.powi(-2))
+        / 12.0)
+        .max(0.0);
+
+    Variance((1.0 + lwe_dimension.0 as f64 * BINARY_KEY_SQUARED_MEAN) * rounding_variance)
+}
@@ -0,0 +1,107 @@
+//! Noise formulas for the [`multi-bit programmable
+//! bootstrapping`](`crate::core_crypto::entities::LweMultiBitBootstrapKey`).
+
+use super::{
+    ciphertext_modulus_as_f64, decomposition_error_variance, decomposition_term_squared_mean,
+    BINARY_KEY_SQUARED_MEAN,
+};
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
+    LweDimension, PolynomialSize,
+};
+
+/// Return the [`Variance`] of the noise of the output of
+/// [`multi_bit_programmable_bootstrap_lwe_ciphertext`](`crate::core_crypto::algorithms::multi_bit_programmable_bootstrap_lwe_ciphertext`).
+///
+/// With $g$ the [`LweBskGroupingFactor`], the blind rotation performs $\frac{n}{g}$ external
+/// products, each one with a GGSW ciphertext computed as the sum of the $2^g$ GGSW ciphertexts of
+/// a group, each one rotated by a monomial. Compared to
+/// [`external_product_glwe_ciphertext_additive_variance`](`super::external_product_glwe_ciphertext_additive_variance`)
+/// the key noise term of each external product is therefore multiplied by $2^g$ while the
+/// decomposition error term is unchanged.
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::{
+///     multi_bit_programmable_bootstrap_lwe_ciphertext_output_variance,
+///     programmable_bootstrap_lwe_ciphertext_output_variance,
+/// };
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let lwe_dimension = LweDimension(888);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_decomp_base_log = DecompositionBaseLog(21);
+/// let pbs_decomp_level_count = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+///
+/// let classic_variance = programmable_bootstrap_lwe_ciphertext_output_variance(
+///     lwe_dimension,
+///     glwe_dimension,
+///     polynomial_size,
+///     glwe_modular_std_dev,
+///     pbs_decomp_base_log,
+///     pbs_decomp_level_count,
+///     ciphertext_modulus,
+/// );
+///
+/// let multi_bit_variance = multi_bit_programmable_bootstrap_lwe_ciphertext_output_variance(
+///     lwe_dimension,
+///     glwe_dimension,
+///     polynomial_size,
+///     LweBskGroupingFactor(3),
+///     glwe_modular_std_dev,
+///     pbs_decomp_base_log,
+///     pbs_decomp_level_count,
+///     ciphertext_modulus,
+/// );
+///
+/// // Each external product uses the sum of 8 GGSW ciphertexts, but the decomposition error
+/// // dominates with these parameters and there are 3 times fewer external products
+/// assert!(multi_bit_variance.get_variance() < classic_variance.get_variance());
+/// ```
+#[allow(clippy::too_many_arguments)]
+pub fn multi_bit_programmable_bootstrap_lwe_ciphertext_output_variance<Scalar: UnsignedInteger>(
+    input_lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    grouping_factor: LweBskGroupingFactor,
+    bootstrap_key_noise_parameters: impl DispersionParameter,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Variance {
+    assert!(
+        input_lwe_dimension.0 % grouping_factor.0 == 0,
+        "Multi Bit PBS requires input LweDimension ({}) to be a multiple of {}",
+        input_lwe_dimension.0,
+        grouping_factor.0,
+    );
+
+    let glwe_dimension = glwe_dimension.0 as f64;
+    let polynomial_size = polynomial_size.0 as f64;
+    let ciphertext_modulus = ciphertext_modulus_as_f64(ciphertext_modulus);
+    let ggsw_per_group = grouping_factor.ggsw_per_multi_bit_element().0 as f64;
+
+    let key_noise_variance = ggsw_per_group
+        * decomposition_level_count.0 as f64
+        * (glwe_dimension + 1.0)
+        * polynomial_size
+        * decomposition_term_squared_mean(decomposition_base_log)
+        * bootstrap_key_noise_parameters.get_variance();
+
+    let decomposition_error_variance = (1.0
+        + glwe_dimension * polynomial_size * BINARY_KEY_SQUARED_MEAN)
+        * decomposition_error_variance(
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        );
+
+    let external_product_count = (input_lwe_dimension.0 / grouping_factor.0) as f64;
+
+    Variance(external_product_count * (key_noise_variance + decomposition_error_variance))
+}
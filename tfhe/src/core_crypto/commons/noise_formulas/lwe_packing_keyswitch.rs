@@ -0,0 +1,40 @@
+//! Noise formulas for the packing keyswitch of LWE ciphertexts into GLWE ciphertexts using an
+//! [`LwePackingKeyswitchKey`](`crate::core_crypto::entities::LwePackingKeyswitchKey`).
+
+use super::keyswitch_lwe_ciphertext_additive_variance;
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+};
+
+/// Return the [`Variance`] of the noise of each coefficient of a packing keyswitch output.
+///
+/// This applies to
+/// [`keyswitch_lwe_ciphertext_into_glwe_ciphertext`](`crate::core_crypto::algorithms::keyswitch_lwe_ciphertext_into_glwe_ciphertext`)
+/// and
+/// [`keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`](`crate::core_crypto::algorithms::keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext`)
+/// given the [`Variance`] of the noise of the input LWE ciphertexts.
+///
+/// Each output coefficient is obtained by key switching a single input ciphertext, the added
+/// variance is the same as for an LWE keyswitch, see
+/// [`keyswitch_lwe_ciphertext_additive_variance`].
+pub fn keyswitch_lwe_ciphertext_into_glwe_ciphertext_output_variance<Scalar: UnsignedInteger>(
+    input_variance: Variance,
+    input_lwe_dimension: LweDimension,
+    packing_keyswitch_key_noise_parameters: impl DispersionParameter,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Variance {
+    let additive_variance = keyswitch_lwe_ciphertext_additive_variance(
+        input_lwe_dimension,
+        packing_keyswitch_key_noise_parameters,
+        decomposition_base_log,
+        decomposition_level_count,
+        ciphertext_modulus,
+    );
+
+    Variance(input_variance.0 + additive_variance.0)
+}
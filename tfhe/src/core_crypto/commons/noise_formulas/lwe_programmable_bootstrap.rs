@@ -0,0 +1,147 @@
+//! Noise formulas for the [`programmable
+//! bootstrapping`](`crate::core_crypto::entities::LweBootstrapKey#programmable-bootstrapping`).
+
+use super::{
+    ciphertext_modulus_as_f64, decomposition_error_variance, decomposition_term_squared_mean,
+    BINARY_KEY_SQUARED_MEAN,
+};
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
+};
+
+/// Return the [`Variance`] added by an external product between a GLWE ciphertext and a GGSW
+/// ciphertext encrypting 1.
+///
+/// This is also an upper bound for a GGSW ciphertext encrypting 0, for which the decomposition
+/// error term vanishes.
+///
+/// With $k$ the [`GlweDimension`], $N$ the [`PolynomialSize`], $B$ the decomposition base, $l$ the
+/// level count and $\sigma\_{ggsw}^2$ the variance used to encrypt the GGSW ciphertext, the added
+/// variance is:
+/// $$
+/// l \cdot (k + 1) \cdot N \cdot \frac{B^2 + 2}{12} \cdot \sigma\_{ggsw}^2 + \left(1 + \frac{k
+/// N}{2}\right) \cdot \frac{1}{12} \left(\frac{1}{B^{2l}} - \frac{1}{q^2}\right)
+/// $$
+/// the first term comes from the GGSW noise multiplied by the decomposition terms of the GLWE
+/// ciphertext, the second from the decomposition error of the GLWE ciphertext multiplied by the
+/// secret key.
+pub fn external_product_glwe_ciphertext_additive_variance<Scalar: UnsignedInteger>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    ggsw_noise_parameters: impl DispersionParameter,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Variance {
+    let (key_noise_variance, decomposition_error_variance) = external_product_variance_terms(
+        glwe_dimension,
+        polynomial_size,
+        ggsw_noise_parameters,
+        decomposition_base_log,
+        decomposition_level_count,
+        ciphertext_modulus,
+    );
+
+    Variance(key_noise_variance + decomposition_error_variance)
+}
+
+/// Return the key noise and decomposition error terms of
+/// [`external_product_glwe_ciphertext_additive_variance`] for a GGSW ciphertext encrypting 1.
+fn external_product_variance_terms<Scalar: UnsignedInteger>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    ggsw_noise_parameters: impl DispersionParameter,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> (f64, f64) {
+    let glwe_dimension = glwe_dimension.0 as f64;
+    let polynomial_size = polynomial_size.0 as f64;
+    let ciphertext_modulus = ciphertext_modulus_as_f64(ciphertext_modulus);
+
+    let key_noise_variance = decomposition_level_count.0 as f64
+        * (glwe_dimension + 1.0)
+        * polynomial_size
+        * decomposition_term_squared_mean(decomposition_base_log)
+        * ggsw_noise_parameters.get_variance();
+
+    let decomposition_error_variance = (1.0
+        + glwe_dimension * polynomial_size * BINARY_KEY_SQUARED_MEAN)
+        * decomposition_error_variance(
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        );
+
+    (key_noise_variance, decomposition_error_variance)
+}
+
+/// Return the [`Variance`] of the noise of the output of
+/// [`programmable_bootstrap_lwe_ciphertext`](`crate::core_crypto::algorithms::programmable_bootstrap_lwe_ciphertext`).
+///
+/// The output noise does not depend on the input noise, the blind rotation performs one CMUX per
+/// coefficient of the input LWE secret key, with $n$ the input [`LweDimension`] the output variance
+/// is $n$ times the variance given by [`external_product_glwe_ciphertext_additive_variance`] where
+/// the decomposition error term is halved: the GGSW ciphertexts encrypt the bits of the input key
+/// which multiply the decomposition error and have a mean square of $\frac{1}{2}$.
+///
+/// The errors introduced by the floating point FFT are not taken into account, with 64 bits
+/// integers they are not negligible for large decomposition bases, see
+/// [`programmable_bootstrap_f128_lwe_ciphertext`](`crate::core_crypto::algorithms::programmable_bootstrap_f128_lwe_ciphertext`)
+/// for a bootstrap with a smaller FFT error.
+///
+/// The input noise must however be small enough for the input ciphertext to be correctly
+/// decrypted after the modulus switch to $2N$, see
+/// [`lwe_ciphertext_modulus_switch_additive_variance`](`super::lwe_ciphertext_modulus_switch_additive_variance`).
+///
+/// ```
+/// use tfhe::core_crypto::commons::noise_formulas::programmable_bootstrap_lwe_ciphertext_output_variance;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let lwe_dimension = LweDimension(742);
+/// let glwe_dimension = GlweDimension(1);
+/// let polynomial_size = PolynomialSize(2048);
+/// let glwe_modular_std_dev = StandardDev(0.00000000000000029403601535432533);
+/// let pbs_decomp_base_log = DecompositionBaseLog(23);
+/// let pbs_decomp_level_count = DecompositionLevelCount(1);
+/// let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+///
+/// let output_variance = programmable_bootstrap_lwe_ciphertext_output_variance(
+///     lwe_dimension,
+///     glwe_dimension,
+///     polynomial_size,
+///     glwe_modular_std_dev,
+///     pbs_decomp_base_log,
+///     pbs_decomp_level_count,
+///     ciphertext_modulus,
+/// );
+///
+/// // The noise stays far below the 1/32 bound of a 4 bits message with a padding bit
+/// assert!(output_variance.get_standard_dev() < 2.0f64.powi(-5) / 10.0);
+/// ```
+pub fn programmable_bootstrap_lwe_ciphertext_output_variance<Scalar: UnsignedInteger>(
+    input_lwe_dimension: LweDimension,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    bootstrap_key_noise_parameters: impl DispersionParameter,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> Variance {
+    let (key_noise_variance, decomposition_error_variance) = external_product_variance_terms(
+        glwe_dimension,
+        polynomial_size,
+        bootstrap_key_noise_parameters,
+        decomposition_base_log,
+        decomposition_level_count,
+        ciphertext_modulus,
+    );
+
+    Variance(
+        input_lwe_dimension.0 as f64
+            * (key_noise_variance + BINARY_KEY_SQUARED_MEAN * decomposition_error_variance),
+    )
+}
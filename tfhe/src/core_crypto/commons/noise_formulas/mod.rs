@@ -0,0 +1,81 @@
+//! Analytic formulas predicting the noise of the outputs of `core_crypto` primitives.
+//!
+//! All the formulas return the
+//! [`Variance`](`crate::core_crypto::commons::dispersion::Variance`) of the output noise expressed
+//! on the torus, i.e. as a fraction of the ciphertext modulus, which is the representation used for
+//! the noise parameters given to the encryption primitives (e.g.
+//! `StandardDev(2.0f64.powi(-40))`). The returned variance can therefore be compared against the
+//! noise budget of an encoding: a message encoded with a scaling factor $\Delta$ is decrypted
+//! correctly as long as the noise stays below $\frac{\Delta}{2q}$ on the torus.
+//!
+//! # Assumptions
+//!
+//! The formulas assume uniform binary secret keys as generated by
+//! [`allocate_and_generate_new_binary_lwe_secret_key`](`crate::core_crypto::algorithms::allocate_and_generate_new_binary_lwe_secret_key`)
+//! and
+//! [`allocate_and_generate_new_binary_glwe_secret_key`](`crate::core_crypto::algorithms::allocate_and_generate_new_binary_glwe_secret_key`),
+//! and model the error introduced by an approximate signed decomposition with base $B$ and $l$
+//! levels as uniform in $\left[-\frac{1}{2B^l}, \frac{1}{2B^l}\right)$ while the decomposition
+//! terms are uniform in $\left[-\frac{B}{2}, \frac{B}{2}\right]$. The errors introduced by the
+//! floating point FFT are not taken into account.
+//!
+//! # Decomposition Variances
+//!
+//! For a base $B$ and $l$ levels on a ciphertext modulus $q$, the decomposition terms have a mean
+//! square of $\frac{B^2 + 2}{12}$ and the decomposition error has a variance of
+//! $\frac{1}{12}\left(\frac{1}{B^{2l}} - \frac{1}{q^2}\right)$ on the torus.
+
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{DecompositionBaseLog, DecompositionLevelCount};
+
+pub use lwe_encryption::*;
+pub use lwe_keyswitch::*;
+pub use lwe_linear_algebra::*;
+pub use lwe_modulus_switch::*;
+pub use lwe_multi_bit_programmable_bootstrap::*;
+pub use lwe_packing_keyswitch::*;
+pub use lwe_programmable_bootstrap::*;
+
+mod lwe_encryption;
+mod lwe_keyswitch;
+mod lwe_linear_algebra;
+mod lwe_modulus_switch;
+mod lwe_multi_bit_programmable_bootstrap;
+mod lwe_packing_keyswitch;
+mod lwe_programmable_bootstrap;
+#[cfg(test)]
+mod tests;
+
+/// The mean of the square of a coefficient of a uniform binary secret key.
+const BINARY_KEY_SQUARED_MEAN: f64 = 0.5;
+
+/// Return the ciphertext modulus $q$ as a float.
+fn ciphertext_modulus_as_f64<Scalar: UnsignedInteger>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> f64 {
+    if ciphertext_modulus.is_native_modulus() {
+        2.0f64.powi(Scalar::BITS as i32)
+    } else {
+        ciphertext_modulus.get_custom_modulus() as f64
+    }
+}
+
+/// Return the mean square of a term of a signed decomposition in base $B$.
+fn decomposition_term_squared_mean(decomposition_base_log: DecompositionBaseLog) -> f64 {
+    let base = 2.0f64.powi(decomposition_base_log.0 as i32);
+    (base * base + 2.0) / 12.0
+}
+
+/// Return the variance on the torus of the error made when approximating a value with a signed
+/// decomposition in base $B$ with $l$ levels.
+fn decomposition_error_variance(
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: f64,
+) -> f64 {
+    let precision_log = (decomposition_base_log.0 * decomposition_level_count.0) as i32;
+    let variance = (2.0f64.powi(-2 * precision_log) - ciphertext_modulus.powi(-2)) / 12.0;
+    // When B^l = q the decomposition is exact
+    variance.max(0.0)
+}
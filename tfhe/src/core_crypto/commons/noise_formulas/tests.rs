@@ -0,0 +1,109 @@
+use super::*;
+use crate::core_crypto::commons::dispersion::{StandardDev, Variance};
+use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, LweDimension,
+};
+use crate::core_crypto::commons::test_tools::{torus_modular_diff, variance};
+use rand::Rng;
+
+// This is 1 / 16 which is exactly representable in an f64 (even an f32)
+// 1 / 32 is too strict and fails the tests
+const RELATIVE_TOLERANCE: f64 = 0.0625;
+
+const NB_SAMPLES: usize = 100_000;
+
+// Check the decomposition variances used by all the formulas against the errors and terms of
+// actual signed decompositions of uniform values
+#[test]
+fn test_decomposition_variances_match_measured_decompositions() {
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+    let mut rng = rand::thread_rng();
+
+    for (base_log, level_count) in [(3, 5), (4, 3), (8, 2), (23, 1)] {
+        let base_log = DecompositionBaseLog(base_log);
+        let level_count = DecompositionLevelCount(level_count);
+        let decomposer = SignedDecomposer::<u64>::new(base_log, level_count);
+
+        let mut errors = Vec::with_capacity(NB_SAMPLES);
+        let mut terms_squared_sum = 0.0;
+
+        for _ in 0..NB_SAMPLES {
+            let input: u64 = rng.gen();
+            let rounded = decomposer.closest_representable(input);
+            errors.push(torus_modular_diff(input, rounded, ciphertext_modulus));
+
+            for term in decomposer.decompose(input) {
+                let signed_term = term.value() as i64 as f64;
+                terms_squared_sum += signed_term * signed_term;
+            }
+        }
+
+        let expected_error_variance = decomposition_error_variance(
+            base_log,
+            level_count,
+            ciphertext_modulus_as_f64(ciphertext_modulus),
+        );
+        let measured_error_variance = variance(&errors).0;
+        assert!(
+            (measured_error_variance - expected_error_variance).abs()
+                < RELATIVE_TOLERANCE * expected_error_variance,
+            "{base_log:?} {level_count:?}: measured decomposition error variance \
+            {measured_error_variance}, expected {expected_error_variance}"
+        );
+
+        let expected_term_squared_mean = decomposition_term_squared_mean(base_log);
+        let measured_term_squared_mean = terms_squared_sum / (NB_SAMPLES * level_count.0) as f64;
+        assert!(
+            (measured_term_squared_mean - expected_term_squared_mean).abs()
+                < RELATIVE_TOLERANCE * expected_term_squared_mean,
+            "{base_log:?} {level_count:?}: measured decomposition term squared mean \
+            {measured_term_squared_mean}, expected {expected_term_squared_mean}"
+        );
+    }
+}
+
+#[test]
+fn test_decomposition_error_variance_exact_decomposition() {
+    let modulus = ciphertext_modulus_as_f64(CiphertextModulus::<u64>::new_native());
+    assert_eq!(
+        decomposition_error_variance(
+            DecompositionBaseLog(16),
+            DecompositionLevelCount(4),
+            modulus
+        ),
+        0.0
+    );
+    assert!(
+        decomposition_error_variance(
+            DecompositionBaseLog(16),
+            DecompositionLevelCount(3),
+            modulus
+        ) > 0.0
+    );
+}
+
+#[test]
+fn test_packing_keyswitch_matches_lwe_keyswitch() {
+    let input_variance = Variance(2.0f64.powi(-40));
+    let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+    assert_eq!(
+        keyswitch_lwe_ciphertext_into_glwe_ciphertext_output_variance(
+            input_variance,
+            LweDimension(742),
+            StandardDev(2.0f64.powi(-50)),
+            DecompositionBaseLog(23),
+            DecompositionLevelCount(1),
+            ciphertext_modulus,
+        ),
+        keyswitch_lwe_ciphertext_output_variance(
+            input_variance,
+            LweDimension(742),
+            StandardDev(2.0f64.powi(-50)),
+            DecompositionBaseLog(23),
+            DecompositionLevelCount(1),
+            ciphertext_modulus,
+        )
+    );
+}
@@ -215,6 +215,32 @@ impl LweBskGroupingFactor {
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub struct GgswPerLweMultiBitBskElement(pub usize);
 
+/// The number of non-zero coefficients in a sparse secret key.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct HammingWeight(pub usize);
+
+/// The exponent $k$ of a ring automorphism $X \mapsto X^{k}$ applied on polynomials modulo
+/// $X^{N} + 1$, it must be odd and smaller than $2N$.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct AutomorphismExponent(pub usize);
+
+/// The index of a secret key share in a threshold secret sharing, indices start at 1.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Copy, Clone, Serialize, Deserialize)]
+pub struct ShareIndex(pub usize);
+
+/// The minimum number of secret key shares required to decrypt in a threshold secret sharing.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct ShareThreshold(pub usize);
+
+/// The number of secret key shares produced by a threshold secret sharing.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct ShareCount(pub usize);
+
+/// The statistical security parameter $\lambda$, statistical distances below $2^{-\lambda}$ are
+/// considered negligible.
+#[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
+pub struct StatisticalSecurityParameter(pub usize);
+
 #[derive(Debug, PartialEq, Eq, Copy, Clone, Serialize, Deserialize)]
 pub enum EncryptionKeyChoice {
     Big,
@@ -0,0 +1,596 @@
+//! Module containing a raw on-disk layout for large key entities, allowing them to be used in place
+//! from a memory mapped file without any deserialization step.
+//!
+//! A raw key is made of a fixed size header of [`RAW_KEY_HEADER_SIZE`] bytes followed by the
+//! entity data stored as-is in native endianness. The header size is a multiple of the alignment
+//! of every supported element type, so that a page aligned mapping of the file yields an aligned
+//! payload that can be reinterpreted in place as a slice of key elements.
+//!
+//! Keys in this format are not portable across platforms with different endianness, this is
+//! checked when the header is parsed.
+
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::traits::{Container, ContainerMut, IntoContainerOwned};
+use std::fmt::{Debug, Display, Formatter};
+use std::io::Write;
+use std::panic::RefUnwindSafe;
+use std::sync::Arc;
+
+/// Size in bytes of the header preceding the data of a raw key.
+pub const RAW_KEY_HEADER_SIZE: usize = 128;
+
+/// Number of entity parameters stored in the header of a raw key.
+pub(crate) const RAW_KEY_PARAMETER_COUNT: usize = 8;
+
+const RAW_KEY_MAGIC: [u8; 8] = *b"TFHE-RAW";
+const RAW_KEY_VERSION: u64 = 1;
+const RAW_KEY_ENDIANNESS_MARKER: u64 = 0x0102_0304_0506_0708;
+// The entity parameters follow the magic and the 5 fields common to all keys: version,
+// endianness marker, kind, element size and element count
+const RAW_KEY_PARAMETERS_OFFSET: usize = RAW_KEY_MAGIC.len() + 5 * std::mem::size_of::<u64>();
+
+/// The kind of entity stored in a raw key.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RawKeyKind {
+    FourierLweBootstrapKey = 1,
+    FourierLweMultiBitBootstrapKey = 2,
+    LweKeyswitchKey = 3,
+    LweBootstrapKey = 4,
+}
+
+impl RawKeyKind {
+    fn from_u64(value: u64) -> Option<Self> {
+        match value {
+            1 => Some(Self::FourierLweBootstrapKey),
+            2 => Some(Self::FourierLweMultiBitBootstrapKey),
+            3 => Some(Self::LweKeyswitchKey),
+            4 => Some(Self::LweBootstrapKey),
+            _ => None,
+        }
+    }
+}
+
+/// Errors that can occur when building a key entity over raw key bytes.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum RawKeyError {
+    TooShort { len: usize },
+    BadMagic,
+    UnsupportedVersion { version: u64 },
+    EndiannessMismatch,
+    KindMismatch { expected: RawKeyKind, got: u64 },
+    ElementSizeMismatch { expected: usize, got: u64 },
+    Misaligned,
+    LengthMismatch { expected: usize, got: usize },
+    InvalidParameters(&'static str),
+}
+
+impl Display for RawKeyError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooShort { len } => write!(
+                f,
+                "Raw key is too short: got {len} bytes, \
+                expected at least {RAW_KEY_HEADER_SIZE} bytes for the header"
+            ),
+            Self::BadMagic => write!(f, "Raw key does not start with the expected magic bytes"),
+            Self::UnsupportedVersion { version } => {
+                write!(f, "Unsupported raw key format version {version}")
+            }
+            Self::EndiannessMismatch => write!(
+                f,
+                "Raw key was written on a platform with a different endianness"
+            ),
+            Self::KindMismatch { expected, got } => {
+                write!(f, "Expected a raw key of kind {expected:?}, got kind {got}")
+            }
+            Self::ElementSizeMismatch { expected, got } => write!(
+                f,
+                "Expected raw key elements of {expected} bytes, got elements of {got} bytes"
+            ),
+            Self::Misaligned => write!(
+                f,
+                "Raw key data is not properly aligned for the key element type"
+            ),
+            Self::LengthMismatch { expected, got } => write!(
+                f,
+                "Raw key data has {got} elements, expected {expected} elements"
+            ),
+            Self::InvalidParameters(reason) => write!(f, "Invalid raw key parameters: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for RawKeyError {}
+
+/// Bytes holding raw keys, typically a memory mapped file, shared by all the keys built over them.
+pub type SharedRawKeyBytes = Arc<dyn AsRef<[u8]> + Send + Sync + RefUnwindSafe>;
+
+/// A container holding the elements of a key, either owned or used in place from
+/// [`SharedRawKeyBytes`].
+///
+/// A container built with [`Self::from_shared_bytes`] does not copy the key data, it keeps the
+/// shared bytes alive and reinterprets them on access. The data is copied to an owned container
+/// of type `C` the first time it is mutably accessed.
+#[derive(Clone)]
+pub struct RawKeyContainer<C> {
+    inner: RawKeyContainerInner<C>,
+}
+
+#[derive(Clone)]
+enum RawKeyContainerInner<C> {
+    Owned(C),
+    Shared {
+        bytes: SharedRawKeyBytes,
+        offset: usize,
+        len: usize,
+    },
+}
+
+impl<C> RawKeyContainer<C>
+where
+    C: Container,
+    C::Element: bytemuck::Pod,
+{
+    /// Wrap an owned container.
+    pub fn from_owned(container: C) -> Self {
+        Self {
+            inner: RawKeyContainerInner::Owned(container),
+        }
+    }
+
+    /// Use `data` in place, `data` must be a slice of `bytes`, as returned when validating a raw
+    /// key stored in `bytes`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `data` does not lie within `bytes`.
+    pub fn from_shared_bytes(bytes: &SharedRawKeyBytes, data: &[C::Element]) -> Self {
+        let shared: &[u8] = (**bytes).as_ref();
+        let start = shared.as_ptr() as usize;
+        let data_start = data.as_ptr() as usize;
+        let byte_len = std::mem::size_of_val(data);
+
+        assert!(
+            data_start >= start && data_start + byte_len <= start + shared.len(),
+            "The key data does not lie within the shared raw key bytes"
+        );
+
+        Self {
+            inner: RawKeyContainerInner::Shared {
+                bytes: Arc::clone(bytes),
+                offset: data_start - start,
+                len: data.len(),
+            },
+        }
+    }
+
+    /// Return whether the data is used in place from shared raw key bytes.
+    pub fn is_shared(&self) -> bool {
+        matches!(self.inner, RawKeyContainerInner::Shared { .. })
+    }
+}
+
+impl<C> AsRef<[C::Element]> for RawKeyContainer<C>
+where
+    C: Container,
+    C::Element: bytemuck::Pod,
+{
+    fn as_ref(&self) -> &[C::Element] {
+        match &self.inner {
+            RawKeyContainerInner::Owned(container) => container.as_ref(),
+            RawKeyContainerInner::Shared { bytes, offset, len } => {
+                let shared: &[u8] = (**bytes).as_ref();
+                // The alignment was checked when the raw key was validated and the shared bytes
+                // cannot move as they are behind an Arc
+                bytemuck::cast_slice(
+                    &shared[*offset..*offset + *len * std::mem::size_of::<C::Element>()],
+                )
+            }
+        }
+    }
+}
+
+impl<C> AsMut<[C::Element]> for RawKeyContainer<C>
+where
+    C: IntoContainerOwned,
+    C::Element: bytemuck::Pod,
+{
+    fn as_mut(&mut self) -> &mut [C::Element] {
+        if self.is_shared() {
+            let owned = C::collect(self.as_ref().iter().copied());
+            self.inner = RawKeyContainerInner::Owned(owned);
+        }
+
+        match &mut self.inner {
+            RawKeyContainerInner::Owned(container) => container.as_mut(),
+            RawKeyContainerInner::Shared { .. } => unreachable!(),
+        }
+    }
+}
+
+impl<C> Container for RawKeyContainer<C>
+where
+    C: Container,
+    C::Element: bytemuck::Pod,
+{
+    type Element = C::Element;
+}
+
+impl<C> ContainerMut for RawKeyContainer<C>
+where
+    C: IntoContainerOwned,
+    C::Element: bytemuck::Pod,
+{
+}
+
+impl<C> IntoContainerOwned for RawKeyContainer<C>
+where
+    C: IntoContainerOwned,
+    C::Element: bytemuck::Pod,
+{
+    fn collect<I: Iterator<Item = Self::Element>>(iter: I) -> Self {
+        Self::from_owned(C::collect(iter))
+    }
+}
+
+impl<C> PartialEq for RawKeyContainer<C>
+where
+    C: Container,
+    C::Element: bytemuck::Pod + PartialEq,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.as_ref() == other.as_ref()
+    }
+}
+
+impl<C> Eq for RawKeyContainer<C>
+where
+    C: Container,
+    C::Element: bytemuck::Pod + Eq,
+{
+}
+
+impl<C> Debug for RawKeyContainer<C>
+where
+    C: Container,
+    C::Element: bytemuck::Pod + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.as_ref().fmt(f)
+    }
+}
+
+// Serialized as the underlying container, so that owned and shared keys have the same format
+impl<C> serde::Serialize for RawKeyContainer<C>
+where
+    C: Container,
+    C::Element: bytemuck::Pod + serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.as_ref().serialize(serializer)
+    }
+}
+
+impl<'de, C> serde::Deserialize<'de> for RawKeyContainer<C>
+where
+    C: Container + serde::Deserialize<'de>,
+    C::Element: bytemuck::Pod,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        C::deserialize(deserializer).map(Self::from_owned)
+    }
+}
+
+/// Write the header of a raw key holding `element_count` elements of type `T`.
+///
+/// The data must then be written right after the header, this allows streaming the data of keys
+/// which do not fit in memory.
+pub(crate) fn write_raw_key_header<T: bytemuck::Pod, W: Write>(
+    mut writer: W,
+    kind: RawKeyKind,
+    parameters: [u64; RAW_KEY_PARAMETER_COUNT],
+    element_count: usize,
+) -> std::io::Result<()> {
+    let mut header = [0u8; RAW_KEY_HEADER_SIZE];
+    let fields = [
+        RAW_KEY_VERSION,
+        RAW_KEY_ENDIANNESS_MARKER,
+        kind as u64,
+        std::mem::size_of::<T>() as u64,
+        element_count as u64,
+    ]
+    .into_iter()
+    .chain(parameters);
+
+    header[..RAW_KEY_MAGIC.len()].copy_from_slice(&RAW_KEY_MAGIC);
+    for (dst, field) in header[RAW_KEY_MAGIC.len()..]
+        .chunks_exact_mut(std::mem::size_of::<u64>())
+        .zip(fields)
+    {
+        dst.copy_from_slice(&field.to_ne_bytes());
+    }
+
+    writer.write_all(&header)
+}
+
+/// Write a raw key made of a header describing the entity followed by its data.
+pub(crate) fn write_raw_key<T: bytemuck::Pod, W: Write>(
+    mut writer: W,
+    kind: RawKeyKind,
+    parameters: [u64; RAW_KEY_PARAMETER_COUNT],
+    data: &[T],
+) -> std::io::Result<()> {
+    write_raw_key_header::<T, _>(&mut writer, kind, parameters, data.len())?;
+    writer.write_all(bytemuck::cast_slice(data))
+}
+
+/// Return the size in bytes, header included, of the raw key starting at the beginning of `bytes`.
+///
+/// This allows splitting several raw keys written one after the other, the key itself is only
+/// validated when building the corresponding entity.
+pub fn raw_key_byte_len(bytes: &[u8]) -> Result<usize, RawKeyError> {
+    let (_, element_size, element_count) = parse_raw_key_header(bytes)?;
+
+    let overflow = || RawKeyError::InvalidParameters("key size overflows the addressable memory");
+    let total_len = element_size
+        .checked_mul(element_count)
+        .and_then(|payload_len| usize::try_from(payload_len).ok())
+        .and_then(|payload_len| payload_len.checked_add(RAW_KEY_HEADER_SIZE))
+        .ok_or_else(overflow)?;
+
+    if total_len > bytes.len() {
+        return Err(RawKeyError::TooShort { len: bytes.len() });
+    }
+
+    Ok(total_len)
+}
+
+/// Validate the part of the header common to all keys, return the stored kind, element size and
+/// element count.
+fn parse_raw_key_header(bytes: &[u8]) -> Result<(u64, u64, u64), RawKeyError> {
+    if bytes.len() < RAW_KEY_HEADER_SIZE {
+        return Err(RawKeyError::TooShort { len: bytes.len() });
+    }
+
+    if bytes[..RAW_KEY_MAGIC.len()] != RAW_KEY_MAGIC {
+        return Err(RawKeyError::BadMagic);
+    }
+
+    let field = |index: usize| {
+        let start = RAW_KEY_MAGIC.len() + index * std::mem::size_of::<u64>();
+        u64::from_ne_bytes(
+            bytes[start..start + std::mem::size_of::<u64>()]
+                .try_into()
+                .unwrap(),
+        )
+    };
+
+    // Check endianness first as other fields are garbage if it does not match
+    if field(1) != RAW_KEY_ENDIANNESS_MARKER {
+        return Err(RawKeyError::EndiannessMismatch);
+    }
+    let version = field(0);
+    if version != RAW_KEY_VERSION {
+        return Err(RawKeyError::UnsupportedVersion { version });
+    }
+
+    Ok((field(2), field(3), field(4)))
+}
+
+/// Validate the header of a raw key and reinterpret its payload in place.
+///
+/// Return the entity parameters stored in the header along with the payload, the caller is
+/// responsible for checking the parameters are consistent with the payload length.
+pub(crate) fn parse_raw_key<T: bytemuck::Pod>(
+    bytes: &[u8],
+    kind: RawKeyKind,
+) -> Result<([u64; RAW_KEY_PARAMETER_COUNT], &[T]), RawKeyError> {
+    let (stored_kind, element_size, element_count) = parse_raw_key_header(bytes)?;
+
+    if RawKeyKind::from_u64(stored_kind) != Some(kind) {
+        return Err(RawKeyError::KindMismatch {
+            expected: kind,
+            got: stored_kind,
+        });
+    }
+
+    if element_size != std::mem::size_of::<T>() as u64 {
+        return Err(RawKeyError::ElementSizeMismatch {
+            expected: std::mem::size_of::<T>(),
+            got: element_size,
+        });
+    }
+
+    let (header, payload) = bytes.split_at(RAW_KEY_HEADER_SIZE);
+
+    let payload_element_count = payload.len() / std::mem::size_of::<T>();
+    if payload.len() % std::mem::size_of::<T>() != 0
+        || element_count != payload_element_count as u64
+    {
+        return Err(RawKeyError::LengthMismatch {
+            expected: usize::try_from(element_count).unwrap_or(usize::MAX),
+            got: payload_element_count,
+        });
+    }
+
+    let mut parameters = [0u64; RAW_KEY_PARAMETER_COUNT];
+    for (parameter, bytes) in parameters
+        .iter_mut()
+        .zip(header[RAW_KEY_PARAMETERS_OFFSET..].chunks_exact(std::mem::size_of::<u64>()))
+    {
+        *parameter = u64::from_ne_bytes(bytes.try_into().unwrap());
+    }
+
+    let data = bytemuck::try_cast_slice(payload).map_err(|_| RawKeyError::Misaligned)?;
+
+    Ok((parameters, data))
+}
+
+/// Convert the leading entity parameters of a raw key to `usize`.
+pub(crate) fn raw_key_usize_parameters<const N: usize>(
+    parameters: &[u64],
+) -> Result<[usize; N], RawKeyError> {
+    let mut result = [0usize; N];
+    for (dst, &src) in result.iter_mut().zip(parameters) {
+        *dst = usize::try_from(src)
+            .map_err(|_| RawKeyError::InvalidParameters("parameter does not fit in a usize"))?;
+    }
+    Ok(result)
+}
+
+/// Split a [`CiphertextModulus`] in two parameters of a raw key, the native modulus is stored as 0.
+pub(crate) fn raw_key_modulus_parameters<Scalar: UnsignedInteger>(
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> [u64; 2] {
+    let modulus = if ciphertext_modulus.is_native_modulus() {
+        0u128
+    } else {
+        ciphertext_modulus.get_custom_modulus()
+    };
+    [modulus as u64, (modulus >> 64) as u64]
+}
+
+/// Rebuild a [`CiphertextModulus`] from the two raw key parameters written by
+/// [`raw_key_modulus_parameters`].
+pub(crate) fn raw_key_ciphertext_modulus<Scalar: UnsignedInteger>(
+    parameters: [u64; 2],
+) -> Result<CiphertextModulus<Scalar>, RawKeyError> {
+    let modulus = (parameters[1] as u128) << 64 | parameters[0] as u128;
+    CiphertextModulus::try_new(modulus).map_err(RawKeyError::InvalidParameters)
+}
+
+/// Compute the expected number of elements of a raw key payload, reporting overflows as invalid
+/// parameters.
+pub(crate) fn raw_key_expected_len(factors: &[usize]) -> Result<usize, RawKeyError> {
+    factors
+        .iter()
+        .try_fold(1usize, |acc, &factor| acc.checked_mul(factor))
+        .ok_or(RawKeyError::InvalidParameters(
+            "key size overflows the addressable memory",
+        ))
+}
+
+/// Check that the payload of a raw key has the length expected from its parameters.
+pub(crate) fn check_raw_key_len(expected: usize, got: usize) -> Result<(), RawKeyError> {
+    if expected == got {
+        Ok(())
+    } else {
+        Err(RawKeyError::LengthMismatch { expected, got })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::prelude::*;
+    use aligned_vec::avec;
+    use concrete_fft::c64;
+
+    fn to_aligned_bytes(bytes: &[u8], offset: usize) -> aligned_vec::AVec<u8> {
+        let mut aligned = avec![0u8; bytes.len() + offset];
+        aligned[offset..].copy_from_slice(bytes);
+        aligned
+    }
+
+    #[test]
+    fn test_raw_keyswitch_key_roundtrip() {
+        let mut ksk = LweKeyswitchKey::new(
+            0u64,
+            DecompositionBaseLog(4),
+            DecompositionLevelCount(3),
+            LweDimension(10),
+            LweDimension(20),
+            CiphertextModulus::try_new_power_of_2(62).unwrap(),
+        );
+        ksk.as_mut()
+            .iter_mut()
+            .enumerate()
+            .for_each(|(idx, x)| *x = idx as u64);
+
+        let mut bytes = Vec::new();
+        ksk.write_raw(&mut bytes).unwrap();
+        let aligned = to_aligned_bytes(&bytes, 0);
+
+        let loaded = LweKeyswitchKey::<&[u64]>::from_raw_bytes(&aligned).unwrap();
+        assert_eq!(loaded, ksk.as_view());
+        assert_eq!(
+            loaded.as_ref().as_ptr(),
+            aligned[RAW_KEY_HEADER_SIZE..].as_ptr().cast()
+        );
+
+        // Wrong scalar type
+        assert!(matches!(
+            LweKeyswitchKey::<&[u32]>::from_raw_bytes(&aligned),
+            Err(RawKeyError::ElementSizeMismatch { .. })
+        ));
+        // Wrong entity
+        assert!(matches!(
+            FourierLweBootstrapKeyView::from_raw_bytes(&aligned),
+            Err(RawKeyError::KindMismatch { .. })
+        ));
+        // Truncated payload
+        assert!(matches!(
+            LweKeyswitchKey::<&[u64]>::from_raw_bytes(&aligned[..aligned.len() - 8]),
+            Err(RawKeyError::LengthMismatch { .. })
+        ));
+        // Misaligned payload
+        let misaligned = to_aligned_bytes(&bytes, 4);
+        assert_eq!(
+            LweKeyswitchKey::<&[u64]>::from_raw_bytes(&misaligned[4..]),
+            Err(RawKeyError::Misaligned)
+        );
+        // Corrupted header
+        let mut corrupted = aligned.clone();
+        corrupted[0] ^= 1;
+        assert_eq!(
+            LweKeyswitchKey::<&[u64]>::from_raw_bytes(&corrupted),
+            Err(RawKeyError::BadMagic)
+        );
+    }
+
+    #[test]
+    fn test_raw_fourier_bootstrap_keys_roundtrip() {
+        let mut bsk = FourierLweBootstrapKey::new(
+            LweDimension(4),
+            GlweSize(2),
+            PolynomialSize(64),
+            DecompositionBaseLog(8),
+            DecompositionLevelCount(2),
+        );
+        bsk.as_mut_view()
+            .data()
+            .iter_mut()
+            .enumerate()
+            .for_each(|(idx, x)| *x = c64::new(idx as f64, -(idx as f64)));
+
+        let mut bytes = Vec::new();
+        bsk.write_raw(&mut bytes).unwrap();
+        let aligned = to_aligned_bytes(&bytes, 0);
+
+        let loaded = FourierLweBootstrapKeyView::from_raw_bytes(&aligned).unwrap();
+        assert_eq!(loaded, bsk.as_view());
+
+        let mut multi_bit_bsk = FourierLweMultiBitBootstrapKey::new(
+            LweDimension(4),
+            GlweSize(2),
+            PolynomialSize(64),
+            DecompositionBaseLog(8),
+            DecompositionLevelCount(2),
+            LweBskGroupingFactor(2),
+        );
+        multi_bit_bsk
+            .as_mut_view()
+            .data()
+            .iter_mut()
+            .enumerate()
+            .for_each(|(idx, x)| *x = c64::new(-(idx as f64), idx as f64));
+
+        let mut bytes = Vec::new();
+        multi_bit_bsk.write_raw(&mut bytes).unwrap();
+        let aligned = to_aligned_bytes(&bytes, 0);
+
+        let loaded = FourierLweMultiBitBootstrapKeyView::from_raw_bytes(&aligned).unwrap();
+        assert_eq!(loaded, multi_bit_bsk.as_view());
+    }
+}
@@ -0,0 +1,524 @@
+//! Estimation of the security of LWE and GLWE secret keys against lattice attacks.
+//!
+//! The estimator mirrors the cost models used by the
+//! [lattice-estimator](https://github.com/malb/lattice-estimator) to select the parameters of this
+//! library, it considers the following attacks:
+//!
+//! + the primal attack solving the unique-SVP instance given by Kannan's embedding, using the
+//!   success condition from [ADPS16](https://eprint.iacr.org/2015/1092);
+//! + the primal attack solving a BDD instance with a lattice reduction followed by a final sieve in
+//!   a larger dimension;
+//! + the dual attack finding short vectors in the dual lattice to distinguish LWE samples from
+//!   uniform ones.
+//!
+//! The cost of a BKZ reduction with block size $\beta$ in dimension $d$ is estimated as $0.292
+//! \beta + 16.4 + \log\_2(8d)$ bits (sieving cost from [BDGL16](https://eprint.iacr.org/2015/1128)),
+//! the attacker is assumed to have access to as many samples as they need. Secret keys are assumed
+//! to be uniform binary, as generated by
+//! [`allocate_and_generate_new_binary_lwe_secret_key`](`crate::core_crypto::algorithms::allocate_and_generate_new_binary_lwe_secret_key`)
+//! and
+//! [`allocate_and_generate_new_binary_glwe_secret_key`](`crate::core_crypto::algorithms::allocate_and_generate_new_binary_glwe_secret_key`).
+//!
+//! The structure of the polynomial ring is not exploited by known attacks, a GLWE secret key with
+//! [`GlweDimension`] $k$ and [`PolynomialSize`] $N$ offers the same security as an LWE secret key
+//! with dimension $kN$.
+//!
+//! This is an estimation and not a replacement for a proper review of custom parameters, results
+//! may differ by a few bits from the lattice-estimator which implements more attacks and finer cost
+//! models.
+
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::dispersion::{DispersionParameter, NoiseDistribution};
+use crate::core_crypto::commons::numeric::UnsignedInteger;
+use crate::core_crypto::commons::parameters::{GlweDimension, LweDimension, PolynomialSize};
+use std::fmt::{Display, Formatter};
+
+/// Smallest BKZ block size considered, the root Hermite factor formula is not accurate below.
+const MIN_BLOCK_SIZE: usize = 40;
+/// Standard deviation of a coefficient of a uniform binary secret key.
+const BINARY_SECRET_STD_DEV: f64 = 0.5;
+/// The attacker may use up to this factor times the LWE dimension of samples.
+const MAX_SAMPLE_FACTOR: usize = 10;
+
+/// The estimated security of an LWE instance, in bits, for each of the considered attacks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SecurityEstimate {
+    pub primal_usvp: f64,
+    pub primal_bdd: f64,
+    pub dual: f64,
+}
+
+impl SecurityEstimate {
+    /// Return the estimated security in bits, i.e. the cost of the cheapest attack.
+    pub fn bits(&self) -> f64 {
+        self.primal_usvp.min(self.primal_bdd).min(self.dual)
+    }
+}
+
+/// Error returned when a parameter set does not reach the requested security level.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SecurityError {
+    InsufficientLweSecurity { estimated_bits: f64, min_bits: u32 },
+    InsufficientGlweSecurity { estimated_bits: f64, min_bits: u32 },
+}
+
+impl Display for SecurityError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InsufficientLweSecurity {
+                estimated_bits,
+                min_bits,
+            } => {
+                write!(
+                    f,
+                    "The estimated security of the LWE secret key ({estimated_bits:.2} bits) \
+                    is below {min_bits} bits"
+                )
+            }
+            Self::InsufficientGlweSecurity {
+                estimated_bits,
+                min_bits,
+            } => {
+                write!(
+                    f,
+                    "The estimated security of the GLWE secret key ({estimated_bits:.2} bits) \
+                    is below {min_bits} bits"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for SecurityError {}
+
+/// Estimate the security of an LWE secret key with the given [`LweDimension`] used to encrypt
+/// ciphertexts with the given noise and [`CiphertextModulus`].
+///
+/// The noise is given on the torus, like for the encryption primitives.
+///
+/// ```
+/// use tfhe::core_crypto::commons::security_estimator::estimate_lwe_security;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let estimate = estimate_lwe_security(
+///     LweDimension(742),
+///     StandardDev(0.000007069849454709433),
+///     CiphertextModulus::<u64>::new_native(),
+/// );
+/// assert!(estimate.bits() >= 128.0);
+///
+/// // Reducing the noise weakens the key
+/// let estimate = estimate_lwe_security(
+///     LweDimension(742),
+///     StandardDev(2.0f64.powi(-40)),
+///     CiphertextModulus::<u64>::new_native(),
+/// );
+/// assert!(estimate.bits() < 80.0);
+/// ```
+pub fn estimate_lwe_security<Scalar: UnsignedInteger>(
+    lwe_dimension: LweDimension,
+    noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> SecurityEstimate {
+    let ln_modulus = if ciphertext_modulus.is_native_modulus() {
+        Scalar::BITS as f64 * std::f64::consts::LN_2
+    } else {
+        (ciphertext_modulus.get_custom_modulus() as f64).ln()
+    };
+
+    // The noise is given on the torus, scale it to Z_q
+    let modular_noise_std_dev = match noise_parameters.into() {
+        NoiseDistribution::Gaussian(std) | NoiseDistribution::DiscreteGaussian(std) => {
+            std.get_standard_dev() * ln_modulus.exp()
+        }
+        NoiseDistribution::TUniform(t_uniform) => t_uniform.modular_variance().sqrt(),
+    };
+
+    if lwe_dimension.0 == 0 || modular_noise_std_dev <= 0.0 {
+        return SecurityEstimate {
+            primal_usvp: 0.0,
+            primal_bdd: 0.0,
+            dual: 0.0,
+        };
+    }
+
+    let instance = LweInstance {
+        dimension: lwe_dimension.0,
+        ln_modulus,
+        ln_noise_std_dev: modular_noise_std_dev.ln(),
+        ln_secret_std_dev: BINARY_SECRET_STD_DEV.ln(),
+        max_lattice_dimension: MAX_SAMPLE_FACTOR * lwe_dimension.0,
+    };
+
+    let (primal_usvp, usvp_block_size) = match primal_usvp_cost(&instance) {
+        Some((cost, block_size)) => (cost, block_size),
+        None => (f64::INFINITY, instance.max_lattice_dimension),
+    };
+
+    SecurityEstimate {
+        primal_usvp,
+        primal_bdd: primal_bdd_cost(&instance, usvp_block_size),
+        dual: dual_cost(&instance),
+    }
+}
+
+/// Estimate the security of a GLWE secret key with the given [`GlweDimension`] and
+/// [`PolynomialSize`] used to encrypt ciphertexts with the given noise and [`CiphertextModulus`].
+///
+/// See [`estimate_lwe_security`].
+///
+/// ```
+/// use tfhe::core_crypto::commons::security_estimator::estimate_glwe_security;
+/// use tfhe::core_crypto::prelude::*;
+///
+/// let estimate = estimate_glwe_security(
+///     GlweDimension(1),
+///     PolynomialSize(2048),
+///     StandardDev(0.00000000000000029403601535432533),
+///     CiphertextModulus::<u64>::new_native(),
+/// );
+/// assert!(estimate.bits() >= 128.0);
+/// ```
+pub fn estimate_glwe_security<Scalar: UnsignedInteger>(
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> SecurityEstimate {
+    estimate_lwe_security(
+        glwe_dimension.to_equivalent_lwe_dimension(polynomial_size),
+        noise_parameters,
+        ciphertext_modulus,
+    )
+}
+
+/// Check the estimated security of an LWE and a GLWE secret key against `min_bits`, this is the
+/// building block of the `validate_security` methods of the parameter types.
+#[allow(clippy::too_many_arguments)]
+pub fn validate_lwe_and_glwe_security<Scalar: UnsignedInteger>(
+    lwe_dimension: LweDimension,
+    lwe_noise_parameters: impl Into<NoiseDistribution>,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    glwe_noise_parameters: impl Into<NoiseDistribution>,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+    min_bits: u32,
+) -> Result<(), SecurityError> {
+    let lwe_bits =
+        estimate_lwe_security(lwe_dimension, lwe_noise_parameters, ciphertext_modulus).bits();
+    if lwe_bits < min_bits as f64 {
+        return Err(SecurityError::InsufficientLweSecurity {
+            estimated_bits: lwe_bits,
+            min_bits,
+        });
+    }
+
+    let glwe_bits = estimate_glwe_security(
+        glwe_dimension,
+        polynomial_size,
+        glwe_noise_parameters,
+        ciphertext_modulus,
+    )
+    .bits();
+    if glwe_bits < min_bits as f64 {
+        return Err(SecurityError::InsufficientGlweSecurity {
+            estimated_bits: glwe_bits,
+            min_bits,
+        });
+    }
+
+    Ok(())
+}
+
+/// An LWE instance, all logarithms are natural logarithms and quantities are expressed in
+/// $\mathbb{Z}\_q$.
+struct LweInstance {
+    dimension: usize,
+    ln_modulus: f64,
+    ln_noise_std_dev: f64,
+    ln_secret_std_dev: f64,
+    max_lattice_dimension: usize,
+}
+
+/// Natural logarithm of the root Hermite factor reached by BKZ with the given block size.
+fn ln_root_hermite_factor(block_size: usize) -> f64 {
+    let beta = block_size as f64;
+    let pi = std::f64::consts::PI;
+    let e = std::f64::consts::E;
+
+    ((beta / (2.0 * pi * e)).ln() + (pi * beta).ln() / beta) / (2.0 * (beta - 1.0))
+}
+
+/// Cost in bits of a BKZ reduction with the given block size in the given dimension.
+fn bkz_cost(block_size: usize, lattice_dimension: usize) -> f64 {
+    0.292 * block_size as f64 + 16.4 + (8.0 * lattice_dimension as f64).log2()
+}
+
+/// Return $\log\_2(2^a + 2^b)$.
+fn log2_sum(a: f64, b: f64) -> f64 {
+    let max = a.max(b);
+    max + (2.0f64.powf(a - max) + 2.0f64.powf(b - max)).log2()
+}
+
+/// Dimension of the primal embedding lattice maximizing the norm of the last Gram-Schmidt vectors
+/// for the given root Hermite factor, the number of samples used is the dimension minus $n + 1$.
+///
+/// The secret is scaled by $\nu = \frac{\sigma\_e}{\sigma\_s}$ to balance it with the noise, the
+/// lattice has volume $q^m \nu^n$.
+fn primal_lattice_dimension(instance: &LweInstance, ln_root_hermite_factor: f64) -> usize {
+    let n = instance.dimension as f64;
+    let ln_scaling = instance.ln_noise_std_dev - instance.ln_secret_std_dev;
+    // ln(vol) / d = ln(q) + c / d
+    let c = n * ln_scaling - (n + 1.0) * instance.ln_modulus;
+    let optimal_dimension = (-c / ln_root_hermite_factor).sqrt();
+
+    (optimal_dimension as usize).clamp(instance.dimension + 1, instance.max_lattice_dimension)
+}
+
+/// Natural logarithm of the norm of the Gram-Schmidt vector $b^*\_{d - k}$ of a basis of the
+/// primal embedding lattice of dimension $d$ reduced with the given root Hermite factor.
+fn ln_primal_gram_schmidt_norm(
+    instance: &LweInstance,
+    lattice_dimension: usize,
+    projection_dimension: usize,
+    ln_root_hermite_factor: f64,
+) -> f64 {
+    let n = instance.dimension as f64;
+    let d = lattice_dimension as f64;
+    let m = d - n - 1.0;
+    let ln_scaling = instance.ln_noise_std_dev - instance.ln_secret_std_dev;
+    let ln_volume = m * instance.ln_modulus + n * ln_scaling;
+
+    (2.0 * projection_dimension as f64 - d - 1.0) * ln_root_hermite_factor + ln_volume / d
+}
+
+/// Return the lattice dimension for which the unique-SVP instance is solved by BKZ with the given
+/// block size if any.
+fn primal_usvp_lattice_dimension(instance: &LweInstance, block_size: usize) -> Option<usize> {
+    let ln_root_hermite_factor = ln_root_hermite_factor(block_size);
+    let lattice_dimension = primal_lattice_dimension(instance, ln_root_hermite_factor);
+    // The projection of the target vector on the last block_size Gram-Schmidt vectors has norm
+    // sigma * sqrt(block_size)
+    let ln_projected_norm = instance.ln_noise_std_dev + 0.5 * (block_size as f64).ln();
+
+    [lattice_dimension, lattice_dimension + 1]
+        .into_iter()
+        .filter(|&d| d <= instance.max_lattice_dimension)
+        .find(|&d| {
+            ln_projected_norm
+                <= ln_primal_gram_schmidt_norm(instance, d, block_size, ln_root_hermite_factor)
+        })
+}
+
+/// Return the cost in bits of the primal unique-SVP attack and the block size required.
+fn primal_usvp_cost(instance: &LweInstance) -> Option<(f64, usize)> {
+    let mut low = MIN_BLOCK_SIZE;
+    let mut high = instance.max_lattice_dimension;
+
+    primal_usvp_lattice_dimension(instance, high)?;
+
+    // The success condition is monotonic in the block size
+    while low < high {
+        let mid = (low + high) / 2;
+        if primal_usvp_lattice_dimension(instance, mid).is_some() {
+            high = mid;
+        } else {
+            low = mid + 1;
+        }
+    }
+
+    let lattice_dimension = primal_usvp_lattice_dimension(instance, low)?;
+
+    Some((bkz_cost(low, lattice_dimension), low))
+}
+
+/// Return the cost in bits of the primal BDD attack, trying block sizes up to the one required by
+/// the unique-SVP attack.
+fn primal_bdd_cost(instance: &LweInstance, max_block_size: usize) -> f64 {
+    let mut best_cost = f64::INFINITY;
+
+    for block_size in (MIN_BLOCK_SIZE..=max_block_size).step_by(2) {
+        let ln_root_hermite_factor = ln_root_hermite_factor(block_size);
+        let lattice_dimension = primal_lattice_dimension(instance, ln_root_hermite_factor);
+
+        // The final sieve in dimension eta finds the target if its projection on the last eta
+        // Gram-Schmidt vectors is the shortest vector of the projected lattice
+        let succeeds = |sieve_dimension: usize| {
+            instance.ln_noise_std_dev + 0.5 * (sieve_dimension as f64).ln()
+                <= ln_primal_gram_schmidt_norm(
+                    instance,
+                    lattice_dimension,
+                    sieve_dimension,
+                    ln_root_hermite_factor,
+                )
+        };
+
+        let mut low = block_size;
+        let mut high = lattice_dimension;
+
+        if !succeeds(high) {
+            continue;
+        }
+
+        while low < high {
+            let mid = (low + high) / 2;
+            if succeeds(mid) {
+                high = mid;
+            } else {
+                low = mid + 1;
+            }
+        }
+
+        let cost = log2_sum(bkz_cost(block_size, lattice_dimension), bkz_cost(low, low));
+        best_cost = best_cost.min(cost);
+    }
+
+    best_cost
+}
+
+/// Return the cost in bits of the dual attack.
+///
+/// BKZ is run on the dual lattice $\\{(w, v) : v = A^T w \mod q\\}$ with $v$ scaled by
+/// $\frac{\sigma\_s}{\sigma\_e}$, a vector of norm $\ell$ allows to distinguish LWE samples with
+/// advantage $\varepsilon = e^{-2\pi^2 \left(\frac{\ell \sigma\_e}{q}\right)^2}$ and
+/// $\frac{1}{\varepsilon^2}$ such vectors are required. The final sieve of BKZ outputs
+/// $2^{0.2075\beta}$ short vectors, additional reductions are run if more are needed.
+fn dual_cost(instance: &LweInstance) -> f64 {
+    let n = instance.dimension as f64;
+    let ln_scaling = instance.ln_secret_std_dev - instance.ln_noise_std_dev;
+    // ln(vol) = n * (ln(q) + ln(scaling))
+    let ln_volume = n * (instance.ln_modulus + ln_scaling);
+
+    let mut best_cost = f64::INFINITY;
+
+    for block_size in MIN_BLOCK_SIZE..=instance.max_lattice_dimension {
+        let ln_root_hermite_factor = ln_root_hermite_factor(block_size);
+        let lattice_dimension = ((ln_volume / ln_root_hermite_factor).sqrt() as usize)
+            .clamp(instance.dimension, instance.max_lattice_dimension);
+
+        let reduction_cost = bkz_cost(block_size, lattice_dimension);
+        // Costs only increase past this point
+        if reduction_cost > best_cost {
+            break;
+        }
+
+        let d = lattice_dimension as f64;
+        let ln_norm = d * ln_root_hermite_factor + ln_volume / d;
+        let ln_relative_noise = instance.ln_noise_std_dev + ln_norm - instance.ln_modulus;
+        // log2(1 / eps^2) = 4 pi^2 (l sigma / q)^2 / ln(2)
+        let log2_required_vectors =
+            4.0 * std::f64::consts::PI * std::f64::consts::PI * (2.0 * ln_relative_noise).exp()
+                / std::f64::consts::LN_2;
+        let log2_repetitions = (log2_required_vectors - 0.2075 * block_size as f64).max(0.0);
+
+        best_cost = best_cost.min(reduction_cost + log2_repetitions);
+    }
+
+    best_cost
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core_crypto::commons::dispersion::StandardDev;
+
+    #[test]
+    fn test_security_estimates_default_parameters() {
+        // Shortint PARAM_MESSAGE_2_CARRY_2_KS_PBS
+        let lwe_estimate = estimate_lwe_security(
+            LweDimension(742),
+            StandardDev(0.000007069849454709433),
+            CiphertextModulus::<u64>::new_native(),
+        );
+        let glwe_estimate = estimate_glwe_security(
+            GlweDimension(1),
+            PolynomialSize(2048),
+            StandardDev(0.00000000000000029403601535432533),
+            CiphertextModulus::<u64>::new_native(),
+        );
+
+        // Values obtained with the lattice-estimator are in the 128 to 135 bits range
+        for estimate in [lwe_estimate, glwe_estimate] {
+            assert!(estimate.bits() >= 128.0, "{estimate:?}");
+            assert!(estimate.bits() <= 140.0, "{estimate:?}");
+        }
+    }
+
+    #[test]
+    fn test_security_estimates_monotonicity() {
+        let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+        let noise = StandardDev(2.0f64.powi(-20));
+
+        let small = estimate_lwe_security(LweDimension(512), noise, ciphertext_modulus).bits();
+        let large = estimate_lwe_security(LweDimension(1024), noise, ciphertext_modulus).bits();
+        assert!(small < large);
+
+        let less_noise = estimate_lwe_security(
+            LweDimension(1024),
+            StandardDev(2.0f64.powi(-30)),
+            ciphertext_modulus,
+        )
+        .bits();
+        assert!(less_noise < large);
+    }
+
+    #[test]
+    fn test_security_estimates_custom_modulus() {
+        let noise = StandardDev(2.0f64.powi(-20));
+
+        // A power of 2 custom modulus on u64 is the same instance as the native u32 modulus
+        let native = estimate_lwe_security(
+            LweDimension(800),
+            noise,
+            CiphertextModulus::<u32>::new_native(),
+        );
+        let custom = estimate_lwe_security(
+            LweDimension(800),
+            noise,
+            CiphertextModulus::<u64>::try_new_power_of_2(32).unwrap(),
+        );
+        assert_eq!(native, custom);
+    }
+
+    #[test]
+    fn test_validate_lwe_and_glwe_security() {
+        let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+
+        assert!(validate_lwe_and_glwe_security(
+            LweDimension(742),
+            StandardDev(0.000007069849454709433),
+            GlweDimension(1),
+            PolynomialSize(2048),
+            StandardDev(0.00000000000000029403601535432533),
+            ciphertext_modulus,
+            128,
+        )
+        .is_ok());
+
+        assert!(matches!(
+            validate_lwe_and_glwe_security(
+                LweDimension(742),
+                StandardDev(2.0f64.powi(-40)),
+                GlweDimension(1),
+                PolynomialSize(2048),
+                StandardDev(0.00000000000000029403601535432533),
+                ciphertext_modulus,
+                128,
+            ),
+            Err(SecurityError::InsufficientLweSecurity { .. })
+        ));
+
+        assert!(matches!(
+            validate_lwe_and_glwe_security(
+                LweDimension(742),
+                StandardDev(0.000007069849454709433),
+                GlweDimension(1),
+                PolynomialSize(1024),
+                StandardDev(0.00000000000000029403601535432533),
+                ciphertext_modulus,
+                128,
+            ),
+            Err(SecurityError::InsufficientGlweSecurity { .. })
+        ));
+    }
+}
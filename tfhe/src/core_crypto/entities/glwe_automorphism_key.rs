@@ -0,0 +1,283 @@
+//! Module containing the definition of the [`GlweAutomorphismKey`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// A [`GLWE automorphism key`](`GlweAutomorphismKey`) allowing to apply a ring automorphism
+/// $X \mapsto X^{k}$ on [`GLWE ciphertexts`](`GlweCiphertext`) while keeping them encrypted under
+/// the same [`GlweSecretKey`].
+///
+/// This is a wrapper type of [`GlweKeyswitchKey`], [`std::ops::Deref`] and [`std::ops::DerefMut`]
+/// are implemented to dereference to the underlying [`GlweKeyswitchKey`] for ease of use. See
+/// [`GlweKeyswitchKey`] for additional methods.
+///
+/// # Formal Definition
+///
+/// ## Automorphism Key
+///
+/// For an odd exponent $k < 2N$ let $\tau\_k$ be the ring automorphism mapping a polynomial
+/// $P(X)$ to $P(X^k)$ modulo $X^N + 1$. An automorphism key for $\tau\_k$ is a
+/// [`GLWE keyswitch key`](`GlweKeyswitchKey`) from the [`GlweSecretKey`]
+/// $\tau\_k\left(\vec{S}\right) = \left( \tau\_k(S\_0), \cdots, \tau\_k(S\_{k-1}) \right)$ to the
+/// [`GlweSecretKey`] $\vec{S}$.
+///
+/// ## Automorphism Application
+///
+/// Given a [`GLWE ciphertext`](`GlweCiphertext`) $\mathsf{CT} = \left( \vec{A}, B \right) \in
+/// \mathsf{GLWE}\_{\vec{S}}\left( \mathsf{PT} \right)$, applying $\tau\_k$ to all of its
+/// polynomials yields $\left( \tau\_k(\vec{A}), \tau\_k(B) \right) \in
+/// \mathsf{GLWE}\_{\tau\_k(\vec{S})}\left( \tau\_k(\mathsf{PT}) \right)$, the automorphism key is
+/// then used to keyswitch the result back to a ciphertext encrypted under $\vec{S}$.
+///
+/// ###### inputs:
+/// - $\mathsf{CT} = \left( \vec{A} , B\right) \in \mathsf{GLWE}\_{\vec{S}}( \mathsf{PT} )$: a GLWE
+/// ciphertext
+/// - $\mathsf{AK}\_{k}$: an automorphism key for $\tau\_k$
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S}} \left( \tau\_k(\mathsf{PT})
+/// \right)$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. compute $\mathsf{CT}' = \left( \tau\_k(A\_0), \cdots, \tau\_k(A\_{k-1}), \tau\_k(B)
+/// \right)$
+/// 2. output $\mathsf{CT}\_{\mathsf{out}} = \mathsf{GlweKeyswitch}\left( \mathsf{CT}',
+/// \mathsf{AK}\_{k} \right)$
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GlweAutomorphismKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    // A GlweAutomorphismKey is literally a GlweKeyswitchKey, so we wrap a GlweKeyswitchKey and
+    // use Deref to have access to all the primitives of the GlweKeyswitchKey easily
+    glwe_keyswitch_key: GlweKeyswitchKey<C>,
+    automorphism_exponent: AutomorphismExponent,
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> std::ops::Deref
+    for GlweAutomorphismKey<C>
+{
+    type Target = GlweKeyswitchKey<C>;
+
+    fn deref(&self) -> &GlweKeyswitchKey<C> {
+        &self.glwe_keyswitch_key
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> std::ops::DerefMut
+    for GlweAutomorphismKey<C>
+{
+    fn deref_mut(&mut self) -> &mut GlweKeyswitchKey<C> {
+        &mut self.glwe_keyswitch_key
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweAutomorphismKey<C> {
+    /// Create a [`GlweAutomorphismKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// This docstring exhibits [`GlweAutomorphismKey`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweAutomorphismKey creation
+    /// let glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(4);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let automorphism_exponent = AutomorphismExponent(3);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlweAutomorphismKey
+    /// let ak = GlweAutomorphismKey::new(
+    ///     0u64,
+    ///     automorphism_exponent,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension,
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// // These methods are "inherited" from GlweKeyswitchKey and are accessed through the Deref
+    /// // trait
+    /// assert_eq!(ak.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(ak.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(ak.polynomial_size(), polynomial_size);
+    /// assert_eq!(ak.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // These methods are specific to the GlweAutomorphismKey
+    /// assert_eq!(ak.automorphism_exponent(), automorphism_exponent);
+    /// assert_eq!(ak.glwe_dimension(), glwe_dimension);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = ak.into_container();
+    ///
+    /// // Recreate a key using from_container
+    /// let ak = GlweAutomorphismKey::from_container(
+    ///     underlying_container,
+    ///     automorphism_exponent,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(ak.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(ak.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(ak.polynomial_size(), polynomial_size);
+    /// assert_eq!(ak.ciphertext_modulus(), ciphertext_modulus);
+    /// assert_eq!(ak.automorphism_exponent(), automorphism_exponent);
+    /// assert_eq!(ak.glwe_dimension(), glwe_dimension);
+    /// ```
+    pub fn from_container(
+        container: C,
+        automorphism_exponent: AutomorphismExponent,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            automorphism_exponent.0 % 2 == 1 && automorphism_exponent.0 < 2 * poly_size.0,
+            "Invalid automorphism exponent {automorphism_exponent:?}, \
+            expected an odd exponent smaller than {}.",
+            2 * poly_size.0
+        );
+
+        let glwe_keyswitch_key = GlweKeyswitchKey::from_container(
+            container,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_size,
+            poly_size,
+            ciphertext_modulus,
+        );
+
+        assert!(
+            glwe_keyswitch_key.input_key_glwe_dimension()
+                == glwe_keyswitch_key.output_key_glwe_dimension(),
+            "The provided container length is not valid. \
+            Expected the keyswitch key to be from a GlweDimension {:?} to itself, \
+            got input GlweDimension {:?}.",
+            glwe_keyswitch_key.output_key_glwe_dimension(),
+            glwe_keyswitch_key.input_key_glwe_dimension()
+        );
+
+        Self {
+            glwe_keyswitch_key,
+            automorphism_exponent,
+        }
+    }
+
+    /// Return the [`AutomorphismExponent`] of the [`GlweAutomorphismKey`].
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn automorphism_exponent(&self) -> AutomorphismExponent {
+        self.automorphism_exponent
+    }
+
+    /// Return the [`GlweDimension`] of the [`GlweSecretKey`] the [`GlweAutomorphismKey`] is built
+    /// for.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn glwe_dimension(&self) -> GlweDimension {
+        self.glwe_keyswitch_key.output_key_glwe_dimension()
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.glwe_keyswitch_key.into_container()
+    }
+
+    /// Return a view of the [`GlweAutomorphismKey`]. This is useful if an algorithm takes a view
+    /// by value.
+    pub fn as_view(&self) -> GlweAutomorphismKey<&'_ [Scalar]> {
+        GlweAutomorphismKey {
+            glwe_keyswitch_key: self.glwe_keyswitch_key.as_view(),
+            automorphism_exponent: self.automorphism_exponent,
+        }
+    }
+
+    /// Return a view of the underlying [`GlweKeyswitchKey`].
+    pub fn as_glwe_keyswitch_key(&self) -> GlweKeyswitchKeyView<'_, Scalar> {
+        self.glwe_keyswitch_key.as_view()
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweAutomorphismKey<C> {
+    /// Mutable variant of [`GlweAutomorphismKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlweAutomorphismKey<&'_ mut [Scalar]> {
+        GlweAutomorphismKey {
+            glwe_keyswitch_key: self.glwe_keyswitch_key.as_mut_view(),
+            automorphism_exponent: self.automorphism_exponent,
+        }
+    }
+
+    /// Mutable variant of [`GlweAutomorphismKey::as_glwe_keyswitch_key`].
+    pub fn as_mut_glwe_keyswitch_key(&mut self) -> GlweKeyswitchKeyMutView<'_, Scalar> {
+        self.glwe_keyswitch_key.as_mut_view()
+    }
+}
+
+/// A [`GlweAutomorphismKey`] owning the memory for its own storage.
+pub type GlweAutomorphismKeyOwned<Scalar> = GlweAutomorphismKey<Vec<Scalar>>;
+/// A [`GlweAutomorphismKey`] immutably borrowing memory for its own storage.
+pub type GlweAutomorphismKeyView<'data, Scalar> = GlweAutomorphismKey<&'data [Scalar]>;
+/// A [`GlweAutomorphismKey`] mutably borrowing memory for its own storage.
+pub type GlweAutomorphismKeyMutView<'data, Scalar> = GlweAutomorphismKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> GlweAutomorphismKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlweAutomorphismKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlweAutomorphismKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_automorphism_key`] using this key as
+    /// output.
+    ///
+    /// See [`GlweAutomorphismKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        automorphism_exponent: AutomorphismExponent,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            GlweKeyswitchKey::new(
+                fill_with,
+                decomp_base_log,
+                decomp_level_count,
+                glwe_dimension,
+                glwe_dimension,
+                poly_size,
+                ciphertext_modulus,
+            )
+            .into_container(),
+            automorphism_exponent,
+            decomp_base_log,
+            decomp_level_count,
+            glwe_dimension.to_glwe_size(),
+            poly_size,
+            ciphertext_modulus,
+        )
+    }
+}
@@ -0,0 +1,479 @@
+//! Module containing the definition of the [`GlweKeyswitchKey`].
+
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::glwe_ciphertext::glwe_ciphertext_size;
+use crate::core_crypto::entities::glwe_ciphertext_list::{
+    GlweCiphertextListCreationMetadata, GlweCiphertextListMutView, GlweCiphertextListView,
+};
+
+/// A keyswitching key allowing to keyswitch [`a GLWE ciphertext`](super::GlweCiphertext)
+/// encrypted under an input [`super::GlweSecretKey`] to [`a GLWE
+/// ciphertext`](super::GlweCiphertext) encrypted under an output [`super::GlweSecretKey`].
+///
+/// The [`GlweDimension`] of the keys may differ, which allows to change the GLWE dimension of a
+/// ciphertext, e.g. after packing. Their [`PolynomialSize`] may differ as well, see
+/// [`ring switching`](`GlweKeyswitchKey#ring-switching`).
+///
+/// # Ring Switching
+///
+/// The input and output keys may live in rings of different degrees $N\_{\mathsf{in}}$ and
+/// $N\_{\mathsf{out}}$, one dividing the other. The keyswitching key then lives in the output ring
+/// $\mathcal{R}\_{\mathsf{out}} = \mathbb{Z}\_q\[Y\]/(Y^{N\_{\mathsf{out}}}+1)$ and encrypts the
+/// input key seen in that ring, the input ciphertext being mapped to the output ring in the same
+/// way before the keyswitch:
+/// - if $N\_{\mathsf{in}} = r \cdot N\_{\mathsf{out}}$, $Y = X^r$ generates a subring of the input
+///   ring and any input polynomial decomposes as $A = \sum\_{j=0}^{r-1} X^j A\_j(Y)$ with
+///   $A\_j \in \mathcal{R}\_{\mathsf{out}}$. The component on $X^0$ of $\left\langle \vec{A},
+///   \vec{S} \right\rangle$ is $\sum\_i A\_{i,0} S\_{i,0} + \sum\_{j=1}^{r-1} (Y \cdot A\_{i,j})
+///   S\_{i,r-j}$, so the input key is seen as the $r \cdot k\_{\mathsf{in}}$ polynomials
+///   $(S\_{i,0}, S\_{i,r-1}, \cdots, S\_{i,1})$ and the input mask as $(A\_{i,0}, Y \cdot A\_{i,1},
+///   \cdots, Y \cdot A\_{i,r-1})$. The output ciphertext encrypts $\mathsf{PT}\_0$, i.e. the
+///   coefficients of the input plaintext whose index is a multiple of $r$;
+/// - if $N\_{\mathsf{out}} = r \cdot N\_{\mathsf{in}}$, the input key and ciphertext are embedded
+///   in the output ring with the ring morphism $X \mapsto Y^r$. The output ciphertext encrypts
+///   the input plaintext, its coefficient of index $i$ being moved to index $i \cdot r$.
+///
+/// The input [`GlweDimension`] of such a key is given by
+/// [`glwe_keyswitch_key_input_key_glwe_dimension`]. Switching to a smaller ring keyswitches $r$
+/// times more polynomials, which increases the keyswitch noise accordingly.
+///
+/// # Formal Definition
+///
+/// ## Key Switching Key
+///
+/// A key switching key is a vector of GLev ciphertexts (described on the bottom of
+/// [`this page`](`crate::core_crypto::entities::GgswCiphertext#glev-ciphertext`)).
+/// It encrypts the polynomials of the input [`super::GlweSecretKey`]
+/// $\vec{S\_{\mathsf{in}}}\in \mathcal{R}^{k\_{\mathsf{in}}}$ under the output
+/// [`super::GlweSecretKey`] $\vec{S\_{\mathsf{out}}}\in \mathcal{R}^{k\_{\mathsf{out}}}$.
+///
+/// $$\mathsf{KSK}\_{\vec{S\_{\mathsf{in}}}\rightarrow \vec{S\_{\mathsf{out}}}} = \left(
+/// \overline{\mathsf{CT}\_0}, \cdots , \overline{\mathsf{CT}\_{k\_{\mathsf{in}}-1}}\right)
+/// \subseteq \mathbb{Z}\_q^{(k\_{\mathsf{out}}+1)\cdot N\cdot k\_{\mathsf{in}}\cdot\ell}$$
+///
+/// where $\vec{S\_{\mathsf{in}}} = \left( S\_0 , \cdots , S\_{k\_{\mathsf{in}}-1} \right)$ and for
+/// all $0\le i <k\_{\mathsf{in}}$ we have $\overline{\mathsf{CT}\_i} \in
+/// \mathsf{GLev}\_{\vec{S\_{\mathsf{out}}}}^{\beta, \ell}\left(S\_i\right)$.
+///
+/// ## GLWE Keyswitch
+///
+/// This homomorphic procedure transforms an input [`GLWE ciphertext`](super::GlweCiphertext)
+/// $\mathsf{CT}\_{\mathsf{in}} = \left( \vec{A}\_{\mathsf{in}} , B\_{\mathsf{in}}\right) \in
+/// \mathsf{GLWE}\_{\vec{S\_{\mathsf{in}}}}( \mathsf{PT} )$ into an output
+/// [`GLWE ciphertext`](super::GlweCiphertext) $\mathsf{CT}\_{\mathsf{out}} = \left(
+/// \vec{A}\_{\mathsf{out}} , B\_{\mathsf{out}}\right) \in
+/// \mathsf{GLWE}\_{\vec{S\_{\mathsf{out}}}}( \mathsf{PT} )$ encrypting the same plaintext
+/// polynomial.
+///
+/// ###### inputs:
+/// - $\mathsf{CT}\_{\mathsf{in}} = \left( \vec{A}\_{\mathsf{in}} , B\_{\mathsf{in}}\right)
+///   \in \mathsf{GLWE}\_{\vec{S\_{\mathsf{in}}}}( \mathsf{PT} )$: a GLWE ciphertext
+/// - $\mathsf{KSK}\_{\vec{S\_{\mathsf{in}}}\rightarrow \vec{S\_{\mathsf{out}}}}$: a key
+///   switching key
+///
+/// ###### outputs:
+/// - $\mathsf{CT}\_{\mathsf{out}} \in \mathsf{GLWE}\_{\vec{S\_{\mathsf{out}}}}
+///   \left( \mathsf{PT} \right)$: a GLWE ciphertext
+///
+/// ###### algorithm:
+/// 1. set $\mathsf{CT}\_{\mathsf{out}}=\left( 0 , \cdots , 0 , B\_{\mathsf{in}} \right)$
+/// 2. compute $\mathsf{CT}\_{\mathsf{out}} \leftarrow \mathsf{CT}\_{\mathsf{out}} -
+///    \sum\_{i=0}^{k\_{\mathsf{in}}-1} \mathsf{decompProduct}\left( A\_i ,
+///    \overline{\mathsf{CT}\_i} \right)$ where the decomposition of the polynomial $A\_i$ is
+///    computed coefficient-wise
+/// 3. output $\mathsf{CT}\_{\mathsf{out}}$
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct GlweKeyswitchKey<C: Container>
+where
+    C::Element: UnsignedInteger,
+{
+    data: C,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+    ciphertext_modulus: CiphertextModulus<C::Element>,
+}
+
+impl<T: UnsignedInteger, C: Container<Element = T>> AsRef<[T]> for GlweKeyswitchKey<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T: UnsignedInteger, C: ContainerMut<Element = T>> AsMut<[T]> for GlweKeyswitchKey<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+/// Return the number of elements in an encryption of an input [`super::GlweSecretKey`] polynomial
+/// for a [`GlweKeyswitchKey`] given a [`DecompositionLevelCount`], output [`GlweSize`] and
+/// [`PolynomialSize`].
+pub fn glwe_keyswitch_key_input_key_element_encrypted_size(
+    decomp_level_count: DecompositionLevelCount,
+    output_glwe_size: GlweSize,
+    poly_size: PolynomialSize,
+) -> usize {
+    // One ciphertext per level encrypted under the output key
+    decomp_level_count.0 * glwe_ciphertext_size(output_glwe_size, poly_size)
+}
+
+/// Return the input [`GlweDimension`] of a [`GlweKeyswitchKey`] switching from a
+/// [`super::GlweSecretKey`] with the given [`GlweDimension`] and [`PolynomialSize`] to a
+/// [`super::GlweSecretKey`] with the given output [`PolynomialSize`].
+///
+/// It is the input [`GlweDimension`] itself when both keys share the same [`PolynomialSize`], see
+/// [`ring switching`](`GlweKeyswitchKey#ring-switching`) otherwise.
+pub fn glwe_keyswitch_key_input_key_glwe_dimension(
+    input_glwe_dimension: GlweDimension,
+    input_poly_size: PolynomialSize,
+    output_poly_size: PolynomialSize,
+) -> GlweDimension {
+    assert!(
+        input_poly_size.0 % output_poly_size.0 == 0 || output_poly_size.0 % input_poly_size.0 == 0,
+        "Ring switching requires one PolynomialSize to divide the other. \
+        Got input PolynomialSize: {input_poly_size:?}, output PolynomialSize: {output_poly_size:?}."
+    );
+
+    if input_poly_size.0 > output_poly_size.0 {
+        GlweDimension(input_glwe_dimension.0 * (input_poly_size.0 / output_poly_size.0))
+    } else {
+        input_glwe_dimension
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> GlweKeyswitchKey<C> {
+    /// Create a [`GlweKeyswitchKey`] from an existing container.
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate a
+    /// [`GlweKeyswitchKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_keyswitch_key`] using this key as output.
+    ///
+    /// This docstring exhibits [`GlweKeyswitchKey`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for GlweKeyswitchKey creation
+    /// let input_glwe_dimension = GlweDimension(2);
+    /// let output_glwe_dimension = GlweDimension(1);
+    /// let polynomial_size = PolynomialSize(1024);
+    /// let decomp_base_log = DecompositionBaseLog(4);
+    /// let decomp_level_count = DecompositionLevelCount(5);
+    /// let ciphertext_modulus = CiphertextModulus::new_native();
+    ///
+    /// // Create a new GlweKeyswitchKey
+    /// let glwe_ksk = GlweKeyswitchKey::new(
+    ///     0u64,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     input_glwe_dimension,
+    ///     output_glwe_dimension,
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(glwe_ksk.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(glwe_ksk.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(glwe_ksk.input_key_glwe_dimension(), input_glwe_dimension);
+    /// assert_eq!(glwe_ksk.output_key_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(glwe_ksk.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     glwe_ksk.output_glwe_size(),
+    ///     output_glwe_dimension.to_glwe_size()
+    /// );
+    /// assert_eq!(glwe_ksk.ciphertext_modulus(), ciphertext_modulus);
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = glwe_ksk.into_container();
+    ///
+    /// // Recreate a keyswitch key using from_container
+    /// let glwe_ksk = GlweKeyswitchKey::from_container(
+    ///     underlying_container,
+    ///     decomp_base_log,
+    ///     decomp_level_count,
+    ///     output_glwe_dimension.to_glwe_size(),
+    ///     polynomial_size,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(glwe_ksk.decomposition_base_log(), decomp_base_log);
+    /// assert_eq!(glwe_ksk.decomposition_level_count(), decomp_level_count);
+    /// assert_eq!(glwe_ksk.input_key_glwe_dimension(), input_glwe_dimension);
+    /// assert_eq!(glwe_ksk.output_key_glwe_dimension(), output_glwe_dimension);
+    /// assert_eq!(glwe_ksk.polynomial_size(), polynomial_size);
+    /// assert_eq!(
+    ///     glwe_ksk.output_glwe_size(),
+    ///     output_glwe_dimension.to_glwe_size()
+    /// );
+    /// assert_eq!(glwe_ksk.ciphertext_modulus(), ciphertext_modulus);
+    /// ```
+    pub fn from_container(
+        container: C,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        output_glwe_size: GlweSize,
+        poly_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<C::Element>,
+    ) -> Self {
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create a GlweKeyswitchKey"
+        );
+        assert!(
+            container.container_len()
+                % glwe_keyswitch_key_input_key_element_encrypted_size(
+                    decomp_level_count,
+                    output_glwe_size,
+                    poly_size
+                )
+                == 0,
+            "The provided container length is not valid. \
+        It needs to be dividable by: {}. Got container length: {} and decomp_level_count: \
+        {decomp_level_count:?}, output_glwe_size: {output_glwe_size:?}, poly_size: \
+        {poly_size:?}.",
+            glwe_keyswitch_key_input_key_element_encrypted_size(
+                decomp_level_count,
+                output_glwe_size,
+                poly_size
+            ),
+            container.container_len()
+        );
+
+        Self {
+            data: container,
+            decomp_base_log,
+            decomp_level_count,
+            output_glwe_size,
+            poly_size,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return the [`DecompositionBaseLog`] of the [`GlweKeyswitchKey`].
+    ///
+    /// See [`GlweKeyswitchKey::from_container`] for usage.
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomp_base_log
+    }
+
+    /// Return the [`DecompositionLevelCount`] of the [`GlweKeyswitchKey`].
+    ///
+    /// See [`GlweKeyswitchKey::from_container`] for usage.
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomp_level_count
+    }
+
+    /// Return the input [`GlweDimension`] of the [`GlweKeyswitchKey`].
+    ///
+    /// See [`GlweKeyswitchKey::from_container`] for usage.
+    pub fn input_key_glwe_dimension(&self) -> GlweDimension {
+        GlweDimension(self.data.container_len() / self.input_key_element_encrypted_size())
+    }
+
+    /// Return the output [`GlweDimension`] of the [`GlweKeyswitchKey`].
+    ///
+    /// See [`GlweKeyswitchKey::from_container`] for usage.
+    pub fn output_key_glwe_dimension(&self) -> GlweDimension {
+        self.output_glwe_size.to_glwe_dimension()
+    }
+
+    /// Return the output [`GlweSize`] of the [`GlweKeyswitchKey`].
+    ///
+    /// See [`GlweKeyswitchKey::from_container`] for usage.
+    pub fn output_glwe_size(&self) -> GlweSize {
+        self.output_glwe_size
+    }
+
+    /// Return the [`PolynomialSize`] of the output key of the [`GlweKeyswitchKey`], which is also
+    /// the one of the input key unless the key switches between rings.
+    ///
+    /// See [`GlweKeyswitchKey::from_container`] for usage.
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.poly_size
+    }
+
+    /// Return the number of elements in an encryption of an input [`super::GlweSecretKey`]
+    /// polynomial of the current [`GlweKeyswitchKey`].
+    pub fn input_key_element_encrypted_size(&self) -> usize {
+        glwe_keyswitch_key_input_key_element_encrypted_size(
+            self.decomp_level_count,
+            self.output_glwe_size,
+            self.poly_size,
+        )
+    }
+
+    /// Return a view of the [`GlweKeyswitchKey`]. This is useful if an algorithm takes a view by
+    /// value.
+    pub fn as_view(&self) -> GlweKeyswitchKeyView<'_, Scalar> {
+        GlweKeyswitchKey::from_container(
+            self.as_ref(),
+            self.decomp_base_log,
+            self.decomp_level_count,
+            self.output_glwe_size,
+            self.poly_size,
+            self.ciphertext_modulus,
+        )
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`GlweKeyswitchKey::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+
+    pub fn as_glwe_ciphertext_list(&self) -> GlweCiphertextListView<'_, Scalar> {
+        GlweCiphertextListView::from_container(
+            self.as_ref(),
+            self.output_glwe_size(),
+            self.polynomial_size(),
+            self.ciphertext_modulus(),
+        )
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
+        self.ciphertext_modulus
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> GlweKeyswitchKey<C> {
+    /// Mutable variant of [`GlweKeyswitchKey::as_view`].
+    pub fn as_mut_view(&mut self) -> GlweKeyswitchKeyMutView<'_, Scalar> {
+        let decomp_base_log = self.decomp_base_log;
+        let decomp_level_count = self.decomp_level_count;
+        let output_glwe_size = self.output_glwe_size;
+        let poly_size = self.poly_size;
+        let ciphertext_modulus = self.ciphertext_modulus;
+        GlweKeyswitchKey::from_container(
+            self.as_mut(),
+            decomp_base_log,
+            decomp_level_count,
+            output_glwe_size,
+            poly_size,
+            ciphertext_modulus,
+        )
+    }
+
+    pub fn as_mut_glwe_ciphertext_list(&mut self) -> GlweCiphertextListMutView<'_, Scalar> {
+        let output_glwe_size = self.output_glwe_size();
+        let poly_size = self.polynomial_size();
+        let ciphertext_modulus = self.ciphertext_modulus();
+        GlweCiphertextListMutView::from_container(
+            self.as_mut(),
+            output_glwe_size,
+            poly_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+/// A [`GlweKeyswitchKey`] owning the memory for its own storage.
+pub type GlweKeyswitchKeyOwned<Scalar> = GlweKeyswitchKey<Vec<Scalar>>;
+/// A [`GlweKeyswitchKey`] immutably borrowing memory for its own storage.
+pub type GlweKeyswitchKeyView<'data, Scalar> = GlweKeyswitchKey<&'data [Scalar]>;
+/// A [`GlweKeyswitchKey`] mutably borrowing memory for its own storage.
+pub type GlweKeyswitchKeyMutView<'data, Scalar> = GlweKeyswitchKey<&'data mut [Scalar]>;
+
+impl<Scalar: UnsignedInteger> GlweKeyswitchKeyOwned<Scalar> {
+    /// Allocate memory and create a new owned [`GlweKeyswitchKey`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate a [`GlweKeyswitchKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_glwe_keyswitch_key`] using this key as output.
+    ///
+    /// See [`GlweKeyswitchKey::from_container`] for usage.
+    pub fn new(
+        fill_with: Scalar,
+        decomp_base_log: DecompositionBaseLog,
+        decomp_level_count: DecompositionLevelCount,
+        input_key_glwe_dimension: GlweDimension,
+        output_key_glwe_dimension: GlweDimension,
+        poly_size: PolynomialSize,
+        ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                fill_with;
+                input_key_glwe_dimension.0
+                    * glwe_keyswitch_key_input_key_element_encrypted_size(
+                        decomp_level_count,
+                        output_key_glwe_dimension.to_glwe_size(),
+                        poly_size
+                    )
+            ],
+            decomp_base_log,
+            decomp_level_count,
+            output_key_glwe_dimension.to_glwe_size(),
+            poly_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> ContiguousEntityContainer
+    for GlweKeyswitchKey<C>
+{
+    type Element = C::Element;
+
+    type EntityViewMetadata = GlweCiphertextListCreationMetadata<Self::Element>;
+
+    type EntityView<'this>
+        = GlweCiphertextListView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    type SelfViewMetadata = ();
+
+    // At the moment it does not make sense to return "sub" keyswitch keys. So we use a dummy
+    // placeholder type here.
+    type SelfView<'this>
+        = DummyCreateFrom
+    where
+        Self: 'this;
+
+    fn get_entity_view_creation_metadata(&self) -> Self::EntityViewMetadata {
+        GlweCiphertextListCreationMetadata(
+            self.output_glwe_size(),
+            self.polynomial_size(),
+            self.ciphertext_modulus(),
+        )
+    }
+
+    fn get_entity_view_pod_size(&self) -> usize {
+        self.input_key_element_encrypted_size()
+    }
+
+    /// Unimplemented for [`GlweKeyswitchKey`]. At the moment it does not make sense to
+    /// return "sub" keyswitch keys.
+    fn get_self_view_creation_metadata(&self) -> Self::SelfViewMetadata {
+        unimplemented!(
+            "This function is not supported for GlweKeyswitchKey. \
+        At the moment it does not make sense to return 'sub' keyswitch keys."
+        )
+    }
+}
+
+impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> ContiguousEntityContainerMut
+    for GlweKeyswitchKey<C>
+{
+    type EntityMutView<'this>
+        = GlweCiphertextListMutView<'this, Self::Element>
+    where
+        Self: 'this;
+
+    // At the moment it does not make sense to return "sub" keyswitch keys. So we use a dummy
+    // placeholder type here.
+    type SelfMutView<'this>
+        = DummyCreateFrom
+    where
+        Self: 'this;
+}
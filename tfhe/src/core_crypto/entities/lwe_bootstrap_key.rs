@@ -1,6 +1,7 @@
 //! Module containing the definition of the LweBootstrapKey.
 
 use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::raw_key_format::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 
@@ -137,6 +138,40 @@ pub fn lwe_bootstrap_key_size(
     )
 }
 
+/// Write the header of an [`LweBootstrapKey`] in the raw key format, the key data must be written
+/// right after it.
+pub(crate) fn write_lwe_bootstrap_key_raw_header<Scalar: UnsignedInteger, W: std::io::Write>(
+    writer: W,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomp_base_log: DecompositionBaseLog,
+    decomp_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<Scalar>,
+) -> std::io::Result<()> {
+    let [modulus_low, modulus_high] = raw_key_modulus_parameters(ciphertext_modulus);
+    write_raw_key_header::<Scalar, _>(
+        writer,
+        RawKeyKind::LweBootstrapKey,
+        [
+            input_lwe_dimension.0 as u64,
+            glwe_size.0 as u64,
+            polynomial_size.0 as u64,
+            decomp_base_log.0 as u64,
+            decomp_level_count.0 as u64,
+            modulus_low,
+            modulus_high,
+            0,
+        ],
+        lwe_bootstrap_key_size(
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomp_level_count,
+        ),
+    )
+}
+
 impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweBootstrapKey<C> {
     /// Create an [`LweBootstrapKey`] from an existing container.
     ///
@@ -271,6 +306,76 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweBootstrapKey<C>
             self.ciphertext_modulus(),
         )
     }
+
+    /// Write the [`LweBootstrapKey`] in the raw key format, see
+    /// [`LweBootstrapKey::from_raw_bytes`] to use it in place without deserialization.
+    pub fn write_raw<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        write_lwe_bootstrap_key_raw_header(
+            &mut writer,
+            self.input_lwe_dimension(),
+            self.glwe_size(),
+            self.polynomial_size(),
+            self.decomposition_base_log(),
+            self.decomposition_level_count(),
+            self.ciphertext_modulus(),
+        )?;
+        writer.write_all(bytemuck::cast_slice(self.as_ref()))
+    }
+}
+
+impl<'data, Scalar: UnsignedInteger> LweBootstrapKey<&'data [Scalar]> {
+    /// Create an [`LweBootstrapKey`] over bytes in the raw key format written by
+    /// [`LweBootstrapKey::write_raw`] or
+    /// [`crate::core_crypto::algorithms::generate_lwe_bootstrap_key_to_writer`], typically a
+    /// memory mapped file.
+    ///
+    /// The key data is used in place, the bytes must therefore be aligned for `Scalar`, which is
+    /// always the case for a page aligned mapping of a whole file. The header and the key
+    /// parameters are validated and an error is returned if they are inconsistent.
+    pub fn from_raw_bytes(bytes: &'data [u8]) -> Result<Self, RawKeyError> {
+        let (parameters, data) = parse_raw_key::<Scalar>(bytes, RawKeyKind::LweBootstrapKey)?;
+        let [lwe_dimension, glwe_size, polynomial_size, base_log, level_count] =
+            raw_key_usize_parameters(&parameters)?;
+
+        if lwe_dimension == 0
+            || glwe_size == 0
+            || polynomial_size == 0
+            || base_log == 0
+            || level_count == 0
+        {
+            return Err(RawKeyError::InvalidParameters(
+                "key parameters must be non zero",
+            ));
+        }
+        if base_log
+            .checked_mul(level_count)
+            .map_or(true, |bits| bits > Scalar::BITS)
+        {
+            return Err(RawKeyError::InvalidParameters(
+                "decomposition exceeds the Scalar bit width",
+            ));
+        }
+
+        let ciphertext_modulus = raw_key_ciphertext_modulus([parameters[5], parameters[6]])?;
+
+        let expected_len = raw_key_expected_len(&[
+            lwe_dimension,
+            level_count,
+            glwe_size,
+            glwe_size,
+            polynomial_size,
+        ])?;
+        check_raw_key_len(expected_len, data.len())?;
+
+        Ok(Self::from_container(
+            data,
+            GlweSize(glwe_size),
+            PolynomialSize(polynomial_size),
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level_count),
+            ciphertext_modulus,
+        ))
+    }
 }
 
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> LweBootstrapKey<C> {
@@ -1,6 +1,7 @@
 //! Module containing the definition of the [`LweKeyswitchKey`].
 
 use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::raw_key_format::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 
@@ -276,6 +277,27 @@ impl<Scalar: UnsignedInteger, C: Container<Element = Scalar>> LweKeyswitchKey<C>
     pub fn ciphertext_modulus(&self) -> CiphertextModulus<C::Element> {
         self.ciphertext_modulus
     }
+
+    /// Write the [`LweKeyswitchKey`] in the raw key format, see
+    /// [`LweKeyswitchKeyView::from_raw_bytes`] to use it in place without deserialization.
+    pub fn write_raw<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        let [modulus_low, modulus_high] = raw_key_modulus_parameters(self.ciphertext_modulus);
+        write_raw_key(
+            writer,
+            RawKeyKind::LweKeyswitchKey,
+            [
+                self.decomp_base_log.0 as u64,
+                self.decomp_level_count.0 as u64,
+                self.input_key_lwe_dimension().0 as u64,
+                self.output_lwe_size.0 as u64,
+                modulus_low,
+                modulus_high,
+                0,
+                0,
+            ],
+            self.as_ref(),
+        )
+    }
 }
 
 impl<Scalar: UnsignedInteger, C: ContainerMut<Element = Scalar>> LweKeyswitchKey<C> {
@@ -308,6 +330,53 @@ pub type LweKeyswitchKeyView<'data, Scalar> = LweKeyswitchKey<&'data [Scalar]>;
 /// An [`LweKeyswitchKey`] mutably borrowing memory for its own storage.
 pub type LweKeyswitchKeyMutView<'data, Scalar> = LweKeyswitchKey<&'data mut [Scalar]>;
 
+impl<'data, Scalar: UnsignedInteger> LweKeyswitchKeyView<'data, Scalar> {
+    /// Create an [`LweKeyswitchKeyView`] over bytes in the raw key format written by
+    /// [`LweKeyswitchKey::write_raw`], typically a memory mapped file.
+    ///
+    /// The key data is used in place, the bytes must therefore be aligned for `Scalar`, which is
+    /// always the case for a page aligned mapping of a whole file. The header and the key
+    /// parameters are validated and an error is returned if they are inconsistent.
+    pub fn from_raw_bytes(bytes: &'data [u8]) -> Result<Self, RawKeyError> {
+        let (parameters, data) = parse_raw_key::<Scalar>(bytes, RawKeyKind::LweKeyswitchKey)?;
+        let [decomp_base_log, decomp_level_count, input_key_lwe_dimension, output_lwe_size] =
+            raw_key_usize_parameters(&parameters)?;
+
+        if decomp_base_log == 0 || decomp_level_count == 0 {
+            return Err(RawKeyError::InvalidParameters(
+                "decomposition parameters must be non zero",
+            ));
+        }
+        if decomp_base_log
+            .checked_mul(decomp_level_count)
+            .map_or(true, |bits| bits > Scalar::BITS)
+        {
+            return Err(RawKeyError::InvalidParameters(
+                "decomposition exceeds the Scalar bit width",
+            ));
+        }
+        if input_key_lwe_dimension == 0 || output_lwe_size == 0 {
+            return Err(RawKeyError::InvalidParameters(
+                "LWE dimensions must be non zero",
+            ));
+        }
+
+        let ciphertext_modulus = raw_key_ciphertext_modulus([parameters[4], parameters[5]])?;
+
+        let expected_len =
+            raw_key_expected_len(&[input_key_lwe_dimension, decomp_level_count, output_lwe_size])?;
+        check_raw_key_len(expected_len, data.len())?;
+
+        Ok(Self::from_container(
+            data,
+            DecompositionBaseLog(decomp_base_log),
+            DecompositionLevelCount(decomp_level_count),
+            LweSize(output_lwe_size),
+            ciphertext_modulus,
+        ))
+    }
+}
+
 impl<Scalar: UnsignedInteger> LweKeyswitchKeyOwned<Scalar> {
     /// Allocate memory and create a new owned [`LweKeyswitchKey`].
     ///
@@ -1,6 +1,7 @@
 //! Module containing the definition of the [`LweMultiBitBootstrapKey`].
 
 use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::raw_key_format::*;
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::FourierPolynomialList;
@@ -472,6 +473,89 @@ impl<C: Container<Element = c64>> FourierLweMultiBitBootstrapKey<C> {
             polynomial_size: self.fourier.polynomial_size,
         }
     }
+
+    /// Write the bootstrapping key in the raw key format, see
+    /// [`FourierLweMultiBitBootstrapKeyView::from_raw_bytes`] to use it in place without
+    /// deserialization.
+    pub fn write_raw<W: std::io::Write>(&self, writer: W) -> std::io::Result<()> {
+        write_raw_key(
+            writer,
+            RawKeyKind::FourierLweMultiBitBootstrapKey,
+            [
+                self.input_lwe_dimension.0 as u64,
+                self.glwe_size.0 as u64,
+                self.fourier.polynomial_size.0 as u64,
+                self.decomposition_base_log.0 as u64,
+                self.decomposition_level_count.0 as u64,
+                self.grouping_factor.0 as u64,
+                0,
+                0,
+            ],
+            self.fourier.data.as_ref(),
+        )
+    }
+}
+
+impl<'a> FourierLweMultiBitBootstrapKeyView<'a> {
+    /// Create a multi bit bootstrapping key over bytes in the raw key format written by
+    /// [`FourierLweMultiBitBootstrapKey::write_raw`], typically a memory mapped file.
+    ///
+    /// The key data is used in place, the bytes must therefore be aligned for [`c64`], which is
+    /// always the case for a page aligned mapping of a whole file. The header and the key
+    /// parameters are validated and an error is returned if they are inconsistent.
+    pub fn from_raw_bytes(bytes: &'a [u8]) -> Result<Self, RawKeyError> {
+        let (parameters, data) =
+            parse_raw_key::<c64>(bytes, RawKeyKind::FourierLweMultiBitBootstrapKey)?;
+        let [lwe_dimension, glwe_size, polynomial_size, base_log, level_count, grouping_factor] =
+            raw_key_usize_parameters(&parameters)?;
+
+        if lwe_dimension == 0
+            || glwe_size == 0
+            || base_log == 0
+            || level_count == 0
+            || grouping_factor == 0
+        {
+            return Err(RawKeyError::InvalidParameters(
+                "key parameters must be non zero",
+            ));
+        }
+        if polynomial_size < 2 || !polynomial_size.is_power_of_two() {
+            return Err(RawKeyError::InvalidParameters(
+                "polynomial size must be a power of 2 greater than 1",
+            ));
+        }
+        if lwe_dimension % grouping_factor != 0 {
+            return Err(RawKeyError::InvalidParameters(
+                "input LWE dimension must be a multiple of the grouping factor",
+            ));
+        }
+
+        let ggsw_per_multi_bit_element = u32::try_from(grouping_factor)
+            .ok()
+            .and_then(|grouping_factor| 1usize.checked_shl(grouping_factor))
+            .ok_or(RawKeyError::InvalidParameters(
+                "grouping factor is too large",
+            ))?;
+        let expected_len = raw_key_expected_len(&[
+            lwe_dimension / grouping_factor,
+            ggsw_per_multi_bit_element,
+            polynomial_size / 2,
+            level_count,
+            glwe_size,
+            glwe_size,
+        ])?;
+        check_raw_key_len(expected_len, data.len())?;
+
+        Ok(Self::from_container(
+            data,
+            LweDimension(lwe_dimension),
+            GlweSize(glwe_size),
+            PolynomialSize(polynomial_size),
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level_count),
+            LweBskGroupingFactor(grouping_factor),
+        ))
+    }
 }
 
 impl FourierLweMultiBitBootstrapKeyOwned {
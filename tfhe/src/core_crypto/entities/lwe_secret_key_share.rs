@@ -0,0 +1,375 @@
+//! Module containing the definition of the LweSecretKeyShare.
+
+use crate::core_crypto::commons::parameters::{
+    LweDimension, ShareCount, ShareIndex, ShareThreshold,
+};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::LweSecretKey;
+
+/// A share of an [`LWE secret key`](`LweSecretKey`) used for threshold decryption.
+///
+/// # Formal Definition
+///
+/// ## Replicated Secret Sharing of an LWE Secret Key
+///
+/// The sharing works directly over $\mathbb{Z}\_q$, the ring of the ciphertexts to decrypt, so
+/// that partial decryptions can be computed without leaving it. For a threshold $t$ among $n$
+/// participants, the secret key $\vec{s} \in \mathbb{Z}\_q^n$ is split in uniformly random key
+/// parts, one per set $T$ of $t - 1$ participants:
+/// $$\vec{s} = \sum\_{T \subset \lbrace 1, \ldots, n \rbrace, |T| = t - 1} \vec{s}\_T \bmod q$$
+/// The key part $\vec{s}\_T$ is given to all the participants which are not in $T$, the share with
+/// index $i \geq 1$ then contains the $\binom{n - 1}{t - 1}$ key parts $\vec{s}\_T$ with
+/// $i \notin T$.
+///
+/// Any set of $t$ participants holds all the key parts and can therefore decrypt, while the
+/// participants of a set $T$ of $t - 1$ participants all miss $\vec{s}\_T$ and learn nothing about
+/// the secret key. The size of a share grows with $\binom{n - 1}{t - 1}$, which restricts this
+/// sharing to small numbers of participants.
+///
+/// See [`crate::core_crypto::algorithms::generate_lwe_secret_key_shares`] to generate shares and
+/// [`crate::core_crypto::algorithms::partial_decrypt_lwe_ciphertext`] to use them.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LweSecretKeyShare<C: Container> {
+    data: C,
+    share_index: ShareIndex,
+    threshold: ShareThreshold,
+    share_count: ShareCount,
+}
+
+impl<T, C: Container<Element = T>> AsRef<[T]> for LweSecretKeyShare<C> {
+    fn as_ref(&self) -> &[T] {
+        self.data.as_ref()
+    }
+}
+
+impl<T, C: ContainerMut<Element = T>> AsMut<[T]> for LweSecretKeyShare<C> {
+    fn as_mut(&mut self) -> &mut [T] {
+        self.data.as_mut()
+    }
+}
+
+impl<Scalar, C: Container<Element = Scalar>> LweSecretKeyShare<C> {
+    /// Create an [`LweSecretKeyShare`] from an existing container.
+    ///
+    /// The container holds the key parts of the share one after the other, in the lexicographic
+    /// order of the sets of participants they are excluded from, see
+    /// [`LweSecretKeyShare::excluded_sets`].
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a container in the appropriate type. If you want to generate
+    /// shares of an [`LweSecretKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_lwe_secret_key_shares`].
+    ///
+    /// This docstring exhibits [`LweSecretKeyShare`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// // DISCLAIMER: these toy example parameters are not guaranteed to be secure or yield correct
+    /// // computations
+    /// // Define parameters for LweSecretKeyShare creation
+    /// let lwe_dimension = LweDimension(600);
+    /// let share_index = ShareIndex(1);
+    /// let threshold = ShareThreshold(2);
+    /// let share_count = ShareCount(3);
+    ///
+    /// // Create a new LweSecretKeyShare
+    /// let key_share = LweSecretKeyShare::new_empty_key_share(
+    ///     0u64,
+    ///     lwe_dimension,
+    ///     share_index,
+    ///     threshold,
+    ///     share_count,
+    /// );
+    ///
+    /// assert_eq!(key_share.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(key_share.share_index(), share_index);
+    /// assert_eq!(key_share.threshold(), threshold);
+    /// assert_eq!(key_share.share_count(), share_count);
+    /// // The share holds the key parts excluded from participant 2 and from participant 3
+    /// assert_eq!(
+    ///     key_share.excluded_sets(),
+    ///     vec![vec![ShareIndex(2)], vec![ShareIndex(3)]]
+    /// );
+    ///
+    /// // Demonstrate how to recover the allocated container
+    /// let underlying_container: Vec<u64> = key_share.into_container();
+    ///
+    /// // Recreate a key share using from_container
+    /// let key_share = LweSecretKeyShare::from_container(
+    ///     underlying_container,
+    ///     share_index,
+    ///     threshold,
+    ///     share_count,
+    /// );
+    ///
+    /// assert_eq!(key_share.lwe_dimension(), lwe_dimension);
+    /// assert_eq!(key_share.share_index(), share_index);
+    /// assert_eq!(key_share.threshold(), threshold);
+    /// assert_eq!(key_share.share_count(), share_count);
+    /// ```
+    pub fn from_container(
+        container: C,
+        share_index: ShareIndex,
+        threshold: ShareThreshold,
+        share_count: ShareCount,
+    ) -> Self {
+        assert!(
+            threshold.0 > 0 && threshold.0 <= share_count.0,
+            "Got an invalid threshold {threshold:?} for {share_count:?}, \
+            the threshold must be between 1 and the share count."
+        );
+        assert!(
+            share_index.0 > 0 && share_index.0 <= share_count.0,
+            "Got an invalid {share_index:?} for {share_count:?}, \
+            share indices must be between 1 and the share count."
+        );
+        let key_part_count = lwe_secret_key_share_key_part_count(threshold, share_count);
+        assert!(
+            container.container_len() > 0,
+            "Got an empty container to create an LweSecretKeyShare"
+        );
+        assert!(
+            container.container_len() % key_part_count == 0,
+            "The provided container length is not valid. \
+            It needs to be dividable by the number of key parts of a share: {key_part_count}. \
+            Got container length: {}.",
+            container.container_len()
+        );
+        Self {
+            data: container,
+            share_index,
+            threshold,
+            share_count,
+        }
+    }
+
+    /// Return the [`LweDimension`] of the [`LweSecretKeyShare`], i.e. of each of its key parts.
+    ///
+    /// See [`LweSecretKeyShare::from_container`] for usage.
+    pub fn lwe_dimension(&self) -> LweDimension {
+        LweDimension(
+            self.data.container_len()
+                / lwe_secret_key_share_key_part_count(self.threshold, self.share_count),
+        )
+    }
+
+    /// Return the [`ShareIndex`] of the [`LweSecretKeyShare`].
+    ///
+    /// See [`LweSecretKeyShare::from_container`] for usage.
+    pub fn share_index(&self) -> ShareIndex {
+        self.share_index
+    }
+
+    /// Return the [`ShareThreshold`] of the sharing the [`LweSecretKeyShare`] belongs to.
+    ///
+    /// See [`LweSecretKeyShare::from_container`] for usage.
+    pub fn threshold(&self) -> ShareThreshold {
+        self.threshold
+    }
+
+    /// Return the [`ShareCount`] of the sharing the [`LweSecretKeyShare`] belongs to.
+    ///
+    /// See [`LweSecretKeyShare::from_container`] for usage.
+    pub fn share_count(&self) -> ShareCount {
+        self.share_count
+    }
+
+    /// Return the sets of participants each key part of the [`LweSecretKeyShare`] is excluded
+    /// from, in the order the key parts are stored.
+    ///
+    /// See [`LweSecretKeyShare::from_container`] for usage.
+    pub fn excluded_sets(&self) -> Vec<Vec<ShareIndex>> {
+        let other_participants: Vec<_> = (1..=self.share_count.0)
+            .map(ShareIndex)
+            .filter(|&participant| participant != self.share_index)
+            .collect();
+        share_index_combinations(&other_participants, self.threshold.0 - 1)
+    }
+
+    /// Return an iterator over the key parts of the [`LweSecretKeyShare`], each one with the set
+    /// of participants it is excluded from.
+    pub fn key_part_iter<'a>(
+        &'a self,
+    ) -> impl Iterator<Item = (Vec<ShareIndex>, LweSecretKey<&'a [Scalar]>)>
+    where
+        Scalar: 'a,
+    {
+        let lwe_dimension = self.lwe_dimension();
+        self.excluded_sets().into_iter().zip(
+            self.data
+                .as_ref()
+                .chunks_exact(lwe_dimension.0)
+                .map(LweSecretKey::from_container),
+        )
+    }
+
+    /// Consume the entity and return its underlying container.
+    ///
+    /// See [`LweSecretKeyShare::from_container`] for usage.
+    pub fn into_container(self) -> C {
+        self.data
+    }
+}
+
+impl<Scalar, C: ContainerMut<Element = Scalar>> LweSecretKeyShare<C> {
+    /// Mutable variant of [`LweSecretKeyShare::key_part_iter`].
+    pub fn key_part_iter_mut<'a>(
+        &'a mut self,
+    ) -> impl Iterator<Item = (Vec<ShareIndex>, LweSecretKey<&'a mut [Scalar]>)>
+    where
+        Scalar: 'a,
+    {
+        let lwe_dimension = self.lwe_dimension();
+        self.excluded_sets().into_iter().zip(
+            self.data
+                .as_mut()
+                .chunks_exact_mut(lwe_dimension.0)
+                .map(LweSecretKey::from_container),
+        )
+    }
+}
+
+/// An [`LweSecretKeyShare`] owning the memory for its own storage.
+pub type LweSecretKeyShareOwned<Scalar> = LweSecretKeyShare<Vec<Scalar>>;
+
+impl<Scalar> LweSecretKeyShareOwned<Scalar>
+where
+    Scalar: Copy,
+{
+    /// Allocate memory and create a new owned [`LweSecretKeyShare`].
+    ///
+    /// # Note
+    ///
+    /// This function allocates a vector of the appropriate size and wraps it in the appropriate
+    /// type. If you want to generate shares of an [`LweSecretKey`] you need to call
+    /// [`crate::core_crypto::algorithms::generate_lwe_secret_key_shares`].
+    ///
+    /// See [`LweSecretKeyShare::from_container`] for usage.
+    pub fn new_empty_key_share(
+        value: Scalar,
+        lwe_dimension: LweDimension,
+        share_index: ShareIndex,
+        threshold: ShareThreshold,
+        share_count: ShareCount,
+    ) -> Self {
+        Self::from_container(
+            vec![
+                value;
+                lwe_dimension.0 * lwe_secret_key_share_key_part_count(threshold, share_count)
+            ],
+            share_index,
+            threshold,
+            share_count,
+        )
+    }
+}
+
+/// Return the number of key parts held by each share of a sharing with the given threshold and
+/// share count, i.e. $\binom{n - 1}{t - 1}$.
+pub fn lwe_secret_key_share_key_part_count(
+    threshold: ShareThreshold,
+    share_count: ShareCount,
+) -> usize {
+    // C(n - 1, k) computed incrementally, each intermediate value C(n - 1 - k + i, i) is an integer
+    let (n, k) = (share_count.0 - 1, threshold.0 - 1);
+    (1..=k).fold(1, |acc, i| acc * (n - k + i) / i)
+}
+
+/// Return all the subsets of `size` elements of `share_indices` in lexicographic order, the input
+/// being sorted.
+pub(crate) fn share_index_combinations(
+    share_indices: &[ShareIndex],
+    size: usize,
+) -> Vec<Vec<ShareIndex>> {
+    if size == 0 {
+        return vec![vec![]];
+    }
+
+    share_indices
+        .iter()
+        .enumerate()
+        .flat_map(|(idx, &first)| {
+            share_index_combinations(&share_indices[idx + 1..], size - 1)
+                .into_iter()
+                .map(move |mut combination| {
+                    combination.insert(0, first);
+                    combination
+                })
+        })
+        .collect()
+}
+
+/// A partial decryption of an [`LWE ciphertext`](`crate::core_crypto::entities::LweCiphertext`)
+/// computed with an [`LweSecretKeyShare`].
+///
+/// # Formal Definition
+///
+/// Given an LWE ciphertext $(\vec{a}, b)$ and a set $S$ of at least $t$ participants, each key part
+/// $\vec{s}\_T$ is assigned to the participant of $S \setminus T$ with the smallest index, which
+/// exists as $|T| < |S|$. Denoting $A\_i$ the key parts assigned to participant $i \in S$, its
+/// partial decryption is:
+/// $$d\_i = \sum\_{T \in A\_i} \langle \vec{a}, \vec{s}\_T \rangle + e\_i \bmod q$$
+/// where $e\_i$ is a flooding noise statistically hiding the noise of the ciphertext, and therefore
+/// the key parts, in the combined result.
+///
+/// A partial decryption is only meaningful for the set $S$ and the ciphertext it was computed for,
+/// it therefore records the set of participants and a digest of the ciphertext, which are checked
+/// when combining partial decryptions.
+///
+/// See [`crate::core_crypto::algorithms::combine_lwe_partial_decryptions`] to recover the
+/// plaintext.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct LwePartialDecryption<Scalar> {
+    share_index: ShareIndex,
+    participants: Vec<ShareIndex>,
+    ciphertext_digest: [u8; 32],
+    value: Scalar,
+}
+
+impl<Scalar: Copy> LwePartialDecryption<Scalar> {
+    /// Create an [`LwePartialDecryption`] from its raw value in $\mathbb{Z}\_q$.
+    ///
+    /// `participants` is the sorted set of participants the partial decryption was computed for,
+    /// and `ciphertext_digest` the digest of the decrypted ciphertext, see
+    /// [`crate::core_crypto::algorithms::lwe_ciphertext_digest`].
+    ///
+    /// # Note
+    ///
+    /// This function only wraps a value in the appropriate type, partial decryptions are computed
+    /// with [`crate::core_crypto::algorithms::partial_decrypt_lwe_ciphertext`].
+    pub fn new(
+        share_index: ShareIndex,
+        participants: Vec<ShareIndex>,
+        ciphertext_digest: [u8; 32],
+        value: Scalar,
+    ) -> Self {
+        Self {
+            share_index,
+            participants,
+            ciphertext_digest,
+            value,
+        }
+    }
+
+    /// Return the [`ShareIndex`] of the share used to compute the [`LwePartialDecryption`].
+    pub fn share_index(&self) -> ShareIndex {
+        self.share_index
+    }
+
+    /// Return the sorted set of participants the [`LwePartialDecryption`] was computed for.
+    pub fn participants(&self) -> &[ShareIndex] {
+        &self.participants
+    }
+
+    /// Return the digest of the ciphertext the [`LwePartialDecryption`] was computed for.
+    pub fn ciphertext_digest(&self) -> [u8; 32] {
+        self.ciphertext_digest
+    }
+
+    /// Return the raw value of the [`LwePartialDecryption`].
+    pub fn value(&self) -> Scalar {
+        self.value
+    }
+}
@@ -6,8 +6,11 @@
 pub mod cleartext;
 pub mod ggsw_ciphertext;
 pub mod ggsw_ciphertext_list;
+pub mod glwe_automorphism_key;
 pub mod glwe_ciphertext;
 pub mod glwe_ciphertext_list;
+pub mod glwe_keyswitch_key;
+pub mod glwe_relinearization_key;
 pub mod glwe_secret_key;
 pub mod gsw_ciphertext;
 pub mod lwe_bootstrap_key;
@@ -22,6 +25,8 @@ pub mod lwe_private_functional_packing_keyswitch_key;
 pub mod lwe_private_functional_packing_keyswitch_key_list;
 pub mod lwe_public_key;
 pub mod lwe_secret_key;
+pub mod lwe_secret_key_share;
+pub mod modulus_switched_lwe_ciphertext;
 pub mod plaintext;
 pub mod plaintext_list;
 pub mod polynomial;
@@ -30,6 +35,7 @@ pub mod seeded_ggsw_ciphertext;
 pub mod seeded_ggsw_ciphertext_list;
 pub mod seeded_glwe_ciphertext;
 pub mod seeded_glwe_ciphertext_list;
+pub mod seeded_glwe_keyswitch_key;
 pub mod seeded_lwe_bootstrap_key;
 pub mod seeded_lwe_ciphertext;
 pub mod seeded_lwe_ciphertext_list;
@@ -43,20 +49,33 @@ pub use crate::core_crypto::fft_impl::fft128::crypto::bootstrap::{
     Fourier128LweBootstrapKey, Fourier128LweBootstrapKeyOwned,
 };
 pub use crate::core_crypto::fft_impl::fft128::crypto::ggsw::{
-    Fourier128GgswCiphertext, Fourier128GgswLevelMatrix, Fourier128GgswLevelRow,
+    Fourier128GgswCiphertext, Fourier128GgswCiphertextOwned, Fourier128GgswLevelMatrix,
+    Fourier128GgswLevelRow,
+};
+pub use crate::core_crypto::fft_impl::fft128::crypto::multi_bit_bootstrap::{
+    Fourier128LweMultiBitBootstrapKey, Fourier128LweMultiBitBootstrapKeyOwned,
 };
 pub use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::{
-    FourierLweBootstrapKey, FourierLweBootstrapKeyOwned,
+    FourierLweBootstrapKey, FourierLweBootstrapKeyOwned, FourierLweBootstrapKeyView,
 };
 pub use crate::core_crypto::fft_impl::fft64::crypto::ggsw::{
     FourierGgswCiphertext, FourierGgswCiphertextList, FourierGgswLevelMatrix, FourierGgswLevelRow,
 };
 pub use crate::core_crypto::fft_impl::fft64::math::polynomial::FourierPolynomial;
+pub use crate::core_crypto::ntt_impl::ntt64::crypto::bootstrap::{
+    NttLweBootstrapKey, NttLweBootstrapKeyOwned,
+};
+pub use crate::core_crypto::ntt_impl::ntt64::crypto::ggsw::{
+    NttGgswCiphertext, NttGgswCiphertextOwned, NttGgswLevelMatrix, NttGgswLevelRow,
+};
 pub use cleartext::*;
 pub use ggsw_ciphertext::*;
 pub use ggsw_ciphertext_list::*;
+pub use glwe_automorphism_key::*;
 pub use glwe_ciphertext::*;
 pub use glwe_ciphertext_list::*;
+pub use glwe_keyswitch_key::*;
+pub use glwe_relinearization_key::*;
 pub use glwe_secret_key::*;
 pub use gsw_ciphertext::*;
 pub use lwe_bootstrap_key::*;
@@ -71,6 +90,8 @@ pub use lwe_private_functional_packing_keyswitch_key::*;
 pub use lwe_private_functional_packing_keyswitch_key_list::*;
 pub use lwe_public_key::*;
 pub use lwe_secret_key::*;
+pub use lwe_secret_key_share::*;
+pub use modulus_switched_lwe_ciphertext::*;
 pub use plaintext::*;
 pub use plaintext_list::*;
 pub use polynomial::*;
@@ -79,6 +100,7 @@ pub use seeded_ggsw_ciphertext::*;
 pub use seeded_ggsw_ciphertext_list::*;
 pub use seeded_glwe_ciphertext::*;
 pub use seeded_glwe_ciphertext_list::*;
+pub use seeded_glwe_keyswitch_key::*;
 pub use seeded_lwe_bootstrap_key::*;
 pub use seeded_lwe_ciphertext::*;
 pub use seeded_lwe_ciphertext_list::*;
@@ -0,0 +1,366 @@
+//! Module containing the definition of the [`ModulusSwitchedLweCiphertext`].
+
+use crate::conformance::ParameterSetConformant;
+use crate::core_crypto::commons::parameters::*;
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+
+/// An [`LWE ciphertext`](`LweCiphertext`) which coefficients have been switched to a smaller power
+/// of 2 modulus $2^{\log\_2 q'}$ and bit-packed, only storing $\log\_2 q'$ bits per coefficient.
+///
+/// This representation is meant for storage and transport of ciphertexts which are going to be
+/// bootstrapped, as the blind rotation only uses $\log\_2 (2N)$ bits of each coefficient. The
+/// packed ciphertext can be fed directly to the blind rotation see
+/// [`blind_rotate_modulus_switched_lwe_ciphertext_assign`](`crate::core_crypto::algorithms::blind_rotate_modulus_switched_lwe_ciphertext_assign`)
+/// or be converted back to an [`LWE ciphertext`](`LweCiphertext`) under its original modulus with
+/// [`ModulusSwitchedLweCiphertext::extract`].
+///
+/// A deserialized [`ModulusSwitchedLweCiphertext`] must be checked with
+/// [`ParameterSetConformant::is_conformant`] before being used, as malformed packed data would make
+/// the unpacking panic.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct ModulusSwitchedLweCiphertext<Scalar: UnsignedInteger> {
+    packed_coeffs: Vec<Scalar>,
+    lwe_dimension: LweDimension,
+    log_modulus: CiphertextModulusLog,
+    uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+}
+
+/// Structure to store the expected properties of a [`ModulusSwitchedLweCiphertext`]
+/// Can be used on a server to check if client inputs are well formed
+/// before running a computation on them
+#[derive(Copy, Clone)]
+pub struct ModulusSwitchedLweCiphertextParameters<T: UnsignedInteger> {
+    pub lwe_dimension: LweDimension,
+    pub log_modulus: CiphertextModulusLog,
+    pub uncompressed_ciphertext_modulus: CiphertextModulus<T>,
+}
+
+impl<T: UnsignedInteger> ParameterSetConformant for ModulusSwitchedLweCiphertext<T> {
+    type ParameterSet = ModulusSwitchedLweCiphertextParameters<T>;
+
+    fn is_conformant(&self, ms_ct_parameters: &ModulusSwitchedLweCiphertextParameters<T>) -> bool {
+        // The size checks come first so that the packed length computation cannot overflow
+        self.lwe_dimension == ms_ct_parameters.lwe_dimension
+            && self.log_modulus == ms_ct_parameters.log_modulus
+            && self.uncompressed_ciphertext_modulus
+                == ms_ct_parameters.uncompressed_ciphertext_modulus
+            && Self::max_log_modulus(self.uncompressed_ciphertext_modulus).is_some_and(
+                |max_log_modulus| self.log_modulus.0 > 0 && self.log_modulus.0 <= max_log_modulus,
+            )
+            && self.packed_coeffs.len()
+                == modulus_switched_lwe_ciphertext_packed_len::<T>(
+                    self.lwe_dimension.to_lwe_size(),
+                    self.log_modulus,
+                )
+    }
+}
+
+// These accessors are used to create invalid objects and test the conformance functions
+// But these functions should not be used in other contexts, hence the `#[cfg(test)]`
+#[cfg(test)]
+#[allow(dead_code)]
+impl<Scalar: UnsignedInteger> ModulusSwitchedLweCiphertext<Scalar> {
+    pub(crate) fn get_mut_packed_coeffs(&mut self) -> &mut Vec<Scalar> {
+        &mut self.packed_coeffs
+    }
+
+    pub(crate) fn get_mut_lwe_dimension(&mut self) -> &mut LweDimension {
+        &mut self.lwe_dimension
+    }
+
+    pub(crate) fn get_mut_log_modulus(&mut self) -> &mut CiphertextModulusLog {
+        &mut self.log_modulus
+    }
+}
+
+/// Return the number of `Scalar` required to store the `lwe_size` coefficients of an LWE ciphertext
+/// with `log_modulus` bits per coefficient.
+pub fn modulus_switched_lwe_ciphertext_packed_len<Scalar: UnsignedInteger>(
+    lwe_size: LweSize,
+    log_modulus: CiphertextModulusLog,
+) -> usize {
+    (lwe_size.0 * log_modulus.0 + Scalar::BITS - 1) / Scalar::BITS
+}
+
+impl<Scalar: UnsignedInteger> ModulusSwitchedLweCiphertext<Scalar> {
+    /// Pack already modulus switched coefficients in a [`ModulusSwitchedLweCiphertext`].
+    ///
+    /// Each coefficient in `switched_coeffs` must be in $[0, 2^{\log\_2 q'})$, the last one
+    /// being the body. `uncompressed_ciphertext_modulus` is the modulus of the LWE ciphertext the
+    /// coefficients were switched from.
+    ///
+    /// # Note
+    ///
+    /// This function only packs coefficients. If you want to modulus switch an existing LWE
+    /// ciphertext you need to use
+    /// [`crate::core_crypto::algorithms::modulus_switch_and_pack_lwe_ciphertext`].
+    ///
+    /// This docstring exhibits [`ModulusSwitchedLweCiphertext`] primitives usage.
+    ///
+    /// ```
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let log_modulus = CiphertextModulusLog(12);
+    /// let ciphertext_modulus = CiphertextModulus::<u64>::new_native();
+    ///
+    /// let switched_coeffs: Vec<u64> = (0..743).map(|x| x % (1 << log_modulus.0)).collect();
+    ///
+    /// let ms_ct = ModulusSwitchedLweCiphertext::from_switched_coeffs(
+    ///     &switched_coeffs,
+    ///     log_modulus,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(ms_ct.lwe_dimension(), LweDimension(742));
+    /// assert_eq!(ms_ct.log_modulus(), log_modulus);
+    /// assert_eq!(ms_ct.uncompressed_ciphertext_modulus(), ciphertext_modulus);
+    /// // 743 coefficients of 12 bits fit in 140 u64
+    /// assert_eq!(ms_ct.packed_coeffs().len(), 140);
+    ///
+    /// let unpacked: Vec<u64> = ms_ct.switched_coeffs().collect();
+    /// assert_eq!(unpacked, switched_coeffs);
+    ///
+    /// // Demonstrate how to recover the packed data
+    /// let (packed_coeffs, lwe_dimension, log_modulus, ciphertext_modulus) =
+    ///     ms_ct.clone().into_raw_parts();
+    ///
+    /// let ms_ct_2 = ModulusSwitchedLweCiphertext::from_raw_parts(
+    ///     packed_coeffs,
+    ///     lwe_dimension,
+    ///     log_modulus,
+    ///     ciphertext_modulus,
+    /// );
+    ///
+    /// assert_eq!(ms_ct, ms_ct_2);
+    ///
+    /// // Recover an LWE ciphertext under the original modulus
+    /// let lwe = ms_ct.extract();
+    ///
+    /// assert_eq!(lwe.lwe_size(), LweDimension(742).to_lwe_size());
+    /// assert_eq!(lwe.ciphertext_modulus(), ciphertext_modulus);
+    /// assert!(lwe
+    ///     .as_ref()
+    ///     .iter()
+    ///     .zip(switched_coeffs.iter())
+    ///     .all(|(&x, &y)| x == y << (u64::BITS as usize - log_modulus.0)));
+    /// ```
+    pub fn from_switched_coeffs(
+        switched_coeffs: &[Scalar],
+        log_modulus: CiphertextModulusLog,
+        uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        assert!(
+            !switched_coeffs.is_empty(),
+            "Cannot create a ModulusSwitchedLweCiphertext without coefficients"
+        );
+        Self::assert_log_modulus_is_valid(log_modulus, uncompressed_ciphertext_modulus);
+
+        let lwe_size = LweSize(switched_coeffs.len());
+        let log_modulus_usize = log_modulus.0;
+
+        let mut packed_coeffs =
+            vec![
+                Scalar::ZERO;
+                modulus_switched_lwe_ciphertext_packed_len::<Scalar>(lwe_size, log_modulus)
+            ];
+
+        for (index, &coeff) in switched_coeffs.iter().enumerate() {
+            assert!(
+                log_modulus_usize == Scalar::BITS || coeff < Scalar::ONE << log_modulus_usize,
+                "Coefficient {coeff:?} at index {index} does not fit in {log_modulus_usize} bits"
+            );
+
+            let start_bit = index * log_modulus_usize;
+            let word = start_bit / Scalar::BITS;
+            let offset = start_bit % Scalar::BITS;
+
+            packed_coeffs[word] |= coeff << offset;
+
+            // The coefficient straddles two words
+            if offset + log_modulus_usize > Scalar::BITS {
+                packed_coeffs[word + 1] |= coeff >> (Scalar::BITS - offset);
+            }
+        }
+
+        Self {
+            packed_coeffs,
+            lwe_dimension: lwe_size.to_lwe_dimension(),
+            log_modulus,
+            uncompressed_ciphertext_modulus,
+        }
+    }
+
+    /// Create a [`ModulusSwitchedLweCiphertext`] from already packed coefficients.
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn from_raw_parts(
+        packed_coeffs: Vec<Scalar>,
+        lwe_dimension: LweDimension,
+        log_modulus: CiphertextModulusLog,
+        uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Self {
+        Self::assert_log_modulus_is_valid(log_modulus, uncompressed_ciphertext_modulus);
+
+        let expected_len = modulus_switched_lwe_ciphertext_packed_len::<Scalar>(
+            lwe_dimension.to_lwe_size(),
+            log_modulus,
+        );
+        assert_eq!(
+            packed_coeffs.len(),
+            expected_len,
+            "Got {} packed coefficients, expected {expected_len} for an LweDimension of \
+            {lwe_dimension:?} and a log modulus of {}",
+            packed_coeffs.len(),
+            log_modulus.0
+        );
+
+        Self {
+            packed_coeffs,
+            lwe_dimension,
+            log_modulus,
+            uncompressed_ciphertext_modulus,
+        }
+    }
+
+    /// Return the number of bits of the power of 2 `uncompressed_ciphertext_modulus`, [`None`] if
+    /// it is not a power of 2.
+    fn max_log_modulus(
+        uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) -> Option<usize> {
+        if uncompressed_ciphertext_modulus.is_native_modulus() {
+            Some(Scalar::BITS)
+        } else if uncompressed_ciphertext_modulus.is_compatible_with_native_modulus() {
+            Some(uncompressed_ciphertext_modulus.get_custom_modulus().ilog2() as usize)
+        } else {
+            None
+        }
+    }
+
+    fn assert_log_modulus_is_valid(
+        log_modulus: CiphertextModulusLog,
+        uncompressed_ciphertext_modulus: CiphertextModulus<Scalar>,
+    ) {
+        let Some(uncompressed_log_modulus) = Self::max_log_modulus(uncompressed_ciphertext_modulus)
+        else {
+            panic!(
+                "ModulusSwitchedLweCiphertext only supports power of 2 uncompressed moduli, \
+                got {uncompressed_ciphertext_modulus:?}"
+            );
+        };
+
+        let log_modulus = log_modulus.0;
+        assert!(
+            log_modulus > 0 && log_modulus <= uncompressed_log_modulus,
+            "log_modulus ({log_modulus}) must be in [1, {uncompressed_log_modulus}]"
+        );
+    }
+
+    /// Return the [`LweDimension`] of the [`ModulusSwitchedLweCiphertext`].
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn lwe_dimension(&self) -> LweDimension {
+        self.lwe_dimension
+    }
+
+    /// Return the [`LweSize`] of the [`ModulusSwitchedLweCiphertext`].
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn lwe_size(&self) -> LweSize {
+        self.lwe_dimension.to_lwe_size()
+    }
+
+    /// Return the [`CiphertextModulusLog`] of the [`ModulusSwitchedLweCiphertext`], i.e. the number
+    /// of bits stored per coefficient.
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn log_modulus(&self) -> CiphertextModulusLog {
+        self.log_modulus
+    }
+
+    /// Return the [`CiphertextModulus`] of the [`LWE ciphertext`](`LweCiphertext`) the
+    /// [`ModulusSwitchedLweCiphertext`] was switched from.
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn uncompressed_ciphertext_modulus(&self) -> CiphertextModulus<Scalar> {
+        self.uncompressed_ciphertext_modulus
+    }
+
+    /// Return a view of the packed coefficients.
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn packed_coeffs(&self) -> &[Scalar] {
+        &self.packed_coeffs
+    }
+
+    /// Consume the entity and return its underlying parts.
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        Vec<Scalar>,
+        LweDimension,
+        CiphertextModulusLog,
+        CiphertextModulus<Scalar>,
+    ) {
+        let Self {
+            packed_coeffs,
+            lwe_dimension,
+            log_modulus,
+            uncompressed_ciphertext_modulus,
+        } = self;
+
+        (
+            packed_coeffs,
+            lwe_dimension,
+            log_modulus,
+            uncompressed_ciphertext_modulus,
+        )
+    }
+
+    /// Return an iterator over the unpacked modulus switched coefficients, each one being in
+    /// $[0, 2^{\log\_2 q'})$, the last one being the body.
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn switched_coeffs(&self) -> impl ExactSizeIterator<Item = Scalar> + '_ {
+        let log_modulus = self.log_modulus.0;
+        let mask = if log_modulus == Scalar::BITS {
+            Scalar::MAX
+        } else {
+            (Scalar::ONE << log_modulus) - Scalar::ONE
+        };
+
+        (0..self.lwe_size().0).map(move |index| {
+            let start_bit = index * log_modulus;
+            let word = start_bit / Scalar::BITS;
+            let offset = start_bit % Scalar::BITS;
+
+            let mut coeff = self.packed_coeffs[word] >> offset;
+
+            // The coefficient straddles two words
+            if offset + log_modulus > Scalar::BITS {
+                coeff |= self.packed_coeffs[word + 1] << (Scalar::BITS - offset);
+            }
+
+            coeff & mask
+        })
+    }
+
+    /// Convert the [`ModulusSwitchedLweCiphertext`] back to an [`LWE ciphertext`](`LweCiphertext`)
+    /// under its uncompressed modulus.
+    ///
+    /// The bits lost during the modulus switch are set to 0, the resulting ciphertext encrypts
+    /// the same message as the original one with the additional modulus switching noise.
+    ///
+    /// See [`ModulusSwitchedLweCiphertext::from_switched_coeffs`] for usage.
+    pub fn extract(&self) -> LweCiphertextOwned<Scalar> {
+        // Power of 2 moduli are stored in the MSBs of the Scalar, whatever the uncompressed
+        // modulus the switched coefficients need to be shifted back to the MSBs
+        let shift = Scalar::BITS - self.log_modulus.0;
+
+        let container: Vec<Scalar> = self.switched_coeffs().map(|coeff| coeff << shift).collect();
+
+        LweCiphertext::from_container(container, self.uncompressed_ciphertext_modulus)
+    }
+}
@@ -1,5 +1,6 @@
 pub mod bootstrap;
 pub mod ggsw;
+pub mod multi_bit_bootstrap;
 
 #[cfg(test)]
 pub mod tests;
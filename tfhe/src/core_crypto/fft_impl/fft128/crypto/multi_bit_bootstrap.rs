@@ -0,0 +1,382 @@
+use super::super::math::fft::Fft128View;
+use super::ggsw::{update_with_fmadd, Fourier128GgswCiphertext};
+use crate::core_crypto::commons::math::torus::UnsignedTorus;
+use crate::core_crypto::commons::numeric::{CastFrom, CastInto};
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweBskGroupingFactor,
+    LweDimension, ModulusSwitchOffset, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContainerMut, ContiguousEntityContainer, Split,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::common::fast_pbs_modulus_switch;
+use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// A multi bit bootstrapping key in the Fourier domain, using a 128 bits floating point
+/// representation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct Fourier128LweMultiBitBootstrapKey<C: Container<Element = f64>> {
+    data_re0: C,
+    data_re1: C,
+    data_im0: C,
+    data_im1: C,
+    polynomial_size: PolynomialSize,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+}
+
+fn fourier_128_multi_bit_bsk_container_len(
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level_count: DecompositionLevelCount,
+    grouping_factor: LweBskGroupingFactor,
+) -> usize {
+    let ggsw_count = (input_lwe_dimension.0 / grouping_factor.0)
+        * grouping_factor.ggsw_per_multi_bit_element().0;
+    ggsw_count
+        * polynomial_size.to_fourier_polynomial_size().0
+        * decomposition_level_count.0
+        * glwe_size.0
+        * glwe_size.0
+}
+
+impl<C: Container<Element = f64>> Fourier128LweMultiBitBootstrapKey<C> {
+    pub fn from_container(
+        data_re0: C,
+        data_re1: C,
+        data_im0: C,
+        data_im1: C,
+        polynomial_size: PolynomialSize,
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+    ) -> Self {
+        assert_eq!(polynomial_size.0 % 2, 0);
+        assert!(
+            input_lwe_dimension.0 % grouping_factor.0 == 0,
+            "Multi Bit BSK requires input LWE dimension ({}) to be a multiple of {}",
+            input_lwe_dimension.0,
+            grouping_factor.0
+        );
+        let container_len = fourier_128_multi_bit_bsk_container_len(
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_level_count,
+            grouping_factor,
+        );
+        assert_eq!(data_re0.container_len(), container_len);
+        assert_eq!(data_re1.container_len(), container_len);
+        assert_eq!(data_im0.container_len(), container_len);
+        assert_eq!(data_im1.container_len(), container_len);
+        Self {
+            data_re0,
+            data_re1,
+            data_im0,
+            data_im1,
+            polynomial_size,
+            input_lwe_dimension,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            grouping_factor,
+        }
+    }
+
+    /// Return an iterator over the GGSW ciphertexts composing the key.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = Fourier128GgswCiphertext<C>>
+    where
+        C: Split,
+    {
+        let ggsw_count = self.multi_bit_input_lwe_dimension().0
+            * self.grouping_factor.ggsw_per_multi_bit_element().0;
+        izip!(
+            self.data_re0.split_into(ggsw_count),
+            self.data_re1.split_into(ggsw_count),
+            self.data_im0.split_into(ggsw_count),
+            self.data_im1.split_into(ggsw_count),
+        )
+        .map(move |(data_re0, data_re1, data_im0, data_im1)| {
+            Fourier128GgswCiphertext::from_container(
+                data_re0,
+                data_re1,
+                data_im0,
+                data_im1,
+                self.polynomial_size,
+                self.glwe_size,
+                self.decomposition_base_log,
+                self.decomposition_level_count,
+            )
+        })
+    }
+
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    pub fn multi_bit_input_lwe_dimension(&self) -> LweDimension {
+        LweDimension(self.input_lwe_dimension.0 / self.grouping_factor.0)
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        LweDimension((self.glwe_size.0 - 1) * self.polynomial_size().0)
+    }
+
+    pub fn grouping_factor(&self) -> LweBskGroupingFactor {
+        self.grouping_factor
+    }
+
+    pub fn data(self) -> (C, C, C, C) {
+        (self.data_re0, self.data_re1, self.data_im0, self.data_im1)
+    }
+
+    pub fn as_view(&self) -> Fourier128LweMultiBitBootstrapKey<&[C::Element]> {
+        Fourier128LweMultiBitBootstrapKey {
+            data_re0: self.data_re0.as_ref(),
+            data_re1: self.data_re1.as_ref(),
+            data_im0: self.data_im0.as_ref(),
+            data_im1: self.data_im1.as_ref(),
+            polynomial_size: self.polynomial_size,
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> Fourier128LweMultiBitBootstrapKey<&mut [C::Element]>
+    where
+        C: AsMut<[C::Element]>,
+    {
+        Fourier128LweMultiBitBootstrapKey {
+            data_re0: self.data_re0.as_mut(),
+            data_re1: self.data_re1.as_mut(),
+            data_im0: self.data_im0.as_mut(),
+            data_im1: self.data_im1.as_mut(),
+            polynomial_size: self.polynomial_size,
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            grouping_factor: self.grouping_factor,
+        }
+    }
+}
+
+pub type Fourier128LweMultiBitBootstrapKeyOwned = Fourier128LweMultiBitBootstrapKey<ABox<[f64]>>;
+
+impl Fourier128LweMultiBitBootstrapKey<ABox<[f64]>> {
+    pub fn new(
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        grouping_factor: LweBskGroupingFactor,
+    ) -> Self {
+        assert!(
+            input_lwe_dimension.0 % grouping_factor.0 == 0,
+            "Multi Bit BSK requires input LWE dimension ({}) to be a multiple of {}",
+            input_lwe_dimension.0,
+            grouping_factor.0
+        );
+        let container_len = fourier_128_multi_bit_bsk_container_len(
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_level_count,
+            grouping_factor,
+        );
+
+        let boxed_re0 = avec![0.0f64; container_len].into_boxed_slice();
+        let boxed_re1 = avec![0.0f64; container_len].into_boxed_slice();
+        let boxed_im0 = avec![0.0f64; container_len].into_boxed_slice();
+        let boxed_im1 = avec![0.0f64; container_len].into_boxed_slice();
+
+        Fourier128LweMultiBitBootstrapKey::from_container(
+            boxed_re0,
+            boxed_re1,
+            boxed_im0,
+            boxed_im1,
+            polynomial_size,
+            input_lwe_dimension,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            grouping_factor,
+        )
+    }
+}
+
+impl<Cont> Fourier128LweMultiBitBootstrapKey<Cont>
+where
+    Cont: ContainerMut<Element = f64>,
+{
+    /// Fill a multi bit bootstrapping key with the Fourier transform of a multi bit bootstrapping
+    /// key in the standard domain.
+    pub fn fill_with_forward_fourier<Scalar, ContBsk>(
+        &mut self,
+        coef_bsk: &LweMultiBitBootstrapKey<ContBsk>,
+        fft: Fft128View<'_>,
+    ) where
+        Scalar: UnsignedTorus,
+        ContBsk: Container<Element = Scalar>,
+    {
+        fn implementation<Scalar: UnsignedTorus>(
+            this: Fourier128LweMultiBitBootstrapKey<&mut [f64]>,
+            coef_bsk: &LweMultiBitBootstrapKey<&[Scalar]>,
+            fft: Fft128View<'_>,
+        ) {
+            for (mut fourier_ggsw, standard_ggsw) in izip!(this.into_ggsw_iter(), coef_bsk.iter()) {
+                fourier_ggsw.fill_with_forward_fourier(&standard_ggsw, fft);
+            }
+        }
+        implementation(self.as_mut_view(), &coef_bsk.as_view(), fft);
+    }
+}
+
+/// Return the required memory for [`prepare_multi_bit_ggsw_f128`].
+pub fn prepare_multi_bit_ggsw_f128_scratch<Scalar>(
+    fft: Fft128View<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    let polynomial_size = fft.polynomial_size();
+    let monomial_scratch = StackReq::try_new_aligned::<Scalar>(polynomial_size.0, CACHELINE_ALIGN)?;
+    let fourier_scratch_single = StackReq::try_new_aligned::<f64>(
+        polynomial_size.to_fourier_polynomial_size().0,
+        CACHELINE_ALIGN,
+    )?;
+    monomial_scratch.try_and(StackReq::try_all_of([fourier_scratch_single; 4])?)
+}
+
+/// Compute in `fourier_ggsw_buffer` the GGSW ciphertext used to process the `lwe_mask_elements`
+/// of a group during a multi bit blind rotation, from the GGSW ciphertexts of the corresponding
+/// group of the multi bit bootstrapping key.
+///
+/// Each GGSW of the group is multiplied by its monomial directly in the Fourier domain: the
+/// monomial is transformed with a single forward FFT which is then applied to all the polynomials
+/// of the GGSW, avoiding the conversion of the whole GGSW ciphertext.
+pub fn prepare_multi_bit_ggsw_f128<Scalar, GgswBufferCont, GgswGroupCont>(
+    fourier_ggsw_buffer: &mut Fourier128GgswCiphertext<GgswBufferCont>,
+    ggsw_group: &[Fourier128GgswCiphertext<GgswGroupCont>],
+    lwe_mask_elements: &[Scalar],
+    fft: Fft128View<'_>,
+    stack: PodStack<'_>,
+) where
+    Scalar: UnsignedTorus + CastInto<usize> + CastFrom<usize>,
+    GgswBufferCont: ContainerMut<Element = f64>,
+    GgswGroupCont: Container<Element = f64>,
+{
+    let polynomial_size = fft.polynomial_size();
+    let fourier_poly_size = polynomial_size.to_fourier_polynomial_size().0;
+    let align = CACHELINE_ALIGN;
+
+    let (mut a_monomial, stack) = stack.make_aligned_raw::<Scalar>(polynomial_size.0, align);
+    let (mut fourier_re0, stack) = stack.make_aligned_raw::<f64>(fourier_poly_size, align);
+    let (mut fourier_re1, stack) = stack.make_aligned_raw::<f64>(fourier_poly_size, align);
+    let (mut fourier_im0, stack) = stack.make_aligned_raw::<f64>(fourier_poly_size, align);
+    let (mut fourier_im1, _) = stack.make_aligned_raw::<f64>(fourier_poly_size, align);
+
+    let mut ggsw_group_iter = ggsw_group.iter();
+
+    // Keygen guarantees the first term is a constant term of the polynomial, no
+    // polynomial multiplication required
+    let ggsw_a_none = ggsw_group_iter.next().unwrap();
+
+    {
+        let (buffer_re0, buffer_re1, buffer_im0, buffer_im1) =
+            fourier_ggsw_buffer.as_mut_view().data();
+        let (a_none_re0, a_none_re1, a_none_im0, a_none_im1) = ggsw_a_none.as_view().data();
+        buffer_re0.copy_from_slice(a_none_re0);
+        buffer_re1.copy_from_slice(a_none_re1);
+        buffer_im0.copy_from_slice(a_none_im0);
+        buffer_im1.copy_from_slice(a_none_im1);
+    }
+
+    for (ggsw_idx, fourier_ggsw) in ggsw_group_iter.enumerate() {
+        // We already processed the first ggsw, advance the index by 1
+        let ggsw_idx = ggsw_idx + 1;
+
+        // Select the proper mask elements to build the monomial degree depending on
+        // the order the GGSW were generated in, using the bits from mask_idx and
+        // ggsw_idx as selector bits
+        let mut monomial_degree = Scalar::ZERO;
+        for (mask_idx, &mask_element) in lwe_mask_elements.iter().enumerate() {
+            let mask_position = lwe_mask_elements.len() - (mask_idx + 1);
+            let selection_bit: Scalar = Scalar::cast_from((ggsw_idx >> mask_position) & 1);
+            monomial_degree =
+                monomial_degree.wrapping_add(selection_bit.wrapping_mul(mask_element));
+        }
+
+        let switched_degree = fast_pbs_modulus_switch(
+            monomial_degree,
+            polynomial_size,
+            ModulusSwitchOffset(0),
+            LutCountLog(0),
+        );
+
+        // X^degree in the negacyclic ring, the switched degree lives in [0, 2N) and
+        // X^(N + d) = -X^d
+        a_monomial.fill(Scalar::ZERO);
+        if switched_degree < polynomial_size.0 {
+            a_monomial[switched_degree] = Scalar::ONE;
+        } else {
+            a_monomial[switched_degree - polynomial_size.0] = Scalar::MAX;
+        }
+
+        fft.forward_as_integer(
+            &mut fourier_re0,
+            &mut fourier_re1,
+            &mut fourier_im0,
+            &mut fourier_im1,
+            &a_monomial,
+        );
+
+        for (buffer_level, ggsw_level) in izip!(
+            fourier_ggsw_buffer.as_mut_view().into_levels(),
+            fourier_ggsw.as_view().into_levels(),
+        ) {
+            for (buffer_row, ggsw_row) in izip!(buffer_level.into_rows(), ggsw_level.into_rows()) {
+                let (buffer_re0, buffer_re1, buffer_im0, buffer_im1) = buffer_row.data();
+                update_with_fmadd(
+                    buffer_re0,
+                    buffer_re1,
+                    buffer_im0,
+                    buffer_im1,
+                    ggsw_row,
+                    &fourier_re0,
+                    &fourier_re1,
+                    &fourier_im0,
+                    &fourier_im1,
+                    false,
+                    fourier_poly_size,
+                );
+            }
+        }
+    }
+}
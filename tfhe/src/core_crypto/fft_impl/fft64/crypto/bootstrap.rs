@@ -6,9 +6,10 @@ use crate::core_crypto::commons::math::decomposition::SignedDecomposer;
 use crate::core_crypto::commons::math::torus::UnsignedTorus;
 use crate::core_crypto::commons::numeric::CastInto;
 use crate::core_crypto::commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweDimension,
-    ModulusSwitchOffset, MonomialDegree, PolynomialSize,
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LutCountLog, LweCiphertextCount,
+    LweDimension, ModulusSwitchOffset, MonomialDegree, PolynomialSize,
 };
+use crate::core_crypto::commons::raw_key_format::*;
 use crate::core_crypto::commons::traits::{
     Container, ContiguousEntityContainer, ContiguousEntityContainerMut, IntoContainerOwned, Split,
 };
@@ -138,6 +139,94 @@ impl<C: Container<Element = c64>> FourierLweBootstrapKey<C> {
             decomposition_level_count: self.decomposition_level_count,
         }
     }
+
+    /// Write the bootstrapping key in the raw key format, see
+    /// [`FourierLweBootstrapKeyView::from_raw_bytes`] to use it in place without deserialization.
+    pub fn write_raw<W: std::io::Write>(&self, mut writer: W) -> std::io::Result<()> {
+        write_fourier_lwe_bootstrap_key_raw_header(
+            &mut writer,
+            self.input_lwe_dimension,
+            self.glwe_size,
+            self.fourier.polynomial_size,
+            self.decomposition_base_log,
+            self.decomposition_level_count,
+        )?;
+        writer.write_all(bytemuck::cast_slice(self.fourier.data.as_ref()))
+    }
+}
+
+/// Write the header of a [`FourierLweBootstrapKey`] in the raw key format, the key data must be
+/// written right after it.
+pub(crate) fn write_fourier_lwe_bootstrap_key_raw_header<W: std::io::Write>(
+    writer: W,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+) -> std::io::Result<()> {
+    write_raw_key_header::<c64, _>(
+        writer,
+        RawKeyKind::FourierLweBootstrapKey,
+        [
+            input_lwe_dimension.0 as u64,
+            glwe_size.0 as u64,
+            polynomial_size.0 as u64,
+            decomposition_base_log.0 as u64,
+            decomposition_level_count.0 as u64,
+            0,
+            0,
+            0,
+        ],
+        input_lwe_dimension.0
+            * polynomial_size.to_fourier_polynomial_size().0
+            * decomposition_level_count.0
+            * glwe_size.0
+            * glwe_size.0,
+    )
+}
+
+impl<'a> FourierLweBootstrapKeyView<'a> {
+    /// Create a bootstrapping key over bytes in the raw key format written by
+    /// [`FourierLweBootstrapKey::write_raw`], typically a memory mapped file.
+    ///
+    /// The key data is used in place, the bytes must therefore be aligned for [`c64`], which is
+    /// always the case for a page aligned mapping of a whole file. The header and the key
+    /// parameters are validated and an error is returned if they are inconsistent.
+    pub fn from_raw_bytes(bytes: &'a [u8]) -> Result<Self, RawKeyError> {
+        let (parameters, data) = parse_raw_key::<c64>(bytes, RawKeyKind::FourierLweBootstrapKey)?;
+        let [lwe_dimension, glwe_size, polynomial_size, base_log, level_count] =
+            raw_key_usize_parameters(&parameters)?;
+
+        if lwe_dimension == 0 || glwe_size == 0 || base_log == 0 || level_count == 0 {
+            return Err(RawKeyError::InvalidParameters(
+                "key parameters must be non zero",
+            ));
+        }
+        if polynomial_size < 2 || !polynomial_size.is_power_of_two() {
+            return Err(RawKeyError::InvalidParameters(
+                "polynomial size must be a power of 2 greater than 1",
+            ));
+        }
+
+        let expected_len = raw_key_expected_len(&[
+            lwe_dimension,
+            polynomial_size / 2,
+            level_count,
+            glwe_size,
+            glwe_size,
+        ])?;
+        check_raw_key_len(expected_len, data.len())?;
+
+        Ok(Self::from_container(
+            data,
+            LweDimension(lwe_dimension),
+            GlweSize(glwe_size),
+            PolynomialSize(polynomial_size),
+            DecompositionBaseLog(base_log),
+            DecompositionLevelCount(level_count),
+        ))
+    }
 }
 
 pub type FourierLweBootstrapKeyOwned = FourierLweBootstrapKey<ABox<[c64]>>;
@@ -237,26 +326,81 @@ pub fn bootstrap_scratch<Scalar>(
     )
 }
 
+/// Return the required memory for [`FourierLweBootstrapKeyView::batch_blind_rotate_assign`].
+///
+/// The scratch memory does not depend on the number of look-up tables being rotated, it is shared
+/// by all the blind rotations of the batch.
+pub fn batch_blind_rotate_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_all_of([
+        // ct1 allocation
+        StackReq::try_new_aligned::<Scalar>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?,
+        // external product
+        add_external_product_assign_scratch::<Scalar>(glwe_size, polynomial_size, fft)?,
+    ])
+}
+
+/// Return the required memory for [`FourierLweBootstrapKeyView::batch_bootstrap`].
+pub fn batch_bootstrap_scratch<Scalar>(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    lwe_ciphertext_count: LweCiphertextCount,
+    fft: FftView<'_>,
+) -> Result<StackReq, SizeOverflow> {
+    batch_blind_rotate_scratch::<Scalar>(glwe_size, polynomial_size, fft)?.try_and(
+        StackReq::try_new_aligned::<Scalar>(
+            lwe_ciphertext_count.0 * glwe_size.0 * polynomial_size.0,
+            CACHELINE_ALIGN,
+        )?,
+    )
+}
+
 impl<'a> FourierLweBootstrapKeyView<'a> {
     // CastInto required for PBS modulus switch which returns a usize
     pub fn blind_rotate_assign<Scalar: UnsignedTorus + CastInto<usize>>(
         self,
-        mut lut: GlweCiphertextMutView<'_, Scalar>,
+        lut: GlweCiphertextMutView<'_, Scalar>,
         lwe: &[Scalar],
         fft: FftView<'_>,
-        mut stack: PodStack<'_>,
+        stack: PodStack<'_>,
     ) {
         let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
 
+        let lut_poly_size = lut.polynomial_size();
+        let switch_to_monomial_degree = |value: Scalar| {
+            MonomialDegree(fast_pbs_modulus_switch(
+                value,
+                lut_poly_size,
+                ModulusSwitchOffset(0),
+                LutCountLog(0),
+            ))
+        };
+
+        self.blind_rotate_assign_with_monomial_degrees(
+            lut,
+            switch_to_monomial_degree(*lwe_body),
+            lwe_mask.iter().map(|&x| switch_to_monomial_degree(x)),
+            fft,
+            stack,
+        );
+    }
+
+    /// Perform the blind rotation of `lut` by an LWE ciphertext whose coefficients have already
+    /// been switched to $\mathbb{Z}\_{2N}$ and are given as [`MonomialDegree`].
+    pub fn blind_rotate_assign_with_monomial_degrees<Scalar: UnsignedTorus>(
+        self,
+        mut lut: GlweCiphertextMutView<'_, Scalar>,
+        body_monomial_degree: MonomialDegree,
+        mask_monomial_degrees: impl ExactSizeIterator<Item = MonomialDegree>,
+        fft: FftView<'_>,
+        mut stack: PodStack<'_>,
+    ) {
         let lut_poly_size = lut.polynomial_size();
         let ciphertext_modulus = lut.ciphertext_modulus();
         assert!(ciphertext_modulus.is_compatible_with_native_modulus());
-        let monomial_degree = MonomialDegree(fast_pbs_modulus_switch(
-            *lwe_body,
-            lut_poly_size,
-            ModulusSwitchOffset(0),
-            LutCountLog(0),
-        ));
 
         lut.as_mut_polynomial_list()
             .iter_mut()
@@ -267,7 +411,7 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
 
                 let mut tmp_poly = Polynomial::from_container(&mut *tmp_poly);
                 tmp_poly.as_mut().copy_from_slice(poly.as_ref());
-                polynomial_wrapping_monic_monomial_div(&mut poly, &tmp_poly, monomial_degree);
+                polynomial_wrapping_monic_monomial_div(&mut poly, &tmp_poly, body_monomial_degree);
             });
 
         // We initialize the ct_0 used for the successive cmuxes
@@ -276,16 +420,11 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
         let mut ct1 =
             GlweCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
 
-        for (lwe_mask_element, bootstrap_key_ggsw) in izip!(lwe_mask.iter(), self.into_ggsw_iter())
+        for (monomial_degree, bootstrap_key_ggsw) in
+            izip!(mask_monomial_degrees, self.into_ggsw_iter())
         {
-            if *lwe_mask_element != Scalar::ZERO {
-                let monomial_degree = MonomialDegree(fast_pbs_modulus_switch(
-                    *lwe_mask_element,
-                    lut_poly_size,
-                    ModulusSwitchOffset(0),
-                    LutCountLog(0),
-                ));
-
+            // A rotation by X^0 makes the cmux an identity, the external product can be skipped
+            if monomial_degree.0 % (2 * lut_poly_size.0) != 0 {
                 // we effectively inline the body of cmux here, merging the initial subtraction
                 // operation with the monic polynomial multiplication, then performing the external
                 // product manually
@@ -362,6 +501,155 @@ impl<'a> FourierLweBootstrapKeyView<'a> {
             MonomialDegree(0),
         );
     }
+
+    /// Perform the blind rotation of each look-up table in `luts` by the LWE ciphertext with the
+    /// same index in `lwe_list`.
+    ///
+    /// The bootstrap key is iterated over in the outer loop, each GGSW ciphertext is loaded once
+    /// and applied to all the look-up tables of the batch before moving on to the next one.
+    // CastInto required for PBS modulus switch which returns a usize
+    pub fn batch_blind_rotate_assign<Scalar: UnsignedTorus + CastInto<usize>>(
+        self,
+        mut luts: GlweCiphertextListMutView<'_, Scalar>,
+        lwe_list: LweCiphertextListView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) {
+        assert_eq!(
+            luts.glwe_ciphertext_count().0,
+            lwe_list.lwe_ciphertext_count().0
+        );
+
+        let lut_poly_size = luts.polynomial_size();
+        let ciphertext_modulus = luts.ciphertext_modulus();
+        assert!(ciphertext_modulus.is_compatible_with_native_modulus());
+
+        let switch_to_monomial_degree = |value: Scalar| {
+            MonomialDegree(fast_pbs_modulus_switch(
+                value,
+                lut_poly_size,
+                ModulusSwitchOffset(0),
+                LutCountLog(0),
+            ))
+        };
+
+        for (mut lut, lwe) in izip!(luts.iter_mut(), lwe_list.iter()) {
+            let body_monomial_degree = switch_to_monomial_degree(*lwe.get_body().data);
+            lut.as_mut_polynomial_list()
+                .iter_mut()
+                .for_each(|mut poly| {
+                    polynomial_wrapping_monic_monomial_div_assign(&mut poly, body_monomial_degree);
+                });
+        }
+
+        // A single ct1 buffer is shared by the cmuxes of all the look-up tables
+        let (mut ct1, mut stack) =
+            stack.make_aligned_raw(luts.glwe_size().0 * lut_poly_size.0, CACHELINE_ALIGN);
+        let mut ct1 =
+            GlweCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
+
+        for (mask_idx, bootstrap_key_ggsw) in self.into_ggsw_iter().enumerate() {
+            for (mut ct0, lwe) in izip!(luts.iter_mut(), lwe_list.iter()) {
+                let monomial_degree = switch_to_monomial_degree(lwe.as_ref()[mask_idx]);
+
+                // A rotation by X^0 makes the cmux an identity, the external product can be
+                // skipped
+                if monomial_degree.0 % (2 * lut_poly_size.0) == 0 {
+                    continue;
+                }
+
+                // ct_1 <- (ct_0 * X^{a_hat}) - ct_0
+                for (mut ct1_poly, ct0_poly) in izip!(
+                    ct1.as_mut_polynomial_list().iter_mut(),
+                    ct0.as_polynomial_list().iter(),
+                ) {
+                    polynomial_wrapping_monic_monomial_mul_and_subtract(
+                        &mut ct1_poly,
+                        &ct0_poly,
+                        monomial_degree,
+                    );
+                }
+
+                add_external_product_assign(
+                    ct0.as_mut_view(),
+                    bootstrap_key_ggsw,
+                    ct1.as_view(),
+                    fft,
+                    stack.rb_mut(),
+                );
+            }
+        }
+
+        if !ciphertext_modulus.is_native_modulus() {
+            // When we convert back from the fourier domain, integer values will contain up to 53
+            // MSBs with information. In our representation of power of 2 moduli < native modulus we
+            // fill the MSBs and leave the LSBs empty, this usage of the signed decomposer allows to
+            // round while keeping the data in the MSBs
+            let signed_decomposer = SignedDecomposer::new(
+                DecompositionBaseLog(ciphertext_modulus.get_custom_modulus().ilog2() as usize),
+                DecompositionLevelCount(1),
+            );
+            luts.as_mut()
+                .iter_mut()
+                .for_each(|x| *x = signed_decomposer.closest_representable(*x));
+        }
+    }
+
+    /// Bootstrap all the ciphertexts of `lwe_in` with the same `accumulator`, see
+    /// [`Self::batch_blind_rotate_assign`].
+    pub fn batch_bootstrap<Scalar>(
+        self,
+        mut lwe_out: LweCiphertextListMutView<'_, Scalar>,
+        lwe_in: LweCiphertextListView<'_, Scalar>,
+        accumulator: GlweCiphertextView<'_, Scalar>,
+        fft: FftView<'_>,
+        stack: PodStack<'_>,
+    ) where
+        // CastInto required for PBS modulus switch which returns a usize
+        Scalar: UnsignedTorus + CastInto<usize>,
+    {
+        debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+        debug_assert_eq!(
+            lwe_in.ciphertext_modulus(),
+            accumulator.ciphertext_modulus()
+        );
+        assert_eq!(
+            lwe_out.lwe_ciphertext_count(),
+            lwe_in.lwe_ciphertext_count()
+        );
+
+        let (mut local_accumulators_data, stack) = stack.collect_aligned(
+            CACHELINE_ALIGN,
+            (0..lwe_in.lwe_ciphertext_count().0).flat_map(|_| accumulator.as_ref().iter().copied()),
+        );
+        self.batch_blind_rotate_assign(
+            GlweCiphertextListMutView::from_container(
+                &mut *local_accumulators_data,
+                accumulator.glwe_size(),
+                accumulator.polynomial_size(),
+                accumulator.ciphertext_modulus(),
+            ),
+            lwe_in,
+            fft,
+            stack,
+        );
+
+        let local_accumulators = GlweCiphertextListView::from_container(
+            &*local_accumulators_data,
+            accumulator.glwe_size(),
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+
+        for (local_accumulator, mut lwe_out) in izip!(local_accumulators.iter(), lwe_out.iter_mut())
+        {
+            extract_lwe_sample_from_glwe_ciphertext(
+                &local_accumulator,
+                &mut lwe_out,
+                MonomialDegree(0),
+            );
+        }
+    }
 }
 
 impl<Scalar> FourierBootstrapKey<Scalar> for FourierLweBootstrapKeyOwned
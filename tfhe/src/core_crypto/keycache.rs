@@ -53,6 +53,7 @@ where
 pub struct KeyCache {
     u32_multi_bit_cache: KeyCacheCoreImpl<MultiBitTestParams<u32>, MultiBitBootstrapKeys<u32>>,
     u64_multi_bit_cache: KeyCacheCoreImpl<MultiBitTestParams<u64>, MultiBitBootstrapKeys<u64>>,
+    u128_multi_bit_cache: KeyCacheCoreImpl<MultiBitTestParams<u128>, MultiBitBootstrapKeys<u128>>,
     u32_classic_cache: KeyCacheCoreImpl<ClassicTestParams<u32>, ClassicBootstrapKeys<u32>>,
     u64_classic_cache: KeyCacheCoreImpl<ClassicTestParams<u64>, ClassicBootstrapKeys<u64>>,
     u128_classic_cache: KeyCacheCoreImpl<ClassicTestParams<u128>, ClassicBootstrapKeys<u128>>,
@@ -105,6 +106,14 @@ impl KeyCacheAccess for MultiBitTestParams<u64> {
     }
 }
 
+impl KeyCacheAccess for MultiBitTestParams<u128> {
+    type Keys = MultiBitBootstrapKeys<u128>;
+
+    fn access(keycache: &KeyCache) -> &KeyCacheCoreImpl<Self, Self::Keys> {
+        &keycache.u128_multi_bit_cache
+    }
+}
+
 impl KeyCacheAccess for ClassicTestParams<u32> {
     type Keys = ClassicBootstrapKeys<u32>;
 
@@ -17,6 +17,7 @@ pub mod prelude;
 pub mod seeders;
 
 pub mod fft_impl;
+pub mod ntt_impl;
 
 #[cfg(test)]
 pub mod keycache;
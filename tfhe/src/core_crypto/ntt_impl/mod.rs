@@ -0,0 +1,9 @@
+//! Number theoretic transform based implementations of the polynomial products used in the
+//! bootstrapping, for prime ciphertext moduli.
+//!
+//! Contrary to the Fourier transform based implementations in
+//! [`fft_impl`](`crate::core_crypto::fft_impl`), polynomial products computed with the NTT are
+//! exact, which makes it possible to run a bootstrap without the noise induced by the floating
+//! point arithmetic of the FFT, at the cost of requiring an NTT friendly prime modulus $q$, i.e.
+//! $q \equiv 1 \mod 2N$ where $N$ is the polynomial size.
+pub mod ntt64;
@@ -0,0 +1,355 @@
+use super::super::math::ntt::Ntt64View;
+use super::ggsw::*;
+use crate::core_crypto::algorithms::extract_lwe_sample_from_glwe_ciphertext;
+use crate::core_crypto::algorithms::polynomial_algorithms::*;
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, MonomialDegree,
+    PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContainerMut, ContiguousEntityContainer, ContiguousEntityContainerMut, Split,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
+use dyn_stack::{PodStack, ReborrowMut, SizeOverflow, StackReq};
+use rayon::prelude::*;
+
+/// An [`LWE bootstrap key`](`LweBootstrapKey`) in the NTT domain, for prime ciphertext moduli.
+#[derive(Clone, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NttLweBootstrapKey<C: Container<Element = u64>> {
+    data: C,
+    input_lwe_dimension: LweDimension,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<u64>,
+}
+
+pub type NttLweBootstrapKeyOwned = NttLweBootstrapKey<ABox<[u64]>>;
+pub type NttLweBootstrapKeyView<'a> = NttLweBootstrapKey<&'a [u64]>;
+pub type NttLweBootstrapKeyMutView<'a> = NttLweBootstrapKey<&'a mut [u64]>;
+
+impl<C: Container<Element = u64>> NttLweBootstrapKey<C> {
+    pub fn from_container(
+        data: C,
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<u64>,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            input_lwe_dimension.0
+                * polynomial_size.0
+                * decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+        );
+        Self {
+            data,
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        }
+    }
+
+    /// Return an iterator over the GGSW ciphertexts composing the key.
+    pub fn into_ggsw_iter(self) -> impl DoubleEndedIterator<Item = NttGgswCiphertext<C>>
+    where
+        C: Split,
+    {
+        self.data
+            .split_into(self.input_lwe_dimension.0)
+            .map(move |slice| {
+                NttGgswCiphertext::from_container(
+                    slice,
+                    self.glwe_size,
+                    self.polynomial_size,
+                    self.decomposition_base_log,
+                    self.decomposition_level_count,
+                    self.ciphertext_modulus,
+                )
+            })
+    }
+
+    pub fn input_lwe_dimension(&self) -> LweDimension {
+        self.input_lwe_dimension
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn output_lwe_dimension(&self) -> LweDimension {
+        self.glwe_size
+            .to_glwe_dimension()
+            .to_equivalent_lwe_dimension(self.polynomial_size)
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<u64> {
+        self.ciphertext_modulus
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttLweBootstrapKeyView<'_> {
+        NttLweBootstrapKeyView {
+            data: self.data.as_ref(),
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            polynomial_size: self.polynomial_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> NttLweBootstrapKeyMutView<'_>
+    where
+        C: AsMut<[u64]>,
+    {
+        NttLweBootstrapKeyMutView {
+            data: self.data.as_mut(),
+            input_lwe_dimension: self.input_lwe_dimension,
+            glwe_size: self.glwe_size,
+            polynomial_size: self.polynomial_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl NttLweBootstrapKeyOwned {
+    pub fn new(
+        input_lwe_dimension: LweDimension,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<u64>,
+    ) -> Self {
+        let boxed = avec![
+            0u64;
+            polynomial_size.0
+                * input_lwe_dimension.0
+                * decomposition_level_count.0
+                * glwe_size.0
+                * glwe_size.0
+        ]
+        .into_boxed_slice();
+
+        Self::from_container(
+            boxed,
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl NttLweBootstrapKeyMutView<'_> {
+    /// Fill a bootstrapping key with the NTT of a bootstrapping key in the standard domain.
+    pub fn fill_with_forward_ntt<InputCont>(
+        self,
+        coef_bsk: &LweBootstrapKey<InputCont>,
+        ntt: Ntt64View<'_>,
+    ) where
+        InputCont: Container<Element = u64>,
+    {
+        for (ntt_ggsw, standard_ggsw) in izip!(self.into_ggsw_iter(), coef_bsk.iter()) {
+            ntt_ggsw.fill_with_forward_ntt(&standard_ggsw, ntt);
+        }
+    }
+
+    /// Parallel variant of [`Self::fill_with_forward_ntt`].
+    pub fn par_fill_with_forward_ntt<InputCont>(
+        self,
+        coef_bsk: &LweBootstrapKey<InputCont>,
+        ntt: Ntt64View<'_>,
+    ) where
+        InputCont: Container<Element = u64>,
+    {
+        let poly_size = self.polynomial_size.0;
+
+        self.data
+            .par_chunks_exact_mut(poly_size)
+            .zip(coef_bsk.as_ref().par_chunks_exact(poly_size))
+            .for_each(|(ntt_poly, coef_poly)| {
+                ntt_poly.copy_from_slice(coef_poly);
+                ntt.forward(ntt_poly);
+            });
+    }
+}
+
+/// Return the required memory for [`NttLweBootstrapKeyView::blind_rotate_assign`].
+pub fn blind_rotate_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    StackReq::try_any_of([
+        // tmp_poly allocation
+        StackReq::try_new_aligned::<u64>(polynomial_size.0, CACHELINE_ALIGN)?,
+        StackReq::try_all_of([
+            // ct1 allocation
+            StackReq::try_new_aligned::<u64>(glwe_size.0 * polynomial_size.0, CACHELINE_ALIGN)?,
+            // external product
+            add_external_product_assign_scratch(glwe_size, polynomial_size)?,
+        ])?,
+    ])
+}
+
+/// Return the required memory for [`NttLweBootstrapKeyView::bootstrap`].
+pub fn bootstrap_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    blind_rotate_scratch(glwe_size, polynomial_size)?.try_and(StackReq::try_new_aligned::<u64>(
+        glwe_size.0 * polynomial_size.0,
+        CACHELINE_ALIGN,
+    )?)
+}
+
+/// Switch a value from $\mathbb{Z}\_q$ to $\mathbb{Z}\_{2N}$, i.e. compute
+/// $\lfloor \frac{2N \cdot x}{q} \rceil \mod 2N$.
+fn pbs_modulus_switch_custom_mod(
+    input: u64,
+    polynomial_size: PolynomialSize,
+    ciphertext_modulus: u64,
+) -> usize {
+    let modulus = ciphertext_modulus as u128;
+    let double_poly_size = 2 * polynomial_size.0 as u128;
+    let switched = (input as u128 * double_poly_size + modulus / 2) / modulus;
+    (switched % double_poly_size) as usize
+}
+
+impl NttLweBootstrapKeyView<'_> {
+    pub fn blind_rotate_assign<OutputCont>(
+        self,
+        lut: &mut GlweCiphertext<OutputCont>,
+        lwe: &[u64],
+        ntt: Ntt64View<'_>,
+        mut stack: PodStack<'_>,
+    ) where
+        OutputCont: ContainerMut<Element = u64>,
+    {
+        let (lwe_body, lwe_mask) = lwe.split_last().unwrap();
+
+        let lut_poly_size = lut.polynomial_size();
+        let ciphertext_modulus = lut.ciphertext_modulus();
+        let modulus = ntt.custom_modulus();
+        debug_assert_eq!(ciphertext_modulus, self.ciphertext_modulus);
+
+        let monomial_degree = MonomialDegree(pbs_modulus_switch_custom_mod(
+            *lwe_body,
+            lut_poly_size,
+            modulus,
+        ));
+
+        lut.as_mut_polynomial_list()
+            .iter_mut()
+            .for_each(|mut poly| {
+                let (mut tmp_poly, _) = stack
+                    .rb_mut()
+                    .make_aligned_raw(poly.as_ref().len(), CACHELINE_ALIGN);
+
+                let mut tmp_poly = Polynomial::from_container(&mut *tmp_poly);
+                tmp_poly.as_mut().copy_from_slice(poly.as_ref());
+                polynomial_wrapping_monic_monomial_div_custom_mod(
+                    &mut poly,
+                    &tmp_poly,
+                    monomial_degree,
+                    modulus,
+                );
+            });
+
+        // We initialize the ct_0 used for the successive cmuxes
+        let ct0 = lut;
+        let (mut ct1, mut stack) = stack.make_aligned_raw(ct0.as_ref().len(), CACHELINE_ALIGN);
+        let mut ct1 =
+            GlweCiphertextMutView::from_container(&mut *ct1, lut_poly_size, ciphertext_modulus);
+
+        for (lwe_mask_element, bootstrap_key_ggsw) in izip!(lwe_mask.iter(), self.into_ggsw_iter())
+        {
+            if *lwe_mask_element != 0 {
+                let monomial_degree = MonomialDegree(pbs_modulus_switch_custom_mod(
+                    *lwe_mask_element,
+                    lut_poly_size,
+                    modulus,
+                ));
+
+                // We rotate ct_1 and subtract ct_0 (first step of cmux) by performing
+                // ct_1 <- (ct_0 * X^{a_hat}) - ct_0
+                for (mut ct1_poly, ct0_poly) in izip!(
+                    ct1.as_mut_polynomial_list().iter_mut(),
+                    ct0.as_polynomial_list().iter(),
+                ) {
+                    polynomial_wrapping_monic_monomial_mul_and_subtract_custom_mod(
+                        &mut ct1_poly,
+                        &ct0_poly,
+                        monomial_degree,
+                        modulus,
+                    );
+                }
+
+                // second step of cmux
+                add_external_product_assign(ct0, &bootstrap_key_ggsw, &ct1, ntt, stack.rb_mut());
+            }
+        }
+    }
+
+    pub fn bootstrap<OutputCont, InputCont, AccCont>(
+        self,
+        lwe_out: &mut LweCiphertext<OutputCont>,
+        lwe_in: &LweCiphertext<InputCont>,
+        accumulator: &GlweCiphertext<AccCont>,
+        ntt: Ntt64View<'_>,
+        stack: PodStack<'_>,
+    ) where
+        OutputCont: ContainerMut<Element = u64>,
+        InputCont: Container<Element = u64>,
+        AccCont: Container<Element = u64>,
+    {
+        debug_assert_eq!(lwe_out.ciphertext_modulus(), lwe_in.ciphertext_modulus());
+        debug_assert_eq!(
+            lwe_in.ciphertext_modulus(),
+            accumulator.ciphertext_modulus()
+        );
+
+        let (mut local_accumulator_data, stack) =
+            stack.collect_aligned(CACHELINE_ALIGN, accumulator.as_ref().iter().copied());
+        let mut local_accumulator = GlweCiphertextMutView::from_container(
+            &mut *local_accumulator_data,
+            accumulator.polynomial_size(),
+            accumulator.ciphertext_modulus(),
+        );
+        self.blind_rotate_assign(&mut local_accumulator, lwe_in.as_ref(), ntt, stack);
+
+        extract_lwe_sample_from_glwe_ciphertext(&local_accumulator, lwe_out, MonomialDegree(0));
+    }
+}
@@ -0,0 +1,386 @@
+use super::super::math::ntt::Ntt64View;
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::math::decomposition::{
+    decompose_one_level_non_native, DecompositionLevel, SignedDecomposerNonNative,
+};
+use crate::core_crypto::commons::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweSize, PolynomialSize,
+};
+use crate::core_crypto::commons::traits::{
+    Container, ContainerMut, ContiguousEntityContainer, ContiguousEntityContainerMut, Split,
+};
+use crate::core_crypto::commons::utils::izip;
+use crate::core_crypto::entities::*;
+use aligned_vec::{avec, ABox, CACHELINE_ALIGN};
+use dyn_stack::{PodStack, SizeOverflow, StackReq};
+
+/// A GGSW ciphertext in the NTT domain.
+///
+/// The polynomials are stored in the same order as in a standard domain
+/// [`GGSW ciphertext`](`GgswCiphertext`), each one being replaced by its forward NTT.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct NttGgswCiphertext<C: Container<Element = u64>> {
+    data: C,
+    polynomial_size: PolynomialSize,
+    glwe_size: GlweSize,
+    decomposition_base_log: DecompositionBaseLog,
+    decomposition_level_count: DecompositionLevelCount,
+    ciphertext_modulus: CiphertextModulus<u64>,
+}
+
+/// A matrix containing a single level of gadget decomposition, in the NTT domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NttGgswLevelMatrix<C: Container<Element = u64>> {
+    data: C,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level: DecompositionLevel,
+}
+
+/// A row of a GGSW level matrix, in the NTT domain.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct NttGgswLevelRow<C: Container<Element = u64>> {
+    data: C,
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+    decomposition_level: DecompositionLevel,
+}
+
+pub type NttGgswCiphertextOwned = NttGgswCiphertext<ABox<[u64]>>;
+pub type NttGgswCiphertextView<'a> = NttGgswCiphertext<&'a [u64]>;
+pub type NttGgswCiphertextMutView<'a> = NttGgswCiphertext<&'a mut [u64]>;
+pub type NttGgswLevelMatrixView<'a> = NttGgswLevelMatrix<&'a [u64]>;
+pub type NttGgswLevelRowView<'a> = NttGgswLevelRow<&'a [u64]>;
+
+impl<C: Container<Element = u64>> NttGgswCiphertext<C> {
+    pub fn from_container(
+        data: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<u64>,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            polynomial_size.0 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0
+        );
+
+        Self {
+            data,
+            polynomial_size,
+            glwe_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        }
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_base_log(&self) -> DecompositionBaseLog {
+        self.decomposition_base_log
+    }
+
+    pub fn decomposition_level_count(&self) -> DecompositionLevelCount {
+        self.decomposition_level_count
+    }
+
+    pub fn ciphertext_modulus(&self) -> CiphertextModulus<u64> {
+        self.ciphertext_modulus
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+
+    pub fn as_view(&self) -> NttGgswCiphertextView<'_> {
+        NttGgswCiphertextView {
+            data: self.data.as_ref(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+
+    pub fn as_mut_view(&mut self) -> NttGgswCiphertextMutView<'_>
+    where
+        C: AsMut<[u64]>,
+    {
+        NttGgswCiphertextMutView {
+            data: self.data.as_mut(),
+            polynomial_size: self.polynomial_size,
+            glwe_size: self.glwe_size,
+            decomposition_base_log: self.decomposition_base_log,
+            decomposition_level_count: self.decomposition_level_count,
+            ciphertext_modulus: self.ciphertext_modulus,
+        }
+    }
+}
+
+impl NttGgswCiphertextOwned {
+    pub fn new(
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_base_log: DecompositionBaseLog,
+        decomposition_level_count: DecompositionLevelCount,
+        ciphertext_modulus: CiphertextModulus<u64>,
+    ) -> Self {
+        let boxed = avec![
+            0u64;
+            polynomial_size.0 * glwe_size.0 * glwe_size.0 * decomposition_level_count.0
+        ]
+        .into_boxed_slice();
+
+        Self::from_container(
+            boxed,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl<C: Container<Element = u64>> NttGgswLevelMatrix<C> {
+    pub fn new(
+        data: C,
+        glwe_size: GlweSize,
+        polynomial_size: PolynomialSize,
+        decomposition_level: DecompositionLevel,
+    ) -> Self {
+        assert_eq!(
+            data.container_len(),
+            polynomial_size.0 * glwe_size.0 * glwe_size.0
+        );
+        Self {
+            data,
+            glwe_size,
+            polynomial_size,
+            decomposition_level,
+        }
+    }
+
+    /// Return an iterator over the rows of the level matrices.
+    pub fn into_rows(self) -> impl DoubleEndedIterator<Item = NttGgswLevelRow<C>>
+    where
+        C: Split,
+    {
+        self.data
+            .split_into(self.glwe_size.0)
+            .map(move |slice| NttGgswLevelRow {
+                data: slice,
+                glwe_size: self.glwe_size,
+                polynomial_size: self.polynomial_size,
+                decomposition_level: self.decomposition_level,
+            })
+    }
+
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_level(&self) -> DecompositionLevel {
+        self.decomposition_level
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+}
+
+impl<C: Container<Element = u64>> NttGgswLevelRow<C> {
+    pub fn polynomial_size(&self) -> PolynomialSize {
+        self.polynomial_size
+    }
+
+    pub fn glwe_size(&self) -> GlweSize {
+        self.glwe_size
+    }
+
+    pub fn decomposition_level(&self) -> DecompositionLevel {
+        self.decomposition_level
+    }
+
+    pub fn data(self) -> C {
+        self.data
+    }
+}
+
+impl<'a> NttGgswCiphertextView<'a> {
+    /// Return an iterator over the level matrices.
+    pub fn into_levels(self) -> impl DoubleEndedIterator<Item = NttGgswLevelMatrixView<'a>> {
+        self.data
+            .split_into(self.decomposition_level_count.0)
+            .enumerate()
+            .map(move |(i, slice)| {
+                NttGgswLevelMatrixView::new(
+                    slice,
+                    self.glwe_size,
+                    self.polynomial_size,
+                    DecompositionLevel(i + 1),
+                )
+            })
+    }
+}
+
+impl NttGgswCiphertextMutView<'_> {
+    /// Fill a GGSW ciphertext with the NTT of a GGSW ciphertext in the standard domain.
+    pub fn fill_with_forward_ntt<InputCont>(
+        self,
+        coef_ggsw: &GgswCiphertext<InputCont>,
+        ntt: Ntt64View<'_>,
+    ) where
+        InputCont: Container<Element = u64>,
+    {
+        debug_assert_eq!(coef_ggsw.polynomial_size(), self.polynomial_size);
+        debug_assert_eq!(coef_ggsw.ciphertext_modulus(), self.ciphertext_modulus);
+        let poly_size = self.polynomial_size.0;
+
+        for (ntt_poly, coef_poly) in izip!(
+            self.data.chunks_exact_mut(poly_size),
+            coef_ggsw.as_polynomial_list().iter()
+        ) {
+            ntt_poly.copy_from_slice(coef_poly.as_ref());
+            ntt.forward(ntt_poly);
+        }
+    }
+}
+
+/// Return the required memory for [`add_external_product_assign`].
+pub fn add_external_product_assign_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    let align = CACHELINE_ALIGN;
+    let glwe_scratch = StackReq::try_new_aligned::<u64>(glwe_size.0 * polynomial_size.0, align)?;
+    let single_poly_scratch = StackReq::try_new_aligned::<u64>(polynomial_size.0, align)?;
+
+    // output accumulator in the NTT domain, decomposition states and decomposed polynomial
+    StackReq::try_all_of([glwe_scratch, glwe_scratch, single_poly_scratch])
+}
+
+/// Perform the external product of `ggsw` and `glwe`, and add the result to `out`.
+///
+/// All the arithmetic is performed modulo the prime ciphertext modulus of the inputs, the
+/// polynomial products are exact.
+pub fn add_external_product_assign<OutputCont, InputCont, GgswCont>(
+    out: &mut GlweCiphertext<OutputCont>,
+    ggsw: &NttGgswCiphertext<GgswCont>,
+    glwe: &GlweCiphertext<InputCont>,
+    ntt: Ntt64View<'_>,
+    stack: PodStack<'_>,
+) where
+    OutputCont: ContainerMut<Element = u64>,
+    InputCont: Container<Element = u64>,
+    GgswCont: Container<Element = u64>,
+{
+    // we check that the polynomial sizes match
+    debug_assert_eq!(ggsw.polynomial_size(), glwe.polynomial_size());
+    debug_assert_eq!(ggsw.polynomial_size(), out.polynomial_size());
+    // we check that the glwe sizes match
+    debug_assert_eq!(ggsw.glwe_size(), glwe.glwe_size());
+    debug_assert_eq!(ggsw.glwe_size(), out.glwe_size());
+    // we check that the moduli match
+    debug_assert_eq!(ggsw.ciphertext_modulus(), glwe.ciphertext_modulus());
+    debug_assert_eq!(ggsw.ciphertext_modulus(), out.ciphertext_modulus());
+
+    let align = CACHELINE_ALIGN;
+    let poly_size = ggsw.polynomial_size().0;
+    let glwe_size = ggsw.glwe_size().0;
+
+    let decomposer = SignedDecomposerNonNative::new(
+        ggsw.decomposition_base_log(),
+        ggsw.decomposition_level_count(),
+        ggsw.ciphertext_modulus(),
+    );
+    let base_log = decomposer.base_log().0;
+    let mod_b_mask = (1u64 << base_log) - 1;
+    let modulus = ntt.custom_modulus();
+
+    let (mut output_ntt_buffer, substack0) =
+        stack.make_aligned_raw::<u64>(poly_size * glwe_size, align);
+    let output_ntt_buffer = &mut *output_ntt_buffer;
+    output_ntt_buffer.fill(0);
+
+    // We round the input mask and body, the decomposition states are then consumed one level at a
+    // time, starting with the least significant one
+    let (mut decomposition_states, substack1) = substack0.collect_aligned(
+        align,
+        glwe.as_ref()
+            .iter()
+            .map(|&value| decomposer.init_decomposition_state(value)),
+    );
+    let (mut decomposed_poly, _) = substack1.make_aligned_raw::<u64>(poly_size, align);
+    let decomposed_poly = &mut *decomposed_poly;
+
+    // ---------------------------------------------------------- EXTERNAL PRODUCT IN NTT DOMAIN
+    // We loop through the levels (we reverse to match the order of the decomposition)
+    for ggsw_decomp_matrix in ggsw.as_view().into_levels().rev() {
+        // For each level we add the product between every row of the matrix and the
+        // corresponding polynomial of the glwe decomposition to the output.
+        for (ggsw_row, states) in izip!(
+            ggsw_decomp_matrix.into_rows(),
+            decomposition_states.chunks_exact_mut(poly_size)
+        ) {
+            for (digit, state) in izip!(decomposed_poly.iter_mut(), states.iter_mut()) {
+                *digit = decompose_one_level_non_native(base_log, state, mod_b_mask, modulus);
+            }
+
+            ntt.forward(decomposed_poly);
+
+            for (output_poly, ggsw_poly) in izip!(
+                output_ntt_buffer.chunks_exact_mut(poly_size),
+                ggsw_row.data().chunks_exact(poly_size)
+            ) {
+                ntt.plan_mul_add(output_poly, decomposed_poly, ggsw_poly);
+            }
+        }
+    }
+
+    // --------------------------------------------  TRANSFORMATION OF RESULT TO STANDARD DOMAIN
+    for (mut out_poly, output_ntt_poly) in izip!(
+        out.as_mut_polynomial_list().iter_mut(),
+        output_ntt_buffer.chunks_exact_mut(poly_size)
+    ) {
+        ntt.backward(output_ntt_poly);
+        ntt.add_assign(out_poly.as_mut(), output_ntt_poly);
+    }
+}
+
+/// Return the required memory for [`cmux`].
+pub fn cmux_scratch(
+    glwe_size: GlweSize,
+    polynomial_size: PolynomialSize,
+) -> Result<StackReq, SizeOverflow> {
+    add_external_product_assign_scratch(glwe_size, polynomial_size)
+}
+
+/// This cmux mutates both ct1 and ct0. The result is in ct0 after the method was called.
+pub fn cmux<Cont0, Cont1, GgswCont>(
+    ct0: &mut GlweCiphertext<Cont0>,
+    ct1: &mut GlweCiphertext<Cont1>,
+    ggsw: &NttGgswCiphertext<GgswCont>,
+    ntt: Ntt64View<'_>,
+    stack: PodStack<'_>,
+) where
+    Cont0: ContainerMut<Element = u64>,
+    Cont1: ContainerMut<Element = u64>,
+    GgswCont: Container<Element = u64>,
+{
+    ntt.sub_assign(ct1.as_mut(), ct0.as_ref());
+    add_external_product_assign(ct0, ggsw, ct1, ntt, stack);
+}
@@ -0,0 +1,2 @@
+pub mod bootstrap;
+pub mod ggsw;
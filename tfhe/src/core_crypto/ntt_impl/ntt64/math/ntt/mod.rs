@@ -0,0 +1,388 @@
+use crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus;
+use crate::core_crypto::commons::parameters::PolynomialSize;
+use crate::core_crypto::commons::utils::izip;
+use aligned_vec::{avec, ABox};
+use std::collections::hash_map::Entry;
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+/// Precomputed data for the negacyclic number theoretic transform modulo a prime $q$, for
+/// polynomials of size $N$.
+///
+/// The transform requires $q \equiv 1 \mod 2N$, so that a primitive $2N$-th root of unity $\psi$
+/// exists in $\mathbb{Z}\_q$. The forward transform evaluates a polynomial on the odd powers of
+/// $\psi$, turning negacyclic products into element-wise products.
+#[derive(Clone, Debug)]
+struct Plan {
+    polynomial_size: usize,
+    modulus: u64,
+    // Powers of psi in bit reversed order, with their Shoup representation
+    psi_powers: ABox<[u64]>,
+    psi_powers_shoup: ABox<[u64]>,
+    // Powers of psi^-1 in bit reversed order, with their Shoup representation
+    inv_psi_powers: ABox<[u64]>,
+    inv_psi_powers_shoup: ABox<[u64]>,
+    // N^-1 mod q, with its Shoup representation
+    inv_polynomial_size: u64,
+    inv_polynomial_size_shoup: u64,
+}
+
+/// Negacyclic NTT plan for polynomials with coefficients in $\mathbb{Z}\_q$ for a prime $q$ fitting
+/// in 64 bits.
+#[derive(Clone, Debug)]
+pub struct Ntt64 {
+    plan: Arc<Plan>,
+}
+
+/// View type for [`Ntt64`].
+#[derive(Clone, Copy, Debug)]
+pub struct Ntt64View<'a> {
+    plan: &'a Plan,
+}
+
+impl Ntt64 {
+    #[inline]
+    pub fn as_view(&self) -> Ntt64View<'_> {
+        Ntt64View { plan: &self.plan }
+    }
+}
+
+type PlanMap = RwLock<HashMap<(usize, u64), Arc<OnceLock<Arc<Plan>>>>>;
+static PLANS: OnceLock<PlanMap> = OnceLock::new();
+fn plans() -> &'static PlanMap {
+    PLANS.get_or_init(|| RwLock::new(HashMap::new()))
+}
+
+impl Ntt64 {
+    /// Create (or fetch from a global cache) the NTT plan for polynomials of size `size` with
+    /// coefficients modulo `modulus`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is not a power of two, or if `modulus` is not a prime $q$ such that
+    /// $q \equiv 1 \mod 2N$.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::ntt_impl::ntt64::math::ntt::Ntt64;
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let ciphertext_modulus = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+    /// let ntt = Ntt64::new(PolynomialSize(2048), ciphertext_modulus);
+    /// assert_eq!(ntt.as_view().polynomial_size(), PolynomialSize(2048));
+    /// ```
+    pub fn new(size: PolynomialSize, modulus: CiphertextModulus<u64>) -> Self {
+        assert!(
+            Self::is_supported_modulus(size, modulus),
+            "The NTT requires a prime modulus q such that q = 1 mod 2N, \
+            got q={:?} and N={}",
+            modulus,
+            size.0
+        );
+
+        let global_plans = plans();
+
+        let key = (size.0, modulus.get_custom_modulus() as u64);
+        let get_plan = || {
+            let plans = global_plans.read().unwrap();
+            let plan = plans.get(&key).cloned();
+            drop(plans);
+
+            plan.map(|p| p.get_or_init(|| Arc::new(Plan::new(key.0, key.1))).clone())
+        };
+
+        // could not find a plan of the given size, we lock the map again and try to insert it
+        let mut plans = global_plans.write().unwrap();
+        if let Entry::Vacant(v) = plans.entry(key) {
+            v.insert(Arc::new(OnceLock::new()));
+        }
+
+        drop(plans);
+
+        Self {
+            plan: get_plan().unwrap(),
+        }
+    }
+
+    /// Return whether an NTT plan can be built for the given polynomial size and modulus.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::core_crypto::ntt_impl::ntt64::math::ntt::Ntt64;
+    /// use tfhe::core_crypto::prelude::*;
+    ///
+    /// let solinas = CiphertextModulus::try_new((1 << 64) - (1 << 32) + 1).unwrap();
+    /// assert!(Ntt64::is_supported_modulus(PolynomialSize(2048), solinas));
+    ///
+    /// let native = CiphertextModulus::new_native();
+    /// assert!(!Ntt64::is_supported_modulus(PolynomialSize(2048), native));
+    /// ```
+    pub fn is_supported_modulus(size: PolynomialSize, modulus: CiphertextModulus<u64>) -> bool {
+        if modulus.is_compatible_with_native_modulus() || !size.0.is_power_of_two() {
+            return false;
+        }
+
+        let q = modulus.get_custom_modulus();
+        q <= u64::MAX as u128 && (q - 1) % (2 * size.0 as u128) == 0 && is_prime(q as u64)
+    }
+}
+
+impl Plan {
+    fn new(polynomial_size: usize, modulus: u64) -> Self {
+        let n = polynomial_size;
+        let log_n = n.ilog2();
+        let psi = find_primitive_root_of_unity(2 * n as u64, modulus);
+        let inv_psi = inverse_mod(psi, modulus);
+
+        let mut psi_powers = avec![0u64; n].into_boxed_slice();
+        let mut psi_powers_shoup = avec![0u64; n].into_boxed_slice();
+        let mut inv_psi_powers = avec![0u64; n].into_boxed_slice();
+        let mut inv_psi_powers_shoup = avec![0u64; n].into_boxed_slice();
+
+        let mut current_psi = 1u64;
+        let mut current_inv_psi = 1u64;
+        for i in 0..n {
+            let reversed = bit_reverse(i, log_n);
+
+            psi_powers[reversed] = current_psi;
+            psi_powers_shoup[reversed] = shoup_precompute(current_psi, modulus);
+            inv_psi_powers[reversed] = current_inv_psi;
+            inv_psi_powers_shoup[reversed] = shoup_precompute(current_inv_psi, modulus);
+
+            current_psi = mul_mod(current_psi, psi, modulus);
+            current_inv_psi = mul_mod(current_inv_psi, inv_psi, modulus);
+        }
+
+        let inv_polynomial_size = inverse_mod(n as u64, modulus);
+
+        Self {
+            polynomial_size,
+            modulus,
+            psi_powers,
+            psi_powers_shoup,
+            inv_psi_powers,
+            inv_psi_powers_shoup,
+            inv_polynomial_size,
+            inv_polynomial_size_shoup: shoup_precompute(inv_polynomial_size, modulus),
+        }
+    }
+}
+
+impl Ntt64View<'_> {
+    /// Return the polynomial size handled by the plan.
+    pub fn polynomial_size(self) -> PolynomialSize {
+        PolynomialSize(self.plan.polynomial_size)
+    }
+
+    /// Return the prime modulus handled by the plan.
+    pub fn custom_modulus(self) -> u64 {
+        self.plan.modulus
+    }
+
+    /// Perform an in-place forward negacyclic NTT.
+    ///
+    /// The input coefficients must be reduced modulo $q$, the output is in bit reversed order,
+    /// which is only meant to be consumed by element-wise operations and
+    /// [`Ntt64View::backward`].
+    pub fn forward(self, data: &mut [u64]) {
+        let n = self.plan.polynomial_size;
+        assert_eq!(data.len(), n);
+        let q = self.plan.modulus;
+
+        // Cooley-Tukey butterflies, merging the twisting by powers of psi
+        let mut t = n;
+        let mut m = 1;
+        while m < n {
+            t /= 2;
+            for i in 0..m {
+                let w = self.plan.psi_powers[m + i];
+                let w_shoup = self.plan.psi_powers_shoup[m + i];
+
+                let (lo, hi) = data[2 * i * t..2 * (i + 1) * t].split_at_mut(t);
+                for (x, y) in izip!(lo.iter_mut(), hi.iter_mut()) {
+                    let u = *x;
+                    let v = mul_mod_shoup(*y, w, w_shoup, q);
+                    *x = add_mod(u, v, q);
+                    *y = sub_mod(u, v, q);
+                }
+            }
+            m *= 2;
+        }
+    }
+
+    /// Perform an in-place backward negacyclic NTT, including the normalization by $N^{-1}$.
+    pub fn backward(self, data: &mut [u64]) {
+        let n = self.plan.polynomial_size;
+        assert_eq!(data.len(), n);
+        let q = self.plan.modulus;
+
+        // Gentleman-Sande butterflies, merging the twisting by powers of psi^-1
+        let mut t = 1;
+        let mut m = n;
+        while m > 1 {
+            let h = m / 2;
+            for i in 0..h {
+                let w = self.plan.inv_psi_powers[h + i];
+                let w_shoup = self.plan.inv_psi_powers_shoup[h + i];
+
+                let (lo, hi) = data[2 * i * t..2 * (i + 1) * t].split_at_mut(t);
+                for (x, y) in izip!(lo.iter_mut(), hi.iter_mut()) {
+                    let u = *x;
+                    let v = *y;
+                    *x = add_mod(u, v, q);
+                    *y = mul_mod_shoup(sub_mod(u, v, q), w, w_shoup, q);
+                }
+            }
+            t *= 2;
+            m = h;
+        }
+
+        let inv_n = self.plan.inv_polynomial_size;
+        let inv_n_shoup = self.plan.inv_polynomial_size_shoup;
+        for x in data.iter_mut() {
+            *x = mul_mod_shoup(*x, inv_n, inv_n_shoup, q);
+        }
+    }
+
+    /// Compute `acc <- acc + lhs * rhs` element-wise, all three slices being in the NTT domain.
+    pub fn plan_mul_add(self, acc: &mut [u64], lhs: &[u64], rhs: &[u64]) {
+        let q = self.plan.modulus;
+        for (acc, &lhs, &rhs) in izip!(acc.iter_mut(), lhs.iter(), rhs.iter()) {
+            *acc = add_mod(*acc, mul_mod(lhs, rhs, q), q);
+        }
+    }
+
+    /// Compute `acc <- acc + input` coefficient-wise modulo $q$.
+    pub fn add_assign(self, acc: &mut [u64], input: &[u64]) {
+        let q = self.plan.modulus;
+        for (acc, &input) in izip!(acc.iter_mut(), input.iter()) {
+            *acc = add_mod(*acc, input, q);
+        }
+    }
+
+    /// Compute `acc <- acc - input` coefficient-wise modulo $q$.
+    pub fn sub_assign(self, acc: &mut [u64], input: &[u64]) {
+        let q = self.plan.modulus;
+        for (acc, &input) in izip!(acc.iter_mut(), input.iter()) {
+            *acc = sub_mod(*acc, input, q);
+        }
+    }
+}
+
+#[inline(always)]
+pub(crate) fn add_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    let (sum, overflowed) = lhs.overflowing_add(rhs);
+    if overflowed || sum >= modulus {
+        sum.wrapping_sub(modulus)
+    } else {
+        sum
+    }
+}
+
+#[inline(always)]
+pub(crate) fn sub_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    if lhs >= rhs {
+        lhs - rhs
+    } else {
+        lhs.wrapping_sub(rhs).wrapping_add(modulus)
+    }
+}
+
+#[inline(always)]
+pub(crate) fn mul_mod(lhs: u64, rhs: u64, modulus: u64) -> u64 {
+    ((lhs as u128 * rhs as u128) % modulus as u128) as u64
+}
+
+// floor(w * 2^64 / q), used to replace the division by q with a multiplication
+#[inline(always)]
+fn shoup_precompute(w: u64, modulus: u64) -> u64 {
+    (((w as u128) << 64) / modulus as u128) as u64
+}
+
+#[inline(always)]
+fn mul_mod_shoup(a: u64, w: u64, w_shoup: u64, modulus: u64) -> u64 {
+    let quotient = ((a as u128 * w_shoup as u128) >> 64) as u64;
+    // The estimated quotient is off by at most one, the difference lies in [0, 2q) which may not
+    // fit in 64 bits for moduli close to 2^64
+    let r = (a as u128 * w as u128) - (quotient as u128 * modulus as u128);
+    if r >= modulus as u128 {
+        (r - modulus as u128) as u64
+    } else {
+        r as u64
+    }
+}
+
+fn pow_mod(mut base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let mut result = 1u64 % modulus;
+    base %= modulus;
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = mul_mod(result, base, modulus);
+        }
+        base = mul_mod(base, base, modulus);
+        exponent >>= 1;
+    }
+    result
+}
+
+// Only valid for prime moduli
+fn inverse_mod(value: u64, modulus: u64) -> u64 {
+    pow_mod(value, modulus - 2, modulus)
+}
+
+fn bit_reverse(value: usize, bits: u32) -> usize {
+    if bits == 0 {
+        0
+    } else {
+        value.reverse_bits() >> (usize::BITS - bits)
+    }
+}
+
+// Find a primitive order-th root of unity modulo a prime, order being a power of 2 dividing
+// modulus - 1
+fn find_primitive_root_of_unity(order: u64, modulus: u64) -> u64 {
+    let exponent = (modulus - 1) / order;
+    (2..modulus)
+        .map(|candidate| pow_mod(candidate, exponent, modulus))
+        // As order is a power of 2, g has order exactly order iff g^(order/2) = -1
+        .find(|&root| pow_mod(root, order / 2, modulus) == modulus - 1)
+        .expect("No primitive root of unity found, the modulus is not an NTT friendly prime")
+}
+
+// Deterministic Miller-Rabin test, the bases used are sufficient for all 64 bits integers
+fn is_prime(n: u64) -> bool {
+    const BASES: [u64; 12] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37];
+
+    if n < 2 {
+        return false;
+    }
+    for &p in BASES.iter() {
+        if n % p == 0 {
+            return n == p;
+        }
+    }
+
+    let s = (n - 1).trailing_zeros();
+    let d = (n - 1) >> s;
+
+    'witness: for &a in BASES.iter() {
+        let mut x = pow_mod(a, d, n);
+        if x == 1 || x == n - 1 {
+            continue;
+        }
+        for _ in 1..s {
+            x = mul_mod(x, x, n);
+            if x == n - 1 {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+
+    true
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,102 @@
+use super::*;
+use crate::core_crypto::commons::test_tools::new_random_generator;
+
+const SOLINAS_PRIME: u128 = (1 << 64) - (1 << 32) + 1;
+
+fn negacyclic_convolution_naive(lhs: &[u64], rhs: &[u64], modulus: u64) -> Vec<u64> {
+    let n = lhs.len();
+    let mut out = vec![0u64; n];
+    for i in 0..n {
+        for j in 0..n {
+            let prod = mul_mod(lhs[i], rhs[j], modulus);
+            if i + j < n {
+                out[i + j] = add_mod(out[i + j], prod, modulus);
+            } else {
+                out[i + j - n] = sub_mod(out[i + j - n], prod, modulus);
+            }
+        }
+    }
+    out
+}
+
+#[test]
+fn test_roundtrip() {
+    let mut generator = new_random_generator();
+    let ciphertext_modulus = CiphertextModulus::try_new(SOLINAS_PRIME).unwrap();
+
+    for size_log in 1..=14 {
+        let size = 1_usize << size_log;
+
+        let ntt = Ntt64::new(PolynomialSize(size), ciphertext_modulus);
+        let ntt = ntt.as_view();
+
+        let mut poly = vec![0u64; size];
+        generator.fill_slice_with_random_uniform_custom_mod(&mut poly, ciphertext_modulus);
+
+        let mut roundtrip = poly.clone();
+        ntt.forward(&mut roundtrip);
+        ntt.backward(&mut roundtrip);
+
+        assert_eq!(poly, roundtrip);
+    }
+}
+
+#[test]
+fn test_product() {
+    let mut generator = new_random_generator();
+
+    // Solinas prime and a smaller NTT friendly prime (2^32 - 2^20 + 1 = 1 mod 2^20)
+    for modulus in [SOLINAS_PRIME, (1 << 32) - (1 << 20) + 1] {
+        let ciphertext_modulus = CiphertextModulus::try_new(modulus).unwrap();
+
+        for size_log in 1..=9 {
+            let size = 1_usize << size_log;
+
+            let ntt = Ntt64::new(PolynomialSize(size), ciphertext_modulus);
+            let ntt = ntt.as_view();
+
+            let mut poly0 = vec![0u64; size];
+            let mut poly1 = vec![0u64; size];
+            generator.fill_slice_with_random_uniform_custom_mod(&mut poly0, ciphertext_modulus);
+            generator.fill_slice_with_random_uniform_custom_mod(&mut poly1, ciphertext_modulus);
+
+            let expected = negacyclic_convolution_naive(&poly0, &poly1, modulus as u64);
+
+            let mut ntt0 = poly0.clone();
+            let mut ntt1 = poly1.clone();
+            ntt.forward(&mut ntt0);
+            ntt.forward(&mut ntt1);
+
+            let mut product = vec![0u64; size];
+            ntt.plan_mul_add(&mut product, &ntt0, &ntt1);
+            ntt.backward(&mut product);
+
+            assert_eq!(product, expected);
+        }
+    }
+}
+
+#[test]
+fn test_supported_moduli() {
+    let polynomial_size = PolynomialSize(2048);
+
+    assert!(Ntt64::is_supported_modulus(
+        polynomial_size,
+        CiphertextModulus::try_new(SOLINAS_PRIME).unwrap()
+    ));
+    // Not a prime
+    assert!(!Ntt64::is_supported_modulus(
+        polynomial_size,
+        CiphertextModulus::try_new((1 << 32) + 1).unwrap()
+    ));
+    // Prime but 2N does not divide q - 1
+    assert!(!Ntt64::is_supported_modulus(
+        polynomial_size,
+        CiphertextModulus::try_new(7681).unwrap()
+    ));
+    // Power of 2 moduli are handled by the FFT
+    assert!(!Ntt64::is_supported_modulus(
+        polynomial_size,
+        CiphertextModulus::try_new_power_of_2(63).unwrap()
+    ));
+}
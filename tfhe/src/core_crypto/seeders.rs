@@ -7,6 +7,7 @@
 pub use crate::core_crypto::commons::math::random::Seeder;
 #[cfg(all(target_os = "macos", not(feature = "__wasm_api")))]
 pub use concrete_csprng::seeders::AppleSecureEnclaveSeeder;
+pub use concrete_csprng::seeders::DerivedSeeder;
 #[cfg(feature = "seeder_x86_64_rdseed")]
 pub use concrete_csprng::seeders::RdseedSeeder;
 #[cfg(feature = "seeder_unix")]
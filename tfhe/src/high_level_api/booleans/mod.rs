@@ -114,6 +114,49 @@ impl FheBool {
     ) -> GenericInteger<Id> {
         self.if_then_else(ct_then, ct_else)
     }
+
+    /// Sanitizes `self`, so that it reveals nothing about the computations it went through
+    /// besides its value.
+    ///
+    /// See [`crate::shortint::ServerKey::sanitize`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if sanitization was not enabled in the config.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheBool};
+    ///
+    /// let config = ConfigBuilder::default()
+    ///     .enable_sanitization(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheBool::encrypt(true, &client_key);
+    /// let b = FheBool::encrypt(false, &client_key);
+    ///
+    /// let result = (&a ^ &b).sanitize();
+    ///
+    /// assert!(result.decrypt(&client_key));
+    /// ```
+    pub fn sanitize(&self) -> Self {
+        let ciphertext = self.id.with_unwrapped_global(|integer_key| {
+            let sanitization_key = integer_key
+                .sanitization_key
+                .as_ref()
+                .expect("Sanitization was not enabled in the config");
+            integer_key
+                .pbs_key()
+                .sanitize_boolean_block(&self.ciphertext, sanitization_key)
+        });
+        Self::new(ciphertext)
+    }
 }
 
 impl<B> FheEq<B> for FheBool
@@ -28,6 +28,34 @@ impl ConfigBuilder {
         self
     }
 
+    /// Enables the noise squashing of unsigned integers, which is required for their threshold
+    /// decryption.
+    ///
+    /// See [FheUint8::squash_noise](crate::high_level_api::FheUint8::squash_noise) for usage.
+    pub fn enable_noise_squashing(
+        mut self,
+        noise_squashing_parameters: crate::shortint::parameters::NoiseSquashingParameters,
+    ) -> Self {
+        self.config
+            .inner
+            .enable_noise_squashing(noise_squashing_parameters);
+        self
+    }
+
+    /// Enables the sanitization of integers and booleans, which hides the computations they went
+    /// through from the owner of the ClientKey.
+    ///
+    /// See [FheUint8::sanitize](crate::high_level_api::FheUint8::sanitize) for usage.
+    pub fn enable_sanitization(
+        mut self,
+        sanitization_parameters: crate::shortint::parameters::SanitizationParameters,
+    ) -> Self {
+        self.config
+            .inner
+            .enable_sanitization(sanitization_parameters);
+        self
+    }
+
     pub fn default_with_big_encryption() -> Self {
         Self {
             config: Config {
@@ -4,14 +4,19 @@ use serde::{Deserialize, Serialize};
 use crate::core_crypto::commons::generators::DeterministicSeeder;
 use crate::core_crypto::prelude::ActivatedRandomGenerator;
 use crate::integer::ciphertext::CompactCiphertextList;
+use crate::integer::noise_squashing::{NoiseSquashingKey, NoiseSquashingPrivateKey};
 use crate::integer::public_key::CompactPublicKey;
+use crate::integer::sanitization::SanitizationKey;
 use crate::integer::CompressedCompactPublicKey;
+use crate::shortint::parameters::{NoiseSquashingParameters, SanitizationParameters};
 use crate::shortint::EncryptionKeyChoice;
 
 #[derive(Copy, Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerConfig {
     pub(crate) block_parameters: crate::shortint::PBSParameters,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    pub(crate) noise_squashing_params: Option<NoiseSquashingParameters>,
+    pub(crate) sanitization_params: Option<SanitizationParameters>,
 }
 
 impl IntegerConfig {
@@ -22,6 +27,8 @@ impl IntegerConfig {
         Self {
             block_parameters,
             wopbs_block_parameters,
+            noise_squashing_params: None,
+            sanitization_params: None,
         }
     }
 
@@ -29,6 +36,8 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS.into(),
             wopbs_block_parameters: None,
+            noise_squashing_params: None,
+            sanitization_params: None,
         }
     }
 
@@ -36,6 +45,8 @@ impl IntegerConfig {
         Self {
             block_parameters: crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS.into(),
             wopbs_block_parameters: None,
+            noise_squashing_params: None,
+            sanitization_params: None,
         }
     }
 
@@ -47,23 +58,41 @@ impl IntegerConfig {
 
         self.wopbs_block_parameters = Some(wopbs_block_parameters);
     }
+
+    pub fn enable_noise_squashing(&mut self, noise_squashing_params: NoiseSquashingParameters) {
+        self.noise_squashing_params = Some(noise_squashing_params);
+    }
+
+    pub fn enable_sanitization(&mut self, sanitization_params: SanitizationParameters) {
+        self.sanitization_params = Some(sanitization_params);
+    }
 }
 
 #[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
 pub(crate) struct IntegerClientKey {
     pub(crate) key: crate::integer::ClientKey,
     pub(crate) wopbs_block_parameters: Option<crate::shortint::WopbsParameters>,
+    pub(crate) noise_squashing_private_key: Option<NoiseSquashingPrivateKey>,
+    pub(crate) sanitization_params: Option<SanitizationParameters>,
 }
 
 impl IntegerClientKey {
     pub(crate) fn with_seed(config: IntegerConfig, seed: Seed) -> Self {
         let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(seed);
-        let cks = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder)
-            .new_client_key(config.block_parameters.into());
+        let mut engine = crate::shortint::engine::ShortintEngine::new_from_seeder(&mut seeder);
+        let cks = engine.new_client_key(config.block_parameters.into());
+        let noise_squashing_private_key =
+            config
+                .noise_squashing_params
+                .map(|params| NoiseSquashingPrivateKey {
+                    key: engine.new_noise_squashing_private_key(&cks, params),
+                });
         let key = crate::integer::ClientKey::from(cks);
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            noise_squashing_private_key,
+            sanitization_params: config.sanitization_params,
         }
     }
 
@@ -76,9 +105,14 @@ impl IntegerClientKey {
 impl From<IntegerConfig> for IntegerClientKey {
     fn from(config: IntegerConfig) -> Self {
         let key = crate::integer::ClientKey::new(config.block_parameters);
+        let noise_squashing_private_key = config
+            .noise_squashing_params
+            .map(|params| key.new_noise_squashing_private_key(params));
         Self {
             key,
             wopbs_block_parameters: config.wopbs_block_parameters,
+            noise_squashing_private_key,
+            sanitization_params: config.sanitization_params,
         }
     }
 }
@@ -87,6 +121,8 @@ impl From<IntegerConfig> for IntegerClientKey {
 pub struct IntegerServerKey {
     pub(crate) key: crate::integer::ServerKey,
     pub(crate) wopbs_key: Option<crate::integer::wopbs::WopbsKey>,
+    pub(crate) noise_squashing_key: Option<NoiseSquashingKey>,
+    pub(crate) sanitization_key: Option<SanitizationKey>,
 }
 
 impl IntegerServerKey {
@@ -104,9 +140,21 @@ impl IntegerServerKey {
             .map(|wopbs_params| {
                 crate::integer::wopbs::WopbsKey::new_wopbs_key(cks, &base_integer_key, wopbs_params)
             });
+        let noise_squashing_key =
+            client_key
+                .noise_squashing_private_key
+                .as_ref()
+                .map(|noise_squashing_private_key| {
+                    cks.new_noise_squashing_key(noise_squashing_private_key)
+                });
+        let sanitization_key = client_key
+            .sanitization_params
+            .map(|sanitization_params| cks.new_sanitization_key(sanitization_params));
         Self {
             key: base_integer_key,
             wopbs_key,
+            noise_squashing_key,
+            sanitization_key,
         }
     }
 
@@ -132,6 +180,18 @@ impl IntegerCompressedServerKey {
                    to create a CompressedServerKey.
                    "
         );
+        assert!(
+            client_key.noise_squashing_private_key.is_none(),
+            "The configuration used to create the ClientKey \
+             had noise squashing enabled. This feature requires an additional key \
+             that is not compressible. Thus, It is not possible to create a CompressedServerKey."
+        );
+        assert!(
+            client_key.sanitization_params.is_none(),
+            "The configuration used to create the ClientKey \
+             had sanitization enabled. This feature requires an additional key \
+             that is not compressible. Thus, It is not possible to create a CompressedServerKey."
+        );
         let key = crate::integer::CompressedServerKey::new_radix_compressed_server_key(integer_key);
         Self { key }
     }
@@ -140,6 +200,8 @@ impl IntegerCompressedServerKey {
         IntegerServerKey {
             key: crate::integer::ServerKey::from(self.key),
             wopbs_key: None,
+            noise_squashing_key: None,
+            sanitization_key: None,
         }
     }
 }
@@ -10,6 +10,8 @@ pub(in crate::high_level_api) use keys::{
     IntegerCompressedServerKey, IntegerConfig, IntegerServerKey,
 };
 
+pub use types::SquashedNoiseFheUint;
+
 pub(in crate::high_level_api) use parameters::IntegerId;
 pub(in crate::high_level_api) use types::GenericInteger;
 
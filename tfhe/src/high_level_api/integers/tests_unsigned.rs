@@ -3,6 +3,8 @@ use rand::Rng;
 use crate::high_level_api::prelude::*;
 use crate::high_level_api::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
 use crate::integer::U256;
+use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+use crate::shortint::parameters::{ShareCount, ShareThreshold, StatisticalSecurityParameter};
 use crate::{
     CompactFheUint32, CompactFheUint32List, CompactPublicKey, CompressedFheUint16,
     CompressedFheUint256, CompressedPublicKey, Config, FheInt16, FheInt32, FheInt8, FheUint128,
@@ -32,6 +34,49 @@ fn test_quickstart_uint8() {
     assert_eq!(decrypted_result, clear_result);
 }
 
+#[test]
+fn test_uint32_threshold_decrypt() {
+    let config = ConfigBuilder::default()
+        .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2)
+        .build();
+
+    let (client_key, server_key) = generate_keys(config);
+
+    set_server_key(server_key);
+
+    let key_shares = client_key.generate_threshold_shares(ShareThreshold(3), ShareCount(4));
+
+    let mut rng = rand::thread_rng();
+    let clear_a = rng.gen::<u32>();
+    let clear_b = rng.gen::<u32>();
+
+    let a = FheUint32::encrypt(clear_a, &client_key);
+    let b = FheUint32::encrypt(clear_b, &client_key);
+
+    let result = (&a * &b).squash_noise();
+
+    let decrypted_result: u32 = result.decrypt(&client_key);
+    assert_eq!(decrypted_result, clear_a.wrapping_mul(clear_b));
+
+    // Participants 1, 2 and 4 decrypt
+    let participating_shares = [&key_shares[0], &key_shares[1], &key_shares[3]];
+    let participants: Vec<_> = participating_shares
+        .iter()
+        .map(|key_share| key_share.share_index())
+        .collect();
+
+    let partial_decryptions: Vec<_> = participating_shares
+        .iter()
+        .map(|key_share| {
+            result.partial_decrypt(key_share, &participants, StatisticalSecurityParameter(40))
+        })
+        .collect();
+
+    let decrypted_result: u32 = result.combine_partial_decryptions(&partial_decryptions);
+
+    assert_eq!(decrypted_result, clear_a.wrapping_mul(clear_b));
+}
+
 #[test]
 fn test_uint8_compare() {
     let config = ConfigBuilder::default().build();
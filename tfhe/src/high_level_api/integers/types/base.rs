@@ -112,6 +112,54 @@ where
 
         Self::new(ciphertext, self.id)
     }
+
+    /// Sanitizes `self`, so that it reveals nothing about the computations it went through
+    /// besides its value.
+    ///
+    /// This is meant to be called on results before returning them to the owner of the
+    /// [ClientKey](crate::ClientKey), when the computed function or the data used by the server
+    /// must stay private. See [`crate::shortint::ServerKey::sanitize`] for details.
+    ///
+    /// # Panics
+    ///
+    /// Panics if sanitization was not enabled in the config.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::default()
+    ///     .enable_sanitization(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(179u8, &client_key);
+    /// let b = FheUint8::encrypt(42u8, &client_key);
+    ///
+    /// let result = (&a * &b).sanitize();
+    ///
+    /// let decrypted: u8 = result.decrypt(&client_key);
+    /// assert_eq!(decrypted, 179u8.wrapping_mul(42u8));
+    /// ```
+    pub fn sanitize(&self) -> Self {
+        let ciphertext = crate::high_level_api::global_state::with_internal_keys(|keys| {
+            let sanitization_key = keys
+                .integer_key
+                .sanitization_key
+                .as_ref()
+                .expect("Sanitization was not enabled in the config");
+            keys.integer_key
+                .pbs_key()
+                .sanitize_parallelized(&self.ciphertext, sanitization_key)
+        });
+
+        Self::new(ciphertext, self.id)
+    }
 }
 
 impl<FromId, IntoId> CastFrom<GenericInteger<FromId>> for GenericInteger<IntoId>
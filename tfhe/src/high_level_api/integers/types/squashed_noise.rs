@@ -0,0 +1,168 @@
+use crate::core_crypto::prelude::UnsignedNumeric;
+use crate::high_level_api::global_state::WithGlobalKey;
+use crate::high_level_api::integers::parameters::IntegerId;
+use crate::high_level_api::integers::{GenericInteger, IntegerServerKey};
+use crate::high_level_api::traits::FheDecrypt;
+use crate::high_level_api::{ClientKey, ClientKeyShare, PartialDecryption};
+use crate::integer::block_decomposition::RecomposableFrom;
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::noise_squashing::SquashedNoiseRadixCiphertext;
+use crate::named::Named;
+use crate::shortint::parameters::{ShareIndex, StatisticalSecurityParameter};
+
+/// An FHE unsigned integer whose noise was squashed
+///
+/// It is obtained with [FheUint8::squash_noise](crate::high_level_api::FheUint8::squash_noise)
+/// and no longer supports computations, it can only be decrypted, either with the [ClientKey] or
+/// by a threshold number of [ClientKeyShare]s.
+#[cfg_attr(all(doc, not(doctest)), doc(cfg(feature = "integer")))]
+#[derive(Clone, serde::Deserialize, serde::Serialize)]
+pub struct SquashedNoiseFheUint {
+    pub(in crate::high_level_api) ciphertext: SquashedNoiseRadixCiphertext,
+}
+
+impl Named for SquashedNoiseFheUint {
+    const NAME: &'static str = "high_level_api::SquashedNoiseFheUint";
+}
+
+impl<Id> GenericInteger<Id>
+where
+    Id: IntegerId<InnerCiphertext = RadixCiphertext> + WithGlobalKey<Key = IntegerServerKey>,
+{
+    /// Squashes the noise of `self`, to be decrypted by a threshold number of participants
+    ///
+    /// # Panics
+    ///
+    /// Panics if noise squashing was not enabled in the config.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::default()
+    ///     .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2)
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(179u8, &client_key);
+    /// let b = FheUint8::encrypt(42u8, &client_key);
+    ///
+    /// let squashed = (&a + &b).squash_noise();
+    ///
+    /// let decrypted: u8 = squashed.decrypt(&client_key);
+    /// assert_eq!(decrypted, 179u8.wrapping_add(42u8));
+    /// ```
+    pub fn squash_noise(&self) -> SquashedNoiseFheUint {
+        self.id.with_unwrapped_global(|integer_key| {
+            let noise_squashing_key = integer_key
+                .noise_squashing_key
+                .as_ref()
+                .expect("Noise squashing was not enabled in the config");
+            let ciphertext = integer_key
+                .pbs_key()
+                .squash_radix_ciphertext_noise(noise_squashing_key, &self.ciphertext);
+            SquashedNoiseFheUint { ciphertext }
+        })
+    }
+}
+
+impl SquashedNoiseFheUint {
+    /// Computes the partial decryption of `self` with a share of the client key
+    ///
+    /// `participants` contains the share indices of all the participants to the decryption,
+    /// all of them must use the same set and it must contain at least a threshold number of
+    /// participants.
+    ///
+    /// A flooding noise $2^{\lambda}$ times larger than the noise of `self` is added to hide the
+    /// share, with $\lambda$ the `statistical_security`, see
+    /// [crate::shortint::ClientKeyShare::partial_decrypt] for its bound.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::{ShareCount, ShareThreshold, StatisticalSecurityParameter};
+    /// use tfhe::{generate_keys, set_server_key, ConfigBuilder, FheUint8};
+    ///
+    /// let config = ConfigBuilder::default()
+    ///     .enable_noise_squashing(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2)
+    ///     .build();
+    /// let (client_key, server_key) = generate_keys(config);
+    ///
+    /// set_server_key(server_key);
+    ///
+    /// // Any 2 out of 3 participants can decrypt together
+    /// let key_shares = client_key.generate_threshold_shares(ShareThreshold(2), ShareCount(3));
+    ///
+    /// let a = FheUint8::encrypt(179u8, &client_key);
+    /// let squashed = a.squash_noise();
+    ///
+    /// // Participants 1 and 2 decrypt
+    /// let participants = [key_shares[0].share_index(), key_shares[1].share_index()];
+    /// let partial_decryptions: Vec<_> = key_shares[..2]
+    ///     .iter()
+    ///     .map(|key_share| {
+    ///         squashed.partial_decrypt(key_share, &participants, StatisticalSecurityParameter(40))
+    ///     })
+    ///     .collect();
+    ///
+    /// let decrypted: u8 = squashed.combine_partial_decryptions(&partial_decryptions);
+    /// assert_eq!(decrypted, 179u8);
+    /// ```
+    pub fn partial_decrypt(
+        &self,
+        key_share: &ClientKeyShare,
+        participants: &[ShareIndex],
+        statistical_security: StatisticalSecurityParameter,
+    ) -> PartialDecryption {
+        PartialDecryption {
+            inner: key_share.key.partial_decrypt_radix(
+                &self.ciphertext,
+                participants,
+                statistical_security,
+            ),
+        }
+    }
+
+    /// Combines the partial decryptions of `self` to get the decrypted value
+    ///
+    /// See [Self::partial_decrypt] for usage.
+    pub fn combine_partial_decryptions<ClearType>(
+        &self,
+        partial_decryptions: &[PartialDecryption],
+    ) -> ClearType
+    where
+        ClearType: RecomposableFrom<u64> + UnsignedNumeric,
+    {
+        let partial_decryptions: Vec<_> = partial_decryptions
+            .iter()
+            .map(|partial_decryption| partial_decryption.inner.clone())
+            .collect();
+        crate::integer::client_key::combine_radix_partial_decryptions(
+            &self.ciphertext,
+            &partial_decryptions,
+        )
+    }
+}
+
+impl<ClearType> FheDecrypt<ClearType> for SquashedNoiseFheUint
+where
+    ClearType: RecomposableFrom<u64> + UnsignedNumeric,
+{
+    /// # Panics
+    ///
+    /// Panics if the config used to create the [ClientKey] did not have noise squashing enabled.
+    fn decrypt(&self, key: &ClientKey) -> ClearType {
+        key.key
+            .noise_squashing_private_key
+            .as_ref()
+            .expect("Noise squashing was not enabled in the config")
+            .decrypt_squashed_noise_radix(&self.ciphertext)
+    }
+}
@@ -6,6 +6,7 @@ use concrete_csprng::seeders::Seed;
 
 use crate::high_level_api::config::Config;
 use crate::high_level_api::integers::IntegerClientKey;
+use crate::shortint::parameters::{ShareCount, ShareIndex, ShareThreshold};
 
 use super::{CompressedServerKey, ServerKey};
 
@@ -48,6 +49,119 @@ impl ClientKey {
     pub fn generate_compressed_server_key(&self) -> CompressedServerKey {
         CompressedServerKey::new(self)
     }
+
+    /// Generates a new ServerKey and writes it to `writer` without holding the whole key in
+    /// memory.
+    ///
+    /// The written key is loaded with [ServerKey::from_raw_bytes].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error of kind [std::io::ErrorKind::InvalidInput] if the config used to create
+    /// the ClientKey had function evaluation on integers, noise squashing or sanitization enabled,
+    /// as the additional keys they require cannot be streamed, or if its block parameters do not
+    /// have 2 bits of message per block, as [ServerKey::new] would refuse them.
+    ///
+    /// Returns an error of kind [std::io::ErrorKind::Unsupported] for multi bit PBS parameters,
+    /// see [crate::shortint::ClientKey::generate_server_key_to_writer].
+    ///
+    /// Any error returned by `writer` is forwarded.
+    pub fn generate_server_key_to_writer<W: std::io::Write>(
+        &self,
+        writer: W,
+    ) -> std::io::Result<()> {
+        let unsupported_feature = if self.key.wopbs_block_parameters.is_some() {
+            Some("function evaluation on integers")
+        } else if self.key.noise_squashing_private_key.is_some() {
+            Some("noise squashing")
+        } else if self.key.sanitization_params.is_some() {
+            Some("sanitization")
+        } else {
+            None
+        };
+        if let Some(feature) = unsupported_feature {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!("Streaming the ServerKey is not supported when {feature} is enabled"),
+            ));
+        }
+        let message_modulus = self.key.key.parameters().message_modulus();
+        if message_modulus.0 != 4 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidInput,
+                format!(
+                    "This API only supports integers with 2 bits per block (MessageModulus(4)), \
+                     got {message_modulus:?}"
+                ),
+            ));
+        }
+        self.key.key.key.generate_server_key_to_writer(writer)
+    }
+
+    /// Splits the noise squashing private key in `share_count` [ClientKeyShare]s, any `threshold`
+    /// of them are required to decrypt.
+    ///
+    /// The ClientKey must be discarded once the shares are given to the participants, for no
+    /// single party to be able to decrypt.
+    ///
+    /// See [SquashedNoiseFheUint::partial_decrypt](crate::high_level_api::SquashedNoiseFheUint::partial_decrypt)
+    /// for usage.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the config used to create the ClientKey did not have noise squashing enabled.
+    pub fn generate_threshold_shares(
+        &self,
+        threshold: ShareThreshold,
+        share_count: ShareCount,
+    ) -> Vec<ClientKeyShare> {
+        self.key
+            .noise_squashing_private_key
+            .as_ref()
+            .expect("Noise squashing was not enabled in the config")
+            .generate_threshold_shares(threshold, share_count)
+            .into_iter()
+            .map(|key| ClientKeyShare { key })
+            .collect()
+    }
+}
+
+/// Share of the noise squashing private key of a [ClientKey]
+///
+/// A threshold number of shares is required to decrypt a
+/// [SquashedNoiseFheUint](crate::high_level_api::SquashedNoiseFheUint), no single share allows to
+/// decrypt. Each participant keeps its share and only sends its partial decryptions.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct ClientKeyShare {
+    pub(crate) key: crate::integer::ClientKeyShare,
+}
+
+impl ClientKeyShare {
+    /// Returns the index of the share.
+    pub fn share_index(&self) -> ShareIndex {
+        self.key.share_index()
+    }
+
+    /// Returns the number of shares required to decrypt.
+    pub fn threshold(&self) -> ShareThreshold {
+        self.key.threshold()
+    }
+}
+
+/// Partial decryption of a squashed encrypted value computed with a [ClientKeyShare]
+///
+/// Partial decryptions of a threshold number of participants are combined to get the
+/// decrypted value.
+#[derive(Clone, Debug, serde::Serialize, serde::Deserialize)]
+pub struct PartialDecryption {
+    pub(crate) inner: crate::integer::client_key::RadixPartialDecryption,
+}
+
+impl PartialDecryption {
+    /// Returns the index of the share used to compute the partial decryption.
+    pub fn share_index(&self) -> ShareIndex {
+        self.inner.share_index()
+    }
 }
 
 impl AsRef<crate::integer::ClientKey> for ClientKey {
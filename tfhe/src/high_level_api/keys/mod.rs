@@ -5,7 +5,7 @@ mod public;
 mod server;
 
 use crate::high_level_api::config::Config;
-pub use client::ClientKey;
+pub use client::{ClientKey, ClientKeyShare, PartialDecryption};
 pub use public::{CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey};
 pub use server::{CompressedServerKey, ServerKey};
 
@@ -1,5 +1,8 @@
+use crate::core_crypto::commons::raw_key_format::RawKeyError;
+use crate::high_level_api::config::Config;
 use crate::high_level_api::integers::{IntegerCompressedServerKey, IntegerServerKey};
 
+use std::panic::RefUnwindSafe;
 use std::sync::Arc;
 
 use super::ClientKey;
@@ -25,6 +28,73 @@ impl ServerKey {
             integer_key: Arc::new(IntegerServerKey::new(&keys.key)),
         }
     }
+
+    /// Loads a ServerKey written by [ClientKey::generate_server_key_to_writer].
+    ///
+    /// `config` must be the one used to create the ClientKey, the bytes must be aligned
+    /// for the key elements (e.g. a memory mapped file). The keys are used in place, the
+    /// ServerKey keeps `bytes` alive.
+    ///
+    /// See [crate::shortint::ServerKey::from_raw_bytes] for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::prelude::*;
+    /// use std::sync::Arc;
+    /// use tfhe::{set_server_key, ClientKey, ConfigBuilder, FheUint8, ServerKey};
+    ///
+    /// let config = ConfigBuilder::default().build();
+    /// let client_key = ClientKey::generate(config.clone());
+    ///
+    /// let mut bytes = Vec::new();
+    /// client_key.generate_server_key_to_writer(&mut bytes).unwrap();
+    ///
+    /// // The raw key data must be aligned to be loaded, this is the case for a memory mapped
+    /// // file, here we copy the bytes to a suitably aligned buffer
+    /// let aligned = Arc::new(aligned_vec::AVec::<u8>::from_slice(0, &bytes));
+    ///
+    /// let server_key = ServerKey::from_raw_bytes(aligned, config).unwrap();
+    /// set_server_key(server_key);
+    ///
+    /// let a = FheUint8::encrypt(27u8, &client_key);
+    /// let b = FheUint8::encrypt(100u8, &client_key);
+    /// let c: u8 = (a + b).decrypt(&client_key);
+    /// assert_eq!(c, 127);
+    /// ```
+    pub fn from_raw_bytes<B, C>(bytes: Arc<B>, config: C) -> Result<Self, RawKeyError>
+    where
+        B: AsRef<[u8]> + Send + Sync + RefUnwindSafe + 'static,
+        C: Into<Config>,
+    {
+        let config: Config = config.into();
+        if config.inner.wopbs_block_parameters.is_some() {
+            return Err(RawKeyError::InvalidParameters(
+                "function evaluation on integers is not supported for raw keys",
+            ));
+        }
+        if config.inner.noise_squashing_params.is_some() {
+            return Err(RawKeyError::InvalidParameters(
+                "noise squashing is not supported for raw keys",
+            ));
+        }
+        if config.inner.sanitization_params.is_some() {
+            return Err(RawKeyError::InvalidParameters(
+                "sanitization is not supported for raw keys",
+            ));
+        }
+        let shortint_key =
+            crate::shortint::ServerKey::from_raw_bytes(bytes, config.inner.block_parameters)?;
+        let key = crate::integer::ServerKey::new_radix_server_key_from_shortint(shortint_key);
+        Ok(Self {
+            integer_key: Arc::new(IntegerServerKey {
+                key,
+                wopbs_key: None,
+                noise_squashing_key: None,
+                sanitization_key: None,
+            }),
+        })
+    }
 }
 
 impl AsRef<crate::integer::ServerKey> for ServerKey {
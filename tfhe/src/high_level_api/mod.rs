@@ -27,14 +27,15 @@ pub use config::{Config, ConfigBuilder};
 pub use errors::{Error, OutOfRangeError};
 pub use global_state::{set_server_key, unset_server_key, with_server_key_as_context};
 pub use keys::{
-    generate_keys, ClientKey, CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey,
-    CompressedServerKey, PublicKey, ServerKey,
+    generate_keys, ClientKey, ClientKeyShare, CompactPublicKey, CompressedCompactPublicKey,
+    CompressedPublicKey, CompressedServerKey, PartialDecryption, PublicKey, ServerKey,
 };
 
 #[cfg(test)]
 mod tests;
 
 pub use crate::high_level_api::booleans::{CompressedFheBool, FheBool};
+pub use crate::high_level_api::integers::SquashedNoiseFheUint;
 expand_pub_use_fhe_type!(
     pub use crate::high_level_api::integers{
         FheUint8, FheUint10, FheUint12, FheUint14, FheUint16, FheUint32, FheUint64, FheUint128,
@@ -1,8 +1,10 @@
 use crate::high_level_api::prelude::*;
 use crate::high_level_api::{
-    generate_keys, ClientKey, ConfigBuilder, FheBool, FheUint256, FheUint8, PublicKey,
+    generate_keys, set_server_key, ClientKey, ConfigBuilder, FheBool, FheInt8, FheUint256,
+    FheUint8, PublicKey,
 };
 use crate::integer::U256;
+use crate::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
 use crate::{CompactPublicKey, CompressedPublicKey, CompressedServerKey};
 use std::fmt::Debug;
 
@@ -44,6 +46,74 @@ fn test_integer_public_key() {
     assert_that_public_key_encryption_is_decrypted_by_client_key::<FheUint8, u8>(235, &pks, &cks);
 }
 
+#[test]
+fn test_sanitize() {
+    let config = ConfigBuilder::default()
+        .enable_sanitization(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+        .build();
+
+    let (cks, sks) = generate_keys(config);
+
+    set_server_key(sks);
+
+    let a = FheUint8::encrypt(235u8, &cks);
+    let b = FheUint8::encrypt(37u8, &cks);
+    let sanitized = (&a + &b).sanitize();
+    let decrypted: u8 = sanitized.decrypt(&cks);
+    assert_eq!(decrypted, 235u8.wrapping_add(37u8));
+
+    let a = FheInt8::encrypt(-100i8, &cks);
+    let b = FheInt8::encrypt(37i8, &cks);
+    let sanitized = (&a - &b).sanitize();
+    let decrypted: i8 = sanitized.decrypt(&cks);
+    assert_eq!(decrypted, (-100i8).wrapping_sub(37i8));
+
+    for (clear_a, clear_b) in [(false, false), (true, false), (true, true)] {
+        let a = FheBool::encrypt(clear_a, &cks);
+        let b = FheBool::encrypt(clear_b, &cks);
+        let sanitized = (&a | &b).sanitize();
+        assert_eq!(sanitized.decrypt(&cks), clear_a | clear_b);
+    }
+}
+
+#[test]
+fn test_server_key_to_writer_rejects_unsupported_configs() {
+    use crate::shortint::parameters::{
+        PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+    };
+    use std::io::ErrorKind;
+
+    let configs = [
+        (
+            ConfigBuilder::default()
+                .enable_sanitization(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+                .build(),
+            ErrorKind::InvalidInput,
+        ),
+        (
+            ConfigBuilder::with_custom_parameters(PARAM_MESSAGE_1_CARRY_1_KS_PBS, None).build(),
+            ErrorKind::InvalidInput,
+        ),
+        (
+            ConfigBuilder::with_custom_parameters(
+                PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS,
+                None,
+            )
+            .build(),
+            ErrorKind::Unsupported,
+        ),
+    ];
+
+    for (config, expected_kind) in configs {
+        let cks = ClientKey::generate(config);
+
+        let mut bytes = Vec::new();
+        let err = cks.generate_server_key_to_writer(&mut bytes).unwrap_err();
+        assert_eq!(err.kind(), expected_kind);
+        assert!(bytes.is_empty());
+    }
+}
+
 #[test]
 fn test_small_uint8() {
     let config = ConfigBuilder::default().build();
@@ -5,6 +5,7 @@
 
 mod crt;
 mod radix;
+mod threshold;
 pub(crate) mod utils;
 
 use crate::core_crypto::prelude::{CastFrom, SignedNumeric, UnsignedNumeric};
@@ -22,6 +23,7 @@ use crate::integer::bigint::static_signed::StaticSignedBigInt;
 use crate::integer::ciphertext::boolean_value::BooleanBlock;
 pub use crt::CrtClientKey;
 pub use radix::RadixClientKey;
+pub use threshold::{combine_radix_partial_decryptions, ClientKeyShare, RadixPartialDecryption};
 
 use super::block_decomposition::{DecomposableInto, RecomposableFrom};
 use super::ciphertext::{
@@ -0,0 +1,225 @@
+use crate::integer::block_decomposition::RecomposableFrom;
+use crate::integer::noise_squashing::{
+    recompose_squashed_noise_blocks, NoiseSquashingPrivateKey, SquashedNoiseRadixCiphertext,
+};
+use crate::shortint::client_key::{
+    combine_partial_decryptions_message_and_carry, ClientKeyShare as ShortintClientKeyShare,
+    PartialDecryption as ShortintPartialDecryption,
+};
+use crate::shortint::parameters::{
+    ShareCount, ShareIndex, ShareThreshold, StatisticalSecurityParameter,
+};
+use serde::{Deserialize, Serialize};
+
+/// A share of a [`NoiseSquashingPrivateKey`], a threshold number of shares is required to decrypt
+/// a [`SquashedNoiseRadixCiphertext`] while no single share allows to decrypt.
+///
+/// See [`ShortintClientKeyShare`] for why threshold decryption only applies to squashed
+/// ciphertexts.
+#[derive(Serialize, Deserialize, PartialEq, Debug, Clone)]
+pub struct ClientKeyShare {
+    pub(crate) key: ShortintClientKeyShare,
+}
+
+/// The partial decryption of a [`SquashedNoiseRadixCiphertext`] computed by a participant with its
+/// [`ClientKeyShare`], it contains one partial decryption per block.
+#[derive(Serialize, Deserialize, PartialEq, Eq, Debug, Clone)]
+pub struct RadixPartialDecryption {
+    pub(crate) blocks: Vec<ShortintPartialDecryption>,
+}
+
+impl RadixPartialDecryption {
+    /// Return the [`ShareIndex`] of the share used to compute the partial decryption.
+    pub fn share_index(&self) -> ShareIndex {
+        self.blocks[0].share_index()
+    }
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Split the noise squashing private key in `share_count` [`ClientKeyShare`]s, any `threshold`
+    /// of them are required to decrypt.
+    ///
+    /// See [`ClientKeyShare::partial_decrypt_radix`] for usage.
+    pub fn generate_threshold_shares(
+        &self,
+        threshold: ShareThreshold,
+        share_count: ShareCount,
+    ) -> Vec<ClientKeyShare> {
+        self.key
+            .generate_threshold_shares(threshold, share_count)
+            .into_iter()
+            .map(|key| ClientKeyShare { key })
+            .collect()
+    }
+}
+
+impl ClientKeyShare {
+    /// Return the [`ShareIndex`] of the share.
+    pub fn share_index(&self) -> ShareIndex {
+        self.key.share_index()
+    }
+
+    /// Return the number of shares required to decrypt.
+    pub fn threshold(&self) -> ShareThreshold {
+        self.key.threshold()
+    }
+
+    /// Compute the partial decryption of a squashed ciphertext in radix decomposition.
+    ///
+    /// See [`ShortintClientKeyShare::partial_decrypt`] for the requirements on the participants
+    /// and the `statistical_security` of the noise flooding.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::client_key::combine_radix_partial_decryptions;
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::{
+    ///     ShareCount, ShareThreshold, StatisticalSecurityParameter, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let noise_squashing_private_key = cks
+    ///     .as_ref()
+    ///     .new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks
+    ///     .as_ref()
+    ///     .new_noise_squashing_key(&noise_squashing_private_key);
+    ///
+    /// // Any 2 out of 3 participants can decrypt together
+    /// let key_shares =
+    ///     noise_squashing_private_key.generate_threshold_shares(ShareThreshold(2), ShareCount(3));
+    ///
+    /// let msg = 191_u64;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // The server squashes the noise of the ciphertext to decrypt
+    /// let squashed = sks.squash_radix_ciphertext_noise(&noise_squashing_key, &ct);
+    ///
+    /// // Participants 1 and 3 decrypt
+    /// let participants = [key_shares[0].share_index(), key_shares[2].share_index()];
+    /// let partial_decryptions: Vec<_> = [&key_shares[0], &key_shares[2]]
+    ///     .into_iter()
+    ///     .map(|key_share| {
+    ///         key_share.partial_decrypt_radix(
+    ///             &squashed,
+    ///             &participants,
+    ///             StatisticalSecurityParameter(40),
+    ///         )
+    ///     })
+    ///     .collect();
+    ///
+    /// let dec: u64 = combine_radix_partial_decryptions(&squashed, &partial_decryptions);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn partial_decrypt_radix(
+        &self,
+        ctxt: &SquashedNoiseRadixCiphertext,
+        participants: &[ShareIndex],
+        statistical_security: StatisticalSecurityParameter,
+    ) -> RadixPartialDecryption {
+        RadixPartialDecryption {
+            blocks: ctxt
+                .blocks
+                .iter()
+                .map(|block| {
+                    self.key
+                        .partial_decrypt(block, participants, statistical_security)
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Combine the partial decryptions of a squashed ciphertext in radix decomposition and return the
+/// decrypted integer.
+///
+/// See [`ClientKeyShare::partial_decrypt_radix`] for usage.
+pub fn combine_radix_partial_decryptions<T>(
+    ctxt: &SquashedNoiseRadixCiphertext,
+    partial_decryptions: &[RadixPartialDecryption],
+) -> T
+where
+    T: RecomposableFrom<u64>,
+{
+    assert!(
+        partial_decryptions
+            .iter()
+            .all(|partial_decryption| partial_decryption.blocks.len() == ctxt.blocks.len()),
+        "Got partial decryptions with a number of blocks not matching the ciphertext's."
+    );
+
+    recompose_squashed_noise_blocks(&ctxt.blocks, |block_index, encrypted_block| {
+        let block_partial_decryptions: Vec<_> = partial_decryptions
+            .iter()
+            .map(|partial_decryption| partial_decryption.blocks[block_index].clone())
+            .collect();
+        combine_partial_decryptions_message_and_carry(encrypted_block, &block_partial_decryptions)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::integer::keycache::KEY_CACHE;
+    use crate::integer::IntegerKeyKind;
+    use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    use crate::shortint::parameters::*;
+    use rand::Rng;
+
+    create_parametrized_test!(integer_threshold_decrypt_radix_32_bits {
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        PARAM_MESSAGE_2_CARRY_2_PBS_KS
+    });
+
+    fn integer_threshold_decrypt_radix_32_bits(param: ClassicPBSParameters) {
+        let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+
+        let mut rng = rand::thread_rng();
+        let num_block = (32f64 / (param.message_modulus.0 as f64).log(2.0)).ceil() as usize;
+
+        let noise_squashing_private_key =
+            cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+        let noise_squashing_key = cks.new_noise_squashing_key(&noise_squashing_private_key);
+
+        let key_shares =
+            noise_squashing_private_key.generate_threshold_shares(ShareThreshold(3), ShareCount(5));
+
+        let clear_0 = rng.gen::<u32>();
+        let clear_1 = rng.gen::<u32>();
+
+        let ct_0 = cks.encrypt_radix(clear_0, num_block);
+        let ct_1 = cks.encrypt_radix(clear_1, num_block);
+        let ct_res = sks.add_parallelized(&ct_0, &ct_1);
+
+        // Use all the shares and the last 3 shares
+        for participating_shares in [&key_shares[..], &key_shares[2..]] {
+            let participants: Vec<_> = participating_shares
+                .iter()
+                .map(ClientKeyShare::share_index)
+                .collect();
+
+            for (ct, expected) in [(&ct_0, clear_0), (&ct_res, clear_0.wrapping_add(clear_1))] {
+                let squashed = sks.squash_radix_ciphertext_noise(&noise_squashing_key, ct);
+
+                let partial_decryptions: Vec<_> = participating_shares
+                    .iter()
+                    .map(|key_share| {
+                        key_share.partial_decrypt_radix(
+                            &squashed,
+                            &participants,
+                            StatisticalSecurityParameter(40),
+                        )
+                    })
+                    .collect();
+
+                let dec: u32 = combine_radix_partial_decryptions(&squashed, &partial_decryptions);
+
+                assert_eq!(dec, expected);
+            }
+        }
+    }
+}
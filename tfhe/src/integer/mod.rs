@@ -58,8 +58,10 @@ pub mod client_key;
 pub mod key_switching_key;
 #[cfg(any(test, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod noise_squashing;
 pub mod parameters;
 pub mod public_key;
+pub mod sanitization;
 pub mod server_key;
 pub mod wopbs;
 
@@ -72,7 +74,7 @@ pub use ciphertext::{
     CrtCiphertext, IntegerCiphertext, IntegerRadixCiphertext, RadixCiphertext,
     SignedRadixCiphertext,
 };
-pub use client_key::{ClientKey, CrtClientKey, RadixClientKey};
+pub use client_key::{ClientKey, ClientKeyShare, CrtClientKey, RadixClientKey};
 pub use public_key::{CompressedCompactPublicKey, CompressedPublicKey, PublicKey};
 pub use server_key::{CheckError, CompressedServerKey, ServerKey};
 
@@ -110,8 +112,12 @@ where
             lwe_dimension: wopbs_params.lwe_dimension,
             glwe_dimension: wopbs_params.glwe_dimension,
             polynomial_size: wopbs_params.polynomial_size,
-            lwe_modular_std_dev: wopbs_params.lwe_modular_std_dev,
-            glwe_modular_std_dev: wopbs_params.glwe_modular_std_dev,
+            lwe_noise_distribution: crate::shortint::parameters::NoiseDistribution::Gaussian(
+                wopbs_params.lwe_modular_std_dev,
+            ),
+            glwe_noise_distribution: crate::shortint::parameters::NoiseDistribution::Gaussian(
+                wopbs_params.glwe_modular_std_dev,
+            ),
             pbs_base_log: wopbs_params.pbs_base_log,
             pbs_level: wopbs_params.pbs_level,
             ks_base_log: wopbs_params.ks_base_log,
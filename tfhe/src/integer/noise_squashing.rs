@@ -0,0 +1,164 @@
+//! This module defines the squashing of the noise of integer ciphertexts.
+//!
+//! Each block of a ciphertext is bootstrapped to a 128 bits ciphertext with a much smaller noise,
+//! see [`crate::shortint::noise_squashing`].
+
+use crate::integer::block_decomposition::{BlockRecomposer, RecomposableFrom};
+use crate::integer::ciphertext::RadixCiphertext;
+use crate::integer::{ClientKey, ServerKey};
+use crate::shortint::noise_squashing::{
+    NoiseSquashingKey as ShortintNoiseSquashingKey,
+    NoiseSquashingPrivateKey as ShortintNoiseSquashingPrivateKey, SquashedNoiseCiphertext,
+};
+use crate::shortint::parameters::NoiseSquashingParameters;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The 128 bits secret key under which squashed ciphertexts are encrypted.
+///
+/// Like the [`ClientKey`] it derives from, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingPrivateKey {
+    pub(crate) key: ShortintNoiseSquashingPrivateKey,
+}
+
+/// A 128 bits bootstrapping key squashing the noise of integer ciphertexts.
+///
+/// It is generated by the client and is meant to be published, like the server key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingKey {
+    pub(crate) key: ShortintNoiseSquashingKey,
+}
+
+/// A ciphertext in radix decomposition whose noise was squashed, each block is a
+/// [`SquashedNoiseCiphertext`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SquashedNoiseRadixCiphertext {
+    pub(crate) blocks: Vec<SquashedNoiseCiphertext>,
+}
+
+impl SquashedNoiseRadixCiphertext {
+    pub fn blocks(&self) -> &[SquashedNoiseCiphertext] {
+        &self.blocks
+    }
+}
+
+impl ClientKey {
+    /// Generate the secret key under which squashed ciphertexts are encrypted.
+    ///
+    /// See [`ServerKey::squash_radix_ciphertext_noise`] for usage.
+    pub fn new_noise_squashing_private_key(
+        &self,
+        params: NoiseSquashingParameters,
+    ) -> NoiseSquashingPrivateKey {
+        NoiseSquashingPrivateKey {
+            key: self.key.new_noise_squashing_private_key(params),
+        }
+    }
+
+    /// Generate the key squashing the noise of integer ciphertexts.
+    ///
+    /// See [`ServerKey::squash_radix_ciphertext_noise`] for usage.
+    pub fn new_noise_squashing_key(
+        &self,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> NoiseSquashingKey {
+        NoiseSquashingKey {
+            key: self
+                .key
+                .new_noise_squashing_key(&noise_squashing_private_key.key),
+        }
+    }
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Decrypt a squashed ciphertext in radix decomposition.
+    ///
+    /// See [`ServerKey::squash_radix_ciphertext_noise`] for usage.
+    pub fn decrypt_squashed_noise_radix<T>(&self, ctxt: &SquashedNoiseRadixCiphertext) -> T
+    where
+        T: RecomposableFrom<u64>,
+    {
+        recompose_squashed_noise_blocks(&ctxt.blocks, |_, block| {
+            self.key.decrypt_squashed_noise_ciphertext(block)
+        })
+    }
+}
+
+/// Recompose the blocks of a squashed ciphertext from the message and carry of each block returned
+/// by `decrypt_block`.
+pub(crate) fn recompose_squashed_noise_blocks<T, F>(
+    blocks: &[SquashedNoiseCiphertext],
+    decrypt_block: F,
+) -> T
+where
+    T: RecomposableFrom<u64>,
+    F: Fn(usize, &SquashedNoiseCiphertext) -> u64,
+{
+    if blocks.is_empty() {
+        return T::ZERO;
+    }
+
+    let bits_in_block = blocks[0].message_modulus.0.ilog2();
+    let mut recomposer = BlockRecomposer::<T>::new(bits_in_block);
+
+    for (block_index, encrypted_block) in blocks.iter().enumerate() {
+        let decrypted_block = decrypt_block(block_index, encrypted_block);
+        if !recomposer.add_unmasked(decrypted_block) {
+            // End of T::BITS reached no need to try more
+            // recomposition
+            break;
+        }
+    }
+
+    recomposer.value()
+}
+
+impl ServerKey {
+    /// Squash the noise of a ciphertext in radix decomposition, by bootstrapping each of its blocks
+    /// to a 128 bits ciphertext.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    ///
+    /// let noise_squashing_private_key = cks
+    ///     .as_ref()
+    ///     .new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks
+    ///     .as_ref()
+    ///     .new_noise_squashing_key(&noise_squashing_private_key);
+    ///
+    /// let ct_1 = cks.encrypt(97u64);
+    /// let ct_2 = cks.encrypt(64u64);
+    /// let ct_res = sks.add_parallelized(&ct_1, &ct_2);
+    ///
+    /// let squashed = sks.squash_radix_ciphertext_noise(&noise_squashing_key, &ct_res);
+    ///
+    /// let dec: u64 = noise_squashing_private_key.decrypt_squashed_noise_radix(&squashed);
+    /// assert_eq!(dec, 161);
+    /// ```
+    pub fn squash_radix_ciphertext_noise(
+        &self,
+        noise_squashing_key: &NoiseSquashingKey,
+        ctxt: &RadixCiphertext,
+    ) -> SquashedNoiseRadixCiphertext {
+        SquashedNoiseRadixCiphertext {
+            blocks: ctxt
+                .blocks
+                .par_iter()
+                .map(|block| {
+                    self.key
+                        .squash_ciphertext_noise(&noise_squashing_key.key, block)
+                })
+                .collect(),
+        }
+    }
+}
@@ -2,9 +2,10 @@ use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
 use serde::{Deserialize, Serialize};
 
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::integer::ciphertext::{CompactCiphertextList, RadixCiphertext};
+use crate::integer::ciphertext::{CompactCiphertextList, IntegerCiphertext, RadixCiphertext};
 use crate::integer::encryption::{create_clear_radix_block_iterator, encrypt_words_radix_impl};
 use crate::integer::{ClientKey, SignedRadixCiphertext};
+use crate::shortint::client_key::CompactPublicKeyShare;
 use crate::shortint::{
     CompactPublicKey as ShortintCompactPublicKey,
     CompressedCompactPublicKey as ShortintCompressedCompactPublicKey,
@@ -123,6 +124,15 @@ impl CompactPublicKey {
         }
     }
 
+    /// Rerandomize each block of an integer ciphertext by adding a fresh encryption of zero to it.
+    ///
+    /// See [`ShortintCompactPublicKey::rerandomize`] for details.
+    pub fn rerandomize<T: IntegerCiphertext>(&self, ct: &mut T) {
+        for block in ct.blocks_mut() {
+            self.key.rerandomize(block);
+        }
+    }
+
     pub fn size_elements(&self) -> usize {
         self.key.size_elements()
     }
@@ -143,6 +153,16 @@ impl CompressedCompactPublicKey {
         Self { key }
     }
 
+    /// Aggregate the shares of all the parties of a distributed key generation into a compressed
+    /// compact public key.
+    ///
+    /// See [`ShortintCompressedCompactPublicKey::aggregate_shares`] for the requirements on the
+    /// shares.
+    pub fn aggregate_shares(shares: &[CompactPublicKeyShare]) -> Self {
+        let key = ShortintCompressedCompactPublicKey::aggregate_shares(shares);
+        Self { key }
+    }
+
     pub fn decompress(self) -> CompactPublicKey {
         CompactPublicKey {
             key: self.key.decompress(),
@@ -1,6 +1,6 @@
 use crate::core_crypto::prelude::{SignedNumeric, UnsignedNumeric};
 use crate::integer::block_decomposition::DecomposableInto;
-use crate::integer::ciphertext::{CrtCiphertext, RadixCiphertext};
+use crate::integer::ciphertext::{CrtCiphertext, IntegerCiphertext, RadixCiphertext};
 use crate::integer::client_key::ClientKey;
 use crate::integer::encryption::{encrypt_crt, encrypt_words_radix_impl};
 use crate::integer::public_key::compressed::CompressedPublicKey;
@@ -84,6 +84,15 @@ impl PublicKey {
             ShortintPublicKey::encrypt_without_padding,
         )
     }
+
+    /// Rerandomize each block of an integer ciphertext by adding a fresh encryption of zero to it.
+    ///
+    /// See [`ShortintPublicKey::rerandomize`] for details.
+    pub fn rerandomize<T: IntegerCiphertext>(&self, ct: &mut T) {
+        for block in ct.blocks_mut() {
+            self.key.rerandomize(block);
+        }
+    }
 }
 
 impl From<CompressedPublicKey> for PublicKey {
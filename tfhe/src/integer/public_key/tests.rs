@@ -1,6 +1,10 @@
 use rand::Rng;
 
-use crate::integer::{gen_keys, CompressedPublicKey, IntegerKeyKind, PublicKey};
+use crate::integer::public_key::CompactPublicKey;
+use crate::integer::{
+    gen_keys, ClientKey, CompressedPublicKey, IntegerKeyKind, PublicKey, RadixCiphertext,
+    ServerKey, SignedRadixCiphertext,
+};
 use crate::shortint::parameters::*;
 use crate::shortint::ClassicPBSParameters;
 
@@ -27,6 +31,16 @@ create_parametrized_test!(small_radix_encrypt_decrypt_compact_128_bits_list {
     PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
 });
 
+create_parametrized_test!(radix_rerandomize_public_key {
+    PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS
+});
+
+create_parametrized_test!(radix_rerandomize_compact_public_key {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
 /// Test that the public key can encrypt a 128 bit number
 /// in radix decomposition, and that the client key can decrypt it
 fn big_radix_encrypt_decrypt_128_bits(param: ClassicPBSParameters) {
@@ -105,3 +119,67 @@ fn radix_encrypt_decrypt_compact_128_bits_list(params: ClassicPBSParameters) {
         }
     }
 }
+
+fn radix_rerandomize_public_key(param: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let public_key = PublicKey::new(&cks);
+
+    radix_rerandomize(
+        &cks,
+        &sks,
+        |ct| public_key.rerandomize(ct),
+        |ct| public_key.rerandomize(ct),
+    );
+}
+
+fn radix_rerandomize_compact_public_key(param: ClassicPBSParameters) {
+    let (cks, sks) = KEY_CACHE.get_from_params(param, IntegerKeyKind::Radix);
+    let public_key = CompactPublicKey::new(&cks);
+
+    radix_rerandomize(
+        &cks,
+        &sks,
+        |ct| public_key.rerandomize(ct),
+        |ct| public_key.rerandomize(ct),
+    );
+}
+
+fn radix_rerandomize(
+    cks: &ClientKey,
+    sks: &ServerKey,
+    rerandomize: impl Fn(&mut RadixCiphertext),
+    rerandomize_signed: impl Fn(&mut SignedRadixCiphertext),
+) {
+    let mut rng = rand::thread_rng();
+    let num_block =
+        (64f64 / (cks.parameters().message_modulus().0 as f64).log(2.0)).ceil() as usize;
+
+    for _ in 0..10 {
+        let clear_0 = rng.gen::<u64>();
+        let clear_1 = rng.gen::<u64>();
+
+        let ct_0 = cks.encrypt_radix(clear_0, num_block);
+        let ct_1 = cks.encrypt_radix(clear_1, num_block);
+
+        let mut ct_res = sks.add_parallelized(&ct_0, &ct_1);
+        let input = ct_res.clone();
+        rerandomize(&mut ct_res);
+
+        for (block, input_block) in ct_res.blocks.iter().zip(input.blocks.iter()) {
+            assert_ne!(block.ct, input_block.ct);
+            assert_eq!(block.degree, input_block.degree);
+        }
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec, clear_0.wrapping_add(clear_1));
+
+        let ct_res = sks.add_parallelized(&ct_res, &ct_0);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec, clear_0.wrapping_add(clear_1).wrapping_add(clear_0));
+
+        let clear = rng.gen::<i64>();
+        let mut ct = cks.encrypt_signed_radix(clear, num_block);
+        rerandomize_signed(&mut ct);
+        let dec: i64 = cks.decrypt_signed_radix(&ct);
+        assert_eq!(dec, clear);
+    }
+}
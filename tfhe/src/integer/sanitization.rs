@@ -0,0 +1,100 @@
+//! This module defines the sanitization of integer ciphertexts.
+//!
+//! Each block of a ciphertext is sanitized, see
+//! [`crate::shortint::ServerKey::sanitize`].
+
+use crate::integer::ciphertext::IntegerRadixCiphertext;
+use crate::integer::{BooleanBlock, ClientKey, ServerKey};
+use crate::shortint::parameters::SanitizationParameters;
+use crate::shortint::SanitizationKey as ShortintSanitizationKey;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A key sanitizing integer ciphertexts.
+///
+/// It is generated by the client and is meant to be published, like the server key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SanitizationKey {
+    pub(crate) key: ShortintSanitizationKey,
+}
+
+impl ClientKey {
+    /// Generate the key sanitizing integer ciphertexts.
+    ///
+    /// See [`ServerKey::sanitize_parallelized`] for usage.
+    pub fn new_sanitization_key(&self, params: SanitizationParameters) -> SanitizationKey {
+        SanitizationKey {
+            key: self.key.new_sanitization_key(params),
+        }
+    }
+}
+
+impl ServerKey {
+    /// Sanitize a radix ciphertext, so that it reveals nothing about the computations it went
+    /// through besides its value.
+    ///
+    /// The carries are propagated if needed, then each block is sanitized with
+    /// [`crate::shortint::ServerKey::sanitize`], see its documentation for details.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::integer::gen_keys_radix;
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // We have 4 * 2 = 8 bits of message
+    /// let num_blocks = 4;
+    /// let (cks, sks) = gen_keys_radix(PARAM_MESSAGE_2_CARRY_2_KS_PBS, num_blocks);
+    /// let sanitization_key = cks
+    ///     .as_ref()
+    ///     .new_sanitization_key(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg1 = 97u64;
+    /// let msg2 = 200u64;
+    /// let ct1 = cks.encrypt(msg1);
+    /// let ct2 = cks.encrypt(msg2);
+    ///
+    /// // The carries of the sum are not propagated
+    /// let ct_res = sks.unchecked_add(&ct1, &ct2);
+    ///
+    /// let ct_res = sks.sanitize_parallelized(&ct_res, &sanitization_key);
+    ///
+    /// let dec: u64 = cks.decrypt(&ct_res);
+    /// assert_eq!(dec, (msg1 + msg2) % 256);
+    /// ```
+    pub fn sanitize_parallelized<T>(&self, ct: &T, sanitization_key: &SanitizationKey) -> T
+    where
+        T: IntegerRadixCiphertext,
+    {
+        let mut tmp_ct: T;
+
+        let ct = if ct.block_carries_are_empty() {
+            ct
+        } else {
+            tmp_ct = ct.clone();
+            self.full_propagate_parallelized(&mut tmp_ct);
+            &tmp_ct
+        };
+
+        let blocks = ct
+            .blocks()
+            .par_iter()
+            .map(|block| self.key.sanitize(block, &sanitization_key.key))
+            .collect::<Vec<_>>();
+
+        T::from_blocks(blocks)
+    }
+
+    /// Sanitize a boolean block, so that it reveals nothing about the computations it went
+    /// through besides its value.
+    ///
+    /// See [`crate::shortint::ServerKey::sanitize`] for details.
+    pub fn sanitize_boolean_block(
+        &self,
+        block: &BooleanBlock,
+        sanitization_key: &SanitizationKey,
+    ) -> BooleanBlock {
+        BooleanBlock::new_unchecked(self.key.sanitize(&block.0, &sanitization_key.key))
+    }
+}
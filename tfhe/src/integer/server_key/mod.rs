@@ -10,6 +10,7 @@ pub(crate) mod radix_parallel;
 
 use crate::integer::client_key::ClientKey;
 use crate::shortint::ciphertext::MaxDegree;
+use crate::shortint::client_key::ServerKeyShare;
 use serde::{Deserialize, Serialize};
 
 /// Error returned when the carry buffer is full.
@@ -225,6 +226,35 @@ impl CompressedServerKey {
         let key = crate::shortint::CompressedServerKey::new(&client_key.key);
         Self { key }
     }
+
+    /// Aggregate the shares of all the parties of a distributed key generation into a compressed
+    /// server key for radix ciphertexts.
+    ///
+    /// See [`crate::shortint::CompressedServerKey::aggregate_shares`] for the requirements on the
+    /// shares.
+    pub fn aggregate_radix_shares(shares: &[ServerKeyShare]) -> Self {
+        assert!(!shares.is_empty(), "Got no ServerKeyShare to aggregate");
+
+        let max_degree = MaxDegree::integer_radix_server_key(
+            shares[0].parameters.message_modulus(),
+            shares[0].parameters.carry_modulus(),
+        );
+
+        let key = crate::shortint::CompressedServerKey::aggregate_shares_with_max_degree(
+            shares, max_degree,
+        );
+        Self { key }
+    }
+
+    /// Aggregate the shares of all the parties of a distributed key generation into a compressed
+    /// server key for CRT ciphertexts.
+    ///
+    /// See [`crate::shortint::CompressedServerKey::aggregate_shares`] for the requirements on the
+    /// shares.
+    pub fn aggregate_crt_shares(shares: &[ServerKeyShare]) -> Self {
+        let key = crate::shortint::CompressedServerKey::aggregate_shares(shares);
+        Self { key }
+    }
 }
 
 impl From<CompressedServerKey> for ServerKey {
@@ -237,8 +267,14 @@ impl From<CompressedServerKey> for ServerKey {
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::integer::RadixClientKey;
-    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2;
+    use crate::core_crypto::commons::math::random::Seed;
+    use crate::integer::public_key::CompactPublicKey;
+    use crate::integer::{CompressedCompactPublicKey, RadixClientKey};
+    use crate::shortint::client_key::deal_party_secret_key_shares;
+    use crate::shortint::parameters::{
+        ShareCount, PARAM_MESSAGE_2_CARRY_2, PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    };
+    use rand::Rng;
 
     /// https://github.com/zama-ai/tfhe-rs/issues/460
     /// Problem with CompressedServerKey degree being set to shortint MaxDegree not accounting for
@@ -293,4 +329,41 @@ mod test {
             assert_eq!(modulus - 6, res);
         }
     }
+
+    #[test]
+    fn test_distributed_key_generation_radix() {
+        let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+        let num_blocks = 32;
+
+        let mut rng = rand::thread_rng();
+
+        let party_count = ShareCount(3);
+        let key_shares = deal_party_secret_key_shares(&cks.key, party_count);
+        let common_seed = Seed(rng.gen());
+
+        let server_key_shares: Vec<_> = key_shares
+            .iter()
+            .map(|key_share| key_share.generate_server_key_share(common_seed, party_count))
+            .collect();
+        let compact_public_key_shares: Vec<_> = key_shares
+            .iter()
+            .map(|key_share| key_share.generate_compact_public_key_share(common_seed, party_count))
+            .collect();
+
+        let sks = ServerKey::from(CompressedServerKey::aggregate_radix_shares(
+            &server_key_shares,
+        ));
+        let cpk: CompactPublicKey =
+            CompressedCompactPublicKey::aggregate_shares(&compact_public_key_shares).into();
+
+        let clear_0 = rng.gen::<u64>();
+        let clear_1 = rng.gen::<u64>();
+
+        let ct_0 = cks.encrypt_radix(clear_0, num_blocks);
+        let ct_1 = cpk.encrypt_radix(clear_1, num_blocks);
+
+        let ct_res = sks.add_parallelized(&ct_0, &ct_1);
+        let dec: u64 = cks.decrypt_radix(&ct_res);
+        assert_eq!(dec, clear_0.wrapping_add(clear_1));
+    }
 }
@@ -93,8 +93,8 @@ pub fn wopbs_native_crt_bivariate() {
         lwe_dimension: wopbs_params.lwe_dimension,
         glwe_dimension: wopbs_params.glwe_dimension,
         polynomial_size: wopbs_params.polynomial_size,
-        lwe_modular_std_dev: wopbs_params.lwe_modular_std_dev,
-        glwe_modular_std_dev: wopbs_params.glwe_modular_std_dev,
+        lwe_noise_distribution: NoiseDistribution::Gaussian(wopbs_params.lwe_modular_std_dev),
+        glwe_noise_distribution: NoiseDistribution::Gaussian(wopbs_params.glwe_modular_std_dev),
         pbs_base_log: wopbs_params.pbs_base_log,
         pbs_level: wopbs_params.pbs_level,
         ks_base_log: wopbs_params.ks_base_log,
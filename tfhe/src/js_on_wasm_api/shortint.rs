@@ -319,8 +319,8 @@ impl Shortint {
             lwe_dimension: LweDimension(lwe_dimension),
             glwe_dimension: GlweDimension(glwe_dimension),
             polynomial_size: PolynomialSize(polynomial_size),
-            lwe_modular_std_dev: StandardDev(lwe_modular_std_dev),
-            glwe_modular_std_dev: StandardDev(glwe_modular_std_dev),
+            lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(lwe_modular_std_dev)),
+            glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(glwe_modular_std_dev)),
             pbs_base_log: DecompositionBaseLog(pbs_base_log),
             pbs_level: DecompositionLevelCount(pbs_level),
             ks_base_log: DecompositionBaseLog(ks_base_log),
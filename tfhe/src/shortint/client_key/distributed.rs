@@ -0,0 +1,317 @@
+//! Module with the definition of the secret key shares used for distributed key generation.
+
+use crate::core_crypto::commons::generators::DeterministicSeeder;
+use crate::core_crypto::commons::math::random::{ActivatedRandomGenerator, Seed, Seeder};
+use crate::core_crypto::prelude::*;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{PBSParameters, ShareCount, ShortintParameterSet};
+use crate::shortint::PBSOrder;
+use serde::{Deserialize, Serialize};
+use std::fmt::Debug;
+
+/// The additive shares held by a party of the joint secret keys of a distributed key generation.
+///
+/// In a distributed key generation the joint secret keys are never known to a single party, each
+/// party only holds additive shares modulo $2^{64}$ of:
+/// * the small LWE secret key $\vec{s}$, used as the output of the keyswitch,
+/// * the GLWE secret key $\vec{S}$, whose LWE equivalent is the large LWE secret key,
+/// * the products $s\_j \cdot S\_m$ of the coefficients of the small key with the polynomials of
+///   the GLWE key, the polynomial at index $j \cdot k + m$ being the share of $s\_j \cdot S\_m$.
+///
+/// These shares must be obtained through a secure multi-party computation protocol, which is out
+/// of the scope of this crate. With them each party generates its [`ServerKeyShare`] and
+/// [`CompactPublicKeyShare`] using a seed common to all the parties, the shares of all the parties
+/// are then aggregated into a compressed server key with
+/// [`aggregate_shares`](`crate::shortint::CompressedServerKey::aggregate_shares`) and into a
+/// compressed compact public key with
+/// [`aggregate_shares`](`crate::shortint::CompressedCompactPublicKey::aggregate_shares`).
+///
+/// Only parameters using the classic PBS are supported.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct PartySecretKeyShares {
+    pub(crate) small_lwe_secret_key_share: LweSecretKeyOwned<u64>,
+    pub(crate) glwe_secret_key_share: GlweSecretKeyOwned<u64>,
+    pub(crate) key_product_shares: PolynomialListOwned<u64>,
+    pub parameters: ShortintParameterSet,
+}
+
+/// The share of a party of a [`CompressedServerKey`](`crate::shortint::CompressedServerKey`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ServerKeyShare {
+    pub(crate) key_switching_key: SeededLweKeyswitchKeyOwned<u64>,
+    pub(crate) bootstrapping_key: SeededLweBootstrapKeyOwned<u64>,
+    pub parameters: ShortintParameterSet,
+}
+
+/// The share of a party of a
+/// [`CompressedCompactPublicKey`](`crate::shortint::CompressedCompactPublicKey`).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompactPublicKeyShare {
+    pub(crate) key: SeededLweCompactPublicKeyOwned<u64>,
+    pub parameters: ShortintParameterSet,
+}
+
+/// The seeds of the keys generated in a distributed key generation, they are all derived from the
+/// seed common to all the parties so that each key gets its own masks.
+pub(crate) struct DistributedKeyGenSeeds {
+    pub(crate) bsk: Seed,
+    pub(crate) ksk: Seed,
+    pub(crate) compact_pk: Seed,
+}
+
+impl DistributedKeyGenSeeds {
+    pub(crate) fn new(common_seed: Seed) -> Self {
+        let mut seeder = DeterministicSeeder::<ActivatedRandomGenerator>::new(common_seed);
+        Self {
+            bsk: seeder.seed(),
+            ksk: seeder.seed(),
+            compact_pk: seeder.seed(),
+        }
+    }
+}
+
+/// Return the noise distribution each party must use so that the sum of the noises of all the
+/// shares has the standard deviation of `noise`.
+///
+/// The sum of TUniform noises is not a TUniform anymore, for those the bound of each party is
+/// reduced so that the variance of the sum does not exceed the variance of `noise`.
+pub(crate) fn party_noise(noise: NoiseDistribution, party_count: ShareCount) -> NoiseDistribution {
+    let scale_std_dev = |std: StandardDev| StandardDev(std.0 / (party_count.0 as f64).sqrt());
+    match noise {
+        NoiseDistribution::Gaussian(std) => NoiseDistribution::Gaussian(scale_std_dev(std)),
+        NoiseDistribution::DiscreteGaussian(std) => {
+            NoiseDistribution::DiscreteGaussian(scale_std_dev(std))
+        }
+        NoiseDistribution::TUniform(t_uniform) => {
+            // The variance of TUniform(b) is about 2^(2b) / 3, dividing it by at least party_count
+            // requires to remove ceil(log2(party_count) / 2) bits from the bound
+            let log2_party_count = party_count.0.next_power_of_two().ilog2();
+            NoiseDistribution::TUniform(TUniform::new(
+                t_uniform
+                    .bound_log2()
+                    .saturating_sub((log2_party_count + 1) / 2),
+            ))
+        }
+    }
+}
+
+impl PartySecretKeyShares {
+    /// Create the [`PartySecretKeyShares`] of a party from the shares output by the multi-party
+    /// computation protocol.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the parameters do not use the classic PBS or if the shares do not match the
+    /// dimensions of the parameters.
+    pub fn from_raw_parts<P>(
+        small_lwe_secret_key_share: LweSecretKeyOwned<u64>,
+        glwe_secret_key_share: GlweSecretKeyOwned<u64>,
+        key_product_shares: PolynomialListOwned<u64>,
+        parameters: P,
+    ) -> Self
+    where
+        P: TryInto<ShortintParameterSet>,
+        <P as TryInto<ShortintParameterSet>>::Error: Debug,
+    {
+        let parameters: ShortintParameterSet = parameters.try_into().unwrap();
+
+        assert!(
+            matches!(parameters.pbs_parameters(), Some(PBSParameters::PBS(_))),
+            "Distributed key generation only supports parameters using the classic PBS"
+        );
+        assert_eq!(
+            small_lwe_secret_key_share.lwe_dimension(),
+            parameters.lwe_dimension(),
+            "Mismatched LweDimension between the small LWE secret key share and the parameters"
+        );
+        assert_eq!(
+            glwe_secret_key_share.glwe_dimension(),
+            parameters.glwe_dimension(),
+            "Mismatched GlweDimension between the GLWE secret key share and the parameters"
+        );
+        assert_eq!(
+            glwe_secret_key_share.polynomial_size(),
+            parameters.polynomial_size(),
+            "Mismatched PolynomialSize between the GLWE secret key share and the parameters"
+        );
+        assert_eq!(
+            key_product_shares.polynomial_size(),
+            parameters.polynomial_size(),
+            "Mismatched PolynomialSize between the key product shares and the parameters"
+        );
+        assert_eq!(
+            key_product_shares.polynomial_count().0,
+            parameters.lwe_dimension().0 * parameters.glwe_dimension().0,
+            "Got key product shares with a number of polynomials different from \
+            the LWE dimension times the GLWE dimension"
+        );
+
+        Self {
+            small_lwe_secret_key_share,
+            glwe_secret_key_share,
+            key_product_shares,
+            parameters,
+        }
+    }
+
+    /// Deconstruct the [`PartySecretKeyShares`] into its constituents.
+    pub fn into_raw_parts(
+        self,
+    ) -> (
+        LweSecretKeyOwned<u64>,
+        GlweSecretKeyOwned<u64>,
+        PolynomialListOwned<u64>,
+        ShortintParameterSet,
+    ) {
+        let Self {
+            small_lwe_secret_key_share,
+            glwe_secret_key_share,
+            key_product_shares,
+            parameters,
+        } = self;
+
+        (
+            small_lwe_secret_key_share,
+            glwe_secret_key_share,
+            key_product_shares,
+            parameters,
+        )
+    }
+
+    /// Generate the share of the party of the server key.
+    ///
+    /// All the parties must use the same `common_seed`, which does not need to be secret, and the
+    /// same `party_count`, the noise of each share is scaled so that the aggregated key has the
+    /// noise level of the parameters.
+    pub fn generate_server_key_share(
+        &self,
+        common_seed: Seed,
+        party_count: ShareCount,
+    ) -> ServerKeyShare {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_server_key_share(self, common_seed, party_count)
+        })
+    }
+
+    /// Generate the share of the party of the compact public key.
+    ///
+    /// All the parties must use the same `common_seed`, which does not need to be secret, and the
+    /// same `party_count`. The seed used for the server key can be reused, the compact public key
+    /// gets its own masks.
+    pub fn generate_compact_public_key_share(
+        &self,
+        common_seed: Seed,
+        party_count: ShareCount,
+    ) -> CompactPublicKeyShare {
+        let parameters = self.parameters;
+        let large_lwe_secret_key_share = self.glwe_secret_key_share.as_lwe_secret_key();
+
+        let (secret_encryption_key_share, encryption_noise) =
+            match parameters.encryption_key_choice().into() {
+                PBSOrder::KeyswitchBootstrap => (
+                    large_lwe_secret_key_share,
+                    parameters.glwe_noise_distribution(),
+                ),
+                PBSOrder::BootstrapKeyswitch => (
+                    LweSecretKey::from_container(self.small_lwe_secret_key_share.as_ref()),
+                    parameters.lwe_noise_distribution(),
+                ),
+            };
+
+        let mut key = SeededLweCompactPublicKey::new(
+            0u64,
+            secret_encryption_key_share.lwe_dimension(),
+            DistributedKeyGenSeeds::new(common_seed).compact_pk.into(),
+            parameters.ciphertext_modulus(),
+        );
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            generate_seeded_lwe_compact_public_key(
+                &secret_encryption_key_share,
+                &mut key,
+                party_noise(encryption_noise, party_count),
+                &mut engine.seeder,
+            );
+        });
+
+        CompactPublicKeyShare { key, parameters }
+    }
+}
+
+/// Deal [`PartySecretKeyShares`] of the secret keys of `client_key` to `party_count` parties,
+/// plays the role of the multi-party computation protocol in tests.
+#[cfg(test)]
+pub(crate) fn deal_party_secret_key_shares(
+    client_key: &super::ClientKey,
+    party_count: ShareCount,
+) -> Vec<PartySecretKeyShares> {
+    use rand::Rng;
+
+    let mut rng = rand::thread_rng();
+    let mut split_in_additive_shares = |data: &[u64]| -> Vec<Vec<u64>> {
+        let mut shares: Vec<Vec<u64>> = (1..party_count.0)
+            .map(|_| data.iter().map(|_| rng.gen()).collect())
+            .collect();
+        let last_share = data
+            .iter()
+            .enumerate()
+            .map(|(idx, &value)| {
+                shares
+                    .iter()
+                    .fold(value, |acc, share| acc.wrapping_sub(share[idx]))
+            })
+            .collect();
+        shares.push(last_share);
+        shares
+    };
+
+    let small_lwe_secret_key = &client_key.small_lwe_secret_key;
+    let glwe_secret_key = &client_key.glwe_secret_key;
+    let polynomial_size = glwe_secret_key.polynomial_size();
+
+    let mut key_products = PolynomialListOwned::new(
+        0u64,
+        polynomial_size,
+        PolynomialCount(
+            small_lwe_secret_key.lwe_dimension().0 * glwe_secret_key.glwe_dimension().0,
+        ),
+    );
+    for (mut key_product, (&lwe_key_element, glwe_key_poly)) in key_products.iter_mut().zip(
+        small_lwe_secret_key
+            .as_ref()
+            .iter()
+            .flat_map(|lwe_key_element| {
+                std::iter::repeat(lwe_key_element).take(glwe_secret_key.glwe_dimension().0)
+            })
+            .zip(
+                glwe_secret_key
+                    .as_ref()
+                    .chunks_exact(polynomial_size.0)
+                    .cycle(),
+            ),
+    ) {
+        for (product_coeff, &glwe_key_coeff) in key_product.as_mut().iter_mut().zip(glwe_key_poly) {
+            *product_coeff = glwe_key_coeff.wrapping_mul(lwe_key_element);
+        }
+    }
+
+    let small_lwe_secret_key_shares = split_in_additive_shares(small_lwe_secret_key.as_ref());
+    let glwe_secret_key_shares = split_in_additive_shares(glwe_secret_key.as_ref());
+    let key_product_shares = split_in_additive_shares(key_products.as_ref());
+
+    small_lwe_secret_key_shares
+        .into_iter()
+        .zip(glwe_secret_key_shares)
+        .zip(key_product_shares)
+        .map(
+            |((small_lwe_secret_key_share, glwe_secret_key_share), key_product_share)| {
+                PartySecretKeyShares::from_raw_parts(
+                    LweSecretKey::from_container(small_lwe_secret_key_share),
+                    GlweSecretKey::from_container(glwe_secret_key_share, polynomial_size),
+                    PolynomialList::from_container(key_product_share, polynomial_size),
+                    client_key.parameters,
+                )
+            },
+        )
+        .collect()
+}
@@ -1,5 +1,8 @@
 //! Module with the definition of the ClientKey.
 
+mod distributed;
+mod threshold;
+
 use crate::core_crypto::entities::*;
 use crate::core_crypto::prelude::decrypt_lwe_ciphertext;
 use crate::shortint::ciphertext::{Ciphertext, CompressedCiphertext};
@@ -9,6 +12,15 @@ use crate::shortint::CarryModulus;
 use serde::{Deserialize, Serialize};
 use std::fmt::Debug;
 
+#[cfg(test)]
+pub(crate) use distributed::deal_party_secret_key_shares;
+pub(crate) use distributed::{party_noise, DistributedKeyGenSeeds};
+pub use distributed::{CompactPublicKeyShare, PartySecretKeyShares, ServerKeyShare};
+pub use threshold::{
+    combine_partial_decryptions, combine_partial_decryptions_message_and_carry, ClientKeyShare,
+    PartialDecryption,
+};
+
 use super::PBSOrder;
 
 /// A structure containing the client key, which must be kept secret.
@@ -51,6 +63,52 @@ impl ClientKey {
         })
     }
 
+    /// Generate a server key and write it to `writer`, without ever holding the whole
+    /// bootstrapping key in memory.
+    ///
+    /// This is meant for devices which cannot hold the server key in memory, the written key is
+    /// loaded with [`ServerKey::from_raw_bytes`](`crate::shortint::ServerKey::from_raw_bytes`).
+    ///
+    /// # Errors
+    ///
+    /// The raw key format only describes classic bootstrapping keys, an error of kind
+    /// [`std::io::ErrorKind::Unsupported`] is returned for multi bit PBS parameters and nothing is
+    /// written.
+    ///
+    /// Any error returned by `writer` is forwarded.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, ServerKey};
+    ///
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// // The writer would typically be a file
+    /// let mut raw_sks = Vec::new();
+    /// cks.generate_server_key_to_writer(&mut raw_sks).unwrap();
+    ///
+    /// // The raw key data must be aligned to be loaded, this is the case for a memory mapped
+    /// // file, here we copy the bytes to a suitably aligned buffer
+    /// let aligned_raw_sks = Arc::new(aligned_vec::AVec::<u8>::from_slice(0, &raw_sks));
+    ///
+    /// let sks = ServerKey::from_raw_bytes(aligned_raw_sks, PARAM_MESSAGE_2_CARRY_2_KS_PBS).unwrap();
+    ///
+    /// let ct = cks.encrypt(1);
+    /// let ct_res = sks.unchecked_scalar_add(&ct, 2);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn generate_server_key_to_writer<W: std::io::Write>(
+        &self,
+        writer: W,
+    ) -> std::io::Result<()> {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_server_key_to_writer(self, writer)
+        })
+    }
+
     /// Encrypt a small integer message using the client key.
     ///
     /// The input message is reduced to the encrypted message space modulus
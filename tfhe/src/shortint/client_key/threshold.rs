@@ -0,0 +1,257 @@
+//! Module with the definition of the ClientKeyShare used for threshold decryption.
+
+use crate::core_crypto::algorithms::{
+    combine_lwe_partial_decryptions, generate_lwe_secret_key_shares,
+    lwe_partial_decryption_flooding_std_dev, partial_decrypt_lwe_ciphertext,
+};
+use crate::core_crypto::commons::dispersion::{DispersionParameter, Variance};
+use crate::core_crypto::commons::noise_formulas::programmable_bootstrap_lwe_ciphertext_output_variance;
+use crate::core_crypto::entities::*;
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::noise_squashing::{NoiseSquashingPrivateKey, SquashedNoiseCiphertext};
+use crate::shortint::parameters::{
+    LweDimension, NoiseSquashingParameters, ShareCount, ShareIndex, ShareThreshold,
+    StatisticalSecurityParameter,
+};
+use serde::{Deserialize, Serialize};
+
+/// A share of a [`NoiseSquashingPrivateKey`], a threshold number of shares is required to decrypt
+/// a [`SquashedNoiseCiphertext`] while no single share allows to decrypt.
+///
+/// Shares are obtained with [`NoiseSquashingPrivateKey::generate_threshold_shares`], each
+/// participant then computes a [`PartialDecryption`] with its share and the partial decryptions
+/// are combined with [`combine_partial_decryptions`].
+///
+/// Threshold decryption only applies to squashed ciphertexts: the noise of the ciphertexts computed
+/// by the [`ServerKey`](crate::shortint::ServerKey) leaves no room in their decoding margin for a
+/// flooding noise hiding it, see [`crate::shortint::noise_squashing`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ClientKeyShare {
+    /// Share of the LWE secret key equivalent to the noise squashing GLWE secret key
+    pub(crate) lwe_secret_key_share: LweSecretKeyShareOwned<u128>,
+    /// Dimension of the LWE secret key the squashed ciphertexts are bootstrapped from
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub params: NoiseSquashingParameters,
+}
+
+/// The partial decryption of a [`SquashedNoiseCiphertext`] computed by a participant with its
+/// [`ClientKeyShare`].
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PartialDecryption {
+    pub(crate) partial_decryption: LwePartialDecryption<u128>,
+}
+
+impl PartialDecryption {
+    /// Return the [`ShareIndex`] of the share used to compute the partial decryption.
+    pub fn share_index(&self) -> ShareIndex {
+        self.partial_decryption.share_index()
+    }
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Split the noise squashing private key in `share_count` [`ClientKeyShare`]s, any `threshold`
+    /// of them are required to decrypt.
+    ///
+    /// The [`ClientKey`](super::ClientKey) and the noise squashing private key must be discarded
+    /// once the shares are given to the participants, for no single party to be able to decrypt.
+    ///
+    /// See [`ClientKeyShare::partial_decrypt`] for usage.
+    pub fn generate_threshold_shares(
+        &self,
+        threshold: ShareThreshold,
+        share_count: ShareCount,
+    ) -> Vec<ClientKeyShare> {
+        let lwe_secret_key_shares = ShortintEngine::with_thread_local_mut(|engine| {
+            generate_lwe_secret_key_shares(
+                &self.post_noise_squashing_secret_key.as_lwe_secret_key(),
+                threshold,
+                share_count,
+                &mut engine.secret_generator,
+            )
+        });
+
+        lwe_secret_key_shares
+            .into_iter()
+            .map(|lwe_secret_key_share| ClientKeyShare {
+                lwe_secret_key_share,
+                input_lwe_dimension: self.input_lwe_dimension,
+                params: self.params,
+            })
+            .collect()
+    }
+}
+
+impl ClientKeyShare {
+    /// Return the [`ShareIndex`] of the share.
+    pub fn share_index(&self) -> ShareIndex {
+        self.lwe_secret_key_share.share_index()
+    }
+
+    /// Return the number of shares required to decrypt.
+    pub fn threshold(&self) -> ShareThreshold {
+        self.lwe_secret_key_share.threshold()
+    }
+
+    /// Compute the partial decryption of a squashed ciphertext.
+    ///
+    /// `participants` contains the share indices of all the participants to the decryption, all of
+    /// them must use the same set. A flooding noise is added to the partial decryption to hide the
+    /// share, it is $2^{\lambda}$ times larger than the noise of the squashed ciphertext, with
+    /// $\lambda$ the `statistical_security`, see
+    /// [`lwe_partial_decryption_flooding_std_dev`].
+    ///
+    /// # Panics
+    ///
+    /// The flooding noises of all the participants add up in the combined result. This panics if
+    /// their sum may exceed the decoding margin of the ciphertext with a probability above
+    /// $2^{-\lambda}$, i.e. if `statistical_security` is too large for the noise squashing
+    /// parameters.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::client_key::combine_partial_decryptions;
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::{
+    ///     ShareCount, ShareThreshold, StatisticalSecurityParameter, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    /// };
+    ///
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let noise_squashing_private_key =
+    ///     cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks.new_noise_squashing_key(&noise_squashing_private_key);
+    ///
+    /// // Any 2 out of 3 participants can decrypt together
+    /// let key_shares =
+    ///     noise_squashing_private_key.generate_threshold_shares(ShareThreshold(2), ShareCount(3));
+    ///
+    /// let msg = 3;
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// // The server squashes the noise of the ciphertext to decrypt
+    /// let squashed = sks.squash_ciphertext_noise(&noise_squashing_key, &ct);
+    ///
+    /// // Participants 2 and 3 decrypt
+    /// let participants = [key_shares[1].share_index(), key_shares[2].share_index()];
+    /// let partial_decryptions: Vec<_> = key_shares[1..]
+    ///     .iter()
+    ///     .map(|key_share| {
+    ///         key_share.partial_decrypt(&squashed, &participants, StatisticalSecurityParameter(40))
+    ///     })
+    ///     .collect();
+    ///
+    /// let dec = combine_partial_decryptions(&squashed, &partial_decryptions);
+    /// assert_eq!(msg, dec);
+    /// ```
+    pub fn partial_decrypt(
+        &self,
+        ct: &SquashedNoiseCiphertext,
+        participants: &[ShareIndex],
+        statistical_security: StatisticalSecurityParameter,
+    ) -> PartialDecryption {
+        assert!(
+            ct.message_modulus == self.params.message_modulus
+                && ct.carry_modulus == self.params.carry_modulus,
+            "Squashed ciphertext parameters do not match the key share parameters"
+        );
+
+        let ciphertext_noise = self.squashed_noise_variance();
+        assert!(
+            flooding_noise_fits_decoding_margin(
+                ciphertext_noise,
+                statistical_security,
+                participants.len(),
+                ct,
+            ),
+            "The flooding noise of {} participants for a {statistical_security:?} does not fit \
+            in the decoding margin of a squashed ciphertext with {:?} and {:?}.",
+            participants.len(),
+            ct.message_modulus,
+            ct.carry_modulus,
+        );
+
+        let partial_decryption = ShortintEngine::with_thread_local_mut(|engine| {
+            partial_decrypt_lwe_ciphertext(
+                &self.lwe_secret_key_share,
+                &ct.ct,
+                participants,
+                ciphertext_noise,
+                statistical_security,
+                &mut engine.encryption_generator,
+            )
+        });
+
+        PartialDecryption { partial_decryption }
+    }
+
+    /// Return the variance of the noise of the squashed ciphertexts, the noise of the 128 bits
+    /// bootstrap does not depend on the noise of its input.
+    fn squashed_noise_variance(&self) -> Variance {
+        programmable_bootstrap_lwe_ciphertext_output_variance(
+            self.input_lwe_dimension,
+            self.params.glwe_dimension,
+            self.params.polynomial_size,
+            self.params.glwe_noise_distribution.variance(u128::BITS),
+            self.params.decomp_base_log,
+            self.params.decomp_level_count,
+            self.params.ciphertext_modulus,
+        )
+    }
+}
+
+/// Return whether the sum of the flooding noises of `participant_count` participants exceeds the
+/// decoding margin of a squashed ciphertext with a probability below $2^{-\lambda}$.
+fn flooding_noise_fits_decoding_margin(
+    ciphertext_noise: Variance,
+    statistical_security: StatisticalSecurityParameter,
+    participant_count: usize,
+    ct: &SquashedNoiseCiphertext,
+) -> bool {
+    // The delta on the torus is 1 / (2 * message_modulus * carry_modulus), the noise must stay
+    // below half of it for the rounding to be correct
+    let decoding_margin = 1.0 / (4 * ct.message_modulus.0 * ct.carry_modulus.0) as f64;
+
+    // A gaussian exceeds k times its standard deviation with a probability below
+    // 2^-(lambda + 1) with k = sqrt(2 (lambda + 2) ln 2)
+    let lambda = statistical_security.0 as f64;
+    let tail_factor = (2.0 * (lambda + 2.0) * std::f64::consts::LN_2).sqrt();
+
+    let flooding_std_dev =
+        lwe_partial_decryption_flooding_std_dev(ciphertext_noise, statistical_security);
+    let total_std_dev = (participant_count as f64 * flooding_std_dev.get_variance()
+        + ciphertext_noise.get_variance())
+    .sqrt();
+
+    tail_factor * total_std_dev <= decoding_margin
+}
+
+/// Combine the partial decryptions of a squashed ciphertext and return the decrypted message and
+/// carry.
+///
+/// See [`ClientKeyShare::partial_decrypt`] for usage.
+pub fn combine_partial_decryptions_message_and_carry(
+    ct: &SquashedNoiseCiphertext,
+    partial_decryptions: &[PartialDecryption],
+) -> u64 {
+    let partial_decryptions: Vec<_> = partial_decryptions
+        .iter()
+        .map(|partial_decryption| partial_decryption.partial_decryption.clone())
+        .collect();
+
+    let decrypted_encoded = combine_lwe_partial_decryptions(&ct.ct, &partial_decryptions);
+
+    ct.decode_message_and_carry(decrypted_encoded.0)
+}
+
+/// Combine the partial decryptions of a squashed ciphertext and return the decrypted message.
+///
+/// See [`ClientKeyShare::partial_decrypt`] for usage.
+pub fn combine_partial_decryptions(
+    ct: &SquashedNoiseCiphertext,
+    partial_decryptions: &[PartialDecryption],
+) -> u64 {
+    combine_partial_decryptions_message_and_carry(ct, partial_decryptions)
+        % ct.message_modulus.0 as u64
+}
@@ -1,7 +1,7 @@
 //! All the `ShortintEngine` method related to client side (encrypt / decrypt)
 use super::ShortintEngine;
 use crate::core_crypto::algorithms::*;
-use crate::core_crypto::commons::dispersion::DispersionParameter;
+use crate::core_crypto::commons::dispersion::NoiseDistribution;
 use crate::core_crypto::entities::*;
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::parameters::{CarryModulus, MessageModulus};
@@ -59,7 +59,7 @@ impl ShortintEngine {
         &mut self,
         client_key_parameters: &ShortintParameterSet,
         client_lwe_sk: &LweSecretKeyOwned<u64>,
-        noise_parameter: impl DispersionParameter,
+        noise_parameter: impl Into<NoiseDistribution>,
         message: u64,
         message_modulus: MessageModulus,
     ) -> LweCiphertextOwned<u64> {
@@ -95,11 +95,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -147,11 +147,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -202,11 +202,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -234,11 +234,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -290,11 +290,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -336,11 +336,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -379,11 +379,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
@@ -422,11 +422,11 @@ impl ShortintEngine {
         let (encryption_lwe_sk, encryption_noise) = match params_op_order {
             PBSOrder::KeyswitchBootstrap => (
                 &client_key.large_lwe_secret_key,
-                client_key.parameters.glwe_modular_std_dev(),
+                client_key.parameters.glwe_noise_distribution(),
             ),
             PBSOrder::BootstrapKeyswitch => (
                 &client_key.small_lwe_secret_key,
-                client_key.parameters.lwe_modular_std_dev(),
+                client_key.parameters.lwe_noise_distribution(),
             ),
         };
 
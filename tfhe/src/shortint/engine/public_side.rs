@@ -27,11 +27,11 @@ impl ShortintEngine {
             match client_parameters.encryption_key_choice().into() {
                 crate::shortint::PBSOrder::KeyswitchBootstrap => (
                     &client_key.large_lwe_secret_key,
-                    client_parameters.glwe_modular_std_dev(),
+                    client_parameters.glwe_noise_distribution(),
                 ),
                 crate::shortint::PBSOrder::BootstrapKeyswitch => (
                     &client_key.small_lwe_secret_key,
-                    client_parameters.lwe_modular_std_dev(),
+                    client_parameters.lwe_noise_distribution(),
                 ),
             };
 
@@ -73,11 +73,11 @@ impl ShortintEngine {
             match client_parameters.encryption_key_choice().into() {
                 crate::shortint::PBSOrder::KeyswitchBootstrap => (
                     &client_key.large_lwe_secret_key,
-                    client_parameters.glwe_modular_std_dev(),
+                    client_parameters.glwe_noise_distribution(),
                 ),
                 crate::shortint::PBSOrder::BootstrapKeyswitch => (
                     &client_key.small_lwe_secret_key,
-                    client_parameters.lwe_modular_std_dev(),
+                    client_parameters.lwe_noise_distribution(),
                 ),
             };
 
@@ -462,4 +462,34 @@ impl ShortintEngine {
             public_key.pbs_order,
         )
     }
+
+    /// Add a fresh encryption of zero under `public_key` to `ct`, which makes its mask independent
+    /// from the computations it went through.
+    pub(crate) fn add_public_key_zero_encryption_assign(
+        &mut self,
+        public_key: &PublicKey,
+        ct: &mut Ciphertext,
+    ) {
+        assert_eq!(
+            ct.pbs_order, public_key.pbs_order,
+            "Mismatched PBSOrder between the ciphertext ({:?}) and the public key ({:?})",
+            ct.pbs_order, public_key.pbs_order
+        );
+
+        let mut zero_encryption = LweCiphertextOwned::new(
+            0u64,
+            public_key.lwe_public_key.lwe_size(),
+            public_key.lwe_public_key.ciphertext_modulus(),
+        );
+
+        encrypt_lwe_ciphertext_with_public_key(
+            &public_key.lwe_public_key,
+            &mut zero_encryption,
+            Plaintext(0),
+            &mut self.secret_generator,
+        );
+
+        lwe_ciphertext_add_assign(&mut ct.ct, &zero_encryption);
+        ct.set_noise_level(ct.noise_level() + NoiseLevel::NOMINAL);
+    }
 }
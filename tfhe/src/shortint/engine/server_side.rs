@@ -1,12 +1,16 @@
 use super::ShortintEngine;
 use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::math::random::Seed;
 use crate::core_crypto::commons::parameters::{
-    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweBskGroupingFactor,
-    LweDimension, PolynomialSize, ThreadCount,
+    DecompositionBaseLog, DecompositionLevelCount, GgswCiphertextCount, GlweDimension,
+    LweBskGroupingFactor, LweDimension, PolynomialSize, ShareCount, ThreadCount,
 };
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
 use crate::shortint::ciphertext::{MaxDegree, MaxNoiseLevel};
+use crate::shortint::client_key::{
+    party_noise, DistributedKeyGenSeeds, PartySecretKeyShares, ServerKeyShare,
+};
 use crate::shortint::parameters::ShortintKeySwitchingParameters;
 use crate::shortint::server_key::{ShortintBootstrappingKey, ShortintCompressedBootstrappingKey};
 use crate::shortint::{ClientKey, CompressedServerKey, ServerKey};
@@ -66,7 +70,7 @@ impl ShortintEngine {
                         &cks.glwe_secret_key,
                         pbs_params.pbs_base_log,
                         pbs_params.pbs_level,
-                        pbs_params.glwe_modular_std_dev,
+                        pbs_params.glwe_noise_distribution,
                         pbs_params.ciphertext_modulus,
                         &mut self.encryption_generator,
                     );
@@ -83,7 +87,7 @@ impl ShortintEngine {
                 // Conversion to fourier domain
                 par_convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut fourier_bsk);
 
-                ShortintBootstrappingKey::Classic(fourier_bsk)
+                ShortintBootstrappingKey::Classic(fourier_bsk.into())
             }
             crate::shortint::PBSParameters::MultiBitPBS(pbs_params) => {
                 let bootstrap_key: LweMultiBitBootstrapKeyOwned<u64> =
@@ -123,7 +127,7 @@ impl ShortintEngine {
                     pbs_params.grouping_factor,
                 );
                 ShortintBootstrappingKey::MultiBit {
-                    fourier_bsk,
+                    fourier_bsk: fourier_bsk.into(),
                     thread_count,
                     deterministic_execution: pbs_params.deterministic_execution,
                 }
@@ -136,7 +140,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log(),
             cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
+            cks.parameters.lwe_noise_distribution(),
             cks.parameters.ciphertext_modulus(),
             &mut self.encryption_generator,
         );
@@ -148,7 +152,7 @@ impl ShortintEngine {
 
         // Pack the keys in the server key set:
         ServerKey {
-            key_switching_key,
+            key_switching_key: key_switching_key.into(),
             bootstrapping_key: bootstrapping_key_base,
             message_modulus: cks.parameters.message_modulus(),
             carry_modulus: cks.parameters.carry_modulus(),
@@ -159,6 +163,66 @@ impl ShortintEngine {
         }
     }
 
+    /// Generate a server key and write it to `writer` without holding the bootstrapping key in
+    /// memory, see [`ServerKey::from_raw_bytes`] for the format.
+    pub(crate) fn new_server_key_to_writer<W: std::io::Write>(
+        &mut self,
+        cks: &ClientKey,
+        mut writer: W,
+    ) -> std::io::Result<()> {
+        let pbs_params = match cks.parameters.pbs_parameters().unwrap() {
+            crate::shortint::PBSParameters::PBS(pbs_params) => pbs_params,
+            crate::shortint::PBSParameters::MultiBitPBS(_) => {
+                return Err(std::io::Error::new(
+                    std::io::ErrorKind::Unsupported,
+                    "Streaming server key generation only supports classic PBS parameters",
+                ));
+            }
+        };
+
+        // The bootstrapping key is generated first, as in new_server_key_with_max_degree, so that
+        // the same engine state yields the same server key
+        #[cfg(not(feature = "__wasm_api"))]
+        par_generate_fourier_lwe_bootstrap_key_to_writer(
+            &cks.small_lwe_secret_key,
+            &cks.glwe_secret_key,
+            pbs_params.pbs_base_log,
+            pbs_params.pbs_level,
+            pbs_params.glwe_noise_distribution,
+            pbs_params.ciphertext_modulus,
+            // One GGSW per thread keeps the memory usage low while using all threads
+            GgswCiphertextCount(rayon::current_num_threads()),
+            &mut self.encryption_generator,
+            &mut writer,
+        )?;
+
+        #[cfg(feature = "__wasm_api")]
+        generate_fourier_lwe_bootstrap_key_to_writer(
+            &cks.small_lwe_secret_key,
+            &cks.glwe_secret_key,
+            pbs_params.pbs_base_log,
+            pbs_params.pbs_level,
+            pbs_params.glwe_noise_distribution,
+            pbs_params.ciphertext_modulus,
+            GgswCiphertextCount(1),
+            &mut self.encryption_generator,
+            &mut writer,
+        )?;
+
+        // The key switching key is much smaller, it is generated in memory
+        let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+            &cks.large_lwe_secret_key,
+            &cks.small_lwe_secret_key,
+            cks.parameters.ks_base_log(),
+            cks.parameters.ks_level(),
+            cks.parameters.lwe_noise_distribution(),
+            cks.parameters.ciphertext_modulus(),
+            &mut self.encryption_generator,
+        );
+
+        key_switching_key.write_raw(&mut writer)
+    }
+
     pub(crate) fn new_key_switching_key(
         &mut self,
         cks1: &ClientKey,
@@ -171,7 +235,7 @@ impl ShortintEngine {
             &cks2.large_lwe_secret_key,
             params.ks_base_log,
             params.ks_level,
-            cks2.parameters.lwe_modular_std_dev(),
+            cks2.parameters.lwe_noise_distribution(),
             cks2.parameters.ciphertext_modulus(),
             &mut self.encryption_generator,
         )
@@ -199,7 +263,7 @@ impl ShortintEngine {
                     &cks.glwe_secret_key,
                     pbs_params.pbs_base_log,
                     pbs_params.pbs_level,
-                    pbs_params.glwe_modular_std_dev,
+                    pbs_params.glwe_noise_distribution,
                     pbs_params.ciphertext_modulus,
                     &mut self.seeder,
                 );
@@ -210,7 +274,7 @@ impl ShortintEngine {
                     &cks.glwe_secret_key,
                     pbs_params.pbs_base_log,
                     pbs_params.pbs_level,
-                    pbs_params.glwe_modular_std_dev,
+                    pbs_params.glwe_noise_distribution,
                     pbs_params.ciphertext_modulus,
                     &mut self.seeder,
                 );
@@ -257,7 +321,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log(),
             cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
+            cks.parameters.lwe_noise_distribution(),
             cks.parameters.ciphertext_modulus(),
             &mut self.seeder,
         );
@@ -273,4 +337,60 @@ impl ShortintEngine {
             pbs_order: cks.parameters.encryption_key_choice().into(),
         }
     }
+
+    pub(crate) fn new_server_key_share(
+        &mut self,
+        key_shares: &PartySecretKeyShares,
+        common_seed: Seed,
+        party_count: ShareCount,
+    ) -> ServerKeyShare {
+        let parameters = key_shares.parameters;
+        let seeds = DistributedKeyGenSeeds::new(common_seed);
+
+        let mut bootstrapping_key = SeededLweBootstrapKey::new(
+            0u64,
+            parameters.glwe_dimension().to_glwe_size(),
+            parameters.polynomial_size(),
+            parameters.pbs_base_log(),
+            parameters.pbs_level(),
+            parameters.lwe_dimension(),
+            seeds.bsk.into(),
+            parameters.ciphertext_modulus(),
+        );
+
+        generate_seeded_lwe_bootstrap_key_share(
+            &key_shares.small_lwe_secret_key_share,
+            &key_shares.glwe_secret_key_share,
+            &key_shares.key_product_shares,
+            &mut bootstrapping_key,
+            party_noise(parameters.glwe_noise_distribution(), party_count),
+            &mut self.seeder,
+        );
+
+        let large_lwe_secret_key_share = key_shares.glwe_secret_key_share.as_lwe_secret_key();
+
+        let mut key_switching_key = SeededLweKeyswitchKey::new(
+            0u64,
+            parameters.ks_base_log(),
+            parameters.ks_level(),
+            large_lwe_secret_key_share.lwe_dimension(),
+            parameters.lwe_dimension(),
+            seeds.ksk.into(),
+            parameters.ciphertext_modulus(),
+        );
+
+        generate_seeded_lwe_keyswitch_key(
+            &large_lwe_secret_key_share,
+            &key_shares.small_lwe_secret_key_share,
+            &mut key_switching_key,
+            party_noise(parameters.lwe_noise_distribution(), party_count),
+            &mut self.seeder,
+        );
+
+        ServerKeyShare {
+            key_switching_key,
+            bootstrapping_key,
+            parameters,
+        }
+    }
 }
@@ -120,7 +120,7 @@ impl ShortintEngine {
             &cks.small_lwe_secret_key,
             cks.parameters.ks_base_log(),
             cks.parameters.ks_level(),
-            cks.parameters.lwe_modular_std_dev(),
+            cks.parameters.lwe_noise_distribution(),
             parameters.ciphertext_modulus,
             &mut self.encryption_generator,
         );
@@ -141,8 +141,8 @@ impl ShortintEngine {
         );
 
         let wopbs_server_key = ServerKey {
-            key_switching_key: ksk_wopbs_large_to_wopbs_small,
-            bootstrapping_key: ShortintBootstrappingKey::Classic(small_bsk),
+            key_switching_key: ksk_wopbs_large_to_wopbs_small.into(),
+            bootstrapping_key: ShortintBootstrappingKey::Classic(small_bsk.into()),
             message_modulus: parameters.message_modulus,
             carry_modulus: parameters.carry_modulus,
             max_degree: MaxDegree::from_msg_carry_modulus(
@@ -160,7 +160,7 @@ impl ShortintEngine {
         );
 
         let pbs_server_key = ServerKey {
-            key_switching_key: ksk_wopbs_large_to_pbs_small,
+            key_switching_key: ksk_wopbs_large_to_pbs_small.into(),
             bootstrapping_key: sks.bootstrapping_key.clone(),
             message_modulus: cks.parameters.message_modulus(),
             carry_modulus: cks.parameters.carry_modulus(),
@@ -177,7 +177,7 @@ impl ShortintEngine {
             wopbs_server_key,
             pbs_server_key,
             cbs_pfpksk,
-            ksk_pbs_to_wopbs: ksk_pbs_large_to_wopbs_large,
+            ksk_pbs_to_wopbs: ksk_pbs_large_to_wopbs_large.into(),
             param: *parameters,
         }
     }
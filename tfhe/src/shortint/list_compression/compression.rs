@@ -0,0 +1,331 @@
+use super::{CompressionKey, DecompressionKey};
+use crate::core_crypto::commons::parameters::{
+    CiphertextModulusLog, GlweDimension, LweCiphertextCount, MonomialDegree, PolynomialSize,
+};
+use crate::core_crypto::prelude::{
+    extract_lwe_sample_from_glwe_ciphertext, keyswitch_lwe_ciphertext,
+    keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext,
+    programmable_bootstrap_lwe_ciphertext, GlweCiphertext, GlweCiphertextOwned, LweCiphertext,
+    LweCiphertextList,
+};
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
+use crate::shortint::{Ciphertext, PBSOrder, ServerKey};
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// A GLWE ciphertext packing several LWE ciphertexts, whose coefficients are stored on
+/// `log_modulus` bits.
+///
+/// Only the coefficients of the body holding a packed ciphertext are stored.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub(crate) struct PackedGlweCiphertext {
+    packed_coefficients: Vec<u64>,
+    glwe_dimension: GlweDimension,
+    polynomial_size: PolynomialSize,
+    bodies_count: LweCiphertextCount,
+    log_modulus: CiphertextModulusLog,
+}
+
+impl PackedGlweCiphertext {
+    fn pack(
+        glwe: &GlweCiphertextOwned<u64>,
+        bodies_count: LweCiphertextCount,
+        log_modulus: CiphertextModulusLog,
+    ) -> Self {
+        let glwe_dimension = glwe.glwe_size().to_glwe_dimension();
+        let polynomial_size = glwe.polynomial_size();
+        let coefficients_count = glwe_dimension.0 * polynomial_size.0 + bodies_count.0;
+
+        let mask = glwe.get_mask();
+        let body = glwe.get_body();
+        let coefficients = mask
+            .as_ref()
+            .iter()
+            .chain(body.as_ref()[..bodies_count.0].iter());
+
+        let mut packed_coefficients = vec![0u64; (coefficients_count * log_modulus.0 + 63) / 64];
+        for (index, coefficient) in coefficients.enumerate() {
+            let value = modulus_switch(*coefficient, log_modulus);
+
+            let start = index * log_modulus.0;
+            let (word, shift) = (start / 64, start % 64);
+            packed_coefficients[word] |= value << shift;
+            if shift + log_modulus.0 > 64 {
+                packed_coefficients[word + 1] |= value >> (64 - shift);
+            }
+        }
+
+        Self {
+            packed_coefficients,
+            glwe_dimension,
+            polynomial_size,
+            bodies_count,
+            log_modulus,
+        }
+    }
+
+    fn unpack(&self, ciphertext_modulus: CiphertextModulus) -> GlweCiphertextOwned<u64> {
+        let coefficients_count =
+            self.glwe_dimension.0 * self.polynomial_size.0 + self.bodies_count.0;
+        let log_modulus = self.log_modulus.0;
+        let mask = if log_modulus == 64 {
+            u64::MAX
+        } else {
+            (1 << log_modulus) - 1
+        };
+
+        let mut glwe = GlweCiphertext::new(
+            0,
+            self.glwe_dimension.to_glwe_size(),
+            self.polynomial_size,
+            ciphertext_modulus,
+        );
+
+        for (index, coefficient) in glwe.as_mut()[..coefficients_count].iter_mut().enumerate() {
+            let start = index * log_modulus;
+            let (word, shift) = (start / 64, start % 64);
+            let mut value = self.packed_coefficients[word] >> shift;
+            if shift + log_modulus > 64 {
+                value |= self.packed_coefficients[word + 1] << (64 - shift);
+            }
+
+            // Switch back to the native modulus
+            *coefficient = (value & mask) << (64 - log_modulus);
+        }
+
+        glwe
+    }
+}
+
+// Round a value of the native torus to the closest multiple of 2^(64 - log_modulus), and return
+// it as an integer modulo 2^log_modulus.
+fn modulus_switch(value: u64, log_modulus: CiphertextModulusLog) -> u64 {
+    let log_modulus = log_modulus.0;
+    if log_modulus == 64 {
+        return value;
+    }
+
+    let rounded = ((value >> (63 - log_modulus)).wrapping_add(1)) >> 1;
+    rounded & ((1 << log_modulus) - 1)
+}
+
+/// A list of shortint ciphertexts packed into GLWE ciphertexts.
+///
+/// See [`ServerKey::compress_ciphertexts`].
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressedCiphertextList {
+    pub(crate) packed_list: Vec<PackedGlweCiphertext>,
+    pub(crate) degrees: Vec<Degree>,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub ciphertext_modulus: CiphertextModulus,
+    pub pbs_order: PBSOrder,
+    pub lwe_per_glwe: LweCiphertextCount,
+}
+
+impl CompressedCiphertextList {
+    /// Return the number of ciphertexts in the list.
+    pub fn len(&self) -> usize {
+        self.degrees.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.degrees.is_empty()
+    }
+}
+
+impl ServerKey {
+    /// Compress a list of ciphertexts, by packing them into GLWE ciphertexts.
+    ///
+    /// Each GLWE ciphertext packs up to `lwe_per_glwe` ciphertexts. If the compression key has a
+    /// storage modulus, the coefficients of the GLWE ciphertexts are then modulus switched to it,
+    /// which reduces their size further.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertexts do not match the parameters of the server key, or if their noise
+    /// level is above the nominal level: compressing adds noise, so the ciphertexts must be
+    /// bootstrapped beforehand.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+    /// let (compression_key, decompression_key) =
+    ///     cks.new_compression_decompression_keys(&private_compression_key);
+    ///
+    /// let ct_1 = cks.encrypt(1);
+    /// let ct_2 = cks.encrypt(2);
+    /// let ct_res = sks.mul_lsb(&ct_1, &ct_2);
+    ///
+    /// let compressed = sks.compress_ciphertexts(&compression_key, &[ct_1, ct_res]);
+    /// assert_eq!(compressed.len(), 2);
+    ///
+    /// let decompressed = sks
+    ///     .decompress_ciphertext(&decompression_key, &compressed, 1)
+    ///     .unwrap();
+    /// assert_eq!(cks.decrypt(&decompressed), 2);
+    /// ```
+    pub fn compress_ciphertexts(
+        &self,
+        compression_key: &CompressionKey,
+        ciphertexts: &[Ciphertext],
+    ) -> CompressedCiphertextList {
+        assert!(
+            self.ciphertext_modulus.is_native_modulus(),
+            "Compression only supports the native ciphertext modulus"
+        );
+
+        for ct in ciphertexts {
+            assert!(
+                ct.message_modulus == self.message_modulus
+                    && ct.carry_modulus == self.carry_modulus
+                    && ct.pbs_order == self.pbs_order,
+                "Ciphertext parameters do not match the server key parameters"
+            );
+            assert!(
+                ct.noise_level() == NoiseLevel::NOMINAL || ct.noise_level() == NoiseLevel::ZERO,
+                "Ciphertexts must have at most a nominal noise level to be compressed, got {:?}",
+                ct.noise_level()
+            );
+        }
+
+        let lwe_pksk = &compression_key.packing_key_switching_key;
+        let log_modulus = compression_key
+            .storage_log_modulus
+            .unwrap_or(CiphertextModulusLog(u64::BITS as usize));
+
+        let packed_list = ciphertexts
+            .par_chunks(compression_key.lwe_per_glwe.0)
+            .map(|chunk| {
+                let lwe_size = chunk[0].ct.lwe_size();
+                let lwe_list = LweCiphertextList::from_container(
+                    chunk
+                        .iter()
+                        .flat_map(|ct| ct.ct.as_ref().iter().copied())
+                        .collect::<Vec<_>>(),
+                    lwe_size,
+                    self.ciphertext_modulus,
+                );
+
+                let mut glwe = GlweCiphertext::new(
+                    0,
+                    lwe_pksk.output_glwe_size(),
+                    lwe_pksk.output_key_polynomial_size(),
+                    self.ciphertext_modulus,
+                );
+
+                keyswitch_lwe_ciphertext_list_and_pack_in_glwe_ciphertext(
+                    lwe_pksk, &lwe_list, &mut glwe,
+                );
+
+                PackedGlweCiphertext::pack(&glwe, LweCiphertextCount(chunk.len()), log_modulus)
+            })
+            .collect();
+
+        CompressedCiphertextList {
+            packed_list,
+            degrees: ciphertexts.iter().map(|ct| ct.degree).collect(),
+            message_modulus: self.message_modulus,
+            carry_modulus: self.carry_modulus,
+            ciphertext_modulus: self.ciphertext_modulus,
+            pbs_order: self.pbs_order,
+            lwe_per_glwe: compression_key.lwe_per_glwe,
+        }
+    }
+
+    /// Decompress the ciphertext at `index` in a compressed ciphertext list, or return `None` if
+    /// `index` is out of bounds.
+    ///
+    /// The ciphertext is extracted from its GLWE ciphertext, then bootstrapped back to the
+    /// parameters of the server key: the output has a nominal noise level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the list does not match the parameters of the server key.
+    ///
+    /// See [`Self::compress_ciphertexts`] for an example.
+    pub fn decompress_ciphertext(
+        &self,
+        decompression_key: &DecompressionKey,
+        list: &CompressedCiphertextList,
+        index: usize,
+    ) -> Option<Ciphertext> {
+        assert!(
+            list.message_modulus == self.message_modulus
+                && list.carry_modulus == self.carry_modulus
+                && list.pbs_order == self.pbs_order,
+            "Compressed list parameters do not match the server key parameters"
+        );
+
+        if index >= list.len() {
+            return None;
+        }
+
+        let packed_glwe = &list.packed_list[index / list.lwe_per_glwe.0];
+        let glwe = packed_glwe.unpack(list.ciphertext_modulus);
+
+        let mut extracted_lwe = LweCiphertext::new(
+            0,
+            glwe.glwe_size()
+                .to_glwe_dimension()
+                .to_equivalent_lwe_dimension(glwe.polynomial_size())
+                .to_lwe_size(),
+            list.ciphertext_modulus,
+        );
+        extract_lwe_sample_from_glwe_ciphertext(
+            &glwe,
+            &mut extracted_lwe,
+            MonomialDegree(index % list.lwe_per_glwe.0),
+        );
+
+        let lookup_table = self.generate_lookup_table(|x| x);
+        let blind_rotate_key = &decompression_key.blind_rotate_key;
+
+        let mut output_lwe = LweCiphertext::new(
+            0,
+            blind_rotate_key.output_lwe_dimension().to_lwe_size(),
+            list.ciphertext_modulus,
+        );
+        programmable_bootstrap_lwe_ciphertext(
+            &extracted_lwe,
+            &mut output_lwe,
+            &lookup_table.acc,
+            blind_rotate_key,
+        );
+
+        let output_lwe = match self.pbs_order {
+            PBSOrder::KeyswitchBootstrap => output_lwe,
+            PBSOrder::BootstrapKeyswitch => {
+                let mut keyswitched_lwe = LweCiphertext::new(
+                    0,
+                    self.key_switching_key.output_lwe_size(),
+                    list.ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(
+                    &self.key_switching_key,
+                    &output_lwe,
+                    &mut keyswitched_lwe,
+                );
+                keyswitched_lwe
+            }
+        };
+
+        Some(Ciphertext::new(
+            output_lwe,
+            list.degrees[index],
+            NoiseLevel::NOMINAL,
+            list.message_modulus,
+            list.carry_modulus,
+            list.pbs_order,
+        ))
+    }
+}
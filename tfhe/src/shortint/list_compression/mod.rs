@@ -0,0 +1,26 @@
+//! This module defines the compression of lists of shortint ciphertexts.
+//!
+//! Ciphertexts computed by the server are LWE ciphertexts, whose mask is as large as the big LWE
+//! secret key. To store them efficiently, several of them can be packed in a single GLWE
+//! ciphertext using a packing keyswitch, and the coefficients of this GLWE ciphertext can then be
+//! modulus switched to a smaller modulus:
+//!
+//! - [`CompressionKey`] packs ciphertexts into GLWE ciphertexts, see
+//!   [`ServerKey::compress_ciphertexts`](crate::shortint::ServerKey::compress_ciphertexts);
+//! - [`DecompressionKey`] recovers the individual ciphertexts, by extracting them from the GLWE
+//!   ciphertexts and bootstrapping them back to the parameters of the server key, see
+//!   [`ServerKey::decompress_ciphertext`](crate::shortint::ServerKey::decompress_ciphertext).
+//!
+//! Both keys are generated from a [`CompressionPrivateKeys`], which is derived from a
+//! [`ClientKey`](crate::shortint::ClientKey) and must be kept secret.
+
+mod compression;
+mod private_key;
+mod server_keys;
+
+#[cfg(test)]
+mod test;
+
+pub use compression::CompressedCiphertextList;
+pub use private_key::CompressionPrivateKeys;
+pub use server_keys::{CompressionKey, DecompressionKey};
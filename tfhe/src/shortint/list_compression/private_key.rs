@@ -0,0 +1,58 @@
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_binary_glwe_secret_key, GlweSecretKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::CompressionParameters;
+use crate::shortint::ClientKey;
+
+use serde::{Deserialize, Serialize};
+
+/// The secret key under which ciphertexts are packed during compression.
+///
+/// Like the [`ClientKey`] it derives from, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionPrivateKeys {
+    pub(crate) post_packing_ks_key: GlweSecretKeyOwned<u64>,
+    pub params: CompressionParameters,
+}
+
+impl ClientKey {
+    /// Generate the secret key under which ciphertexts are packed during compression.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+    /// ```
+    pub fn new_compression_private_key(
+        &self,
+        params: CompressionParameters,
+    ) -> CompressionPrivateKeys {
+        assert!(
+            params.lwe_per_glwe.0 <= params.packing_ks_polynomial_size.0,
+            "Cannot pack more than {} ciphertexts per GLWE, got {}",
+            params.packing_ks_polynomial_size.0,
+            params.lwe_per_glwe.0
+        );
+
+        let post_packing_ks_key = ShortintEngine::with_thread_local_mut(|engine| {
+            allocate_and_generate_new_binary_glwe_secret_key(
+                params.packing_ks_glwe_dimension,
+                params.packing_ks_polynomial_size,
+                &mut engine.secret_generator,
+            )
+        });
+
+        CompressionPrivateKeys {
+            post_packing_ks_key,
+            params,
+        }
+    }
+}
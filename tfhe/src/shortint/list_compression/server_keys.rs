@@ -0,0 +1,119 @@
+use super::CompressionPrivateKeys;
+use crate::core_crypto::commons::parameters::{CiphertextModulusLog, LweCiphertextCount};
+use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKeyOwned;
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_lwe_packing_keyswitch_key,
+    par_allocate_and_generate_new_lwe_bootstrap_key,
+    par_convert_standard_lwe_bootstrap_key_to_fourier, FourierLweBootstrapKey,
+    LweBootstrapKeyOwned, LwePackingKeyswitchKeyOwned,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::{ClientKey, PBSOrder};
+
+use serde::{Deserialize, Serialize};
+
+/// A key packing shortint ciphertexts into GLWE ciphertexts.
+///
+/// The compression key is generated by the client and is meant to be published, like the server
+/// key.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompressionKey {
+    pub(crate) packing_key_switching_key: LwePackingKeyswitchKeyOwned<u64>,
+    pub lwe_per_glwe: LweCiphertextCount,
+    pub storage_log_modulus: Option<CiphertextModulusLog>,
+}
+
+/// A key recovering individual shortint ciphertexts from compressed ciphertext lists.
+///
+/// The decompression key is a bootstrapping key from the secret key under which ciphertexts are
+/// packed to the GLWE secret key of the client key. It is generated by the client and is meant to
+/// be published, like the server key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct DecompressionKey {
+    pub(crate) blind_rotate_key: FourierLweBootstrapKeyOwned,
+    pub lwe_per_glwe: LweCiphertextCount,
+}
+
+impl ClientKey {
+    /// Generate the keys used to compress shortint ciphertexts, and to decompress them.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+    /// let (compression_key, decompression_key) =
+    ///     cks.new_compression_decompression_keys(&private_compression_key);
+    /// ```
+    pub fn new_compression_decompression_keys(
+        &self,
+        private_compression_key: &CompressionPrivateKeys,
+    ) -> (CompressionKey, DecompressionKey) {
+        let params = &private_compression_key.params;
+        let ciphertext_modulus = self.parameters.ciphertext_modulus();
+
+        // Ciphertexts are packed from the key they are encrypted under
+        let input_lwe_secret_key = match self.parameters.encryption_key_choice().into() {
+            PBSOrder::KeyswitchBootstrap => &self.large_lwe_secret_key,
+            PBSOrder::BootstrapKeyswitch => &self.small_lwe_secret_key,
+        };
+
+        let (packing_key_switching_key, bootstrap_key) =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                let packing_key_switching_key = allocate_and_generate_new_lwe_packing_keyswitch_key(
+                    input_lwe_secret_key,
+                    &private_compression_key.post_packing_ks_key,
+                    params.packing_ks_base_log,
+                    params.packing_ks_level,
+                    params.packing_ks_key_noise_distribution,
+                    ciphertext_modulus,
+                    &mut engine.encryption_generator,
+                );
+
+                let bootstrap_key: LweBootstrapKeyOwned<u64> =
+                    par_allocate_and_generate_new_lwe_bootstrap_key(
+                        &private_compression_key
+                            .post_packing_ks_key
+                            .as_lwe_secret_key(),
+                        &self.glwe_secret_key,
+                        params.br_base_log,
+                        params.br_level,
+                        self.parameters.glwe_noise_distribution(),
+                        ciphertext_modulus,
+                        &mut engine.encryption_generator,
+                    );
+
+                (packing_key_switching_key, bootstrap_key)
+            });
+
+        // Creation of the bootstrapping key in the Fourier domain
+        let mut blind_rotate_key = FourierLweBootstrapKey::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+        );
+
+        // Conversion to fourier domain
+        par_convert_standard_lwe_bootstrap_key_to_fourier(&bootstrap_key, &mut blind_rotate_key);
+
+        (
+            CompressionKey {
+                packing_key_switching_key,
+                lwe_per_glwe: params.lwe_per_glwe,
+                storage_log_modulus: params.storage_log_modulus,
+            },
+            DecompressionKey {
+                blind_rotate_key,
+                lwe_per_glwe: params.lwe_per_glwe,
+            },
+        )
+    }
+}
@@ -0,0 +1,117 @@
+use super::CompressedCiphertextList;
+use crate::core_crypto::commons::parameters::LweCiphertextCount;
+use crate::shortint::ciphertext::NoiseLevel;
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::list_compression::COMP_PARAM_MESSAGE_2_CARRY_2;
+use crate::shortint::parameters::{
+    CompressionParameters, PARAM_MESSAGE_2_CARRY_2_KS_PBS, PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+};
+use crate::shortint::ClassicPBSParameters;
+use rand::Rng;
+
+fn test_list_compression(params: ClassicPBSParameters, comp_params: CompressionParameters) {
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_compression_key = cks.new_compression_private_key(comp_params);
+    let (compression_key, decompression_key) =
+        cks.new_compression_decompression_keys(&private_compression_key);
+
+    let mut rng = rand::thread_rng();
+    let full_modulus =
+        (cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0) as u64;
+
+    // Spans several GLWE ciphertexts, the last one being partially filled
+    let count = comp_params.lwe_per_glwe.0 + comp_params.lwe_per_glwe.0 / 2 + 1;
+    let clears: Vec<u64> = (0..count)
+        .map(|_| rng.gen::<u64>() % full_modulus)
+        .collect();
+    let mut cts: Vec<_> = clears
+        .iter()
+        .map(|clear| cks.unchecked_encrypt(*clear))
+        .collect();
+    cts[1] = sks.unchecked_create_trivial(clears[1]);
+
+    let compressed = sks.compress_ciphertexts(&compression_key, &cts);
+    assert_eq!(compressed.len(), count);
+    assert_eq!(compressed.packed_list.len(), 2);
+
+    let serialized = bincode::serialize(&compressed).unwrap();
+    let compressed: CompressedCiphertextList = bincode::deserialize(&serialized).unwrap();
+
+    for (index, (ct, clear)) in cts.iter().zip(clears.iter()).enumerate() {
+        let decompressed = sks
+            .decompress_ciphertext(&decompression_key, &compressed, index)
+            .unwrap();
+
+        assert_eq!(decompressed.degree, ct.degree);
+        assert_eq!(decompressed.noise_level(), NoiseLevel::NOMINAL);
+        assert_eq!(cks.decrypt_message_and_carry(&decompressed), *clear);
+    }
+
+    assert!(sks
+        .decompress_ciphertext(&decompression_key, &compressed, count)
+        .is_none());
+
+    // Decompressed ciphertexts can be used in computations
+    let ct_0 = sks
+        .decompress_ciphertext(&decompression_key, &compressed, 0)
+        .unwrap();
+    let ct_1 = sks.message_extract(&ct_0);
+    let ct_res = sks.add(&ct_0, &ct_1);
+    let modulus = cks.parameters.message_modulus().0 as u64;
+    assert_eq!(
+        cks.decrypt(&ct_res),
+        (clears[0] + clears[0] % modulus) % modulus
+    );
+}
+
+#[test]
+fn test_list_compression_ks_pbs() {
+    test_list_compression(PARAM_MESSAGE_2_CARRY_2_KS_PBS, COMP_PARAM_MESSAGE_2_CARRY_2);
+}
+
+#[test]
+fn test_list_compression_pbs_ks() {
+    test_list_compression(PARAM_MESSAGE_2_CARRY_2_PBS_KS, COMP_PARAM_MESSAGE_2_CARRY_2);
+}
+
+#[test]
+fn test_list_compression_without_modulus_switch() {
+    let comp_params = CompressionParameters {
+        lwe_per_glwe: LweCiphertextCount(16),
+        storage_log_modulus: None,
+        ..COMP_PARAM_MESSAGE_2_CARRY_2
+    };
+    test_list_compression(PARAM_MESSAGE_2_CARRY_2_KS_PBS, comp_params);
+}
+
+#[test]
+fn test_list_compression_empty() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+    let (compression_key, decompression_key) =
+        cks.new_compression_decompression_keys(&private_compression_key);
+
+    let compressed = sks.compress_ciphertexts(&compression_key, &[]);
+    assert!(compressed.is_empty());
+    assert!(sks
+        .decompress_ciphertext(&decompression_key, &compressed, 0)
+        .is_none());
+}
+
+#[test]
+#[should_panic(expected = "nominal noise level")]
+fn test_list_compression_noisy_input() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let private_compression_key = cks.new_compression_private_key(COMP_PARAM_MESSAGE_2_CARRY_2);
+    let (compression_key, _) = cks.new_compression_decompression_keys(&private_compression_key);
+
+    let ct = cks.encrypt(1);
+    let ct = sks.unchecked_add(&ct, &ct);
+    let _ = sks.compress_ciphertexts(&compression_key, &[ct]);
+}
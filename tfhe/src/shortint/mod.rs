@@ -53,6 +53,8 @@ pub mod engine;
 pub mod key_switching_key;
 #[cfg(any(test, doctest, feature = "internal-keycache"))]
 pub mod keycache;
+pub mod list_compression;
+pub mod noise_squashing;
 pub mod parameters;
 pub mod prelude;
 pub mod public_key;
@@ -60,7 +62,7 @@ pub mod server_key;
 pub mod wopbs;
 
 pub use ciphertext::{Ciphertext, CompressedCiphertext, PBSOrder};
-pub use client_key::ClientKey;
+pub use client_key::{ClientKey, ClientKeyShare, PartySecretKeyShares};
 pub use key_switching_key::KeySwitchingKey;
 pub use parameters::{
     CarryModulus, CiphertextModulus, ClassicPBSParameters, EncryptionKeyChoice, MessageModulus,
@@ -69,7 +71,7 @@ pub use parameters::{
 pub use public_key::{
     CompactPublicKey, CompressedCompactPublicKey, CompressedPublicKey, PublicKey,
 };
-pub use server_key::{CheckError, CompressedServerKey, ServerKey};
+pub use server_key::{CheckError, CompressedServerKey, SanitizationKey, ServerKey};
 
 /// Generate a couple of client and server keys.
 ///
@@ -101,8 +103,12 @@ where
             lwe_dimension: wopbs_params.lwe_dimension,
             glwe_dimension: wopbs_params.glwe_dimension,
             polynomial_size: wopbs_params.polynomial_size,
-            lwe_modular_std_dev: wopbs_params.lwe_modular_std_dev,
-            glwe_modular_std_dev: wopbs_params.glwe_modular_std_dev,
+            lwe_noise_distribution: crate::shortint::parameters::NoiseDistribution::Gaussian(
+                wopbs_params.lwe_modular_std_dev,
+            ),
+            glwe_noise_distribution: crate::shortint::parameters::NoiseDistribution::Gaussian(
+                wopbs_params.glwe_modular_std_dev,
+            ),
             pbs_base_log: wopbs_params.pbs_base_log,
             pbs_level: wopbs_params.pbs_level,
             ks_base_log: wopbs_params.ks_base_log,
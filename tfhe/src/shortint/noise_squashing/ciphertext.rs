@@ -0,0 +1,41 @@
+use crate::core_crypto::entities::LweCiphertextOwned;
+use crate::shortint::ciphertext::Degree;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+
+use serde::{Deserialize, Serialize};
+
+/// A shortint ciphertext whose noise was squashed, encrypted on 128 bits under the
+/// [`NoiseSquashingPrivateKey`](super::NoiseSquashingPrivateKey).
+///
+/// Squashed ciphertexts cannot be used in further computations, they are meant to be decrypted.
+///
+/// See [`ServerKey::squash_ciphertext_noise`](crate::shortint::ServerKey::squash_ciphertext_noise).
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SquashedNoiseCiphertext {
+    pub(crate) ct: LweCiphertextOwned<u128>,
+    pub degree: Degree,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+}
+
+impl SquashedNoiseCiphertext {
+    /// Return the scaling factor of the message and carry in the 128 bits ciphertext, the most
+    /// significant bit is kept as a padding bit.
+    pub(crate) fn delta(&self) -> u128 {
+        (1u128 << 127) / (self.message_modulus.0 * self.carry_modulus.0) as u128
+    }
+
+    /// Remove the encoding of a noisy plaintext of the squashed ciphertext and return its message
+    /// and carry.
+    pub(crate) fn decode_message_and_carry(&self, noisy_plaintext: u128) -> u64 {
+        let delta = self.delta();
+
+        //The bit before the message
+        let rounding_bit = delta >> 1;
+
+        //compute the rounding bit
+        let rounding = (noisy_plaintext & rounding_bit) << 1;
+
+        (noisy_plaintext.wrapping_add(rounding) / delta) as u64
+    }
+}
@@ -0,0 +1,26 @@
+//! This module defines the squashing of the noise of shortint ciphertexts.
+//!
+//! The noise of a shortint ciphertext takes a large part of its decoding margin, which leaves no
+//! room for the noise flooding required by threshold decryption. A bootstrap on 128 bits integers
+//! computes a 128 bits ciphertext of the same message whose noise is about $2^{-64}$ times smaller
+//! relatively to the modulus, the noise is said to be squashed:
+//!
+//! - [`NoiseSquashingKey`] is the 128 bits bootstrapping key, see
+//!   [`ServerKey::squash_ciphertext_noise`](crate::shortint::ServerKey::squash_ciphertext_noise);
+//! - [`SquashedNoiseCiphertext`] is the resulting 128 bits ciphertext, it can be decrypted with
+//!   the [`NoiseSquashingPrivateKey`] or with shares of it, see
+//!   [`ClientKeyShare`](crate::shortint::ClientKeyShare).
+//!
+//! The [`NoiseSquashingPrivateKey`] is derived from a [`ClientKey`](crate::shortint::ClientKey)
+//! and must be kept secret.
+
+mod ciphertext;
+mod private_key;
+mod server_key;
+
+#[cfg(test)]
+mod test;
+
+pub use ciphertext::SquashedNoiseCiphertext;
+pub use private_key::NoiseSquashingPrivateKey;
+pub use server_key::NoiseSquashingKey;
@@ -0,0 +1,104 @@
+use super::SquashedNoiseCiphertext;
+use crate::core_crypto::prelude::{
+    allocate_and_generate_new_binary_glwe_secret_key, decrypt_lwe_ciphertext, GlweSecretKeyOwned,
+    LweDimension,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::NoiseSquashingParameters;
+use crate::shortint::ClientKey;
+
+use serde::{Deserialize, Serialize};
+
+/// The 128 bits secret key under which squashed ciphertexts are encrypted.
+///
+/// Like the [`ClientKey`] it derives from, it must be kept secret.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingPrivateKey {
+    pub(crate) post_noise_squashing_secret_key: GlweSecretKeyOwned<u128>,
+    /// Dimension of the small LWE secret key of the client key, which the noise is squashed from
+    pub(crate) input_lwe_dimension: LweDimension,
+    pub params: NoiseSquashingParameters,
+}
+
+impl ClientKey {
+    /// Generate the secret key under which squashed ciphertexts are encrypted.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the message and carry moduli of `params` do not match the ones of the client key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let noise_squashing_private_key =
+    ///     cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// ```
+    pub fn new_noise_squashing_private_key(
+        &self,
+        params: NoiseSquashingParameters,
+    ) -> NoiseSquashingPrivateKey {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.new_noise_squashing_private_key(self, params)
+        })
+    }
+}
+
+impl ShortintEngine {
+    pub(crate) fn new_noise_squashing_private_key(
+        &mut self,
+        client_key: &ClientKey,
+        params: NoiseSquashingParameters,
+    ) -> NoiseSquashingPrivateKey {
+        assert!(
+            params.message_modulus == client_key.parameters.message_modulus()
+                && params.carry_modulus == client_key.parameters.carry_modulus(),
+            "Noise squashing parameters for {:?} and {:?} do not match the client key parameters \
+            with {:?} and {:?}",
+            params.message_modulus,
+            params.carry_modulus,
+            client_key.parameters.message_modulus(),
+            client_key.parameters.carry_modulus(),
+        );
+
+        let post_noise_squashing_secret_key = allocate_and_generate_new_binary_glwe_secret_key(
+            params.glwe_dimension,
+            params.polynomial_size,
+            &mut self.secret_generator,
+        );
+
+        NoiseSquashingPrivateKey {
+            post_noise_squashing_secret_key,
+            input_lwe_dimension: client_key.small_lwe_secret_key.lwe_dimension(),
+            params,
+        }
+    }
+}
+
+impl NoiseSquashingPrivateKey {
+    /// Decrypt a squashed ciphertext and return its message and carry.
+    ///
+    /// See [`ServerKey::squash_ciphertext_noise`](crate::shortint::ServerKey::squash_ciphertext_noise)
+    /// for usage.
+    pub fn decrypt_squashed_noise_ciphertext(&self, ct: &SquashedNoiseCiphertext) -> u64 {
+        assert!(
+            ct.message_modulus == self.params.message_modulus
+                && ct.carry_modulus == self.params.carry_modulus,
+            "Squashed ciphertext parameters do not match the noise squashing private key \
+            parameters"
+        );
+
+        let decrypted_encoded = decrypt_lwe_ciphertext(
+            &self.post_noise_squashing_secret_key.as_lwe_secret_key(),
+            &ct.ct,
+        );
+
+        ct.decode_message_and_carry(decrypted_encoded.0)
+    }
+}
@@ -0,0 +1,247 @@
+use super::{NoiseSquashingPrivateKey, SquashedNoiseCiphertext};
+use crate::core_crypto::commons::parameters::CiphertextModulus as CoreCiphertextModulus;
+use crate::core_crypto::prelude::{
+    convert_standard_lwe_bootstrap_key_to_fourier_128, keyswitch_lwe_ciphertext,
+    par_allocate_and_generate_new_lwe_bootstrap_key, programmable_bootstrap_f128_lwe_ciphertext,
+    Fourier128LweBootstrapKey, Fourier128LweBootstrapKeyOwned, GlweCiphertextOwned,
+    LweBootstrapKeyOwned, LweCiphertext, LweCiphertextOwned, LweSecretKey,
+};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::parameters::{CarryModulus, MessageModulus};
+use crate::shortint::{Ciphertext, ClientKey, PBSOrder, ServerKey};
+
+use serde::{Deserialize, Serialize};
+
+/// A 128 bits bootstrapping key squashing the noise of shortint ciphertexts.
+///
+/// The noise squashing key is a bootstrapping key from the small LWE secret key of the client key
+/// to the [`NoiseSquashingPrivateKey`]. It is generated by the client and is meant to be published,
+/// like the server key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingKey {
+    pub(crate) bootstrapping_key: Fourier128LweBootstrapKeyOwned,
+    pub message_modulus: MessageModulus,
+    pub carry_modulus: CarryModulus,
+    pub output_ciphertext_modulus: CoreCiphertextModulus<u128>,
+}
+
+impl ClientKey {
+    /// Generate the key squashing the noise of shortint ciphertexts.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let noise_squashing_private_key =
+    ///     cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks.new_noise_squashing_key(&noise_squashing_private_key);
+    /// ```
+    pub fn new_noise_squashing_key(
+        &self,
+        noise_squashing_private_key: &NoiseSquashingPrivateKey,
+    ) -> NoiseSquashingKey {
+        let params = &noise_squashing_private_key.params;
+
+        assert!(
+            self.parameters.ciphertext_modulus().is_native_modulus(),
+            "Noise squashing only supports the native ciphertext modulus"
+        );
+
+        // The bootstrap runs on 128 bits integers, the coefficients of the binary input key are
+        // the same on 128 bits
+        let input_lwe_secret_key = LweSecretKey::from_container(
+            self.small_lwe_secret_key
+                .as_ref()
+                .iter()
+                .map(|&coefficient| coefficient as u128)
+                .collect::<Vec<_>>(),
+        );
+
+        let bootstrap_key: LweBootstrapKeyOwned<u128> =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                par_allocate_and_generate_new_lwe_bootstrap_key(
+                    &input_lwe_secret_key,
+                    &noise_squashing_private_key.post_noise_squashing_secret_key,
+                    params.decomp_base_log,
+                    params.decomp_level_count,
+                    params.glwe_noise_distribution,
+                    params.ciphertext_modulus,
+                    &mut engine.encryption_generator,
+                )
+            });
+
+        // Creation of the bootstrapping key in the Fourier domain
+        let mut bootstrapping_key = Fourier128LweBootstrapKey::new(
+            bootstrap_key.input_lwe_dimension(),
+            bootstrap_key.glwe_size(),
+            bootstrap_key.polynomial_size(),
+            bootstrap_key.decomposition_base_log(),
+            bootstrap_key.decomposition_level_count(),
+        );
+
+        // Conversion to fourier domain
+        convert_standard_lwe_bootstrap_key_to_fourier_128(&bootstrap_key, &mut bootstrapping_key);
+
+        NoiseSquashingKey {
+            bootstrapping_key,
+            message_modulus: params.message_modulus,
+            carry_modulus: params.carry_modulus,
+            output_ciphertext_modulus: params.ciphertext_modulus,
+        }
+    }
+}
+
+impl NoiseSquashingKey {
+    /// Return the accumulator of the identity function over the message and carry, encoded on 128
+    /// bits with a padding bit.
+    fn identity_accumulator(&self) -> GlweCiphertextOwned<u128> {
+        let polynomial_size = self.bootstrapping_key.polynomial_size();
+
+        let mut accumulator = GlweCiphertextOwned::new(
+            0u128,
+            self.bootstrapping_key.glwe_size(),
+            polynomial_size,
+            self.output_ciphertext_modulus,
+        );
+
+        // Modulus of the msg contained in the msg bits and operations buffer
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+
+        // N/(p/2) = size of each block
+        let box_size = polynomial_size.0 / modulus_sup;
+
+        // Value of the shift we multiply our messages by
+        let delta = (1u128 << 127) / modulus_sup as u128;
+
+        let mut body = accumulator.get_mut_body();
+        let accumulator_u128 = body.as_mut();
+
+        for i in 0..modulus_sup {
+            let index = i * box_size;
+            accumulator_u128[index..index + box_size].fill(i as u128 * delta);
+        }
+
+        let half_box_size = box_size / 2;
+
+        // Negate the first half_box_size coefficients
+        for a_i in accumulator_u128[0..half_box_size].iter_mut() {
+            *a_i = (*a_i).wrapping_neg();
+        }
+
+        // Rotate the accumulator
+        accumulator_u128.rotate_left(half_box_size);
+
+        accumulator
+    }
+}
+
+impl ServerKey {
+    /// Squash the noise of a ciphertext, by bootstrapping it to a 128 bits ciphertext encrypting
+    /// the same message and carry under the [`NoiseSquashingPrivateKey`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext or the noise squashing key do not match the parameters of the
+    /// server key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let noise_squashing_private_key =
+    ///     cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    /// let noise_squashing_key = cks.new_noise_squashing_key(&noise_squashing_private_key);
+    ///
+    /// let ct_1 = cks.encrypt(1);
+    /// let ct_2 = cks.encrypt(2);
+    /// let ct_res = sks.mul_lsb(&ct_1, &ct_2);
+    ///
+    /// let squashed = sks.squash_ciphertext_noise(&noise_squashing_key, &ct_res);
+    /// assert_eq!(
+    ///     noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed),
+    ///     2
+    /// );
+    /// ```
+    pub fn squash_ciphertext_noise(
+        &self,
+        noise_squashing_key: &NoiseSquashingKey,
+        ct: &Ciphertext,
+    ) -> SquashedNoiseCiphertext {
+        assert!(
+            self.ciphertext_modulus.is_native_modulus(),
+            "Noise squashing only supports the native ciphertext modulus"
+        );
+        assert!(
+            ct.message_modulus == self.message_modulus
+                && ct.carry_modulus == self.carry_modulus
+                && ct.pbs_order == self.pbs_order,
+            "Ciphertext parameters do not match the server key parameters"
+        );
+        assert!(
+            noise_squashing_key.message_modulus == self.message_modulus
+                && noise_squashing_key.carry_modulus == self.carry_modulus
+                && noise_squashing_key.bootstrapping_key.input_lwe_dimension()
+                    == self.key_switching_key.output_key_lwe_dimension(),
+            "Noise squashing key parameters do not match the server key parameters"
+        );
+
+        // The noise squashing key bootstraps from the small LWE secret key
+        let input_lwe = match ct.pbs_order {
+            PBSOrder::KeyswitchBootstrap => {
+                let mut after_ks = LweCiphertext::new(
+                    0u64,
+                    self.key_switching_key.output_lwe_size(),
+                    self.ciphertext_modulus,
+                );
+                keyswitch_lwe_ciphertext(&self.key_switching_key, &ct.ct, &mut after_ks);
+                after_ks
+            }
+            PBSOrder::BootstrapKeyswitch => ct.ct.clone(),
+        };
+        // The modulus switch of the bootstrap only keeps the most significant bits, the 64 bits
+        // ciphertext is put in the most significant bits of a 128 bits ciphertext
+        let input_lwe = LweCiphertext::from_container(
+            input_lwe
+                .as_ref()
+                .iter()
+                .map(|&coefficient| (coefficient as u128) << 64)
+                .collect::<Vec<_>>(),
+            noise_squashing_key.output_ciphertext_modulus,
+        );
+
+        let mut output_lwe = LweCiphertextOwned::new(
+            0u128,
+            noise_squashing_key
+                .bootstrapping_key
+                .output_lwe_dimension()
+                .to_lwe_size(),
+            noise_squashing_key.output_ciphertext_modulus,
+        );
+
+        programmable_bootstrap_f128_lwe_ciphertext(
+            &input_lwe,
+            &mut output_lwe,
+            &noise_squashing_key.identity_accumulator(),
+            &noise_squashing_key.bootstrapping_key,
+        );
+
+        SquashedNoiseCiphertext {
+            ct: output_lwe,
+            degree: ct.degree,
+            message_modulus: ct.message_modulus,
+            carry_modulus: ct.carry_modulus,
+        }
+    }
+}
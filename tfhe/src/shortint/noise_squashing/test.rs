@@ -0,0 +1,80 @@
+use super::SquashedNoiseCiphertext;
+use crate::core_crypto::prelude::decrypt_lwe_ciphertext;
+use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+use crate::shortint::parameters::{
+    NoiseSquashingParameters, PARAM_MESSAGE_1_CARRY_1_KS_PBS, PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+};
+use crate::shortint::ClassicPBSParameters;
+use rand::Rng;
+
+fn test_noise_squashing(params: ClassicPBSParameters, squashing_params: NoiseSquashingParameters) {
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let noise_squashing_private_key = cks.new_noise_squashing_private_key(squashing_params);
+    let noise_squashing_key = cks.new_noise_squashing_key(&noise_squashing_private_key);
+
+    let mut rng = rand::thread_rng();
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..4 {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt(clear_0);
+        let ct_1 = cks.encrypt(clear_1);
+        // The carry is kept by the noise squashing
+        let ct_res = sks.unchecked_add(&ct_0, &ct_1);
+
+        let squashed = sks.squash_ciphertext_noise(&noise_squashing_key, &ct_res);
+        assert_eq!(squashed.degree, ct_res.degree);
+
+        let serialized = bincode::serialize(&squashed).unwrap();
+        let squashed: SquashedNoiseCiphertext = bincode::deserialize(&serialized).unwrap();
+
+        assert_eq!(
+            noise_squashing_private_key.decrypt_squashed_noise_ciphertext(&squashed),
+            clear_0 + clear_1
+        );
+
+        // The noise of the squashed ciphertext is far below the one of a 64 bits ciphertext
+        let plaintext = decrypt_lwe_ciphertext(
+            &noise_squashing_private_key
+                .post_noise_squashing_secret_key
+                .as_lwe_secret_key(),
+            &squashed.ct,
+        );
+        let noise = plaintext
+            .0
+            .wrapping_sub(u128::from(clear_0 + clear_1) * squashed.delta());
+        let noise = noise.min(noise.wrapping_neg());
+        assert!(noise < 1u128 << 80, "Unexpected squashed noise {noise}");
+    }
+}
+
+#[test]
+fn test_noise_squashing_ks_pbs() {
+    test_noise_squashing(
+        PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+    );
+}
+
+#[test]
+fn test_noise_squashing_pbs_ks() {
+    test_noise_squashing(
+        PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+        NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+    );
+}
+
+#[test]
+#[should_panic(expected = "do not match the client key parameters")]
+fn test_noise_squashing_mismatched_parameters() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_1_CARRY_1_KS_PBS);
+    let cks = keys.client_key();
+
+    let _ = cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+}
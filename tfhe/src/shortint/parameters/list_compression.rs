@@ -0,0 +1,83 @@
+use crate::core_crypto::commons::parameters::{CiphertextModulusLog, LweCiphertextCount};
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, GlweDimension, NoiseDistribution,
+    PolynomialSize, TUniform,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters for the compression of shortint ciphertexts into packed GLWE
+/// ciphertexts, and for their decompression.
+///
+/// See [`crate::shortint::list_compression`] for details.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct CompressionParameters {
+    /// Base log of the bootstrapping key used to decompress ciphertexts.
+    pub br_base_log: DecompositionBaseLog,
+    /// Level count of the bootstrapping key used to decompress ciphertexts.
+    pub br_level: DecompositionLevelCount,
+    /// Base log of the packing keyswitch key used to compress ciphertexts.
+    pub packing_ks_base_log: DecompositionBaseLog,
+    /// Level count of the packing keyswitch key used to compress ciphertexts.
+    pub packing_ks_level: DecompositionLevelCount,
+    /// GLWE dimension of the secret key under which ciphertexts are packed.
+    pub packing_ks_glwe_dimension: GlweDimension,
+    /// Polynomial size of the secret key under which ciphertexts are packed.
+    pub packing_ks_polynomial_size: PolynomialSize,
+    /// Noise distribution of the packing keyswitch key.
+    pub packing_ks_key_noise_distribution: NoiseDistribution,
+    /// Number of ciphertexts packed in a single GLWE ciphertext, at most the polynomial size.
+    pub lwe_per_glwe: LweCiphertextCount,
+    /// Number of bits each coefficient of the packed GLWE ciphertexts is stored on, or `None` to
+    /// store them without modulus switching.
+    pub storage_log_modulus: Option<CiphertextModulusLog>,
+}
+
+impl CompressionParameters {
+    /// Constructs a new set of parameters for shortint list compression.
+    ///
+    /// # Warning
+    ///
+    /// Failing to fix the parameters properly would yield incorrect and insecure computation.
+    /// Unless you are a cryptographer who really knows the impact of each of those parameters, you
+    /// __must__ stick with the provided parameters (if any), which both offer correct results with
+    /// 128 bits of security.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        br_base_log: DecompositionBaseLog,
+        br_level: DecompositionLevelCount,
+        packing_ks_base_log: DecompositionBaseLog,
+        packing_ks_level: DecompositionLevelCount,
+        packing_ks_glwe_dimension: GlweDimension,
+        packing_ks_polynomial_size: PolynomialSize,
+        packing_ks_key_noise_distribution: NoiseDistribution,
+        lwe_per_glwe: LweCiphertextCount,
+        storage_log_modulus: Option<CiphertextModulusLog>,
+    ) -> Self {
+        Self {
+            br_base_log,
+            br_level,
+            packing_ks_base_log,
+            packing_ks_level,
+            packing_ks_glwe_dimension,
+            packing_ks_polynomial_size,
+            packing_ks_key_noise_distribution,
+            lwe_per_glwe,
+            storage_log_modulus,
+        }
+    }
+}
+
+/// Compression parameters for ciphertexts encrypted with
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS).
+pub const COMP_PARAM_MESSAGE_2_CARRY_2: CompressionParameters = CompressionParameters {
+    br_base_log: DecompositionBaseLog(23),
+    br_level: DecompositionLevelCount(1),
+    packing_ks_base_log: DecompositionBaseLog(4),
+    packing_ks_level: DecompositionLevelCount(4),
+    packing_ks_glwe_dimension: GlweDimension(4),
+    packing_ks_polynomial_size: PolynomialSize(256),
+    packing_ks_key_noise_distribution: NoiseDistribution::TUniform(TUniform::new(42)),
+    lwe_per_glwe: LweCiphertextCount(256),
+    storage_log_modulus: Some(CiphertextModulusLog(12)),
+};
@@ -6,26 +6,40 @@
 //! sets.
 
 use crate::conformance::ListSizeConstraint;
-pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
+pub use crate::core_crypto::commons::dispersion::{
+    DispersionParameter, NoiseDistribution, StandardDev,
+};
+pub use crate::core_crypto::commons::math::random::TUniform;
 pub use crate::core_crypto::commons::parameters::{
     CiphertextModulus as CoreCiphertextModulus, DecompositionBaseLog, DecompositionLevelCount,
-    GlweDimension, LweBskGroupingFactor, LweDimension, PolynomialSize,
+    GlweDimension, LweBskGroupingFactor, LweDimension, PolynomialSize, ShareCount, ShareIndex,
+    ShareThreshold, StatisticalSecurityParameter,
 };
+use crate::core_crypto::commons::security_estimator::validate_lwe_and_glwe_security;
+pub use crate::core_crypto::commons::security_estimator::SecurityError;
 use crate::core_crypto::prelude::{LweCiphertextListParameters, LweCiphertextParameters};
 use serde::{Deserialize, Serialize};
 
 pub mod key_switching;
+pub mod list_compression;
 pub mod multi_bit;
+pub mod noise_squashing;
+pub mod optimizer;
 pub mod parameters_compact_pk;
 pub mod parameters_wopbs;
 pub mod parameters_wopbs_message_carry;
 pub(crate) mod parameters_wopbs_prime_moduli;
+pub mod sanitization;
 
 pub use crate::core_crypto::commons::parameters::EncryptionKeyChoice;
 pub use key_switching::ShortintKeySwitchingParameters;
+pub use list_compression::CompressionParameters;
 pub use multi_bit::*;
+pub use noise_squashing::NoiseSquashingParameters;
+pub use optimizer::{optimize, CostModel, OptimizationError};
 pub use parameters_compact_pk::*;
 pub use parameters_wopbs::WopbsParameters;
+pub use sanitization::SanitizationParameters;
 
 use super::ciphertext::{Degree, NoiseLevel};
 use super::PBSOrder;
@@ -63,8 +77,8 @@ pub struct ClassicPBSParameters {
     pub lwe_dimension: LweDimension,
     pub glwe_dimension: GlweDimension,
     pub polynomial_size: PolynomialSize,
-    pub lwe_modular_std_dev: StandardDev,
-    pub glwe_modular_std_dev: StandardDev,
+    pub lwe_noise_distribution: NoiseDistribution,
+    pub glwe_noise_distribution: NoiseDistribution,
     pub pbs_base_log: DecompositionBaseLog,
     pub pbs_level: DecompositionLevelCount,
     pub ks_base_log: DecompositionBaseLog,
@@ -88,8 +102,8 @@ impl ClassicPBSParameters {
         lwe_dimension: LweDimension,
         glwe_dimension: GlweDimension,
         polynomial_size: PolynomialSize,
-        lwe_modular_std_dev: StandardDev,
-        glwe_modular_std_dev: StandardDev,
+        lwe_noise_distribution: NoiseDistribution,
+        glwe_noise_distribution: NoiseDistribution,
         pbs_base_log: DecompositionBaseLog,
         pbs_level: DecompositionLevelCount,
         ks_base_log: DecompositionBaseLog,
@@ -103,8 +117,8 @@ impl ClassicPBSParameters {
             lwe_dimension,
             glwe_dimension,
             polynomial_size,
-            lwe_modular_std_dev,
-            glwe_modular_std_dev,
+            lwe_noise_distribution,
+            glwe_noise_distribution,
             pbs_base_log,
             pbs_level,
             ks_base_log,
@@ -116,6 +130,32 @@ impl ClassicPBSParameters {
         }
     }
 
+    /// Check that the estimated security of the LWE and GLWE secret keys generated with these
+    /// parameters is at least `min_bits`.
+    ///
+    /// See [`crate::core_crypto::commons::security_estimator`] for the attacks considered.
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::{LweDimension, PARAM_MESSAGE_2_CARRY_2_KS_PBS};
+    ///
+    /// assert!(PARAM_MESSAGE_2_CARRY_2_KS_PBS.validate_security(128).is_ok());
+    ///
+    /// let mut params = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// params.lwe_dimension = LweDimension(500);
+    /// assert!(params.validate_security(128).is_err());
+    /// ```
+    pub fn validate_security(&self, min_bits: u32) -> Result<(), SecurityError> {
+        validate_lwe_and_glwe_security(
+            self.lwe_dimension,
+            self.lwe_noise_distribution,
+            self.glwe_dimension,
+            self.polynomial_size,
+            self.glwe_noise_distribution,
+            self.ciphertext_modulus,
+            min_bits,
+        )
+    }
+
     pub fn to_shortint_conformance_param(&self) -> CiphertextConformanceParams {
         let (pbs_order, expected_dim) = match self.encryption_key_choice {
             EncryptionKeyChoice::Big => (
@@ -230,16 +270,16 @@ impl PBSParameters {
             Self::MultiBitPBS(params) => params.polynomial_size,
         }
     }
-    pub const fn lwe_modular_std_dev(&self) -> StandardDev {
+    pub const fn lwe_noise_distribution(&self) -> NoiseDistribution {
         match self {
-            Self::PBS(params) => params.lwe_modular_std_dev,
-            Self::MultiBitPBS(params) => params.lwe_modular_std_dev,
+            Self::PBS(params) => params.lwe_noise_distribution,
+            Self::MultiBitPBS(params) => NoiseDistribution::Gaussian(params.lwe_modular_std_dev),
         }
     }
-    pub const fn glwe_modular_std_dev(&self) -> StandardDev {
+    pub const fn glwe_noise_distribution(&self) -> NoiseDistribution {
         match self {
-            Self::PBS(params) => params.glwe_modular_std_dev,
-            Self::MultiBitPBS(params) => params.glwe_modular_std_dev,
+            Self::PBS(params) => params.glwe_noise_distribution,
+            Self::MultiBitPBS(params) => NoiseDistribution::Gaussian(params.glwe_modular_std_dev),
         }
     }
     pub const fn pbs_base_log(&self) -> DecompositionBaseLog {
@@ -307,6 +347,13 @@ impl PBSParameters {
         matches!(self, Self::MultiBitPBS(_))
     }
 
+    pub fn validate_security(&self, min_bits: u32) -> Result<(), SecurityError> {
+        match self {
+            Self::PBS(params) => params.validate_security(min_bits),
+            Self::MultiBitPBS(params) => params.validate_security(min_bits),
+        }
+    }
+
     pub fn to_shortint_conformance_param(&self) -> CiphertextConformanceParams {
         match self {
             Self::PBS(param) => param.to_shortint_conformance_param(),
@@ -417,19 +464,23 @@ impl ShortintParameterSet {
         }
     }
 
-    pub const fn lwe_modular_std_dev(&self) -> StandardDev {
+    pub const fn lwe_noise_distribution(&self) -> NoiseDistribution {
         match self.inner {
-            ShortintParameterSetInner::PBSOnly(params) => params.lwe_modular_std_dev(),
-            ShortintParameterSetInner::WopbsOnly(params) => params.lwe_modular_std_dev,
-            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.lwe_modular_std_dev(),
+            ShortintParameterSetInner::PBSOnly(params) => params.lwe_noise_distribution(),
+            ShortintParameterSetInner::WopbsOnly(params) => {
+                NoiseDistribution::Gaussian(params.lwe_modular_std_dev)
+            }
+            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.lwe_noise_distribution(),
         }
     }
 
-    pub const fn glwe_modular_std_dev(&self) -> StandardDev {
+    pub const fn glwe_noise_distribution(&self) -> NoiseDistribution {
         match self.inner {
-            ShortintParameterSetInner::PBSOnly(params) => params.glwe_modular_std_dev(),
-            ShortintParameterSetInner::WopbsOnly(params) => params.glwe_modular_std_dev,
-            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.glwe_modular_std_dev(),
+            ShortintParameterSetInner::PBSOnly(params) => params.glwe_noise_distribution(),
+            ShortintParameterSetInner::WopbsOnly(params) => {
+                NoiseDistribution::Gaussian(params.glwe_modular_std_dev)
+            }
+            ShortintParameterSetInner::PBSAndWopbs(params, _) => params.glwe_noise_distribution(),
         }
     }
 
@@ -599,8 +650,8 @@ pub const PARAM_MESSAGE_1_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(678),
     glwe_dimension: GlweDimension(5),
     polynomial_size: PolynomialSize(256),
-    lwe_modular_std_dev: StandardDev(0.000022810107419132102),
-    glwe_modular_std_dev: StandardDev(0.00000000037411618952047216),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000022810107419132102)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000000037411618952047216)),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(2),
@@ -614,8 +665,10 @@ pub const PARAM_MESSAGE_1_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(684),
     glwe_dimension: GlweDimension(3),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.00002043784477291318),
-    glwe_modular_std_dev: StandardDev(0.0000000000034525330484572114),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00002043784477291318)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000034525330484572114,
+    )),
     pbs_base_log: DecompositionBaseLog(18),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -629,8 +682,8 @@ pub const PARAM_MESSAGE_2_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(656),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.000034119201269311964),
-    glwe_modular_std_dev: StandardDev(0.00000004053919869756513),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000034119201269311964)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000004053919869756513)),
     pbs_base_log: DecompositionBaseLog(8),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -644,8 +697,10 @@ pub const PARAM_MESSAGE_1_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -659,8 +714,10 @@ pub const PARAM_MESSAGE_2_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -674,8 +731,10 @@ pub const PARAM_MESSAGE_3_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -689,8 +748,10 @@ pub const PARAM_MESSAGE_1_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(745),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.000006692125069956277),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000006692125069956277)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -704,8 +765,10 @@ pub const PARAM_MESSAGE_2_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -719,8 +782,10 @@ pub const PARAM_MESSAGE_3_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -734,8 +799,10 @@ pub const PARAM_MESSAGE_4_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(742),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.000007069849454709433),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000007069849454709433)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -749,8 +816,10 @@ pub const PARAM_MESSAGE_1_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(807),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000021515145918907506),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000021515145918907506)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -764,8 +833,10 @@ pub const PARAM_MESSAGE_2_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(856),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000008775214009854235),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000008775214009854235)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(6),
@@ -779,8 +850,10 @@ pub const PARAM_MESSAGE_3_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(812),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000019633637461248447),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000019633637461248447)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -794,8 +867,10 @@ pub const PARAM_MESSAGE_4_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(808),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000021124945159091033),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000021124945159091033)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -809,8 +884,10 @@ pub const PARAM_MESSAGE_5_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(807),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(0.0000021515145918907506),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000021515145918907506)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -824,8 +901,10 @@ pub const PARAM_MESSAGE_1_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(864),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000000757998020150446)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -839,8 +918,10 @@ pub const PARAM_MESSAGE_2_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(864),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000000757998020150446)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -854,8 +935,10 @@ pub const PARAM_MESSAGE_3_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(864),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000000757998020150446)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -869,8 +952,10 @@ pub const PARAM_MESSAGE_4_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(864),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.000000757998020150446),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000000757998020150446)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -884,8 +969,10 @@ pub const PARAM_MESSAGE_5_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(875),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.0000006197725091905067),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000006197725091905067)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(6),
@@ -899,8 +986,10 @@ pub const PARAM_MESSAGE_6_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(915),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.00000029804653749339636),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000029804653749339636)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -914,8 +1003,10 @@ pub const PARAM_MESSAGE_1_CARRY_6_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(6),
@@ -929,8 +1020,10 @@ pub const PARAM_MESSAGE_2_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(934),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000021050318566634375),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000021050318566634375)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -944,8 +1037,10 @@ pub const PARAM_MESSAGE_3_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -959,8 +1054,10 @@ pub const PARAM_MESSAGE_4_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -974,8 +1071,10 @@ pub const PARAM_MESSAGE_5_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -989,8 +1088,10 @@ pub const PARAM_MESSAGE_6_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -1004,8 +1105,10 @@ pub const PARAM_MESSAGE_7_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(930),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(0.00000022649232786295453),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000022649232786295453)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -1019,8 +1122,10 @@ pub const PARAM_MESSAGE_1_CARRY_7_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(1004),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.00000005845871624688967),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000005845871624688967)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -1034,8 +1139,10 @@ pub const PARAM_MESSAGE_2_CARRY_6_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(987),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.00000007979529246348835),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000007979529246348835)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -1049,8 +1156,10 @@ pub const PARAM_MESSAGE_3_CARRY_5_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(985),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.00000008277032914509569),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000008277032914509569)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -1064,8 +1173,10 @@ pub const PARAM_MESSAGE_4_CARRY_4_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(996),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.00000006767666038309478),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000006767666038309478)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -1079,8 +1190,10 @@ pub const PARAM_MESSAGE_5_CARRY_3_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(1020),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.000000043618425315728666),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000000043618425315728666)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -1094,8 +1207,10 @@ pub const PARAM_MESSAGE_6_CARRY_2_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(1018),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.000000045244666805696514),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.000000045244666805696514)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -1109,8 +1224,10 @@ pub const PARAM_MESSAGE_7_CARRY_1_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(1017),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.0000000460803851108693),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000000460803851108693)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -1124,8 +1241,10 @@ pub const PARAM_MESSAGE_8_CARRY_0_KS_PBS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(1017),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.0000000460803851108693),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000000460803851108693)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -1140,8 +1259,10 @@ pub const PARAM_MESSAGE_1_CARRY_1_PBS_KS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(783),
     glwe_dimension: GlweDimension(3),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(0.0000033382067621812462),
-    glwe_modular_std_dev: StandardDev(0.0000000000034525330484572114),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000033382067621812462)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000034525330484572114,
+    )),
     pbs_base_log: DecompositionBaseLog(18),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -1156,8 +1277,10 @@ pub const PARAM_MESSAGE_2_CARRY_2_PBS_KS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(870),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(0.0000006791658447437413),
-    glwe_modular_std_dev: StandardDev(0.00000000000000029403601535432533),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000006791658447437413)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.00000000000000029403601535432533,
+    )),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -1172,8 +1295,10 @@ pub const PARAM_MESSAGE_3_CARRY_3_PBS_KS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(1025),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(0.00000003980397588319241),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.00000003980397588319241)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -1188,8 +1313,10 @@ pub const PARAM_MESSAGE_4_CARRY_4_PBS_KS: ClassicPBSParameters = ClassicPBSParam
     lwe_dimension: LweDimension(1214),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(0.0000000012520482863081104),
-    glwe_modular_std_dev: StandardDev(0.0000000000000000002168404344971009),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(0.0000000012520482863081104)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(
+        0.0000000000000000002168404344971009,
+    )),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
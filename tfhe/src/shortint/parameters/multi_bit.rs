@@ -5,6 +5,9 @@ pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardD
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
+use crate::core_crypto::commons::security_estimator::{
+    validate_lwe_and_glwe_security, SecurityError,
+};
 use crate::core_crypto::prelude::LweCiphertextParameters;
 use crate::shortint::ciphertext::{Degree, NoiseLevel};
 use crate::shortint::parameters::{
@@ -50,6 +53,23 @@ impl MultiBitPBSParameters {
         }
     }
 
+    /// Check that the estimated security of the LWE and GLWE secret keys generated with these
+    /// parameters is at least `min_bits`.
+    ///
+    /// The multi-bit bootstrapping key does not change the secret key distribution, the check is
+    /// the same as for [`super::ClassicPBSParameters::validate_security`].
+    pub fn validate_security(&self, min_bits: u32) -> Result<(), SecurityError> {
+        validate_lwe_and_glwe_security(
+            self.lwe_dimension,
+            self.lwe_modular_std_dev,
+            self.glwe_dimension,
+            self.polynomial_size,
+            self.glwe_modular_std_dev,
+            self.ciphertext_modulus,
+            min_bits,
+        )
+    }
+
     pub fn to_shortint_conformance_param(&self) -> CiphertextConformanceParams {
         let (pbs_order, expected_dim) = match self.encryption_key_choice {
             EncryptionKeyChoice::Big => (
@@ -0,0 +1,49 @@
+use crate::core_crypto::commons::parameters::CiphertextModulus as CoreCiphertextModulus;
+use crate::shortint::parameters::{
+    CarryModulus, DecompositionBaseLog, DecompositionLevelCount, GlweDimension, MessageModulus,
+    NoiseDistribution, PolynomialSize, TUniform,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters for the squashing of the noise of shortint ciphertexts into
+/// 128 bits ciphertexts.
+///
+/// See [`crate::shortint::noise_squashing`] for details.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoiseSquashingParameters {
+    /// GLWE dimension of the secret key under which the squashed ciphertexts are encrypted.
+    pub glwe_dimension: GlweDimension,
+    /// Polynomial size of the secret key under which the squashed ciphertexts are encrypted.
+    pub polynomial_size: PolynomialSize,
+    /// Noise distribution of the 128 bits bootstrapping key.
+    pub glwe_noise_distribution: NoiseDistribution,
+    /// Base log of the 128 bits bootstrapping key.
+    pub decomp_base_log: DecompositionBaseLog,
+    /// Level count of the 128 bits bootstrapping key.
+    pub decomp_level_count: DecompositionLevelCount,
+    /// Message modulus of the ciphertexts whose noise is squashed.
+    pub message_modulus: MessageModulus,
+    /// Carry modulus of the ciphertexts whose noise is squashed.
+    pub carry_modulus: CarryModulus,
+    /// Modulus of the squashed ciphertexts.
+    pub ciphertext_modulus: CoreCiphertextModulus<u128>,
+}
+
+/// Noise squashing parameters for 2 bits of message and 2 bits of carry.
+///
+/// They apply to ciphertexts encrypted with
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS)
+/// or
+/// [`PARAM_MESSAGE_2_CARRY_2_PBS_KS`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS).
+pub const NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2: NoiseSquashingParameters =
+    NoiseSquashingParameters {
+        glwe_dimension: GlweDimension(2),
+        polynomial_size: PolynomialSize(2048),
+        glwe_noise_distribution: NoiseDistribution::TUniform(TUniform::new(30)),
+        decomp_base_log: DecompositionBaseLog(24),
+        decomp_level_count: DecompositionLevelCount(3),
+        message_modulus: MessageModulus(4),
+        carry_modulus: CarryModulus(4),
+        ciphertext_modulus: CoreCiphertextModulus::<u128>::new_native(),
+    };
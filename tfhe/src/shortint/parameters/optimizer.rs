@@ -0,0 +1,580 @@
+//! Search of parameter sets for message and carry moduli not covered by the provided parameters.
+//!
+//! The [`optimize`] function explores the parameter space of the keyswitch followed by
+//! bootstrapping pipeline ([`EncryptionKeyChoice::Big`]) on the native 64 bits modulus and returns
+//! the parameter set minimizing the estimated cost of a PBS under the following constraints:
+//!
+//! + the LWE and GLWE secret keys reach the requested security level according to
+//!   [`crate::core_crypto::commons::security_estimator`];
+//! + the probability of failure of a PBS applied to a ciphertext at the maximum noise level
+//!   ([`MaxNoiseLevel::from_msg_carry_modulus`]) is below the requested p-error according to
+//!   [`crate::core_crypto::commons::noise_formulas`].
+//!
+//! # Warning
+//!
+//! The returned parameters rely on estimates, they should be reviewed before being used in
+//! production, just like any custom parameter set.
+
+use super::{
+    CarryModulus, CiphertextModulus, ClassicPBSParameters, DecompositionBaseLog,
+    DecompositionLevelCount, EncryptionKeyChoice, GlweDimension, LweBskGroupingFactor,
+    LweDimension, MessageModulus, MultiBitPBSParameters, NoiseDistribution, PBSParameters,
+    PolynomialSize, StandardDev,
+};
+use crate::core_crypto::commons::dispersion::Variance;
+use crate::core_crypto::commons::noise_formulas::{
+    external_product_glwe_ciphertext_additive_variance, keyswitch_lwe_ciphertext_additive_variance,
+    lwe_ciphertext_modulus_switch_additive_variance,
+    multi_bit_programmable_bootstrap_lwe_ciphertext_output_variance,
+    programmable_bootstrap_lwe_ciphertext_output_variance,
+};
+use crate::core_crypto::commons::parameters::CiphertextModulusLog;
+use crate::core_crypto::commons::security_estimator::estimate_lwe_security;
+use crate::shortint::ciphertext::MaxNoiseLevel;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
+
+const MIN_LWE_DIMENSION: usize = 512;
+const MAX_LWE_DIMENSION: usize = 1536;
+const LWE_DIMENSION_STEP: usize = 8;
+const MAX_GLWE_DIMENSION: usize = 6;
+const MIN_POLYNOMIAL_SIZE_LOG: usize = 8;
+const MAX_POLYNOMIAL_SIZE_LOG: usize = 17;
+const MAX_EQUIVALENT_LWE_DIMENSION: usize = 1 << 17;
+const MAX_DECOMPOSITION_LEVEL_COUNT: usize = 16;
+/// Number of bisection steps of the search of the smallest secure noise, the searched interval of
+/// 60 bits is narrowed down to less than 0.03 bits.
+const NOISE_LOG2_SEARCH_ITERATIONS: usize = 11;
+
+/// The estimated cost of the operations performed by a PBS, selects the kind of PBS to optimize
+/// parameters for.
+///
+/// Costs are given as a number of floating point operations, FFTs of size $N$ are assumed to cost
+/// $N \log\_2 N$.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CostModel {
+    /// Optimize [`ClassicPBSParameters`].
+    ClassicPBS,
+    /// Optimize [`MultiBitPBSParameters`] with the given grouping factor.
+    MultiBitPBS {
+        grouping_factor: LweBskGroupingFactor,
+    },
+}
+
+impl CostModel {
+    /// Return the estimated cost of a keyswitch followed by a PBS with the given parameters.
+    pub fn ks_pbs_cost(&self, params: &PBSParameters) -> f64 {
+        let big_lwe_dimension = params
+            .glwe_dimension()
+            .to_equivalent_lwe_dimension(params.polynomial_size());
+
+        let ks_cost = keyswitch_cost(big_lwe_dimension, params.lwe_dimension(), params.ks_level());
+
+        ks_cost
+            + self.pbs_cost(
+                params.lwe_dimension(),
+                params.glwe_dimension(),
+                params.polynomial_size(),
+                params.pbs_level(),
+            )
+    }
+
+    fn pbs_cost(
+        &self,
+        lwe_dimension: LweDimension,
+        glwe_dimension: GlweDimension,
+        polynomial_size: PolynomialSize,
+        pbs_level: DecompositionLevelCount,
+    ) -> f64 {
+        let glwe_size = glwe_dimension.to_glwe_size().0 as f64;
+        let polynomial_size_f64 = polynomial_size.0 as f64;
+        let level = pbs_level.0 as f64;
+
+        let fft_cost = polynomial_size_f64 * polynomial_size_f64.log2();
+        // Forward FFT of each decomposed polynomial, multiply-add with the GGSW in the Fourier
+        // domain, backward FFT of the output polynomials
+        let external_product_cost = glwe_size * level * fft_cost
+            + glwe_size * glwe_size * level * polynomial_size_f64
+            + glwe_size * fft_cost;
+
+        match self {
+            Self::ClassicPBS => lwe_dimension.0 as f64 * external_product_cost,
+            Self::MultiBitPBS { grouping_factor } => {
+                // The GGSW of each group is the sum of 2^g - 1 GGSW rotated by monomials
+                let ggsw_sum_cost = ((1 << grouping_factor.0) - 1) as f64
+                    * glwe_size
+                    * glwe_size
+                    * level
+                    * polynomial_size_f64;
+                (lwe_dimension.0 / grouping_factor.0) as f64
+                    * (external_product_cost + ggsw_sum_cost)
+            }
+        }
+    }
+}
+
+fn keyswitch_cost(
+    input_lwe_dimension: LweDimension,
+    output_lwe_dimension: LweDimension,
+    ks_level: DecompositionLevelCount,
+) -> f64 {
+    (input_lwe_dimension.0 * ks_level.0 * output_lwe_dimension.to_lwe_size().0) as f64
+}
+
+/// Error returned by [`optimize`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum OptimizationError {
+    InvalidMessageCarryModulus {
+        message_modulus: MessageModulus,
+        carry_modulus: CarryModulus,
+    },
+    InvalidPError(f64),
+    NoParametersFound,
+}
+
+impl Display for OptimizationError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::InvalidMessageCarryModulus {
+                message_modulus,
+                carry_modulus,
+            } => {
+                write!(
+                    f,
+                    "Message modulus ({}) and carry modulus ({}) must be powers of 2, \
+                    the message modulus must be at least 2",
+                    message_modulus.0, carry_modulus.0
+                )
+            }
+            Self::InvalidPError(p_error) => {
+                write!(f, "The target p-error ({p_error}) must be in ]0, 1[")
+            }
+            Self::NoParametersFound => {
+                write!(
+                    f,
+                    "No parameters satisfying the constraints were found in the search space"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for OptimizationError {}
+
+/// Search the parameter set minimizing the estimated PBS cost for the given message and carry
+/// moduli.
+///
+/// The cost is estimated with `cost_model`, a PBS of the returned parameters fails with a
+/// probability below `target_p_error` and the secret keys offer at least `min_security_bits` of
+/// security.
+///
+/// The returned [`PBSParameters`] are [`PBSParameters::PBS`] or [`PBSParameters::MultiBitPBS`]
+/// depending on the [`CostModel`].
+///
+/// ```rust
+/// use tfhe::shortint::parameters::{optimize, CarryModulus, CostModel, MessageModulus};
+///
+/// // 3 bits of message and 2 bits of carry
+/// let params = optimize(
+///     MessageModulus(8),
+///     CarryModulus(4),
+///     2.0f64.powi(-40),
+///     128,
+///     CostModel::ClassicPBS,
+/// )
+/// .unwrap();
+///
+/// assert!(params.is_pbs());
+/// assert!(params.validate_security(128).is_ok());
+/// ```
+pub fn optimize(
+    message_modulus: MessageModulus,
+    carry_modulus: CarryModulus,
+    target_p_error: f64,
+    min_security_bits: u32,
+    cost_model: CostModel,
+) -> Result<PBSParameters, OptimizationError> {
+    if message_modulus.0 < 2
+        || !message_modulus.0.is_power_of_two()
+        || !carry_modulus.0.is_power_of_two()
+    {
+        return Err(OptimizationError::InvalidMessageCarryModulus {
+            message_modulus,
+            carry_modulus,
+        });
+    }
+
+    if !(target_p_error > 0.0 && target_p_error < 1.0) {
+        return Err(OptimizationError::InvalidPError(target_p_error));
+    }
+
+    let ciphertext_modulus = CiphertextModulus::new_native();
+    let max_noise_level = MaxNoiseLevel::from_msg_carry_modulus(message_modulus, carry_modulus);
+
+    // With the padding bit the encoding uses a scaling factor 1 / (2 * message * carry) and the
+    // noise must stay below half of it
+    let noise_bound = 1.0 / (4.0 * (message_modulus.0 * carry_modulus.0) as f64);
+    let max_variance =
+        Variance((noise_bound / (std::f64::consts::SQRT_2 * erfc_inverse(target_p_error))).powi(2));
+
+    let mut secure_std_devs = SecureStdDevCache::new(min_security_bits);
+
+    let lwe_dimension_multiple = match cost_model {
+        CostModel::ClassicPBS => 1,
+        CostModel::MultiBitPBS { grouping_factor } => grouping_factor.0,
+    };
+    // Multi-bit PBS requires LWE dimensions multiple of the grouping factor
+    let lwe_dimension_step = (LWE_DIMENSION_STEP + lwe_dimension_multiple - 1)
+        / lwe_dimension_multiple
+        * lwe_dimension_multiple;
+    let first_lwe_dimension =
+        (MIN_LWE_DIMENSION + lwe_dimension_step - 1) / lwe_dimension_step * lwe_dimension_step;
+    let lwe_dimensions: Vec<_> = (first_lwe_dimension..=MAX_LWE_DIMENSION)
+        .step_by(lwe_dimension_step)
+        .map(LweDimension)
+        .filter_map(|dim| secure_std_devs.get(dim.0).map(|std_dev| (dim, std_dev)))
+        .collect();
+
+    let mut best: Option<(f64, PBSParameters)> = None;
+
+    for polynomial_size_log in MIN_POLYNOMIAL_SIZE_LOG..=MAX_POLYNOMIAL_SIZE_LOG {
+        let polynomial_size = PolynomialSize(1 << polynomial_size_log);
+
+        // Rounding the mask to 2N must leave room for the message and padding bit
+        let modulus_switch_log = CiphertextModulusLog(polynomial_size_log + 1);
+
+        for glwe_dimension in (1..=MAX_GLWE_DIMENSION).map(GlweDimension) {
+            let big_lwe_dimension = glwe_dimension.to_equivalent_lwe_dimension(polynomial_size);
+            if big_lwe_dimension.0 > MAX_EQUIVALENT_LWE_DIMENSION {
+                break;
+            }
+
+            let Some(glwe_modular_std_dev) = secure_std_devs.get(big_lwe_dimension.0) else {
+                continue;
+            };
+
+            for pbs_level in (1..=MAX_DECOMPOSITION_LEVEL_COUNT).map(DecompositionLevelCount) {
+                let (pbs_base_log, _) = best_decomposition_base_log(pbs_level, |base_log| {
+                    external_product_glwe_ciphertext_additive_variance(
+                        glwe_dimension,
+                        polynomial_size,
+                        glwe_modular_std_dev,
+                        base_log,
+                        pbs_level,
+                        ciphertext_modulus,
+                    )
+                });
+
+                for &(lwe_dimension, lwe_modular_std_dev) in &lwe_dimensions {
+                    let pbs_cost = cost_model.pbs_cost(
+                        lwe_dimension,
+                        glwe_dimension,
+                        polynomial_size,
+                        pbs_level,
+                    );
+                    if best.is_some_and(|(best_cost, _)| pbs_cost >= best_cost) {
+                        continue;
+                    }
+
+                    let pbs_variance = match cost_model {
+                        CostModel::ClassicPBS => {
+                            programmable_bootstrap_lwe_ciphertext_output_variance(
+                                lwe_dimension,
+                                glwe_dimension,
+                                polynomial_size,
+                                glwe_modular_std_dev,
+                                pbs_base_log,
+                                pbs_level,
+                                ciphertext_modulus,
+                            )
+                        }
+                        CostModel::MultiBitPBS { grouping_factor } => {
+                            multi_bit_programmable_bootstrap_lwe_ciphertext_output_variance(
+                                lwe_dimension,
+                                glwe_dimension,
+                                polynomial_size,
+                                grouping_factor,
+                                glwe_modular_std_dev,
+                                pbs_base_log,
+                                pbs_level,
+                                ciphertext_modulus,
+                            )
+                        }
+                    };
+                    let modulus_switch_variance = lwe_ciphertext_modulus_switch_additive_variance(
+                        lwe_dimension,
+                        modulus_switch_log,
+                        ciphertext_modulus,
+                    );
+                    let variance_without_ks =
+                        max_noise_level.get() as f64 * pbs_variance.0 + modulus_switch_variance.0;
+                    if variance_without_ks > max_variance.0 {
+                        continue;
+                    }
+
+                    // The cost increases with the level, the first level meeting the constraint is
+                    // the cheapest one
+                    let ks_decomposition = (1..=MAX_DECOMPOSITION_LEVEL_COUNT)
+                        .map(DecompositionLevelCount)
+                        .find_map(|ks_level| {
+                            let (ks_base_log, ks_variance) =
+                                best_decomposition_base_log(ks_level, |base_log| {
+                                    keyswitch_lwe_ciphertext_additive_variance(
+                                        big_lwe_dimension,
+                                        lwe_modular_std_dev,
+                                        base_log,
+                                        ks_level,
+                                        ciphertext_modulus,
+                                    )
+                                });
+                            (variance_without_ks + ks_variance.0 <= max_variance.0)
+                                .then_some((ks_base_log, ks_level))
+                        });
+
+                    let Some((ks_base_log, ks_level)) = ks_decomposition else {
+                        continue;
+                    };
+
+                    let params = match cost_model {
+                        CostModel::ClassicPBS => PBSParameters::PBS(ClassicPBSParameters {
+                            lwe_dimension,
+                            glwe_dimension,
+                            polynomial_size,
+                            lwe_noise_distribution: NoiseDistribution::Gaussian(
+                                lwe_modular_std_dev,
+                            ),
+                            glwe_noise_distribution: NoiseDistribution::Gaussian(
+                                glwe_modular_std_dev,
+                            ),
+                            pbs_base_log,
+                            pbs_level,
+                            ks_base_log,
+                            ks_level,
+                            message_modulus,
+                            carry_modulus,
+                            ciphertext_modulus,
+                            encryption_key_choice: EncryptionKeyChoice::Big,
+                        }),
+                        CostModel::MultiBitPBS { grouping_factor } => {
+                            PBSParameters::MultiBitPBS(MultiBitPBSParameters {
+                                lwe_dimension,
+                                glwe_dimension,
+                                polynomial_size,
+                                lwe_modular_std_dev,
+                                glwe_modular_std_dev,
+                                pbs_base_log,
+                                pbs_level,
+                                ks_base_log,
+                                ks_level,
+                                message_modulus,
+                                carry_modulus,
+                                ciphertext_modulus,
+                                encryption_key_choice: EncryptionKeyChoice::Big,
+                                grouping_factor,
+                                deterministic_execution: false,
+                            })
+                        }
+                    };
+
+                    let cost = cost_model.ks_pbs_cost(&params);
+                    if !best.is_some_and(|(best_cost, _)| best_cost <= cost) {
+                        best = Some((cost, params));
+                    }
+                }
+            }
+        }
+    }
+
+    best.map(|(_, params)| params)
+        .ok_or(OptimizationError::NoParametersFound)
+}
+
+/// Return the base log minimizing the [`Variance`] computed by `variance` for the given level
+/// count, the decomposition may use at most the 64 bits of the ciphertext modulus.
+fn best_decomposition_base_log(
+    level_count: DecompositionLevelCount,
+    variance: impl Fn(DecompositionBaseLog) -> Variance,
+) -> (DecompositionBaseLog, Variance) {
+    (1..=u64::BITS as usize / level_count.0)
+        .map(DecompositionBaseLog)
+        .map(|base_log| (base_log, variance(base_log)))
+        .min_by(|(_, lhs), (_, rhs)| lhs.0.total_cmp(&rhs.0))
+        .unwrap()
+}
+
+/// Memoize the smallest noise making secret keys of a given dimension secure, the noise can then
+/// only be increased without weakening the keys.
+struct SecureStdDevCache {
+    min_security_bits: u32,
+    std_devs: HashMap<usize, Option<StandardDev>>,
+}
+
+impl SecureStdDevCache {
+    fn new(min_security_bits: u32) -> Self {
+        Self {
+            min_security_bits,
+            std_devs: HashMap::new(),
+        }
+    }
+
+    fn get(&mut self, lwe_dimension: usize) -> Option<StandardDev> {
+        let min_security_bits = self.min_security_bits;
+        *self.std_devs.entry(lwe_dimension).or_insert_with(|| {
+            smallest_secure_std_dev(LweDimension(lwe_dimension), min_security_bits)
+        })
+    }
+}
+
+fn smallest_secure_std_dev(
+    lwe_dimension: LweDimension,
+    min_security_bits: u32,
+) -> Option<StandardDev> {
+    let is_secure = |std_dev_log2: f64| {
+        estimate_lwe_security(
+            lwe_dimension,
+            StandardDev(2.0f64.powf(std_dev_log2)),
+            CiphertextModulus::new_native(),
+        )
+        .bits()
+            >= min_security_bits as f64
+    };
+
+    // Noise below a few units of the 64 bits modulus is meaningless
+    let mut low = 2.0 - u64::BITS as f64;
+    // Noise above 1/4 of the torus leaves no room for a message
+    let mut high = -2.0;
+
+    if !is_secure(high) {
+        return None;
+    }
+    if is_secure(low) {
+        return Some(StandardDev(2.0f64.powf(low)));
+    }
+
+    for _ in 0..NOISE_LOG2_SEARCH_ITERATIONS {
+        let mid = (low + high) / 2.0;
+        if is_secure(mid) {
+            high = mid;
+        } else {
+            low = mid;
+        }
+    }
+
+    Some(StandardDev(2.0f64.powf(high)))
+}
+
+/// Return $x$ such that $\text{erfc}(x) = p$ for $p \in ]0, 1[$.
+fn erfc_inverse(p: f64) -> f64 {
+    // erfc is decreasing and erfc(10) is below 2^-150
+    let mut low = 0.0;
+    let mut high = 10.0;
+
+    for _ in 0..100 {
+        let mid = (low + high) / 2.0;
+        if erfc(mid) > p {
+            low = mid;
+        } else {
+            high = mid;
+        }
+    }
+
+    high
+}
+
+/// Complementary error function for $x \geq 0$ with a relative error below $1.2 \cdot 10^{-7}$,
+/// from Numerical Recipes.
+fn erfc(x: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.5 * x);
+    let polynomial = -1.26551223
+        + t * (1.00002368
+            + t * (0.37409196
+                + t * (0.09678418
+                    + t * (-0.18628806
+                        + t * (0.27886807
+                            + t * (-1.13520398
+                                + t * (1.48851587 + t * (-0.82215223 + t * 0.17087277))))))));
+
+    t * (-x * x + polynomial).exp()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+
+    #[test]
+    fn test_erfc_inverse() {
+        // Known values of the Gaussian tail: P(|X| > z sigma) = erfc(z / sqrt(2))
+        let z = std::f64::consts::SQRT_2 * erfc_inverse(2.0f64.powi(-40));
+        assert!((z - 7.1).abs() < 0.05, "{z}");
+        let z = std::f64::consts::SQRT_2 * erfc_inverse(0.05);
+        assert!((z - 1.96).abs() < 0.01, "{z}");
+    }
+
+    #[test]
+    fn test_optimize_classic_pbs() {
+        let params = optimize(
+            MessageModulus(4),
+            CarryModulus(4),
+            2.0f64.powi(-40),
+            128,
+            CostModel::ClassicPBS,
+        )
+        .unwrap();
+
+        assert!(params.is_pbs());
+        assert!(params.validate_security(128).is_ok());
+        assert_eq!(params.message_modulus(), MessageModulus(4));
+        assert_eq!(params.carry_modulus(), CarryModulus(4));
+
+        // The provided parameters satisfy the same constraints, the optimizer can only do better
+        // with respect to its own cost model
+        let cost_model = CostModel::ClassicPBS;
+        assert!(
+            cost_model.ks_pbs_cost(&params)
+                <= cost_model.ks_pbs_cost(&PARAM_MESSAGE_2_CARRY_2_KS_PBS.into())
+        );
+    }
+
+    #[test]
+    fn test_optimize_multi_bit_pbs() {
+        let grouping_factor = LweBskGroupingFactor(3);
+        let params = optimize(
+            MessageModulus(8),
+            CarryModulus(4),
+            2.0f64.powi(-40),
+            128,
+            CostModel::MultiBitPBS { grouping_factor },
+        )
+        .unwrap();
+
+        assert!(params.is_multi_bit_pbs());
+        assert_eq!(params.grouping_factor(), grouping_factor);
+        assert_eq!(params.lwe_dimension().0 % grouping_factor.0, 0);
+        assert!(params.validate_security(128).is_ok());
+    }
+
+    #[test]
+    fn test_optimize_invalid_inputs() {
+        assert!(matches!(
+            optimize(
+                MessageModulus(3),
+                CarryModulus(4),
+                2.0f64.powi(-40),
+                128,
+                CostModel::ClassicPBS,
+            ),
+            Err(OptimizationError::InvalidMessageCarryModulus { .. })
+        ));
+        assert!(matches!(
+            optimize(
+                MessageModulus(4),
+                CarryModulus(4),
+                0.0,
+                128,
+                CostModel::ClassicPBS,
+            ),
+            Err(OptimizationError::InvalidPError(_))
+        ));
+    }
+}
@@ -1,6 +1,8 @@
 //! #Warning experimental
 
-pub use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev};
+pub use crate::core_crypto::commons::dispersion::{
+    DispersionParameter, NoiseDistribution, StandardDev,
+};
 pub use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweDimension, LweDimension, PolynomialSize,
 };
@@ -72,8 +74,8 @@ pub const PARAM_MESSAGE_1_CARRY_1_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(638),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(6.150656787521441e-05),
-    glwe_modular_std_dev: StandardDev(4.9902938117294516e-08),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(6.150656787521441e-05)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.9902938117294516e-08)),
     pbs_base_log: DecompositionBaseLog(6),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(6),
@@ -87,8 +89,8 @@ pub const PARAM_MESSAGE_1_CARRY_2_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(710),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(1.6307554775887557e-05),
-    glwe_modular_std_dev: StandardDev(3.152834667799722e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(1.6307554775887557e-05)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.152834667799722e-16)),
     pbs_base_log: DecompositionBaseLog(21),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -102,8 +104,8 @@ pub const PARAM_MESSAGE_1_CARRY_3_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(756),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(6.983104533665408e-06),
-    glwe_modular_std_dev: StandardDev(3.152834667799722e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(6.983104533665408e-06)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.152834667799722e-16)),
     pbs_base_log: DecompositionBaseLog(21),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -117,8 +119,8 @@ pub const PARAM_MESSAGE_1_CARRY_4_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(821),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(2.1066761751849058e-06),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.1066761751849058e-06)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -132,8 +134,8 @@ pub const PARAM_MESSAGE_1_CARRY_5_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(888),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(6.12494404462554e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(6.12494404462554e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(6),
@@ -147,8 +149,8 @@ pub const PARAM_MESSAGE_1_CARRY_6_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(942),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(2.2630942423569665e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.2630942423569665e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -162,8 +164,8 @@ pub const PARAM_MESSAGE_1_CARRY_7_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1029),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(4.5508144326041556e-08),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.5508144326041556e-08)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -177,8 +179,8 @@ pub const PARAM_MESSAGE_2_CARRY_1_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(710),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(1.6307554775887557e-05),
-    glwe_modular_std_dev: StandardDev(3.152834667799722e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(1.6307554775887557e-05)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.152834667799722e-16)),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -192,8 +194,8 @@ pub const PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(756),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(6.983104533665408e-06),
-    glwe_modular_std_dev: StandardDev(3.152834667799722e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(6.983104533665408e-06)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.152834667799722e-16)),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -207,8 +209,8 @@ pub const PARAM_MESSAGE_2_CARRY_3_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(850),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(1.2341934723690542e-06),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(1.2341934723690542e-06)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -222,8 +224,8 @@ pub const PARAM_MESSAGE_2_CARRY_4_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(877),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(7.502111286917793e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(7.502111286917793e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -237,8 +239,8 @@ pub const PARAM_MESSAGE_2_CARRY_5_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(942),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(2.2630942423569665e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.2630942423569665e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -252,8 +254,8 @@ pub const PARAM_MESSAGE_2_CARRY_6_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1030),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(4.46767660406645e-08),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.46767660406645e-08)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -267,8 +269,8 @@ pub const PARAM_MESSAGE_3_CARRY_1_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(759),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(6.607793351104514e-06),
-    glwe_modular_std_dev: StandardDev(3.152834667799722e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(6.607793351104514e-06)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.152834667799722e-16)),
     pbs_base_log: DecompositionBaseLog(23),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(5),
@@ -282,8 +284,8 @@ pub const PARAM_MESSAGE_3_CARRY_2_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(862),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(9.892236038140916e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(9.892236038140916e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(6),
@@ -297,8 +299,8 @@ pub const PARAM_MESSAGE_3_CARRY_3_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(877),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(7.502111286917793e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(7.502111286917793e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -312,8 +314,8 @@ pub const PARAM_MESSAGE_3_CARRY_4_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(942),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(2.2630942423569665e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.2630942423569665e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -327,8 +329,8 @@ pub const PARAM_MESSAGE_3_CARRY_5_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1032),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(4.305929680023812e-08),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.305929680023812e-08)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -342,8 +344,8 @@ pub const PARAM_MESSAGE_4_CARRY_1_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(820),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(2.145878762605306e-06),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.145878762605306e-06)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -357,8 +359,8 @@ pub const PARAM_MESSAGE_4_CARRY_2_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(877),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(7.502111286917793e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(7.502111286917793e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -372,8 +374,8 @@ pub const PARAM_MESSAGE_4_CARRY_3_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(943),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(2.2219042764335445e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.2219042764335445e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -387,8 +389,8 @@ pub const PARAM_MESSAGE_4_CARRY_4_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1044),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(3.4512638181977925e-08),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.4512638181977925e-08)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -402,8 +404,8 @@ pub const PARAM_MESSAGE_5_CARRY_1_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(877),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(7.502111286917793e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(7.502111286917793e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -417,8 +419,8 @@ pub const PARAM_MESSAGE_5_CARRY_2_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(947),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(2.0639337523302752e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.0639337523302752e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(15),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(6),
@@ -432,8 +434,8 @@ pub const PARAM_MESSAGE_5_CARRY_3_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(997),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(8.20967300015962e-08),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(8.20967300015962e-08)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -447,8 +449,8 @@ pub const PARAM_MESSAGE_6_CARRY_1_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(942),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(2.2630942423569665e-07),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.2630942423569665e-07)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(6),
@@ -462,8 +464,8 @@ pub const PARAM_MESSAGE_6_CARRY_2_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(998),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(8.05969228871865e-08),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(8.05969228871865e-08)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -477,8 +479,8 @@ pub const PARAM_MESSAGE_7_CARRY_1_COMPACT_PK_KS_PBS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1017),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(5.6777713805325606e-08),
-    glwe_modular_std_dev: StandardDev(2.168404344971009e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(5.6777713805325606e-08)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.168404344971009e-19)),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(7),
@@ -494,8 +496,8 @@ pub const PARAM_MESSAGE_1_CARRY_1_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(3),
     polynomial_size: PolynomialSize(512),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(3.96669408172410e-12),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.96669408172410e-12)),
     pbs_base_log: DecompositionBaseLog(18),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(2),
@@ -509,8 +511,8 @@ pub const PARAM_MESSAGE_1_CARRY_2_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(3.15283466779972e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
     pbs_base_log: DecompositionBaseLog(18),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(2),
@@ -524,8 +526,8 @@ pub const PARAM_MESSAGE_1_CARRY_3_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(3.15283466779972e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
     pbs_base_log: DecompositionBaseLog(21),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(2),
@@ -539,8 +541,8 @@ pub const PARAM_MESSAGE_1_CARRY_4_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(21),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -554,8 +556,8 @@ pub const PARAM_MESSAGE_1_CARRY_5_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(4),
@@ -569,8 +571,8 @@ pub const PARAM_MESSAGE_1_CARRY_6_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(5),
@@ -584,8 +586,8 @@ pub const PARAM_MESSAGE_1_CARRY_7_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(11),
@@ -599,8 +601,8 @@ pub const PARAM_MESSAGE_2_CARRY_1_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(2),
     polynomial_size: PolynomialSize(1024),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(3.15283466779972e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
     pbs_base_log: DecompositionBaseLog(21),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(2),
@@ -614,8 +616,8 @@ pub const PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(3.15283466779972e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
     pbs_base_log: DecompositionBaseLog(21),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(2),
@@ -629,8 +631,8 @@ pub const PARAM_MESSAGE_2_CARRY_3_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(21),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -644,8 +646,8 @@ pub const PARAM_MESSAGE_2_CARRY_4_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -659,8 +661,8 @@ pub const PARAM_MESSAGE_2_CARRY_5_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -674,8 +676,8 @@ pub const PARAM_MESSAGE_2_CARRY_6_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(65536),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(1),
@@ -689,8 +691,8 @@ pub const PARAM_MESSAGE_3_CARRY_1_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(2048),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(3.15283466779972e-16),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
     pbs_base_log: DecompositionBaseLog(22),
     pbs_level: DecompositionLevelCount(1),
     ks_level: DecompositionLevelCount(3),
@@ -704,8 +706,8 @@ pub const PARAM_MESSAGE_3_CARRY_2_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(4),
@@ -719,8 +721,8 @@ pub const PARAM_MESSAGE_3_CARRY_3_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(7),
@@ -734,8 +736,8 @@ pub const PARAM_MESSAGE_3_CARRY_4_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(22),
@@ -749,8 +751,8 @@ pub const PARAM_MESSAGE_3_CARRY_5_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(65536),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(1),
@@ -764,8 +766,8 @@ pub const PARAM_MESSAGE_4_CARRY_1_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(4096),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(12),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(11),
@@ -779,8 +781,8 @@ pub const PARAM_MESSAGE_4_CARRY_2_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(1024),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(8192),
-    lwe_modular_std_dev: StandardDev(4.99029381172945e-8),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(4.99029381172945e-8)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(21),
@@ -794,8 +796,8 @@ pub const PARAM_MESSAGE_4_CARRY_3_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(1),
@@ -809,8 +811,8 @@ pub const PARAM_MESSAGE_4_CARRY_4_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(65536),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(1),
@@ -824,8 +826,8 @@ pub const PARAM_MESSAGE_5_CARRY_1_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(16384),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(1),
@@ -839,8 +841,8 @@ pub const PARAM_MESSAGE_5_CARRY_2_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(14),
     pbs_level: DecompositionLevelCount(2),
     ks_level: DecompositionLevelCount(1),
@@ -854,8 +856,8 @@ pub const PARAM_MESSAGE_5_CARRY_3_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(65536),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(1),
@@ -869,8 +871,8 @@ pub const PARAM_MESSAGE_6_CARRY_1_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(32768),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(1),
@@ -884,8 +886,8 @@ pub const PARAM_MESSAGE_6_CARRY_2_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(65536),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(11),
     pbs_level: DecompositionLevelCount(3),
     ks_level: DecompositionLevelCount(2),
@@ -899,8 +901,8 @@ pub const PARAM_MESSAGE_7_CARRY_1_COMPACT_PK_PBS_KS: ClassicPBSParameters = Clas
     lwe_dimension: LweDimension(2048),
     glwe_dimension: GlweDimension(1),
     polynomial_size: PolynomialSize(65536),
-    lwe_modular_std_dev: StandardDev(3.15283466779972e-16),
-    glwe_modular_std_dev: StandardDev(2.16840434497101e-19),
+    lwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(3.15283466779972e-16)),
+    glwe_noise_distribution: NoiseDistribution::Gaussian(StandardDev(2.16840434497101e-19)),
     pbs_base_log: DecompositionBaseLog(9),
     pbs_level: DecompositionLevelCount(4),
     ks_level: DecompositionLevelCount(2),
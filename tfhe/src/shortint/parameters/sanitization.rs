@@ -0,0 +1,46 @@
+use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
+use crate::shortint::parameters::{
+    DecompositionBaseLog, DecompositionLevelCount, NoiseSquashingParameters,
+    StatisticalSecurityParameter,
+};
+
+use serde::{Deserialize, Serialize};
+
+/// A set of cryptographic parameters for the sanitization of shortint ciphertexts.
+///
+/// See [`ServerKey::sanitize`](crate::shortint::ServerKey::sanitize) for details.
+#[derive(Copy, Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SanitizationParameters {
+    /// Parameters of the 128 bits bootstrap bringing the noise of the ciphertexts far below the
+    /// flooding noise, and of the compact public key rerandomizing their mask.
+    pub noise_squashing_parameters: NoiseSquashingParameters,
+    /// Base log of the keyswitching key going back from the 128 bits secret key to the encryption
+    /// key of the ciphertexts.
+    pub ks_base_log: DecompositionBaseLog,
+    /// Level count of the keyswitching key going back from the 128 bits secret key to the
+    /// encryption key of the ciphertexts.
+    pub ks_level: DecompositionLevelCount,
+    /// The flooding noise is $2^{\lambda}$ times larger than the noise it hides, with $\lambda$
+    /// the statistical security.
+    pub statistical_security: StatisticalSecurityParameter,
+}
+
+/// Sanitization parameters for ciphertexts encrypted with
+/// [`PARAM_MESSAGE_2_CARRY_2_KS_PBS`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS).
+pub const SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS: SanitizationParameters =
+    SanitizationParameters {
+        noise_squashing_parameters: NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+        ks_base_log: DecompositionBaseLog(24),
+        ks_level: DecompositionLevelCount(1),
+        statistical_security: StatisticalSecurityParameter(40),
+    };
+
+/// Sanitization parameters for ciphertexts encrypted with
+/// [`PARAM_MESSAGE_2_CARRY_2_PBS_KS`](crate::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_PBS_KS).
+pub const SANITIZATION_PARAM_MESSAGE_2_CARRY_2_PBS_KS: SanitizationParameters =
+    SanitizationParameters {
+        noise_squashing_parameters: NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2,
+        ks_base_log: DecompositionBaseLog(2),
+        ks_level: DecompositionLevelCount(8),
+        statistical_security: StatisticalSecurityParameter(40),
+    };
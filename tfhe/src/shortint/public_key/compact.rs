@@ -3,14 +3,17 @@ use std::iter::once;
 use serde::{Deserialize, Serialize};
 
 use crate::core_crypto::prelude::{
+    aggregate_seeded_lwe_compact_public_key_shares,
     allocate_and_generate_new_seeded_lwe_compact_public_key, generate_lwe_compact_public_key,
-    LweCiphertextCount, LweCiphertextOwned, LweCompactCiphertextListOwned,
-    LweCompactPublicKeyOwned, Plaintext, PlaintextList, SeededLweCompactPublicKeyOwned,
+    lwe_ciphertext_add_assign, LweCiphertextCount, LweCiphertextOwned,
+    LweCompactCiphertextListOwned, LweCompactPublicKeyOwned, Plaintext, PlaintextList,
+    SeededLweCompactPublicKey, SeededLweCompactPublicKeyOwned,
 };
 
 use crate::core_crypto::prelude::encrypt_lwe_ciphertext_with_compact_public_key;
 
 use crate::shortint::ciphertext::{CompactCiphertextList, Degree, NoiseLevel};
+use crate::shortint::client_key::CompactPublicKeyShare;
 use crate::shortint::{Ciphertext, ClientKey, PBSOrder, ShortintParameterSet};
 
 use crate::shortint::engine::ShortintEngine;
@@ -54,11 +57,11 @@ impl CompactPublicKey {
             match client_key.parameters.encryption_key_choice().into() {
                 crate::shortint::PBSOrder::KeyswitchBootstrap => (
                     &client_key.large_lwe_secret_key,
-                    parameters.glwe_modular_std_dev(),
+                    parameters.glwe_noise_distribution(),
                 ),
                 crate::shortint::PBSOrder::BootstrapKeyswitch => (
                     &client_key.small_lwe_secret_key,
-                    parameters.lwe_modular_std_dev(),
+                    parameters.lwe_noise_distribution(),
                 ),
             };
 
@@ -92,6 +95,60 @@ impl CompactPublicKey {
             .next()
             .unwrap();
 
+        let encrypted_ct = self.encrypt_plaintext(plain);
+
+        let message_modulus = self.parameters.message_modulus();
+        Ciphertext::new(
+            encrypted_ct,
+            Degree::new(message_modulus.0 - 1),
+            NoiseLevel::NOMINAL,
+            message_modulus,
+            self.parameters.carry_modulus(),
+            self.pbs_order,
+        )
+    }
+
+    /// Rerandomize a ciphertext by adding a fresh encryption of zero to it.
+    ///
+    /// The rerandomized ciphertext encrypts the same message, but can no longer be linked to the
+    /// input ciphertext by looking at its mask. Its noise level is increased by one nominal level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext and the public key do not share the same [`PBSOrder`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, CompactPublicKey};
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+    /// let pk = CompactPublicKey::new(&cks);
+    ///
+    /// let ct = pk.encrypt(3);
+    /// let mut ct_res = ct.clone();
+    ///
+    /// pk.rerandomize(&mut ct_res);
+    ///
+    /// assert_ne!(ct, ct_res);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn rerandomize(&self, ct: &mut Ciphertext) {
+        assert_eq!(
+            ct.pbs_order, self.pbs_order,
+            "Mismatched PBSOrder between the ciphertext ({:?}) and the public key ({:?})",
+            ct.pbs_order, self.pbs_order
+        );
+
+        let zero_encryption = self.encrypt_plaintext(Plaintext(0));
+
+        lwe_ciphertext_add_assign(&mut ct.ct, &zero_encryption);
+        ct.set_noise_level(ct.noise_level() + NoiseLevel::NOMINAL);
+    }
+
+    fn encrypt_plaintext(&self, plain: Plaintext<u64>) -> LweCiphertextOwned<u64> {
         // This allocates the required ct
         let mut encrypted_ct = LweCiphertextOwned::new(
             0u64,
@@ -100,8 +157,12 @@ impl CompactPublicKey {
         );
 
         let encryption_noise = match self.pbs_order {
-            crate::shortint::PBSOrder::KeyswitchBootstrap => self.parameters.glwe_modular_std_dev(),
-            crate::shortint::PBSOrder::BootstrapKeyswitch => self.parameters.lwe_modular_std_dev(),
+            crate::shortint::PBSOrder::KeyswitchBootstrap => {
+                self.parameters.glwe_noise_distribution()
+            }
+            crate::shortint::PBSOrder::BootstrapKeyswitch => {
+                self.parameters.lwe_noise_distribution()
+            }
         };
 
         ShortintEngine::with_thread_local_mut(|engine| {
@@ -116,15 +177,7 @@ impl CompactPublicKey {
             );
         });
 
-        let message_modulus = self.parameters.message_modulus();
-        Ciphertext::new(
-            encrypted_ct,
-            Degree::new(message_modulus.0 - 1),
-            NoiseLevel::NOMINAL,
-            message_modulus,
-            self.parameters.carry_modulus(),
-            self.pbs_order,
-        )
+        encrypted_ct
     }
 
     pub fn encrypt_slice(&self, messages: &[u64]) -> CompactCiphertextList {
@@ -153,8 +206,8 @@ impl CompactPublicKey {
                     &self.key,
                     &mut ct_list,
                     &plaintext_list,
-                    self.parameters.glwe_modular_std_dev(),
-                    self.parameters.lwe_modular_std_dev(),
+                    self.parameters.glwe_noise_distribution(),
+                    self.parameters.lwe_noise_distribution(),
                     &mut engine.secret_generator,
                     &mut engine.encryption_generator,
                 );
@@ -170,8 +223,8 @@ impl CompactPublicKey {
                     &self.key,
                     &mut ct_list,
                     &plaintext_list,
-                    self.parameters.glwe_modular_std_dev(),
-                    self.parameters.lwe_modular_std_dev(),
+                    self.parameters.glwe_noise_distribution(),
+                    self.parameters.lwe_noise_distribution(),
                     &mut engine.secret_generator,
                     &mut engine.encryption_generator,
                 );
@@ -212,11 +265,11 @@ impl CompressedCompactPublicKey {
             match client_key.parameters.encryption_key_choice().into() {
                 crate::shortint::PBSOrder::KeyswitchBootstrap => (
                     &client_key.large_lwe_secret_key,
-                    parameters.glwe_modular_std_dev(),
+                    parameters.glwe_noise_distribution(),
                 ),
                 crate::shortint::PBSOrder::BootstrapKeyswitch => (
                     &client_key.small_lwe_secret_key,
-                    parameters.lwe_modular_std_dev(),
+                    parameters.lwe_noise_distribution(),
                 ),
             };
 
@@ -236,6 +289,45 @@ impl CompressedCompactPublicKey {
         }
     }
 
+    /// Aggregate the [`CompactPublicKeyShare`]s of all the parties of a distributed key
+    /// generation into a compressed compact public key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shares` is empty or if the shares were not generated with the same parameters
+    /// and common seed.
+    pub fn aggregate_shares(shares: &[CompactPublicKeyShare]) -> Self {
+        assert!(
+            !shares.is_empty(),
+            "Got no CompactPublicKeyShare to aggregate"
+        );
+
+        let parameters = shares[0].parameters;
+        assert!(
+            shares.iter().all(|share| share.parameters == parameters),
+            "Got CompactPublicKeyShares generated with different parameters"
+        );
+
+        let key_shares: Vec<_> = shares
+            .iter()
+            .map(|share| {
+                SeededLweCompactPublicKey::from_container(
+                    share.key.as_ref(),
+                    share.key.compression_seed(),
+                    share.key.ciphertext_modulus(),
+                )
+            })
+            .collect();
+        let mut key = shares[0].key.clone();
+        aggregate_seeded_lwe_compact_public_key_shares(&key_shares, &mut key);
+
+        Self {
+            key,
+            parameters,
+            pbs_order: parameters.encryption_key_choice().into(),
+        }
+    }
+
     pub fn decompress(self) -> CompactPublicKey {
         let decompressed_key = self.key.decompress_into_lwe_compact_public_key();
         CompactPublicKey {
@@ -189,6 +189,39 @@ impl PublicKey {
             engine.encrypt_native_crt_with_public_key(self, message, message_modulus)
         })
     }
+
+    /// Rerandomize a ciphertext by adding a fresh encryption of zero to it.
+    ///
+    /// The rerandomized ciphertext encrypts the same message, but can no longer be linked to the
+    /// input ciphertext by looking at its mask. Its noise level is increased by one nominal level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext and the public key do not share the same [`PBSOrder`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::{ClientKey, PublicKey};
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let pk = PublicKey::new(&cks);
+    ///
+    /// let ct = cks.encrypt(3);
+    /// let mut ct_res = ct.clone();
+    ///
+    /// pk.rerandomize(&mut ct_res);
+    ///
+    /// assert_ne!(ct, ct_res);
+    /// assert_eq!(cks.decrypt(&ct_res), 3);
+    /// ```
+    pub fn rerandomize(&self, ct: &mut Ciphertext) {
+        ShortintEngine::with_thread_local_mut(|engine| {
+            engine.add_public_key_zero_encryption_assign(self, ct);
+        });
+    }
 }
 
 impl From<CompressedPublicKey> for PublicKey {
@@ -2,6 +2,7 @@
 
 use super::MaxDegree;
 use crate::core_crypto::prelude::*;
+use crate::shortint::client_key::ServerKeyShare;
 use crate::shortint::engine::ShortintEngine;
 use crate::shortint::parameters::{CarryModulus, CiphertextModulus, MessageModulus};
 use crate::shortint::{ClientKey, PBSOrder};
@@ -79,4 +80,69 @@ impl CompressedServerKey {
             engine.new_compressed_server_key_with_max_degree(cks, max_degree)
         })
     }
+
+    /// Aggregate the [`ServerKeyShare`]s of all the parties of a distributed key generation into
+    /// a compressed server key.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `shares` is empty or if the shares were not generated with the same parameters
+    /// and common seed.
+    pub fn aggregate_shares(shares: &[ServerKeyShare]) -> Self {
+        assert!(!shares.is_empty(), "Got no ServerKeyShare to aggregate");
+
+        let max_degree = MaxDegree::from_msg_carry_modulus(
+            shares[0].parameters.message_modulus(),
+            shares[0].parameters.carry_modulus(),
+        );
+
+        Self::aggregate_shares_with_max_degree(shares, max_degree)
+    }
+
+    /// Aggregate the [`ServerKeyShare`]s of all the parties of a distributed key generation into
+    /// a compressed server key with a chosen maximum degree.
+    ///
+    /// See [`CompressedServerKey::aggregate_shares`] for the panics.
+    pub fn aggregate_shares_with_max_degree(
+        shares: &[ServerKeyShare],
+        max_degree: MaxDegree,
+    ) -> Self {
+        assert!(!shares.is_empty(), "Got no ServerKeyShare to aggregate");
+
+        let parameters = shares[0].parameters;
+        assert!(
+            shares.iter().all(|share| share.parameters == parameters),
+            "Got ServerKeyShares generated with different parameters"
+        );
+
+        let bootstrapping_key_shares: Vec<_> = shares
+            .iter()
+            .map(|share| share.bootstrapping_key.as_view())
+            .collect();
+        let mut bootstrapping_key = shares[0].bootstrapping_key.clone();
+        aggregate_seeded_lwe_bootstrap_key_shares(
+            &bootstrapping_key_shares,
+            &mut bootstrapping_key,
+        );
+
+        let key_switching_key_shares: Vec<_> = shares
+            .iter()
+            .map(|share| share.key_switching_key.as_view())
+            .collect();
+        let mut key_switching_key = shares[0].key_switching_key.clone();
+        aggregate_seeded_lwe_keyswitch_key_shares(
+            &key_switching_key_shares,
+            &mut key_switching_key,
+        );
+
+        Self {
+            key_switching_key,
+            bootstrapping_key: ShortintCompressedBootstrappingKey::Classic(bootstrapping_key),
+            message_modulus: parameters.message_modulus(),
+            carry_modulus: parameters.carry_modulus(),
+            max_degree,
+            ciphertext_modulus: parameters.ciphertext_modulus(),
+            pbs_order: parameters.encryption_key_choice().into(),
+        }
+    }
 }
@@ -0,0 +1,381 @@
+use super::{ServerKey, ShortintBootstrappingKey};
+use crate::core_crypto::algorithms::*;
+use crate::core_crypto::commons::computation_buffers::ComputationBuffers;
+use crate::core_crypto::commons::parameters::{LutCountLog, LweCiphertextCount};
+use crate::core_crypto::commons::traits::*;
+use crate::core_crypto::entities::*;
+use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
+use crate::shortint::ciphertext::{Degree, MaxDegree, NoiseLevel};
+use crate::shortint::engine::{fill_accumulator, ShortintEngine};
+use crate::shortint::{Ciphertext, PBSOrder};
+
+/// A lookup table packing several univariate functions in a single accumulator, all the functions
+/// being evaluated by a single programmable bootstrapping.
+///
+/// The accumulator is split in `2^lut_count_log` chunks, one per function, which reduces the
+/// input space accordingly: the degree of the input ciphertext must not exceed `input_max_degree`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+#[must_use]
+pub struct ManyLookupTable<C: Container<Element = u64>> {
+    pub acc: GlweCiphertext<C>,
+    // Degree of the ciphertext output for each function, in the order of the functions
+    pub per_function_output_degree: Vec<Degree>,
+    pub lut_count_log: LutCountLog,
+    pub input_max_degree: MaxDegree,
+}
+
+pub type ManyLookupTableOwned = ManyLookupTable<Vec<u64>>;
+pub type ManyLookupTableMutView<'a> = ManyLookupTable<&'a mut [u64]>;
+pub type ManyLookupTableView<'a> = ManyLookupTable<&'a [u64]>;
+
+impl<C: Container<Element = u64>> ManyLookupTable<C> {
+    /// Return the number of functions packed in the lookup table.
+    pub fn function_count(&self) -> usize {
+        self.per_function_output_degree.len()
+    }
+}
+
+impl ServerKey {
+    /// Constructs a lookup table packing several functions, to be evaluated by a single
+    /// programmable bootstrapping with [`ServerKey::apply_many_lookup_table`].
+    ///
+    /// The input space of the returned lookup table is the full message and carry space divided by
+    /// the number of functions rounded up to the next power of two.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `functions` is empty or if there are more functions than values in the message
+    /// and carry space.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 3;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let f1 = |x: u64| x * x % 4;
+    /// let f2 = |x: u64| (x + 1) % 4;
+    ///
+    /// let lut = sks.generate_many_lookup_table(&[&f1, &f2]);
+    /// let ct_res = sks.apply_many_lookup_table(&ct, &lut);
+    ///
+    /// assert_eq!(cks.decrypt(&ct_res[0]), f1(msg));
+    /// assert_eq!(cks.decrypt(&ct_res[1]), f2(msg));
+    /// ```
+    pub fn generate_many_lookup_table(
+        &self,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> ManyLookupTableOwned {
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let function_count = functions.len();
+
+        assert!(
+            function_count != 0,
+            "At least one function is required to generate a many lookup table"
+        );
+        assert!(
+            function_count <= modulus_sup,
+            "Cannot pack {function_count} functions in a lookup table with {modulus_sup} values"
+        );
+
+        let lut_count_log = LutCountLog(function_count.next_power_of_two().ilog2() as usize);
+        // Number of input values each function is evaluated on
+        let input_modulus = (modulus_sup >> lut_count_log.0) as u64;
+
+        // The i-th function fills the i-th chunk of input_modulus boxes, trailing chunks left when
+        // function_count is not a power of two are filled with 0
+        let packed_function = |x: u64| -> u64 {
+            let function_idx = (x / input_modulus) as usize;
+            functions
+                .get(function_idx)
+                .map_or(0, |f| f(x % input_modulus))
+        };
+
+        let mut acc = GlweCiphertext::new(
+            0,
+            self.bootstrapping_key.glwe_size(),
+            self.bootstrapping_key.polynomial_size(),
+            self.ciphertext_modulus,
+        );
+        fill_accumulator(&mut acc, self, packed_function);
+
+        let per_function_output_degree = functions
+            .iter()
+            .map(|f| {
+                let max_value = (0..input_modulus).map(f).max().unwrap_or(0);
+                Degree::new(max_value as usize)
+            })
+            .collect();
+
+        ManyLookupTableOwned {
+            acc,
+            per_function_output_degree,
+            lut_count_log,
+            input_max_degree: MaxDegree::new(input_modulus as usize - 1),
+        }
+    }
+
+    /// Evaluate all the functions of a [`ManyLookupTable`] on a ciphertext with a single
+    /// programmable bootstrapping, returning one ciphertext per function.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the degree of the input ciphertext exceeds the input space of the lookup table.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg_modulus = cks.parameters.message_modulus().0 as u64;
+    ///
+    /// let ct1 = cks.encrypt(3);
+    /// let ct2 = cks.encrypt(2);
+    /// // 3 + 2 = 5 = 0b01_01, the input stays in the first half of the message and carry space
+    /// let ct = sks.unchecked_add(&ct1, &ct2);
+    ///
+    /// let message = |x: u64| x % msg_modulus;
+    /// let carry = |x: u64| x / msg_modulus;
+    ///
+    /// let lut = sks.generate_many_lookup_table(&[&message, &carry]);
+    /// let ct_res = sks.apply_many_lookup_table(&ct, &lut);
+    ///
+    /// assert_eq!(cks.decrypt(&ct_res[0]), 1);
+    /// assert_eq!(cks.decrypt(&ct_res[1]), 1);
+    /// ```
+    pub fn apply_many_lookup_table(
+        &self,
+        ct: &Ciphertext,
+        lut: &ManyLookupTableOwned,
+    ) -> Vec<Ciphertext> {
+        assert!(
+            ct.degree.get() <= lut.input_max_degree.get(),
+            "The degree of the input ciphertext (={}) exceeds the maximum input degree (={}) of \
+            the many lookup table",
+            ct.degree.get(),
+            lut.input_max_degree.get(),
+        );
+
+        if ct.is_trivial() {
+            return self.trivial_many_lut_pbs(ct, lut);
+        }
+
+        let output_cts = ShortintEngine::with_thread_local_mut(|engine| {
+            let (mut ciphertext_buffers, buffers) = engine.get_buffers(self);
+
+            match self.pbs_order {
+                PBSOrder::KeyswitchBootstrap => {
+                    // Compute a key switch
+                    keyswitch_lwe_ciphertext(
+                        &self.key_switching_key,
+                        &ct.ct,
+                        &mut ciphertext_buffers.buffer_lwe_after_ks,
+                    );
+
+                    let pbs_outputs = self.many_lut_programmable_bootstrap(
+                        &ciphertext_buffers.buffer_lwe_after_ks,
+                        lut,
+                        buffers,
+                    );
+
+                    pbs_outputs
+                        .iter()
+                        .map(|lwe| {
+                            LweCiphertextOwned::from_container(
+                                lwe.as_ref().to_vec(),
+                                self.ciphertext_modulus,
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                }
+                PBSOrder::BootstrapKeyswitch => {
+                    let pbs_outputs = self.many_lut_programmable_bootstrap(&ct.ct, lut, buffers);
+
+                    pbs_outputs
+                        .iter()
+                        .map(|lwe| {
+                            let mut output = LweCiphertextOwned::new(
+                                0,
+                                self.key_switching_key
+                                    .output_key_lwe_dimension()
+                                    .to_lwe_size(),
+                                self.ciphertext_modulus,
+                            );
+                            // Compute a key switch
+                            keyswitch_lwe_ciphertext(&self.key_switching_key, &lwe, &mut output);
+                            output
+                        })
+                        .collect::<Vec<_>>()
+                }
+            }
+        });
+
+        output_cts
+            .into_iter()
+            .zip(lut.per_function_output_degree.iter())
+            .map(|(output_ct, degree)| {
+                Ciphertext::new(
+                    output_ct,
+                    *degree,
+                    NoiseLevel::NOMINAL,
+                    self.message_modulus,
+                    self.carry_modulus,
+                    self.pbs_order,
+                )
+            })
+            .collect()
+    }
+
+    /// Evaluate several functions on a ciphertext with a single programmable bootstrapping,
+    /// returning one ciphertext per function in the order of `functions`.
+    ///
+    /// This is equivalent to calling [`ServerKey::apply_lookup_table`] for each function, at the
+    /// cost of a single PBS, but the input space is divided by the number of functions rounded up
+    /// to the next power of two, see [`ServerKey::generate_many_lookup_table`].
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let msg = 2;
+    ///
+    /// let ct = cks.encrypt(msg);
+    ///
+    /// let double = |x: u64| (2 * x) % 4;
+    /// let square = |x: u64| (x * x) % 4;
+    /// let negate = |x: u64| (4 - x) % 4;
+    ///
+    /// let ct_res = sks.apply_many_lookup_tables(&ct, &[&double, &square, &negate]);
+    ///
+    /// assert_eq!(cks.decrypt(&ct_res[0]), double(msg));
+    /// assert_eq!(cks.decrypt(&ct_res[1]), square(msg));
+    /// assert_eq!(cks.decrypt(&ct_res[2]), negate(msg));
+    /// ```
+    pub fn apply_many_lookup_tables(
+        &self,
+        ct: &Ciphertext,
+        functions: &[&dyn Fn(u64) -> u64],
+    ) -> Vec<Ciphertext> {
+        let lut = self.generate_many_lookup_table(functions);
+
+        self.apply_many_lookup_table(ct, &lut)
+    }
+
+    fn many_lut_programmable_bootstrap<InputCont>(
+        &self,
+        input: &LweCiphertext<InputCont>,
+        lut: &ManyLookupTableOwned,
+        buffers: &mut ComputationBuffers,
+    ) -> LweCiphertextListOwned<u64>
+    where
+        InputCont: Container<Element = u64>,
+    {
+        let mut output = LweCiphertextListOwned::new(
+            0,
+            self.bootstrapping_key.output_lwe_dimension().to_lwe_size(),
+            LweCiphertextCount(lut.function_count()),
+            self.ciphertext_modulus,
+        );
+
+        match &self.bootstrapping_key {
+            ShortintBootstrappingKey::Classic(fourier_bsk) => {
+                let fft = Fft::new(fourier_bsk.polynomial_size());
+                let fft = fft.as_view();
+                buffers.resize(
+                    programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized_requirement::<u64>(
+                        fourier_bsk.glwe_size(),
+                        fourier_bsk.polynomial_size(),
+                        fft,
+                    )
+                    .unwrap()
+                    .unaligned_bytes_required(),
+                );
+                let stack = buffers.stack();
+
+                programmable_bootstrap_lwe_ciphertext_many_lut_mem_optimized(
+                    input,
+                    &mut output,
+                    &lut.acc,
+                    fourier_bsk,
+                    lut.lut_count_log,
+                    fft,
+                    stack,
+                );
+            }
+            ShortintBootstrappingKey::MultiBit {
+                fourier_bsk,
+                thread_count,
+                deterministic_execution,
+            } => {
+                if *deterministic_execution {
+                    multi_bit_deterministic_programmable_bootstrap_lwe_ciphertext_many_lut(
+                        input,
+                        &mut output,
+                        &lut.acc,
+                        fourier_bsk,
+                        lut.lut_count_log,
+                        *thread_count,
+                    );
+                } else {
+                    multi_bit_programmable_bootstrap_lwe_ciphertext_many_lut(
+                        input,
+                        &mut output,
+                        &lut.acc,
+                        fourier_bsk,
+                        lut.lut_count_log,
+                        *thread_count,
+                    );
+                }
+            }
+        }
+
+        output
+    }
+
+    fn trivial_many_lut_pbs(&self, ct: &Ciphertext, lut: &ManyLookupTableOwned) -> Vec<Ciphertext> {
+        assert_eq!(ct.noise_level(), NoiseLevel::ZERO);
+        let modulus_sup = self.message_modulus.0 * self.carry_modulus.0;
+        let delta = (1_u64 << 63) / (self.message_modulus.0 * self.carry_modulus.0) as u64;
+        let ct_value = *ct.ct.get_body().data / delta;
+
+        let polynomial_size = self.bootstrapping_key.polynomial_size().0;
+        let box_size = polynomial_size / modulus_sup;
+        let sample_extraction_stride = polynomial_size >> lut.lut_count_log.0;
+
+        lut.per_function_output_degree
+            .iter()
+            .enumerate()
+            .map(|(function_idx, degree)| {
+                let index_in_lut = function_idx * sample_extraction_stride
+                    + (ct_value % modulus_sup as u64) as usize * box_size;
+                let result = if ct_value >= modulus_sup as u64 {
+                    // padding bit is 1
+                    lut.acc.get_body().as_ref()[index_in_lut].wrapping_neg()
+                } else {
+                    lut.acc.get_body().as_ref()[index_in_lut]
+                };
+
+                let mut output = ct.clone();
+                *output.ct.get_mut_body().data = result;
+                output.degree = *degree;
+                output
+            })
+            .collect()
+    }
+}
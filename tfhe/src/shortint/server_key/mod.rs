@@ -7,8 +7,10 @@ mod bitwise_op;
 mod bivariate_pbs;
 mod comp_op;
 mod div_mod;
+mod many_lut;
 mod mul;
 mod neg;
+mod sanitize;
 mod scalar_add;
 mod scalar_bitwise_op;
 mod scalar_mul;
@@ -21,6 +23,10 @@ pub use bivariate_pbs::{
     BivariateLookupTableMutView, BivariateLookupTableOwned, BivariateLookupTableView,
 };
 pub use compressed::{CompressedServerKey, ShortintCompressedBootstrappingKey};
+pub use many_lut::{
+    ManyLookupTable, ManyLookupTableMutView, ManyLookupTableOwned, ManyLookupTableView,
+};
+pub use sanitize::SanitizationKey;
 
 #[cfg(test)]
 mod tests;
@@ -30,6 +36,9 @@ use crate::core_crypto::commons::parameters::{
     DecompositionBaseLog, DecompositionLevelCount, GlweSize, LweDimension, PolynomialSize,
     ThreadCount,
 };
+use crate::core_crypto::commons::raw_key_format::{
+    raw_key_byte_len, RawKeyContainer, RawKeyError, SharedRawKeyBytes,
+};
 use crate::core_crypto::commons::traits::*;
 use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::crypto::bootstrap::FourierLweBootstrapKey;
@@ -39,10 +48,15 @@ use crate::shortint::client_key::ClientKey;
 use crate::shortint::engine::{fill_accumulator, ShortintEngine};
 use crate::shortint::parameters::{
     CarryModulus, CiphertextConformanceParams, CiphertextModulus, MessageModulus,
+    ShortintParameterSet,
 };
-use crate::shortint::PBSOrder;
+use crate::shortint::{PBSOrder, PBSParameters};
+use aligned_vec::ABox;
+use concrete_fft::c64;
 use serde::{Deserialize, Serialize};
 use std::fmt::{Debug, Display, Formatter};
+use std::panic::RefUnwindSafe;
+use std::sync::Arc;
 
 /// Error returned when the carry buffer is full.
 #[derive(Debug)]
@@ -100,11 +114,79 @@ impl Display for CheckError {
 
 impl std::error::Error for CheckError {}
 
+/// The keyswitching key of a [`ServerKey`], its data may be used in place from raw key bytes, see
+/// [`ServerKey::from_raw_bytes`].
+pub type ShortintKeyswitchKey = LweKeyswitchKey<RawKeyContainer<Vec<u64>>>;
+/// The classic bootstrapping key of a [`ServerKey`], its data may be used in place from raw key
+/// bytes, see [`ServerKey::from_raw_bytes`].
+pub type ShortintFourierBootstrapKey = FourierLweBootstrapKey<RawKeyContainer<ABox<[c64]>>>;
+/// The multi bit bootstrapping key of a [`ServerKey`], its data may be used in place from raw key
+/// bytes, see [`ServerKey::from_raw_bytes`].
+pub type ShortintFourierMultiBitBootstrapKey =
+    FourierLweMultiBitBootstrapKey<RawKeyContainer<ABox<[c64]>>>;
+
+impl From<LweKeyswitchKeyOwned<u64>> for ShortintKeyswitchKey {
+    fn from(key: LweKeyswitchKeyOwned<u64>) -> Self {
+        let decomp_base_log = key.decomposition_base_log();
+        let decomp_level_count = key.decomposition_level_count();
+        let output_lwe_size = key.output_lwe_size();
+        let ciphertext_modulus = key.ciphertext_modulus();
+
+        Self::from_container(
+            RawKeyContainer::from_owned(key.into_container()),
+            decomp_base_log,
+            decomp_level_count,
+            output_lwe_size,
+            ciphertext_modulus,
+        )
+    }
+}
+
+impl From<FourierLweBootstrapKeyOwned> for ShortintFourierBootstrapKey {
+    fn from(key: FourierLweBootstrapKeyOwned) -> Self {
+        let input_lwe_dimension = key.input_lwe_dimension();
+        let glwe_size = key.glwe_size();
+        let polynomial_size = key.polynomial_size();
+        let decomposition_base_log = key.decomposition_base_log();
+        let decomposition_level_count = key.decomposition_level_count();
+
+        Self::from_container(
+            RawKeyContainer::from_owned(key.data()),
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+        )
+    }
+}
+
+impl From<FourierLweMultiBitBootstrapKeyOwned> for ShortintFourierMultiBitBootstrapKey {
+    fn from(key: FourierLweMultiBitBootstrapKeyOwned) -> Self {
+        let input_lwe_dimension = key.input_lwe_dimension();
+        let glwe_size = key.glwe_size();
+        let polynomial_size = key.polynomial_size();
+        let decomposition_base_log = key.decomposition_base_log();
+        let decomposition_level_count = key.decomposition_level_count();
+        let grouping_factor = key.grouping_factor();
+
+        Self::from_container(
+            RawKeyContainer::from_owned(key.data()),
+            input_lwe_dimension,
+            glwe_size,
+            polynomial_size,
+            decomposition_base_log,
+            decomposition_level_count,
+            grouping_factor,
+        )
+    }
+}
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum ShortintBootstrappingKey {
-    Classic(FourierLweBootstrapKeyOwned),
+    Classic(ShortintFourierBootstrapKey),
     MultiBit {
-        fourier_bsk: FourierLweMultiBitBootstrapKeyOwned,
+        fourier_bsk: ShortintFourierMultiBitBootstrapKey,
         thread_count: ThreadCount,
         deterministic_execution: bool,
     },
@@ -282,7 +364,7 @@ impl ShortintBootstrappingKey {
 /// sends it to the server so it can compute homomorphic circuits.
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct ServerKey {
-    pub key_switching_key: LweKeyswitchKeyOwned<u64>,
+    pub key_switching_key: ShortintKeyswitchKey,
     pub bootstrapping_key: ShortintBootstrappingKey,
     // Size of the message buffer
     pub message_modulus: MessageModulus,
@@ -356,6 +438,97 @@ impl ServerKey {
         })
     }
 
+    /// Load a server key written by [`ClientKey::generate_server_key_to_writer`].
+    ///
+    /// The keys are used in place, without copying them: the returned server key, and all its
+    /// clones, keep `bytes` alive. The bytes are typically a memory mapped file, several
+    /// processes mapping the same file then share a single copy of the keys in the page cache.
+    ///
+    /// The bytes must be aligned for the key elements, which is the case for a page aligned
+    /// mapping of a whole file. The parameters must be the ones of the client key used to
+    /// generate the server key, an error is returned if they do not match the stored keys.
+    ///
+    /// See [`ClientKey::generate_server_key_to_writer`] for usage.
+    pub fn from_raw_bytes<B, P>(bytes: Arc<B>, parameters: P) -> Result<Self, RawKeyError>
+    where
+        B: AsRef<[u8]> + Send + Sync + RefUnwindSafe + 'static,
+        P: Into<ShortintParameterSet>,
+    {
+        let shared_bytes: SharedRawKeyBytes = bytes;
+        let bytes: &[u8] = (*shared_bytes).as_ref();
+
+        let parameters: ShortintParameterSet = parameters.into();
+        let Some(PBSParameters::PBS(pbs_params)) = parameters.pbs_parameters() else {
+            return Err(RawKeyError::InvalidParameters(
+                "only classic PBS parameters are supported",
+            ));
+        };
+
+        let (raw_bsk, raw_ksk) = bytes.split_at(raw_key_byte_len(bytes)?);
+        let bsk = FourierLweBootstrapKeyView::from_raw_bytes(raw_bsk)?;
+        let ksk = LweKeyswitchKeyView::<u64>::from_raw_bytes(raw_ksk)?;
+
+        if bsk.input_lwe_dimension() != pbs_params.lwe_dimension
+            || bsk.glwe_size() != pbs_params.glwe_dimension.to_glwe_size()
+            || bsk.polynomial_size() != pbs_params.polynomial_size
+            || bsk.decomposition_base_log() != pbs_params.pbs_base_log
+            || bsk.decomposition_level_count() != pbs_params.pbs_level
+        {
+            return Err(RawKeyError::InvalidParameters(
+                "bootstrapping key does not match the given parameters",
+            ));
+        }
+
+        if ksk.input_key_lwe_dimension()
+            != pbs_params
+                .glwe_dimension
+                .to_equivalent_lwe_dimension(pbs_params.polynomial_size)
+            || ksk.output_key_lwe_dimension() != pbs_params.lwe_dimension
+            || ksk.decomposition_base_log() != pbs_params.ks_base_log
+            || ksk.decomposition_level_count() != pbs_params.ks_level
+            || ksk.ciphertext_modulus() != pbs_params.ciphertext_modulus
+        {
+            return Err(RawKeyError::InvalidParameters(
+                "keyswitching key does not match the given parameters",
+            ));
+        }
+
+        let fourier_bsk = FourierLweBootstrapKey::from_container(
+            RawKeyContainer::from_shared_bytes(&shared_bytes, bsk.data()),
+            bsk.input_lwe_dimension(),
+            bsk.glwe_size(),
+            bsk.polynomial_size(),
+            bsk.decomposition_base_log(),
+            bsk.decomposition_level_count(),
+        );
+
+        let key_switching_key = LweKeyswitchKey::from_container(
+            RawKeyContainer::from_shared_bytes(&shared_bytes, ksk.as_ref()),
+            ksk.decomposition_base_log(),
+            ksk.decomposition_level_count(),
+            ksk.output_lwe_size(),
+            ksk.ciphertext_modulus(),
+        );
+
+        let max_degree =
+            MaxDegree::new(parameters.message_modulus().0 * parameters.carry_modulus().0 - 1);
+        let max_noise_level = MaxNoiseLevel::from_msg_carry_modulus(
+            parameters.message_modulus(),
+            parameters.carry_modulus(),
+        );
+
+        Ok(Self {
+            key_switching_key,
+            bootstrapping_key: ShortintBootstrappingKey::Classic(fourier_bsk),
+            message_modulus: parameters.message_modulus(),
+            carry_modulus: parameters.carry_modulus(),
+            max_degree,
+            max_noise_level,
+            ciphertext_modulus: parameters.ciphertext_modulus(),
+            pbs_order: parameters.encryption_key_choice().into(),
+        })
+    }
+
     /// Constructs the lookup table given a function as input.
     ///
     /// # Example
@@ -964,7 +1137,7 @@ impl From<CompressedServerKey> for ServerKey {
                         &mut bootstrapping_key,
                     );
 
-                    ShortintBootstrappingKey::Classic(bootstrapping_key)
+                    ShortintBootstrappingKey::Classic(bootstrapping_key.into())
                 }
                 ShortintCompressedBootstrappingKey::MultiBit {
                     seeded_bsk: bootstrapping_key,
@@ -999,7 +1172,7 @@ impl From<CompressedServerKey> for ServerKey {
                     });
 
                     ShortintBootstrappingKey::MultiBit {
-                        fourier_bsk: bootstrapping_key,
+                        fourier_bsk: bootstrapping_key.into(),
                         thread_count,
                         deterministic_execution,
                     }
@@ -1010,7 +1183,7 @@ impl From<CompressedServerKey> for ServerKey {
         let max_noise_level = MaxNoiseLevel::from_msg_carry_modulus(message_modulus, carry_modulus);
 
         Self {
-            key_switching_key,
+            key_switching_key: key_switching_key.into(),
             bootstrapping_key,
             message_modulus,
             carry_modulus,
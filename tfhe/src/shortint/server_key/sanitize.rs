@@ -0,0 +1,403 @@
+use super::ServerKey;
+use crate::core_crypto::algorithms::{
+    allocate_and_generate_new_lwe_compact_public_key, allocate_and_generate_new_lwe_keyswitch_key,
+    encrypt_lwe_ciphertext_with_compact_public_key, keyswitch_lwe_ciphertext,
+    lwe_ciphertext_add_assign,
+};
+use crate::core_crypto::commons::dispersion::{DispersionParameter, StandardDev, Variance};
+use crate::core_crypto::commons::noise_formulas::*;
+use crate::core_crypto::commons::parameters::{CiphertextModulusLog, LweDimension};
+use crate::core_crypto::entities::{
+    LweCiphertext, LweCiphertextOwned, LweCompactPublicKeyOwned, LweKeyswitchKeyOwned,
+    LweSecretKey, Plaintext,
+};
+use crate::shortint::ciphertext::{Degree, MaxNoiseLevel, NoiseLevel};
+use crate::shortint::engine::ShortintEngine;
+use crate::shortint::noise_squashing::NoiseSquashingKey;
+use crate::shortint::parameters::{
+    PBSParameters, SanitizationParameters, StatisticalSecurityParameter,
+};
+use crate::shortint::{Ciphertext, ClientKey, PBSOrder};
+
+use serde::{Deserialize, Serialize};
+
+/// A key sanitizing shortint ciphertexts, see [`ServerKey::sanitize`].
+///
+/// It is generated by the client and is meant to be published, like the server key.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SanitizationKey {
+    /// Bootstraps the ciphertexts to a 128 bits secret key
+    pub(crate) noise_squashing_key: NoiseSquashingKey,
+    /// Encrypts zeros under the 128 bits secret key to rerandomize the mask of the ciphertexts
+    pub(crate) compact_public_key: LweCompactPublicKeyOwned<u128>,
+    /// Switches from the 128 bits secret key back to the encryption key of the ciphertexts
+    pub(crate) key_switching_key: LweKeyswitchKeyOwned<u64>,
+    pub(crate) pbs_order: PBSOrder,
+    pub params: SanitizationParameters,
+}
+
+impl ClientKey {
+    /// Generate the key sanitizing shortint ciphertexts.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the sanitization parameters do not match the client key parameters, i.e. if the
+    /// ciphertexts given to the last bootstrap of the sanitization would be noisier than the
+    /// server key allows.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::ClientKey;
+    ///
+    /// // Generate the client key:
+    /// let cks = ClientKey::new(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let sanitization_key = cks.new_sanitization_key(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// ```
+    pub fn new_sanitization_key(&self, params: SanitizationParameters) -> SanitizationKey {
+        let noise_squashing_params = params.noise_squashing_parameters;
+        let pbs_parameters = self
+            .parameters
+            .pbs_parameters()
+            .expect("Sanitization requires PBS parameters");
+        let pbs_order: PBSOrder = pbs_parameters.encryption_key_choice().into();
+
+        let (output_lwe_secret_key, output_noise) = match pbs_order {
+            PBSOrder::KeyswitchBootstrap => (
+                &self.large_lwe_secret_key,
+                pbs_parameters.glwe_noise_distribution(),
+            ),
+            PBSOrder::BootstrapKeyswitch => (
+                &self.small_lwe_secret_key,
+                pbs_parameters.lwe_noise_distribution(),
+            ),
+        };
+
+        let sanitized_variance = sanitized_noise_variance(
+            &params,
+            self.small_lwe_secret_key.lwe_dimension(),
+            output_noise.variance(u64::BITS),
+        );
+        let max_variance = max_noise_variance(&pbs_parameters, pbs_order);
+        assert!(
+            sanitized_variance.get_variance() <= max_variance.get_variance(),
+            "The noise of sanitized ciphertexts with {params:?} exceeds the noise budget of the \
+            client key parameters"
+        );
+
+        let noise_squashing_private_key =
+            self.new_noise_squashing_private_key(noise_squashing_params);
+        let noise_squashing_key = self.new_noise_squashing_key(&noise_squashing_private_key);
+        let squashing_lwe_secret_key = noise_squashing_private_key
+            .post_noise_squashing_secret_key
+            .as_lwe_secret_key();
+
+        // The keyswitch runs on 64 bits integers, the coefficients of the binary input key are the
+        // same on 64 bits
+        let input_lwe_secret_key = LweSecretKey::from_container(
+            squashing_lwe_secret_key
+                .as_ref()
+                .iter()
+                .map(|&coefficient| coefficient as u64)
+                .collect::<Vec<_>>(),
+        );
+
+        let (compact_public_key, key_switching_key) =
+            ShortintEngine::with_thread_local_mut(|engine| {
+                let compact_public_key = allocate_and_generate_new_lwe_compact_public_key(
+                    &squashing_lwe_secret_key,
+                    noise_squashing_params.glwe_noise_distribution,
+                    noise_squashing_params.ciphertext_modulus,
+                    &mut engine.encryption_generator,
+                );
+
+                let key_switching_key = allocate_and_generate_new_lwe_keyswitch_key(
+                    &input_lwe_secret_key,
+                    output_lwe_secret_key,
+                    params.ks_base_log,
+                    params.ks_level,
+                    output_noise,
+                    pbs_parameters.ciphertext_modulus(),
+                    &mut engine.encryption_generator,
+                );
+
+                (compact_public_key, key_switching_key)
+            });
+
+        SanitizationKey {
+            noise_squashing_key,
+            compact_public_key,
+            key_switching_key,
+            pbs_order,
+            params,
+        }
+    }
+}
+
+impl SanitizationKey {
+    /// Return the standard deviation of the flooding noise, see [`ServerKey::sanitize`].
+    fn flooding_noise(&self) -> StandardDev {
+        flooding_std_dev(
+            squashed_noise_variance(
+                &self.params,
+                self.noise_squashing_key
+                    .bootstrapping_key
+                    .input_lwe_dimension(),
+            ),
+            self.params.statistical_security,
+        )
+    }
+}
+
+impl ServerKey {
+    /// Sanitize a ciphertext, so that it reveals nothing about the computations it went through
+    /// besides its message.
+    ///
+    /// This is meant to be called on results before returning them to the owner of the client
+    /// key, when the computed function or the data used by the server must stay private. The
+    /// sanitization:
+    /// 1. bootstraps the ciphertext to a 128 bits ciphertext, whose noise is far below the one of
+    ///    the 64 bits ciphertexts, see [`crate::shortint::noise_squashing`];
+    /// 2. rerandomizes its mask by adding a fresh encryption of zero under a compact public key;
+    /// 3. floods its noise with a gaussian noise $2^{\lambda}$ times larger than the worst case
+    ///    noise of the 128 bits bootstrap, with $\lambda$ the statistical security of the
+    ///    [`SanitizationParameters`];
+    /// 4. rounds it to 64 bits and keyswitches it back to the encryption key of the ciphertexts;
+    /// 5. bootstraps it with the server key.
+    ///
+    /// The noise of the 128 bits bootstrap is the only one still depending on the input ciphertext
+    /// after the first step, once flooded the input of the last bootstrap is within a statistical
+    /// distance of $2^{-\lambda}$ from a fresh encryption of the message, independently of the
+    /// computations the input went through.
+    ///
+    /// The output always has an empty carry and a nominal noise level.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the ciphertext or the sanitization key do not match the parameters of the server
+    /// key.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use tfhe::shortint::gen_keys;
+    /// use tfhe::shortint::parameters::sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    /// use tfhe::shortint::parameters::PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    ///
+    /// // Generate the client key and the server key:
+    /// let (cks, sks) = gen_keys(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    /// let sanitization_key = cks.new_sanitization_key(SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    ///
+    /// let ct_1 = cks.encrypt(3);
+    /// let ct_2 = cks.encrypt(2);
+    ///
+    /// // The carry of the sum is not empty
+    /// let ct_res = sks.unchecked_add(&ct_1, &ct_2);
+    ///
+    /// let ct_res = sks.sanitize(&ct_res, &sanitization_key);
+    ///
+    /// assert!(ct_res.carry_is_empty());
+    /// assert_eq!(cks.decrypt(&ct_res), 1);
+    /// ```
+    pub fn sanitize(&self, ct: &Ciphertext, sanitization_key: &SanitizationKey) -> Ciphertext {
+        let mut ct_res = ct.clone();
+        self.sanitize_assign(&mut ct_res, sanitization_key);
+        ct_res
+    }
+
+    /// Sanitize a ciphertext in place.
+    ///
+    /// See [`Self::sanitize`] for details.
+    pub fn sanitize_assign(&self, ct: &mut Ciphertext, sanitization_key: &SanitizationKey) {
+        assert!(
+            sanitization_key.pbs_order == self.pbs_order
+                && sanitization_key
+                    .key_switching_key
+                    .output_key_lwe_dimension()
+                    == ct.ct.lwe_size().to_lwe_dimension(),
+            "Sanitization key parameters do not match the server key parameters"
+        );
+
+        let lookup_table = self.generate_msg_lookup_table(|x| x, ct.message_modulus);
+        // The message is kept as is, its degree is bounded by the input degree
+        let degree = Degree::new(ct.degree.get().min(ct.message_modulus.0 - 1));
+        let flooding_noise = sanitization_key.flooding_noise();
+
+        let mut squashed = self
+            .squash_ciphertext_noise(&sanitization_key.noise_squashing_key, ct)
+            .ct;
+
+        ShortintEngine::with_thread_local_mut(|engine| {
+            let mut zero_encryption =
+                LweCiphertextOwned::new(0u128, squashed.lwe_size(), squashed.ciphertext_modulus());
+            let noise_distribution = sanitization_key
+                .params
+                .noise_squashing_parameters
+                .glwe_noise_distribution;
+            encrypt_lwe_ciphertext_with_compact_public_key(
+                &sanitization_key.compact_public_key,
+                &mut zero_encryption,
+                Plaintext(0),
+                noise_distribution,
+                noise_distribution,
+                &mut engine.secret_generator,
+                &mut engine.encryption_generator,
+            );
+            lwe_ciphertext_add_assign(&mut squashed, &zero_encryption);
+
+            let noise: u128 = engine.encryption_generator.random_noise(flooding_noise);
+            let body = squashed.get_mut_body();
+            *body.data = (*body.data).wrapping_add(noise);
+        });
+
+        // Round the 128 bits ciphertext to its 64 most significant bits
+        let rounded = LweCiphertext::from_container(
+            squashed
+                .as_ref()
+                .iter()
+                .map(|&coefficient| (coefficient.wrapping_add(1 << 63) >> 64) as u64)
+                .collect::<Vec<_>>(),
+            self.ciphertext_modulus,
+        );
+
+        keyswitch_lwe_ciphertext(&sanitization_key.key_switching_key, &rounded, &mut ct.ct);
+        // The noise was checked to fit the noise budget when generating the sanitization key, the
+        // ciphertext is no longer trivial
+        ct.set_noise_level(NoiseLevel::NOMINAL);
+
+        self.apply_lookup_table_assign(ct, &lookup_table);
+        ct.degree = degree;
+    }
+}
+
+/// Return the standard deviation of a gaussian noise hiding a noise of variance `noise`.
+///
+/// The hidden noise is bounded by $k\sigma$ except with probability $2^{-(\lambda + 1)}$, with
+/// $k = \sqrt{2 (\lambda + 2) \ln 2}$. The flooding noise has a standard deviation
+/// $2^{\lambda} k \sigma$, which shifted by the hidden noise is within a statistical distance of
+/// $2^{-\lambda}$ from itself.
+fn flooding_std_dev(
+    noise: impl DispersionParameter,
+    statistical_security: StatisticalSecurityParameter,
+) -> StandardDev {
+    let lambda = statistical_security.0 as f64;
+    let tail_factor = (2.0 * (lambda + 2.0) * std::f64::consts::LN_2).sqrt();
+
+    StandardDev(2.0f64.powf(lambda) * tail_factor * noise.get_standard_dev())
+}
+
+/// Return the variance of the noise of the 128 bits bootstrap, which does not depend on the noise
+/// of its input.
+fn squashed_noise_variance(
+    params: &SanitizationParameters,
+    input_lwe_dimension: LweDimension,
+) -> Variance {
+    let noise_squashing_params = &params.noise_squashing_parameters;
+
+    programmable_bootstrap_lwe_ciphertext_output_variance(
+        input_lwe_dimension,
+        noise_squashing_params.glwe_dimension,
+        noise_squashing_params.polynomial_size,
+        noise_squashing_params
+            .glwe_noise_distribution
+            .variance(u128::BITS),
+        noise_squashing_params.decomp_base_log,
+        noise_squashing_params.decomp_level_count,
+        noise_squashing_params.ciphertext_modulus,
+    )
+}
+
+/// Return the variance of the noise of a sanitized ciphertext before its last bootstrap.
+fn sanitized_noise_variance(
+    params: &SanitizationParameters,
+    input_lwe_dimension: LweDimension,
+    output_noise: Variance,
+) -> Variance {
+    let noise_squashing_params = &params.noise_squashing_parameters;
+    let squashing_lwe_dimension = noise_squashing_params
+        .glwe_dimension
+        .to_equivalent_lwe_dimension(noise_squashing_params.polynomial_size);
+    let squashing_noise = noise_squashing_params
+        .glwe_noise_distribution
+        .variance(u128::BITS);
+
+    let squashed_variance = squashed_noise_variance(params, input_lwe_dimension);
+    let zero_encryption_variance = encrypt_lwe_ciphertext_with_compact_public_key_output_variance(
+        squashing_lwe_dimension,
+        squashing_noise,
+        squashing_noise,
+        squashing_noise,
+    );
+    let flooding_variance = flooding_std_dev(squashed_variance, params.statistical_security);
+    let rounding_variance = lwe_ciphertext_modulus_switch_additive_variance(
+        squashing_lwe_dimension,
+        CiphertextModulusLog(u64::BITS as usize),
+        noise_squashing_params.ciphertext_modulus,
+    );
+
+    keyswitch_lwe_ciphertext_output_variance(
+        Variance(
+            squashed_variance.get_variance()
+                + zero_encryption_variance.get_variance()
+                + flooding_variance.get_variance()
+                + rounding_variance.get_variance(),
+        ),
+        squashing_lwe_dimension,
+        output_noise,
+        params.ks_base_log,
+        params.ks_level,
+        crate::core_crypto::commons::ciphertext_modulus::CiphertextModulus::<u64>::new_native(),
+    )
+}
+
+/// Return the variance of the noise of the noisiest ciphertexts the server key accepts as input of
+/// a bootstrap.
+fn max_noise_variance(parameters: &PBSParameters, pbs_order: PBSOrder) -> Variance {
+    let ciphertext_modulus = parameters.ciphertext_modulus();
+    let pbs_variance = if parameters.is_multi_bit_pbs() {
+        multi_bit_programmable_bootstrap_lwe_ciphertext_output_variance(
+            parameters.lwe_dimension(),
+            parameters.glwe_dimension(),
+            parameters.polynomial_size(),
+            parameters.grouping_factor(),
+            parameters.glwe_noise_distribution().variance(u64::BITS),
+            parameters.pbs_base_log(),
+            parameters.pbs_level(),
+            ciphertext_modulus,
+        )
+    } else {
+        programmable_bootstrap_lwe_ciphertext_output_variance(
+            parameters.lwe_dimension(),
+            parameters.glwe_dimension(),
+            parameters.polynomial_size(),
+            parameters.glwe_noise_distribution().variance(u64::BITS),
+            parameters.pbs_base_log(),
+            parameters.pbs_level(),
+            ciphertext_modulus,
+        )
+    };
+
+    let nominal_variance = match pbs_order {
+        PBSOrder::KeyswitchBootstrap => pbs_variance,
+        PBSOrder::BootstrapKeyswitch => keyswitch_lwe_ciphertext_output_variance(
+            pbs_variance,
+            parameters
+                .glwe_dimension()
+                .to_equivalent_lwe_dimension(parameters.polynomial_size()),
+            parameters.lwe_noise_distribution().variance(u64::BITS),
+            parameters.ks_base_log(),
+            parameters.ks_level(),
+            ciphertext_modulus,
+        ),
+    };
+
+    let max_noise_level = MaxNoiseLevel::from_msg_carry_modulus(
+        parameters.message_modulus(),
+        parameters.carry_modulus(),
+    );
+
+    Variance(max_noise_level.get() as f64 * nominal_variance.get_variance())
+}
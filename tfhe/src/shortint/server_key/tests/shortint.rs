@@ -1,7 +1,13 @@
-use crate::shortint::ciphertext::NoiseLevel;
+use crate::core_crypto::commons::math::random::Seed;
+use crate::core_crypto::prelude::LweCiphertextOwned;
+use crate::shortint::ciphertext::{Degree, NoiseLevel};
+use crate::shortint::client_key::{combine_partial_decryptions, deal_party_secret_key_shares};
 use crate::shortint::keycache::KEY_CACHE;
+use crate::shortint::noise_squashing::SquashedNoiseCiphertext;
+use crate::shortint::parameters::noise_squashing::NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2;
 use crate::shortint::parameters::*;
 use crate::shortint::server_key::LookupTableOwned;
+use crate::shortint::{ClientKey, CompressedCompactPublicKey, CompressedServerKey, ServerKey};
 use paste::paste;
 use rand::Rng;
 
@@ -177,6 +183,7 @@ create_parametrized_test!(shortint_keyswitch_bootstrap);
 create_parametrized_test!(shortint_keyswitch_programmable_bootstrap);
 create_parametrized_test!(shortint_carry_extract);
 create_parametrized_test!(shortint_message_extract);
+create_parametrized_test!(shortint_apply_many_lookup_tables);
 create_parametrized_test!(shortint_generate_lookup_table);
 create_parametrized_test!(shortint_unchecked_add);
 create_parametrized_test!(shortint_smart_add);
@@ -338,6 +345,162 @@ where
     }
 }
 
+#[test]
+fn test_shortint_threshold_decrypt_param_message_2_carry_2_ks_pbs() {
+    shortint_threshold_decrypt(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+}
+
+#[test]
+fn test_shortint_threshold_decrypt_param_message_2_carry_2_pbs_ks() {
+    shortint_threshold_decrypt(PARAM_MESSAGE_2_CARRY_2_PBS_KS);
+}
+
+/// test threshold decryption of squashed ciphertexts with shares of the noise squashing private key
+fn shortint_threshold_decrypt<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+
+    let noise_squashing_private_key =
+        cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    let noise_squashing_key = cks.new_noise_squashing_key(&noise_squashing_private_key);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let key_shares =
+        noise_squashing_private_key.generate_threshold_shares(ShareThreshold(2), ShareCount(3));
+
+    for _ in 0..NB_TESTS_SMART {
+        let clear = rng.gen::<u64>() % modulus;
+
+        let ct = cks.encrypt(clear);
+        // Also decrypt the output of a PBS
+        let ct_pbs = sks.message_extract(&ct);
+
+        // Pick 2 distinct participants out of 3
+        let excluded = rng.gen_range(0..key_shares.len());
+        let participating_shares: Vec<_> = key_shares
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, key_share)| (idx != excluded).then_some(key_share))
+            .collect();
+        let participants: Vec<_> = participating_shares
+            .iter()
+            .map(|key_share| key_share.share_index())
+            .collect();
+
+        for ct in [&ct, &ct_pbs] {
+            let squashed = sks.squash_ciphertext_noise(&noise_squashing_key, ct);
+
+            let partial_decryptions: Vec<_> = participating_shares
+                .iter()
+                .map(|key_share| {
+                    key_share.partial_decrypt(
+                        &squashed,
+                        &participants,
+                        StatisticalSecurityParameter(40),
+                    )
+                })
+                .collect();
+
+            let dec = combine_partial_decryptions(&squashed, &partial_decryptions);
+
+            assert_eq!(clear, dec);
+        }
+    }
+}
+
+#[test]
+#[should_panic(expected = "does not fit in the decoding margin")]
+fn test_shortint_threshold_decrypt_rejects_oversized_statistical_security() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let cks = keys.client_key();
+
+    let noise_squashing_private_key =
+        cks.new_noise_squashing_private_key(NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2);
+    let key_shares =
+        noise_squashing_private_key.generate_threshold_shares(ShareThreshold(2), ShareCount(3));
+    let participants = [key_shares[0].share_index(), key_shares[1].share_index()];
+
+    // The squashed noise is around 2^-64, flooding it by 2^64 exceeds the decoding margin
+    let squashed = SquashedNoiseCiphertext {
+        ct: LweCiphertextOwned::new(
+            0u128,
+            noise_squashing_private_key
+                .post_noise_squashing_secret_key
+                .as_lwe_secret_key()
+                .lwe_dimension()
+                .to_lwe_size(),
+            NOISE_SQUASHING_PARAM_MESSAGE_2_CARRY_2.ciphertext_modulus,
+        ),
+        degree: Degree::new(0),
+        message_modulus: cks.parameters.message_modulus(),
+        carry_modulus: cks.parameters.carry_modulus(),
+    };
+    let _ =
+        key_shares[0].partial_decrypt(&squashed, &participants, StatisticalSecurityParameter(64));
+}
+
+#[test]
+fn test_shortint_distributed_key_generation_param_message_2_carry_2_compact_pk_ks_pbs() {
+    shortint_distributed_key_generation(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS);
+}
+
+#[test]
+fn test_shortint_distributed_key_generation_param_message_2_carry_2_compact_pk_pbs_ks() {
+    shortint_distributed_key_generation(PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS);
+}
+
+/// test the server key and compact public key aggregated from the shares of a distributed key
+/// generation, the client key is only used by the dealer of the secret key shares and to decrypt
+fn shortint_distributed_key_generation<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let param: PBSParameters = param.into();
+    let cks = ClientKey::new(param);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let party_count = ShareCount(3);
+    let key_shares = deal_party_secret_key_shares(&cks, party_count);
+    let common_seed = Seed(rng.gen());
+
+    let server_key_shares: Vec<_> = key_shares
+        .iter()
+        .map(|key_share| key_share.generate_server_key_share(common_seed, party_count))
+        .collect();
+    let compact_public_key_shares: Vec<_> = key_shares
+        .iter()
+        .map(|key_share| key_share.generate_compact_public_key_share(common_seed, party_count))
+        .collect();
+
+    let sks: ServerKey = CompressedServerKey::aggregate_shares(&server_key_shares).into();
+    let cpk = CompressedCompactPublicKey::aggregate_shares(&compact_public_key_shares).decompress();
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt(clear_0);
+        let ct_1 = cpk.encrypt(clear_1);
+
+        // Both the keyswitch and the bootstrap are exercised by the message extract
+        let ct_res = sks.unchecked_add(&ct_0, &ct_1);
+        let ct_res = sks.message_extract(&ct_res);
+
+        let dec = cks.decrypt(&ct_res);
+
+        assert_eq!((clear_0 + clear_1) % modulus, dec);
+    }
+}
+
 fn shortint_encrypt_decrypt_without_padding<P>(param: P)
 where
     P: Into<PBSParameters>,
@@ -528,6 +691,48 @@ where
     }
 }
 
+/// test extraction of the message and the carry with a single many lookup table PBS
+fn shortint_apply_many_lookup_tables<P>(param: P)
+where
+    P: Into<PBSParameters>,
+{
+    let keys = KEY_CACHE.get_from_param(param);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    //RNG
+    let mut rng = rand::thread_rng();
+
+    let modulus_sup =
+        (cks.parameters.message_modulus().0 * cks.parameters.carry_modulus().0) as u64;
+    // With two functions the input space is halved
+    let input_modulus = modulus_sup / 2;
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    let message = |x: u64| x % modulus;
+    let carry = |x: u64| x / modulus;
+
+    for _ in 0..NB_TESTS {
+        let clear = rng.gen::<u64>() % input_modulus;
+
+        // encryption of an integer
+        let mut ctxt = cks.unchecked_encrypt(clear);
+        ctxt.degree = Degree::new(input_modulus as usize - 1);
+
+        // message and carry extraction
+        let ct_res = sks.apply_many_lookup_tables(&ctxt, &[&message, &carry]);
+        assert_eq!(ct_res.len(), 2);
+
+        // decryption of the results
+        let dec_msg = cks.decrypt_message_and_carry(&ct_res[0]);
+        let dec_carry = cks.decrypt_message_and_carry(&ct_res[1]);
+
+        // assert
+        assert_eq!(message(clear), dec_msg);
+        assert_eq!(carry(clear), dec_carry);
+        assert!(ct_res[0].degree.get() < modulus as usize);
+    }
+}
+
 /// test multiplication with the LWE server key
 fn shortint_generate_lookup_table<P>(param: P)
 where
@@ -3294,3 +3499,163 @@ where
         }
     }
 }
+
+#[test]
+fn test_shortint_t_uniform_noise_distribution() {
+    // Replace the gaussian noises by TUniform noises whose bounds are below the gaussian standard
+    // deviations, the computations are therefore at least as correct as with the original noises
+    let t_uniform_below = |noise: NoiseDistribution| {
+        let std_dev = noise.gaussian_std_dev().unwrap();
+        NoiseDistribution::TUniform(TUniform::new((std_dev.0.log2() + 64.0).floor() as u32))
+    };
+
+    let mut param = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    param.lwe_noise_distribution = t_uniform_below(param.lwe_noise_distribution);
+    param.glwe_noise_distribution = t_uniform_below(param.glwe_noise_distribution);
+
+    let (cks, sks) = crate::shortint::gen_keys(param);
+
+    let mut rng = rand::thread_rng();
+    let modulus = param.message_modulus.0 as u64;
+
+    let lut = sks.generate_lookup_table(|x| (x * x) % modulus);
+
+    for _ in 0..NB_TESTS_SMART {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ct_0 = cks.encrypt(clear_0);
+        let ct_1 = cks.encrypt(clear_1);
+
+        let ct_res = sks.unchecked_add(&ct_0, &ct_1);
+        let ct_res = sks.apply_lookup_table(&ct_res, &lut);
+
+        let clear_res = (clear_0 + clear_1) % modulus;
+        assert_eq!(cks.decrypt(&ct_res), (clear_res * clear_res) % modulus);
+    }
+}
+
+#[test]
+fn test_shortint_sanitize() {
+    for (param, sanitization_param) in [
+        (
+            PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+            sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS,
+        ),
+        (
+            PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+            sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_PBS_KS,
+        ),
+    ] {
+        let keys = KEY_CACHE.get_from_param(param);
+        let (cks, sks) = (keys.client_key(), keys.server_key());
+        let sanitization_key = cks.new_sanitization_key(sanitization_param);
+
+        let mut rng = rand::thread_rng();
+        let modulus = param.message_modulus.0 as u64;
+
+        for _ in 0..NB_TESTS {
+            let clear_0 = rng.gen::<u64>() % modulus;
+            let clear_1 = rng.gen::<u64>() % modulus;
+
+            let ct_0 = cks.encrypt(clear_0);
+            let ct_1 = cks.encrypt(clear_1);
+
+            // Reach the maximum noise level to check the sanitization handles it
+            let mut ct_res = sks.unchecked_add(&ct_0, &ct_1);
+            for _ in 0..sks.max_noise_level.get() - 2 {
+                sks.unchecked_add_assign(&mut ct_res, &cks.encrypt(0));
+            }
+
+            let sanitized = sks.sanitize(&ct_res, &sanitization_key);
+            let sanitized_again = sks.sanitize(&ct_res, &sanitization_key);
+
+            assert_eq!(sanitized.noise_level(), NoiseLevel::NOMINAL);
+            assert!(sanitized.carry_is_empty());
+            assert_ne!(sanitized.ct, sanitized_again.ct);
+
+            let clear_res = (clear_0 + clear_1) % modulus;
+            assert_eq!(cks.decrypt(&sanitized), clear_res);
+            assert_eq!(cks.decrypt(&sanitized_again), clear_res);
+        }
+
+        // Trivial ciphertexts are encrypted by the sanitization
+        let trivial = sks.create_trivial(1);
+        let sanitized = sks.sanitize(&trivial, &sanitization_key);
+        assert!(!sanitized.is_trivial());
+        assert_eq!(cks.decrypt(&sanitized), 1);
+    }
+}
+
+#[test]
+#[should_panic(expected = "exceeds the noise budget")]
+fn test_shortint_sanitization_key_rejects_oversized_statistical_security() {
+    let keys = KEY_CACHE.get_from_param(PARAM_MESSAGE_2_CARRY_2_KS_PBS);
+    let cks = keys.client_key();
+
+    // The flooding noise would no longer fit the noise budget of the last bootstrap
+    let sanitization_param = SanitizationParameters {
+        statistical_security: StatisticalSecurityParameter(64),
+        ..sanitization::SANITIZATION_PARAM_MESSAGE_2_CARRY_2_KS_PBS
+    };
+    let _ = cks.new_sanitization_key(sanitization_param);
+}
+
+#[test]
+fn test_shortint_server_key_from_raw_bytes_is_zero_copy() {
+    use crate::core_crypto::commons::raw_key_format::{raw_key_byte_len, RAW_KEY_HEADER_SIZE};
+    use crate::shortint::server_key::ShortintBootstrappingKey;
+    use std::sync::Arc;
+
+    let param = PARAM_MESSAGE_2_CARRY_2_KS_PBS;
+    let keys = KEY_CACHE.get_from_param(param);
+    let cks = keys.client_key();
+
+    let mut raw_sks = Vec::new();
+    cks.generate_server_key_to_writer(&mut raw_sks).unwrap();
+    let raw_sks = Arc::new(aligned_vec::AVec::<u8>::from_slice(0, &raw_sks));
+
+    let sks = ServerKey::from_raw_bytes(Arc::clone(&raw_sks), param).unwrap();
+    let sks_clone = sks.clone();
+
+    let bsk_data_ptr = |sks: &ServerKey| match &sks.bootstrapping_key {
+        ShortintBootstrappingKey::Classic(bsk) => bsk.as_view().data().as_ptr().cast::<u8>(),
+        ShortintBootstrappingKey::MultiBit { .. } => unreachable!(),
+    };
+    let ksk_data_ptr = |sks: &ServerKey| sks.key_switching_key.as_ref().as_ptr().cast::<u8>();
+
+    // The keys point into the raw bytes, the key data is right after the header of each key
+    let expected_bsk_ptr = raw_sks[RAW_KEY_HEADER_SIZE..].as_ptr();
+    let ksk_offset = raw_key_byte_len(&raw_sks).unwrap();
+    let expected_ksk_ptr = raw_sks[ksk_offset + RAW_KEY_HEADER_SIZE..].as_ptr();
+
+    for sks in [&sks, &sks_clone] {
+        assert_eq!(bsk_data_ptr(sks), expected_bsk_ptr);
+        assert_eq!(ksk_data_ptr(sks), expected_ksk_ptr);
+    }
+    // The raw bytes are shared, not copied
+    assert_eq!(Arc::strong_count(&raw_sks), 5);
+
+    let ct = cks.encrypt(1);
+    let ct_res = sks.unchecked_scalar_add(&ct, 2);
+    let ct_res = sks.message_extract(&ct_res);
+    assert_eq!(cks.decrypt(&ct_res), 3);
+
+    let mut bsk_clone = sks_clone.bootstrapping_key;
+    if let ShortintBootstrappingKey::Classic(bsk) = &mut bsk_clone {
+        // Mutable accesses copy the data instead of writing to the shared bytes
+        bsk.as_mut_view().data()[0] = concrete_fft::c64::default();
+        assert_ne!(bsk.as_view().data().as_ptr().cast::<u8>(), expected_bsk_ptr);
+    }
+    assert_eq!(bsk_data_ptr(&sks), expected_bsk_ptr);
+}
+
+#[test]
+fn test_shortint_server_key_to_writer_rejects_multi_bit_parameters() {
+    let cks = ClientKey::new(PARAM_MULTI_BIT_MESSAGE_2_CARRY_2_GROUP_2_KS_PBS);
+
+    let mut raw_sks = Vec::new();
+    let err = cks.generate_server_key_to_writer(&mut raw_sks).unwrap_err();
+    assert_eq!(err.kind(), std::io::ErrorKind::Unsupported);
+    assert!(raw_sks.is_empty());
+}
@@ -1,3 +1,4 @@
+use crate::shortint::ciphertext::NoiseLevel;
 use crate::shortint::keycache::KEY_CACHE;
 use crate::shortint::parameters::parameters_compact_pk::*;
 use crate::shortint::parameters::*;
@@ -219,3 +220,45 @@ fn shortint_compact_public_key_base_list_smart_sub(params: ClassicPBSParameters)
         }
     }
 }
+
+create_parametrized_test!(shortint_compact_public_key_rerandomize {
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_KS_PBS,
+    PARAM_MESSAGE_2_CARRY_2_COMPACT_PK_PBS_KS
+});
+
+fn shortint_compact_public_key_rerandomize(params: ClassicPBSParameters) {
+    let keys = KEY_CACHE.get_from_param(params);
+    let (cks, sks) = (keys.client_key(), keys.server_key());
+    let pk = crate::shortint::CompactPublicKey::new(cks);
+
+    let mut rng = rand::thread_rng();
+
+    let modulus = cks.parameters.message_modulus().0 as u64;
+
+    for _ in 0..NB_TESTS {
+        let clear_0 = rng.gen::<u64>() % modulus;
+        let clear_1 = rng.gen::<u64>() % modulus;
+
+        let ctxt_0 = pk.encrypt(clear_0);
+        let ctxt_1 = pk.encrypt(clear_1);
+
+        let mut ct_res = sks.unchecked_add(&ctxt_0, &ctxt_1);
+        let rerandomized_input = ct_res.clone();
+        pk.rerandomize(&mut ct_res);
+
+        assert_ne!(ct_res.ct, rerandomized_input.ct);
+        assert_eq!(
+            ct_res.noise_level(),
+            rerandomized_input.noise_level() + NoiseLevel::NOMINAL
+        );
+        assert_eq!(ct_res.degree, rerandomized_input.degree);
+
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!((clear_0 + clear_1) % modulus, dec_res);
+
+        // The rerandomized ciphertext can still be used in computations
+        let ct_res = sks.add(&ct_res, &ctxt_0);
+        let dec_res = cks.decrypt(&ct_res);
+        assert_eq!((2 * clear_0 + clear_1) % modulus, dec_res);
+    }
+}
@@ -15,7 +15,7 @@ use crate::core_crypto::entities::*;
 use crate::core_crypto::fft_impl::fft64::math::fft::Fft;
 use crate::shortint::ciphertext::*;
 use crate::shortint::engine::ShortintEngine;
-use crate::shortint::server_key::ShortintBootstrappingKey;
+use crate::shortint::server_key::{ShortintBootstrappingKey, ShortintKeyswitchKey};
 use crate::shortint::{Ciphertext, ClientKey, ServerKey, WopbsParameters};
 use serde::{Deserialize, Serialize};
 
@@ -46,7 +46,7 @@ pub struct WopbsKey {
     pub wopbs_server_key: ServerKey,
     pub pbs_server_key: ServerKey,
     pub cbs_pfpksk: LwePrivateFunctionalPackingKeyswitchKeyListOwned<u64>,
-    pub ksk_pbs_to_wopbs: LweKeyswitchKeyOwned<u64>,
+    pub ksk_pbs_to_wopbs: ShortintKeyswitchKey,
     pub param: WopbsParameters,
 }
 